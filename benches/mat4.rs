@@ -4,7 +4,7 @@ mod macros;
 mod support;
 
 use criterion::{criterion_group, criterion_main, Criterion};
-use glam::Mat4;
+use glam::{Mat4, Vec3};
 use std::ops::Mul;
 use support::*;
 
@@ -27,6 +27,17 @@ bench_unop!(mat4_inverse, "mat4 inverse", op => inverse, ty => Mat4, from => ran
 bench_binop!(mat4_mul_mat4, "mat4 * mat4", op => mul, ty => Mat4, from => random_srt_mat4);
 // bench_binop!(mat4_mul_mat4, "mat4 mul_mat4", op => mul_mat4, ty => Mat4, from => TransformSRT);
 
+fn mat4_transform_point3_slice(c: &mut Criterion) {
+    const LEN: usize = 1 << 13;
+    let mut rng = support::PCG32::default();
+    let mat = random_srt_mat4(&mut rng);
+    let points: Vec<Vec3> = (0..LEN).map(|_| random_vec3(&mut rng)).collect();
+    let mut out = vec![Vec3::zero(); LEN];
+    c.bench_function("mat4 transform_point3_slice", move |b| {
+        b.iter(|| mat.transform_point3_slice(&points, &mut out))
+    });
+}
+
 criterion_group!(
     benches,
     mat4_transpose,
@@ -34,6 +45,7 @@ criterion_group!(
     mat4_inverse,
     // mat4_mul_op_mat4,
     mat4_mul_mat4,
+    mat4_transform_point3_slice,
 );
 
 criterion_main!(benches);
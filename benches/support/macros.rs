@@ -1,5 +1,4 @@
 #[macro_export]
-
 macro_rules! bench_func {
     ($name: ident, $desc: expr, op => $func: ident, ty => $ty: ty, from => $from: expr) => {
         pub(crate) fn $name(c: &mut Criterion) {
@@ -18,6 +18,14 @@ bench_binop!(
     from2 => random_vec4
 );
 
-criterion_group!(benches, vec4_mul_mat4,);
+bench_unop!(
+    vec4_sin_cos,
+    "vec4 sin_cos",
+    op => sin_cos,
+    ty => Vec4,
+    from => random_vec4
+);
+
+criterion_group!(benches, vec4_mul_mat4, vec4_sin_cos,);
 
 criterion_main!(benches);
@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// The error returned by aligned slice loads and stores such as
+/// `Vec4::from_slice_aligned` when the slice does not start at a multiple of
+/// the alignment of the type being loaded or stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlignmentError {
+    required: usize,
+}
+
+impl AlignmentError {
+    /// Returns an error if `ptr` is not aligned to the alignment of `T`.
+    #[inline]
+    pub(crate) fn check<T, U>(ptr: *const U) -> Result<(), Self> {
+        let required = std::mem::align_of::<T>();
+        // alignments are always a power of two
+        if ptr as usize & (required - 1) == 0 {
+            Ok(())
+        } else {
+            Err(Self { required })
+        }
+    }
+
+    /// Returns the alignment in bytes that the slice was required to have.
+    #[inline]
+    pub fn required_alignment(&self) -> usize {
+        self.required
+    }
+}
+
+impl fmt::Display for AlignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "slice is not aligned to {} bytes", self.required)
+    }
+}
+
+impl std::error::Error for AlignmentError {}
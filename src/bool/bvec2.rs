@@ -0,0 +1,169 @@
+use crate::f32::Vec2;
+
+use std::{fmt, hash, ops::*};
+
+/// A 2-dimensional boolean vector mask.
+///
+/// This type is typically created by comparison methods on 2D vectors.
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+pub struct BVec2(pub(crate) u32, pub(crate) u32);
+
+#[inline]
+pub fn bvec2(x: bool, y: bool) -> BVec2 {
+    BVec2::new(x, y)
+}
+
+impl BVec2 {
+    /// Creates a new `BVec2`.
+    #[inline]
+    pub fn new(x: bool, y: bool) -> Self {
+        const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
+        Self(MASK[x as usize], MASK[y as usize])
+    }
+
+    /// Creates a new `BVec2` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        Self::new(v, v)
+    }
+
+    /// Returns a bitmask with the lowest two bits set from the elements of
+    /// the `BVec2`.
+    ///
+    /// A true element results in a `1` bit and a false element in a `0` bit.
+    /// Element `x` goes into the first lowest bit, element `y` into the
+    /// second, etc.
+    #[inline]
+    pub fn bitmask(self) -> u32 {
+        (self.0 & 0x1) | (self.1 & 0x1) << 1
+    }
+
+    /// Returns true if any of the elements are true, false otherwise.
+    ///
+    /// In other words: `x || y`.
+    #[inline]
+    pub fn any(self) -> bool {
+        (self.0 != 0) || (self.1 != 0)
+    }
+
+    /// Returns true if all the elements are true, false otherwise.
+    ///
+    /// In other words: `x && y`.
+    #[inline]
+    pub fn all(self) -> bool {
+        (self.0 != 0) && (self.1 != 0)
+    }
+
+    /// Creates a new `Vec2` from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on the `BVec2`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(self, if_true: Vec2, if_false: Vec2) -> Vec2 {
+        Vec2::select(self, if_true, if_false)
+    }
+}
+
+impl PartialEq for BVec2 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.bitmask().eq(&other.bitmask())
+    }
+}
+
+impl Eq for BVec2 {}
+
+impl hash::Hash for BVec2 {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
+impl BitAnd for BVec2 {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0, self.1 & other.1)
+    }
+}
+
+impl BitAndAssign for BVec2 {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = *self & other
+    }
+}
+
+impl BitOr for BVec2 {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0, self.1 | other.1)
+    }
+}
+
+impl BitOrAssign for BVec2 {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = *self | other
+    }
+}
+
+impl BitXor for BVec2 {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        Self(self.0 ^ other.0, self.1 ^ other.1)
+    }
+}
+
+impl BitXorAssign for BVec2 {
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = *self ^ other
+    }
+}
+
+impl Not for BVec2 {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self(!self.0, !self.1)
+    }
+}
+
+impl fmt::Debug for BVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let arr: [bool; 2] = (*self).into();
+        f.debug_tuple("BVec2")
+            .field(&arr[0])
+            .field(&arr[1])
+            .finish()
+    }
+}
+
+impl fmt::Display for BVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let arr: [bool; 2] = (*self).into();
+        write!(f, "[{}, {}]", arr[0], arr[1])
+    }
+}
+
+impl From<[bool; 2]> for BVec2 {
+    #[inline]
+    fn from(a: [bool; 2]) -> Self {
+        Self::new(a[0], a[1])
+    }
+}
+
+impl From<BVec2> for [bool; 2] {
+    #[inline]
+    fn from(m: BVec2) -> Self {
+        let bitmask = m.bitmask();
+        [(bitmask & 1) != 0, (bitmask & 2) != 0]
+    }
+}
@@ -0,0 +1,173 @@
+use crate::f32::Vec3;
+
+use std::{fmt, hash, ops::*};
+
+/// A 3-dimensional boolean vector mask.
+///
+/// This type is typically created by comparison methods on 3D vectors.  It is
+/// essentially a vector of three boolean values.
+#[derive(Clone, Copy, Default)]
+// if compiling with simd enabled assume alignment needs to match the simd type
+#[cfg_attr(not(feature = "scalar-math"), repr(align(16)))]
+#[repr(C)]
+pub struct BVec3(pub(crate) u32, pub(crate) u32, pub(crate) u32);
+
+#[inline]
+pub fn bvec3(x: bool, y: bool, z: bool) -> BVec3 {
+    BVec3::new(x, y, z)
+}
+
+impl BVec3 {
+    /// Creates a new `BVec3`.
+    #[inline]
+    pub fn new(x: bool, y: bool, z: bool) -> Self {
+        const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
+        Self(MASK[x as usize], MASK[y as usize], MASK[z as usize])
+    }
+
+    /// Creates a new `BVec3` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        Self::new(v, v, v)
+    }
+
+    /// Returns a bitmask with the lowest three bits set from the elements of
+    /// the `BVec3`.
+    ///
+    /// A true element results in a `1` bit and a false element in a `0` bit.
+    /// Element `x` goes into the first lowest bit, element `y` into the
+    /// second, etc.
+    #[inline]
+    pub fn bitmask(self) -> u32 {
+        (self.0 & 0x1) | (self.1 & 0x1) << 1 | (self.2 & 0x1) << 2
+    }
+
+    /// Returns true if any of the elements are true, false otherwise.
+    ///
+    /// In other words: `x || y || z`.
+    #[inline]
+    pub fn any(self) -> bool {
+        (self.0 != 0) || (self.1 != 0) || (self.2 != 0)
+    }
+
+    /// Returns true if all the elements are true, false otherwise.
+    ///
+    /// In other words: `x && y && z`.
+    #[inline]
+    pub fn all(self) -> bool {
+        (self.0 != 0) && (self.1 != 0) && (self.2 != 0)
+    }
+
+    /// Creates a new `Vec3` from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on the `BVec3`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(self, if_true: Vec3, if_false: Vec3) -> Vec3 {
+        Vec3::select(self, if_true, if_false)
+    }
+}
+
+impl BitAnd for BVec3 {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0, self.1 & other.1, self.2 & other.2)
+    }
+}
+
+impl BitOr for BVec3 {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0, self.1 | other.1, self.2 | other.2)
+    }
+}
+
+impl BitXor for BVec3 {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        Self(self.0 ^ other.0, self.1 ^ other.1, self.2 ^ other.2)
+    }
+}
+
+impl Not for BVec3 {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self(!self.0, !self.1, !self.2)
+    }
+}
+
+impl PartialEq for BVec3 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.bitmask().eq(&other.bitmask())
+    }
+}
+
+impl Eq for BVec3 {}
+
+impl hash::Hash for BVec3 {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
+impl BitAndAssign for BVec3 {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = *self & other
+    }
+}
+
+impl BitOrAssign for BVec3 {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = *self | other
+    }
+}
+
+impl BitXorAssign for BVec3 {
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = *self ^ other
+    }
+}
+
+impl fmt::Debug for BVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let arr: [bool; 3] = (*self).into();
+        f.debug_tuple("BVec3")
+            .field(&arr[0])
+            .field(&arr[1])
+            .field(&arr[2])
+            .finish()
+    }
+}
+
+impl fmt::Display for BVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let arr: [bool; 3] = (*self).into();
+        write!(f, "[{}, {}, {}]", arr[0], arr[1], arr[2])
+    }
+}
+
+impl From<[bool; 3]> for BVec3 {
+    #[inline]
+    fn from(a: [bool; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+}
+
+impl From<BVec3> for [bool; 3] {
+    #[inline]
+    fn from(m: BVec3) -> Self {
+        let bitmask = m.bitmask();
+        [(bitmask & 1) != 0, (bitmask & 2) != 0, (bitmask & 4) != 0]
+    }
+}
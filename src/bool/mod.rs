@@ -0,0 +1,47 @@
+mod bvec2;
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+mod bvec3_sse2;
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "scalar-math",
+    feature = "core-simd"
+))]
+mod bvec3_u32;
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+mod bvec4_sse2;
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "scalar-math",
+    feature = "core-simd"
+))]
+mod bvec4_u32;
+
+pub use bvec2::*;
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+pub use bvec3_sse2::*;
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "scalar-math",
+    feature = "core-simd"
+))]
+pub use bvec3_u32::*;
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+pub use bvec4_sse2::*;
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "scalar-math",
+    feature = "core-simd"
+))]
+pub use bvec4_u32::*;
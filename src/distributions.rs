@@ -0,0 +1,11 @@
+//! Random distributions of `glam` types that are not covered by `Standard`.
+//!
+//! Sampling a `Quat` or `DQuat` with `Standard` uses `UnitQuaternion`.
+
+/// Samples unit quaternions uniformly distributed over all rotations.
+///
+/// Uses the subgroup algorithm from "Uniform Random Rotations" by Ken Shoemake
+/// in Graphics Gems III. Sampling each element independently or sampling Euler
+/// angles would instead cluster the rotations around some axes.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitQuaternion;
@@ -0,0 +1,70 @@
+/// The order in which the three rotations of a set of Euler angles are applied.
+///
+/// Each variant names the axes in the order their angles are passed to
+/// methods such as `Quat::from_euler`.
+///
+/// The plain variants are intrinsic: each rotation is about an axis of the
+/// frame produced by the previous rotations, so `XYZ` rotates about `x`, then
+/// about the rotated `y` and finally about the twice rotated `z`. The `Ex`
+/// variants are extrinsic: every rotation is about an axis of the fixed
+/// original frame. An extrinsic order is equivalent to the reversed intrinsic
+/// order with the angles reversed, for example `XYZEx` with angles `(a, b, c)`
+/// is the same rotation as `ZYX` with angles `(c, b, a)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EulerRot {
+    /// Intrinsic rotation about `x`, then `y`, then `z`.
+    XYZ,
+    /// Intrinsic rotation about `x`, then `z`, then `y`.
+    XZY,
+    /// Intrinsic rotation about `y`, then `x`, then `z`.
+    YXZ,
+    /// Intrinsic rotation about `y`, then `z`, then `x`.
+    YZX,
+    /// Intrinsic rotation about `z`, then `x`, then `y`.
+    ZXY,
+    /// Intrinsic rotation about `z`, then `y`, then `x`.
+    ZYX,
+    /// Extrinsic rotation about `x`, then `y`, then `z`.
+    XYZEx,
+    /// Extrinsic rotation about `x`, then `z`, then `y`.
+    XZYEx,
+    /// Extrinsic rotation about `y`, then `x`, then `z`.
+    YXZEx,
+    /// Extrinsic rotation about `y`, then `z`, then `x`.
+    YZXEx,
+    /// Extrinsic rotation about `z`, then `x`, then `y`.
+    ZXYEx,
+    /// Extrinsic rotation about `z`, then `y`, then `x`.
+    ZYXEx,
+}
+
+impl Default for EulerRot {
+    /// Returns `YXZ`, the yaw, pitch and roll order used by `from_rotation_ypr`.
+    #[inline]
+    fn default() -> Self {
+        Self::YXZ
+    }
+}
+
+impl EulerRot {
+    /// Returns the indices of the axes in the order the angles are given.
+    #[inline]
+    pub(crate) fn axes(self) -> (usize, usize, usize) {
+        use EulerRot::*;
+        match self {
+            XYZ | XYZEx => (0, 1, 2),
+            XZY | XZYEx => (0, 2, 1),
+            YXZ | YXZEx => (1, 0, 2),
+            YZX | YZXEx => (1, 2, 0),
+            ZXY | ZXYEx => (2, 0, 1),
+            ZYX | ZYXEx => (2, 1, 0),
+        }
+    }
+
+    /// Returns `true` if the rotations are about the axes of the fixed frame.
+    #[inline]
+    pub fn is_extrinsic(self) -> bool {
+        use EulerRot::*;
+        matches!(self, XYZEx | XZYEx | YXZEx | YZXEx | ZXYEx | ZYXEx)
+    }
+}
@@ -0,0 +1,107 @@
+use super::funcs::{f16_bits_to_f32, f32_to_f16_bits};
+use crate::{f32::Vec2, u16::U16Vec2};
+
+/// A 2-dimensional vector of half precision (IEEE 754 binary16) floats.
+///
+/// This is a storage type intended for encoding and decoding GPU vertex and
+/// texture data. Elements are stored as raw `u16` bits, so equality and
+/// hashing are bitwise. Convert to `Vec2` to perform math.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(C)]
+pub struct F16Vec2(u16, u16);
+
+#[inline]
+pub fn f16vec2(x: f32, y: f32) -> F16Vec2 {
+    F16Vec2::new(x, y)
+}
+
+impl F16Vec2 {
+    /// Creates a new `F16Vec2`, rounding each element to the nearest half
+    /// precision value.
+    #[inline]
+    pub fn new(x: f32, y: f32) -> Self {
+        Self(f32_to_f16_bits(x), f32_to_f16_bits(y))
+    }
+
+    /// Creates a new `F16Vec2` from raw half precision bits.
+    #[inline]
+    pub fn from_bits(bits: [u16; 2]) -> Self {
+        Self(bits[0], bits[1])
+    }
+
+    /// Returns the raw half precision bits of each element.
+    #[inline]
+    pub fn to_bits(self) -> [u16; 2] {
+        [self.0, self.1]
+    }
+
+    /// Returns element `x` converted to `f32`.
+    #[inline]
+    pub fn x(self) -> f32 {
+        f16_bits_to_f32(self.0)
+    }
+
+    /// Returns element `y` converted to `f32`.
+    #[inline]
+    pub fn y(self) -> f32 {
+        f16_bits_to_f32(self.1)
+    }
+
+    /// Converts a `Vec2` to half precision, rounding to nearest even.
+    ///
+    /// Values outside of the half precision range become infinity.
+    #[inline]
+    pub fn from_vec2(v: Vec2) -> Self {
+        Self(f32_to_f16_bits(v.x()), f32_to_f16_bits(v.y()))
+    }
+
+    /// Converts `self` to a `Vec2`. This conversion is exact.
+    #[inline]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(f16_bits_to_f32(self.0), f16_bits_to_f32(self.1))
+    }
+}
+
+impl From<Vec2> for F16Vec2 {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        Self::from_vec2(v)
+    }
+}
+
+impl From<F16Vec2> for Vec2 {
+    #[inline]
+    fn from(v: F16Vec2) -> Self {
+        v.to_vec2()
+    }
+}
+
+impl From<U16Vec2> for F16Vec2 {
+    /// Reinterprets the elements of a `U16Vec2` as half precision bits.
+    #[inline]
+    fn from(v: U16Vec2) -> Self {
+        Self(v.x(), v.y())
+    }
+}
+
+impl From<F16Vec2> for U16Vec2 {
+    /// Returns the half precision bits of each element.
+    #[inline]
+    fn from(v: F16Vec2) -> Self {
+        Self::new(v.0, v.1)
+    }
+}
+
+impl From<[u16; 2]> for F16Vec2 {
+    #[inline]
+    fn from(a: [u16; 2]) -> Self {
+        Self::from_bits(a)
+    }
+}
+
+impl From<F16Vec2> for [u16; 2] {
+    #[inline]
+    fn from(v: F16Vec2) -> Self {
+        v.to_bits()
+    }
+}
@@ -0,0 +1,121 @@
+use super::funcs::{f16_bits_to_f32, f32_to_f16_bits};
+use crate::{f32::Vec3, u16::U16Vec3};
+
+/// A 3-dimensional vector of half precision (IEEE 754 binary16) floats.
+///
+/// This is a storage type intended for encoding and decoding GPU vertex and
+/// texture data. Elements are stored as raw `u16` bits, so equality and
+/// hashing are bitwise. Convert to `Vec3` to perform math.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(C)]
+pub struct F16Vec3(u16, u16, u16);
+
+#[inline]
+pub fn f16vec3(x: f32, y: f32, z: f32) -> F16Vec3 {
+    F16Vec3::new(x, y, z)
+}
+
+impl F16Vec3 {
+    /// Creates a new `F16Vec3`, rounding each element to the nearest half
+    /// precision value.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(f32_to_f16_bits(x), f32_to_f16_bits(y), f32_to_f16_bits(z))
+    }
+
+    /// Creates a new `F16Vec3` from raw half precision bits.
+    #[inline]
+    pub fn from_bits(bits: [u16; 3]) -> Self {
+        Self(bits[0], bits[1], bits[2])
+    }
+
+    /// Returns the raw half precision bits of each element.
+    #[inline]
+    pub fn to_bits(self) -> [u16; 3] {
+        [self.0, self.1, self.2]
+    }
+
+    /// Returns element `x` converted to `f32`.
+    #[inline]
+    pub fn x(self) -> f32 {
+        f16_bits_to_f32(self.0)
+    }
+
+    /// Returns element `y` converted to `f32`.
+    #[inline]
+    pub fn y(self) -> f32 {
+        f16_bits_to_f32(self.1)
+    }
+
+    /// Returns element `z` converted to `f32`.
+    #[inline]
+    pub fn z(self) -> f32 {
+        f16_bits_to_f32(self.2)
+    }
+
+    /// Converts a `Vec3` to half precision, rounding to nearest even.
+    ///
+    /// Values outside of the half precision range become infinity.
+    #[inline]
+    pub fn from_vec3(v: Vec3) -> Self {
+        Self(
+            f32_to_f16_bits(v.x()),
+            f32_to_f16_bits(v.y()),
+            f32_to_f16_bits(v.z()),
+        )
+    }
+
+    /// Converts `self` to a `Vec3`. This conversion is exact.
+    #[inline]
+    pub fn to_vec3(self) -> Vec3 {
+        Vec3::new(
+            f16_bits_to_f32(self.0),
+            f16_bits_to_f32(self.1),
+            f16_bits_to_f32(self.2),
+        )
+    }
+}
+
+impl From<Vec3> for F16Vec3 {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        Self::from_vec3(v)
+    }
+}
+
+impl From<F16Vec3> for Vec3 {
+    #[inline]
+    fn from(v: F16Vec3) -> Self {
+        v.to_vec3()
+    }
+}
+
+impl From<U16Vec3> for F16Vec3 {
+    /// Reinterprets the elements of a `U16Vec3` as half precision bits.
+    #[inline]
+    fn from(v: U16Vec3) -> Self {
+        Self(v.x(), v.y(), v.z())
+    }
+}
+
+impl From<F16Vec3> for U16Vec3 {
+    /// Returns the half precision bits of each element.
+    #[inline]
+    fn from(v: F16Vec3) -> Self {
+        Self::new(v.0, v.1, v.2)
+    }
+}
+
+impl From<[u16; 3]> for F16Vec3 {
+    #[inline]
+    fn from(a: [u16; 3]) -> Self {
+        Self::from_bits(a)
+    }
+}
+
+impl From<F16Vec3> for [u16; 3] {
+    #[inline]
+    fn from(v: F16Vec3) -> Self {
+        v.to_bits()
+    }
+}
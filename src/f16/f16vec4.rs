@@ -0,0 +1,134 @@
+use super::funcs::{f16_bits_to_f32, f32_to_f16_bits};
+use crate::{f32::Vec4, u16::U16Vec4};
+
+/// A 4-dimensional vector of half precision (IEEE 754 binary16) floats.
+///
+/// This is a storage type intended for encoding and decoding GPU vertex and
+/// texture data. Elements are stored as raw `u16` bits, so equality and
+/// hashing are bitwise. Convert to `Vec4` to perform math.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(C)]
+pub struct F16Vec4(u16, u16, u16, u16);
+
+#[inline]
+pub fn f16vec4(x: f32, y: f32, z: f32, w: f32) -> F16Vec4 {
+    F16Vec4::new(x, y, z, w)
+}
+
+impl F16Vec4 {
+    /// Creates a new `F16Vec4`, rounding each element to the nearest half
+    /// precision value.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self(
+            f32_to_f16_bits(x),
+            f32_to_f16_bits(y),
+            f32_to_f16_bits(z),
+            f32_to_f16_bits(w),
+        )
+    }
+
+    /// Creates a new `F16Vec4` from raw half precision bits.
+    #[inline]
+    pub fn from_bits(bits: [u16; 4]) -> Self {
+        Self(bits[0], bits[1], bits[2], bits[3])
+    }
+
+    /// Returns the raw half precision bits of each element.
+    #[inline]
+    pub fn to_bits(self) -> [u16; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+
+    /// Returns element `x` converted to `f32`.
+    #[inline]
+    pub fn x(self) -> f32 {
+        f16_bits_to_f32(self.0)
+    }
+
+    /// Returns element `y` converted to `f32`.
+    #[inline]
+    pub fn y(self) -> f32 {
+        f16_bits_to_f32(self.1)
+    }
+
+    /// Returns element `z` converted to `f32`.
+    #[inline]
+    pub fn z(self) -> f32 {
+        f16_bits_to_f32(self.2)
+    }
+
+    /// Returns element `w` converted to `f32`.
+    #[inline]
+    pub fn w(self) -> f32 {
+        f16_bits_to_f32(self.3)
+    }
+
+    /// Converts a `Vec4` to half precision, rounding to nearest even.
+    ///
+    /// Values outside of the half precision range become infinity.
+    #[inline]
+    pub fn from_vec4(v: Vec4) -> Self {
+        Self(
+            f32_to_f16_bits(v.x()),
+            f32_to_f16_bits(v.y()),
+            f32_to_f16_bits(v.z()),
+            f32_to_f16_bits(v.w()),
+        )
+    }
+
+    /// Converts `self` to a `Vec4`. This conversion is exact.
+    #[inline]
+    pub fn to_vec4(self) -> Vec4 {
+        Vec4::new(
+            f16_bits_to_f32(self.0),
+            f16_bits_to_f32(self.1),
+            f16_bits_to_f32(self.2),
+            f16_bits_to_f32(self.3),
+        )
+    }
+}
+
+impl From<Vec4> for F16Vec4 {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        Self::from_vec4(v)
+    }
+}
+
+impl From<F16Vec4> for Vec4 {
+    #[inline]
+    fn from(v: F16Vec4) -> Self {
+        v.to_vec4()
+    }
+}
+
+impl From<U16Vec4> for F16Vec4 {
+    /// Reinterprets the elements of a `U16Vec4` as half precision bits.
+    #[inline]
+    fn from(v: U16Vec4) -> Self {
+        Self(v.x(), v.y(), v.z(), v.w())
+    }
+}
+
+impl From<F16Vec4> for U16Vec4 {
+    /// Returns the half precision bits of each element.
+    #[inline]
+    fn from(v: F16Vec4) -> Self {
+        Self::new(v.0, v.1, v.2, v.3)
+    }
+}
+
+impl From<[u16; 4]> for F16Vec4 {
+    #[inline]
+    fn from(a: [u16; 4]) -> Self {
+        Self::from_bits(a)
+    }
+}
+
+impl From<F16Vec4> for [u16; 4] {
+    #[inline]
+    fn from(v: F16Vec4) -> Self {
+        v.to_bits()
+    }
+}
@@ -0,0 +1,118 @@
+/// Converts an `f32` to IEEE 754 binary16 bits, rounding to nearest even.
+///
+/// Values too large for half precision become infinity and values too small
+/// become signed zero. NaN payloads are truncated but remain NaN.
+pub(crate) fn f32_to_f16_bits(value: f32) -> u16 {
+    let x = value.to_bits();
+    let sign = ((x >> 16) & 0x8000) as u16;
+    let exp = ((x >> 23) & 0xff) as i32;
+    let man = x & 0x007f_ffff;
+
+    if exp == 0xff {
+        // infinity or NaN, make sure NaN stays NaN by setting the quiet bit
+        let nan = if man != 0 {
+            0x0200 | (man >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan;
+    }
+
+    let half_exp = exp - 127 + 15;
+    if half_exp >= 0x1f {
+        // overflow
+        return sign | 0x7c00;
+    }
+
+    if half_exp <= 0 {
+        // subnormal or underflow
+        let shift = (14 - half_exp) as u32;
+        if shift > 24 {
+            return sign;
+        }
+        let man = man | 0x0080_0000;
+        let mut half_man = man >> shift;
+        let round_bit = 1 << (shift - 1);
+        if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+            half_man += 1;
+        }
+        return sign | half_man as u16;
+    }
+
+    // a carry out of the mantissa correctly increments the exponent
+    let mut result = sign | ((half_exp as u16) << 10) | (man >> 13) as u16;
+    if man & 0x1000 != 0 && man & (3 * 0x1000 - 1) != 0 {
+        result += 1;
+    }
+    result
+}
+
+/// Converts IEEE 754 binary16 bits to an `f32`. This conversion is exact.
+pub(crate) fn f16_bits_to_f32(bits: u16) -> f32 {
+    let bits = u32::from(bits);
+    let sign = (bits & 0x8000) << 16;
+    let exp = (bits >> 10) & 0x1f;
+    let man = bits & 0x03ff;
+
+    if exp == 0 {
+        if man == 0 {
+            return f32::from_bits(sign);
+        }
+        // subnormal, normalize the mantissa
+        let e = man.leading_zeros() - 22;
+        return f32::from_bits(sign | ((127 - 15 - e) << 23) | ((man << (14 + e)) & 0x007f_ffff));
+    }
+
+    if exp == 0x1f {
+        return f32::from_bits(sign | 0x7f80_0000 | (man << 13));
+    }
+
+    f32::from_bits(sign | ((exp + 112) << 23) | (man << 13))
+}
+
+#[test]
+fn test_f32_to_f16_bits() {
+    assert_eq!(0x0000, f32_to_f16_bits(0.0));
+    assert_eq!(0x8000, f32_to_f16_bits(-0.0));
+    assert_eq!(0x3c00, f32_to_f16_bits(1.0));
+    assert_eq!(0xc000, f32_to_f16_bits(-2.0));
+    assert_eq!(0x3555, f32_to_f16_bits(1.0 / 3.0));
+    assert_eq!(0x7bff, f32_to_f16_bits(65504.0));
+    assert_eq!(0x7c00, f32_to_f16_bits(65520.0));
+    assert_eq!(0x7c00, f32_to_f16_bits(1.0e10));
+    assert_eq!(0xfc00, f32_to_f16_bits(f32::NEG_INFINITY));
+    assert_eq!(0x7c00, f32_to_f16_bits(f32::INFINITY));
+    assert!(f32_to_f16_bits(f32::NAN) & 0x7fff > 0x7c00);
+    // smallest subnormal and underflow
+    assert_eq!(0x0001, f32_to_f16_bits(5.960_464_5e-8));
+    assert_eq!(0x0000, f32_to_f16_bits(2.0e-8));
+    assert_eq!(0x8000, f32_to_f16_bits(-1.0e-30));
+    // largest subnormal and smallest normal
+    assert_eq!(0x03ff, f32_to_f16_bits(6.097_555e-5));
+    assert_eq!(0x0400, f32_to_f16_bits(6.103_515_6e-5));
+    // ties round to even
+    assert_eq!(0x3c00, f32_to_f16_bits(1.0 + 1.0 / 2048.0));
+    assert_eq!(0x3c02, f32_to_f16_bits(1.0 + 3.0 / 2048.0));
+}
+
+#[test]
+fn test_f16_bits_to_f32() {
+    assert_eq!(0.0, f16_bits_to_f32(0x0000));
+    assert!(f16_bits_to_f32(0x8000).is_sign_negative());
+    assert_eq!(1.0, f16_bits_to_f32(0x3c00));
+    assert_eq!(-2.0, f16_bits_to_f32(0xc000));
+    assert_eq!(65504.0, f16_bits_to_f32(0x7bff));
+    assert_eq!(5.960_464_5e-8, f16_bits_to_f32(0x0001));
+    assert_eq!(6.097_555e-5, f16_bits_to_f32(0x03ff));
+    assert_eq!(f32::INFINITY, f16_bits_to_f32(0x7c00));
+    assert_eq!(f32::NEG_INFINITY, f16_bits_to_f32(0xfc00));
+    assert!(f16_bits_to_f32(0x7e00).is_nan());
+
+    // every non-NaN half round trips through f32
+    for bits in 0..=0xffff_u16 {
+        let f = f16_bits_to_f32(bits);
+        if !f.is_nan() {
+            assert_eq!(bits, f32_to_f16_bits(f));
+        }
+    }
+}
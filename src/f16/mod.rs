@@ -0,0 +1,8 @@
+mod f16vec2;
+mod f16vec3;
+mod f16vec4;
+mod funcs;
+
+pub use f16vec2::*;
+pub use f16vec3::*;
+pub use f16vec4::*;
@@ -0,0 +1,55 @@
+// Runtime CPU feature dispatch for the `runtime-dispatch` feature.
+//
+// When the crate is compiled for baseline x86 or x86_64 the SSE2 code paths
+// cannot use FMA or the VEX encoded AVX instructions. With `runtime-dispatch`
+// enabled the hottest `Mat4` and `Quat` operations check once which features
+// the CPU supports and call a copy of the same code compiled with those
+// features enabled. SSE4.1 is not checked because none of these operations
+// benefit from it.
+use super::x86_utils::FmaMulAdd;
+use super::{Mat4, Quat};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const UNKNOWN: u8 = 0;
+const BASELINE: u8 = 1;
+const AVX_FMA: u8 = 2;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+#[cold]
+fn detect() -> u8 {
+    let level = if is_x86_feature_detected!("avx") && is_x86_feature_detected!("fma") {
+        AVX_FMA
+    } else {
+        BASELINE
+    };
+    LEVEL.store(level, Ordering::Relaxed);
+    level
+}
+
+/// Returns true if the CPU supports both AVX and FMA. The result of the check
+/// is cached after the first call.
+#[inline]
+pub(crate) fn has_avx_fma() -> bool {
+    let level = match LEVEL.load(Ordering::Relaxed) {
+        UNKNOWN => detect(),
+        level => level,
+    };
+    level == AVX_FMA
+}
+
+#[target_feature(enable = "avx,fma")]
+pub(crate) unsafe fn mat4_mul_mat4_avx_fma(lhs: &Mat4, rhs: &Mat4) -> Mat4 {
+    lhs.mul_mat4_m128::<FmaMulAdd>(rhs)
+}
+
+#[cfg(not(target_feature = "avx"))]
+#[target_feature(enable = "avx")]
+pub(crate) unsafe fn mat4_inverse_avx(m: &Mat4) -> Mat4 {
+    m.inverse_sse2()
+}
+
+#[target_feature(enable = "avx,fma")]
+pub(crate) unsafe fn quat_mul_quat_avx_fma(lhs: Quat, rhs: Quat) -> Quat {
+    lhs.mul_quat_m128::<FmaMulAdd>(rhs)
+}
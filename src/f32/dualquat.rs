@@ -0,0 +1,287 @@
+use super::{scalar_sin_cos, Quat, Vec3};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg};
+
+/// A dual quaternion representing a rigid transform, a rotation followed by a
+/// translation.
+///
+/// The `real` part is the rotation and the `dual` part encodes the translation
+/// as `0.5 * translation * real`. Like `Quat` this is intended to be of unit
+/// length, meaning `real` is normalized and orthogonal to `dual`.
+///
+/// Blending dual quaternions does not suffer from the volume loss of blending
+/// matrices, which makes them well suited to skinning. For example a vertex
+/// influenced by two joints can be blended with
+/// `(joint0 * weight0 + joint1 * weight1).normalize()`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct DualQuat {
+    pub real: Quat,
+    pub dual: Quat,
+}
+
+/// Multiplies two quaternions without requiring them to be normalized.
+#[inline]
+fn quat_mul(lhs: Quat, rhs: Quat) -> Quat {
+    let (x0, y0, z0, w0) = lhs.into();
+    let (x1, y1, z1, w1) = rhs.into();
+    Quat::new(
+        w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+        w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+        w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+        w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+    )
+}
+
+impl DualQuat {
+    /// Creates a new dual quaternion from its real and dual parts.
+    #[inline]
+    pub fn new(real: Quat, dual: Quat) -> Self {
+        Self { real, dual }
+    }
+
+    /// Creates the identity transform.
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            real: Quat::identity(),
+            dual: Quat::new(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Creates a dual quaternion from a rotation and a translation which is
+    /// applied after the rotation.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let t = Quat(translation.extend(0.0) * 0.5);
+        Self {
+            real: rotation,
+            dual: quat_mul(t, rotation),
+        }
+    }
+
+    /// Creates a dual quaternion from a rotation.
+    #[inline]
+    pub fn from_rotation(rotation: Quat) -> Self {
+        Self::from_rotation_translation(rotation, Vec3::zero())
+    }
+
+    /// Creates a dual quaternion from a translation.
+    #[inline]
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self::from_rotation_translation(Quat::identity(), translation)
+    }
+
+    /// Returns the rotation and the translation of `self`.
+    #[inline]
+    pub fn to_rotation_translation(self) -> (Quat, Vec3) {
+        (self.real, self.translation())
+    }
+
+    /// Returns the translation of `self`.
+    #[inline]
+    pub fn translation(self) -> Vec3 {
+        glam_assert!(self.is_normalized());
+        // t = 2 * dual * conjugate(real)
+        let t = quat_mul(self.dual, self.real.conjugate());
+        t.0.truncate() * 2.0
+    }
+
+    /// Returns the conjugate of `self`, which is the inverse of a unit dual
+    /// quaternion.
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Returns `self` normalized to a unit dual quaternion, so that it is a rigid
+    /// transform again.
+    ///
+    /// For valid results, the real part of `self` must not be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let inv_length = 1.0 / self.real.length();
+        let real = self.real.0 * inv_length;
+        let dual = self.dual.0 * inv_length;
+        // remove the part of the dual which is not orthogonal to the real part
+        let dual = dual - real * real.dot(dual);
+        Self {
+            real: Quat(real),
+            dual: Quat(dual),
+        }
+    }
+
+    /// Returns whether `self` is of unit length within a small tolerance, that is
+    /// whether the real part is normalized and orthogonal to the dual part.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        const THRESHOLD: f32 = 1e-4;
+        self.real.is_normalized() && self.real.dot(self.dual).abs() <= THRESHOLD
+    }
+
+    /// Multiplies two dual quaternions. The result applies `other` first and then
+    /// `self`.
+    #[inline]
+    pub fn mul_dual_quat(self, other: Self) -> Self {
+        let real = quat_mul(self.real, other.real);
+        let dual = quat_mul(self.real, other.dual).0 + quat_mul(self.dual, other.real).0;
+        Self {
+            real,
+            dual: Quat(dual),
+        }
+    }
+
+    /// Transforms the given 3D point, applying the rotation and then the
+    /// translation.
+    #[inline]
+    pub fn transform_point3(self, other: Vec3) -> Vec3 {
+        self.real.mul_vec3(other) + self.translation()
+    }
+
+    /// Transforms the given 3D vector, applying only the rotation.
+    #[inline]
+    pub fn transform_vector3(self, other: Vec3) -> Vec3 {
+        self.real.mul_vec3(other)
+    }
+
+    /// Performs a screw linear interpolation between `self` and `end` based on
+    /// the value `t`, taking the shortest path.
+    ///
+    /// The result moves along the screw motion from `self` to `end` with constant
+    /// speed, rotating and translating at the same time. When `t` is `0.0` the
+    /// result is `self` and when `t` is `1.0` the result is the same transform as
+    /// `end`.
+    #[inline]
+    pub fn sclerp(self, end: Self, t: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        // -end is the same transform as end
+        let end = if self.real.dot(end.real) < 0.0 {
+            -end
+        } else {
+            end
+        };
+        let diff = self.conjugate().mul_dual_quat(end);
+        self.mul_dual_quat(diff.powf(t))
+    }
+
+    /// Raises a unit dual quaternion to the power `t` by scaling the angle and
+    /// the distance of its screw motion.
+    #[inline]
+    fn powf(self, t: f32) -> Self {
+        const EPSILON: f32 = 1e-6;
+        let real = self.real.0;
+        let dual = self.dual.0;
+        let v = real.truncate();
+        let v_length = v.length();
+        if v_length < EPSILON {
+            // a pure translation
+            return Self {
+                real: self.real,
+                dual: Quat(dual * t),
+            };
+        }
+
+        // the screw axis direction, moment, angle and distance
+        let axis = v / v_length;
+        let angle = 2.0 * v_length.atan2(real.w());
+        let distance = -2.0 * dual.w() / v_length;
+        let moment = (dual.truncate() - axis * (distance * 0.5 * real.w())) / v_length;
+
+        let angle = angle * t;
+        let distance = distance * t;
+        let (sin, cos) = scalar_sin_cos(angle * 0.5);
+        let half_distance = distance * 0.5;
+        Self {
+            real: Quat((axis * sin).extend(cos)),
+            dual: Quat((moment * sin + axis * (half_distance * cos)).extend(-half_distance * sin)),
+        }
+    }
+}
+
+impl Default for DualQuat {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Add<DualQuat> for DualQuat {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            real: Quat(self.real.0 + other.real.0),
+            dual: Quat(self.dual.0 + other.dual.0),
+        }
+    }
+}
+
+impl AddAssign<DualQuat> for DualQuat {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Mul<f32> for DualQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        Self {
+            real: Quat(self.real.0 * other),
+            dual: Quat(self.dual.0 * other),
+        }
+    }
+}
+
+impl MulAssign<f32> for DualQuat {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<DualQuat> for DualQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_dual_quat(other)
+    }
+}
+
+impl MulAssign<DualQuat> for DualQuat {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_dual_quat(other);
+    }
+}
+
+impl Neg for DualQuat {
+    type Output = Self;
+    /// Negates both parts of `self`, which represents the same rigid transform.
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            real: -self.real,
+            dual: -self.dual,
+        }
+    }
+}
+
+impl From<(Quat, Vec3)> for DualQuat {
+    #[inline]
+    fn from((rotation, translation): (Quat, Vec3)) -> Self {
+        Self::from_rotation_translation(rotation, translation)
+    }
+}
+
+impl From<DualQuat> for (Quat, Vec3) {
+    #[inline]
+    fn from(dq: DualQuat) -> Self {
+        dq.to_rotation_translation()
+    }
+}
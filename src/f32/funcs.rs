@@ -177,7 +177,7 @@ pub fn scalar_acos(value: f32) -> f32 {
 #[cfg(test)]
 macro_rules! assert_approx_eq {
     ($a:expr, $b:expr) => {{
-        assert_approx_eq!($a, $b, core::f32::EPSILON);
+        assert_approx_eq!($a, $b, f32::EPSILON);
     }};
     ($a:expr, $b:expr, $eps:expr) => {{
         let (a, b) = (&$a, &$b);
@@ -197,7 +197,7 @@ macro_rules! assert_approx_eq {
 #[cfg(test)]
 macro_rules! assert_relative_eq {
     ($a:expr, $b:expr) => {{
-        assert_relative_eq!($a, $b, core::f32::EPSILON);
+        assert_relative_eq!($a, $b, f32::EPSILON);
     }};
     ($a:expr, $b:expr, $eps:expr) => {{
         let (a, b) = (&$a, &$b);
@@ -230,13 +230,10 @@ fn test_scalar_acos() {
     const PTVE_ONE: u32 = 0x3f_80_00_00; // 1.0_f32.to_bits();
     const NGVE_ONE: u32 = SIGN | PTVE_ONE;
     const STEP_SIZE: usize = (PTVE_ONE / MAX_TESTS) as usize;
-    for f in (SIGN..=NGVE_ONE)
-        .step_by(STEP_SIZE)
-        .map(|i| f32::from_bits(i))
-    {
+    for f in (SIGN..=NGVE_ONE).step_by(STEP_SIZE).map(f32::from_bits) {
         test_scalar_acos_angle(f);
     }
-    for f in (0..=PTVE_ONE).step_by(STEP_SIZE).map(|i| f32::from_bits(i)) {
+    for f in (0..=PTVE_ONE).step_by(STEP_SIZE).map(f32::from_bits) {
         test_scalar_acos_angle(f);
     }
 
@@ -261,33 +258,30 @@ fn test_scalar_sin_cos() {
     let ptve_pi = std::f32::consts::PI.to_bits();
     let ngve_pi = SIGN | ptve_pi;
     let step_pi = (ptve_pi / MAX_TESTS) as usize;
-    for f in (SIGN..=ngve_pi).step_by(step_pi).map(|i| f32::from_bits(i)) {
+    for f in (SIGN..=ngve_pi).step_by(step_pi).map(f32::from_bits) {
         test_scalar_sin_cos_angle(f);
     }
-    for f in (0..=ptve_pi).step_by(step_pi).map(|i| f32::from_bits(i)) {
+    for f in (0..=ptve_pi).step_by(step_pi).map(f32::from_bits) {
         test_scalar_sin_cos_angle(f);
     }
 
     // test 1024 floats between -INF and +INF exclusive
-    let ptve_inf = std::f32::INFINITY.to_bits();
-    let ngve_inf = std::f32::NEG_INFINITY.to_bits();
+    let ptve_inf = f32::INFINITY.to_bits();
+    let ngve_inf = f32::NEG_INFINITY.to_bits();
     let step_inf = (ptve_inf / MAX_TESTS) as usize;
-    for f in (SIGN..ngve_inf)
-        .step_by(step_inf)
-        .map(|i| f32::from_bits(i))
-    {
+    for f in (SIGN..ngve_inf).step_by(step_inf).map(f32::from_bits) {
         test_scalar_sin_cos_angle(f);
     }
-    for f in (0..ptve_inf).step_by(step_inf).map(|i| f32::from_bits(i)) {
+    for f in (0..ptve_inf).step_by(step_inf).map(f32::from_bits) {
         test_scalar_sin_cos_angle(f);
     }
 
     // +inf and -inf should return NaN
-    let (s, c) = scalar_sin_cos(std::f32::INFINITY);
+    let (s, c) = scalar_sin_cos(f32::INFINITY);
     assert!(s.is_nan());
     assert!(c.is_nan());
 
-    let (s, c) = scalar_sin_cos(std::f32::NEG_INFINITY);
+    let (s, c) = scalar_sin_cos(f32::NEG_INFINITY);
     assert!(s.is_nan());
     assert!(c.is_nan());
 }
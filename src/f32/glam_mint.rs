@@ -1,4 +1,4 @@
-use super::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use super::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 use mint;
 
 impl From<mint::Point2<f32>> for Vec2 {
@@ -27,6 +27,19 @@ impl From<Vec3> for mint::Point3<f32> {
     }
 }
 
+impl From<mint::Point3<f32>> for Vec3A {
+    fn from(v: mint::Point3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for mint::Point3<f32> {
+    fn from(v: Vec3A) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
 impl From<mint::Vector2<f32>> for Vec2 {
     fn from(v: mint::Vector2<f32>) -> Self {
         Self::new(v.x, v.y)
@@ -53,6 +66,19 @@ impl From<Vec3> for mint::Vector3<f32> {
     }
 }
 
+impl From<mint::Vector3<f32>> for Vec3A {
+    fn from(v: mint::Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for mint::Vector3<f32> {
+    fn from(v: Vec3A) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
 impl From<mint::Vector4<f32>> for Vec4 {
     fn from(v: mint::Vector4<f32>) -> Self {
         Self::new(v.x, v.y, v.z, v.w)
@@ -146,6 +172,39 @@ impl From<Mat3> for mint::ColumnMatrix3<f32> {
     }
 }
 
+impl From<mint::RowMatrix3<f32>> for Mat3A {
+    fn from(m: mint::RowMatrix3<f32>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into()).transpose()
+    }
+}
+
+impl From<Mat3A> for mint::RowMatrix3<f32> {
+    fn from(m: Mat3A) -> Self {
+        let mt = m.transpose();
+        Self {
+            x: mt.x_axis().into(),
+            y: mt.y_axis().into(),
+            z: mt.z_axis().into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3<f32>> for Mat3A {
+    fn from(m: mint::ColumnMatrix3<f32>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl From<Mat3A> for mint::ColumnMatrix3<f32> {
+    fn from(m: Mat3A) -> Self {
+        Self {
+            x: m.x_axis().into(),
+            y: m.y_axis().into(),
+            z: m.z_axis().into(),
+        }
+    }
+}
+
 impl From<mint::RowMatrix4<f32>> for Mat4 {
     fn from(m: mint::RowMatrix4<f32>) -> Self {
         Self::from_cols(m.x.into(), m.y.into(), m.z.into(), m.w.into()).transpose()
@@ -206,6 +265,19 @@ mod test {
         assert_eq!(m, g.into());
     }
 
+    #[test]
+    fn test_point3a() {
+        use crate::Vec3A;
+        let m = mint::Point3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let g = Vec3A::from(m);
+        assert_eq!(g, Vec3A::new(1.0, 2.0, 3.0));
+        assert_eq!(m, g.into());
+    }
+
     #[test]
     fn test_vector2() {
         use crate::Vec2;
@@ -228,6 +300,19 @@ mod test {
         assert_eq!(m, g.into());
     }
 
+    #[test]
+    fn test_vector3a() {
+        use crate::Vec3A;
+        let m = mint::Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let g = Vec3A::from(m);
+        assert_eq!(g, Vec3A::new(1.0, 2.0, 3.0));
+        assert_eq!(m, g.into());
+    }
+
     #[test]
     fn test_vector4() {
         use crate::Vec4;
@@ -283,6 +368,20 @@ mod test {
         assert_eq!(g, Mat3::from(mt));
     }
 
+    #[test]
+    fn test_matrix3a() {
+        use crate::Mat3A;
+        let g = Mat3A::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let m = mint::ColumnMatrix3::from(g);
+        assert_eq!(g, Mat3A::from(m));
+        let mt = mint::RowMatrix3::from(g);
+        assert_eq!(
+            mt,
+            mint::RowMatrix3::from([[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 9.0]])
+        );
+        assert_eq!(g, Mat3A::from(mt));
+    }
+
     #[test]
     fn test_matrix4() {
         use crate::Mat4;
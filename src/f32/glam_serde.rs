@@ -1,4 +1,4 @@
-use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use crate::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 
 use serde::{
     de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -36,6 +36,22 @@ impl Serialize for Vec3 {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for Vec3A {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (x, y, z) = (*self).into();
+        // 3 is the number of fields in the struct.
+        let mut state = serializer.serialize_tuple_struct("Vec3A", 3)?;
+        state.serialize_field(&x)?;
+        state.serialize_field(&y)?;
+        state.serialize_field(&z)?;
+        state.end()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for Vec4 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -110,6 +126,30 @@ impl Serialize for Mat3 {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for Mat3A {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (m00, m01, m02) = self.x_axis.into();
+        let (m10, m11, m12) = self.y_axis.into();
+        let (m20, m21, m22) = self.z_axis.into();
+
+        let mut state = serializer.serialize_tuple_struct("Mat3A", 9)?;
+        state.serialize_field(&m00)?;
+        state.serialize_field(&m01)?;
+        state.serialize_field(&m02)?;
+        state.serialize_field(&m10)?;
+        state.serialize_field(&m11)?;
+        state.serialize_field(&m12)?;
+        state.serialize_field(&m20)?;
+        state.serialize_field(&m21)?;
+        state.serialize_field(&m22)?;
+        state.end()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for Mat4 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -195,6 +235,43 @@ impl<'de> Deserialize<'de> for Vec3 {
     }
 }
 
+impl<'de> Deserialize<'de> for Vec3A {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Vec3AVisitor;
+
+        // TODO: Not sure why this line is reported as uncovered
+        #[cfg_attr(tarpaulin, skip)]
+        impl<'de> Visitor<'de> for Vec3AVisitor {
+            type Value = Vec3A;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Vec3A")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<Vec3A, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                Ok(Vec3A::new(x, y, z))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("Vec3A", 3, Vec3AVisitor)
+    }
+}
+
 impl<'de> Deserialize<'de> for Vec4 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -296,8 +373,8 @@ impl<'de> Deserialize<'de> for Mat2 {
                 V: SeqAccess<'de>,
             {
                 let mut f = { [0.0; 4] };
-                for i in 0..4 {
-                    f[i] = seq
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
                         .next_element()?
                         .ok_or_else(|| de::Error::invalid_length(i, &self))?;
                 }
@@ -332,8 +409,8 @@ impl<'de> Deserialize<'de> for Mat3 {
                 V: SeqAccess<'de>,
             {
                 let mut f = { [0.0; 9] };
-                for i in 0..9 {
-                    f[i] = seq
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
                         .next_element()?
                         .ok_or_else(|| de::Error::invalid_length(i, &self))?;
                 }
@@ -348,6 +425,43 @@ impl<'de> Deserialize<'de> for Mat3 {
     }
 }
 
+impl<'de> Deserialize<'de> for Mat3A {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Mat3AVisitor;
+
+        // TODO: Not sure why this line is reported as uncovered
+        #[cfg_attr(tarpaulin, skip)]
+        impl<'de> Visitor<'de> for Mat3AVisitor {
+            type Value = Mat3A;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Mat3A")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<Mat3A, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 9] };
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                let x = Vec3A::new(f[0], f[1], f[2]);
+                let y = Vec3A::new(f[3], f[4], f[5]);
+                let z = Vec3A::new(f[6], f[7], f[8]);
+                Ok(Mat3A::from_cols(x, y, z))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("Mat3A", 9, Mat3AVisitor)
+    }
+}
+
 impl<'de> Deserialize<'de> for Mat4 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -369,8 +483,8 @@ impl<'de> Deserialize<'de> for Mat4 {
                 V: SeqAccess<'de>,
             {
                 let mut f = { [0.0; 16] };
-                for i in 0..16 {
-                    f[i] = seq
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
                         .next_element()?
                         .ok_or_else(|| de::Error::invalid_length(i, &self))?;
                 }
@@ -0,0 +1,46 @@
+use super::{scalar_sin_cos, Mat2, Mat3, Mat4, Quat, Vec2, Vec3A};
+
+#[inline]
+pub fn mat3a(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Mat3A {
+    Mat3A {
+        x_axis,
+        y_axis,
+        z_axis,
+    }
+}
+
+/// A 3x3 column major matrix.
+///
+/// The columns of this type are stored as 16 byte aligned `Vec3A` values,
+/// which allows matrix and vector multiplication to use SIMD.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct Mat3A {
+    pub(crate) x_axis: Vec3A,
+    pub(crate) y_axis: Vec3A,
+    pub(crate) z_axis: Vec3A,
+}
+
+impl_mat3!(Mat3A, f32, Vec2, Vec3A, Mat2, Mat4);
+impl_mat3_quat!(Mat3A, f32, Quat, Vec3A);
+
+impl From<Mat3> for Mat3A {
+    #[inline]
+    fn from(m: Mat3) -> Self {
+        Self {
+            x_axis: m.x_axis.into(),
+            y_axis: m.y_axis.into(),
+            z_axis: m.z_axis.into(),
+        }
+    }
+}
+
+impl From<Mat3A> for Mat3 {
+    #[inline]
+    fn from(m: Mat3A) -> Self {
+        Self {
+            x_axis: m.x_axis.into(),
+            y_axis: m.y_axis.into(),
+            z_axis: m.z_axis.into(),
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use super::{scalar_sin_cos, Mat2, Mat3, Mat4, Vec2, Vec4};
+use std::ops::{Mul, MulAssign};
+
+#[inline]
+pub fn mat3x2(x_axis: Vec2, y_axis: Vec2, z_axis: Vec2) -> Mat3x2 {
+    Mat3x2::from_cols(x_axis, y_axis, z_axis)
+}
+
+/// A column major matrix with 3 columns and 2 rows, storing a 2D affine
+/// transform in 6 floats.
+///
+/// The `x_axis` and `y_axis` columns are the linear part of the transform and
+/// the `z_axis` column is the translation. This is the same as a `Mat3` whose
+/// last row is `(0, 0, 1)`, which is implied rather than stored. The memory
+/// layout is the one used by many 2D graphics APIs for their transforms.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct Mat3x2 {
+    pub(crate) x_axis: Vec2,
+    pub(crate) y_axis: Vec2,
+    pub(crate) z_axis: Vec2,
+}
+
+impl Default for Mat3x2 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl std::fmt::Display for Mat3x2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+    }
+}
+
+impl Mat3x2 {
+    /// Creates a new matrix with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::from_cols(Vec2::zero(), Vec2::zero(), Vec2::zero())
+    }
+
+    /// Creates the identity transform.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_cols(Vec2::unit_x(), Vec2::unit_y(), Vec2::zero())
+    }
+
+    /// Creates a new matrix from three column vectors, the last one being the
+    /// translation.
+    #[inline]
+    pub fn from_cols(x_axis: Vec2, y_axis: Vec2, z_axis: Vec2) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a new matrix from an array stored in column major order.
+    #[inline]
+    pub fn from_cols_array(m: &[f32; 6]) -> Self {
+        Self::from_cols(
+            Vec2::new(m[0], m[1]),
+            Vec2::new(m[2], m[3]),
+            Vec2::new(m[4], m[5]),
+        )
+    }
+
+    /// Creates a new array storing data in column major order.
+    #[inline]
+    pub fn to_cols_array(&self) -> [f32; 6] {
+        let (m00, m01) = self.x_axis.into();
+        let (m10, m11) = self.y_axis.into();
+        let (m20, m21) = self.z_axis.into();
+        [m00, m01, m10, m11, m20, m21]
+    }
+
+    /// Creates a new matrix from a 2D array stored in column major order.
+    #[inline]
+    pub fn from_cols_array_2d(m: &[[f32; 2]; 3]) -> Self {
+        Self::from_cols(m[0].into(), m[1].into(), m[2].into())
+    }
+
+    /// Creates a new 2D array storing data in column major order.
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[f32; 2]; 3] {
+        [self.x_axis.into(), self.y_axis.into(), self.z_axis.into()]
+    }
+
+    /// Creates a new matrix from the first 6 values in `slice`, stored in
+    /// column major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 6 elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f32]) -> Self {
+        Self::from_cols(
+            Vec2::from_slice_unaligned(&slice[0..2]),
+            Vec2::from_slice_unaligned(&slice[2..4]),
+            Vec2::from_slice_unaligned(&slice[4..6]),
+        )
+    }
+
+    /// Writes the elements of `self` to the first 6 elements in `slice` in
+    /// column major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 6 elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(&self, slice: &mut [f32]) {
+        self.x_axis.write_to_slice_unaligned(&mut slice[0..2]);
+        self.y_axis.write_to_slice_unaligned(&mut slice[2..4]);
+        self.z_axis.write_to_slice_unaligned(&mut slice[4..6]);
+    }
+
+    /// Creates a transform from the linear part `m` and a `translation`.
+    #[inline]
+    pub fn from_mat2_translation(m: Mat2, translation: Vec2) -> Self {
+        Self::from_cols(m.x_axis(), m.y_axis(), translation)
+    }
+
+    /// Creates a transform from the 2D affine transform `m`, dropping its last
+    /// row which is assumed to be `(0, 0, 1)`.
+    #[inline]
+    pub fn from_mat3(m: &Mat3) -> Self {
+        glam_assert!(m.x_axis.z() == 0.0 && m.y_axis.z() == 0.0 && m.z_axis.z() == 1.0);
+        Self::from_cols(
+            m.x_axis.truncate(),
+            m.y_axis.truncate(),
+            m.z_axis.truncate(),
+        )
+    }
+
+    /// Creates a transform that scales, rotates and translates, applied in that
+    /// order. `angle` is in radians.
+    #[inline]
+    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
+        Self::from_mat2_translation(Mat2::from_scale_angle(scale, angle), translation)
+    }
+
+    /// Creates a transform containing the given translation.
+    #[inline]
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self::from_cols(Vec2::unit_x(), Vec2::unit_y(), translation)
+    }
+
+    /// Creates a transform containing a rotation of `angle` (in radians).
+    #[inline]
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = scalar_sin_cos(angle);
+        Self::from_cols(Vec2::new(cos, sin), Vec2::new(-sin, cos), Vec2::zero())
+    }
+
+    /// Creates a transform containing the given non-uniform `scale`.
+    #[inline]
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self::from_cols(
+            Vec2::new(scale.x(), 0.0),
+            Vec2::new(0.0, scale.y()),
+            Vec2::zero(),
+        )
+    }
+
+    #[inline]
+    pub fn x_axis(&self) -> Vec2 {
+        self.x_axis
+    }
+
+    #[inline]
+    pub fn y_axis(&self) -> Vec2 {
+        self.y_axis
+    }
+
+    #[inline]
+    pub fn z_axis(&self) -> Vec2 {
+        self.z_axis
+    }
+
+    /// Returns the linear part of the transform, without the translation.
+    #[inline]
+    pub fn matrix2(&self) -> Mat2 {
+        Mat2::from_cols(self.x_axis, self.y_axis)
+    }
+
+    /// Returns the translation of the transform.
+    #[inline]
+    pub fn translation(&self) -> Vec2 {
+        self.z_axis
+    }
+
+    /// Returns the determinant of the linear part of the transform.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.x() * self.y_axis.y() - self.y_axis.x() * self.x_axis.y()
+    }
+
+    /// Returns the inverse transform of `self`.
+    ///
+    /// The linear part of `self` must be invertible.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let det = self.determinant();
+        glam_assert!(det != 0.0);
+        let inv_det = 1.0 / det;
+        let (m00, m01) = self.x_axis.into();
+        let (m10, m11) = self.y_axis.into();
+        let x_axis = Vec2::new(m11, -m01) * inv_det;
+        let y_axis = Vec2::new(-m10, m00) * inv_det;
+        let z_axis = -(x_axis * self.z_axis.x() + y_axis * self.z_axis.y());
+        Self::from_cols(x_axis, y_axis, z_axis)
+    }
+
+    /// Transforms the given 2D point, applying the linear part and then the
+    /// translation.
+    #[inline]
+    pub fn transform_point2(&self, other: Vec2) -> Vec2 {
+        self.transform_vector2(other) + self.z_axis
+    }
+
+    /// Transforms the given 2D vector, applying only the linear part.
+    #[inline]
+    pub fn transform_vector2(&self, other: Vec2) -> Vec2 {
+        self.x_axis * other.x() + self.y_axis * other.y()
+    }
+
+    /// Multiplies two transforms. The result applies `other` first and then
+    /// `self`.
+    #[inline]
+    pub fn mul_mat3x2(&self, other: &Self) -> Self {
+        Self::from_cols(
+            self.transform_vector2(other.x_axis),
+            self.transform_vector2(other.y_axis),
+            self.transform_point2(other.z_axis),
+        )
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+    }
+
+    /// Returns true if all elements of `self` and `other` are within
+    /// `max_abs_diff` of each other, or within `max_relative` times the larger
+    /// magnitude of the two elements.
+    #[inline]
+    pub fn relative_eq(&self, other: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        self.x_axis
+            .relative_eq(other.x_axis, max_abs_diff, max_relative)
+            && self
+                .y_axis
+                .relative_eq(other.y_axis, max_abs_diff, max_relative)
+            && self
+                .z_axis
+                .relative_eq(other.z_axis, max_abs_diff, max_relative)
+    }
+
+    /// Returns true if all elements of `self` and `other` are within
+    /// `max_abs_diff` of each other, or at most `max_ulps` representable
+    /// floating point values apart.
+    #[inline]
+    pub fn ulps_eq(&self, other: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        self.x_axis.ulps_eq(other.x_axis, max_abs_diff, max_ulps)
+            && self.y_axis.ulps_eq(other.y_axis, max_abs_diff, max_ulps)
+            && self.z_axis.ulps_eq(other.z_axis, max_abs_diff, max_ulps)
+    }
+}
+
+impl AsRef<[f32; 6]> for Mat3x2 {
+    #[inline]
+    fn as_ref(&self) -> &[f32; 6] {
+        unsafe { &*(self as *const Self as *const [f32; 6]) }
+    }
+}
+
+impl AsMut<[f32; 6]> for Mat3x2 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32; 6] {
+        unsafe { &mut *(self as *mut Self as *mut [f32; 6]) }
+    }
+}
+
+impl Mul<Mat3x2> for Mat3x2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat3x2(&other)
+    }
+}
+
+impl MulAssign<Mat3x2> for Mat3x2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_mat3x2(&other);
+    }
+}
+
+impl_ref_binop!(Mul, mul, Mat3x2, Mat3x2, Mat3x2);
+impl_slice_methods!(Mat3x2, f32, 6);
+
+impl From<Mat3x2> for Mat3 {
+    #[inline]
+    fn from(m: Mat3x2) -> Self {
+        Self::from_cols(
+            m.x_axis.extend(0.0),
+            m.y_axis.extend(0.0),
+            m.z_axis.extend(1.0),
+        )
+    }
+}
+
+impl From<Mat3x2> for Mat4 {
+    /// Embeds the 2D transform in the `xy` plane of a 3D transform.
+    #[inline]
+    fn from(m: Mat3x2) -> Self {
+        Self::from_cols(
+            m.x_axis.extend(0.0).extend(0.0),
+            m.y_axis.extend(0.0).extend(0.0),
+            Vec4::unit_z(),
+            m.z_axis.extend(0.0).extend(1.0),
+        )
+    }
+}
@@ -1,15 +1,18 @@
-use super::{scalar_sin_cos, Quat, Vec3, Vec4};
-
-#[cfg(feature = "rand")]
-use rand::{
-    distributions::{Distribution, Standard},
-    Rng,
-};
-
-use std::{
-    fmt,
-    ops::{Add, Mul, Sub},
-};
+#[cfg(all(
+    target_arch = "x86",
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+use std::arch::x86::__m128;
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+use std::arch::x86_64::__m128;
+
+use super::{scalar_sin_cos, Mat3, Mat3A, Quat, Vec3, Vec3A, Vec4};
+use crate::U16Vec4;
 
 #[inline]
 pub fn mat4(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Mat4 {
@@ -21,29 +24,6 @@ pub fn mat4(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Mat4 {
     }
 }
 
-#[inline]
-fn quat_to_axes(rotation: Quat) -> (Vec4, Vec4, Vec4) {
-    glam_assert!(rotation.is_normalized());
-    let (x, y, z, w) = rotation.into();
-    let x2 = x + x;
-    let y2 = y + y;
-    let z2 = z + z;
-    let xx = x * x2;
-    let xy = x * y2;
-    let xz = x * z2;
-    let yy = y * y2;
-    let yz = y * z2;
-    let zz = z * z2;
-    let wx = w * x2;
-    let wy = w * y2;
-    let wz = w * z2;
-
-    let x_axis = Vec4::new(1.0 - (yy + zz), xy + wz, xz - wy, 0.0);
-    let y_axis = Vec4::new(xy - wz, 1.0 - (xx + zz), yz + wx, 0.0);
-    let z_axis = Vec4::new(xz + wy, yz - wx, 1.0 - (xx + yy), 0.0);
-    (x_axis, y_axis, z_axis)
-}
-
 /// A 4x4 column major matrix.
 ///
 /// This type is 16 byte aligned.
@@ -55,298 +35,76 @@ pub struct Mat4 {
     pub(crate) w_axis: Vec4,
 }
 
-impl Default for Mat4 {
-    #[inline]
-    fn default() -> Self {
-        Self::identity()
-    }
-}
-
-impl fmt::Display for Mat4 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "[{}, {}, {}, {}]",
-            self.x_axis, self.y_axis, self.z_axis, self.w_axis
-        )
-    }
-}
+impl_mat4!(Mat4, f32, Vec3, Vec4, Mat3);
+impl_mat4_quat!(Mat4, f32, Quat, Vec3, Vec4);
+
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "scalar-math",
+    feature = "core-simd"
+))]
+impl_mat4_scalar!(Mat4, Vec4);
+
+#[cfg(all(
+    target_arch = "x86",
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+impl_mat4_simd_cols!(Mat4, std::arch::x86::__m128);
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+impl_mat4_simd_cols!(Mat4, std::arch::x86_64::__m128);
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+impl_mat4_simd_cols!(Mat4, std::arch::aarch64::float32x4_t);
+#[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
+impl_mat4_simd_cols!(Mat4, std::simd::f32x4);
 
 impl Mat4 {
-    /// Creates a new `Mat4` with all elements set to `0.0`.
-    #[inline]
-    pub fn zero() -> Self {
-        Self {
-            x_axis: Vec4::zero(),
-            y_axis: Vec4::zero(),
-            z_axis: Vec4::zero(),
-            w_axis: Vec4::zero(),
-        }
-    }
-
-    /// Creates a new `Mat4` identity matrix.
-    #[inline]
-    pub fn identity() -> Self {
-        Self {
-            x_axis: Vec4::unit_x(),
-            y_axis: Vec4::unit_y(),
-            z_axis: Vec4::unit_z(),
-            w_axis: Vec4::unit_w(),
-        }
-    }
-
     #[deprecated(since = "0.7.2", note = "please use `Mat4::from_cols` instead")]
     #[inline]
     pub fn new(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Self {
         Self::from_cols(x_axis, y_axis, z_axis, w_axis)
     }
 
-    /// Creates a new `Mat4` from four column vectors.
-    #[inline]
-    pub fn from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Self {
-        Self {
-            x_axis,
-            y_axis,
-            z_axis,
-            w_axis,
-        }
-    }
-
-    /// Creates a new `Mat4` from a `[f32; 16]` stored in column major order.
-    /// If your data is stored in row major you will need to `transpose` the resulting `Mat4`.
-    #[inline]
-    pub fn from_cols_array(m: &[f32; 16]) -> Self {
-        Mat4 {
-            x_axis: Vec4::new(m[0], m[1], m[2], m[3]),
-            y_axis: Vec4::new(m[4], m[5], m[6], m[7]),
-            z_axis: Vec4::new(m[8], m[9], m[10], m[11]),
-            w_axis: Vec4::new(m[12], m[13], m[14], m[15]),
-        }
-    }
-
-    /// Creates a new `[f32; 16]` storing data in column major order.
-    /// If you require data in row major order `transpose` the `Mat4` first.
-    #[inline]
-    pub fn to_cols_array(&self) -> [f32; 16] {
-        *self.as_ref()
-    }
-
-    /// Creates a new `Mat4` from a `[[f32; 4]; 4]` stored in column major order.
-    /// If your data is in row major order you will need to `transpose` the resulting `Mat4`.
-    #[inline]
-    pub fn from_cols_array_2d(m: &[[f32; 4]; 4]) -> Self {
-        Mat4 {
-            x_axis: m[0].into(),
-            y_axis: m[1].into(),
-            z_axis: m[2].into(),
-            w_axis: m[3].into(),
-        }
-    }
-
-    /// Creates a new `[[f32; 4]; 4]` storing data in column major order.
-    /// If you require data in row major order `transpose` the `Mat4` first.
-    #[inline]
-    pub fn to_cols_array_2d(&self) -> [[f32; 4]; 4] {
-        [
-            self.x_axis.into(),
-            self.y_axis.into(),
-            self.z_axis.into(),
-            self.w_axis.into(),
-        ]
-    }
-
-    #[inline]
-    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
-        glam_assert!(rotation.is_normalized());
-        let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
-        let (scale_x, scale_y, scale_z) = scale.into();
-        Self {
-            x_axis: x_axis * scale_x,
-            y_axis: y_axis * scale_y,
-            z_axis: z_axis * scale_z,
-            w_axis: translation.extend(1.0),
-        }
-    }
-
-    #[inline]
-    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
-        glam_assert!(rotation.is_normalized());
-        let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
-        Self {
-            x_axis,
-            y_axis,
-            z_axis,
-            w_axis: translation.extend(1.0),
-        }
-    }
-
-    #[inline]
-    pub fn from_quat(rotation: Quat) -> Self {
-        glam_assert!(rotation.is_normalized());
-        let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
-        Self {
-            x_axis,
-            y_axis,
-            z_axis,
-            w_axis: Vec4::unit_w(),
-        }
-    }
-
-    #[inline]
-    pub fn from_translation(translation: Vec3) -> Self {
-        Self {
-            x_axis: Vec4::unit_x(),
-            y_axis: Vec4::unit_y(),
-            z_axis: Vec4::unit_z(),
-            w_axis: translation.extend(1.0),
-        }
-    }
-
-    /// Creates a new `Mat4` containing a rotation around a normalized rotation axis of
-    /// angle (in radians).
-    #[inline]
-    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
-        glam_assert!(axis.is_normalized());
-        let (sin, cos) = scalar_sin_cos(angle);
-        let (x, y, z) = axis.into();
-        let (xsin, ysin, zsin) = (axis * sin).into();
-        let (x2, y2, z2) = (axis * axis).into();
-        let omc = 1.0 - cos;
-        let xyomc = x * y * omc;
-        let xzomc = x * z * omc;
-        let yzomc = y * z * omc;
-        Self {
-            x_axis: Vec4::new(x2 * omc + cos, xyomc + zsin, xzomc - ysin, 0.0),
-            y_axis: Vec4::new(xyomc - zsin, y2 * omc + cos, yzomc + xsin, 0.0),
-            z_axis: Vec4::new(xzomc + ysin, yzomc - xsin, z2 * omc + cos, 0.0),
-            w_axis: Vec4::unit_w(),
-        }
-    }
-
-    /// Creates a new `Mat4` containing a rotation around the given euler angles
-    /// (in radians).
-    #[inline]
-    pub fn from_rotation_ypr(yaw: f32, pitch: f32, roll: f32) -> Self {
-        let quat = Quat::from_rotation_ypr(yaw, pitch, roll);
-        Self::from_quat(quat)
-    }
-
-    /// Creates a new `Mat4` containing a rotation around the x axis of angle
-    /// (in radians).
-    #[inline]
-    pub fn from_rotation_x(angle: f32) -> Self {
-        let (sina, cosa) = scalar_sin_cos(angle);
-        Self {
-            x_axis: Vec4::unit_x(),
-            y_axis: Vec4::new(0.0, cosa, sina, 0.0),
-            z_axis: Vec4::new(0.0, -sina, cosa, 0.0),
-            w_axis: Vec4::unit_w(),
-        }
-    }
-
-    /// Creates a new `Mat4` containing a rotation around the y axis of angle
-    /// (in radians).
-    #[inline]
-    pub fn from_rotation_y(angle: f32) -> Self {
-        let (sina, cosa) = scalar_sin_cos(angle);
-        Self {
-            x_axis: Vec4::new(cosa, 0.0, -sina, 0.0),
-            y_axis: Vec4::unit_y(),
-            z_axis: Vec4::new(sina, 0.0, cosa, 0.0),
-            w_axis: Vec4::unit_w(),
-        }
-    }
-
-    /// Creates a new `Mat4` containing a rotation around the z axis of angle
-    /// (in radians).
-    #[inline]
-    pub fn from_rotation_z(angle: f32) -> Self {
-        let (sina, cosa) = scalar_sin_cos(angle);
-        Self {
-            x_axis: Vec4::new(cosa, sina, 0.0, 0.0),
-            y_axis: Vec4::new(-sina, cosa, 0.0, 0.0),
-            z_axis: Vec4::unit_z(),
-            w_axis: Vec4::unit_w(),
-        }
-    }
-
-    #[inline]
-    pub fn from_scale(scale: Vec3) -> Self {
-        glam_assert!(scale.cmpne(Vec3::zero()).any()); // Do not panic as long as any component is non-zero
-        let (x, y, z) = scale.into();
-        Self {
-            x_axis: Vec4::new(x, 0.0, 0.0, 0.0),
-            y_axis: Vec4::new(0.0, y, 0.0, 0.0),
-            z_axis: Vec4::new(0.0, 0.0, z, 0.0),
-            w_axis: Vec4::unit_w(),
-        }
-    }
-
-    #[inline]
-    pub fn set_x_axis(&mut self, x: Vec4) {
-        self.x_axis = x;
-    }
-
-    #[inline]
-    pub fn set_y_axis(&mut self, y: Vec4) {
-        self.y_axis = y;
-    }
-
-    #[inline]
-    pub fn set_z_axis(&mut self, z: Vec4) {
-        self.z_axis = z;
-    }
-
-    #[inline]
-    pub fn set_w_axis(&mut self, w: Vec4) {
-        self.w_axis = w;
-    }
-
-    #[inline]
-    pub fn x_axis(&self) -> Vec4 {
-        self.x_axis
-    }
-
-    #[inline]
-    pub fn y_axis(&self) -> Vec4 {
-        self.y_axis
-    }
-
-    #[inline]
-    pub fn z_axis(&self) -> Vec4 {
-        self.z_axis
-    }
-
-    #[inline]
-    pub fn w_axis(&self) -> Vec4 {
-        self.w_axis
-    }
-
-    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
     #[inline]
     pub fn transpose(&self) -> Self {
-        // sse2 implementation based off DirectXMath XMMatrixInverse (MIT License)
+        // sse2 implementation based off the _MM_TRANSPOSE4_PS macro
         #[cfg(target_arch = "x86")]
         use std::arch::x86::*;
         #[cfg(target_arch = "x86_64")]
         use std::arch::x86_64::*;
 
         unsafe {
-            let tmp0 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b01_00_01_00);
-            let tmp1 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b11_10_11_10);
-            let tmp2 = _mm_shuffle_ps(self.z_axis.0, self.w_axis.0, 0b01_00_01_00);
-            let tmp3 = _mm_shuffle_ps(self.z_axis.0, self.w_axis.0, 0b11_10_11_10);
+            let tmp0 = _mm_unpacklo_ps(self.x_axis.0, self.y_axis.0);
+            let tmp1 = _mm_unpacklo_ps(self.z_axis.0, self.w_axis.0);
+            let tmp2 = _mm_unpackhi_ps(self.x_axis.0, self.y_axis.0);
+            let tmp3 = _mm_unpackhi_ps(self.z_axis.0, self.w_axis.0);
 
             Self {
-                x_axis: _mm_shuffle_ps(tmp0, tmp2, 0b10_00_10_00).into(),
-                y_axis: _mm_shuffle_ps(tmp0, tmp2, 0b11_01_11_01).into(),
-                z_axis: _mm_shuffle_ps(tmp1, tmp3, 0b10_00_10_00).into(),
-                w_axis: _mm_shuffle_ps(tmp1, tmp3, 0b11_01_11_01).into(),
+                x_axis: _mm_movelh_ps(tmp0, tmp1).into(),
+                y_axis: _mm_movehl_ps(tmp1, tmp0).into(),
+                z_axis: _mm_movelh_ps(tmp2, tmp3).into(),
+                w_axis: _mm_movehl_ps(tmp3, tmp2).into(),
             }
         }
     }
 
-    #[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+    #[cfg(any(
+        not(target_feature = "sse2"),
+        feature = "scalar-math",
+        feature = "core-simd"
+    ))]
     #[inline]
     pub fn transpose(&self) -> Self {
         let (m00, m01, m02, m03) = self.x_axis.into();
@@ -362,189 +120,13 @@ impl Mat4 {
         }
     }
 
-    #[inline]
-    pub fn determinant(&self) -> f32 {
-        let (m00, m01, m02, m03) = self.x_axis.into();
-        let (m10, m11, m12, m13) = self.y_axis.into();
-        let (m20, m21, m22, m23) = self.z_axis.into();
-        let (m30, m31, m32, m33) = self.w_axis.into();
-
-        let a2323 = m22 * m33 - m23 * m32;
-        let a1323 = m21 * m33 - m23 * m31;
-        let a1223 = m21 * m32 - m22 * m31;
-        let a0323 = m20 * m33 - m23 * m30;
-        let a0223 = m20 * m32 - m22 * m30;
-        let a0123 = m20 * m31 - m21 * m30;
-
-        m00 * (m11 * a2323 - m12 * a1323 + m13 * a1223)
-            - m01 * (m10 * a2323 - m12 * a0323 + m13 * a0223)
-            + m02 * (m10 * a1323 - m11 * a0323 + m13 * a0123)
-            - m03 * (m10 * a1223 - m11 * a0223 + m12 * a0123)
-    }
-
-    // #[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
-    pub fn inverse(&self) -> Self {
-        let (m00, m01, m02, m03) = self.x_axis.into();
-        let (m10, m11, m12, m13) = self.y_axis.into();
-        let (m20, m21, m22, m23) = self.z_axis.into();
-        let (m30, m31, m32, m33) = self.w_axis.into();
-
-        let coef00 = m22 * m33 - m32 * m23;
-        let coef02 = m12 * m33 - m32 * m13;
-        let coef03 = m12 * m23 - m22 * m13;
-
-        let coef04 = m21 * m33 - m31 * m23;
-        let coef06 = m11 * m33 - m31 * m13;
-        let coef07 = m11 * m23 - m21 * m13;
-
-        let coef08 = m21 * m32 - m31 * m22;
-        let coef10 = m11 * m32 - m31 * m12;
-        let coef11 = m11 * m22 - m21 * m12;
-
-        let coef12 = m20 * m33 - m30 * m23;
-        let coef14 = m10 * m33 - m30 * m13;
-        let coef15 = m10 * m23 - m20 * m13;
-
-        let coef16 = m20 * m32 - m30 * m22;
-        let coef18 = m10 * m32 - m30 * m12;
-        let coef19 = m10 * m22 - m20 * m12;
-
-        let coef20 = m20 * m31 - m30 * m21;
-        let coef22 = m10 * m31 - m30 * m11;
-        let coef23 = m10 * m21 - m20 * m11;
-
-        let fac0 = Vec4::new(coef00, coef00, coef02, coef03);
-        let fac1 = Vec4::new(coef04, coef04, coef06, coef07);
-        let fac2 = Vec4::new(coef08, coef08, coef10, coef11);
-        let fac3 = Vec4::new(coef12, coef12, coef14, coef15);
-        let fac4 = Vec4::new(coef16, coef16, coef18, coef19);
-        let fac5 = Vec4::new(coef20, coef20, coef22, coef23);
-
-        let vec0 = Vec4::new(m10, m00, m00, m00);
-        let vec1 = Vec4::new(m11, m01, m01, m01);
-        let vec2 = Vec4::new(m12, m02, m02, m02);
-        let vec3 = Vec4::new(m13, m03, m03, m03);
-
-        let inv0 = vec1 * fac0 - vec2 * fac1 + vec3 * fac2;
-        let inv1 = vec0 * fac0 - vec2 * fac3 + vec3 * fac4;
-        let inv2 = vec0 * fac1 - vec1 * fac3 + vec3 * fac5;
-        let inv3 = vec0 * fac2 - vec1 * fac4 + vec2 * fac5;
-
-        let sign_a = Vec4::new(1.0, -1.0, 1.0, -1.0);
-        let sign_b = Vec4::new(-1.0, 1.0, -1.0, 1.0);
-
-        let inverse = Self {
-            x_axis: inv0 * sign_a,
-            y_axis: inv1 * sign_b,
-            z_axis: inv2 * sign_a,
-            w_axis: inv3 * sign_b,
-        };
-
-        let col0 = Vec4::new(
-            inverse.x_axis.x(),
-            inverse.y_axis.x(),
-            inverse.z_axis.x(),
-            inverse.w_axis.x(),
-        );
-
-        let dot0 = self.x_axis * col0;
-        let dot1 = dot0.x() + dot0.y() + dot0.z() + dot0.w();
-
-        glam_assert!(dot1 != 0.0);
-
-        let rcp_det = 1.0 / dot1;
-        inverse * rcp_det
-    }
-
-    #[inline]
-    // TODO: make public at some point
-    fn look_to_lh(eye: Vec3, dir: Vec3, up: Vec3) -> Self {
-        let f = dir.normalize();
-        let s = up.cross(f).normalize();
-        let u = f.cross(s);
-        let (fx, fy, fz) = f.into();
-        let (sx, sy, sz) = s.into();
-        let (ux, uy, uz) = u.into();
-        Mat4::from_cols(
-            Vec4::new(sx, ux, fx, 0.0),
-            Vec4::new(sy, uy, fy, 0.0),
-            Vec4::new(sz, uz, fz, 0.0),
-            Vec4::new(-s.dot(eye), -u.dot(eye), -f.dot(eye), 1.0),
-        )
-    }
-
-    #[inline]
-    pub fn look_at_lh(eye: Vec3, center: Vec3, up: Vec3) -> Self {
-        glam_assert!(up.is_normalized());
-        Mat4::look_to_lh(eye, center - eye, up)
-    }
-
-    #[inline]
-    pub fn look_at_rh(eye: Vec3, center: Vec3, up: Vec3) -> Self {
-        glam_assert!(up.is_normalized());
-        Mat4::look_to_lh(eye, eye - center, up)
-    }
-
-    #[inline]
-    /// Builds a right-handed perspective projection matrix with [-1,1] depth range.
-    /// This is the equivalent of the common perspective function `gluPerspective` in OpenGL.
-    /// See https://www.khronos.org/opengl/wiki/GluPerspective_code
-    pub fn perspective_glu_rh(
-        fov_y_radians: f32,
-        aspect_ratio: f32,
-        z_near: f32,
-        z_far: f32,
-    ) -> Mat4 {
-        let inv_length = 1.0 / (z_near - z_far);
-        let f = 1.0 / (0.5 * fov_y_radians).tan();
-        let a = f / aspect_ratio;
-        let b = (z_near + z_far) * inv_length;
-        let c = (2.0 * z_near * z_far) * inv_length;
-        Mat4::from_cols(
-            Vec4::new(a, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, f, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, b, -1.0),
-            Vec4::new(0.0, 0.0, c, 0.0),
-        )
-    }
-
-    /// Build infinite right-handed perspective projection matrix with [0,1] depth range.
-    pub fn perspective_infinite_rh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32) -> Mat4 {
-        let f = 1.0 / (0.5 * fov_y_radians).tan();
-        Mat4::from_cols(
-            Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, f, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, -1.0, -1.0),
-            Vec4::new(0.0, 0.0, -z_near, 0.0),
-        )
-    }
-
-    /// Build infinite reverse right-handed perspective projection matrix with [0,1] depth range.
-    pub fn perspective_infinite_reverse_rh(
-        fov_y_radians: f32,
-        aspect_ratio: f32,
-        z_near: f32,
-    ) -> Mat4 {
-        let f = 1.0 / (0.5 * fov_y_radians).tan();
-        Mat4::from_cols(
-            Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, f, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, 0.0, -1.0),
-            Vec4::new(0.0, 0.0, z_near, 0.0),
-        )
-    }
-
-    #[inline]
-    pub fn mul_vec4(&self, other: Vec4) -> Vec4 {
-        let mut res = self.x_axis * other.dup_x();
-        res = self.y_axis.mul_add(other.dup_y(), res);
-        res = self.z_axis.mul_add(other.dup_z(), res);
-        res = self.w_axis.mul_add(other.dup_w(), res);
-        res
-    }
-
-    #[inline]
     /// Multiplies two 4x4 matrices.
+    #[cfg(any(
+        not(target_feature = "sse2"),
+        feature = "scalar-math",
+        feature = "core-simd"
+    ))]
+    #[inline]
     pub fn mul_mat4(&self, other: &Self) -> Self {
         Self {
             x_axis: self.mul_vec4(other.x_axis),
@@ -554,140 +136,347 @@ impl Mat4 {
         }
     }
 
+    /// Multiplies two 4x4 matrices.
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
     #[inline]
-    pub fn add_mat4(&self, other: &Self) -> Self {
-        Self {
-            x_axis: self.x_axis + other.x_axis,
-            y_axis: self.y_axis + other.y_axis,
-            z_axis: self.z_axis + other.z_axis,
-            w_axis: self.w_axis + other.w_axis,
-        }
-    }
-
-    #[inline]
-    pub fn sub_mat4(&self, other: &Self) -> Self {
-        Self {
-            x_axis: self.x_axis - other.x_axis,
-            y_axis: self.y_axis - other.y_axis,
-            z_axis: self.z_axis - other.z_axis,
-            w_axis: self.w_axis - other.w_axis,
+    pub fn mul_mat4(&self, other: &Self) -> Self {
+        #[cfg(all(feature = "runtime-dispatch", not(target_feature = "fma")))]
+        {
+            if super::dispatch::has_avx_fma() {
+                return unsafe { super::dispatch::mat4_mul_mat4_avx_fma(self, other) };
+            }
         }
-    }
-
-    #[inline]
-    pub fn mul_scalar(&self, other: f32) -> Self {
-        let s = Vec4::splat(other);
+        unsafe { self.mul_mat4_m128::<super::x86_utils::BaselineMulAdd>(other) }
+    }
+
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
+    #[inline(always)]
+    pub(crate) unsafe fn mul_mat4_m128<M: super::x86_utils::M128MulAdd>(
+        &self,
+        other: &Self,
+    ) -> Self {
         Self {
-            x_axis: self.x_axis * s,
-            y_axis: self.y_axis * s,
-            z_axis: self.z_axis * s,
-            w_axis: self.w_axis * s,
+            x_axis: Vec4(self.mul_vec4_m128::<M>(other.x_axis.0)),
+            y_axis: Vec4(self.mul_vec4_m128::<M>(other.y_axis.0)),
+            z_axis: Vec4(self.mul_vec4_m128::<M>(other.z_axis.0)),
+            w_axis: Vec4(self.mul_vec4_m128::<M>(other.w_axis.0)),
         }
     }
 
-    #[inline]
-    pub fn transform_point3(&self, other: Vec3) -> Vec3 {
-        let mut res = self.x_axis.truncate() * other.dup_x();
-        res = self.y_axis.truncate().mul_add(other.dup_y(), res);
-        res = self.z_axis.truncate().mul_add(other.dup_z(), res);
-        // other w = 1
-        res = self.w_axis.truncate() + res;
-        res
-    }
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
+    #[inline(always)]
+    unsafe fn mul_vec4_m128<M: super::x86_utils::M128MulAdd>(&self, other: __m128) -> __m128 {
+        #[cfg(target_arch = "x86")]
+        use std::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use std::arch::x86_64::*;
 
-    #[inline]
-    pub fn transform_vector3(&self, other: Vec3) -> Vec3 {
-        let mut res = self.x_axis.truncate() * other.dup_x();
-        res = self.y_axis.truncate().mul_add(other.dup_y(), res);
-        res = self.z_axis.truncate().mul_add(other.dup_z(), res);
-        // other w = 0
-        res
+        let mut res = _mm_mul_ps(self.x_axis.0, _mm_shuffle_ps(other, other, 0b00_00_00_00));
+        res = M::mul_add(
+            self.y_axis.0,
+            _mm_shuffle_ps(other, other, 0b01_01_01_01),
+            res,
+        );
+        res = M::mul_add(
+            self.z_axis.0,
+            _mm_shuffle_ps(other, other, 0b10_10_10_10),
+            res,
+        );
+        M::mul_add(
+            self.w_axis.0,
+            _mm_shuffle_ps(other, other, 0b11_11_11_11),
+            res,
+        )
     }
 
-    /// Returns true if the absolute difference of all elements between `self`
-    /// and `other` is less than or equal to `max_abs_diff`.
-    ///
-    /// This can be used to compare if two `Mat4`'s contain similar elements. It
-    /// works best when comparing with a known value. The `max_abs_diff` that
-    /// should be used used depends on the values being compared against.
-    ///
-    /// For more on floating point comparisons see
-    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
     #[inline]
-    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
-        self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
-            && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
-            && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
-            && self.w_axis.abs_diff_eq(other.w_axis, max_abs_diff)
+    pub fn inverse(&self) -> Self {
+        #[cfg(all(feature = "runtime-dispatch", not(target_feature = "avx")))]
+        {
+            if super::dispatch::has_avx_fma() {
+                return unsafe { super::dispatch::mat4_inverse_avx(self) };
+            }
+        }
+        self.inverse_sse2()
     }
-}
 
-#[cfg(feature = "rand")]
-impl Distribution<Mat4> for Standard {
-    #[inline]
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat4 {
-        Mat4::from_cols_array(&rng.gen())
-    }
-}
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
+    #[inline(always)]
+    pub(crate) fn inverse_sse2(&self) -> Self {
+        // sse2 implementation based off GLM glm_mat4_inverse (MIT License)
+        #[cfg(target_arch = "x86")]
+        use std::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use std::arch::x86_64::*;
 
-impl AsRef<[f32; 16]> for Mat4 {
-    #[inline]
-    fn as_ref(&self) -> &[f32; 16] {
-        unsafe { &*(self as *const Self as *const [f32; 16]) }
-    }
-}
+        unsafe {
+            let fac0 = {
+                let swp0a = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b11_11_11_11);
+                let swp0b = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b10_10_10_10);
+
+                let swp00 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b10_10_10_10);
+                let swp01 = _mm_shuffle_ps(swp0a, swp0a, 0b10_00_00_00);
+                let swp02 = _mm_shuffle_ps(swp0b, swp0b, 0b10_00_00_00);
+                let swp03 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b11_11_11_11);
+
+                let mul00 = _mm_mul_ps(swp00, swp01);
+                let mul01 = _mm_mul_ps(swp02, swp03);
+                _mm_sub_ps(mul00, mul01)
+            };
+            let fac1 = {
+                let swp0a = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b11_11_11_11);
+                let swp0b = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b01_01_01_01);
+
+                let swp00 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b01_01_01_01);
+                let swp01 = _mm_shuffle_ps(swp0a, swp0a, 0b10_00_00_00);
+                let swp02 = _mm_shuffle_ps(swp0b, swp0b, 0b10_00_00_00);
+                let swp03 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b11_11_11_11);
+
+                let mul00 = _mm_mul_ps(swp00, swp01);
+                let mul01 = _mm_mul_ps(swp02, swp03);
+                _mm_sub_ps(mul00, mul01)
+            };
+            let fac2 = {
+                let swp0a = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b10_10_10_10);
+                let swp0b = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b01_01_01_01);
+
+                let swp00 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b01_01_01_01);
+                let swp01 = _mm_shuffle_ps(swp0a, swp0a, 0b10_00_00_00);
+                let swp02 = _mm_shuffle_ps(swp0b, swp0b, 0b10_00_00_00);
+                let swp03 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b10_10_10_10);
+
+                let mul00 = _mm_mul_ps(swp00, swp01);
+                let mul01 = _mm_mul_ps(swp02, swp03);
+                _mm_sub_ps(mul00, mul01)
+            };
+            let fac3 = {
+                let swp0a = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b11_11_11_11);
+                let swp0b = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b00_00_00_00);
+
+                let swp00 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b00_00_00_00);
+                let swp01 = _mm_shuffle_ps(swp0a, swp0a, 0b10_00_00_00);
+                let swp02 = _mm_shuffle_ps(swp0b, swp0b, 0b10_00_00_00);
+                let swp03 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b11_11_11_11);
+
+                let mul00 = _mm_mul_ps(swp00, swp01);
+                let mul01 = _mm_mul_ps(swp02, swp03);
+                _mm_sub_ps(mul00, mul01)
+            };
+            let fac4 = {
+                let swp0a = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b10_10_10_10);
+                let swp0b = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b00_00_00_00);
+
+                let swp00 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b00_00_00_00);
+                let swp01 = _mm_shuffle_ps(swp0a, swp0a, 0b10_00_00_00);
+                let swp02 = _mm_shuffle_ps(swp0b, swp0b, 0b10_00_00_00);
+                let swp03 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b10_10_10_10);
+
+                let mul00 = _mm_mul_ps(swp00, swp01);
+                let mul01 = _mm_mul_ps(swp02, swp03);
+                _mm_sub_ps(mul00, mul01)
+            };
+            let fac5 = {
+                let swp0a = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b01_01_01_01);
+                let swp0b = _mm_shuffle_ps(self.w_axis.0, self.z_axis.0, 0b00_00_00_00);
+
+                let swp00 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b00_00_00_00);
+                let swp01 = _mm_shuffle_ps(swp0a, swp0a, 0b10_00_00_00);
+                let swp02 = _mm_shuffle_ps(swp0b, swp0b, 0b10_00_00_00);
+                let swp03 = _mm_shuffle_ps(self.z_axis.0, self.y_axis.0, 0b01_01_01_01);
+
+                let mul00 = _mm_mul_ps(swp00, swp01);
+                let mul01 = _mm_mul_ps(swp02, swp03);
+                _mm_sub_ps(mul00, mul01)
+            };
+
+            let sign_a = _mm_set_ps(1.0, -1.0, 1.0, -1.0);
+            let sign_b = _mm_set_ps(-1.0, 1.0, -1.0, 1.0);
+
+            let temp0 = _mm_shuffle_ps(self.y_axis.0, self.x_axis.0, 0b00_00_00_00);
+            let vec0 = _mm_shuffle_ps(temp0, temp0, 0b10_10_10_00);
+
+            let temp1 = _mm_shuffle_ps(self.y_axis.0, self.x_axis.0, 0b01_01_01_01);
+            let vec1 = _mm_shuffle_ps(temp1, temp1, 0b10_10_10_00);
+
+            let temp2 = _mm_shuffle_ps(self.y_axis.0, self.x_axis.0, 0b10_10_10_10);
+            let vec2 = _mm_shuffle_ps(temp2, temp2, 0b10_10_10_00);
+
+            let temp3 = _mm_shuffle_ps(self.y_axis.0, self.x_axis.0, 0b11_11_11_11);
+            let vec3 = _mm_shuffle_ps(temp3, temp3, 0b10_10_10_00);
+
+            let mul00 = _mm_mul_ps(vec1, fac0);
+            let mul01 = _mm_mul_ps(vec2, fac1);
+            let mul02 = _mm_mul_ps(vec3, fac2);
+            let sub00 = _mm_sub_ps(mul00, mul01);
+            let add00 = _mm_add_ps(sub00, mul02);
+            let inv0 = _mm_mul_ps(sign_b, add00);
+
+            let mul03 = _mm_mul_ps(vec0, fac0);
+            let mul04 = _mm_mul_ps(vec2, fac3);
+            let mul05 = _mm_mul_ps(vec3, fac4);
+            let sub01 = _mm_sub_ps(mul03, mul04);
+            let add01 = _mm_add_ps(sub01, mul05);
+            let inv1 = _mm_mul_ps(sign_a, add01);
+
+            let mul06 = _mm_mul_ps(vec0, fac1);
+            let mul07 = _mm_mul_ps(vec1, fac3);
+            let mul08 = _mm_mul_ps(vec3, fac5);
+            let sub02 = _mm_sub_ps(mul06, mul07);
+            let add02 = _mm_add_ps(sub02, mul08);
+            let inv2 = _mm_mul_ps(sign_b, add02);
+
+            let mul09 = _mm_mul_ps(vec0, fac2);
+            let mul10 = _mm_mul_ps(vec1, fac4);
+            let mul11 = _mm_mul_ps(vec2, fac5);
+            let sub03 = _mm_sub_ps(mul09, mul10);
+            let add03 = _mm_add_ps(sub03, mul11);
+            let inv3 = _mm_mul_ps(sign_a, add03);
+
+            let row0 = _mm_shuffle_ps(inv0, inv1, 0b00_00_00_00);
+            let row1 = _mm_shuffle_ps(inv2, inv3, 0b00_00_00_00);
+            let row2 = _mm_shuffle_ps(row0, row1, 0b10_00_10_00);
+
+            let dot0 = self.x_axis.dot(row2.into());
+            glam_assert!(dot0 != 0.0);
+
+            let rcp0 = _mm_set1_ps(1.0 / dot0);
 
-impl AsMut<[f32; 16]> for Mat4 {
-    #[inline]
-    fn as_mut(&mut self) -> &mut [f32; 16] {
-        unsafe { &mut *(self as *mut Self as *mut [f32; 16]) }
+            Self {
+                x_axis: _mm_mul_ps(inv0, rcp0).into(),
+                y_axis: _mm_mul_ps(inv1, rcp0).into(),
+                z_axis: _mm_mul_ps(inv2, rcp0).into(),
+                w_axis: _mm_mul_ps(inv3, rcp0).into(),
+            }
+        }
     }
 }
 
-impl Add<Mat4> for Mat4 {
-    type Output = Self;
+impl Mat4 {
+    /// Creates a 4x4 matrix with `m` as its upper left 3x3 part, as `from_mat3`
+    /// does.
     #[inline]
-    fn add(self, other: Self) -> Self {
-        self.add_mat4(&other)
+    pub fn from_mat3a(m: &Mat3A) -> Self {
+        Self {
+            x_axis: m.x_axis.extend(0.0),
+            y_axis: m.y_axis.extend(0.0),
+            z_axis: m.z_axis.extend(0.0),
+            w_axis: Vec4::unit_w(),
+        }
     }
-}
 
-impl Sub<Mat4> for Mat4 {
-    type Output = Self;
+    /// Transforms the given `Vec3A` as a 3D point, treating it as a `Vec4` with
+    /// a `w` of `1.0`.
     #[inline]
-    fn sub(self, other: Self) -> Self {
-        self.sub_mat4(&other)
+    pub fn transform_point3a(&self, other: Vec3A) -> Vec3A {
+        let mut res = Vec3A::from(self.x_axis) * other.dup_x();
+        res = Vec3A::from(self.y_axis).mul_add(other.dup_y(), res);
+        res = Vec3A::from(self.z_axis).mul_add(other.dup_z(), res);
+        // other w = 1
+        res = Vec3A::from(self.w_axis) + res;
+        res
     }
-}
 
-impl Mul<Mat4> for Mat4 {
-    type Output = Self;
+    /// Transforms the given `Vec3A` as a 3D vector, treating it as a `Vec4`
+    /// with a `w` of `0.0`.
     #[inline]
-    fn mul(self, other: Self) -> Self {
-        self.mul_mat4(&other)
+    pub fn transform_vector3a(&self, other: Vec3A) -> Vec3A {
+        let mut res = Vec3A::from(self.x_axis) * other.dup_x();
+        res = Vec3A::from(self.y_axis).mul_add(other.dup_y(), res);
+        res = Vec3A::from(self.z_axis).mul_add(other.dup_z(), res);
+        // other w = 0
+        res
     }
-}
 
-impl Mul<Vec4> for Mat4 {
-    type Output = Vec4;
+    /// Transforms the given `Vec3A` as a 3D point with a perspective divide, as
+    /// `project_point3` does.
     #[inline]
-    fn mul(self, other: Vec4) -> Vec4 {
-        self.mul_vec4(other)
+    pub fn project_point3a(&self, other: Vec3A) -> Vec3A {
+        let res = self.mul_vec4(other.extend(1.0));
+        Vec3A::from(res) / res.w()
     }
-}
 
-impl Mul<Mat4> for f32 {
-    type Output = Mat4;
-    #[inline]
-    fn mul(self, other: Mat4) -> Mat4 {
-        other.mul_scalar(self)
+    /// Returns the weighted sum of four matrices, where each element of
+    /// `weights` is the weight of the matrix at the same index.
+    ///
+    /// For linear blend skinning the weights should sum to `1.0`.
+    #[inline]
+    pub fn blend4(matrices: &[Mat4; 4], weights: Vec4) -> Self {
+        let w = [
+            weights.dup_x(),
+            weights.dup_y(),
+            weights.dup_z(),
+            weights.dup_w(),
+        ];
+        let mut res = Self {
+            x_axis: matrices[0].x_axis * w[0],
+            y_axis: matrices[0].y_axis * w[0],
+            z_axis: matrices[0].z_axis * w[0],
+            w_axis: matrices[0].w_axis * w[0],
+        };
+        for (m, w) in matrices[1..].iter().zip(w[1..].iter()) {
+            res.x_axis = m.x_axis.mul_add(*w, res.x_axis);
+            res.y_axis = m.y_axis.mul_add(*w, res.y_axis);
+            res.z_axis = m.z_axis.mul_add(*w, res.z_axis);
+            res.w_axis = m.w_axis.mul_add(*w, res.w_axis);
+        }
+        res
     }
-}
 
-impl Mul<f32> for Mat4 {
-    type Output = Self;
-    #[inline]
-    fn mul(self, other: f32) -> Self {
-        self.mul_scalar(other)
+    /// Applies linear blend skinning to a slice of `(position, normal)` pairs
+    /// in place.
+    ///
+    /// For each vertex, the four `palette` matrices indexed by `joints` are
+    /// blended by `weights` using `blend4`. The position is transformed as a
+    /// point and the normal as a vector which is then normalized. Vertices
+    /// with fewer than four influences should give the unused joints a weight
+    /// of `0.0`.
+    ///
+    /// The blended matrix is assumed to have no non-uniform scale, otherwise
+    /// normals will not remain perpendicular to the surface.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `joints`, `weights` and `vertices` are not the same length, or
+    /// if a joint index is out of bounds of `palette`.
+    pub fn skin_slice(
+        palette: &[Mat4],
+        joints: &[U16Vec4],
+        weights: &[Vec4],
+        vertices: &mut [(Vec3, Vec3)],
+    ) {
+        assert_eq!(joints.len(), vertices.len());
+        assert_eq!(weights.len(), vertices.len());
+        for ((joint, weight), (position, normal)) in
+            joints.iter().zip(weights.iter()).zip(vertices.iter_mut())
+        {
+            let j: [u16; 4] = (*joint).into();
+            let m = Self::blend4(
+                &[
+                    palette[j[0] as usize],
+                    palette[j[1] as usize],
+                    palette[j[2] as usize],
+                    palette[j[3] as usize],
+                ],
+                *weight,
+            );
+            *position = m.transform_point3_as_vec4(*position).truncate();
+            *normal = m.transform_vector3_as_vec4(*normal).truncate().normalize();
+        }
     }
 }
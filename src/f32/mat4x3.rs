@@ -0,0 +1,365 @@
+use super::{Mat3, Mat4, Quat, Vec3};
+use std::ops::{Mul, MulAssign};
+
+#[inline]
+pub fn mat4x3(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3, w_axis: Vec3) -> Mat4x3 {
+    Mat4x3::from_cols(x_axis, y_axis, z_axis, w_axis)
+}
+
+/// A column major matrix with 4 columns and 3 rows, storing a 3D affine
+/// transform in 12 floats.
+///
+/// The `x_axis`, `y_axis` and `z_axis` columns are the linear part of the
+/// transform and the `w_axis` column is the translation. This is the same as a
+/// `Mat4` whose last row is `(0, 0, 0, 1)`, which is implied rather than
+/// stored. Use `to_rows_array` to get the row major 3x4 layout commonly used to
+/// upload skinning matrix palettes to the GPU.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct Mat4x3 {
+    pub(crate) x_axis: Vec3,
+    pub(crate) y_axis: Vec3,
+    pub(crate) z_axis: Vec3,
+    pub(crate) w_axis: Vec3,
+}
+
+impl Default for Mat4x3 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl std::fmt::Display for Mat4x3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}]",
+            self.x_axis, self.y_axis, self.z_axis, self.w_axis
+        )
+    }
+}
+
+impl Mat4x3 {
+    /// Creates a new matrix with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::from_cols(Vec3::zero(), Vec3::zero(), Vec3::zero(), Vec3::zero())
+    }
+
+    /// Creates the identity transform.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_cols(Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z(), Vec3::zero())
+    }
+
+    /// Creates a new matrix from four column vectors, the last one being the
+    /// translation.
+    #[inline]
+    pub fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3, w_axis: Vec3) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Creates a new matrix from an array stored in column major order.
+    #[inline]
+    pub fn from_cols_array(m: &[f32; 12]) -> Self {
+        Self::from_cols(
+            Vec3::new(m[0], m[1], m[2]),
+            Vec3::new(m[3], m[4], m[5]),
+            Vec3::new(m[6], m[7], m[8]),
+            Vec3::new(m[9], m[10], m[11]),
+        )
+    }
+
+    /// Creates a new array storing data in column major order.
+    #[inline]
+    pub fn to_cols_array(&self) -> [f32; 12] {
+        let (m00, m01, m02) = self.x_axis.into();
+        let (m10, m11, m12) = self.y_axis.into();
+        let (m20, m21, m22) = self.z_axis.into();
+        let (m30, m31, m32) = self.w_axis.into();
+        [m00, m01, m02, m10, m11, m12, m20, m21, m22, m30, m31, m32]
+    }
+
+    /// Creates a new matrix from a 2D array stored in column major order.
+    #[inline]
+    pub fn from_cols_array_2d(m: &[[f32; 3]; 4]) -> Self {
+        Self::from_cols(m[0].into(), m[1].into(), m[2].into(), m[3].into())
+    }
+
+    /// Creates a new 2D array storing data in column major order.
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[f32; 3]; 4] {
+        [
+            self.x_axis.into(),
+            self.y_axis.into(),
+            self.z_axis.into(),
+            self.w_axis.into(),
+        ]
+    }
+
+    /// Creates a new matrix from the first 12 values in `slice`, stored in
+    /// column major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 12 elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f32]) -> Self {
+        Self::from_cols(
+            Vec3::from_slice_unaligned(&slice[0..3]),
+            Vec3::from_slice_unaligned(&slice[3..6]),
+            Vec3::from_slice_unaligned(&slice[6..9]),
+            Vec3::from_slice_unaligned(&slice[9..12]),
+        )
+    }
+
+    /// Writes the elements of `self` to the first 12 elements in `slice` in
+    /// column major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 12 elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(&self, slice: &mut [f32]) {
+        self.x_axis.write_to_slice_unaligned(&mut slice[0..3]);
+        self.y_axis.write_to_slice_unaligned(&mut slice[3..6]);
+        self.z_axis.write_to_slice_unaligned(&mut slice[6..9]);
+        self.w_axis.write_to_slice_unaligned(&mut slice[9..12]);
+    }
+
+    /// Creates a new matrix from an array of three rows of four elements, the
+    /// layout of a row major 3x4 matrix.
+    #[inline]
+    pub fn from_rows_array(m: &[f32; 12]) -> Self {
+        Self::from_cols(
+            Vec3::new(m[0], m[4], m[8]),
+            Vec3::new(m[1], m[5], m[9]),
+            Vec3::new(m[2], m[6], m[10]),
+            Vec3::new(m[3], m[7], m[11]),
+        )
+    }
+
+    /// Creates a new array storing three rows of four elements, the layout of a
+    /// row major 3x4 matrix.
+    ///
+    /// Each row is 16 bytes, so the result can be uploaded to the GPU as three
+    /// `vec4` values without any padding.
+    #[inline]
+    pub fn to_rows_array(&self) -> [f32; 12] {
+        let (m00, m01, m02) = self.x_axis.into();
+        let (m10, m11, m12) = self.y_axis.into();
+        let (m20, m21, m22) = self.z_axis.into();
+        let (m30, m31, m32) = self.w_axis.into();
+        [m00, m10, m20, m30, m01, m11, m21, m31, m02, m12, m22, m32]
+    }
+
+    /// Creates a transform from the linear part `m` and a `translation`.
+    #[inline]
+    pub fn from_mat3_translation(m: Mat3, translation: Vec3) -> Self {
+        Self::from_cols(m.x_axis, m.y_axis, m.z_axis, translation)
+    }
+
+    /// Creates a transform from the 3D affine transform `m`, dropping its last
+    /// row which is assumed to be `(0, 0, 0, 1)`.
+    #[inline]
+    pub fn from_mat4(m: &Mat4) -> Self {
+        glam_assert!(
+            m.x_axis.w() == 0.0
+                && m.y_axis.w() == 0.0
+                && m.z_axis.w() == 0.0
+                && m.w_axis.w() == 1.0
+        );
+        Self::from_cols(
+            m.x_axis.truncate(),
+            m.y_axis.truncate(),
+            m.z_axis.truncate(),
+            m.w_axis.truncate(),
+        )
+    }
+
+    /// Creates a transform that scales, rotates and translates, applied in that
+    /// order.
+    #[inline]
+    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
+        let m = Mat3::from_quat(rotation);
+        Self::from_cols(
+            m.x_axis * scale.x(),
+            m.y_axis * scale.y(),
+            m.z_axis * scale.z(),
+            translation,
+        )
+    }
+
+    /// Creates a transform that rotates and then translates.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
+        Self::from_mat3_translation(Mat3::from_quat(rotation), translation)
+    }
+
+    /// Creates a transform containing the given translation.
+    #[inline]
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self::from_cols(Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z(), translation)
+    }
+
+    #[inline]
+    pub fn x_axis(&self) -> Vec3 {
+        self.x_axis
+    }
+
+    #[inline]
+    pub fn y_axis(&self) -> Vec3 {
+        self.y_axis
+    }
+
+    #[inline]
+    pub fn z_axis(&self) -> Vec3 {
+        self.z_axis
+    }
+
+    #[inline]
+    pub fn w_axis(&self) -> Vec3 {
+        self.w_axis
+    }
+
+    /// Returns the linear part of the transform, without the translation.
+    #[inline]
+    pub fn matrix3(&self) -> Mat3 {
+        Mat3::from_cols(self.x_axis, self.y_axis, self.z_axis)
+    }
+
+    /// Returns the translation of the transform.
+    #[inline]
+    pub fn translation(&self) -> Vec3 {
+        self.w_axis
+    }
+
+    /// Returns the determinant of the linear part of the transform.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.z_axis.dot(self.x_axis.cross(self.y_axis))
+    }
+
+    /// Returns the inverse transform of `self`.
+    ///
+    /// The linear part of `self` must be invertible.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let m = self.matrix3().inverse();
+        Self::from_mat3_translation(m, -(m * self.w_axis))
+    }
+
+    /// Transforms the given 3D point, applying the linear part and then the
+    /// translation.
+    #[inline]
+    pub fn transform_point3(&self, other: Vec3) -> Vec3 {
+        self.transform_vector3(other) + self.w_axis
+    }
+
+    /// Transforms the given 3D vector, applying only the linear part.
+    #[inline]
+    pub fn transform_vector3(&self, other: Vec3) -> Vec3 {
+        self.x_axis * other.x() + self.y_axis * other.y() + self.z_axis * other.z()
+    }
+
+    /// Multiplies two transforms. The result applies `other` first and then
+    /// `self`.
+    #[inline]
+    pub fn mul_mat4x3(&self, other: &Self) -> Self {
+        Self::from_cols(
+            self.transform_vector3(other.x_axis),
+            self.transform_vector3(other.y_axis),
+            self.transform_vector3(other.z_axis),
+            self.transform_point3(other.w_axis),
+        )
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+            && self.w_axis.abs_diff_eq(other.w_axis, max_abs_diff)
+    }
+
+    /// Returns true if all elements of `self` and `other` are within
+    /// `max_abs_diff` of each other, or within `max_relative` times the larger
+    /// magnitude of the two elements.
+    #[inline]
+    pub fn relative_eq(&self, other: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        self.x_axis
+            .relative_eq(other.x_axis, max_abs_diff, max_relative)
+            && self
+                .y_axis
+                .relative_eq(other.y_axis, max_abs_diff, max_relative)
+            && self
+                .z_axis
+                .relative_eq(other.z_axis, max_abs_diff, max_relative)
+            && self
+                .w_axis
+                .relative_eq(other.w_axis, max_abs_diff, max_relative)
+    }
+
+    /// Returns true if all elements of `self` and `other` are within
+    /// `max_abs_diff` of each other, or at most `max_ulps` representable
+    /// floating point values apart.
+    #[inline]
+    pub fn ulps_eq(&self, other: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        self.x_axis.ulps_eq(other.x_axis, max_abs_diff, max_ulps)
+            && self.y_axis.ulps_eq(other.y_axis, max_abs_diff, max_ulps)
+            && self.z_axis.ulps_eq(other.z_axis, max_abs_diff, max_ulps)
+            && self.w_axis.ulps_eq(other.w_axis, max_abs_diff, max_ulps)
+    }
+}
+
+impl AsRef<[f32; 12]> for Mat4x3 {
+    #[inline]
+    fn as_ref(&self) -> &[f32; 12] {
+        unsafe { &*(self as *const Self as *const [f32; 12]) }
+    }
+}
+
+impl AsMut<[f32; 12]> for Mat4x3 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32; 12] {
+        unsafe { &mut *(self as *mut Self as *mut [f32; 12]) }
+    }
+}
+
+impl Mul<Mat4x3> for Mat4x3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat4x3(&other)
+    }
+}
+
+impl MulAssign<Mat4x3> for Mat4x3 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_mat4x3(&other);
+    }
+}
+
+impl_ref_binop!(Mul, mul, Mat4x3, Mat4x3, Mat4x3);
+impl_slice_methods!(Mat4x3, f32, 12);
+
+impl From<Mat4x3> for Mat4 {
+    #[inline]
+    fn from(m: Mat4x3) -> Self {
+        Self::from_cols(
+            m.x_axis.extend(0.0),
+            m.y_axis.extend(0.0),
+            m.z_axis.extend(0.0),
+            m.w_axis.extend(1.0),
+        )
+    }
+}
@@ -1,49 +1,123 @@
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(target_feature = "fma"),
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+mod dispatch;
+mod dualquat;
 mod funcs;
 mod mat2;
 mod mat3;
+mod mat3a;
+mod mat3x2;
 mod mat4;
+mod mat4x3;
 mod quat;
+mod quatx4;
 #[cfg(feature = "transform-types")]
 mod transform;
+mod trig;
 mod vec2;
 mod vec3;
-#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
-mod vec3_f32;
-#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
-mod vec3_sse2;
+mod vec3a;
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "scalar-math",
+    feature = "core-simd"
+))]
+mod vec3a_f32;
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+mod vec3a_sse2;
+mod vec3x4;
+mod vec3x8;
 mod vec4;
-#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+#[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
+mod vec4_core_simd;
+#[cfg(any(
+    feature = "scalar-math",
+    not(any(
+        feature = "core-simd",
+        target_feature = "sse2",
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))
+))]
 mod vec4_f32;
-#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+mod vec4_neon;
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
 mod vec4_sse2;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "scalar-math")
+))]
 mod x86_utils;
 
+pub use dualquat::*;
 pub(crate) use funcs::{scalar_acos, scalar_sin_cos};
 pub use mat2::*;
 pub use mat3::*;
+pub use mat3a::*;
+pub use mat3x2::*;
 pub use mat4::*;
+pub use mat4x3::*;
 pub use quat::*;
+pub use quatx4::*;
 #[cfg(feature = "transform-types")]
 pub use transform::*;
 pub use vec2::*;
 pub use vec3::*;
-#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
-pub use vec3_f32::*;
-#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
-pub use vec3_sse2::*;
+pub use vec3a::*;
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "scalar-math",
+    feature = "core-simd"
+))]
+pub use vec3a_f32::*;
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+pub use vec3a_sse2::*;
+pub use vec3x4::*;
+pub use vec3x8::*;
 pub use vec4::*;
-#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+#[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
+pub use vec4_core_simd::*;
+#[cfg(any(
+    feature = "scalar-math",
+    not(any(
+        feature = "core-simd",
+        target_feature = "sse2",
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))
+))]
 pub use vec4_f32::*;
-#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+pub use vec4_neon::*;
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
 pub use vec4_sse2::*;
 
 #[cfg(feature = "mint")]
 mod glam_mint;
-#[cfg(feature = "mint")]
-pub use glam_mint::*;
 
 #[cfg(feature = "serde")]
 mod glam_serde;
-#[cfg(feature = "serde")]
-pub use glam_serde::*;
@@ -0,0 +1,327 @@
+use super::{Quat, Vec3x4, Vec4};
+
+/// Four quaternions stored as a structure of arrays.
+///
+/// The `x`, `y`, `z` and `w` elements of each lane are stored in separate
+/// `Vec4`s so that an operation is applied to all four quaternions at once.
+/// This is intended for processing many rotations together, such as blending
+/// the bones of an animation pose.
+///
+/// Arithmetic uses `Vec4` and so is SIMD accelerated where `Vec4` is, but
+/// square roots and the trigonometry in `slerp` are computed per lane with
+/// scalar math.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Quatx4 {
+    x: Vec4,
+    y: Vec4,
+    z: Vec4,
+    w: Vec4,
+}
+
+#[inline]
+fn map(v: Vec4, f: impl Fn(f32) -> f32) -> Vec4 {
+    let (x, y, z, w) = v.into();
+    Vec4::new(f(x), f(y), f(z), f(w))
+}
+
+impl Quatx4 {
+    /// Creates a new `Quatx4` from arrays holding the `x`, `y`, `z` and `w`
+    /// elements of each lane.
+    #[inline]
+    pub fn new(x: [f32; 4], y: [f32; 4], z: [f32; 4], w: [f32; 4]) -> Self {
+        Self {
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+            w: w.into(),
+        }
+    }
+
+    /// Creates a new `Quatx4` with all lanes set to the identity quaternion.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::splat(Quat::identity())
+    }
+
+    /// Creates a new `Quatx4` with all lanes set to `q`.
+    #[inline]
+    pub fn splat(q: Quat) -> Self {
+        let (x, y, z, w) = q.0.into();
+        Self {
+            x: Vec4::splat(x),
+            y: Vec4::splat(y),
+            z: Vec4::splat(z),
+            w: Vec4::splat(w),
+        }
+    }
+
+    /// Returns the `x` elements of all lanes.
+    #[inline]
+    pub fn x(&self) -> [f32; 4] {
+        self.x.into()
+    }
+
+    /// Returns the `y` elements of all lanes.
+    #[inline]
+    pub fn y(&self) -> [f32; 4] {
+        self.y.into()
+    }
+
+    /// Returns the `z` elements of all lanes.
+    #[inline]
+    pub fn z(&self) -> [f32; 4] {
+        self.z.into()
+    }
+
+    /// Returns the `w` elements of all lanes.
+    #[inline]
+    pub fn w(&self) -> [f32; 4] {
+        self.w.into()
+    }
+
+    /// Returns the quaternion stored in lane `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than 3.
+    #[inline]
+    pub fn lane(&self, i: usize) -> Quat {
+        Quat::new(
+            self.x.as_ref()[i],
+            self.y.as_ref()[i],
+            self.z.as_ref()[i],
+            self.w.as_ref()[i],
+        )
+    }
+
+    /// Sets the quaternion stored in lane `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than 3.
+    #[inline]
+    pub fn set_lane(&mut self, i: usize, q: Quat) {
+        let (x, y, z, w) = q.0.into();
+        self.x.as_mut()[i] = x;
+        self.y.as_mut()[i] = y;
+        self.z.as_mut()[i] = z;
+        self.w.as_mut()[i] = w;
+    }
+
+    /// Packs the first four quaternions in `slice` into a new `Quatx4`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn from_slice(slice: &[Quat]) -> Self {
+        assert!(slice.len() >= 4);
+        let mut out = Self::identity();
+        for (i, q) in slice[..4].iter().enumerate() {
+            out.set_lane(i, *q);
+        }
+        out
+    }
+
+    /// Unpacks all lanes into the first four elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Quat]) {
+        assert!(slice.len() >= 4);
+        for (i, q) in slice[..4].iter_mut().enumerate() {
+            *q = self.lane(i);
+        }
+    }
+
+    #[inline]
+    fn dot_as_vec4(self, other: Self) -> Vec4 {
+        let mut dot = self.x * other.x;
+        dot = self.y.mul_add(other.y, dot);
+        dot = self.z.mul_add(other.z, dot);
+        self.w.mul_add(other.w, dot)
+    }
+
+    #[inline]
+    fn scale(self, s: Vec4) -> Self {
+        Self {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+            w: self.w * s,
+        }
+    }
+
+    /// Computes the dot product of each lane of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> [f32; 4] {
+        self.dot_as_vec4(other).into()
+    }
+
+    /// Computes the squared length of each lane of `self`.
+    #[inline]
+    pub fn length_squared(self) -> [f32; 4] {
+        self.dot(self)
+    }
+
+    /// Computes the length of each lane of `self`.
+    #[inline]
+    pub fn length(self) -> [f32; 4] {
+        map(self.dot_as_vec4(self), f32::sqrt).into()
+    }
+
+    /// Returns each lane of `self` normalized to length 1.0.
+    ///
+    /// For valid results, no lane of `self` may be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self.scale(map(self.dot_as_vec4(self), |d| 1.0 / d.sqrt()))
+    }
+
+    /// Returns the quaternion conjugate of each lane of `self`. For a unit
+    /// quaternion the conjugate is also the inverse.
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Returns `-1.0` for lanes where `self` and `end` are more than 90
+    /// degrees apart and `1.0` otherwise, so interpolation takes the shortest
+    /// path.
+    #[inline]
+    fn shortest_path_bias(self, end: Self) -> Vec4 {
+        Vec4::select(
+            self.dot_as_vec4(end).cmpge(Vec4::zero()),
+            Vec4::one(),
+            -Vec4::one(),
+        )
+    }
+
+    /// Performs a normalized linear interpolation between each lane of `self`
+    /// and `end` based on the value `s`, taking the shortest path.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is
+    /// `1.0`, the result will be equal to `end`.
+    #[inline]
+    pub fn nlerp(self, end: Self, s: f32) -> Self {
+        let end = end.scale(self.shortest_path_bias(end));
+        let s = Vec4::splat(s);
+        Self {
+            x: (end.x - self.x).mul_add(s, self.x),
+            y: (end.y - self.y).mul_add(s, self.y),
+            z: (end.z - self.z).mul_add(s, self.z),
+            w: (end.w - self.w).mul_add(s, self.w),
+        }
+        .normalize()
+    }
+
+    /// Performs a spherical linear interpolation between each lane of `self`
+    /// and `end` based on the value `s`, taking the shortest path.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is
+    /// `1.0`, the result will be equal to `end`. Lanes which are nearly
+    /// parallel fall back to a normalized linear interpolation.
+    #[inline]
+    pub fn slerp(self, end: Self, s: f32) -> Self {
+        const DOT_THRESHOLD: f32 = 0.9995;
+        let end = end.scale(self.shortest_path_bias(end));
+        let dot: [f32; 4] = self.dot_as_vec4(end).into();
+        let mut a = [1.0 - s; 4];
+        let mut b = [s; 4];
+        for ((dot, a), b) in dot.iter().zip(a.iter_mut()).zip(b.iter_mut()) {
+            if *dot < DOT_THRESHOLD {
+                let theta = dot.acos();
+                let inv_sin_theta = 1.0 / theta.sin();
+                *a = (theta * (1.0 - s)).sin() * inv_sin_theta;
+                *b = (theta * s).sin() * inv_sin_theta;
+            }
+        }
+        let b = Vec4::from(b);
+        let interpolated = self.scale(a.into());
+        Self {
+            x: end.x.mul_add(b, interpolated.x),
+            y: end.y.mul_add(b, interpolated.y),
+            z: end.z.mul_add(b, interpolated.z),
+            w: end.w.mul_add(b, interpolated.w),
+        }
+        .normalize()
+    }
+
+    /// Multiplies each lane of `self` and `other`.
+    /// Note that due to floating point rounding the result may not be perfectly normalized.
+    #[inline]
+    pub fn mul_quat(self, other: Self) -> Self {
+        let (x0, y0, z0, w0) = (self.x, self.y, self.z, self.w);
+        let (x1, y1, z1, w1) = (other.x, other.y, other.z, other.w);
+        Self {
+            x: w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+            y: w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+            z: w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+            w: w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+        }
+    }
+
+    /// Rotates each lane of `other` by the corresponding lane of `self`.
+    #[inline]
+    pub fn mul_vec3x4(self, other: Vec3x4) -> Vec3x4 {
+        // v' = v + w * t + b x t where t = 2 * (b x v)
+        let (bx, by, bz, w) = (self.x, self.y, self.z, self.w);
+        let (vx, vy, vz) = (other.x, other.y, other.z);
+        let two = Vec4::splat(2.0);
+        let tx = (by * vz - bz * vy) * two;
+        let ty = (bz * vx - bx * vz) * two;
+        let tz = (bx * vy - by * vx) * two;
+        Vec3x4 {
+            x: tx.mul_add(w, vx) + (by * tz - bz * ty),
+            y: ty.mul_add(w, vy) + (bz * tx - bx * tz),
+            z: tz.mul_add(w, vz) + (bx * ty - by * tx),
+        }
+    }
+}
+
+impl Default for Quatx4 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<[Quat; 4]> for Quatx4 {
+    #[inline]
+    fn from(a: [Quat; 4]) -> Self {
+        Self::from_slice(&a)
+    }
+}
+
+impl From<Quatx4> for [Quat; 4] {
+    #[inline]
+    fn from(q: Quatx4) -> Self {
+        let mut out = [Quat::identity(); 4];
+        q.write_to_slice(&mut out);
+        out
+    }
+}
+
+impl std::ops::Mul<Quatx4> for Quatx4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_quat(other)
+    }
+}
+
+impl std::ops::Mul<Vec3x4> for Quatx4 {
+    type Output = Vec3x4;
+    #[inline]
+    fn mul(self, other: Vec3x4) -> Vec3x4 {
+        self.mul_vec3x4(other)
+    }
+}
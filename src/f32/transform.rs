@@ -124,7 +124,7 @@ fn mul_srt_srt(lhs: &TransformSRT, rhs: &TransformSRT) -> TransformSRT {
         result_mtx
             .set_z_axis((result_mtx.z_axis().truncate().normalize() * sign.dup_z()).extend(0.0));
 
-        let rotation = Quat::from_rotation_mat4(&result_mtx);
+        let rotation = Quat::from_mat4(&result_mtx);
         let translation = result_mtx.w_axis().truncate();
         TransformSRT {
             scale,
@@ -279,9 +279,9 @@ impl Distribution<TransformRT> for Standard {
         TransformRT::new(
             rng.gen::<Quat>(),
             Vec3::new(
-                rng.gen_range(std::f32::MIN, std::f32::MAX),
-                rng.gen_range(std::f32::MIN, std::f32::MAX),
-                rng.gen_range(std::f32::MIN, std::f32::MAX),
+                rng.gen_range(f32::MIN, f32::MAX),
+                rng.gen_range(f32::MIN, f32::MAX),
+                rng.gen_range(f32::MIN, f32::MAX),
             ),
         )
     }
@@ -292,8 +292,8 @@ impl Distribution<TransformSRT> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TransformSRT {
         let mut gen_non_zero = || loop {
-            let f: f32 = rng.gen_range(std::f32::MIN, std::f32::MAX);
-            if f.abs() > std::f32::MIN_POSITIVE {
+            let f: f32 = rng.gen_range(f32::MIN, f32::MAX);
+            if f.abs() > f32::MIN_POSITIVE {
                 return f;
             }
         };
@@ -301,9 +301,9 @@ impl Distribution<TransformSRT> for Standard {
             Vec3::new(gen_non_zero(), gen_non_zero(), gen_non_zero()),
             rng.gen::<Quat>(),
             Vec3::new(
-                rng.gen_range(std::f32::MIN, std::f32::MAX),
-                rng.gen_range(std::f32::MIN, std::f32::MAX),
-                rng.gen_range(std::f32::MIN, std::f32::MAX),
+                rng.gen_range(f32::MIN, f32::MAX),
+                rng.gen_range(f32::MIN, f32::MAX),
+                rng.gen_range(f32::MIN, f32::MAX),
             ),
         )
     }
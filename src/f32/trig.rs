@@ -0,0 +1,273 @@
+// Element-wise trigonometric functions for the `f32` vector types.
+//
+// `sin_cos` is based on the Cephes polynomials used by
+// http://gruntthepeon.free.fr/ssemath/sse_mathfun.h and `atan2` on the Cephes
+// `atanf` polynomial. Both are written in terms of `Vec4` operations so that
+// every lane is computed at once on each SIMD backend. `Vec2`, `Vec3` and
+// `Vec3A` are extended to a `Vec4` for the computation.
+use super::{Vec2, Vec3, Vec3A, Vec4};
+use crate::BVec4;
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+// 4 / PI
+const CEPHES_FOPI: f32 = 1.273_239_5;
+// PI / 4 split into three parts for extended precision range reduction
+const MINUS_CEPHES_DP1: f32 = -0.785_156_25;
+const MINUS_CEPHES_DP2: f32 = -2.418_756_5e-4;
+const MINUS_CEPHES_DP3: f32 = -3.774_895e-8;
+const SINCOF_P0: f32 = -1.951_529_6e-4;
+const SINCOF_P1: f32 = 8.332_161e-3;
+const SINCOF_P2: f32 = -1.666_665_5e-1;
+const COSCOF_P0: f32 = 2.443_315_7e-5;
+const COSCOF_P1: f32 = -1.388_731_6e-3;
+const COSCOF_P2: f32 = 4.166_664_6e-2;
+const ATANCOF_P0: f32 = 8.053_744_5e-2;
+const ATANCOF_P1: f32 = -1.387_768_6e-1;
+const ATANCOF_P2: f32 = 1.997_771_1e-1;
+const ATANCOF_P3: f32 = -3.333_295e-1;
+// tan(3 * PI / 8) and tan(PI / 8)
+const TAN_3PI_8: f32 = 2.414_213_5;
+const TAN_PI_8: f32 = 0.414_213_57;
+
+impl Vec4 {
+    /// Rounds each element of `self` down. Only correct for elements in the
+    /// range `[0, 2^23)`, which is all the range reduction below needs.
+    #[inline]
+    fn floor_positive(self) -> Self {
+        // adding and subtracting 2^23 rounds to the nearest integer
+        let magic = Vec4::splat(8_388_608.0);
+        let rounded = (self + magic) - magic;
+        Vec4::select(rounded.cmpgt(self), rounded - Vec4::one(), rounded)
+    }
+
+    /// Returns a mask of the elements of `self` that have their sign bit set,
+    /// including `-0.0`.
+    #[inline]
+    fn is_sign_negative(self) -> BVec4 {
+        let zero = Vec4::zero();
+        // 1.0 / -0.0 is negative infinity
+        self.cmplt(zero) | (self.cmpeq(zero) & (Vec4::one() / self).cmplt(zero))
+    }
+
+    /// Returns a vector containing the sine and a vector containing the cosine
+    /// of each element of `self`, in radians.
+    ///
+    /// The precision is similar to `f32::sin_cos` for elements in the range
+    /// `[-8192, 8192]` and degrades for larger magnitudes.
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let one = Vec4::one();
+        let x = self.abs();
+
+        // the octant of each element, rounded up to an even number so that
+        // the reduced angle is in [-PI/4, PI/4]
+        let j = (x * CEPHES_FOPI).floor_positive();
+        let j = ((j + one) * 0.5).floor_positive() * 2.0;
+        let octant = j - (j * 0.125).floor_positive() * 8.0;
+
+        // extended precision modular arithmetic
+        let x = j.mul_add(Vec4::splat(MINUS_CEPHES_DP1), x);
+        let x = j.mul_add(Vec4::splat(MINUS_CEPHES_DP2), x);
+        let x = j.mul_add(Vec4::splat(MINUS_CEPHES_DP3), x);
+
+        let z = x * x;
+        let mut cos_poly = z.mul_add(Vec4::splat(COSCOF_P0), Vec4::splat(COSCOF_P1));
+        cos_poly = cos_poly.mul_add(z, Vec4::splat(COSCOF_P2));
+        cos_poly = (cos_poly * z * z).mul_add(one, one - z * 0.5);
+        let mut sin_poly = z.mul_add(Vec4::splat(SINCOF_P0), Vec4::splat(SINCOF_P1));
+        sin_poly = sin_poly.mul_add(z, Vec4::splat(SINCOF_P2));
+        sin_poly = (sin_poly * z).mul_add(x, x);
+
+        // octants 2 and 6 swap the polynomials, octants 4 and 6 negate the
+        // sine and octants 2 and 4 negate the cosine
+        let two = Vec4::splat(2.0);
+        let four = Vec4::splat(4.0);
+        let swap = octant.cmpeq(two) | octant.cmpeq(Vec4::splat(6.0));
+        let sin = Vec4::select(swap, cos_poly, sin_poly);
+        let cos = Vec4::select(swap, sin_poly, cos_poly);
+        let negate_sin = octant.cmpge(four) ^ self.is_sign_negative();
+        let negate_cos = octant.cmpeq(two) | octant.cmpeq(four);
+        (
+            Vec4::select(negate_sin, -sin, sin),
+            Vec4::select(negate_cos, -cos, cos),
+        )
+    }
+
+    /// Returns the sine of each element of `self`, in radians.
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Returns the cosine of each element of `self`, in radians.
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Returns the tangent of each element of `self`, in radians.
+    #[inline]
+    pub fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        sin / cos
+    }
+
+    /// Returns the four quadrant arctangent of each element of `self` and
+    /// `other`, in radians, where `self` holds the `y` coordinates and `other`
+    /// the `x` coordinates.
+    ///
+    /// Like `f32::atan2` the results are in the range `[-PI, PI]`. Infinite
+    /// inputs are not handled specially.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        let zero = Vec4::zero();
+        let one = Vec4::one();
+        let y_abs = self.abs();
+        let x_abs = other.abs();
+
+        // atan(t) for t = |y| / |x| in [0, inf], reduced to [0, tan(PI / 8)]
+        let t = y_abs / x_abs;
+        let large = t.cmpgt(Vec4::splat(TAN_3PI_8));
+        let medium = t.cmpgt(Vec4::splat(TAN_PI_8)) & !large;
+        let base = Vec4::select(
+            large,
+            Vec4::splat(FRAC_PI_2),
+            Vec4::select(medium, Vec4::splat(FRAC_PI_4), zero),
+        );
+        let t = Vec4::select(
+            large,
+            -one / t,
+            Vec4::select(medium, (t - one) / (t + one), t),
+        );
+        let z = t * t;
+        let mut poly = z.mul_add(Vec4::splat(ATANCOF_P0), Vec4::splat(ATANCOF_P1));
+        poly = poly.mul_add(z, Vec4::splat(ATANCOF_P2));
+        poly = poly.mul_add(z, Vec4::splat(ATANCOF_P3));
+        let angle = (poly * z).mul_add(t, t) + base;
+
+        // atan2(0, 0) is 0 rather than the NaN from 0 / 0
+        let angle = Vec4::select(y_abs.cmpeq(zero), zero, angle);
+        // mirror into the left half plane and below the x axis, counting -0.0
+        // as negative like `f32::atan2`
+        let angle = Vec4::select(other.is_sign_negative(), Vec4::splat(PI) - angle, angle);
+        Vec4::select(self.is_sign_negative(), -angle, angle)
+    }
+}
+
+impl Vec3A {
+    /// Returns a vector containing the sine and a vector containing the cosine
+    /// of each element of `self`, in radians.
+    ///
+    /// The precision is similar to `f32::sin_cos` for elements in the range
+    /// `[-8192, 8192]` and degrades for larger magnitudes.
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.extend(0.0).sin_cos();
+        (sin.into(), cos.into())
+    }
+
+    /// Returns the sine of each element of `self`, in radians.
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.extend(0.0).sin().into()
+    }
+
+    /// Returns the cosine of each element of `self`, in radians.
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.extend(0.0).cos().into()
+    }
+
+    /// Returns the tangent of each element of `self`, in radians.
+    #[inline]
+    pub fn tan(self) -> Self {
+        self.extend(0.0).tan().into()
+    }
+
+    /// Returns the four quadrant arctangent of each element of `self` and
+    /// `other`, in radians, where `self` holds the `y` coordinates and `other`
+    /// the `x` coordinates.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        self.extend(0.0).atan2(other.extend(1.0)).into()
+    }
+}
+
+impl Vec3 {
+    /// Returns a vector containing the sine and a vector containing the cosine
+    /// of each element of `self`, in radians.
+    ///
+    /// The precision is similar to `f32::sin_cos` for elements in the range
+    /// `[-8192, 8192]` and degrades for larger magnitudes.
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.extend(0.0).sin_cos();
+        (sin.truncate(), cos.truncate())
+    }
+
+    /// Returns the sine of each element of `self`, in radians.
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.extend(0.0).sin().truncate()
+    }
+
+    /// Returns the cosine of each element of `self`, in radians.
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.extend(0.0).cos().truncate()
+    }
+
+    /// Returns the tangent of each element of `self`, in radians.
+    #[inline]
+    pub fn tan(self) -> Self {
+        self.extend(0.0).tan().truncate()
+    }
+
+    /// Returns the four quadrant arctangent of each element of `self` and
+    /// `other`, in radians, where `self` holds the `y` coordinates and `other`
+    /// the `x` coordinates.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        self.extend(0.0).atan2(other.extend(1.0)).truncate()
+    }
+}
+
+impl Vec2 {
+    /// Returns a vector containing the sine and a vector containing the cosine
+    /// of each element of `self`, in radians.
+    ///
+    /// The precision is similar to `f32::sin_cos` for elements in the range
+    /// `[-8192, 8192]` and degrades for larger magnitudes.
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.extend(0.0).extend(0.0).sin_cos();
+        (sin.truncate().truncate(), cos.truncate().truncate())
+    }
+
+    /// Returns the sine of each element of `self`, in radians.
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.extend(0.0).extend(0.0).sin().truncate().truncate()
+    }
+
+    /// Returns the cosine of each element of `self`, in radians.
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.extend(0.0).extend(0.0).cos().truncate().truncate()
+    }
+
+    /// Returns the tangent of each element of `self`, in radians.
+    #[inline]
+    pub fn tan(self) -> Self {
+        self.extend(0.0).extend(0.0).tan().truncate().truncate()
+    }
+
+    /// Returns the four quadrant arctangent of each element of `self` and
+    /// `other`, in radians, where `self` holds the `y` coordinates and `other`
+    /// the `x` coordinates.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        let x = other.extend(1.0).extend(1.0);
+        self.extend(0.0).extend(0.0).atan2(x).truncate().truncate()
+    }
+}
@@ -1,72 +1,55 @@
-use super::Vec3;
+#![allow(dead_code)]
+
+use crate::{
+    bool::BVec3,
+    f32::{Mat3, Vec2, Vec4},
+    f64::DVec3,
+    i16::I16Vec3,
+    i32::IVec3,
+    i64::I64Vec3,
+    u16::U16Vec3,
+    u32::UVec3,
+    u64::U64Vec3,
+};
+
+/// A 3-dimensional vector.
+///
+/// This type is stored as three packed `f32` values and is 4 byte aligned. Use
+/// `Vec3A` for a 16 byte aligned 3D vector which can take advantage of SIMD.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(C)]
+pub struct Vec3(f32, f32, f32);
 
 #[inline]
 pub fn vec3(x: f32, y: f32, z: f32) -> Vec3 {
     Vec3::new(x, y, z)
 }
 
-impl Vec3 {
-    /// Returns a new `Vec4` with elements representing the sign of `self`.
-    ///
-    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
-    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
-    #[inline]
-    pub fn sign(self) -> Self {
-        let mask = self.cmpge(Self::zero());
-        mask.select(Self::splat(1.0), Self::splat(-1.0))
-    }
-
-    /// Computes the reciprocal `1.0/n` of each element, returning the
-    /// results in a new `Vec3`.
-    #[inline]
-    pub fn reciprocal(self) -> Self {
-        // TODO: Optimize
-        Self::one() / self
-    }
-
-    /// Performs a linear interpolation between `self` and `other` based on
-    /// the value `s`.
-    ///
-    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
-    /// is `1.0`, the result will be equal to `other`.
-    #[inline]
-    pub fn lerp(self, other: Self, s: f32) -> Self {
-        self + ((other - self) * s)
-    }
-
-    /// Returns whether `self` of length `1.0` or not.
-    ///
-    /// Uses a precision threshold of `1e-6`.
-    #[inline]
-    pub fn is_normalized(self) -> bool {
-        is_normalized!(self)
-    }
-
-    /// Returns true if the absolute difference of all elements between `self`
-    /// and `other` is less than or equal to `max_abs_diff`.
-    ///
-    /// This can be used to compare if two `Vec3`'s contain similar elements. It
-    /// works best when comparing with a known value. The `max_abs_diff` that
-    /// should be used used depends on the values being compared against.
-    ///
-    /// For more on floating point comparisons see
-    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
-    #[inline]
-    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
-        abs_diff_eq!(self, other, max_abs_diff)
-    }
-}
-
-impl AsRef<[f32; 3]> for Vec3 {
-    #[inline]
-    fn as_ref(&self) -> &[f32; 3] {
-        unsafe { &*(self as *const Vec3 as *const [f32; 3]) }
-    }
-}
-
-impl AsMut<[f32; 3]> for Vec3 {
-    #[inline]
-    fn as_mut(&mut self) -> &mut [f32; 3] {
-        unsafe { &mut *(self as *mut Vec3 as *mut [f32; 3]) }
-    }
-}
+impl_vec3_float_methods!(Vec3, f32, Mat3, BVec3);
+impl_vec3_scalar!(Vec3, f32, BVec3, Vec2, Vec4);
+impl_vec3_as!(
+    Vec3,
+    as_dvec3 => DVec3, f64;
+    as_i16vec3 => I16Vec3, i16;
+    as_u16vec3 => U16Vec3, u16;
+    as_ivec3 => IVec3, i32;
+    as_uvec3 => UVec3, u32;
+    as_i64vec3 => I64Vec3, i64;
+    as_u64vec3 => U64Vec3, u64;
+);
+impl_vec3_try_as!(
+    Vec3,
+    f32,
+    try_as_i16vec3 => I16Vec3, i16;
+    try_as_u16vec3 => U16Vec3, u16;
+    try_as_ivec3 => IVec3, i32;
+    try_as_uvec3 => UVec3, u32;
+    try_as_i64vec3 => I64Vec3, i64;
+    try_as_u64vec3 => U64Vec3, u64;
+);
+
+/// A 3-dimensional vector mask.
+///
+/// This is an alias for `BVec3`.
+#[deprecated(since = "0.9.0", note = "please use `BVec3` instead")]
+pub type Vec3Mask = BVec3;
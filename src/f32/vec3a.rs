@@ -0,0 +1,47 @@
+use super::{Mat3A, Vec3, Vec3A};
+use crate::{
+    bool::BVec3, f64::DVec3, i16::I16Vec3, i32::IVec3, i64::I64Vec3, u16::U16Vec3, u32::UVec3,
+    u64::U64Vec3,
+};
+
+#[inline]
+pub fn vec3a(x: f32, y: f32, z: f32) -> Vec3A {
+    Vec3A::new(x, y, z)
+}
+
+impl_vec3_float_methods!(Vec3A, f32, Mat3A, BVec3);
+impl_vec3_as!(
+    Vec3A,
+    as_dvec3 => DVec3, f64;
+    as_i16vec3 => I16Vec3, i16;
+    as_u16vec3 => U16Vec3, u16;
+    as_ivec3 => IVec3, i32;
+    as_uvec3 => UVec3, u32;
+    as_i64vec3 => I64Vec3, i64;
+    as_u64vec3 => U64Vec3, u64;
+);
+impl_vec3_try_as!(
+    Vec3A,
+    f32,
+    try_as_i16vec3 => I16Vec3, i16;
+    try_as_u16vec3 => U16Vec3, u16;
+    try_as_ivec3 => IVec3, i32;
+    try_as_uvec3 => UVec3, u32;
+    try_as_i64vec3 => I64Vec3, i64;
+    try_as_u64vec3 => U64Vec3, u64;
+);
+
+impl From<Vec3> for Vec3A {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x(), v.y(), v.z())
+    }
+}
+
+impl From<Vec3A> for Vec3 {
+    #[inline]
+    fn from(v: Vec3A) -> Self {
+        let (x, y, z) = v.into();
+        Self::new(x, y, z)
+    }
+}
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use crate::{
+    bool::BVec3,
+    f32::{Vec2, Vec4},
+};
+
+/// A 3-dimensional vector.
+///
+/// This type is 16 byte aligned and thus contains 4 bytes padding. This is the
+/// scalar fallback used when SSE2 is not available.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(C, align(16))]
+pub struct Vec3A(f32, f32, f32);
+
+impl_vec3_scalar!(Vec3A, f32, BVec3, Vec2, Vec4);
+
+impl From<Vec4> for Vec3A {
+    /// Creates a `Vec3A` from the first three elements of the `Vec4`,
+    /// discarding `w`.
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        Self(v.x(), v.y(), v.z())
+    }
+}
@@ -7,6 +7,7 @@ use rand::{
 };
 
 use crate::{
+    bool::BVec3,
     f32::{Vec2, Vec4, X_AXIS, Y_AXIS, Z_AXIS},
     Align16,
 };
@@ -16,35 +17,38 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use super::x86_utils::{m128_mul_add, m128_neg_mul_sub, m128_rsqrt_nr};
 use std::{cmp::Ordering, f32, fmt, mem::MaybeUninit, ops::*};
 
 /// A 3-dimensional vector.
 ///
-/// This type is 16 byte aligned and thus contains 4 bytes padding.
+/// This type is 16 byte aligned and thus contains 4 bytes padding. It is
+/// stored in a SIMD register which makes it faster than `Vec3` for most
+/// operations.
 #[derive(Clone, Copy, Debug)]
 #[repr(C)]
-pub struct Vec3(pub(crate) __m128);
+pub struct Vec3A(pub(crate) __m128);
 
-impl Vec3 {
-    /// Creates a new `Vec3`.
+impl Vec3A {
+    /// Creates a new `Vec3A`.
     #[inline]
     pub fn new(x: f32, y: f32, z: f32) -> Self {
         unsafe { Self(_mm_set_ps(z, z, y, x)) }
     }
 
-    /// Creates a new `Vec3` with all elements set to `0.0`.
+    /// Creates a new `Vec3A` with all elements set to `0.0`.
     #[inline]
     pub fn zero() -> Self {
         unsafe { Self(_mm_setzero_ps()) }
     }
 
-    /// Creates a new `Vec3` with all elements set to `1.0`.
+    /// Creates a new `Vec3A` with all elements set to `1.0`.
     #[inline]
     pub fn one() -> Self {
         unsafe { Self(_mm_set1_ps(1.0)) }
     }
 
-    /// Creates a new `Vec3` with values `[x: 1.0, y: 0.0, z: 0.0]`.
+    /// Creates a new `Vec3A` with values `[x: 1.0, y: 0.0, z: 0.0]`.
     #[inline]
     pub fn unit_x() -> Self {
         unsafe {
@@ -54,7 +58,7 @@ impl Vec3 {
         }
     }
 
-    /// Creates a new `Vec3` with values `[x: 0.0, y: 1.0, z: 0.0]`.
+    /// Creates a new `Vec3A` with values `[x: 0.0, y: 1.0, z: 0.0]`.
     #[inline]
     pub fn unit_y() -> Self {
         unsafe {
@@ -64,7 +68,7 @@ impl Vec3 {
         }
     }
 
-    /// Creates a new `Vec3` with values `[x: 0.0, y: 0.0, z: 1.0]`.
+    /// Creates a new `Vec3A` with values `[x: 0.0, y: 0.0, z: 1.0]`.
     #[inline]
     pub fn unit_z() -> Self {
         unsafe {
@@ -74,7 +78,7 @@ impl Vec3 {
         }
     }
 
-    /// Creates a new `Vec3` with all elements set to `v`.
+    /// Creates a new `Vec3A` with all elements set to `v`.
     #[inline]
     pub fn splat(v: f32) -> Self {
         unsafe { Self(_mm_set_ps1(v)) }
@@ -96,6 +100,13 @@ impl Vec3 {
         Vec2::new(x, y)
     }
 
+    /// Returns the `x` and `y` elements of `self` as a new vector, the same as
+    /// `truncate`.
+    #[inline]
+    pub fn xy(self) -> Vec2 {
+        self.truncate()
+    }
+
     /// Returns element `x`.
     #[inline]
     pub fn x(self) -> f32 {
@@ -142,25 +153,25 @@ impl Vec3 {
         }
     }
 
-    /// Returns a `Vec3` with all elements set to the value of element `x`.
+    /// Returns a `Vec3A` with all elements set to the value of element `x`.
     #[inline]
     pub(crate) fn dup_x(self) -> Self {
         unsafe { Self(_mm_shuffle_ps(self.0, self.0, 0b00_00_00_00)) }
     }
 
-    /// Returns a `Vec3` with all elements set to the value of element `y`.
+    /// Returns a `Vec3A` with all elements set to the value of element `y`.
     #[inline]
     pub(crate) fn dup_y(self) -> Self {
         unsafe { Self(_mm_shuffle_ps(self.0, self.0, 0b01_01_01_01)) }
     }
 
-    /// Returns a `Vec3` with all elements set to the value of element `z`.
+    /// Returns a `Vec3A` with all elements set to the value of element `z`.
     #[inline]
     pub(crate) fn dup_z(self) -> Self {
         unsafe { Self(_mm_shuffle_ps(self.0, self.0, 0b10_10_10_10)) }
     }
 
-    /// Calculates the Vec3 dot product and returns answer in x lane of __m128.
+    /// Calculates the Vec3A dot product and returns answer in x lane of __m128.
     #[inline]
     unsafe fn dot_as_m128(self, other: Self) -> __m128 {
         let x2_y2_z2_w2 = _mm_mul_ps(self.0, other.0);
@@ -170,12 +181,16 @@ impl Vec3 {
         _mm_add_ss(x2y2_0_0_0, z2_0_0_0)
     }
 
-    /// Returns Vec3 dot in all lanes of Vec3
+    /// Computes the dot product of `self` and `other`, returning the result in
+    /// all elements of a vector.
+    ///
+    /// This keeps the result in SIMD registers when it is used in further
+    /// vector arithmetic such as normalization.
     #[inline]
-    pub(crate) fn dot_as_vec3(self, other: Self) -> Self {
+    pub fn dot_into_vec(self, other: Self) -> Self {
         unsafe {
             let dot_in_x = self.dot_as_m128(other);
-            Vec3(_mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00))
+            Vec3A(_mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00))
         }
     }
 
@@ -206,38 +221,64 @@ impl Vec3 {
     /// Computes the length of `self`.
     #[inline]
     pub fn length(self) -> f32 {
-        let dot = self.dot_as_vec3(self);
+        let dot = self.dot_into_vec(self);
         unsafe { _mm_cvtss_f32(_mm_sqrt_ps(dot.0)) }
     }
 
     /// Computes the squared length of `self`.
     ///
-    /// This is generally faster than `Vec3::length()` as it avoids a square
+    /// This is generally faster than `Vec3A::length()` as it avoids a square
     /// root operation.
     #[inline]
     pub fn length_squared(self) -> f32 {
         self.dot(self)
     }
 
-    /// Computes `1.0 / Vec3::length()`.
+    /// Computes `1.0 / Vec3A::length()`.
     ///
     /// For valid results, `self` must _not_ be of length zero.
     #[inline]
     pub fn length_reciprocal(self) -> f32 {
-        let dot = self.dot_as_vec3(self);
+        let dot = self.dot_into_vec(self);
         unsafe {
             // _mm_rsqrt_ps is lower precision
             _mm_cvtss_f32(_mm_div_ps(_mm_set_ps1(1.0), _mm_sqrt_ps(dot.0)))
         }
     }
 
+    /// Computes an approximation of `1.0 / Vec3A::length()`.
+    ///
+    /// This refines the `_mm_rsqrt_ps` estimate with one Newton-Raphson step,
+    /// which is faster than `length_reciprocal` at the cost of a small loss of
+    /// precision.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f32 {
+        let dot = self.dot_into_vec(self);
+        unsafe { _mm_cvtss_f32(m128_rsqrt_nr(dot.0)) }
+    }
+
     /// Returns `self` normalized to length 1.0.
     ///
     /// For valid results, `self` must _not_ be of length zero.
+    ///
+    /// This scales `self` by `length_recip`, so the result can differ from
+    /// `self / self.length()` in the last bits. Vectors whose squared length is
+    /// zero, denormal or overflows are divided by `length()` instead.
     #[inline]
     pub fn normalize(self) -> Self {
-        let dot = self.dot_as_vec3(self);
-        unsafe { Self(_mm_div_ps(self.0, _mm_sqrt_ps(dot.0))) }
+        let dot = self.dot_into_vec(self);
+        // The estimate is infinite for a zero or denormal squared length and zero
+        // for an infinite one, so those use the exact `sqrt` and division.
+        let exact = dot.cmplt(Self::splat(f32::MIN_POSITIVE)) | dot.cmpge(Self::splat(f32::MAX));
+        unsafe {
+            Self::select(
+                exact,
+                Self(_mm_div_ps(self.0, _mm_sqrt_ps(dot.0))),
+                Self(_mm_mul_ps(self.0, m128_rsqrt_nr(dot.0))),
+            )
+        }
     }
 
     /// Returns the vertical minimum of `self` and `other`.
@@ -287,74 +328,127 @@ impl Vec3 {
     }
 
     /// Performs a vertical `==` comparison between `self` and `other`,
-    /// returning a `Vec3Mask` of the results.
+    /// returning a `BVec3` of the results.
     ///
     /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2, w1 == w2]`.
     #[inline]
-    pub fn cmpeq(self, other: Self) -> Vec3Mask {
-        unsafe { Vec3Mask(_mm_cmpeq_ps(self.0, other.0)) }
+    pub fn cmpeq(self, other: Self) -> BVec3 {
+        unsafe { BVec3(_mm_cmpeq_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `!=` comparison between `self` and `other`,
-    /// returning a `Vec3Mask` of the results.
+    /// returning a `BVec3` of the results.
     ///
     /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2, w1 != w2]`.
     #[inline]
-    pub fn cmpne(self, other: Self) -> Vec3Mask {
-        unsafe { Vec3Mask(_mm_cmpneq_ps(self.0, other.0)) }
+    pub fn cmpne(self, other: Self) -> BVec3 {
+        unsafe { BVec3(_mm_cmpneq_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `>=` comparison between `self` and `other`,
-    /// returning a `Vec3Mask` of the results.
+    /// returning a `BVec3` of the results.
     ///
     /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2, w1 >= w2]`.
     #[inline]
-    pub fn cmpge(self, other: Self) -> Vec3Mask {
-        unsafe { Vec3Mask(_mm_cmpge_ps(self.0, other.0)) }
+    pub fn cmpge(self, other: Self) -> BVec3 {
+        unsafe { BVec3(_mm_cmpge_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `>` comparison between `self` and `other`,
-    /// returning a `Vec3Mask` of the results.
+    /// returning a `BVec3` of the results.
     ///
     /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2, w1 > w2]`.
     #[inline]
-    pub fn cmpgt(self, other: Self) -> Vec3Mask {
-        unsafe { Vec3Mask(_mm_cmpgt_ps(self.0, other.0)) }
+    pub fn cmpgt(self, other: Self) -> BVec3 {
+        unsafe { BVec3(_mm_cmpgt_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `<=` comparison between `self` and `other`,
-    /// returning a `Vec3Mask` of the results.
+    /// returning a `BVec3` of the results.
     ///
     /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2, w1 <= w2]`.
     #[inline]
-    pub fn cmple(self, other: Self) -> Vec3Mask {
-        unsafe { Vec3Mask(_mm_cmple_ps(self.0, other.0)) }
+    pub fn cmple(self, other: Self) -> BVec3 {
+        unsafe { BVec3(_mm_cmple_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `<` comparison between `self` and `other`,
-    /// returning a `Vec3Mask` of the results.
+    /// returning a `BVec3` of the results.
     ///
     /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2, w1 < w2]`.
     #[inline]
-    pub fn cmplt(self, other: Self) -> Vec3Mask {
-        unsafe { Vec3Mask(_mm_cmplt_ps(self.0, other.0)) }
+    pub fn cmplt(self, other: Self) -> BVec3 {
+        unsafe { BVec3(_mm_cmplt_ps(self.0, other.0)) }
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on `mask`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec3, if_true: Self, if_false: Self) -> Self {
+        unsafe {
+            Self(_mm_or_ps(
+                _mm_andnot_ps(mask.0, if_false.0),
+                _mm_and_ps(if_true.0, mask.0),
+            ))
+        }
     }
 
-    /// Per element multiplication/addition of the three inputs: b + (self * a)
+    /// Creates a new vector from the first three values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than three elements long.
     #[inline]
-    pub(crate) fn mul_add(self, a: Self, b: Self) -> Self {
-        unsafe { Self(_mm_add_ps(_mm_mul_ps(self.0, a.0), b.0)) }
+    pub fn from_slice_unaligned(slice: &[f32]) -> Self {
+        Self::new(slice[0], slice[1], slice[2])
+    }
+
+    /// Writes the elements of `self` to the first three elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than three elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f32]) {
+        let (x, y, z) = self.into();
+        slice[0] = x;
+        slice[1] = y;
+        slice[2] = z;
+    }
+
+    /// Computes `(self * a) + b` element-wise.
+    ///
+    /// This uses a fused multiply-add instruction, which rounds only once,
+    /// when the target supports it, for example when the `fma` target
+    /// feature is enabled on x86. Otherwise the multiplication and addition
+    /// are rounded separately.
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        unsafe { Self(m128_mul_add(self.0, a.0, b.0)) }
     }
 
     /// Per element negative multiplication/subtraction of the three inputs `-((self * a) - b)`
     /// This is mathematically equivalent to `b - (self * a)`
     #[inline]
     pub(crate) fn neg_mul_sub(self, a: Self, b: Self) -> Self {
-        unsafe { Self(_mm_sub_ps(b.0, _mm_mul_ps(self.0, a.0))) }
+        unsafe { Self(m128_neg_mul_sub(self.0, a.0, b.0)) }
+    }
+
+    /// Returns a new `Vec3A` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    #[inline]
+    pub fn sign(self) -> Self {
+        let mask = self.cmpge(Self::zero());
+        Self::select(mask, Self::splat(1.0), Self::splat(-1.0))
     }
 
-    /// Returns a new `Vec3` containing the absolute value of each element of the original
-    /// `Vec3`.
+    /// Returns a new `Vec3A` containing the absolute value of each element of the original
+    /// `Vec3A`.
     #[inline]
     pub fn abs(self) -> Self {
         unsafe {
@@ -364,16 +458,23 @@ impl Vec3 {
             ))
         }
     }
+
+    /// Returns a new vector containing the square root of each element of
+    /// the original vector.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        unsafe { Self(_mm_sqrt_ps(self.0)) }
+    }
 }
 
-impl fmt::Display for Vec3 {
+impl fmt::Display for Vec3A {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let (x, y, z) = (*self).into();
         write!(f, "[{}, {}, {}]", x, y, z)
     }
 }
 
-impl Div<Vec3> for Vec3 {
+impl Div<Vec3A> for Vec3A {
     type Output = Self;
     #[inline]
     fn div(self, other: Self) -> Self {
@@ -381,7 +482,7 @@ impl Div<Vec3> for Vec3 {
     }
 }
 
-impl DivAssign<Vec3> for Vec3 {
+impl DivAssign<Vec3A> for Vec3A {
     #[inline]
     fn div_assign(&mut self, other: Self) {
         unsafe {
@@ -390,7 +491,7 @@ impl DivAssign<Vec3> for Vec3 {
     }
 }
 
-impl Div<f32> for Vec3 {
+impl Div<f32> for Vec3A {
     type Output = Self;
     #[inline]
     fn div(self, other: f32) -> Self {
@@ -398,14 +499,14 @@ impl Div<f32> for Vec3 {
     }
 }
 
-impl DivAssign<f32> for Vec3 {
+impl DivAssign<f32> for Vec3A {
     #[inline]
     fn div_assign(&mut self, other: f32) {
         unsafe { self.0 = _mm_div_ps(self.0, _mm_set1_ps(other)) }
     }
 }
 
-impl Mul<Vec3> for Vec3 {
+impl Mul<Vec3A> for Vec3A {
     type Output = Self;
     #[inline]
     fn mul(self, other: Self) -> Self {
@@ -413,7 +514,7 @@ impl Mul<Vec3> for Vec3 {
     }
 }
 
-impl MulAssign<Vec3> for Vec3 {
+impl MulAssign<Vec3A> for Vec3A {
     #[inline]
     fn mul_assign(&mut self, other: Self) {
         unsafe {
@@ -422,7 +523,7 @@ impl MulAssign<Vec3> for Vec3 {
     }
 }
 
-impl Mul<f32> for Vec3 {
+impl Mul<f32> for Vec3A {
     type Output = Self;
     #[inline]
     fn mul(self, other: f32) -> Self {
@@ -430,22 +531,22 @@ impl Mul<f32> for Vec3 {
     }
 }
 
-impl MulAssign<f32> for Vec3 {
+impl MulAssign<f32> for Vec3A {
     #[inline]
     fn mul_assign(&mut self, other: f32) {
         unsafe { self.0 = _mm_mul_ps(self.0, _mm_set1_ps(other)) }
     }
 }
 
-impl Mul<Vec3> for f32 {
-    type Output = Vec3;
+impl Mul<Vec3A> for f32 {
+    type Output = Vec3A;
     #[inline]
-    fn mul(self, other: Vec3) -> Vec3 {
-        unsafe { Vec3(_mm_mul_ps(_mm_set1_ps(self), other.0)) }
+    fn mul(self, other: Vec3A) -> Vec3A {
+        unsafe { Vec3A(_mm_mul_ps(_mm_set1_ps(self), other.0)) }
     }
 }
 
-impl Add for Vec3 {
+impl Add for Vec3A {
     type Output = Self;
     #[inline]
     fn add(self, other: Self) -> Self {
@@ -453,14 +554,14 @@ impl Add for Vec3 {
     }
 }
 
-impl AddAssign for Vec3 {
+impl AddAssign for Vec3A {
     #[inline]
     fn add_assign(&mut self, other: Self) {
         unsafe { self.0 = _mm_add_ps(self.0, other.0) }
     }
 }
 
-impl Sub for Vec3 {
+impl Sub for Vec3A {
     type Output = Self;
     #[inline]
     fn sub(self, other: Self) -> Self {
@@ -468,68 +569,76 @@ impl Sub for Vec3 {
     }
 }
 
-impl SubAssign for Vec3 {
+impl SubAssign for Vec3A {
     #[inline]
     fn sub_assign(&mut self, other: Self) {
         unsafe { self.0 = _mm_sub_ps(self.0, other.0) }
     }
 }
 
-impl Neg for Vec3 {
+impl Neg for Vec3A {
     type Output = Self;
     #[inline]
     fn neg(self) -> Self {
-        unsafe { Self(_mm_sub_ps(_mm_set1_ps(0.0), self.0)) }
+        unsafe { Self(_mm_xor_ps(_mm_set1_ps(-0.0), self.0)) }
     }
 }
 
-impl Default for Vec3 {
+impl Default for Vec3A {
     #[inline]
     fn default() -> Self {
-        Vec3::zero()
+        Vec3A::zero()
     }
 }
 
-impl PartialEq for Vec3 {
+impl PartialEq for Vec3A {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.cmpeq(*other).all()
     }
 }
 
-impl PartialOrd for Vec3 {
+impl PartialOrd for Vec3A {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.as_ref().partial_cmp(other.as_ref())
     }
 }
 
-impl From<Vec3> for __m128 {
-    // TODO: write test
+impl From<Vec3A> for __m128 {
     #[cfg_attr(tarpaulin, skip)]
     #[inline]
-    fn from(t: Vec3) -> Self {
+    fn from(t: Vec3A) -> Self {
         t.0
     }
 }
 
-impl From<__m128> for Vec3 {
+impl From<__m128> for Vec3A {
     #[inline]
     fn from(t: __m128) -> Self {
         Self(t)
     }
 }
 
-impl From<(f32, f32, f32)> for Vec3 {
+impl From<Vec4> for Vec3A {
+    /// Creates a `Vec3A` from the first three elements of the `Vec4`,
+    /// discarding `w`.
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        Self(v.0)
+    }
+}
+
+impl From<(f32, f32, f32)> for Vec3A {
     #[inline]
     fn from(t: (f32, f32, f32)) -> Self {
         Self::new(t.0, t.1, t.2)
     }
 }
 
-impl From<Vec3> for (f32, f32, f32) {
+impl From<Vec3A> for (f32, f32, f32) {
     #[inline]
-    fn from(v: Vec3) -> Self {
+    fn from(v: Vec3A) -> Self {
         let mut out: MaybeUninit<Align16<(f32, f32, f32)>> = MaybeUninit::uninit();
         unsafe {
             // out is 16 bytes in size due to alignment
@@ -539,16 +648,16 @@ impl From<Vec3> for (f32, f32, f32) {
     }
 }
 
-impl From<[f32; 3]> for Vec3 {
+impl From<[f32; 3]> for Vec3A {
     #[inline]
     fn from(a: [f32; 3]) -> Self {
         Self::new(a[0], a[1], a[2])
     }
 }
 
-impl From<Vec3> for [f32; 3] {
+impl From<Vec3A> for [f32; 3] {
     #[inline]
-    fn from(v: Vec3) -> Self {
+    fn from(v: Vec3A) -> Self {
         let mut out: MaybeUninit<Align16<[f32; 3]>> = MaybeUninit::uninit();
         unsafe {
             // out is 16 bytes in size due to alignment
@@ -559,126 +668,9 @@ impl From<Vec3> for [f32; 3] {
 }
 
 #[cfg(feature = "rand")]
-impl Distribution<Vec3> for Standard {
+impl Distribution<Vec3A> for Standard {
     #[inline]
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
         rng.gen::<(f32, f32, f32)>().into()
     }
 }
-
-/// A 3-dimensional vector mask.
-///
-/// This type is typically created by comparison methods on `Vec3`.  It is
-/// essentially a vector of three boolean values.
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct Vec3Mask(__m128);
-
-impl Vec3Mask {
-    /// Creates a new `Vec3Mask`.
-    #[inline]
-    pub fn new(x: bool, y: bool, z: bool) -> Self {
-        const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
-        unsafe {
-            Self(_mm_set_ps(
-                f32::from_bits(MASK[z as usize]),
-                f32::from_bits(MASK[z as usize]),
-                f32::from_bits(MASK[y as usize]),
-                f32::from_bits(MASK[x as usize]),
-            ))
-        }
-    }
-
-    /// Returns a bitmask with the lowest three bits set from the elements of
-    /// the `Vec3Mask`.
-    ///
-    /// A true element results in a `1` bit and a false element in a `0` bit.
-    /// Element `x` goes into the first lowest bit, element `y` into the
-    /// second, etc.
-    #[inline]
-    pub fn bitmask(&self) -> u32 {
-        unsafe { (_mm_movemask_ps(self.0) as u32) & 0x7 }
-    }
-
-    /// Returns true if any of the elements are true, false otherwise.
-    ///
-    /// In other words: `x || y || z`.
-    #[inline]
-    pub fn any(&self) -> bool {
-        unsafe { (_mm_movemask_ps(self.0) & 0x7) != 0 }
-    }
-
-    /// Returns true if all the elements are true, false otherwise.
-    ///
-    /// In other words: `x && y && z`.
-    #[inline]
-    pub fn all(&self) -> bool {
-        unsafe { (_mm_movemask_ps(self.0) & 0x7) == 0x7 }
-    }
-
-    /// Creates a new `Vec3` from the elements in `if_true` and `if_false`,
-    /// selecting which to use for each element based on the `Vec3Mask`.
-    ///
-    /// A true element in the mask uses the corresponding element from
-    /// `if_true`, and false uses the element from `if_false`.
-    #[inline]
-    pub fn select(self, if_true: Vec3, if_false: Vec3) -> Vec3 {
-        unsafe {
-            Vec3(_mm_or_ps(
-                _mm_andnot_ps(self.0, if_false.0),
-                _mm_and_ps(if_true.0, self.0),
-            ))
-        }
-    }
-}
-
-impl Default for Vec3Mask {
-    #[inline]
-    fn default() -> Self {
-        unsafe { Self(_mm_setzero_ps()) }
-    }
-}
-
-impl BitAnd for Vec3Mask {
-    type Output = Self;
-
-    #[inline]
-    fn bitand(self, other: Self) -> Self {
-        unsafe { Self(_mm_and_ps(self.0, other.0)) }
-    }
-}
-
-impl BitAndAssign for Vec3Mask {
-    fn bitand_assign(&mut self, other: Self) {
-        *self = *self & other
-    }
-}
-
-impl BitOr for Vec3Mask {
-    type Output = Self;
-
-    #[inline]
-    fn bitor(self, other: Self) -> Self {
-        unsafe { Self(_mm_or_ps(self.0, other.0)) }
-    }
-}
-
-impl BitOrAssign for Vec3Mask {
-    fn bitor_assign(&mut self, other: Self) {
-        *self = *self | other
-    }
-}
-
-impl Not for Vec3Mask {
-    type Output = Self;
-
-    #[inline]
-    fn not(self) -> Self {
-        unsafe {
-            Self(_mm_andnot_ps(
-                self.0,
-                _mm_set_ps1(f32::from_bits(0xff_ff_ff_ff)),
-            ))
-        }
-    }
-}
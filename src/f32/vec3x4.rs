@@ -0,0 +1,383 @@
+use super::{Vec3, Vec4};
+use crate::BVec4;
+
+/// Four 3-dimensional vectors stored as a structure of arrays.
+///
+/// The `x`, `y` and `z` elements of each lane are stored in separate `Vec4`s
+/// so that an operation is applied to all four vectors at once. This type is
+/// 16 byte aligned.
+///
+/// Arithmetic uses `Vec4` and so is SIMD accelerated where `Vec4` is, but
+/// square roots are computed per lane with scalar math.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C, align(16))]
+pub struct Vec3x4 {
+    pub(crate) x: Vec4,
+    pub(crate) y: Vec4,
+    pub(crate) z: Vec4,
+}
+
+#[inline]
+fn map(v: Vec4, f: impl Fn(f32) -> f32) -> Vec4 {
+    let (x, y, z, w) = v.into();
+    Vec4::new(f(x), f(y), f(z), f(w))
+}
+
+impl Vec3x4 {
+    /// Creates a new `Vec3x4` from arrays holding the `x`, `y` and `z` elements
+    /// of each lane.
+    #[inline]
+    pub fn new(x: [f32; 4], y: [f32; 4], z: [f32; 4]) -> Self {
+        Self {
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+        }
+    }
+
+    /// Creates a new `Vec3x4` with all lanes set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Vec3::zero())
+    }
+
+    /// Creates a new `Vec3x4` with all lanes set to `v`.
+    #[inline]
+    pub fn splat(v: Vec3) -> Self {
+        Self {
+            x: Vec4::splat(v.x()),
+            y: Vec4::splat(v.y()),
+            z: Vec4::splat(v.z()),
+        }
+    }
+
+    /// Returns the `x` elements of all lanes.
+    #[inline]
+    pub fn x(&self) -> [f32; 4] {
+        self.x.into()
+    }
+
+    /// Returns the `y` elements of all lanes.
+    #[inline]
+    pub fn y(&self) -> [f32; 4] {
+        self.y.into()
+    }
+
+    /// Returns the `z` elements of all lanes.
+    #[inline]
+    pub fn z(&self) -> [f32; 4] {
+        self.z.into()
+    }
+
+    /// Returns the vector stored in lane `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than 3.
+    #[inline]
+    pub fn lane(&self, i: usize) -> Vec3 {
+        Vec3::new(self.x.as_ref()[i], self.y.as_ref()[i], self.z.as_ref()[i])
+    }
+
+    /// Sets the vector stored in lane `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than 3.
+    #[inline]
+    pub fn set_lane(&mut self, i: usize, v: Vec3) {
+        self.x.as_mut()[i] = v.x();
+        self.y.as_mut()[i] = v.y();
+        self.z.as_mut()[i] = v.z();
+    }
+
+    /// Packs the first four vectors in `slice` into a new `Vec3x4`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn from_slice(slice: &[Vec3]) -> Self {
+        assert!(slice.len() >= 4);
+        let mut out = Self::zero();
+        for (i, v) in slice[..4].iter().enumerate() {
+            out.set_lane(i, *v);
+        }
+        out
+    }
+
+    /// Unpacks all lanes into the first four elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Vec3]) {
+        assert!(slice.len() >= 4);
+        for (i, v) in slice[..4].iter_mut().enumerate() {
+            *v = self.lane(i);
+        }
+    }
+
+    #[inline]
+    fn dot_as_vec4(self, other: Self) -> Vec4 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[inline]
+    pub(crate) fn scale(self, s: Vec4) -> Self {
+        Self {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+        }
+    }
+
+    /// Computes the dot product of each lane of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> [f32; 4] {
+        self.dot_as_vec4(other).into()
+    }
+
+    /// Computes the cross product of each lane of `self` and `other`.
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - other.y * self.z,
+            y: self.z * other.x - other.z * self.x,
+            z: self.x * other.y - other.x * self.y,
+        }
+    }
+
+    /// Computes the squared length of each lane of `self`.
+    #[inline]
+    pub fn length_squared(self) -> [f32; 4] {
+        self.dot(self)
+    }
+
+    /// Computes the length of each lane of `self`.
+    #[inline]
+    pub fn length(self) -> [f32; 4] {
+        map(self.dot_as_vec4(self), f32::sqrt).into()
+    }
+
+    /// Returns each lane of `self` normalized to length 1.0.
+    ///
+    /// For valid results, no lane of `self` may be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self.scale(map(self.dot_as_vec4(self), |d| 1.0 / d.sqrt()))
+    }
+
+    /// Returns the element-wise minimum of each lane of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Returns the element-wise maximum of each lane of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Performs a `==` comparison between each lane of `self` and `other`,
+    /// returning a `BVec4` with one element per lane.
+    ///
+    /// A lane is true if all of its `x`, `y` and `z` elements are equal.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> BVec4 {
+        self.x.cmpeq(other.x) & self.y.cmpeq(other.y) & self.z.cmpeq(other.z)
+    }
+
+    /// Performs a `!=` comparison between each lane of `self` and `other`,
+    /// returning a `BVec4` with one element per lane.
+    ///
+    /// A lane is true if any of its `x`, `y` and `z` elements differ.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> BVec4 {
+        self.x.cmpne(other.x) | self.y.cmpne(other.y) | self.z.cmpne(other.z)
+    }
+
+    /// Performs a `>=` comparison between each lane of `self` and `other`,
+    /// returning a `BVec4` with one element per lane.
+    ///
+    /// A lane is true if the comparison holds for all of its `x`, `y` and `z`
+    /// elements.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> BVec4 {
+        self.x.cmpge(other.x) & self.y.cmpge(other.y) & self.z.cmpge(other.z)
+    }
+
+    /// Performs a `>` comparison between each lane of `self` and `other`,
+    /// returning a `BVec4` with one element per lane.
+    ///
+    /// A lane is true if the comparison holds for all of its `x`, `y` and `z`
+    /// elements.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> BVec4 {
+        self.x.cmpgt(other.x) & self.y.cmpgt(other.y) & self.z.cmpgt(other.z)
+    }
+
+    /// Performs a `<=` comparison between each lane of `self` and `other`,
+    /// returning a `BVec4` with one element per lane.
+    ///
+    /// A lane is true if the comparison holds for all of its `x`, `y` and `z`
+    /// elements.
+    #[inline]
+    pub fn cmple(self, other: Self) -> BVec4 {
+        self.x.cmple(other.x) & self.y.cmple(other.y) & self.z.cmple(other.z)
+    }
+
+    /// Performs a `<` comparison between each lane of `self` and `other`,
+    /// returning a `BVec4` with one element per lane.
+    ///
+    /// A lane is true if the comparison holds for all of its `x`, `y` and `z`
+    /// elements.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> BVec4 {
+        self.x.cmplt(other.x) & self.y.cmplt(other.y) & self.z.cmplt(other.z)
+    }
+
+    /// Creates a vector from the lanes of `if_true` and `if_false`, selecting
+    /// which to use for each lane based on `mask`.
+    ///
+    /// A true element in the mask uses the corresponding lane from `if_true`,
+    /// and false uses the lane from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec4, if_true: Self, if_false: Self) -> Self {
+        Self {
+            x: Vec4::select(mask, if_true.x, if_false.x),
+            y: Vec4::select(mask, if_true.y, if_false.y),
+            z: Vec4::select(mask, if_true.z, if_false.z),
+        }
+    }
+}
+
+impl Default for Vec3x4 {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl From<[Vec3; 4]> for Vec3x4 {
+    #[inline]
+    fn from(a: [Vec3; 4]) -> Self {
+        Self::from_slice(&a)
+    }
+}
+
+impl From<Vec3x4> for [Vec3; 4] {
+    #[inline]
+    fn from(v: Vec3x4) -> Self {
+        let mut out = [Vec3::zero(); 4];
+        v.write_to_slice(&mut out);
+        out
+    }
+}
+
+impl std::ops::Add for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl std::ops::AddAssign for Vec3x4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::Sub for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl std::ops::SubAssign for Vec3x4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl std::ops::Mul for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+}
+
+impl std::ops::Mul<[f32; 4]> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: [f32; 4]) -> Self {
+        self.scale(other.into())
+    }
+}
+
+impl std::ops::Mul<f32> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        self.scale(Vec4::splat(other))
+    }
+}
+
+impl std::ops::MulAssign<f32> for Vec3x4 {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        *self = *self * other;
+    }
+}
+
+impl std::ops::Div<f32> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f32) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl std::ops::Neg for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use super::{Vec3, Vec3x4, Vec4};
+use crate::BVec4;
+
+/// Eight 3-dimensional vectors stored as a structure of arrays.
+///
+/// The `x`, `y` and `z` elements of each lane are stored in separate pairs of
+/// `Vec4`s so that an operation is applied to all eight vectors at once. Lanes
+/// `0..4` are held in the first `Vec4` of each pair and lanes `4..8` in the
+/// second. This type is 32 byte aligned.
+///
+/// Arithmetic uses `Vec4` and so is SIMD accelerated where `Vec4` is, but
+/// square roots are computed per lane with scalar math.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C, align(32))]
+pub struct Vec3x8 {
+    x: [Vec4; 2],
+    y: [Vec4; 2],
+    z: [Vec4; 2],
+}
+
+#[inline]
+fn split(a: [f32; 8]) -> [Vec4; 2] {
+    [
+        Vec4::new(a[0], a[1], a[2], a[3]),
+        Vec4::new(a[4], a[5], a[6], a[7]),
+    ]
+}
+
+#[inline]
+fn join(lo: [f32; 4], hi: [f32; 4]) -> [f32; 8] {
+    [lo[0], lo[1], lo[2], lo[3], hi[0], hi[1], hi[2], hi[3]]
+}
+
+impl Vec3x8 {
+    /// Returns the four lanes in half `i` of `self` as a `Vec3x4`.
+    #[inline]
+    fn half(self, i: usize) -> Vec3x4 {
+        Vec3x4 {
+            x: self.x[i],
+            y: self.y[i],
+            z: self.z[i],
+        }
+    }
+
+    #[inline]
+    fn from_halves(lo: Vec3x4, hi: Vec3x4) -> Self {
+        Self {
+            x: [lo.x, hi.x],
+            y: [lo.y, hi.y],
+            z: [lo.z, hi.z],
+        }
+    }
+
+    #[inline]
+    fn map(self, f: impl Fn(Vec3x4) -> Vec3x4) -> Self {
+        Self::from_halves(f(self.half(0)), f(self.half(1)))
+    }
+
+    #[inline]
+    fn map2(self, other: Self, f: impl Fn(Vec3x4, Vec3x4) -> Vec3x4) -> Self {
+        Self::from_halves(
+            f(self.half(0), other.half(0)),
+            f(self.half(1), other.half(1)),
+        )
+    }
+
+    #[inline]
+    fn map2_mask(self, other: Self, f: impl Fn(Vec3x4, Vec3x4) -> BVec4) -> [BVec4; 2] {
+        [
+            f(self.half(0), other.half(0)),
+            f(self.half(1), other.half(1)),
+        ]
+    }
+
+    /// Creates a new `Vec3x8` from arrays holding the `x`, `y` and `z` elements
+    /// of each lane.
+    #[inline]
+    pub fn new(x: [f32; 8], y: [f32; 8], z: [f32; 8]) -> Self {
+        Self {
+            x: split(x),
+            y: split(y),
+            z: split(z),
+        }
+    }
+
+    /// Creates a new `Vec3x8` with all lanes set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Vec3::zero())
+    }
+
+    /// Creates a new `Vec3x8` with all lanes set to `v`.
+    #[inline]
+    pub fn splat(v: Vec3) -> Self {
+        let half = Vec3x4::splat(v);
+        Self::from_halves(half, half)
+    }
+
+    /// Returns the `x` elements of all lanes.
+    #[inline]
+    pub fn x(&self) -> [f32; 8] {
+        join(self.x[0].into(), self.x[1].into())
+    }
+
+    /// Returns the `y` elements of all lanes.
+    #[inline]
+    pub fn y(&self) -> [f32; 8] {
+        join(self.y[0].into(), self.y[1].into())
+    }
+
+    /// Returns the `z` elements of all lanes.
+    #[inline]
+    pub fn z(&self) -> [f32; 8] {
+        join(self.z[0].into(), self.z[1].into())
+    }
+
+    /// Returns the vector stored in lane `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than 7.
+    #[inline]
+    pub fn lane(&self, i: usize) -> Vec3 {
+        assert!(i < 8);
+        self.half(i / 4).lane(i % 4)
+    }
+
+    /// Sets the vector stored in lane `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than 7.
+    #[inline]
+    pub fn set_lane(&mut self, i: usize, v: Vec3) {
+        assert!(i < 8);
+        let (h, i) = (i / 4, i % 4);
+        self.x[h].as_mut()[i] = v.x();
+        self.y[h].as_mut()[i] = v.y();
+        self.z[h].as_mut()[i] = v.z();
+    }
+
+    /// Packs the first eight vectors in `slice` into a new `Vec3x8`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than eight elements long.
+    #[inline]
+    pub fn from_slice(slice: &[Vec3]) -> Self {
+        assert!(slice.len() >= 8);
+        Self::from_halves(
+            Vec3x4::from_slice(&slice[..4]),
+            Vec3x4::from_slice(&slice[4..]),
+        )
+    }
+
+    /// Unpacks all lanes into the first eight elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than eight elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Vec3]) {
+        assert!(slice.len() >= 8);
+        self.half(0).write_to_slice(&mut slice[..4]);
+        self.half(1).write_to_slice(&mut slice[4..]);
+    }
+
+    /// Computes the dot product of each lane of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> [f32; 8] {
+        join(
+            self.half(0).dot(other.half(0)),
+            self.half(1).dot(other.half(1)),
+        )
+    }
+
+    /// Computes the cross product of each lane of `self` and `other`.
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        self.map2(other, Vec3x4::cross)
+    }
+
+    /// Computes the squared length of each lane of `self`.
+    #[inline]
+    pub fn length_squared(self) -> [f32; 8] {
+        self.dot(self)
+    }
+
+    /// Computes the length of each lane of `self`.
+    #[inline]
+    pub fn length(self) -> [f32; 8] {
+        join(self.half(0).length(), self.half(1).length())
+    }
+
+    /// Returns each lane of `self` normalized to length 1.0.
+    ///
+    /// For valid results, no lane of `self` may be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self.map(Vec3x4::normalize)
+    }
+
+    /// Returns the element-wise minimum of each lane of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        self.map2(other, Vec3x4::min)
+    }
+
+    /// Returns the element-wise maximum of each lane of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        self.map2(other, Vec3x4::max)
+    }
+
+    /// Performs a `==` comparison between each lane of `self` and `other`,
+    /// returning a pair of `BVec4`s for lanes `0..4` and `4..8`.
+    ///
+    /// A lane is true if all of its `x`, `y` and `z` elements are equal.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> [BVec4; 2] {
+        self.map2_mask(other, Vec3x4::cmpeq)
+    }
+
+    /// Performs a `!=` comparison between each lane of `self` and `other`,
+    /// returning a pair of `BVec4`s for lanes `0..4` and `4..8`.
+    ///
+    /// A lane is true if any of its `x`, `y` and `z` elements differ.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> [BVec4; 2] {
+        self.map2_mask(other, Vec3x4::cmpne)
+    }
+
+    /// Performs a `>=` comparison between each lane of `self` and `other`,
+    /// returning a pair of `BVec4`s for lanes `0..4` and `4..8`.
+    ///
+    /// A lane is true if the comparison holds for all of its `x`, `y` and `z`
+    /// elements.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> [BVec4; 2] {
+        self.map2_mask(other, Vec3x4::cmpge)
+    }
+
+    /// Performs a `>` comparison between each lane of `self` and `other`,
+    /// returning a pair of `BVec4`s for lanes `0..4` and `4..8`.
+    ///
+    /// A lane is true if the comparison holds for all of its `x`, `y` and `z`
+    /// elements.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> [BVec4; 2] {
+        self.map2_mask(other, Vec3x4::cmpgt)
+    }
+
+    /// Performs a `<=` comparison between each lane of `self` and `other`,
+    /// returning a pair of `BVec4`s for lanes `0..4` and `4..8`.
+    ///
+    /// A lane is true if the comparison holds for all of its `x`, `y` and `z`
+    /// elements.
+    #[inline]
+    pub fn cmple(self, other: Self) -> [BVec4; 2] {
+        self.map2_mask(other, Vec3x4::cmple)
+    }
+
+    /// Performs a `<` comparison between each lane of `self` and `other`,
+    /// returning a pair of `BVec4`s for lanes `0..4` and `4..8`.
+    ///
+    /// A lane is true if the comparison holds for all of its `x`, `y` and `z`
+    /// elements.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> [BVec4; 2] {
+        self.map2_mask(other, Vec3x4::cmplt)
+    }
+
+    /// Creates a vector from the lanes of `if_true` and `if_false`, selecting
+    /// which to use for each lane based on `mask`.
+    ///
+    /// The first `BVec4` of the mask selects lanes `0..4` and the second lanes
+    /// `4..8`. A true element in the mask uses the corresponding lane from
+    /// `if_true`, and false uses the lane from `if_false`.
+    #[inline]
+    pub fn select(mask: [BVec4; 2], if_true: Self, if_false: Self) -> Self {
+        Self::from_halves(
+            Vec3x4::select(mask[0], if_true.half(0), if_false.half(0)),
+            Vec3x4::select(mask[1], if_true.half(1), if_false.half(1)),
+        )
+    }
+}
+
+impl Default for Vec3x8 {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl From<[Vec3; 8]> for Vec3x8 {
+    #[inline]
+    fn from(a: [Vec3; 8]) -> Self {
+        Self::from_slice(&a)
+    }
+}
+
+impl From<Vec3x8> for [Vec3; 8] {
+    #[inline]
+    fn from(v: Vec3x8) -> Self {
+        let mut out = [Vec3::zero(); 8];
+        v.write_to_slice(&mut out);
+        out
+    }
+}
+
+impl std::ops::Add for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.map2(other, |a, b| a + b)
+    }
+}
+
+impl std::ops::AddAssign for Vec3x8 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::Sub for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.map2(other, |a, b| a - b)
+    }
+}
+
+impl std::ops::SubAssign for Vec3x8 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl std::ops::Mul for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.map2(other, |a, b| a * b)
+    }
+}
+
+impl std::ops::Mul<[f32; 8]> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: [f32; 8]) -> Self {
+        let [lo, hi] = split(other);
+        Self::from_halves(self.half(0).scale(lo), self.half(1).scale(hi))
+    }
+}
+
+impl std::ops::Mul<f32> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        self.map(|a| a * other)
+    }
+}
+
+impl std::ops::MulAssign<f32> for Vec3x8 {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        *self = *self * other;
+    }
+}
+
+impl std::ops::Div<f32> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f32) -> Self {
+        self.map(|a| a / other)
+    }
+}
+
+impl std::ops::Neg for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self.map(|a| -a)
+    }
+}
@@ -1,72 +1,38 @@
-use super::Vec4;
+use super::{Mat4, Vec4};
+use crate::{
+    bool::BVec4, f64::DVec4, i16::I16Vec4, i32::IVec4, i64::I64Vec4, u16::U16Vec4, u32::UVec4,
+    u64::U64Vec4,
+};
 
 #[inline]
 pub fn vec4(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
     Vec4::new(x, y, z, w)
 }
 
-impl Vec4 {
-    /// Returns a new `Vec4` with elements representing the sign of `self`.
-    ///
-    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
-    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
-    #[inline]
-    pub fn sign(self) -> Self {
-        let mask = self.cmpge(Self::zero());
-        mask.select(Self::splat(1.0), Self::splat(-1.0))
-    }
-
-    /// Computes the reciprocal `1.0/n` of each element, returning the
-    /// results in a new `Vec4`.
-    #[inline]
-    pub fn reciprocal(self) -> Self {
-        // TODO: Optimize
-        Self::one() / self
-    }
-
-    /// Performs a linear interpolation between `self` and `other` based on
-    /// the value `s`.
-    ///
-    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
-    /// is `1.0`, the result will be equal to `other`.
-    #[inline]
-    pub fn lerp(self, other: Self, s: f32) -> Self {
-        self + ((other - self) * s)
-    }
-
-    /// Returns whether `self` is length `1.0` or not.
-    ///
-    /// Uses a precision threshold of `1e-6`.
-    #[inline]
-    pub fn is_normalized(self) -> bool {
-        is_normalized!(self)
-    }
-
-    /// Returns true if the absolute difference of all elements between `self`
-    /// and `other` is less than or equal to `max_abs_diff`.
-    ///
-    /// This can be used to compare if two `Vec4`'s contain similar elements. It
-    /// works best when comparing with a known value. The `max_abs_diff` that
-    /// should be used used depends on the values being compared against.
-    ///
-    /// For more on floating point comparisons see
-    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
-    #[inline]
-    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
-        abs_diff_eq!(self, other, max_abs_diff)
-    }
-}
-
-impl AsRef<[f32; 4]> for Vec4 {
-    #[inline]
-    fn as_ref(&self) -> &[f32; 4] {
-        unsafe { &*(self as *const Self as *const [f32; 4]) }
-    }
-}
-
-impl AsMut<[f32; 4]> for Vec4 {
-    #[inline]
-    fn as_mut(&mut self) -> &mut [f32; 4] {
-        unsafe { &mut *(self as *mut Self as *mut [f32; 4]) }
-    }
-}
+impl_vec4_float_methods!(Vec4, f32, Mat4, BVec4);
+impl_vec4_as!(
+    Vec4,
+    as_dvec4 => DVec4, f64;
+    as_i16vec4 => I16Vec4, i16;
+    as_u16vec4 => U16Vec4, u16;
+    as_ivec4 => IVec4, i32;
+    as_uvec4 => UVec4, u32;
+    as_i64vec4 => I64Vec4, i64;
+    as_u64vec4 => U64Vec4, u64;
+);
+impl_vec4_try_as!(
+    Vec4,
+    f32,
+    try_as_i16vec4 => I16Vec4, i16;
+    try_as_u16vec4 => U16Vec4, u16;
+    try_as_ivec4 => IVec4, i32;
+    try_as_uvec4 => UVec4, u32;
+    try_as_i64vec4 => I64Vec4, i64;
+    try_as_u64vec4 => U64Vec4, u64;
+);
+
+/// A 4-dimensional vector mask.
+///
+/// This is an alias for `BVec4`.
+#[deprecated(since = "0.9.0", note = "please use `BVec4` instead")]
+pub type Vec4Mask = BVec4;
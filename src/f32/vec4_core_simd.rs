@@ -0,0 +1,602 @@
+#![allow(dead_code)]
+
+#[cfg(feature = "rand")]
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+use crate::{bool::BVec4, f32::Vec3};
+
+use std::simd::{
+    cmp::SimdPartialEq, cmp::SimdPartialOrd, f32x4, i32x4, num::SimdFloat, Mask, Select, StdFloat,
+};
+
+use std::{cmp::Ordering, f32, fmt, ops::*};
+
+/// A 4-dimensional vector.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Vec4(pub(crate) f32x4);
+
+#[inline]
+fn bvec4_from_mask(mask: Mask<i32, 4>) -> BVec4 {
+    let m = mask.to_simd().to_array();
+    BVec4(m[0] as u32, m[1] as u32, m[2] as u32, m[3] as u32)
+}
+
+#[inline]
+fn bvec4_to_mask(mask: BVec4) -> Mask<i32, 4> {
+    Mask::from_simd(i32x4::from_array([
+        mask.0 as i32,
+        mask.1 as i32,
+        mask.2 as i32,
+        mask.3 as i32,
+    ]))
+}
+
+impl Vec4 {
+    /// Creates a new `Vec4`.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self(f32x4::from_array([x, y, z, w]))
+    }
+
+    /// Creates a new `Vec4` with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self(f32x4::splat(0.0))
+    }
+
+    /// Creates a new `Vec4` with all elements set to `1.0`.
+    #[inline]
+    pub fn one() -> Self {
+        Self(f32x4::splat(1.0))
+    }
+
+    /// Creates a new `Vec4` with values `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_x() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 1.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_y() -> Self {
+        Self::new(0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 0.0, z: 1.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_z() -> Self {
+        Self::new(0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 0.0, z: 0.0, w: 1.0]`.
+    #[inline]
+    pub fn unit_w() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates a new `Vec4` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: f32) -> Self {
+        Self(f32x4::splat(v))
+    }
+
+    /// Creates a `Vec3` from the first three elements of `self`,
+    /// removing `w`.
+    #[inline]
+    pub fn truncate(self) -> Vec3 {
+        let (x, y, z, _) = self.into();
+        Vec3::new(x, y, z)
+    }
+
+    /// Returns the `x`, `y` and `z` elements of `self` as a new vector, the same as
+    /// `truncate`.
+    #[inline]
+    pub fn xyz(self) -> Vec3 {
+        self.truncate()
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> f32 {
+        self.0[0]
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> f32 {
+        self.0[1]
+    }
+
+    /// Returns element `z`.
+    #[inline]
+    pub fn z(self) -> f32 {
+        self.0[2]
+    }
+
+    /// Returns element `w`.
+    #[inline]
+    pub fn w(self) -> f32 {
+        self.0[3]
+    }
+
+    /// Sets element `x`.
+    #[inline]
+    pub fn set_x(&mut self, x: f32) {
+        self.0[0] = x;
+    }
+
+    /// Sets element `y`.
+    #[inline]
+    pub fn set_y(&mut self, y: f32) {
+        self.0[1] = y;
+    }
+
+    /// Sets element `z`.
+    #[inline]
+    pub fn set_z(&mut self, z: f32) {
+        self.0[2] = z;
+    }
+
+    /// Sets element `w`.
+    #[inline]
+    pub fn set_w(&mut self, w: f32) {
+        self.0[3] = w;
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `x`.
+    #[inline]
+    pub(crate) fn dup_x(self) -> Self {
+        Self(f32x4::splat(self.0[0]))
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `y`.
+    #[inline]
+    pub(crate) fn dup_y(self) -> Self {
+        Self(f32x4::splat(self.0[1]))
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `z`.
+    #[inline]
+    pub(crate) fn dup_z(self) -> Self {
+        Self(f32x4::splat(self.0[2]))
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `w`.
+    #[inline]
+    pub(crate) fn dup_w(self) -> Self {
+        Self(f32x4::splat(self.0[3]))
+    }
+
+    /// Computes the 4D dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> f32 {
+        (self.0 * other.0).reduce_sum()
+    }
+
+    /// Computes the dot product of `self` and `other`, returning the result in
+    /// all elements of a vector.
+    ///
+    /// This keeps the result in SIMD registers when it is used in further
+    /// vector arithmetic such as normalization.
+    #[inline]
+    pub fn dot_into_vec(self, other: Self) -> Self {
+        Self(f32x4::splat(self.dot(other)))
+    }
+
+    /// Computes the 4D length of `self`.
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared 4D length of `self`.
+    ///
+    /// This is generally faster than `Vec4::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / Vec4::length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_reciprocal(self) -> f32 {
+        1.0 / self.length()
+    }
+
+    /// Computes an approximation of `1.0 / length()`.
+    ///
+    /// `core::simd` has no portable reciprocal square root estimate, so the
+    /// result is the same as `length_reciprocal`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f32 {
+        self.length_reciprocal()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let dot = self.dot_into_vec(self);
+        Self(self.0 / dot.0.sqrt())
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2), w: min(w1, w2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self(self.0.simd_min(other.0))
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2), w: max(w1, w2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self(self.0.simd_max(other.0))
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z, w)`.
+    #[inline]
+    pub fn min_element(self) -> f32 {
+        self.0.reduce_min()
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z, w)`.
+    #[inline]
+    pub fn max_element(self) -> f32 {
+        self.0.reduce_max()
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2, w1 == w2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> BVec4 {
+        bvec4_from_mask(self.0.simd_eq(other.0))
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2, w1 != w2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> BVec4 {
+        bvec4_from_mask(self.0.simd_ne(other.0))
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2, w1 >= w2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> BVec4 {
+        bvec4_from_mask(self.0.simd_ge(other.0))
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2, w1 > w2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> BVec4 {
+        bvec4_from_mask(self.0.simd_gt(other.0))
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2, w1 <= w2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> BVec4 {
+        bvec4_from_mask(self.0.simd_le(other.0))
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2, w1 < w2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> BVec4 {
+        bvec4_from_mask(self.0.simd_lt(other.0))
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on `mask`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec4, if_true: Self, if_false: Self) -> Self {
+        Self(bvec4_to_mask(mask).select(if_true.0, if_false.0))
+    }
+
+    /// Creates a new `Vec4` from the first four values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f32]) -> Self {
+        assert!(slice.len() >= 4);
+        Self(f32x4::from_slice(&slice[..4]))
+    }
+
+    /// Writes the elements of `self` to the first four elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f32]) {
+        assert!(slice.len() >= 4);
+        self.0.copy_to_slice(&mut slice[..4])
+    }
+
+    /// Computes `(self * a) + b` element-wise.
+    ///
+    /// This uses a fused multiply-add instruction, which rounds only once,
+    /// when the target supports it, for example when the `fma` target
+    /// feature is enabled on x86. Otherwise the multiplication and addition
+    /// are rounded separately.
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        #[cfg(any(target_feature = "fma", target_arch = "aarch64"))]
+        {
+            Self(self.0.mul_add(a.0, b.0))
+        }
+        #[cfg(not(any(target_feature = "fma", target_arch = "aarch64")))]
+        {
+            Self((self.0 * a.0) + b.0)
+        }
+    }
+
+    /// Per element negative multiplication/subtraction of the three inputs `-((self * a) - b)`
+    /// This is mathematically equivalent to `b - (self * a)`
+    #[inline]
+    pub(crate) fn neg_mul_sub(self, a: Self, b: Self) -> Self {
+        Self(b.0 - (self.0 * a.0))
+    }
+
+    /// Returns a new `Vec4` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    #[inline]
+    pub fn sign(self) -> Self {
+        let mask = self.cmpge(Self::zero());
+        Self::select(mask, Self::splat(1.0), Self::splat(-1.0))
+    }
+
+    /// Returns a new `Vec4` containing the absolute value of each element of the original
+    /// `Vec4`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns a new vector containing the square root of each element of
+    /// the original vector.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        Self(self.0.sqrt())
+    }
+}
+
+impl fmt::Debug for Vec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y, z, w) = (*self).into();
+        fmt.debug_tuple("Vec4")
+            .field(&x)
+            .field(&y)
+            .field(&z)
+            .field(&w)
+            .finish()
+    }
+}
+
+impl fmt::Display for Vec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y, z, w) = (*self).into();
+        write!(fmt, "[{}, {}, {}, {}]", x, y, z, w)
+    }
+}
+
+impl Div<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self(self.0 / other.0)
+    }
+}
+
+impl DivAssign<Vec4> for Vec4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        self.0 /= other.0
+    }
+}
+
+impl Div<f32> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f32) -> Self {
+        Self(self.0 / f32x4::splat(other))
+    }
+}
+
+impl DivAssign<f32> for Vec4 {
+    #[inline]
+    fn div_assign(&mut self, other: f32) {
+        self.0 /= f32x4::splat(other)
+    }
+}
+
+impl Mul<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl MulAssign<Vec4> for Vec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        self.0 *= other.0
+    }
+}
+
+impl Mul<f32> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        Self(self.0 * f32x4::splat(other))
+    }
+}
+
+impl MulAssign<f32> for Vec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        self.0 *= f32x4::splat(other)
+    }
+}
+
+impl Mul<Vec4> for f32 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, other: Vec4) -> Vec4 {
+        Vec4(f32x4::splat(self) * other.0)
+    }
+}
+
+impl Add for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Vec4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0
+    }
+}
+
+impl Sub for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Vec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Default for Vec4 {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl PartialEq for Vec4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmpeq(*other).all()
+    }
+}
+
+impl PartialOrd for Vec4 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other.as_ref())
+    }
+}
+
+impl From<Vec4> for f32x4 {
+    #[inline]
+    fn from(t: Vec4) -> Self {
+        t.0
+    }
+}
+
+impl From<f32x4> for Vec4 {
+    #[inline]
+    fn from(t: f32x4) -> Self {
+        Self(t)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Vec4 {
+    #[inline]
+    fn from(t: (f32, f32, f32, f32)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<Vec4> for (f32, f32, f32, f32) {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        let a: [f32; 4] = v.into();
+        (a[0], a[1], a[2], a[3])
+    }
+}
+
+impl From<[f32; 4]> for Vec4 {
+    #[inline]
+    fn from(a: [f32; 4]) -> Self {
+        Self(f32x4::from_array(a))
+    }
+}
+
+impl From<Vec4> for [f32; 4] {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        v.0.to_array()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Vec4> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4 {
+        rng.gen::<[f32; 4]>().into()
+    }
+}
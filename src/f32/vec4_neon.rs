@@ -0,0 +1,621 @@
+#![allow(dead_code)]
+
+#[cfg(feature = "rand")]
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+use crate::{bool::BVec4, f32::Vec3, Align16};
+
+use std::arch::aarch64::*;
+
+use std::{cmp::Ordering, f32, fmt, mem, mem::MaybeUninit, ops::*};
+
+/// A 4-dimensional vector.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Vec4(pub(crate) float32x4_t);
+
+// `BVec4` stores four `u32` lane masks with 16 byte alignment so it has the same
+// layout as `uint32x4_t`.
+#[inline]
+fn bvec4_from_neon(mask: uint32x4_t) -> BVec4 {
+    unsafe { mem::transmute(mask) }
+}
+
+#[inline]
+fn bvec4_to_neon(mask: BVec4) -> uint32x4_t {
+    unsafe { mem::transmute(mask) }
+}
+
+// Computes an approximation of `1.0 / sqrt(a)`, refining the `vrsqrteq_f32`
+// estimate with two Newton-Raphson steps. `vrsqrtsq_f32(a * y, y)` computes
+// `(3 - a * y * y) / 2`.
+#[inline]
+unsafe fn rsqrt_nr(a: float32x4_t) -> float32x4_t {
+    let y = vrsqrteq_f32(a);
+    let y = vmulq_f32(y, vrsqrtsq_f32(vmulq_f32(a, y), y));
+    vmulq_f32(y, vrsqrtsq_f32(vmulq_f32(a, y), y))
+}
+
+impl Vec4 {
+    /// Creates a new `Vec4`.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        let a = Align16([x, y, z, w]);
+        unsafe { Self(vld1q_f32(a.as_ptr() as *const f32)) }
+    }
+
+    /// Creates a new `Vec4` with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        unsafe { Self(vdupq_n_f32(0.0)) }
+    }
+
+    /// Creates a new `Vec4` with all elements set to `1.0`.
+    #[inline]
+    pub fn one() -> Self {
+        unsafe { Self(vdupq_n_f32(1.0)) }
+    }
+
+    /// Creates a new `Vec4` with values `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_x() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 1.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_y() -> Self {
+        Self::new(0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 0.0, z: 1.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_z() -> Self {
+        Self::new(0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 0.0, z: 0.0, w: 1.0]`.
+    #[inline]
+    pub fn unit_w() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates a new `Vec4` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: f32) -> Self {
+        unsafe { Self(vdupq_n_f32(v)) }
+    }
+
+    /// Creates a `Vec3` from the first three elements of `self`,
+    /// removing `w`.
+    #[inline]
+    pub fn truncate(self) -> Vec3 {
+        let (x, y, z, _) = self.into();
+        Vec3::new(x, y, z)
+    }
+
+    /// Returns the `x`, `y` and `z` elements of `self` as a new vector, the same as
+    /// `truncate`.
+    #[inline]
+    pub fn xyz(self) -> Vec3 {
+        self.truncate()
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> f32 {
+        unsafe { vgetq_lane_f32::<0>(self.0) }
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> f32 {
+        unsafe { vgetq_lane_f32::<1>(self.0) }
+    }
+
+    /// Returns element `z`.
+    #[inline]
+    pub fn z(self) -> f32 {
+        unsafe { vgetq_lane_f32::<2>(self.0) }
+    }
+
+    /// Returns element `w`.
+    #[inline]
+    pub fn w(self) -> f32 {
+        unsafe { vgetq_lane_f32::<3>(self.0) }
+    }
+
+    /// Sets element `x`.
+    #[inline]
+    pub fn set_x(&mut self, x: f32) {
+        unsafe { self.0 = vsetq_lane_f32::<0>(x, self.0) }
+    }
+
+    /// Sets element `y`.
+    #[inline]
+    pub fn set_y(&mut self, y: f32) {
+        unsafe { self.0 = vsetq_lane_f32::<1>(y, self.0) }
+    }
+
+    /// Sets element `z`.
+    #[inline]
+    pub fn set_z(&mut self, z: f32) {
+        unsafe { self.0 = vsetq_lane_f32::<2>(z, self.0) }
+    }
+
+    /// Sets element `w`.
+    #[inline]
+    pub fn set_w(&mut self, w: f32) {
+        unsafe { self.0 = vsetq_lane_f32::<3>(w, self.0) }
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `x`.
+    #[inline]
+    pub(crate) fn dup_x(self) -> Self {
+        unsafe { Self(vdupq_laneq_f32::<0>(self.0)) }
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `y`.
+    #[inline]
+    pub(crate) fn dup_y(self) -> Self {
+        unsafe { Self(vdupq_laneq_f32::<1>(self.0)) }
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `z`.
+    #[inline]
+    pub(crate) fn dup_z(self) -> Self {
+        unsafe { Self(vdupq_laneq_f32::<2>(self.0)) }
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `w`.
+    #[inline]
+    pub(crate) fn dup_w(self) -> Self {
+        unsafe { Self(vdupq_laneq_f32::<3>(self.0)) }
+    }
+
+    /// Computes the 4D dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> f32 {
+        unsafe { vaddvq_f32(vmulq_f32(self.0, other.0)) }
+    }
+
+    /// Computes the dot product of `self` and `other`, returning the result in
+    /// all elements of a vector.
+    ///
+    /// This keeps the result in SIMD registers when it is used in further
+    /// vector arithmetic such as normalization.
+    #[inline]
+    pub fn dot_into_vec(self, other: Self) -> Self {
+        unsafe { Self(vdupq_n_f32(self.dot(other))) }
+    }
+
+    /// Computes the 4D length of `self`.
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared 4D length of `self`.
+    ///
+    /// This is generally faster than `Vec4::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / Vec4::length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_reciprocal(self) -> f32 {
+        // vrsqrteq_f32 is lower precision
+        1.0 / self.length()
+    }
+
+    /// Computes an approximation of `1.0 / Vec4::length()`.
+    ///
+    /// This refines the `vrsqrteq_f32` estimate with two Newton-Raphson steps,
+    /// which is faster than `length_reciprocal` at the cost of a small loss of
+    /// precision. The NEON estimate is only accurate to about 8 bits so a single
+    /// step is not enough for `f32`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f32 {
+        let dot = self.dot_into_vec(self);
+        unsafe { vgetq_lane_f32::<0>(rsqrt_nr(dot.0)) }
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    ///
+    /// This scales `self` by `length_recip`, so the result can differ from
+    /// `self / self.length()` in the last bits. Vectors whose squared length is
+    /// zero, denormal or overflows are divided by `length()` instead.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let dot = self.dot_into_vec(self);
+        // The estimate is infinite for a zero or denormal squared length and zero
+        // for an infinite one, so those use the exact `sqrt` and division.
+        let exact = dot.cmplt(Self::splat(f32::MIN_POSITIVE)) | dot.cmpge(Self::splat(f32::MAX));
+        unsafe {
+            Self::select(
+                exact,
+                Self(vdivq_f32(self.0, vsqrtq_f32(dot.0))),
+                Self(vmulq_f32(self.0, rsqrt_nr(dot.0))),
+            )
+        }
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2), w: min(w1, w2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        unsafe { Self(vminnmq_f32(self.0, other.0)) }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2), w: max(w1, w2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        unsafe { Self(vmaxnmq_f32(self.0, other.0)) }
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z, w)`.
+    #[inline]
+    pub fn min_element(self) -> f32 {
+        unsafe { vminnmvq_f32(self.0) }
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z, w)`.
+    #[inline]
+    pub fn max_element(self) -> f32 {
+        unsafe { vmaxnmvq_f32(self.0) }
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2, w1 == w2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_neon(vceqq_f32(self.0, other.0)) }
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2, w1 != w2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_neon(vmvnq_u32(vceqq_f32(self.0, other.0))) }
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2, w1 >= w2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_neon(vcgeq_f32(self.0, other.0)) }
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2, w1 > w2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_neon(vcgtq_f32(self.0, other.0)) }
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2, w1 <= w2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_neon(vcleq_f32(self.0, other.0)) }
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2, w1 < w2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_neon(vcltq_f32(self.0, other.0)) }
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on `mask`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec4, if_true: Self, if_false: Self) -> Self {
+        unsafe { Self(vbslq_f32(bvec4_to_neon(mask), if_true.0, if_false.0)) }
+    }
+
+    /// Creates a new `Vec4` from the first four values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f32]) -> Self {
+        assert!(slice.len() >= 4);
+        unsafe { Self(vld1q_f32(slice.as_ptr())) }
+    }
+
+    /// Writes the elements of `self` to the first four elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f32]) {
+        assert!(slice.len() >= 4);
+        unsafe { vst1q_f32(slice.as_mut_ptr(), self.0) }
+    }
+
+    /// Computes `(self * a) + b` element-wise.
+    ///
+    /// This uses a fused multiply-add instruction, which rounds only once,
+    /// when the target supports it, for example when the `fma` target
+    /// feature is enabled on x86. Otherwise the multiplication and addition
+    /// are rounded separately.
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        unsafe { Self(vfmaq_f32(b.0, self.0, a.0)) }
+    }
+
+    /// Per element negative multiplication/subtraction of the three inputs `-((self * a) - b)`
+    /// This is mathematically equivalent to `b - (self * a)`
+    #[inline]
+    pub(crate) fn neg_mul_sub(self, a: Self, b: Self) -> Self {
+        unsafe { Self(vmlsq_f32(b.0, self.0, a.0)) }
+    }
+
+    /// Returns a new `Vec4` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    #[inline]
+    pub fn sign(self) -> Self {
+        let mask = self.cmpge(Self::zero());
+        Self::select(mask, Self::splat(1.0), Self::splat(-1.0))
+    }
+
+    /// Returns a new `Vec4` containing the absolute value of each element of the original
+    /// `Vec4`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        unsafe { Self(vabsq_f32(self.0)) }
+    }
+
+    /// Returns a new vector containing the square root of each element of
+    /// the original vector.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        unsafe { Self(vsqrtq_f32(self.0)) }
+    }
+}
+
+impl fmt::Debug for Vec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y, z, w) = (*self).into();
+        fmt.debug_tuple("Vec4")
+            .field(&x)
+            .field(&y)
+            .field(&z)
+            .field(&w)
+            .finish()
+    }
+}
+
+impl fmt::Display for Vec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y, z, w) = (*self).into();
+        write!(fmt, "[{}, {}, {}, {}]", x, y, z, w)
+    }
+}
+
+impl Div<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        unsafe { Self(vdivq_f32(self.0, other.0)) }
+    }
+}
+
+impl DivAssign<Vec4> for Vec4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        unsafe { self.0 = vdivq_f32(self.0, other.0) }
+    }
+}
+
+impl Div<f32> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f32) -> Self {
+        unsafe { Self(vdivq_f32(self.0, vdupq_n_f32(other))) }
+    }
+}
+
+impl DivAssign<f32> for Vec4 {
+    #[inline]
+    fn div_assign(&mut self, other: f32) {
+        unsafe { self.0 = vdivq_f32(self.0, vdupq_n_f32(other)) }
+    }
+}
+
+impl Mul<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        unsafe { Self(vmulq_f32(self.0, other.0)) }
+    }
+}
+
+impl MulAssign<Vec4> for Vec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        unsafe { self.0 = vmulq_f32(self.0, other.0) }
+    }
+}
+
+impl Mul<f32> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        unsafe { Self(vmulq_n_f32(self.0, other)) }
+    }
+}
+
+impl MulAssign<f32> for Vec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        unsafe { self.0 = vmulq_n_f32(self.0, other) }
+    }
+}
+
+impl Mul<Vec4> for f32 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, other: Vec4) -> Vec4 {
+        unsafe { Vec4(vmulq_n_f32(other.0, self)) }
+    }
+}
+
+impl Add for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        unsafe { Self(vaddq_f32(self.0, other.0)) }
+    }
+}
+
+impl AddAssign for Vec4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        unsafe { self.0 = vaddq_f32(self.0, other.0) }
+    }
+}
+
+impl Sub for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        unsafe { Self(vsubq_f32(self.0, other.0)) }
+    }
+}
+
+impl SubAssign for Vec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        unsafe { self.0 = vsubq_f32(self.0, other.0) }
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        unsafe { Self(vnegq_f32(self.0)) }
+    }
+}
+
+impl Default for Vec4 {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl PartialEq for Vec4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmpeq(*other).all()
+    }
+}
+
+impl PartialOrd for Vec4 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other.as_ref())
+    }
+}
+
+impl From<Vec4> for float32x4_t {
+    #[inline]
+    fn from(t: Vec4) -> Self {
+        t.0
+    }
+}
+
+impl From<float32x4_t> for Vec4 {
+    #[inline]
+    fn from(t: float32x4_t) -> Self {
+        Self(t)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Vec4 {
+    #[inline]
+    fn from(t: (f32, f32, f32, f32)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<Vec4> for (f32, f32, f32, f32) {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        let a: [f32; 4] = v.into();
+        (a[0], a[1], a[2], a[3])
+    }
+}
+
+impl From<[f32; 4]> for Vec4 {
+    #[inline]
+    fn from(a: [f32; 4]) -> Self {
+        unsafe { Self(vld1q_f32(a.as_ptr())) }
+    }
+}
+
+impl From<Vec4> for [f32; 4] {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        let mut out: MaybeUninit<Align16<[f32; 4]>> = MaybeUninit::uninit();
+        unsafe {
+            vst1q_f32(out.as_mut_ptr() as *mut f32, v.0);
+            out.assume_init().0
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Vec4> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4 {
+        rng.gen::<[f32; 4]>().into()
+    }
+}
@@ -6,13 +6,14 @@ use rand::{
     Rng,
 };
 
-use crate::{f32::Vec3, Align16};
+use crate::{bool::BVec4, f32::Vec3, Align16};
 
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use super::x86_utils::{m128_mul_add, m128_neg_mul_sub, m128_rsqrt_nr};
 use std::{cmp::Ordering, f32, fmt, mem::MaybeUninit, ops::*};
 
 pub(crate) const X_AXIS: Align16<(f32, f32, f32, f32)> = Align16((1.0, 0.0, 0.0, 0.0));
@@ -96,7 +97,15 @@ impl Vec4 {
     /// removing `w`.
     #[inline]
     pub fn truncate(self) -> Vec3 {
-        self.0.into()
+        let (x, y, z, _) = self.into();
+        Vec3::new(x, y, z)
+    }
+
+    /// Returns the `x`, `y` and `z` elements of `self` as a new vector, the same as
+    /// `truncate`.
+    #[inline]
+    pub fn xyz(self) -> Vec3 {
+        self.truncate()
     }
 
     /// Returns element `x`.
@@ -195,9 +204,13 @@ impl Vec4 {
         _mm_add_ps(x2z2_y2w2_0_0, y2w2_0_0_0)
     }
 
-    /// Returns Vec4 dot in all lanes of Vec4
+    /// Computes the dot product of `self` and `other`, returning the result in
+    /// all elements of a vector.
+    ///
+    /// This keeps the result in SIMD registers when it is used in further
+    /// vector arithmetic such as normalization.
     #[inline]
-    fn dot_as_vec4(self, other: Self) -> Self {
+    pub fn dot_into_vec(self, other: Self) -> Self {
         unsafe {
             let dot_in_x = self.dot_as_m128(other);
             Self(_mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00))
@@ -213,7 +226,7 @@ impl Vec4 {
     /// Computes the 4D length of `self`.
     #[inline]
     pub fn length(self) -> f32 {
-        let dot = self.dot_as_vec4(self);
+        let dot = self.dot_into_vec(self);
         unsafe { _mm_cvtss_f32(_mm_sqrt_ps(dot.0)) }
     }
 
@@ -231,20 +244,46 @@ impl Vec4 {
     /// For valid results, `self` must _not_ be of length zero.
     #[inline]
     pub fn length_reciprocal(self) -> f32 {
-        let dot = self.dot_as_vec4(self);
+        let dot = self.dot_into_vec(self);
         unsafe {
             // _mm_rsqrt_ps is lower precision
             _mm_cvtss_f32(_mm_div_ps(_mm_set_ps1(1.0), _mm_sqrt_ps(dot.0)))
         }
     }
 
+    /// Computes an approximation of `1.0 / Vec4::length()`.
+    ///
+    /// This refines the `_mm_rsqrt_ps` estimate with one Newton-Raphson step,
+    /// which is faster than `length_reciprocal` at the cost of a small loss of
+    /// precision.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f32 {
+        let dot = self.dot_into_vec(self);
+        unsafe { _mm_cvtss_f32(m128_rsqrt_nr(dot.0)) }
+    }
+
     /// Returns `self` normalized to length 1.0.
     ///
     /// For valid results, `self` must _not_ be of length zero.
+    ///
+    /// This scales `self` by `length_recip`, so the result can differ from
+    /// `self / self.length()` in the last bits. Vectors whose squared length is
+    /// zero, denormal or overflows are divided by `length()` instead.
     #[inline]
     pub fn normalize(self) -> Self {
-        let dot = self.dot_as_vec4(self);
-        unsafe { Self(_mm_div_ps(self.0, _mm_sqrt_ps(dot.0))) }
+        let dot = self.dot_into_vec(self);
+        // The estimate is infinite for a zero or denormal squared length and zero
+        // for an infinite one, so those use the exact `sqrt` and division.
+        let exact = dot.cmplt(Self::splat(f32::MIN_POSITIVE)) | dot.cmpge(Self::splat(f32::MAX));
+        unsafe {
+            Self::select(
+                exact,
+                Self(_mm_div_ps(self.0, _mm_sqrt_ps(dot.0))),
+                Self(_mm_mul_ps(self.0, m128_rsqrt_nr(dot.0))),
+            )
+        }
     }
 
     /// Returns the vertical minimum of `self` and `other`.
@@ -294,57 +333,72 @@ impl Vec4 {
     }
 
     /// Performs a vertical `==` comparison between `self` and `other`,
-    /// returning a `Vec4Mask` of the results.
+    /// returning a `BVec4` of the results.
     ///
     /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2, w1 == w2]`.
     #[inline]
-    pub fn cmpeq(self, other: Self) -> Vec4Mask {
-        unsafe { Vec4Mask(_mm_cmpeq_ps(self.0, other.0)) }
+    pub fn cmpeq(self, other: Self) -> BVec4 {
+        unsafe { BVec4(_mm_cmpeq_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `!=` comparison between `self` and `other`,
-    /// returning a `Vec4Mask` of the results.
+    /// returning a `BVec4` of the results.
     ///
     /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2, w1 != w2]`.
     #[inline]
-    pub fn cmpne(self, other: Self) -> Vec4Mask {
-        unsafe { Vec4Mask(_mm_cmpneq_ps(self.0, other.0)) }
+    pub fn cmpne(self, other: Self) -> BVec4 {
+        unsafe { BVec4(_mm_cmpneq_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `>=` comparison between `self` and `other`,
-    /// returning a `Vec4Mask` of the results.
+    /// returning a `BVec4` of the results.
     ///
     /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2, w1 >= w2]`.
     #[inline]
-    pub fn cmpge(self, other: Self) -> Vec4Mask {
-        unsafe { Vec4Mask(_mm_cmpge_ps(self.0, other.0)) }
+    pub fn cmpge(self, other: Self) -> BVec4 {
+        unsafe { BVec4(_mm_cmpge_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `>` comparison between `self` and `other`,
-    /// returning a `Vec4Mask` of the results.
+    /// returning a `BVec4` of the results.
     ///
     /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2, w1 > w2]`.
     #[inline]
-    pub fn cmpgt(self, other: Self) -> Vec4Mask {
-        unsafe { Vec4Mask(_mm_cmpgt_ps(self.0, other.0)) }
+    pub fn cmpgt(self, other: Self) -> BVec4 {
+        unsafe { BVec4(_mm_cmpgt_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `<=` comparison between `self` and `other`,
-    /// returning a `Vec4Mask` of the results.
+    /// returning a `BVec4` of the results.
     ///
     /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2, w1 <= w2]`.
     #[inline]
-    pub fn cmple(self, other: Self) -> Vec4Mask {
-        unsafe { Vec4Mask(_mm_cmple_ps(self.0, other.0)) }
+    pub fn cmple(self, other: Self) -> BVec4 {
+        unsafe { BVec4(_mm_cmple_ps(self.0, other.0)) }
     }
 
     /// Performs a vertical `<` comparison between `self` and `other`,
-    /// returning a `Vec4Mask` of the results.
+    /// returning a `BVec4` of the results.
     ///
     /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2, w1 < w2]`.
     #[inline]
-    pub fn cmplt(self, other: Self) -> Vec4Mask {
-        unsafe { Vec4Mask(_mm_cmplt_ps(self.0, other.0)) }
+    pub fn cmplt(self, other: Self) -> BVec4 {
+        unsafe { BVec4(_mm_cmplt_ps(self.0, other.0)) }
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on `mask`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec4, if_true: Self, if_false: Self) -> Self {
+        unsafe {
+            Self(_mm_or_ps(
+                _mm_andnot_ps(mask.0, if_false.0),
+                _mm_and_ps(if_true.0, mask.0),
+            ))
+        }
     }
 
     /// Creates a new `Vec4` from the first four values in `slice`.
@@ -371,17 +425,32 @@ impl Vec4 {
         }
     }
 
-    /// Per element multiplication/addition of the three inputs: b + (self * a)
+    /// Computes `(self * a) + b` element-wise.
+    ///
+    /// This uses a fused multiply-add instruction, which rounds only once,
+    /// when the target supports it, for example when the `fma` target
+    /// feature is enabled on x86. Otherwise the multiplication and addition
+    /// are rounded separately.
     #[inline]
-    pub(crate) fn mul_add(self, a: Self, b: Self) -> Self {
-        unsafe { Self(_mm_add_ps(_mm_mul_ps(self.0, a.0), b.0)) }
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        unsafe { Self(m128_mul_add(self.0, a.0, b.0)) }
     }
 
     /// Per element negative multiplication/subtraction of the three inputs `-((self * a) - b)`
     /// This is mathematically equivalent to `b - (self * a)`
     #[inline]
     pub(crate) fn neg_mul_sub(self, a: Self, b: Self) -> Self {
-        unsafe { Self(_mm_sub_ps(b.0, _mm_mul_ps(self.0, a.0))) }
+        unsafe { Self(m128_neg_mul_sub(self.0, a.0, b.0)) }
+    }
+
+    /// Returns a new `Vec4` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    #[inline]
+    pub fn sign(self) -> Self {
+        let mask = self.cmpge(Self::zero());
+        mask.select(Self::splat(1.0), Self::splat(-1.0))
     }
 
     /// Returns a new `Vec4` containing the absolute value of each element of the original
@@ -395,6 +464,13 @@ impl Vec4 {
             ))
         }
     }
+
+    /// Returns a new vector containing the square root of each element of
+    /// the original vector.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        unsafe { Self(_mm_sqrt_ps(self.0)) }
+    }
 }
 
 impl fmt::Display for Vec4 {
@@ -510,7 +586,7 @@ impl Neg for Vec4 {
     type Output = Self;
     #[inline]
     fn neg(self) -> Self {
-        unsafe { Self(_mm_sub_ps(_mm_set1_ps(0.0), self.0)) }
+        unsafe { Self(_mm_xor_ps(_mm_set1_ps(-0.0), self.0)) }
     }
 }
 
@@ -536,7 +612,6 @@ impl PartialOrd for Vec4 {
 }
 
 impl From<Vec4> for __m128 {
-    // TODO: write test
     #[cfg_attr(tarpaulin, skip)]
     #[inline]
     fn from(t: Vec4) -> Self {
@@ -594,120 +669,3 @@ impl Distribution<Vec4> for Standard {
         rng.gen::<[f32; 4]>().into()
     }
 }
-
-/// A 4-dimensional vector mask.
-///
-/// This type is typically created by comparison methods on `Vec4`.  It is
-/// essentially a vector of four boolean values.
-#[derive(Clone, Copy)]
-#[repr(C)]
-pub struct Vec4Mask(__m128);
-
-impl Vec4Mask {
-    /// Creates a new `Vec4Mask`.
-    #[inline]
-    pub fn new(x: bool, y: bool, z: bool, w: bool) -> Self {
-        const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
-        unsafe {
-            Self(_mm_set_ps(
-                f32::from_bits(MASK[w as usize]),
-                f32::from_bits(MASK[z as usize]),
-                f32::from_bits(MASK[y as usize]),
-                f32::from_bits(MASK[x as usize]),
-            ))
-        }
-    }
-
-    /// Returns a bitmask with the lowest four bits set from the elements of
-    /// the `Vec4Mask`.
-    ///
-    /// A true element results in a `1` bit and a false element in a `0` bit.
-    /// Element `x` goes into the first lowest bit, element `y` into the
-    /// second, etc.
-    #[inline]
-    pub fn bitmask(self) -> u32 {
-        unsafe { (_mm_movemask_ps(self.0) as u32) }
-    }
-
-    /// Returns true if any of the elements are true, false otherwise.
-    ///
-    /// In other words: `x || y || z || w`.
-    #[inline]
-    pub fn any(self) -> bool {
-        unsafe { _mm_movemask_ps(self.0) != 0 }
-    }
-
-    /// Returns true if all the elements are true, false otherwise.
-    ///
-    /// In other words: `x && y && z && w`.
-    #[inline]
-    pub fn all(self) -> bool {
-        unsafe { _mm_movemask_ps(self.0) == 0xf }
-    }
-
-    /// Creates a new `Vec4` from the elements in `if_true` and `if_false`,
-    /// selecting which to use for each element based on the `Vec4Mask`.
-    ///
-    /// A true element in the mask uses the corresponding element from
-    /// `if_true`, and false uses the element from `if_false`.
-    #[inline]
-    pub fn select(self, if_true: Vec4, if_false: Vec4) -> Vec4 {
-        unsafe {
-            Vec4(_mm_or_ps(
-                _mm_andnot_ps(self.0, if_false.0),
-                _mm_and_ps(if_true.0, self.0),
-            ))
-        }
-    }
-}
-
-impl Default for Vec4Mask {
-    #[inline]
-    fn default() -> Self {
-        unsafe { Self(_mm_setzero_ps()) }
-    }
-}
-
-impl BitAnd for Vec4Mask {
-    type Output = Self;
-
-    #[inline]
-    fn bitand(self, other: Self) -> Self {
-        unsafe { Self(_mm_and_ps(self.0, other.0)) }
-    }
-}
-
-impl BitAndAssign for Vec4Mask {
-    fn bitand_assign(&mut self, other: Self) {
-        *self = *self & other
-    }
-}
-
-impl BitOr for Vec4Mask {
-    type Output = Self;
-
-    #[inline]
-    fn bitor(self, other: Self) -> Self {
-        unsafe { Self(_mm_or_ps(self.0, other.0)) }
-    }
-}
-
-impl BitOrAssign for Vec4Mask {
-    fn bitor_assign(&mut self, other: Self) {
-        *self = *self | other
-    }
-}
-
-impl Not for Vec4Mask {
-    type Output = Self;
-
-    #[inline]
-    fn not(self) -> Self {
-        unsafe {
-            Self(_mm_andnot_ps(
-                self.0,
-                _mm_set_ps1(f32::from_bits(0xff_ff_ff_ff)),
-            ))
-        }
-    }
-}
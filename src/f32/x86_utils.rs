@@ -4,6 +4,7 @@ use std::arch::x86::*;
 use std::arch::x86_64::*;
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
 pub(crate) union UnionCast {
     pub m128: __m128,
     pub m128i: __m128i,
@@ -11,3 +12,92 @@ pub(crate) union UnionCast {
     pub i32x4: [i32; 4],
     pub u32x4: [u32; 4],
 }
+
+/// Computes `(a * b) + c`, using a fused multiply-add when the `fma` target
+/// feature is enabled.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
+#[inline]
+pub(crate) unsafe fn m128_mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
+    #[cfg(target_feature = "fma")]
+    {
+        _mm_fmadd_ps(a, b, c)
+    }
+    #[cfg(not(target_feature = "fma"))]
+    {
+        _mm_add_ps(_mm_mul_ps(a, b), c)
+    }
+}
+
+/// Computes `c - (a * b)`, using a fused negative multiply-add when the `fma`
+/// target feature is enabled.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
+#[inline]
+pub(crate) unsafe fn m128_neg_mul_sub(a: __m128, b: __m128, c: __m128) -> __m128 {
+    #[cfg(target_feature = "fma")]
+    {
+        _mm_fnmadd_ps(a, b, c)
+    }
+    #[cfg(not(target_feature = "fma"))]
+    {
+        _mm_sub_ps(c, _mm_mul_ps(a, b))
+    }
+}
+
+/// Computes an approximation of `1.0 / sqrt(a)`, refining the `_mm_rsqrt_ps`
+/// estimate with one Newton-Raphson step.
+///
+/// The estimate has a relative error of about `1.5 * 2^-12` which one step
+/// brings close to full `f32` precision, while still being cheaper than a
+/// square root followed by a division.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
+#[inline]
+pub(crate) unsafe fn m128_rsqrt_nr(a: __m128) -> __m128 {
+    let y = _mm_rsqrt_ps(a);
+    // y * (1.5 - 0.5 * a * y * y)
+    let half_a_y = _mm_mul_ps(_mm_mul_ps(_mm_set_ps1(0.5), a), y);
+    _mm_mul_ps(y, m128_neg_mul_sub(half_a_y, y, _mm_set_ps1(1.5)))
+}
+
+/// Computes `(a * b) + c` for SIMD code that is compiled both for the baseline
+/// target and for CPUs with FMA detected at runtime.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
+pub(crate) trait M128MulAdd {
+    unsafe fn mul_add(a: __m128, b: __m128, c: __m128) -> __m128;
+}
+
+/// Uses `m128_mul_add`, which is only fused if the `fma` target feature is
+/// enabled at compile time.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
+pub(crate) struct BaselineMulAdd;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl M128MulAdd for BaselineMulAdd {
+    #[inline(always)]
+    unsafe fn mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
+        m128_mul_add(a, b, c)
+    }
+}
+
+/// Always uses a fused multiply-add. Must only be used from functions with the
+/// `fma` target feature enabled.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "runtime-dispatch"
+))]
+pub(crate) struct FmaMulAdd;
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "runtime-dispatch"
+))]
+impl M128MulAdd for FmaMulAdd {
+    #[inline(always)]
+    unsafe fn mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
+        _mm_fmadd_ps(a, b, c)
+    }
+}
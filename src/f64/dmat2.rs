@@ -0,0 +1,13 @@
+use super::{scalar_sin_cos, DMat3, DVec2, DVec4};
+
+#[inline]
+pub fn dmat2(x_axis: DVec2, y_axis: DVec2) -> DMat2 {
+    DMat2::from_cols(x_axis, y_axis)
+}
+
+/// A 2x2 column major matrix of `f64` elements.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct DMat2(pub(crate) DVec4);
+
+impl_mat2!(DMat2, f64, DVec2, DVec4, DMat3);
+impl_mat2_scalar!(DMat2, f64, DVec2, DVec4);
@@ -0,0 +1,21 @@
+use super::{scalar_sin_cos, DMat2, DMat4, DQuat, DVec2, DVec3};
+
+#[inline]
+pub fn dmat3(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3) -> DMat3 {
+    DMat3 {
+        x_axis,
+        y_axis,
+        z_axis,
+    }
+}
+
+/// A 3x3 column major matrix of `f64` elements.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct DMat3 {
+    pub(crate) x_axis: DVec3,
+    pub(crate) y_axis: DVec3,
+    pub(crate) z_axis: DVec3,
+}
+
+impl_mat3!(DMat3, f64, DVec2, DVec3, DMat2, DMat4);
+impl_mat3_quat!(DMat3, f64, DQuat, DVec3);
@@ -0,0 +1,65 @@
+use super::{scalar_sin_cos, DMat3, DQuat, DVec3, DVec4};
+
+#[inline]
+pub fn dmat4(x_axis: DVec4, y_axis: DVec4, z_axis: DVec4, w_axis: DVec4) -> DMat4 {
+    DMat4 {
+        x_axis,
+        y_axis,
+        z_axis,
+        w_axis,
+    }
+}
+
+/// A 4x4 column major matrix of `f64` elements.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct DMat4 {
+    pub(crate) x_axis: DVec4,
+    pub(crate) y_axis: DVec4,
+    pub(crate) z_axis: DVec4,
+    pub(crate) w_axis: DVec4,
+}
+
+impl_mat4!(DMat4, f64, DVec3, DVec4, DMat3);
+impl_mat4_quat!(DMat4, f64, DQuat, DVec3, DVec4);
+impl_mat4_scalar!(DMat4, DVec4);
+
+#[cfg(all(
+    target_arch = "x86",
+    target_feature = "avx",
+    not(feature = "scalar-math")
+))]
+impl_mat4_simd_cols!(DMat4, std::arch::x86::__m256d);
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx",
+    not(feature = "scalar-math")
+))]
+impl_mat4_simd_cols!(DMat4, std::arch::x86_64::__m256d);
+
+impl DMat4 {
+    /// Multiplies two 4x4 matrices.
+    #[inline]
+    pub fn mul_mat4(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.mul_vec4(other.x_axis),
+            y_axis: self.mul_vec4(other.y_axis),
+            z_axis: self.mul_vec4(other.z_axis),
+            w_axis: self.mul_vec4(other.w_axis),
+        }
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        Self {
+            x_axis: DVec4::new(m00, m10, m20, m30),
+            y_axis: DVec4::new(m01, m11, m21, m31),
+            z_axis: DVec4::new(m02, m12, m22, m32),
+            w_axis: DVec4::new(m03, m13, m23, m33),
+        }
+    }
+}
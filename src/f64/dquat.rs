@@ -0,0 +1,66 @@
+use super::{scalar_acos, scalar_sin_cos, DMat3, DMat4, DVec2, DVec3, DVec4};
+
+/// A quaternion of `f64` elements representing an orientation.
+///
+/// This quaternion is intended to be of unit length but may denormalize due to
+/// floating point "error creep" which can occur when successive quaternion
+/// operations are applied.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DQuat(pub(crate) DVec4);
+
+#[inline]
+pub fn dquat(x: f64, y: f64, z: f64, w: f64) -> DQuat {
+    DQuat::new(x, y, z, w)
+}
+
+impl_quat!(DQuat, f64, DVec2, DVec3, DVec4, DMat3, DMat4);
+impl_quat_scalar!(DQuat, DVec3);
+
+#[cfg(all(
+    target_arch = "x86",
+    target_feature = "avx",
+    not(feature = "scalar-math")
+))]
+impl From<DQuat> for std::arch::x86::__m256d {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        q.0.into()
+    }
+}
+
+#[cfg(all(
+    target_arch = "x86",
+    target_feature = "avx",
+    not(feature = "scalar-math")
+))]
+impl From<std::arch::x86::__m256d> for DQuat {
+    #[inline]
+    fn from(t: std::arch::x86::__m256d) -> Self {
+        Self(t.into())
+    }
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx",
+    not(feature = "scalar-math")
+))]
+impl From<DQuat> for std::arch::x86_64::__m256d {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        q.0.into()
+    }
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx",
+    not(feature = "scalar-math")
+))]
+impl From<std::arch::x86_64::__m256d> for DQuat {
+    #[inline]
+    fn from(t: std::arch::x86_64::__m256d) -> Self {
+        Self(t.into())
+    }
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use crate::{
+    bool::BVec2,
+    f32::Vec2,
+    f64::{scalar_sin_cos, DMat2, DVec3},
+    i16::I16Vec2,
+    i32::IVec2,
+    i64::I64Vec2,
+    u16::U16Vec2,
+    u32::UVec2,
+    u64::U64Vec2,
+};
+
+/// A 2-dimensional vector of `f64` elements.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(C)]
+pub struct DVec2(f64, f64);
+
+#[inline]
+pub fn dvec2(x: f64, y: f64) -> DVec2 {
+    DVec2(x, y)
+}
+
+impl_vec2_float_methods!(DVec2, f64, DMat2, BVec2);
+impl_vec2_scalar!(DVec2, f64, BVec2, DVec3);
+impl_vec2_as!(
+    DVec2,
+    as_vec2 => Vec2, f32;
+    as_i16vec2 => I16Vec2, i16;
+    as_u16vec2 => U16Vec2, u16;
+    as_ivec2 => IVec2, i32;
+    as_uvec2 => UVec2, u32;
+    as_i64vec2 => I64Vec2, i64;
+    as_u64vec2 => U64Vec2, u64;
+);
+impl_vec2_try_as!(
+    DVec2,
+    f64,
+    try_as_i16vec2 => I16Vec2, i16;
+    try_as_u16vec2 => U16Vec2, u16;
+    try_as_ivec2 => IVec2, i32;
+    try_as_uvec2 => UVec2, u32;
+    try_as_i64vec2 => I64Vec2, i64;
+    try_as_u64vec2 => U64Vec2, u64;
+);
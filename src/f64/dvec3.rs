@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use crate::{
+    bool::BVec3,
+    f32::{Vec3, Vec3A},
+    f64::{DMat3, DVec2, DVec4},
+    i16::I16Vec3,
+    i32::IVec3,
+    i64::I64Vec3,
+    u16::U16Vec3,
+    u32::UVec3,
+    u64::U64Vec3,
+};
+
+/// A 3-dimensional vector of `f64` elements.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(C)]
+pub struct DVec3(f64, f64, f64);
+
+#[inline]
+pub fn dvec3(x: f64, y: f64, z: f64) -> DVec3 {
+    DVec3(x, y, z)
+}
+
+impl_vec3_float_methods!(DVec3, f64, DMat3, BVec3);
+impl_vec3_scalar!(DVec3, f64, BVec3, DVec2, DVec4);
+impl_vec3_as!(
+    DVec3,
+    as_vec3 => Vec3, f32;
+    as_vec3a => Vec3A, f32;
+    as_i16vec3 => I16Vec3, i16;
+    as_u16vec3 => U16Vec3, u16;
+    as_ivec3 => IVec3, i32;
+    as_uvec3 => UVec3, u32;
+    as_i64vec3 => I64Vec3, i64;
+    as_u64vec3 => U64Vec3, u64;
+);
+impl_vec3_try_as!(
+    DVec3,
+    f64,
+    try_as_i16vec3 => I16Vec3, i16;
+    try_as_u16vec3 => U16Vec3, u16;
+    try_as_ivec3 => IVec3, i32;
+    try_as_uvec3 => UVec3, u32;
+    try_as_i64vec3 => I64Vec3, i64;
+    try_as_u64vec3 => U64Vec3, u64;
+);
@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+use super::{DMat4, DVec4};
+use crate::{
+    bool::BVec4, f32::Vec4, i16::I16Vec4, i32::IVec4, i64::I64Vec4, u16::U16Vec4, u32::UVec4,
+    u64::U64Vec4,
+};
+
+#[inline]
+pub fn dvec4(x: f64, y: f64, z: f64, w: f64) -> DVec4 {
+    DVec4::new(x, y, z, w)
+}
+
+impl_vec4_float_methods!(DVec4, f64, DMat4, BVec4);
+impl_vec4_as!(
+    DVec4,
+    as_vec4 => Vec4, f32;
+    as_i16vec4 => I16Vec4, i16;
+    as_u16vec4 => U16Vec4, u16;
+    as_ivec4 => IVec4, i32;
+    as_uvec4 => UVec4, u32;
+    as_i64vec4 => I64Vec4, i64;
+    as_u64vec4 => U64Vec4, u64;
+);
+impl_vec4_try_as!(
+    DVec4,
+    f64,
+    try_as_i16vec4 => I16Vec4, i16;
+    try_as_u16vec4 => U16Vec4, u16;
+    try_as_ivec4 => IVec4, i32;
+    try_as_uvec4 => UVec4, u32;
+    try_as_i64vec4 => I64Vec4, i64;
+    try_as_u64vec4 => U64Vec4, u64;
+);
@@ -0,0 +1,686 @@
+#![allow(dead_code)]
+
+#[cfg(feature = "rand")]
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+use crate::{bool::BVec4, f64::DVec3};
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use std::{cmp::Ordering, f64, fmt, mem::MaybeUninit, ops::*};
+
+// `BVec4` stores four 32-bit lanes while AVX comparisons produce four 64-bit
+// lanes, so masks are converted through their bitmask.
+#[inline]
+unsafe fn bvec4_from_m256d(mask: __m256d) -> BVec4 {
+    let bits = _mm256_movemask_pd(mask);
+    BVec4::new(bits & 1 != 0, bits & 2 != 0, bits & 4 != 0, bits & 8 != 0)
+}
+
+#[inline]
+unsafe fn bvec4_to_m256d(mask: BVec4) -> __m256d {
+    let bits = mask.bitmask();
+    _mm256_castsi256_pd(_mm256_set_epi64x(
+        -(((bits >> 3) & 1) as i64),
+        -(((bits >> 2) & 1) as i64),
+        -(((bits >> 1) & 1) as i64),
+        -((bits & 1) as i64),
+    ))
+}
+
+/// A 4-dimensional vector of `f64` elements.
+///
+/// This type is 32 byte aligned.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DVec4(pub(crate) __m256d);
+
+impl DVec4 {
+    /// Creates a new `DVec4`.
+    #[inline]
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        unsafe { Self(_mm256_set_pd(w, z, y, x)) }
+    }
+
+    /// Creates a new `DVec4` with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        unsafe { Self(_mm256_set1_pd(0.0)) }
+    }
+
+    /// Creates a new `DVec4` with all elements set to `1.0`.
+    #[inline]
+    pub fn one() -> Self {
+        unsafe { Self(_mm256_set1_pd(1.0)) }
+    }
+
+    /// Creates a new `DVec4` with values `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_x() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates a new `DVec4` with values `[x: 0.0, y: 1.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_y() -> Self {
+        Self::new(0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a new `DVec4` with values `[x: 0.0, y: 0.0, z: 1.0, w: 0.0]`.
+    #[inline]
+    pub fn unit_z() -> Self {
+        Self::new(0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Creates a new `DVec4` with values `[x: 0.0, y: 0.0, z: 0.0, w: 1.0]`.
+    #[inline]
+    pub fn unit_w() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates a new `DVec4` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: f64) -> Self {
+        unsafe { Self(_mm256_set1_pd(v)) }
+    }
+
+    /// Creates a `DVec3` from the first three elements of `self`,
+    /// removing `w`.
+    #[inline]
+    pub fn truncate(self) -> DVec3 {
+        let (x, y, z, _) = self.into();
+        DVec3::new(x, y, z)
+    }
+
+    /// Returns the `x`, `y` and `z` elements of `self` as a new vector, the same as
+    /// `truncate`.
+    #[inline]
+    pub fn xyz(self) -> DVec3 {
+        self.truncate()
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> f64 {
+        unsafe { _mm256_cvtsd_f64(self.0) }
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> f64 {
+        unsafe {
+            let xy = _mm256_castpd256_pd128(self.0);
+            _mm_cvtsd_f64(_mm_unpackhi_pd(xy, xy))
+        }
+    }
+
+    /// Returns element `z`.
+    #[inline]
+    pub fn z(self) -> f64 {
+        unsafe { _mm_cvtsd_f64(_mm256_extractf128_pd(self.0, 1)) }
+    }
+
+    /// Returns element `w`.
+    #[inline]
+    pub fn w(self) -> f64 {
+        unsafe {
+            let zw = _mm256_extractf128_pd(self.0, 1);
+            _mm_cvtsd_f64(_mm_unpackhi_pd(zw, zw))
+        }
+    }
+
+    /// Sets element `x`.
+    #[inline]
+    pub fn set_x(&mut self, x: f64) {
+        unsafe { self.0 = _mm256_blend_pd(self.0, _mm256_set1_pd(x), 0b0001) }
+    }
+
+    /// Sets element `y`.
+    #[inline]
+    pub fn set_y(&mut self, y: f64) {
+        unsafe { self.0 = _mm256_blend_pd(self.0, _mm256_set1_pd(y), 0b0010) }
+    }
+
+    /// Sets element `z`.
+    #[inline]
+    pub fn set_z(&mut self, z: f64) {
+        unsafe { self.0 = _mm256_blend_pd(self.0, _mm256_set1_pd(z), 0b0100) }
+    }
+
+    /// Sets element `w`.
+    #[inline]
+    pub fn set_w(&mut self, w: f64) {
+        unsafe { self.0 = _mm256_blend_pd(self.0, _mm256_set1_pd(w), 0b1000) }
+    }
+
+    /// Returns a `DVec4` with all elements set to the value of element `x`.
+    #[inline]
+    pub(crate) fn dup_x(self) -> Self {
+        unsafe {
+            let xyxy = _mm256_permute2f128_pd(self.0, self.0, 0x00);
+            Self(_mm256_permute_pd(xyxy, 0b0000))
+        }
+    }
+
+    /// Returns a `DVec4` with all elements set to the value of element `y`.
+    #[inline]
+    pub(crate) fn dup_y(self) -> Self {
+        unsafe {
+            let xyxy = _mm256_permute2f128_pd(self.0, self.0, 0x00);
+            Self(_mm256_permute_pd(xyxy, 0b1111))
+        }
+    }
+
+    /// Returns a `DVec4` with all elements set to the value of element `z`.
+    #[inline]
+    pub(crate) fn dup_z(self) -> Self {
+        unsafe {
+            let zwzw = _mm256_permute2f128_pd(self.0, self.0, 0x11);
+            Self(_mm256_permute_pd(zwzw, 0b0000))
+        }
+    }
+
+    /// Returns a `DVec4` with all elements set to the value of element `w`.
+    #[inline]
+    pub(crate) fn dup_w(self) -> Self {
+        unsafe {
+            let zwzw = _mm256_permute2f128_pd(self.0, self.0, 0x11);
+            Self(_mm256_permute_pd(zwzw, 0b1111))
+        }
+    }
+
+    /// Calculates the DVec4 dot product and returns answer in x lane of __m128d.
+    #[inline]
+    unsafe fn dot_as_m128d(self, other: Self) -> __m128d {
+        let x2_y2_z2_w2 = _mm256_mul_pd(self.0, other.0);
+        let x2_y2 = _mm256_castpd256_pd128(x2_y2_z2_w2);
+        let z2_w2 = _mm256_extractf128_pd(x2_y2_z2_w2, 1);
+        let x2z2_y2w2 = _mm_add_pd(x2_y2, z2_w2);
+        _mm_add_sd(x2z2_y2w2, _mm_unpackhi_pd(x2z2_y2w2, x2z2_y2w2))
+    }
+
+    /// Computes the dot product of `self` and `other`, returning the result in
+    /// all elements of a vector.
+    ///
+    /// This keeps the result in SIMD registers when it is used in further
+    /// vector arithmetic such as normalization.
+    #[inline]
+    pub fn dot_into_vec(self, other: Self) -> Self {
+        unsafe {
+            let dot_in_x = self.dot_as_m128d(other);
+            let dot_in_xy = _mm_unpacklo_pd(dot_in_x, dot_in_x);
+            Self(_mm256_insertf128_pd(
+                _mm256_castpd128_pd256(dot_in_xy),
+                dot_in_xy,
+                1,
+            ))
+        }
+    }
+
+    /// Computes the 4D dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> f64 {
+        unsafe { _mm_cvtsd_f64(self.dot_as_m128d(other)) }
+    }
+
+    /// Computes the 4D length of `self`.
+    #[inline]
+    pub fn length(self) -> f64 {
+        unsafe {
+            let dot = self.dot_as_m128d(self);
+            _mm_cvtsd_f64(_mm_sqrt_pd(dot))
+        }
+    }
+
+    /// Computes the squared 4D length of `self`.
+    ///
+    /// This is generally faster than `DVec4::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / DVec4::length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_reciprocal(self) -> f64 {
+        1.0 / self.length()
+    }
+
+    /// Computes an approximation of `1.0 / length()`.
+    ///
+    /// AVX has no reciprocal square root estimate for `f64` lanes, so the result
+    /// is the same as `length_reciprocal`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f64 {
+        self.length_reciprocal()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let dot = self.dot_into_vec(self);
+        unsafe { Self(_mm256_div_pd(self.0, _mm256_sqrt_pd(dot.0))) }
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2), w: min(w1, w2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        unsafe { Self(_mm256_min_pd(self.0, other.0)) }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2), w: max(w1, w2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        unsafe { Self(_mm256_max_pd(self.0, other.0)) }
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z, w)`.
+    #[inline]
+    pub fn min_element(self) -> f64 {
+        unsafe {
+            let xy = _mm256_castpd256_pd128(self.0);
+            let zw = _mm256_extractf128_pd(self.0, 1);
+            let v = _mm_min_pd(xy, zw);
+            let v = _mm_min_pd(v, _mm_unpackhi_pd(v, v));
+            _mm_cvtsd_f64(v)
+        }
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z, w)`.
+    #[inline]
+    pub fn max_element(self) -> f64 {
+        unsafe {
+            let xy = _mm256_castpd256_pd128(self.0);
+            let zw = _mm256_extractf128_pd(self.0, 1);
+            let v = _mm_max_pd(xy, zw);
+            let v = _mm_max_pd(v, _mm_unpackhi_pd(v, v));
+            _mm_cvtsd_f64(v)
+        }
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2, w1 == w2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_m256d(_mm256_cmp_pd(self.0, other.0, _CMP_EQ_OQ)) }
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2, w1 != w2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_m256d(_mm256_cmp_pd(self.0, other.0, _CMP_NEQ_UQ)) }
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2, w1 >= w2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_m256d(_mm256_cmp_pd(self.0, other.0, _CMP_GE_OQ)) }
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2, w1 > w2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_m256d(_mm256_cmp_pd(self.0, other.0, _CMP_GT_OQ)) }
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2, w1 <= w2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_m256d(_mm256_cmp_pd(self.0, other.0, _CMP_LE_OQ)) }
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `BVec4` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2, w1 < w2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> BVec4 {
+        unsafe { bvec4_from_m256d(_mm256_cmp_pd(self.0, other.0, _CMP_LT_OQ)) }
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on `mask`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec4, if_true: Self, if_false: Self) -> Self {
+        unsafe {
+            Self(_mm256_blendv_pd(
+                if_false.0,
+                if_true.0,
+                bvec4_to_m256d(mask),
+            ))
+        }
+    }
+
+    /// Creates a new `DVec4` from the first four values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f64]) -> Self {
+        assert!(slice.len() >= 4);
+        unsafe { Self(_mm256_loadu_pd(slice.as_ptr())) }
+    }
+
+    /// Writes the elements of `self` to the first four elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f64]) {
+        unsafe {
+            assert!(slice.len() >= 4);
+            _mm256_storeu_pd(slice.as_mut_ptr(), self.0);
+        }
+    }
+
+    /// Computes `(self * a) + b` element-wise.
+    ///
+    /// This uses a fused multiply-add instruction, which rounds only once,
+    /// when the target supports it, for example when the `fma` target
+    /// feature is enabled on x86. Otherwise the multiplication and addition
+    /// are rounded separately.
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        #[cfg(target_feature = "fma")]
+        unsafe {
+            Self(_mm256_fmadd_pd(self.0, a.0, b.0))
+        }
+        #[cfg(not(target_feature = "fma"))]
+        unsafe {
+            Self(_mm256_add_pd(_mm256_mul_pd(self.0, a.0), b.0))
+        }
+    }
+
+    /// Per element negative multiplication/subtraction of the three inputs `-((self * a) - b)`
+    /// This is mathematically equivalent to `b - (self * a)`
+    #[inline]
+    pub(crate) fn neg_mul_sub(self, a: Self, b: Self) -> Self {
+        #[cfg(target_feature = "fma")]
+        unsafe {
+            Self(_mm256_fnmadd_pd(self.0, a.0, b.0))
+        }
+        #[cfg(not(target_feature = "fma"))]
+        unsafe {
+            Self(_mm256_sub_pd(b.0, _mm256_mul_pd(self.0, a.0)))
+        }
+    }
+
+    /// Returns a new `DVec4` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    #[inline]
+    pub fn sign(self) -> Self {
+        unsafe {
+            let mask = _mm256_cmp_pd(self.0, _mm256_set1_pd(0.0), _CMP_GE_OQ);
+            Self(_mm256_blendv_pd(
+                _mm256_set1_pd(-1.0),
+                _mm256_set1_pd(1.0),
+                mask,
+            ))
+        }
+    }
+
+    /// Returns a new `DVec4` containing the absolute value of each element of the original
+    /// `DVec4`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        unsafe { Self(_mm256_andnot_pd(_mm256_set1_pd(-0.0), self.0)) }
+    }
+
+    /// Returns a new vector containing the square root of each element of
+    /// the original vector.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        unsafe { Self(_mm256_sqrt_pd(self.0)) }
+    }
+}
+
+impl fmt::Debug for DVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y, z, w) = (*self).into();
+        fmt.debug_tuple("DVec4")
+            .field(&x)
+            .field(&y)
+            .field(&z)
+            .field(&w)
+            .finish()
+    }
+}
+
+impl fmt::Display for DVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y, z, w) = (*self).into();
+        write!(fmt, "[{}, {}, {}, {}]", x, y, z, w)
+    }
+}
+
+impl Div<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        unsafe { Self(_mm256_div_pd(self.0, other.0)) }
+    }
+}
+
+impl DivAssign<DVec4> for DVec4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        unsafe {
+            self.0 = _mm256_div_pd(self.0, other.0);
+        }
+    }
+}
+
+impl Div<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f64) -> Self {
+        unsafe { Self(_mm256_div_pd(self.0, _mm256_set1_pd(other))) }
+    }
+}
+
+impl DivAssign<f64> for DVec4 {
+    #[inline]
+    fn div_assign(&mut self, other: f64) {
+        unsafe { self.0 = _mm256_div_pd(self.0, _mm256_set1_pd(other)) }
+    }
+}
+
+impl Mul<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        unsafe { Self(_mm256_mul_pd(self.0, other.0)) }
+    }
+}
+
+impl MulAssign<DVec4> for DVec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        unsafe {
+            self.0 = _mm256_mul_pd(self.0, other.0);
+        }
+    }
+}
+
+impl Mul<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f64) -> Self {
+        unsafe { Self(_mm256_mul_pd(self.0, _mm256_set1_pd(other))) }
+    }
+}
+
+impl MulAssign<f64> for DVec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: f64) {
+        unsafe { self.0 = _mm256_mul_pd(self.0, _mm256_set1_pd(other)) }
+    }
+}
+
+impl Mul<DVec4> for f64 {
+    type Output = DVec4;
+    #[inline]
+    fn mul(self, other: DVec4) -> DVec4 {
+        unsafe { DVec4(_mm256_mul_pd(_mm256_set1_pd(self), other.0)) }
+    }
+}
+
+impl Add for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        unsafe { Self(_mm256_add_pd(self.0, other.0)) }
+    }
+}
+
+impl AddAssign for DVec4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        unsafe { self.0 = _mm256_add_pd(self.0, other.0) }
+    }
+}
+
+impl Sub for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        unsafe { Self(_mm256_sub_pd(self.0, other.0)) }
+    }
+}
+
+impl SubAssign for DVec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        unsafe { self.0 = _mm256_sub_pd(self.0, other.0) }
+    }
+}
+
+impl Neg for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        unsafe { Self(_mm256_xor_pd(_mm256_set1_pd(-0.0), self.0)) }
+    }
+}
+
+impl Default for DVec4 {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl PartialEq for DVec4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { _mm256_movemask_pd(_mm256_cmp_pd(self.0, other.0, _CMP_EQ_OQ)) == 0b1111 }
+    }
+}
+
+impl PartialOrd for DVec4 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other.as_ref())
+    }
+}
+
+impl From<DVec4> for __m256d {
+    #[inline]
+    fn from(t: DVec4) -> Self {
+        t.0
+    }
+}
+
+impl From<__m256d> for DVec4 {
+    #[inline]
+    fn from(t: __m256d) -> Self {
+        Self(t)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for DVec4 {
+    #[inline]
+    fn from(t: (f64, f64, f64, f64)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<DVec4> for (f64, f64, f64, f64) {
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        let a: [f64; 4] = v.into();
+        (a[0], a[1], a[2], a[3])
+    }
+}
+
+impl From<[f64; 4]> for DVec4 {
+    #[inline]
+    fn from(a: [f64; 4]) -> Self {
+        unsafe { Self(_mm256_loadu_pd(a.as_ptr())) }
+    }
+}
+
+impl From<DVec4> for [f64; 4] {
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        let mut out: MaybeUninit<[f64; 4]> = MaybeUninit::uninit();
+        unsafe {
+            _mm256_storeu_pd(out.as_mut_ptr() as *mut f64, v.0);
+            out.assume_init()
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<DVec4> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DVec4 {
+        rng.gen::<[f64; 4]>().into()
+    }
+}
@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+
+use crate::{bool::BVec4, f64::DVec3};
+
+/// A 4-dimensional vector of `f64` elements.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(C)]
+pub struct DVec4(f64, f64, f64, f64);
+
+impl_vec4_scalar!(DVec4, f64, BVec4, DVec3);
@@ -0,0 +1,10 @@
+#[inline]
+pub(crate) fn scalar_sin_cos(x: f64) -> (f64, f64) {
+    x.sin_cos()
+}
+
+#[inline]
+pub(crate) fn scalar_acos(value: f64) -> f64 {
+    // Clamp input to [-1,1] like the f32 approximation does.
+    value.clamp(-1.0, 1.0).acos()
+}
@@ -0,0 +1,25 @@
+mod dmat2;
+mod dmat3;
+mod dmat4;
+mod dquat;
+mod dvec2;
+mod dvec3;
+mod dvec4;
+#[cfg(all(target_feature = "avx", not(feature = "scalar-math")))]
+mod dvec4_avx;
+#[cfg(any(not(target_feature = "avx"), feature = "scalar-math"))]
+mod dvec4_f64;
+mod funcs;
+
+pub use dmat2::*;
+pub use dmat3::*;
+pub use dmat4::*;
+pub use dquat::*;
+pub use dvec2::*;
+pub use dvec3::*;
+pub use dvec4::*;
+#[cfg(all(target_feature = "avx", not(feature = "scalar-math")))]
+pub use dvec4_avx::*;
+#[cfg(any(not(target_feature = "avx"), feature = "scalar-math"))]
+pub use dvec4_f64::*;
+pub(crate) use funcs::{scalar_acos, scalar_sin_cos};
@@ -0,0 +1,237 @@
+use super::{Fixed, FixedVec2};
+use crate::f32::Mat2;
+
+#[inline]
+pub fn fixedmat2(x_axis: FixedVec2, y_axis: FixedVec2) -> FixedMat2 {
+    FixedMat2 { x_axis, y_axis }
+}
+
+/// A 2x2 column major matrix of 16.16 fixed-point elements.
+///
+/// All arithmetic is performed with integer operations so results are
+/// reproducible across platforms. See [`Fixed`] for rounding and overflow
+/// behaviour.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FixedMat2 {
+    pub(crate) x_axis: FixedVec2,
+    pub(crate) y_axis: FixedVec2,
+}
+
+impl Default for FixedMat2 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl FixedMat2 {
+    /// Creates a 2x2 matrix with all elements set to `0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::from_cols(FixedVec2::zero(), FixedVec2::zero())
+    }
+
+    /// Creates a 2x2 identity matrix.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_cols(FixedVec2::unit_x(), FixedVec2::unit_y())
+    }
+
+    /// Creates a 2x2 matrix from two column vectors.
+    #[inline]
+    pub fn from_cols(x_axis: FixedVec2, y_axis: FixedVec2) -> Self {
+        Self { x_axis, y_axis }
+    }
+
+    /// Creates a 2x2 matrix with its diagonal set to `diagonal` and all other
+    /// entries set to `0`.
+    #[inline]
+    pub fn from_diagonal(diagonal: FixedVec2) -> Self {
+        Self::from_cols(
+            FixedVec2::new(diagonal.x(), Fixed::zero()),
+            FixedVec2::new(Fixed::zero(), diagonal.y()),
+        )
+    }
+
+    /// Returns the first column.
+    #[inline]
+    pub fn x_axis(&self) -> FixedVec2 {
+        self.x_axis
+    }
+
+    /// Returns the second column.
+    #[inline]
+    pub fn y_axis(&self) -> FixedVec2 {
+        self.y_axis
+    }
+
+    /// Sets the first column.
+    #[inline]
+    pub fn set_x_axis(&mut self, x: FixedVec2) {
+        self.x_axis = x;
+    }
+
+    /// Sets the second column.
+    #[inline]
+    pub fn set_y_axis(&mut self, y: FixedVec2) {
+        self.y_axis = y;
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(
+            FixedVec2::new(self.x_axis.x(), self.y_axis.x()),
+            FixedVec2::new(self.x_axis.y(), self.y_axis.y()),
+        )
+    }
+
+    /// Returns the determinant of `self`.
+    ///
+    /// The products are summed at full precision before being rounded back to
+    /// 16.16.
+    #[inline]
+    pub fn determinant(&self) -> Fixed {
+        Fixed::det2(
+            self.x_axis.x(),
+            self.y_axis.x(),
+            self.x_axis.y(),
+            self.y_axis.y(),
+        )
+    }
+
+    /// Transforms a 2D vector.
+    ///
+    /// Each element of the result is rounded once, see [`FixedVec2::dot`].
+    #[inline]
+    pub fn mul_vec2(&self, other: FixedVec2) -> FixedVec2 {
+        let rows = self.transpose();
+        FixedVec2::new(rows.x_axis.dot(other), rows.y_axis.dot(other))
+    }
+
+    /// Multiplies two 2x2 matrices.
+    #[inline]
+    pub fn mul_mat2(&self, other: &Self) -> Self {
+        Self::from_cols(self.mul_vec2(other.x_axis), self.mul_vec2(other.y_axis))
+    }
+
+    /// Converts a `Mat2` to fixed-point, rounding each element to the nearest
+    /// representable value. Out of range elements saturate.
+    #[inline]
+    pub fn from_mat2(m: &Mat2) -> Self {
+        Self::from_cols(
+            FixedVec2::from_vec2(m.x_axis()),
+            FixedVec2::from_vec2(m.y_axis()),
+        )
+    }
+
+    /// Converts `self` to a `Mat2`.
+    #[inline]
+    pub fn to_mat2(&self) -> Mat2 {
+        Mat2::from_cols(self.x_axis.to_vec2(), self.y_axis.to_vec2())
+    }
+}
+
+impl std::fmt::Display for FixedMat2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.x_axis, self.y_axis)
+    }
+}
+
+impl std::ops::Add<FixedMat2> for FixedMat2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self::from_cols(self.x_axis + other.x_axis, self.y_axis + other.y_axis)
+    }
+}
+
+impl std::ops::AddAssign<FixedMat2> for FixedMat2 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::Sub<FixedMat2> for FixedMat2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self::from_cols(self.x_axis - other.x_axis, self.y_axis - other.y_axis)
+    }
+}
+
+impl std::ops::SubAssign<FixedMat2> for FixedMat2 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl std::ops::Mul<FixedMat2> for FixedMat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat2(&other)
+    }
+}
+
+impl std::ops::MulAssign<FixedMat2> for FixedMat2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_mat2(&other);
+    }
+}
+
+impl std::ops::Mul<FixedVec2> for FixedMat2 {
+    type Output = FixedVec2;
+    #[inline]
+    fn mul(self, other: FixedVec2) -> FixedVec2 {
+        self.mul_vec2(other)
+    }
+}
+
+impl std::ops::Mul<Fixed> for FixedMat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Fixed) -> Self {
+        Self::from_cols(self.x_axis * other, self.y_axis * other)
+    }
+}
+
+impl std::ops::MulAssign<Fixed> for FixedMat2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Fixed) {
+        *self = *self * other;
+    }
+}
+
+impl std::ops::Mul<FixedMat2> for Fixed {
+    type Output = FixedMat2;
+    #[inline]
+    fn mul(self, other: FixedMat2) -> FixedMat2 {
+        other * self
+    }
+}
+
+impl std::ops::Neg for FixedMat2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_cols(-self.x_axis, -self.y_axis)
+    }
+}
+
+impl From<Mat2> for FixedMat2 {
+    #[inline]
+    fn from(m: Mat2) -> Self {
+        Self::from_mat2(&m)
+    }
+}
+
+impl From<FixedMat2> for Mat2 {
+    #[inline]
+    fn from(m: FixedMat2) -> Self {
+        m.to_mat2()
+    }
+}
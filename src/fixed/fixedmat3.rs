@@ -0,0 +1,290 @@
+use super::{Fixed, FixedVec3};
+use crate::f32::Mat3;
+
+#[inline]
+pub fn fixedmat3(x_axis: FixedVec3, y_axis: FixedVec3, z_axis: FixedVec3) -> FixedMat3 {
+    FixedMat3 {
+        x_axis,
+        y_axis,
+        z_axis,
+    }
+}
+
+/// A 3x3 column major matrix of 16.16 fixed-point elements.
+///
+/// All arithmetic is performed with integer operations so results are
+/// reproducible across platforms. See [`Fixed`] for rounding and overflow
+/// behaviour.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FixedMat3 {
+    pub(crate) x_axis: FixedVec3,
+    pub(crate) y_axis: FixedVec3,
+    pub(crate) z_axis: FixedVec3,
+}
+
+impl Default for FixedMat3 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl FixedMat3 {
+    /// Creates a 3x3 matrix with all elements set to `0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::from_cols(FixedVec3::zero(), FixedVec3::zero(), FixedVec3::zero())
+    }
+
+    /// Creates a 3x3 identity matrix.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_cols(
+            FixedVec3::unit_x(),
+            FixedVec3::unit_y(),
+            FixedVec3::unit_z(),
+        )
+    }
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[inline]
+    pub fn from_cols(x_axis: FixedVec3, y_axis: FixedVec3, z_axis: FixedVec3) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 3x3 matrix with its diagonal set to `diagonal` and all other
+    /// entries set to `0`.
+    #[inline]
+    pub fn from_diagonal(diagonal: FixedVec3) -> Self {
+        let zero = Fixed::zero();
+        Self::from_cols(
+            FixedVec3::new(diagonal.x(), zero, zero),
+            FixedVec3::new(zero, diagonal.y(), zero),
+            FixedVec3::new(zero, zero, diagonal.z()),
+        )
+    }
+
+    /// Returns the first column.
+    #[inline]
+    pub fn x_axis(&self) -> FixedVec3 {
+        self.x_axis
+    }
+
+    /// Returns the second column.
+    #[inline]
+    pub fn y_axis(&self) -> FixedVec3 {
+        self.y_axis
+    }
+
+    /// Returns the third column.
+    #[inline]
+    pub fn z_axis(&self) -> FixedVec3 {
+        self.z_axis
+    }
+
+    /// Sets the first column.
+    #[inline]
+    pub fn set_x_axis(&mut self, x: FixedVec3) {
+        self.x_axis = x;
+    }
+
+    /// Sets the second column.
+    #[inline]
+    pub fn set_y_axis(&mut self, y: FixedVec3) {
+        self.y_axis = y;
+    }
+
+    /// Sets the third column.
+    #[inline]
+    pub fn set_z_axis(&mut self, z: FixedVec3) {
+        self.z_axis = z;
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(
+            FixedVec3::new(self.x_axis.x(), self.y_axis.x(), self.z_axis.x()),
+            FixedVec3::new(self.x_axis.y(), self.y_axis.y(), self.z_axis.y()),
+            FixedVec3::new(self.x_axis.z(), self.y_axis.z(), self.z_axis.z()),
+        )
+    }
+
+    /// Returns the determinant of `self`.
+    ///
+    /// The 2x2 minors and the final sum are each rounded once.
+    #[inline]
+    pub fn determinant(&self) -> Fixed {
+        let (y, z) = (self.y_axis, self.z_axis);
+        let cross = FixedVec3::new(
+            Fixed::det2(y.y(), y.z(), z.y(), z.z()),
+            Fixed::det2(y.z(), y.x(), z.z(), z.x()),
+            Fixed::det2(y.x(), y.y(), z.x(), z.y()),
+        );
+        self.x_axis.dot(cross)
+    }
+
+    /// Transforms a 3D vector.
+    ///
+    /// Each element of the result is rounded once, see [`FixedVec3::dot`].
+    #[inline]
+    pub fn mul_vec3(&self, other: FixedVec3) -> FixedVec3 {
+        let rows = self.transpose();
+        FixedVec3::new(
+            rows.x_axis.dot(other),
+            rows.y_axis.dot(other),
+            rows.z_axis.dot(other),
+        )
+    }
+
+    /// Multiplies two 3x3 matrices.
+    #[inline]
+    pub fn mul_mat3(&self, other: &Self) -> Self {
+        Self::from_cols(
+            self.mul_vec3(other.x_axis),
+            self.mul_vec3(other.y_axis),
+            self.mul_vec3(other.z_axis),
+        )
+    }
+
+    /// Converts a `Mat3` to fixed-point, rounding each element to the nearest
+    /// representable value. Out of range elements saturate.
+    #[inline]
+    pub fn from_mat3(m: &Mat3) -> Self {
+        Self::from_cols(
+            FixedVec3::from_vec3(m.x_axis()),
+            FixedVec3::from_vec3(m.y_axis()),
+            FixedVec3::from_vec3(m.z_axis()),
+        )
+    }
+
+    /// Converts `self` to a `Mat3`.
+    #[inline]
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_cols(
+            self.x_axis.to_vec3(),
+            self.y_axis.to_vec3(),
+            self.z_axis.to_vec3(),
+        )
+    }
+}
+
+impl std::fmt::Display for FixedMat3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+    }
+}
+
+impl std::ops::Add<FixedMat3> for FixedMat3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self::from_cols(
+            self.x_axis + other.x_axis,
+            self.y_axis + other.y_axis,
+            self.z_axis + other.z_axis,
+        )
+    }
+}
+
+impl std::ops::AddAssign<FixedMat3> for FixedMat3 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::Sub<FixedMat3> for FixedMat3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self::from_cols(
+            self.x_axis - other.x_axis,
+            self.y_axis - other.y_axis,
+            self.z_axis - other.z_axis,
+        )
+    }
+}
+
+impl std::ops::SubAssign<FixedMat3> for FixedMat3 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl std::ops::Mul<FixedMat3> for FixedMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat3(&other)
+    }
+}
+
+impl std::ops::MulAssign<FixedMat3> for FixedMat3 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_mat3(&other);
+    }
+}
+
+impl std::ops::Mul<FixedVec3> for FixedMat3 {
+    type Output = FixedVec3;
+    #[inline]
+    fn mul(self, other: FixedVec3) -> FixedVec3 {
+        self.mul_vec3(other)
+    }
+}
+
+impl std::ops::Mul<Fixed> for FixedMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Fixed) -> Self {
+        Self::from_cols(
+            self.x_axis * other,
+            self.y_axis * other,
+            self.z_axis * other,
+        )
+    }
+}
+
+impl std::ops::MulAssign<Fixed> for FixedMat3 {
+    #[inline]
+    fn mul_assign(&mut self, other: Fixed) {
+        *self = *self * other;
+    }
+}
+
+impl std::ops::Mul<FixedMat3> for Fixed {
+    type Output = FixedMat3;
+    #[inline]
+    fn mul(self, other: FixedMat3) -> FixedMat3 {
+        other * self
+    }
+}
+
+impl std::ops::Neg for FixedMat3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_cols(-self.x_axis, -self.y_axis, -self.z_axis)
+    }
+}
+
+impl From<Mat3> for FixedMat3 {
+    #[inline]
+    fn from(m: Mat3) -> Self {
+        Self::from_mat3(&m)
+    }
+}
+
+impl From<FixedMat3> for Mat3 {
+    #[inline]
+    fn from(m: FixedMat3) -> Self {
+        m.to_mat3()
+    }
+}
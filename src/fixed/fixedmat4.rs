@@ -0,0 +1,369 @@
+use super::{Fixed, FixedVec3, FixedVec4};
+use crate::f32::Mat4;
+
+#[inline]
+pub fn fixedmat4(
+    x_axis: FixedVec4,
+    y_axis: FixedVec4,
+    z_axis: FixedVec4,
+    w_axis: FixedVec4,
+) -> FixedMat4 {
+    FixedMat4 {
+        x_axis,
+        y_axis,
+        z_axis,
+        w_axis,
+    }
+}
+
+/// A 4x4 column major matrix of 16.16 fixed-point elements.
+///
+/// All arithmetic is performed with integer operations so results are
+/// reproducible across platforms. See [`Fixed`] for rounding and overflow
+/// behaviour.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FixedMat4 {
+    pub(crate) x_axis: FixedVec4,
+    pub(crate) y_axis: FixedVec4,
+    pub(crate) z_axis: FixedVec4,
+    pub(crate) w_axis: FixedVec4,
+}
+
+impl Default for FixedMat4 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl FixedMat4 {
+    /// Creates a 4x4 matrix with all elements set to `0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::from_cols(
+            FixedVec4::zero(),
+            FixedVec4::zero(),
+            FixedVec4::zero(),
+            FixedVec4::zero(),
+        )
+    }
+
+    /// Creates a 4x4 identity matrix.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_cols(
+            FixedVec4::unit_x(),
+            FixedVec4::unit_y(),
+            FixedVec4::unit_z(),
+            FixedVec4::unit_w(),
+        )
+    }
+
+    /// Creates a 4x4 matrix from four column vectors.
+    #[inline]
+    pub fn from_cols(
+        x_axis: FixedVec4,
+        y_axis: FixedVec4,
+        z_axis: FixedVec4,
+        w_axis: FixedVec4,
+    ) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Creates a 4x4 matrix with its diagonal set to `diagonal` and all other
+    /// entries set to `0`.
+    #[inline]
+    pub fn from_diagonal(diagonal: FixedVec4) -> Self {
+        let zero = Fixed::zero();
+        Self::from_cols(
+            FixedVec4::new(diagonal.x(), zero, zero, zero),
+            FixedVec4::new(zero, diagonal.y(), zero, zero),
+            FixedVec4::new(zero, zero, diagonal.z(), zero),
+            FixedVec4::new(zero, zero, zero, diagonal.w()),
+        )
+    }
+
+    /// Returns the first column.
+    #[inline]
+    pub fn x_axis(&self) -> FixedVec4 {
+        self.x_axis
+    }
+
+    /// Returns the second column.
+    #[inline]
+    pub fn y_axis(&self) -> FixedVec4 {
+        self.y_axis
+    }
+
+    /// Returns the third column.
+    #[inline]
+    pub fn z_axis(&self) -> FixedVec4 {
+        self.z_axis
+    }
+
+    /// Returns the fourth column.
+    #[inline]
+    pub fn w_axis(&self) -> FixedVec4 {
+        self.w_axis
+    }
+
+    /// Sets the first column.
+    #[inline]
+    pub fn set_x_axis(&mut self, x: FixedVec4) {
+        self.x_axis = x;
+    }
+
+    /// Sets the second column.
+    #[inline]
+    pub fn set_y_axis(&mut self, y: FixedVec4) {
+        self.y_axis = y;
+    }
+
+    /// Sets the third column.
+    #[inline]
+    pub fn set_z_axis(&mut self, z: FixedVec4) {
+        self.z_axis = z;
+    }
+
+    /// Sets the fourth column.
+    #[inline]
+    pub fn set_w_axis(&mut self, w: FixedVec4) {
+        self.w_axis = w;
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(
+            FixedVec4::new(
+                self.x_axis.x(),
+                self.y_axis.x(),
+                self.z_axis.x(),
+                self.w_axis.x(),
+            ),
+            FixedVec4::new(
+                self.x_axis.y(),
+                self.y_axis.y(),
+                self.z_axis.y(),
+                self.w_axis.y(),
+            ),
+            FixedVec4::new(
+                self.x_axis.z(),
+                self.y_axis.z(),
+                self.z_axis.z(),
+                self.w_axis.z(),
+            ),
+            FixedVec4::new(
+                self.x_axis.w(),
+                self.y_axis.w(),
+                self.z_axis.w(),
+                self.w_axis.w(),
+            ),
+        )
+    }
+
+    /// Returns the determinant of `self`.
+    ///
+    /// The 2x2 minors, the 3x3 cofactors and the final sum are each rounded
+    /// once.
+    #[inline]
+    pub fn determinant(&self) -> Fixed {
+        let x = self.x_axis;
+        let (m00, m01, m02, m03) = (x.x(), x.y(), x.z(), x.w());
+        let y = self.y_axis;
+        let (m10, m11, m12, m13) = (y.x(), y.y(), y.z(), y.w());
+        let z = self.z_axis;
+        let (m20, m21, m22, m23) = (z.x(), z.y(), z.z(), z.w());
+        let w = self.w_axis;
+        let (m30, m31, m32, m33) = (w.x(), w.y(), w.z(), w.w());
+
+        let a2323 = Fixed::det2(m22, m23, m32, m33);
+        let a1323 = Fixed::det2(m21, m23, m31, m33);
+        let a1223 = Fixed::det2(m21, m22, m31, m32);
+        let a0323 = Fixed::det2(m20, m23, m30, m33);
+        let a0223 = Fixed::det2(m20, m22, m30, m32);
+        let a0123 = Fixed::det2(m20, m21, m30, m31);
+
+        let c0 = FixedVec3::new(m11, m12, m13).dot(FixedVec3::new(a2323, -a1323, a1223));
+        let c1 = FixedVec3::new(m10, m12, m13).dot(FixedVec3::new(a2323, -a0323, a0223));
+        let c2 = FixedVec3::new(m10, m11, m13).dot(FixedVec3::new(a1323, -a0323, a0123));
+        let c3 = FixedVec3::new(m10, m11, m12).dot(FixedVec3::new(a1223, -a0223, a0123));
+        FixedVec4::new(m00, m01, m02, m03).dot(FixedVec4::new(c0, -c1, c2, -c3))
+    }
+
+    /// Transforms a 4D vector.
+    ///
+    /// Each element of the result is rounded once, see [`FixedVec4::dot`].
+    #[inline]
+    pub fn mul_vec4(&self, other: FixedVec4) -> FixedVec4 {
+        let rows = self.transpose();
+        FixedVec4::new(
+            rows.x_axis.dot(other),
+            rows.y_axis.dot(other),
+            rows.z_axis.dot(other),
+            rows.w_axis.dot(other),
+        )
+    }
+
+    /// Multiplies two 4x4 matrices.
+    #[inline]
+    pub fn mul_mat4(&self, other: &Self) -> Self {
+        Self::from_cols(
+            self.mul_vec4(other.x_axis),
+            self.mul_vec4(other.y_axis),
+            self.mul_vec4(other.z_axis),
+            self.mul_vec4(other.w_axis),
+        )
+    }
+
+    /// Converts a `Mat4` to fixed-point, rounding each element to the nearest
+    /// representable value. Out of range elements saturate.
+    #[inline]
+    pub fn from_mat4(m: &Mat4) -> Self {
+        Self::from_cols(
+            FixedVec4::from_vec4(m.x_axis()),
+            FixedVec4::from_vec4(m.y_axis()),
+            FixedVec4::from_vec4(m.z_axis()),
+            FixedVec4::from_vec4(m.w_axis()),
+        )
+    }
+
+    /// Converts `self` to a `Mat4`.
+    #[inline]
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_cols(
+            self.x_axis.to_vec4(),
+            self.y_axis.to_vec4(),
+            self.z_axis.to_vec4(),
+            self.w_axis.to_vec4(),
+        )
+    }
+}
+
+impl std::fmt::Display for FixedMat4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}]",
+            self.x_axis, self.y_axis, self.z_axis, self.w_axis,
+        )
+    }
+}
+
+impl std::ops::Add<FixedMat4> for FixedMat4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self::from_cols(
+            self.x_axis + other.x_axis,
+            self.y_axis + other.y_axis,
+            self.z_axis + other.z_axis,
+            self.w_axis + other.w_axis,
+        )
+    }
+}
+
+impl std::ops::AddAssign<FixedMat4> for FixedMat4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::Sub<FixedMat4> for FixedMat4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self::from_cols(
+            self.x_axis - other.x_axis,
+            self.y_axis - other.y_axis,
+            self.z_axis - other.z_axis,
+            self.w_axis - other.w_axis,
+        )
+    }
+}
+
+impl std::ops::SubAssign<FixedMat4> for FixedMat4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl std::ops::Mul<FixedMat4> for FixedMat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat4(&other)
+    }
+}
+
+impl std::ops::MulAssign<FixedMat4> for FixedMat4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_mat4(&other);
+    }
+}
+
+impl std::ops::Mul<FixedVec4> for FixedMat4 {
+    type Output = FixedVec4;
+    #[inline]
+    fn mul(self, other: FixedVec4) -> FixedVec4 {
+        self.mul_vec4(other)
+    }
+}
+
+impl std::ops::Mul<Fixed> for FixedMat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Fixed) -> Self {
+        Self::from_cols(
+            self.x_axis * other,
+            self.y_axis * other,
+            self.z_axis * other,
+            self.w_axis * other,
+        )
+    }
+}
+
+impl std::ops::MulAssign<Fixed> for FixedMat4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Fixed) {
+        *self = *self * other;
+    }
+}
+
+impl std::ops::Mul<FixedMat4> for Fixed {
+    type Output = FixedMat4;
+    #[inline]
+    fn mul(self, other: FixedMat4) -> FixedMat4 {
+        other * self
+    }
+}
+
+impl std::ops::Neg for FixedMat4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_cols(-self.x_axis, -self.y_axis, -self.z_axis, -self.w_axis)
+    }
+}
+
+impl From<Mat4> for FixedMat4 {
+    #[inline]
+    fn from(m: Mat4) -> Self {
+        Self::from_mat4(&m)
+    }
+}
+
+impl From<FixedMat4> for Mat4 {
+    #[inline]
+    fn from(m: FixedMat4) -> Self {
+        m.to_mat4()
+    }
+}
@@ -0,0 +1,416 @@
+use super::{Fixed, FixedVec3};
+use crate::{bool::BVec2, f32::Vec2, i32::IVec2};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 2-dimensional vector of 16.16 fixed-point elements.
+///
+/// All arithmetic is performed with integer operations so results are
+/// reproducible across platforms. See [`Fixed`] for rounding and overflow
+/// behaviour.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(C)]
+pub struct FixedVec2(Fixed, Fixed);
+
+#[inline]
+pub fn fixedvec2(x: Fixed, y: Fixed) -> FixedVec2 {
+    FixedVec2(x, y)
+}
+
+impl FixedVec2 {
+    /// Creates a new vector.
+    #[inline]
+    pub fn new(x: Fixed, y: Fixed) -> Self {
+        Self(x, y)
+    }
+
+    /// Creates a new vector with all elements set to `0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Fixed::zero())
+    }
+
+    /// Creates a new vector with all elements set to `1`.
+    #[inline]
+    pub fn one() -> Self {
+        Self::splat(Fixed::one())
+    }
+
+    /// Creates a new vector with values `[x: 1, y: 0]`.
+    #[inline]
+    pub fn unit_x() -> Self {
+        Self(Fixed::one(), Fixed::zero())
+    }
+
+    /// Creates a new vector with values `[x: 0, y: 1]`.
+    #[inline]
+    pub fn unit_y() -> Self {
+        Self(Fixed::zero(), Fixed::one())
+    }
+
+    /// Creates a new vector with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: Fixed) -> Self {
+        Self(v, v)
+    }
+
+    /// Creates a new 3D vector from `self` and the given `z` value.
+    #[inline]
+    pub fn extend(self, z: Fixed) -> FixedVec3 {
+        FixedVec3::new(self.0, self.1, z)
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> Fixed {
+        self.0
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> Fixed {
+        self.1
+    }
+
+    /// Sets element `x`.
+    #[inline]
+    pub fn set_x(&mut self, x: Fixed) {
+        self.0 = x;
+    }
+
+    /// Sets element `y`.
+    #[inline]
+    pub fn set_y(&mut self, y: Fixed) {
+        self.1 = y;
+    }
+
+    /// Computes the dot product of `self` and `other`.
+    ///
+    /// The products are summed at full precision before being rounded back to
+    /// 16.16.
+    #[inline]
+    pub fn dot(self, other: Self) -> Fixed {
+        Fixed::from_wide(self.0.wide_mul(other.0) + self.1.wide_mul(other.1))
+    }
+
+    /// Computes the squared length of `self`.
+    #[inline]
+    pub fn length_squared(self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0), self.1.min(other.1))
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0), self.1.max(other.1))
+    }
+
+    /// Component-wise clamping of values, similar to the scalar `clamp`.
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+        self.max(min).min(max)
+    }
+
+    /// Returns a vector containing the minimum of each element of `self` and
+    /// `min`.
+    #[inline]
+    pub fn min_scalar(self, min: Fixed) -> Self {
+        self.min(Self::splat(min))
+    }
+
+    /// Returns a vector containing the maximum of each element of `self` and
+    /// `max`.
+    #[inline]
+    pub fn max_scalar(self, max: Fixed) -> Self {
+        self.max(Self::splat(max))
+    }
+
+    /// Clamps every element of `self` to the range `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp_scalar(self, min: Fixed, max: Fixed) -> Self {
+        glam_assert!(min <= max, "clamp_scalar: expected min <= max");
+        self.max(Self::splat(min)).min(Self::splat(max))
+    }
+
+    /// Returns a vector containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs(), self.1.abs())
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a boolean vector mask of the results.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> BVec2 {
+        BVec2::new(self.0 == other.0, self.1 == other.1)
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a boolean vector mask of the results.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> BVec2 {
+        BVec2::new(self.0 < other.0, self.1 < other.1)
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a boolean vector mask of the results.
+    #[inline]
+    pub fn cmple(self, other: Self) -> BVec2 {
+        BVec2::new(self.0 <= other.0, self.1 <= other.1)
+    }
+
+    /// Converts a `Vec2` to fixed-point, rounding each element to the nearest
+    /// representable value. Out of range elements saturate.
+    #[inline]
+    pub fn from_vec2(v: Vec2) -> Self {
+        Self(Fixed::from_f32(v.x()), Fixed::from_f32(v.y()))
+    }
+
+    /// Converts `self` to a `Vec2`.
+    #[inline]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.0.to_f32(), self.1.to_f32())
+    }
+
+    /// Converts an `IVec2` to fixed-point.
+    ///
+    /// Elements outside of the range `-32768..=32767` overflow, which panics
+    /// when debug assertions are enabled and wraps otherwise. Use `try_from` to
+    /// check the range instead.
+    #[inline]
+    pub fn from_ivec2(v: IVec2) -> Self {
+        Self(Fixed::from_int(v.x()), Fixed::from_int(v.y()))
+    }
+
+    /// Converts `self` to an `IVec2`, rounding each element towards negative
+    /// infinity.
+    #[inline]
+    pub fn to_ivec2(self) -> IVec2 {
+        IVec2::new(self.0.to_int(), self.1.to_int())
+    }
+}
+
+impl std::fmt::Display for FixedVec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.0, self.1)
+    }
+}
+
+impl std::ops::Div<FixedVec2> for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self(self.0 / other.0, self.1 / other.1)
+    }
+}
+
+impl std::ops::DivAssign<FixedVec2> for FixedVec2 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = Self(self.0 / other.0, self.1 / other.1)
+    }
+}
+
+impl std::ops::Div<Fixed> for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Fixed) -> Self {
+        Self(self.0 / other, self.1 / other)
+    }
+}
+
+impl std::ops::DivAssign<Fixed> for FixedVec2 {
+    #[inline]
+    fn div_assign(&mut self, other: Fixed) {
+        *self = Self(self.0 / other, self.1 / other)
+    }
+}
+
+impl std::ops::Div<FixedVec2> for Fixed {
+    type Output = FixedVec2;
+    #[inline]
+    fn div(self, other: FixedVec2) -> FixedVec2 {
+        FixedVec2(self / other.0, self / other.1)
+    }
+}
+
+impl std::ops::Mul<FixedVec2> for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0, self.1 * other.1)
+    }
+}
+
+impl std::ops::MulAssign<FixedVec2> for FixedVec2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = Self(self.0 * other.0, self.1 * other.1)
+    }
+}
+
+impl std::ops::Mul<Fixed> for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Fixed) -> Self {
+        Self(self.0 * other, self.1 * other)
+    }
+}
+
+impl std::ops::MulAssign<Fixed> for FixedVec2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Fixed) {
+        *self = Self(self.0 * other, self.1 * other)
+    }
+}
+
+impl std::ops::Mul<FixedVec2> for Fixed {
+    type Output = FixedVec2;
+    #[inline]
+    fn mul(self, other: FixedVec2) -> FixedVec2 {
+        FixedVec2(self * other.0, self * other.1)
+    }
+}
+
+impl std::ops::Add<FixedVec2> for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl std::ops::AddAssign<FixedVec2> for FixedVec2 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = Self(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl std::ops::Add<Fixed> for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Fixed) -> Self {
+        Self(self.0 + other, self.1 + other)
+    }
+}
+
+impl std::ops::AddAssign<Fixed> for FixedVec2 {
+    #[inline]
+    fn add_assign(&mut self, other: Fixed) {
+        *self = Self(self.0 + other, self.1 + other)
+    }
+}
+
+impl std::ops::Add<FixedVec2> for Fixed {
+    type Output = FixedVec2;
+    #[inline]
+    fn add(self, other: FixedVec2) -> FixedVec2 {
+        FixedVec2(self + other.0, self + other.1)
+    }
+}
+
+impl std::ops::Sub<FixedVec2> for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl std::ops::SubAssign<FixedVec2> for FixedVec2 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl std::ops::Sub<Fixed> for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Fixed) -> Self {
+        Self(self.0 - other, self.1 - other)
+    }
+}
+
+impl std::ops::SubAssign<Fixed> for FixedVec2 {
+    #[inline]
+    fn sub_assign(&mut self, other: Fixed) {
+        *self = Self(self.0 - other, self.1 - other)
+    }
+}
+
+impl std::ops::Sub<FixedVec2> for Fixed {
+    type Output = FixedVec2;
+    #[inline]
+    fn sub(self, other: FixedVec2) -> FixedVec2 {
+        FixedVec2(self - other.0, self - other.1)
+    }
+}
+
+impl std::ops::Neg for FixedVec2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl From<[Fixed; 2]> for FixedVec2 {
+    #[inline]
+    fn from(a: [Fixed; 2]) -> Self {
+        Self(a[0], a[1])
+    }
+}
+
+impl From<FixedVec2> for [Fixed; 2] {
+    #[inline]
+    fn from(v: FixedVec2) -> Self {
+        [v.0, v.1]
+    }
+}
+
+impl From<Vec2> for FixedVec2 {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        Self::from_vec2(v)
+    }
+}
+
+impl From<FixedVec2> for Vec2 {
+    #[inline]
+    fn from(v: FixedVec2) -> Self {
+        v.to_vec2()
+    }
+}
+
+impl TryFrom<IVec2> for FixedVec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `FixedVec2`, failing if any element is outside of the
+    /// range `-32768..=32767`.
+    #[inline]
+    fn try_from(v: IVec2) -> Result<Self, Self::Error> {
+        Ok(Self(
+            Fixed::from(i16::try_from(v.x())?),
+            Fixed::from(i16::try_from(v.y())?),
+        ))
+    }
+}
@@ -0,0 +1,605 @@
+use super::{Fixed, FixedVec3};
+use crate::{bool::BVec4, f32::Vec4, i32::IVec4};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 4-dimensional vector of 16.16 fixed-point elements.
+///
+/// All arithmetic is performed with integer operations so results are
+/// reproducible across platforms. See [`Fixed`] for rounding and overflow
+/// behaviour.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(C)]
+pub struct FixedVec4(Fixed, Fixed, Fixed, Fixed);
+
+#[inline]
+pub fn fixedvec4(x: Fixed, y: Fixed, z: Fixed, w: Fixed) -> FixedVec4 {
+    FixedVec4(x, y, z, w)
+}
+
+impl FixedVec4 {
+    /// Creates a new vector.
+    #[inline]
+    pub fn new(x: Fixed, y: Fixed, z: Fixed, w: Fixed) -> Self {
+        Self(x, y, z, w)
+    }
+
+    /// Creates a new vector with all elements set to `0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Fixed::zero())
+    }
+
+    /// Creates a new vector with all elements set to `1`.
+    #[inline]
+    pub fn one() -> Self {
+        Self::splat(Fixed::one())
+    }
+
+    /// Creates a new vector with values `[x: 1, y: 0, z: 0, w: 0]`.
+    #[inline]
+    pub fn unit_x() -> Self {
+        Self(Fixed::one(), Fixed::zero(), Fixed::zero(), Fixed::zero())
+    }
+
+    /// Creates a new vector with values `[x: 0, y: 1, z: 0, w: 0]`.
+    #[inline]
+    pub fn unit_y() -> Self {
+        Self(Fixed::zero(), Fixed::one(), Fixed::zero(), Fixed::zero())
+    }
+
+    /// Creates a new vector with values `[x: 0, y: 0, z: 1, w: 0]`.
+    #[inline]
+    pub fn unit_z() -> Self {
+        Self(Fixed::zero(), Fixed::zero(), Fixed::one(), Fixed::zero())
+    }
+
+    /// Creates a new vector with values `[x: 0, y: 0, z: 0, w: 1]`.
+    #[inline]
+    pub fn unit_w() -> Self {
+        Self(Fixed::zero(), Fixed::zero(), Fixed::zero(), Fixed::one())
+    }
+
+    /// Creates a new vector with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: Fixed) -> Self {
+        Self(v, v, v, v)
+    }
+
+    /// Creates a `FixedVec3` from the `x`, `y` and `z` elements of `self`,
+    /// discarding `w`.
+    #[inline]
+    pub fn truncate(self) -> FixedVec3 {
+        FixedVec3::new(self.0, self.1, self.2)
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> Fixed {
+        self.0
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> Fixed {
+        self.1
+    }
+
+    /// Returns element `z`.
+    #[inline]
+    pub fn z(self) -> Fixed {
+        self.2
+    }
+
+    /// Returns element `w`.
+    #[inline]
+    pub fn w(self) -> Fixed {
+        self.3
+    }
+
+    /// Sets element `x`.
+    #[inline]
+    pub fn set_x(&mut self, x: Fixed) {
+        self.0 = x;
+    }
+
+    /// Sets element `y`.
+    #[inline]
+    pub fn set_y(&mut self, y: Fixed) {
+        self.1 = y;
+    }
+
+    /// Sets element `z`.
+    #[inline]
+    pub fn set_z(&mut self, z: Fixed) {
+        self.2 = z;
+    }
+
+    /// Sets element `w`.
+    #[inline]
+    pub fn set_w(&mut self, w: Fixed) {
+        self.3 = w;
+    }
+
+    /// Computes the dot product of `self` and `other`.
+    ///
+    /// The products are summed at full precision before being rounded back to
+    /// 16.16.
+    #[inline]
+    pub fn dot(self, other: Self) -> Fixed {
+        Fixed::from_wide(
+            self.0.wide_mul(other.0)
+                + self.1.wide_mul(other.1)
+                + self.2.wide_mul(other.2)
+                + self.3.wide_mul(other.3),
+        )
+    }
+
+    /// Computes the squared length of `self`.
+    #[inline]
+    pub fn length_squared(self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self(
+            self.0.min(other.0),
+            self.1.min(other.1),
+            self.2.min(other.2),
+            self.3.min(other.3),
+        )
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self(
+            self.0.max(other.0),
+            self.1.max(other.1),
+            self.2.max(other.2),
+            self.3.max(other.3),
+        )
+    }
+
+    /// Component-wise clamping of values, similar to the scalar `clamp`.
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+        self.max(min).min(max)
+    }
+
+    /// Returns a vector containing the minimum of each element of `self` and
+    /// `min`.
+    #[inline]
+    pub fn min_scalar(self, min: Fixed) -> Self {
+        self.min(Self::splat(min))
+    }
+
+    /// Returns a vector containing the maximum of each element of `self` and
+    /// `max`.
+    #[inline]
+    pub fn max_scalar(self, max: Fixed) -> Self {
+        self.max(Self::splat(max))
+    }
+
+    /// Clamps every element of `self` to the range `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp_scalar(self, min: Fixed, max: Fixed) -> Self {
+        glam_assert!(min <= max, "clamp_scalar: expected min <= max");
+        self.max(Self::splat(min)).min(Self::splat(max))
+    }
+
+    /// Returns a vector containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs(), self.1.abs(), self.2.abs(), self.3.abs())
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a boolean vector mask of the results.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> BVec4 {
+        BVec4::new(
+            self.0 == other.0,
+            self.1 == other.1,
+            self.2 == other.2,
+            self.3 == other.3,
+        )
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a boolean vector mask of the results.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> BVec4 {
+        BVec4::new(
+            self.0 < other.0,
+            self.1 < other.1,
+            self.2 < other.2,
+            self.3 < other.3,
+        )
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a boolean vector mask of the results.
+    #[inline]
+    pub fn cmple(self, other: Self) -> BVec4 {
+        BVec4::new(
+            self.0 <= other.0,
+            self.1 <= other.1,
+            self.2 <= other.2,
+            self.3 <= other.3,
+        )
+    }
+
+    /// Converts a `Vec4` to fixed-point, rounding each element to the nearest
+    /// representable value. Out of range elements saturate.
+    #[inline]
+    pub fn from_vec4(v: Vec4) -> Self {
+        Self(
+            Fixed::from_f32(v.x()),
+            Fixed::from_f32(v.y()),
+            Fixed::from_f32(v.z()),
+            Fixed::from_f32(v.w()),
+        )
+    }
+
+    /// Converts `self` to a `Vec4`.
+    #[inline]
+    pub fn to_vec4(self) -> Vec4 {
+        Vec4::new(
+            self.0.to_f32(),
+            self.1.to_f32(),
+            self.2.to_f32(),
+            self.3.to_f32(),
+        )
+    }
+
+    /// Converts an `IVec4` to fixed-point.
+    ///
+    /// Elements outside of the range `-32768..=32767` overflow, which panics
+    /// when debug assertions are enabled and wraps otherwise. Use `try_from` to
+    /// check the range instead.
+    #[inline]
+    pub fn from_ivec4(v: IVec4) -> Self {
+        Self(
+            Fixed::from_int(v.x()),
+            Fixed::from_int(v.y()),
+            Fixed::from_int(v.z()),
+            Fixed::from_int(v.w()),
+        )
+    }
+
+    /// Converts `self` to an `IVec4`, rounding each element towards negative
+    /// infinity.
+    #[inline]
+    pub fn to_ivec4(self) -> IVec4 {
+        IVec4::new(
+            self.0.to_int(),
+            self.1.to_int(),
+            self.2.to_int(),
+            self.3.to_int(),
+        )
+    }
+}
+
+impl std::fmt::Display for FixedVec4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.0, self.1, self.2, self.3)
+    }
+}
+
+impl std::ops::Div<FixedVec4> for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self(
+            self.0 / other.0,
+            self.1 / other.1,
+            self.2 / other.2,
+            self.3 / other.3,
+        )
+    }
+}
+
+impl std::ops::DivAssign<FixedVec4> for FixedVec4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = Self(
+            self.0 / other.0,
+            self.1 / other.1,
+            self.2 / other.2,
+            self.3 / other.3,
+        )
+    }
+}
+
+impl std::ops::Div<Fixed> for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Fixed) -> Self {
+        Self(
+            self.0 / other,
+            self.1 / other,
+            self.2 / other,
+            self.3 / other,
+        )
+    }
+}
+
+impl std::ops::DivAssign<Fixed> for FixedVec4 {
+    #[inline]
+    fn div_assign(&mut self, other: Fixed) {
+        *self = Self(
+            self.0 / other,
+            self.1 / other,
+            self.2 / other,
+            self.3 / other,
+        )
+    }
+}
+
+impl std::ops::Div<FixedVec4> for Fixed {
+    type Output = FixedVec4;
+    #[inline]
+    fn div(self, other: FixedVec4) -> FixedVec4 {
+        FixedVec4(
+            self / other.0,
+            self / other.1,
+            self / other.2,
+            self / other.3,
+        )
+    }
+}
+
+impl std::ops::Mul<FixedVec4> for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self(
+            self.0 * other.0,
+            self.1 * other.1,
+            self.2 * other.2,
+            self.3 * other.3,
+        )
+    }
+}
+
+impl std::ops::MulAssign<FixedVec4> for FixedVec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = Self(
+            self.0 * other.0,
+            self.1 * other.1,
+            self.2 * other.2,
+            self.3 * other.3,
+        )
+    }
+}
+
+impl std::ops::Mul<Fixed> for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Fixed) -> Self {
+        Self(
+            self.0 * other,
+            self.1 * other,
+            self.2 * other,
+            self.3 * other,
+        )
+    }
+}
+
+impl std::ops::MulAssign<Fixed> for FixedVec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Fixed) {
+        *self = Self(
+            self.0 * other,
+            self.1 * other,
+            self.2 * other,
+            self.3 * other,
+        )
+    }
+}
+
+impl std::ops::Mul<FixedVec4> for Fixed {
+    type Output = FixedVec4;
+    #[inline]
+    fn mul(self, other: FixedVec4) -> FixedVec4 {
+        FixedVec4(
+            self * other.0,
+            self * other.1,
+            self * other.2,
+            self * other.3,
+        )
+    }
+}
+
+impl std::ops::Add<FixedVec4> for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(
+            self.0 + other.0,
+            self.1 + other.1,
+            self.2 + other.2,
+            self.3 + other.3,
+        )
+    }
+}
+
+impl std::ops::AddAssign<FixedVec4> for FixedVec4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = Self(
+            self.0 + other.0,
+            self.1 + other.1,
+            self.2 + other.2,
+            self.3 + other.3,
+        )
+    }
+}
+
+impl std::ops::Add<Fixed> for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Fixed) -> Self {
+        Self(
+            self.0 + other,
+            self.1 + other,
+            self.2 + other,
+            self.3 + other,
+        )
+    }
+}
+
+impl std::ops::AddAssign<Fixed> for FixedVec4 {
+    #[inline]
+    fn add_assign(&mut self, other: Fixed) {
+        *self = Self(
+            self.0 + other,
+            self.1 + other,
+            self.2 + other,
+            self.3 + other,
+        )
+    }
+}
+
+impl std::ops::Add<FixedVec4> for Fixed {
+    type Output = FixedVec4;
+    #[inline]
+    fn add(self, other: FixedVec4) -> FixedVec4 {
+        FixedVec4(
+            self + other.0,
+            self + other.1,
+            self + other.2,
+            self + other.3,
+        )
+    }
+}
+
+impl std::ops::Sub<FixedVec4> for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(
+            self.0 - other.0,
+            self.1 - other.1,
+            self.2 - other.2,
+            self.3 - other.3,
+        )
+    }
+}
+
+impl std::ops::SubAssign<FixedVec4> for FixedVec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self(
+            self.0 - other.0,
+            self.1 - other.1,
+            self.2 - other.2,
+            self.3 - other.3,
+        )
+    }
+}
+
+impl std::ops::Sub<Fixed> for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Fixed) -> Self {
+        Self(
+            self.0 - other,
+            self.1 - other,
+            self.2 - other,
+            self.3 - other,
+        )
+    }
+}
+
+impl std::ops::SubAssign<Fixed> for FixedVec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Fixed) {
+        *self = Self(
+            self.0 - other,
+            self.1 - other,
+            self.2 - other,
+            self.3 - other,
+        )
+    }
+}
+
+impl std::ops::Sub<FixedVec4> for Fixed {
+    type Output = FixedVec4;
+    #[inline]
+    fn sub(self, other: FixedVec4) -> FixedVec4 {
+        FixedVec4(
+            self - other.0,
+            self - other.1,
+            self - other.2,
+            self - other.3,
+        )
+    }
+}
+
+impl std::ops::Neg for FixedVec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2, -self.3)
+    }
+}
+
+impl From<[Fixed; 4]> for FixedVec4 {
+    #[inline]
+    fn from(a: [Fixed; 4]) -> Self {
+        Self(a[0], a[1], a[2], a[3])
+    }
+}
+
+impl From<FixedVec4> for [Fixed; 4] {
+    #[inline]
+    fn from(v: FixedVec4) -> Self {
+        [v.0, v.1, v.2, v.3]
+    }
+}
+
+impl From<Vec4> for FixedVec4 {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        Self::from_vec4(v)
+    }
+}
+
+impl From<FixedVec4> for Vec4 {
+    #[inline]
+    fn from(v: FixedVec4) -> Self {
+        v.to_vec4()
+    }
+}
+
+impl TryFrom<IVec4> for FixedVec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `FixedVec4`, failing if any element is outside of the
+    /// range `-32768..=32767`.
+    #[inline]
+    fn try_from(v: IVec4) -> Result<Self, Self::Error> {
+        Ok(Self(
+            Fixed::from(i16::try_from(v.x())?),
+            Fixed::from(i16::try_from(v.y())?),
+            Fixed::from(i16::try_from(v.z())?),
+            Fixed::from(i16::try_from(v.w())?),
+        ))
+    }
+}
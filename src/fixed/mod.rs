@@ -0,0 +1,15 @@
+mod fixedmat2;
+mod fixedmat3;
+mod fixedmat4;
+mod fixedvec2;
+mod fixedvec3;
+mod fixedvec4;
+mod scalar;
+
+pub use fixedmat2::*;
+pub use fixedmat3::*;
+pub use fixedmat4::*;
+pub use fixedvec2::*;
+pub use fixedvec3::*;
+pub use fixedvec4::*;
+pub use scalar::*;
@@ -0,0 +1,243 @@
+use std::convert::TryFrom;
+
+const FRAC_BITS: u32 = 16;
+const ONE_BITS: i32 = 1 << FRAC_BITS;
+
+/// A signed 16.16 fixed-point number stored in an `i32`.
+///
+/// Arithmetic on `Fixed` only uses integer operations so results are identical
+/// on every platform, which makes it suitable for deterministic lockstep
+/// simulations. Multiplication and division use a 64-bit intermediate.
+/// Multiplication rounds towards negative infinity and division rounds towards
+/// zero.
+///
+/// Like the integer types, arithmetic panics on overflow when debug assertions
+/// are enabled and wraps otherwise. This includes multiplication and division
+/// whose result does not fit in 16.16, and `from_int` with an integer outside
+/// of `-32768..=32767`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    /// The number of fractional bits.
+    pub const FRAC_BITS: u32 = FRAC_BITS;
+
+    /// Creates a `Fixed` from its raw 16.16 representation.
+    #[inline]
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw 16.16 representation of `self`.
+    #[inline]
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Returns `0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    /// Returns `1`.
+    #[inline]
+    pub const fn one() -> Self {
+        Self(ONE_BITS)
+    }
+
+    /// Returns the smallest value that can be represented.
+    #[inline]
+    pub const fn min_value() -> Self {
+        Self(i32::MIN)
+    }
+
+    /// Returns the largest value that can be represented.
+    #[inline]
+    pub const fn max_value() -> Self {
+        Self(i32::MAX)
+    }
+
+    /// Converts an integer to `Fixed`.
+    ///
+    /// Integers outside of the range `-32768..=32767` overflow, which panics
+    /// when debug assertions are enabled and wraps otherwise.
+    #[inline]
+    pub fn from_int(v: i32) -> Self {
+        debug_assert!(
+            (-32768..=32767).contains(&v),
+            "integer out of range for Fixed"
+        );
+        Self(v << FRAC_BITS)
+    }
+
+    /// Converts `self` to an integer, rounding towards negative infinity.
+    #[inline]
+    pub fn to_int(self) -> i32 {
+        self.0 >> FRAC_BITS
+    }
+
+    /// Converts an `f32` to `Fixed`, rounding to the nearest representable
+    /// value. Out of range values saturate and NaN becomes `0`.
+    #[inline]
+    pub fn from_f32(v: f32) -> Self {
+        Self((v * ONE_BITS as f32).round() as i32)
+    }
+
+    /// Converts `self` to an `f32`. This may lose precision for large values.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / ONE_BITS as f32
+    }
+
+    /// Converts an `f64` to `Fixed`, rounding to the nearest representable
+    /// value. Out of range values saturate and NaN becomes `0`.
+    #[inline]
+    pub fn from_f64(v: f64) -> Self {
+        Self((v * f64::from(ONE_BITS)).round() as i32)
+    }
+
+    /// Converts `self` to an `f64`. This conversion is exact.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.0) / f64::from(ONE_BITS)
+    }
+
+    /// Returns the absolute value of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns the smaller of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// Returns the larger of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    /// Multiplies `self` by `other` and returns the full precision 32.32 result
+    /// as an `i64`. Used to accumulate products without intermediate rounding.
+    #[inline]
+    pub(crate) fn wide_mul(self, other: Self) -> i64 {
+        i64::from(self.0) * i64::from(other.0)
+    }
+
+    /// Converts a 32.32 fixed-point product back to 16.16.
+    #[inline]
+    pub(crate) fn from_wide(wide: i64) -> Self {
+        let bits = wide >> FRAC_BITS;
+        debug_assert!(i32::try_from(bits).is_ok(), "Fixed overflow");
+        Self(bits as i32)
+    }
+
+    /// Computes the 2x2 determinant `a * d - b * c`, summing the products at
+    /// full precision before rounding back to 16.16.
+    #[inline]
+    pub(crate) fn det2(a: Self, b: Self, c: Self, d: Self) -> Self {
+        Self::from_wide(a.wide_mul(d) - b.wide_mul(c))
+    }
+}
+
+impl std::fmt::Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl From<i16> for Fixed {
+    #[inline]
+    fn from(v: i16) -> Self {
+        Self::from_int(i32::from(v))
+    }
+}
+
+impl From<Fixed> for f32 {
+    #[inline]
+    fn from(v: Fixed) -> Self {
+        v.to_f32()
+    }
+}
+
+impl From<Fixed> for f64 {
+    #[inline]
+    fn from(v: Fixed) -> Self {
+        v.to_f64()
+    }
+}
+
+impl std::ops::Add for Fixed {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl std::ops::AddAssign for Fixed {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl std::ops::Sub for Fixed {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl std::ops::SubAssign for Fixed {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl std::ops::Mul for Fixed {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self::from_wide(self.wide_mul(other))
+    }
+}
+
+impl std::ops::MulAssign for Fixed {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl std::ops::Div for Fixed {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        let bits = (i64::from(self.0) << FRAC_BITS) / i64::from(other.0);
+        debug_assert!(i32::try_from(bits).is_ok(), "Fixed overflow");
+        Self(bits as i32)
+    }
+}
+
+impl std::ops::DivAssign for Fixed {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl std::ops::Neg for Fixed {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    bool::BVec2, f32::Vec2, f64::DVec2, i16::I16Vec3, i32::IVec2, i64::I64Vec2, u16::U16Vec2,
+    u32::UVec2, u64::U64Vec2,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 2-dimensional vector of `i16` elements.
+///
+/// This type is intended for compact storage such as vertex buffers. Convert to
+/// `IVec2` for general purpose integer math.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct I16Vec2(i16, i16);
+
+#[inline]
+pub fn i16vec2(x: i16, y: i16) -> I16Vec2 {
+    I16Vec2(x, y)
+}
+
+impl_vec2_int!(I16Vec2, i16, i64, BVec2, I16Vec3);
+impl_vec2_signed!(I16Vec2);
+impl_vec2_as!(
+    I16Vec2,
+    as_vec2 => Vec2, f32;
+    as_dvec2 => DVec2, f64;
+    as_u16vec2 => U16Vec2, u16;
+    as_ivec2 => IVec2, i32;
+    as_uvec2 => UVec2, u32;
+    as_i64vec2 => I64Vec2, i64;
+    as_u64vec2 => U64Vec2, u64;
+);
+
+impl From<I16Vec2> for IVec2 {
+    #[inline]
+    fn from(v: I16Vec2) -> Self {
+        Self::new(i32::from(v.0), i32::from(v.1))
+    }
+}
+
+impl TryFrom<IVec2> for I16Vec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `I16Vec2`, failing if any element is out of range for `i16`.
+    #[inline]
+    fn try_from(v: IVec2) -> Result<Self, Self::Error> {
+        Ok(Self(i16::try_from(v.x())?, i16::try_from(v.y())?))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    bool::BVec3,
+    f32::{Vec3, Vec3A},
+    f64::DVec3,
+    i16::{I16Vec2, I16Vec4},
+    i32::IVec3,
+    i64::I64Vec3,
+    u16::U16Vec3,
+    u32::UVec3,
+    u64::U64Vec3,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 3-dimensional vector of `i16` elements.
+///
+/// This type is intended for compact storage such as vertex buffers. Convert to
+/// `IVec3` for general purpose integer math.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct I16Vec3(i16, i16, i16);
+
+#[inline]
+pub fn i16vec3(x: i16, y: i16, z: i16) -> I16Vec3 {
+    I16Vec3(x, y, z)
+}
+
+impl_vec3_int!(I16Vec3, i16, i64, BVec3, I16Vec2, I16Vec4);
+impl_vec3_signed!(I16Vec3);
+impl_vec3_as!(
+    I16Vec3,
+    as_vec3 => Vec3, f32;
+    as_vec3a => Vec3A, f32;
+    as_dvec3 => DVec3, f64;
+    as_u16vec3 => U16Vec3, u16;
+    as_ivec3 => IVec3, i32;
+    as_uvec3 => UVec3, u32;
+    as_i64vec3 => I64Vec3, i64;
+    as_u64vec3 => U64Vec3, u64;
+);
+
+impl From<I16Vec3> for IVec3 {
+    #[inline]
+    fn from(v: I16Vec3) -> Self {
+        Self::new(i32::from(v.0), i32::from(v.1), i32::from(v.2))
+    }
+}
+
+impl TryFrom<IVec3> for I16Vec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `I16Vec3`, failing if any element is out of range for `i16`.
+    #[inline]
+    fn try_from(v: IVec3) -> Result<Self, Self::Error> {
+        Ok(Self(
+            i16::try_from(v.x())?,
+            i16::try_from(v.y())?,
+            i16::try_from(v.z())?,
+        ))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    bool::BVec4, f32::Vec4, f64::DVec4, i16::I16Vec3, i32::IVec4, i64::I64Vec4, u16::U16Vec4,
+    u32::UVec4, u64::U64Vec4,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 4-dimensional vector of `i16` elements.
+///
+/// This type is intended for compact storage such as vertex buffers. Convert to
+/// `IVec4` for general purpose integer math.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct I16Vec4(i16, i16, i16, i16);
+
+#[inline]
+pub fn i16vec4(x: i16, y: i16, z: i16, w: i16) -> I16Vec4 {
+    I16Vec4(x, y, z, w)
+}
+
+impl_vec4_int!(I16Vec4, i16, i64, BVec4, I16Vec3);
+impl_vec4_signed!(I16Vec4);
+impl_vec4_as!(
+    I16Vec4,
+    as_vec4 => Vec4, f32;
+    as_dvec4 => DVec4, f64;
+    as_u16vec4 => U16Vec4, u16;
+    as_ivec4 => IVec4, i32;
+    as_uvec4 => UVec4, u32;
+    as_i64vec4 => I64Vec4, i64;
+    as_u64vec4 => U64Vec4, u64;
+);
+
+impl From<I16Vec4> for IVec4 {
+    #[inline]
+    fn from(v: I16Vec4) -> Self {
+        Self::new(
+            i32::from(v.0),
+            i32::from(v.1),
+            i32::from(v.2),
+            i32::from(v.3),
+        )
+    }
+}
+
+impl TryFrom<IVec4> for I16Vec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `I16Vec4`, failing if any element is out of range for `i16`.
+    #[inline]
+    fn try_from(v: IVec4) -> Result<Self, Self::Error> {
+        Ok(Self(
+            i16::try_from(v.x())?,
+            i16::try_from(v.y())?,
+            i16::try_from(v.z())?,
+            i16::try_from(v.w())?,
+        ))
+    }
+}
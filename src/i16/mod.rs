@@ -0,0 +1,7 @@
+mod i16vec2;
+mod i16vec3;
+mod i16vec4;
+
+pub use i16vec2::*;
+pub use i16vec3::*;
+pub use i16vec4::*;
@@ -0,0 +1,34 @@
+use crate::{
+    bool::BVec2, f32::Vec2, f64::DVec2, i16::I16Vec2, i32::IVec3, i64::I64Vec2, u16::U16Vec2,
+    u32::UVec2, u64::U64Vec2,
+};
+
+/// A 2-dimensional vector of `i32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct IVec2(i32, i32);
+
+#[inline]
+pub fn ivec2(x: i32, y: i32) -> IVec2 {
+    IVec2(x, y)
+}
+
+impl_vec2_int!(IVec2, i32, i64, BVec2, IVec3);
+impl_vec2_signed!(IVec2);
+impl_vec2_as!(
+    IVec2,
+    as_vec2 => Vec2, f32;
+    as_dvec2 => DVec2, f64;
+    as_i16vec2 => I16Vec2, i16;
+    as_u16vec2 => U16Vec2, u16;
+    as_uvec2 => UVec2, u32;
+    as_i64vec2 => I64Vec2, i64;
+    as_u64vec2 => U64Vec2, u64;
+);
+
+impl From<IVec2> for DVec2 {
+    #[inline]
+    fn from(v: IVec2) -> Self {
+        v.as_dvec2()
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    bool::BVec3,
+    f32::{Vec3, Vec3A},
+    f64::DVec3,
+    i16::I16Vec3,
+    i32::{IVec2, IVec4},
+    i64::I64Vec3,
+    u16::U16Vec3,
+    u32::UVec3,
+    u64::U64Vec3,
+};
+
+/// A 3-dimensional vector of `i32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct IVec3(i32, i32, i32);
+
+#[inline]
+pub fn ivec3(x: i32, y: i32, z: i32) -> IVec3 {
+    IVec3(x, y, z)
+}
+
+impl_vec3_int!(IVec3, i32, i64, BVec3, IVec2, IVec4);
+impl_vec3_signed!(IVec3);
+impl_vec3_as!(
+    IVec3,
+    as_vec3 => Vec3, f32;
+    as_vec3a => Vec3A, f32;
+    as_dvec3 => DVec3, f64;
+    as_i16vec3 => I16Vec3, i16;
+    as_u16vec3 => U16Vec3, u16;
+    as_uvec3 => UVec3, u32;
+    as_i64vec3 => I64Vec3, i64;
+    as_u64vec3 => U64Vec3, u64;
+);
+
+impl From<IVec3> for DVec3 {
+    #[inline]
+    fn from(v: IVec3) -> Self {
+        v.as_dvec3()
+    }
+}
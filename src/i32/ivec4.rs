@@ -0,0 +1,34 @@
+use crate::{
+    bool::BVec4, f32::Vec4, f64::DVec4, i16::I16Vec4, i32::IVec3, i64::I64Vec4, u16::U16Vec4,
+    u32::UVec4, u64::U64Vec4,
+};
+
+/// A 4-dimensional vector of `i32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct IVec4(i32, i32, i32, i32);
+
+#[inline]
+pub fn ivec4(x: i32, y: i32, z: i32, w: i32) -> IVec4 {
+    IVec4(x, y, z, w)
+}
+
+impl_vec4_int!(IVec4, i32, i64, BVec4, IVec3);
+impl_vec4_signed!(IVec4);
+impl_vec4_as!(
+    IVec4,
+    as_vec4 => Vec4, f32;
+    as_dvec4 => DVec4, f64;
+    as_i16vec4 => I16Vec4, i16;
+    as_u16vec4 => U16Vec4, u16;
+    as_uvec4 => UVec4, u32;
+    as_i64vec4 => I64Vec4, i64;
+    as_u64vec4 => U64Vec4, u64;
+);
+
+impl From<IVec4> for DVec4 {
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        v.as_dvec4()
+    }
+}
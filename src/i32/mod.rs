@@ -0,0 +1,7 @@
+mod ivec2;
+mod ivec3;
+mod ivec4;
+
+pub use ivec2::*;
+pub use ivec3::*;
+pub use ivec4::*;
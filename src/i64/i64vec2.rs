@@ -0,0 +1,63 @@
+use crate::{
+    bool::BVec2, f32::Vec2, f64::DVec2, i16::I16Vec2, i32::IVec2, i64::I64Vec3, u16::U16Vec2,
+    u32::UVec2, u64::U64Vec2,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 2-dimensional vector of `i64` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct I64Vec2(i64, i64);
+
+#[inline]
+pub fn i64vec2(x: i64, y: i64) -> I64Vec2 {
+    I64Vec2(x, y)
+}
+
+impl_vec2_int!(I64Vec2, i64, i128, BVec2, I64Vec3);
+impl_vec2_signed!(I64Vec2);
+impl_vec2_as!(
+    I64Vec2,
+    as_vec2 => Vec2, f32;
+    as_dvec2 => DVec2, f64;
+    as_i16vec2 => I16Vec2, i16;
+    as_u16vec2 => U16Vec2, u16;
+    as_ivec2 => IVec2, i32;
+    as_uvec2 => UVec2, u32;
+    as_u64vec2 => U64Vec2, u64;
+);
+
+impl From<IVec2> for I64Vec2 {
+    #[inline]
+    fn from(v: IVec2) -> Self {
+        Self(i64::from(v.x()), i64::from(v.y()))
+    }
+}
+
+impl From<UVec2> for I64Vec2 {
+    #[inline]
+    fn from(v: UVec2) -> Self {
+        Self(i64::from(v.x()), i64::from(v.y()))
+    }
+}
+
+impl TryFrom<I64Vec2> for IVec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `IVec2`, failing if any element is out of range for `i32`.
+    #[inline]
+    fn try_from(v: I64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(i32::try_from(v.0)?, i32::try_from(v.1)?))
+    }
+}
+
+impl TryFrom<U64Vec2> for I64Vec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `I64Vec2`, failing if any element is out of range for `i64`.
+    #[inline]
+    fn try_from(v: U64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self(i64::try_from(v.x())?, i64::try_from(v.y())?))
+    }
+}
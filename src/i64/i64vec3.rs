@@ -0,0 +1,79 @@
+use crate::{
+    bool::BVec3,
+    f32::{Vec3, Vec3A},
+    f64::DVec3,
+    i16::I16Vec3,
+    i32::IVec3,
+    i64::{I64Vec2, I64Vec4},
+    u16::U16Vec3,
+    u32::UVec3,
+    u64::U64Vec3,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 3-dimensional vector of `i64` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct I64Vec3(i64, i64, i64);
+
+#[inline]
+pub fn i64vec3(x: i64, y: i64, z: i64) -> I64Vec3 {
+    I64Vec3(x, y, z)
+}
+
+impl_vec3_int!(I64Vec3, i64, i128, BVec3, I64Vec2, I64Vec4);
+impl_vec3_signed!(I64Vec3);
+impl_vec3_as!(
+    I64Vec3,
+    as_vec3 => Vec3, f32;
+    as_vec3a => Vec3A, f32;
+    as_dvec3 => DVec3, f64;
+    as_i16vec3 => I16Vec3, i16;
+    as_u16vec3 => U16Vec3, u16;
+    as_ivec3 => IVec3, i32;
+    as_uvec3 => UVec3, u32;
+    as_u64vec3 => U64Vec3, u64;
+);
+
+impl From<IVec3> for I64Vec3 {
+    #[inline]
+    fn from(v: IVec3) -> Self {
+        Self(i64::from(v.x()), i64::from(v.y()), i64::from(v.z()))
+    }
+}
+
+impl From<UVec3> for I64Vec3 {
+    #[inline]
+    fn from(v: UVec3) -> Self {
+        Self(i64::from(v.x()), i64::from(v.y()), i64::from(v.z()))
+    }
+}
+
+impl TryFrom<I64Vec3> for IVec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `IVec3`, failing if any element is out of range for `i32`.
+    #[inline]
+    fn try_from(v: I64Vec3) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.0)?,
+            i32::try_from(v.1)?,
+            i32::try_from(v.2)?,
+        ))
+    }
+}
+
+impl TryFrom<U64Vec3> for I64Vec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `I64Vec3`, failing if any element is out of range for `i64`.
+    #[inline]
+    fn try_from(v: U64Vec3) -> Result<Self, Self::Error> {
+        Ok(Self(
+            i64::try_from(v.x())?,
+            i64::try_from(v.y())?,
+            i64::try_from(v.z())?,
+        ))
+    }
+}
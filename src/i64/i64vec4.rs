@@ -0,0 +1,83 @@
+use crate::{
+    bool::BVec4, f32::Vec4, f64::DVec4, i16::I16Vec4, i32::IVec4, i64::I64Vec3, u16::U16Vec4,
+    u32::UVec4, u64::U64Vec4,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 4-dimensional vector of `i64` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct I64Vec4(i64, i64, i64, i64);
+
+#[inline]
+pub fn i64vec4(x: i64, y: i64, z: i64, w: i64) -> I64Vec4 {
+    I64Vec4(x, y, z, w)
+}
+
+impl_vec4_int!(I64Vec4, i64, i128, BVec4, I64Vec3);
+impl_vec4_signed!(I64Vec4);
+impl_vec4_as!(
+    I64Vec4,
+    as_vec4 => Vec4, f32;
+    as_dvec4 => DVec4, f64;
+    as_i16vec4 => I16Vec4, i16;
+    as_u16vec4 => U16Vec4, u16;
+    as_ivec4 => IVec4, i32;
+    as_uvec4 => UVec4, u32;
+    as_u64vec4 => U64Vec4, u64;
+);
+
+impl From<IVec4> for I64Vec4 {
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        Self(
+            i64::from(v.x()),
+            i64::from(v.y()),
+            i64::from(v.z()),
+            i64::from(v.w()),
+        )
+    }
+}
+
+impl From<UVec4> for I64Vec4 {
+    #[inline]
+    fn from(v: UVec4) -> Self {
+        Self(
+            i64::from(v.x()),
+            i64::from(v.y()),
+            i64::from(v.z()),
+            i64::from(v.w()),
+        )
+    }
+}
+
+impl TryFrom<I64Vec4> for IVec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `IVec4`, failing if any element is out of range for `i32`.
+    #[inline]
+    fn try_from(v: I64Vec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.0)?,
+            i32::try_from(v.1)?,
+            i32::try_from(v.2)?,
+            i32::try_from(v.3)?,
+        ))
+    }
+}
+
+impl TryFrom<U64Vec4> for I64Vec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `I64Vec4`, failing if any element is out of range for `i64`.
+    #[inline]
+    fn try_from(v: U64Vec4) -> Result<Self, Self::Error> {
+        Ok(Self(
+            i64::try_from(v.x())?,
+            i64::try_from(v.y())?,
+            i64::try_from(v.z())?,
+            i64::try_from(v.w())?,
+        ))
+    }
+}
@@ -0,0 +1,7 @@
+mod i64vec2;
+mod i64vec3;
+mod i64vec4;
+
+pub use i64vec2::*;
+pub use i64vec3::*;
+pub use i64vec4::*;
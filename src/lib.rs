@@ -5,13 +5,37 @@
 
 ## Features
 
-`glam` is built with SIMD in mind. Currently only SSE2 on x86/x86_64 is
-supported as this is what stable Rust supports.
-
-* Single precision float (`f32`) support only
-* SSE2 implementation for most types, including `Mat2`, `Mat3`, `Mat4`, `Quat`,
-  `Vec3` and `Vec4`
+`glam` is built with SIMD in mind. SSE2 is supported on x86/x86_64 and NEON is
+supported for `Vec4` on aarch64.
+
+* Single precision float (`f32`) types with double precision (`f64`) vector
+  types `DVec2`, `DVec3` and `DVec4`, matrix types `DMat2`, `DMat3` and
+  `DMat4` and quaternion type `DQuat`
+* Signed integer (`i32`) vector types `IVec2`, `IVec3` and `IVec4` and unsigned
+  integer (`u32`) vector types `UVec2`, `UVec3` and `UVec4`
+* 64-bit integer vector types `I64Vec2`, `I64Vec3`, `I64Vec4`, `U64Vec2`,
+  `U64Vec3` and `U64Vec4`
+* 16-bit integer vector types `I16Vec2`, `I16Vec3`, `I16Vec4`, `U16Vec2`,
+  `U16Vec3` and `U16Vec4` for compact storage
+* Half precision (`f16`) storage vector types `F16Vec2`, `F16Vec3` and
+  `F16Vec4` behind the `"half"` feature
+* 16.16 fixed-point (`Fixed`) vector and matrix types `FixedVec2`, `FixedVec3`,
+  `FixedVec4`, `FixedMat2`, `FixedMat3` and `FixedMat4` for deterministic
+  simulation behind the `"fixed"` feature
+* Boolean vector mask types `BVec2`, `BVec3` and `BVec4`
+* Dual quaternion type `DualQuat` for rigid transforms and skinning
+* Compact affine transform types `Mat3x2` for 2D and `Mat4x3` for 3D
+* SSE2 implementation for most types, including `Mat2`, `Mat4`, `Quat`, `Vec3A`
+  and `Vec4`
 * SSE2 implementation of `sin_cos`
+* NEON implementation of `Vec4` on aarch64, which is used by `Mat4` and `Quat`
+* Fused multiply-add is used by `Mat4`, `Vec3A`, `Vec4` and `Quat`
+  multiplication when the `fma` target feature is enabled, for example with
+  `RUSTFLAGS="-C target-feature=+fma"`
+* AVX implementation of `DVec4`, which is used by `DMat2`, `DMat4` and
+  `DQuat`, when the `avx` target feature is enabled
+* Portable `core::simd` implementation of `Vec4` behind the `"core-simd"`
+  feature, which requires nightly Rust
 * Scalar fallback implementations exist when SSE2 is not available
 * Most functionality includes unit tests and benchmarks
 
@@ -39,23 +63,29 @@ use glam::{Mat3, Vec3};
 // rotate +x 90 degrees clockwise around y giving -z
 let m = Mat3::from_rotation_y(90.0_f32.to_radians());
 let v = m * Vec3::unit_x();
-assert!(v.abs_diff_eq(-Vec3::unit_z(), core::f32::EPSILON));
+assert!(v.abs_diff_eq(-Vec3::unit_z(), f32::EPSILON));
 ```
 
 ## Size and alignment of types
 
 Most `glam` types use SIMD for storage meaning most types are 16 byte aligned.
-The only exception is Vec2`. When SSE2 is not available on the target
-architecture the types will still be 16 byte aligned, so object sizes and
-layouts will not change between architectures.
+The exceptions are `Vec2`, `Vec3` and `Mat3` which are stored as packed `f32`
+values. When SSE2 is not available on the target architecture the types will
+still be 16 byte aligned, so object sizes and layouts will not change between
+architectures.
 
 16 byte alignment means that some types will have a stride larger than their
 size resulting in some wasted space.
 
-| Type | f32 bytes | SIMD bytes | Wasted bytes |
-|:-----|----------:|-----------:|-------------:|
-|`Vec3`|         12|          16|             4|
-|`Mat3`|         36|          48|            12|
+| Type  | f32 bytes | SIMD bytes | Wasted bytes |
+|:------|----------:|-----------:|-------------:|
+|`Vec3A`|         12|          16|             4|
+|`Mat3A`|         36|          48|            12|
+
+`Vec3A` is a SIMD version of `Vec3` and `Mat3A` is a version of `Mat3` with
+`Vec3A` columns. These can be converted to and from their packed counterparts
+using `From`. `Quat` and `Mat4` provide `Vec3A` versions of their transform
+methods such as `Quat::mul_vec3a` and `Mat4::transform_point3a`.
 
 Despite this wasted space the SIMD version tends to outperform the `f32`
 implementation in [**mathbench**](https://github.com/bitshifter/mathbench-rs)
@@ -111,24 +141,90 @@ and benchmarks.
 
 * `"std"` - the default feature, has no dependencies.
 * `"rand"` - used to generate random values. Used in benchmarks.
+* `"rayon"` - adds parallel versions of the `Mat4` and `DMat4` slice transforms.
 * `"serde"` - used for serialization and deserialization of types.
 * `"mint"` - used for interoperating with other linear algebra libraries.
+* `"half"` - adds half precision storage vector types, has no dependencies.
+* `"fixed"` - adds 16.16 fixed-point vector and matrix types, has no dependencies.
 * `"scalar-math"` - disables SIMD support and uses native alignment for all
   types.
+* `"core-simd"` - uses `core::simd` for `Vec4` instead of platform intrinsics.
+  Requires nightly Rust. `"scalar-math"` takes precedence if both are enabled.
+* `"runtime-dispatch"` - detects AVX and FMA support at runtime on x86 and
+  x86_64 and uses it for `Mat4` and `Quat` multiplication and `Mat4` inverse.
+  Has no effect if FMA is already enabled at compile time.
 
 
 */
 #![doc(html_root_url = "https://docs.rs/glam/0.8.2")]
+#![cfg_attr(
+    all(feature = "core-simd", not(feature = "scalar-math")),
+    feature(portable_simd)
+)]
 
 #[macro_use]
 mod macros;
-
+#[macro_use]
+mod mat2_macros;
+#[macro_use]
+mod mat3_macros;
+#[macro_use]
+mod mat4_macros;
+#[macro_use]
+mod quat_macros;
+#[macro_use]
+mod vec2_macros;
+#[macro_use]
+mod vec3_macros;
+#[macro_use]
+mod vec4_macros;
+
+mod alignment_error;
+pub mod bool;
+#[cfg(feature = "rand")]
+pub mod distributions;
+mod euler;
+#[cfg(feature = "half")]
+pub mod f16;
 pub mod f32;
-
+pub mod f64;
+#[cfg(feature = "fixed")]
+pub mod fixed;
+pub mod i16;
+pub mod i32;
+pub mod i64;
+mod slice_length_error;
+pub mod u16;
+pub mod u32;
+pub mod u64;
+
+pub use self::alignment_error::AlignmentError;
+pub use self::bool::{bvec2, bvec3, bvec4, BVec2, BVec3, BVec4};
+pub use self::euler::EulerRot;
+#[cfg(feature = "half")]
+pub use self::f16::{f16vec2, f16vec3, f16vec4, F16Vec2, F16Vec3, F16Vec4};
 pub use self::f32::{
-    mat2, mat3, mat4, quat, vec2, vec3, vec4, Mat2, Mat3, Mat4, Quat, Vec2, Vec2Mask, Vec3,
-    Vec3Mask, Vec4, Vec4Mask,
+    mat2, mat3, mat3a, mat3x2, mat4, mat4x3, quat, vec2, vec3, vec3a, vec4, DualQuat, Mat2, Mat3,
+    Mat3A, Mat3x2, Mat4, Mat4x3, Quat, Quatx4, Vec2, Vec3, Vec3A, Vec3x4, Vec3x8, Vec4,
+};
+#[allow(deprecated)]
+pub use self::f32::{Vec2Mask, Vec3Mask, Vec4Mask};
+pub use self::f64::{
+    dmat2, dmat3, dmat4, dquat, dvec2, dvec3, dvec4, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3,
+    DVec4,
+};
+#[cfg(feature = "fixed")]
+pub use self::fixed::{
+    fixedmat2, fixedmat3, fixedmat4, fixedvec2, fixedvec3, fixedvec4, Fixed, FixedMat2, FixedMat3,
+    FixedMat4, FixedVec2, FixedVec3, FixedVec4,
 };
+pub use self::i16::{i16vec2, i16vec3, i16vec4, I16Vec2, I16Vec3, I16Vec4};
+pub use self::i32::{ivec2, ivec3, ivec4, IVec2, IVec3, IVec4};
+pub use self::i64::{i64vec2, i64vec3, i64vec4, I64Vec2, I64Vec3, I64Vec4};
+pub use self::slice_length_error::SliceLengthError;
+pub use self::u16::{u16vec2, u16vec3, u16vec4, U16Vec2, U16Vec3, U16Vec4};
+pub use self::u32::{uvec2, uvec3, uvec4, UVec2, UVec3, UVec4};
+pub use self::u64::{u64vec2, u64vec3, u64vec4, U64Vec2, U64Vec3, U64Vec4};
 
 #[repr(align(16))]
 pub(crate) struct Align16<T>(T);
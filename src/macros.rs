@@ -18,7 +18,7 @@ macro_rules! is_normalized {
         ($self.length_squared() - 1.0).abs() <= $max_diff
     };
     ($self:expr) => {
-        is_normalized!($self, 1e-6)
+        is_normalized!($self, 1e-4)
     };
 }
 
@@ -30,3 +30,196 @@ macro_rules! abs_diff_eq {
             .all()
     };
 }
+
+macro_rules! impl_sum {
+    ($t:ident, $zero:expr) => {
+        impl std::iter::Sum<$t> for $t {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($zero, |acc, x| acc + x)
+            }
+        }
+
+        impl<'a> std::iter::Sum<&'a $t> for $t {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold($zero, |acc, &x| acc + x)
+            }
+        }
+    };
+}
+
+macro_rules! impl_product {
+    ($t:ident, $one:expr) => {
+        impl std::iter::Product<$t> for $t {
+            #[inline]
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($one, |acc, x| acc * x)
+            }
+        }
+
+        impl<'a> std::iter::Product<&'a $t> for $t {
+            #[inline]
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold($one, |acc, &x| acc * x)
+            }
+        }
+    };
+}
+
+macro_rules! relative_eq {
+    ($self:expr, $other:expr, $max_abs_diff:expr, $max_relative:expr) => {
+        $self
+            .as_ref()
+            .iter()
+            .zip($other.as_ref().iter())
+            .all(|(&a, &b)| {
+                // the equality check is needed for infinities
+                a == b || {
+                    let diff = (a - b).abs();
+                    diff <= $max_abs_diff || diff <= a.abs().max(b.abs()) * $max_relative
+                }
+            })
+    };
+}
+
+macro_rules! ulps_eq {
+    ($self:expr, $other:expr, $max_abs_diff:expr, $max_ulps:expr) => {
+        $self
+            .as_ref()
+            .iter()
+            .zip($other.as_ref().iter())
+            .all(|(&a, &b)| {
+                if (a - b).abs() <= $max_abs_diff {
+                    true
+                } else if a.signum() != b.signum() {
+                    false
+                } else {
+                    // floats of the same sign are ordered like their bits
+                    let (a, b) = (a.to_bits(), b.to_bits());
+                    let ulps = if a > b { a - b } else { b - a };
+                    ulps <= $max_ulps.into()
+                }
+            })
+    };
+}
+
+macro_rules! impl_ref_binop {
+    ($trait:ident, $fn:ident, $lhs:ty, $rhs:ty, $output:ty) => {
+        impl<'a> std::ops::$trait<&'a $rhs> for $lhs {
+            type Output = $output;
+            #[inline]
+            fn $fn(self, other: &'a $rhs) -> $output {
+                std::ops::$trait::$fn(self, *other)
+            }
+        }
+
+        impl<'a> std::ops::$trait<$rhs> for &'a $lhs {
+            type Output = $output;
+            #[inline]
+            fn $fn(self, other: $rhs) -> $output {
+                std::ops::$trait::$fn(*self, other)
+            }
+        }
+
+        impl<'a, 'b> std::ops::$trait<&'a $rhs> for &'b $lhs {
+            type Output = $output;
+            #[inline]
+            fn $fn(self, other: &'a $rhs) -> $output {
+                std::ops::$trait::$fn(*self, *other)
+            }
+        }
+    };
+}
+
+// Implements `from_slice`, `write_to_slice` and their `try_` variants in terms of
+// the `from_slice_unaligned` and `write_to_slice_unaligned` methods of `$ty`,
+// which has `$len` elements of type `$t`.
+macro_rules! impl_slice_methods {
+    ($ty:ty, $t:ty, $len:expr) => {
+        impl $ty {
+            /// Creates a new value from the leading elements of `slice`, stored in
+            /// `x, y, z, w` order for vectors and column major order for matrices.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is too short.
+            #[inline]
+            pub fn from_slice(slice: &[$t]) -> Self {
+                Self::from_slice_unaligned(slice)
+            }
+
+            /// Writes the elements of `self` to the leading elements of `slice`, in
+            /// `x, y, z, w` order for vectors and column major order for matrices.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is too short.
+            #[inline]
+            pub fn write_to_slice(self, slice: &mut [$t]) {
+                self.write_to_slice_unaligned(slice)
+            }
+
+            /// Creates a new value from the leading elements of `slice`, like
+            /// `from_slice`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a `SliceLengthError` if `slice` is too short.
+            #[inline]
+            pub fn try_from_slice(slice: &[$t]) -> Result<Self, crate::SliceLengthError> {
+                crate::SliceLengthError::check(slice.len(), $len)?;
+                Ok(Self::from_slice_unaligned(slice))
+            }
+
+            /// Writes the elements of `self` to the leading elements of `slice`, like
+            /// `write_to_slice`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a `SliceLengthError` if `slice` is too short, in which case
+            /// `slice` is left unchanged.
+            #[inline]
+            pub fn try_write_to_slice(
+                self,
+                slice: &mut [$t],
+            ) -> Result<(), crate::SliceLengthError> {
+                crate::SliceLengthError::check(slice.len(), $len)?;
+                self.write_to_slice_unaligned(slice);
+                Ok(())
+            }
+        }
+    };
+}
+
+// Implements `Index<usize>` and `IndexMut<usize>` for the vector type `$vec`
+// with `$len` elements of type `$t`, using its `AsRef` and `AsMut` array
+// implementations.
+macro_rules! impl_vec_index {
+    ($vec:ident, $t:ty, $len:expr) => {
+        /// Indexes the elements of the vector, `0` being `x`, `1` being `y` and so
+        /// on.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is out of bounds.
+        impl std::ops::Index<usize> for $vec {
+            type Output = $t;
+            #[inline]
+            fn index(&self, index: usize) -> &$t {
+                assert!(index < $len, "index {} out of bounds", index);
+                &AsRef::<[$t; $len]>::as_ref(self)[index]
+            }
+        }
+
+        /// Mutably indexes the elements of the vector, see the `Index`
+        /// implementation.
+        impl std::ops::IndexMut<usize> for $vec {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut $t {
+                assert!(index < $len, "index {} out of bounds", index);
+                &mut AsMut::<[$t; $len]>::as_mut(self)[index]
+            }
+        }
+    };
+}
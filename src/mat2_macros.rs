@@ -0,0 +1,523 @@
+// Shared implementation of 2x2 matrix types.
+//
+// `impl_mat2` implements a 2x2 column major matrix stored as a single 4D
+// vector. The struct itself must be declared by the caller so that it can
+// choose its own attributes and documentation. `scalar_sin_cos` must be in
+// scope where the macro is invoked.
+//
+// `impl_mat2_scalar` implements the determinant, inverse and multiplication for
+// 2x2 matrix types that store their 4D vector as scalars.
+
+macro_rules! impl_mat2 {
+    ($mat2:ident, $t:ty, $vec2:ident, $vec4:ident, $mat3:ident) => {
+        impl Default for $mat2 {
+            #[inline]
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl std::fmt::Display for $mat2 {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "[{}, {}]", self.x_axis(), self.y_axis())
+            }
+        }
+
+        impl $mat2 {
+            #[inline]
+            pub fn zero() -> Self {
+                $mat2($vec4::zero())
+            }
+
+            #[inline]
+            pub fn identity() -> Self {
+                Self($vec4::new(1.0, 0.0, 0.0, 1.0))
+            }
+
+            /// Creates a new matrix from two column vectors.
+            #[inline]
+            pub fn from_cols(x_axis: $vec2, y_axis: $vec2) -> Self {
+                Self($vec4::new(x_axis.x(), x_axis.y(), y_axis.x(), y_axis.y()))
+            }
+
+            /// Creates a new matrix from an array stored in column major order.
+            /// If your data is stored in row major you will need to `transpose` the resulting matrix.
+            #[inline]
+            pub fn from_cols_array(m: &[$t; 4]) -> Self {
+                $mat2($vec4::new(m[0], m[1], m[2], m[3]))
+            }
+
+            /// Creates a new array storing data in column major order.
+            /// If you require data in row major order `transpose` the matrix first.
+            #[inline]
+            pub fn to_cols_array(&self) -> [$t; 4] {
+                self.0.into()
+            }
+
+            /// Creates a new matrix from a 2D array stored in column major order.
+            /// If your data is in row major order you will need to `transpose` the resulting matrix.
+            #[inline]
+            pub fn from_cols_array_2d(m: &[[$t; 2]; 2]) -> Self {
+                $mat2($vec4::new(m[0][0], m[0][1], m[1][0], m[1][1]))
+            }
+
+            /// Creates a new 2D array storing data in column major order.
+            /// If you require data in row major order `transpose` the matrix first.
+            #[inline]
+            pub fn to_cols_array_2d(&self) -> [[$t; 2]; 2] {
+                let (x0, y0, x1, y1) = self.0.into();
+                [[x0, y0], [x1, y1]]
+            }
+
+            /// Creates a new matrix from the first 4 values in `slice`, stored in
+            /// column major order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than 4 elements long.
+            #[inline]
+            pub fn from_slice_unaligned(slice: &[$t]) -> Self {
+                $mat2($vec4::from_slice_unaligned(slice))
+            }
+
+            /// Writes the elements of `self` to the first 4 elements in `slice` in
+            /// column major order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than 4 elements long.
+            #[inline]
+            pub fn write_to_slice_unaligned(&self, slice: &mut [$t]) {
+                self.0.write_to_slice_unaligned(slice)
+            }
+
+            /// Creates a 2x2 matrix from the upper left 2x2 part of `m`, dropping the
+            /// translation of a 2D affine transform.
+            #[inline]
+            pub fn from_mat3(m: &$mat3) -> Self {
+                Self::from_cols(m.x_axis.truncate(), m.y_axis.truncate())
+            }
+
+            /// Create a 2x2 matrix containing scale and rotation (in radians).
+            #[inline]
+            pub fn from_scale_angle(scale: $vec2, angle: $t) -> Self {
+                let (sin, cos) = scalar_sin_cos(angle);
+                let (scale_x, scale_y) = scale.into();
+                Self($vec4::new(
+                    cos * scale_x,
+                    sin * scale_x,
+                    -sin * scale_y,
+                    cos * scale_y,
+                ))
+            }
+
+            /// Create a 2x2 matrix containing a rotation (in radians).
+            #[inline]
+            pub fn from_angle(angle: $t) -> Self {
+                let (sin, cos) = scalar_sin_cos(angle);
+                Self($vec4::new(cos, sin, -sin, cos))
+            }
+
+            #[inline]
+            pub fn from_scale(scale: $vec2) -> Self {
+                let (x, y) = scale.into();
+                Self($vec4::new(x, 0.0, 0.0, y))
+            }
+
+            /// Creates a 2x2 matrix with `diagonal` on its diagonal and all other
+            /// elements set to `0.0`.
+            #[inline]
+            pub fn from_diagonal(diagonal: $vec2) -> Self {
+                let (x, y) = diagonal.into();
+                Self($vec4::new(x, 0.0, 0.0, y))
+            }
+
+            /// Returns the diagonal of `self`.
+            #[inline]
+            pub fn diagonal(&self) -> $vec2 {
+                let (x, _, _, y) = self.0.into();
+                $vec2::new(x, y)
+            }
+
+            /// Returns the trace of `self`, the sum of its diagonal elements.
+            #[inline]
+            pub fn trace(&self) -> $t {
+                let (x, _, _, y) = self.0.into();
+                x + y
+            }
+
+            #[inline]
+            pub fn set_x_axis(&mut self, x: $vec2) {
+                let m = self.0.as_mut();
+                m[0] = x.x();
+                m[1] = x.y();
+            }
+
+            #[inline]
+            pub fn set_y_axis(&mut self, y: $vec2) {
+                let m = self.0.as_mut();
+                m[2] = y.x();
+                m[3] = y.y();
+            }
+
+            #[inline]
+            pub fn x_axis(&self) -> $vec2 {
+                let (x, y, _, _) = self.0.into();
+                $vec2::new(x, y)
+            }
+
+            #[inline]
+            pub fn y_axis(&self) -> $vec2 {
+                let (_, _, x, y) = self.0.into();
+                $vec2::new(x, y)
+            }
+
+            /// Returns the column at `index`, where `0` is the `x_axis`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 1.
+            #[inline]
+            pub fn col(&self, index: usize) -> $vec2 {
+                match index {
+                    0 => self.x_axis(),
+                    1 => self.y_axis(),
+                    _ => panic!("column index {} out of bounds", index),
+                }
+            }
+
+            /// Sets the column at `index`, where `0` is the `x_axis`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 1.
+            #[inline]
+            pub fn set_col(&mut self, index: usize, col: $vec2) {
+                match index {
+                    0 => self.set_x_axis(col),
+                    1 => self.set_y_axis(col),
+                    _ => panic!("column index {} out of bounds", index),
+                }
+            }
+
+            /// Returns the row at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 1.
+            #[inline]
+            pub fn row(&self, index: usize) -> $vec2 {
+                assert!(index < 2, "row index {} out of bounds", index);
+                let m = self.0.as_ref();
+                $vec2::new(m[index], m[index + 2])
+            }
+
+            /// Sets the row at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 1.
+            #[inline]
+            pub fn set_row(&mut self, index: usize, row: $vec2) {
+                assert!(index < 2, "row index {} out of bounds", index);
+                let m = self.0.as_mut();
+                m[index] = row.x();
+                m[index + 2] = row.y();
+            }
+
+            #[inline]
+            pub fn transpose(&self) -> Self {
+                let (m00, m01, m10, m11) = self.0.into();
+                Self($vec4::new(m00, m10, m01, m11))
+            }
+
+            /// Computes the singular value decomposition of `self`, returned as
+            /// `(u, singular_values, v)` so that
+            /// `self == u * Self::from_diagonal(singular_values) * v.transpose()`.
+            ///
+            /// The singular values are non negative and sorted from largest to
+            /// smallest, `u` and `v` are orthogonal. `v` is always a rotation while `u`
+            /// also contains a reflection when the determinant of `self` is negative.
+            /// This uses a closed form solution.
+            pub fn svd(&self) -> (Self, $vec2, Self) {
+                let (m00, m01, m10, m11) = self.0.into();
+                let e = (m00 + m11) * 0.5;
+                let f = (m00 - m11) * 0.5;
+                let g = (m01 + m10) * 0.5;
+                let h = (m01 - m10) * 0.5;
+                let q = (e * e + h * h).sqrt();
+                let r = (f * f + g * g).sqrt();
+                let a1 = g.atan2(f);
+                let a2 = h.atan2(e);
+                // self == from_angle(phi) * from_diagonal(sx, sy) * from_angle(theta)
+                let theta = (a2 - a1) * 0.5;
+                let phi = (a2 + a1) * 0.5;
+                let (sin_phi, cos_phi) = scalar_sin_cos(phi);
+                let sx = q + r;
+                let sy = q - r;
+                let (u, sy) = if sy < 0.0 {
+                    (Self($vec4::new(cos_phi, sin_phi, sin_phi, -cos_phi)), -sy)
+                } else {
+                    (Self($vec4::new(cos_phi, sin_phi, -sin_phi, cos_phi)), sy)
+                };
+                (u, $vec2::new(sx, sy), Self::from_angle(-theta))
+            }
+
+            #[inline]
+            pub fn add_mat2(&self, other: &Self) -> Self {
+                $mat2(self.0 + other.0)
+            }
+
+            #[inline]
+            pub fn sub_mat2(&self, other: &Self) -> Self {
+                $mat2(self.0 - other.0)
+            }
+
+            #[inline]
+            pub fn mul_scalar(&self, other: $t) -> Self {
+                let s = $vec4::splat(other);
+                $mat2(self.0 * s)
+            }
+
+            /// Returns `true` if all elements of `self` are finite, that is neither
+            /// infinite nor NaN.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                self.0.as_ref().iter().all(|e| e.is_finite())
+            }
+
+            /// Returns `true` if any element of `self` is NaN.
+            #[inline]
+            pub fn is_nan(&self) -> bool {
+                self.0.as_ref().iter().any(|e| e.is_nan())
+            }
+
+            /// Returns true if the absolute difference of all elements between `self`
+            /// and `other` is less than or equal to `max_abs_diff`.
+            ///
+            /// This can be used to compare if two matrices contain similar elements. It
+            /// works best when comparing with a known value. The `max_abs_diff` that
+            /// should be used used depends on the values being compared against.
+            ///
+            /// For more on floating point comparisons see
+            /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+            #[inline]
+            pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+                self.0.abs_diff_eq(other.0, max_abs_diff)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or within `max_relative` times the larger
+            /// magnitude of the two elements.
+            #[inline]
+            pub fn relative_eq(&self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                self.0.relative_eq(other.0, max_abs_diff, max_relative)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or at most `max_ulps` representable
+            /// floating point values apart.
+            #[inline]
+            pub fn ulps_eq(&self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                self.0.ulps_eq(other.0, max_abs_diff, max_ulps)
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl rand::distributions::Distribution<$mat2> for rand::distributions::Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $mat2 {
+                $mat2::from_cols_array(&rng.gen())
+            }
+        }
+
+        impl AsRef<[$t; 4]> for $mat2 {
+            #[inline]
+            fn as_ref(&self) -> &[$t; 4] {
+                unsafe { &*(self as *const Self as *const [$t; 4]) }
+            }
+        }
+
+        impl AsMut<[$t; 4]> for $mat2 {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [$t; 4] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; 4]) }
+            }
+        }
+
+        impl std::ops::Add<$mat2> for $mat2 {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                self.add_mat2(&other)
+            }
+        }
+
+        impl std::ops::AddAssign<$mat2> for $mat2 {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = self.add_mat2(&other);
+            }
+        }
+
+        impl std::ops::Sub<$mat2> for $mat2 {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                self.sub_mat2(&other)
+            }
+        }
+
+        impl std::ops::SubAssign<$mat2> for $mat2 {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = self.sub_mat2(&other);
+            }
+        }
+
+        impl std::ops::Mul<$mat2> for $mat2 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                self.mul_mat2(&other)
+            }
+        }
+
+        impl std::ops::MulAssign<$mat2> for $mat2 {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = self.mul_mat2(&other);
+            }
+        }
+
+        impl std::ops::Mul<$vec2> for $mat2 {
+            type Output = $vec2;
+            #[inline]
+            fn mul(self, other: $vec2) -> $vec2 {
+                self.mul_vec2(other)
+            }
+        }
+
+        impl std::ops::Mul<$mat2> for $t {
+            type Output = $mat2;
+            #[inline]
+            fn mul(self, other: $mat2) -> $mat2 {
+                other.mul_scalar(self)
+            }
+        }
+
+        impl std::ops::Mul<$t> for $mat2 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: $t) -> Self {
+                self.mul_scalar(other)
+            }
+        }
+
+        impl std::ops::MulAssign<$t> for $mat2 {
+            #[inline]
+            fn mul_assign(&mut self, other: $t) {
+                *self = self.mul_scalar(other);
+            }
+        }
+
+        impl std::ops::Div<$t> for $mat2 {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: $t) -> Self {
+                $mat2(self.0 / other)
+            }
+        }
+
+        impl std::ops::DivAssign<$t> for $mat2 {
+            #[inline]
+            fn div_assign(&mut self, other: $t) {
+                *self = *self / other;
+            }
+        }
+
+        impl std::ops::Neg for $mat2 {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                $mat2(-self.0)
+            }
+        }
+
+        /// Indexes the elements of the matrix by `(column, row)`, following the
+        /// column major convention of glam. For example `m[(1, 0)]` is the `x`
+        /// element of the `y_axis` column.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the column or the row is out of bounds.
+        impl std::ops::Index<(usize, usize)> for $mat2 {
+            type Output = $t;
+            #[inline]
+            fn index(&self, (col, row): (usize, usize)) -> &$t {
+                assert!(col < 2, "column index {} out of bounds", col);
+                assert!(row < 2, "row index {} out of bounds", row);
+                &self.0.as_ref()[col * 2 + row]
+            }
+        }
+
+        /// Mutably indexes the elements of the matrix by `(column, row)`, see the
+        /// `Index` implementation.
+        impl std::ops::IndexMut<(usize, usize)> for $mat2 {
+            #[inline]
+            fn index_mut(&mut self, (col, row): (usize, usize)) -> &mut $t {
+                assert!(col < 2, "column index {} out of bounds", col);
+                assert!(row < 2, "row index {} out of bounds", row);
+                &mut self.0.as_mut()[col * 2 + row]
+            }
+        }
+
+        impl_ref_binop!(Add, add, $mat2, $mat2, $mat2);
+        impl_ref_binop!(Sub, sub, $mat2, $mat2, $mat2);
+        impl_ref_binop!(Mul, mul, $mat2, $mat2, $mat2);
+        impl_ref_binop!(Mul, mul, $mat2, $vec2, $vec2);
+        impl_ref_binop!(Mul, mul, $mat2, $t, $mat2);
+
+        impl_sum!($mat2, $mat2::zero());
+        impl_product!($mat2, $mat2::identity());
+        impl_slice_methods!($mat2, $t, 4);
+    };
+}
+
+macro_rules! impl_mat2_scalar {
+    ($mat2:ident, $t:ty, $vec2:ident, $vec4:ident) => {
+        impl $mat2 {
+            #[inline]
+            pub fn determinant(&self) -> $t {
+                let (a, b, c, d) = self.0.into();
+                a * d - b * c
+            }
+
+            #[inline]
+            pub fn inverse(&self) -> Self {
+                let (a, b, c, d) = self.0.into();
+                let det = a * d - b * c;
+                glam_assert!(det != 0.0);
+                let tmp = $vec4::new(1.0, -1.0, -1.0, 1.0) / det;
+                Self($vec4::new(d, b, c, a) * tmp)
+            }
+
+            #[inline]
+            pub fn mul_vec2(&self, other: $vec2) -> $vec2 {
+                let other = $vec4::new(other.x(), other.x(), other.y(), other.y());
+                let tmp = self.0 * other;
+                let (x0, y0, x1, y1) = tmp.into();
+                $vec2::new(x0 + x1, y0 + y1)
+            }
+
+            #[inline]
+            pub fn mul_mat2(&self, other: &Self) -> Self {
+                let (x0, y0, x1, y1) = other.0.into();
+                $mat2::from_cols(
+                    self.mul_vec2($vec2::new(x0, y0)),
+                    self.mul_vec2($vec2::new(x1, y1)),
+                )
+            }
+        }
+    };
+}
@@ -0,0 +1,926 @@
+// Shared implementation of 3x3 matrix types.
+//
+// `impl_mat3` implements a 3x3 column major matrix stored as three 3D column
+// vectors named `x_axis`, `y_axis` and `z_axis`. The struct itself must be
+// declared by the caller so that it can choose its own attributes and
+// documentation. `scalar_sin_cos` must be in scope where the macro is invoked.
+//
+// `impl_mat3_quat` implements conversions from a quaternion type to a 3x3
+// matrix type.
+
+macro_rules! impl_mat3 {
+    ($mat3:ident, $t:ty, $vec2:ident, $vec3:ident, $mat2:ident, $mat4:ident) => {
+        impl Default for $mat3 {
+            #[inline]
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl std::fmt::Display for $mat3 {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+            }
+        }
+
+        impl $mat3 {
+            #[inline]
+            pub fn zero() -> Self {
+                Self {
+                    x_axis: $vec3::zero(),
+                    y_axis: $vec3::zero(),
+                    z_axis: $vec3::zero(),
+                }
+            }
+
+            #[inline]
+            pub fn identity() -> Self {
+                Self {
+                    x_axis: $vec3::unit_x(),
+                    y_axis: $vec3::unit_y(),
+                    z_axis: $vec3::unit_z(),
+                }
+            }
+
+            /// Creates a new matrix from three column vectors.
+            #[inline]
+            pub fn from_cols(x_axis: $vec3, y_axis: $vec3, z_axis: $vec3) -> Self {
+                Self {
+                    x_axis,
+                    y_axis,
+                    z_axis,
+                }
+            }
+
+            /// Creates a new matrix from an array stored in column major order.
+            /// If your data is stored in row major you will need to `transpose` the resulting matrix.
+            #[inline]
+            pub fn from_cols_array(m: &[$t; 9]) -> Self {
+                $mat3 {
+                    x_axis: $vec3::new(m[0], m[1], m[2]),
+                    y_axis: $vec3::new(m[3], m[4], m[5]),
+                    z_axis: $vec3::new(m[6], m[7], m[8]),
+                }
+            }
+
+            /// Creates a new array storing data in column major order.
+            /// If you require data in row major order `transpose` the matrix first.
+            #[inline]
+            pub fn to_cols_array(&self) -> [$t; 9] {
+                let (m00, m01, m02) = self.x_axis.into();
+                let (m10, m11, m12) = self.y_axis.into();
+                let (m20, m21, m22) = self.z_axis.into();
+                [m00, m01, m02, m10, m11, m12, m20, m21, m22]
+            }
+
+            /// Creates a new matrix from a 2D array stored in column major order.
+            /// If your data is in row major order you will need to `transpose` the resulting matrix.
+            #[inline]
+            pub fn from_cols_array_2d(m: &[[$t; 3]; 3]) -> Self {
+                $mat3 {
+                    x_axis: m[0].into(),
+                    y_axis: m[1].into(),
+                    z_axis: m[2].into(),
+                }
+            }
+
+            /// Creates a new 2D array storing data in column major order.
+            /// If you require data in row major order `transpose` the matrix first.
+            #[inline]
+            pub fn to_cols_array_2d(&self) -> [[$t; 3]; 3] {
+                [self.x_axis.into(), self.y_axis.into(), self.z_axis.into()]
+            }
+
+            /// Creates a new matrix from the first 9 values in `slice`, stored in
+            /// column major order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than 9 elements long.
+            #[inline]
+            pub fn from_slice_unaligned(slice: &[$t]) -> Self {
+                Self {
+                    x_axis: $vec3::from_slice_unaligned(&slice[0..3]),
+                    y_axis: $vec3::from_slice_unaligned(&slice[3..6]),
+                    z_axis: $vec3::from_slice_unaligned(&slice[6..9]),
+                }
+            }
+
+            /// Writes the elements of `self` to the first 9 elements in `slice` in
+            /// column major order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than 9 elements long.
+            #[inline]
+            pub fn write_to_slice_unaligned(&self, slice: &mut [$t]) {
+                self.x_axis.write_to_slice_unaligned(&mut slice[0..3]);
+                self.y_axis.write_to_slice_unaligned(&mut slice[3..6]);
+                self.z_axis.write_to_slice_unaligned(&mut slice[6..9]);
+            }
+
+            /// Creates a 3x3 matrix from the upper left 3x3 part of `m`, dropping the
+            /// translation of an affine transform.
+            #[inline]
+            pub fn from_mat4(m: &$mat4) -> Self {
+                Self {
+                    x_axis: m.x_axis.truncate().into(),
+                    y_axis: m.y_axis.truncate().into(),
+                    z_axis: m.z_axis.truncate().into(),
+                }
+            }
+
+            /// Creates a 3x3 matrix with `m` as its upper left 2x2 part and the
+            /// remaining elements taken from the identity, giving a 2D affine
+            /// transform without translation.
+            #[inline]
+            pub fn from_mat2(m: &$mat2) -> Self {
+                Self {
+                    x_axis: m.x_axis().extend(0.0).into(),
+                    y_axis: m.y_axis().extend(0.0).into(),
+                    z_axis: $vec3::unit_z(),
+                }
+            }
+
+            /// Creates a new matrix that can scale, rotate and translate a 2D vector.
+            /// `angle` is in radians.
+            #[inline]
+            pub fn from_scale_angle_translation(
+                scale: $vec2,
+                angle: $t,
+                translation: $vec2,
+            ) -> Self {
+                let (sin, cos) = scalar_sin_cos(angle);
+                let (scale_x, scale_y) = scale.into();
+                Self {
+                    x_axis: $vec3::new(cos * scale_x, sin * scale_x, 0.0),
+                    y_axis: $vec3::new(-sin * scale_y, cos * scale_y, 0.0),
+                    z_axis: $vec3::new(translation.x(), translation.y(), 1.0),
+                }
+            }
+
+            /// Creates a new 2D affine transform matrix containing the given translation.
+            #[inline]
+            pub fn from_translation(translation: $vec2) -> Self {
+                Self {
+                    x_axis: $vec3::unit_x(),
+                    y_axis: $vec3::unit_y(),
+                    z_axis: $vec3::new(translation.x(), translation.y(), 1.0),
+                }
+            }
+
+            /// Creates a new 2D affine transform matrix containing a rotation of `angle`
+            /// (in radians). This is the same as `from_rotation_z`.
+            #[inline]
+            pub fn from_angle(angle: $t) -> Self {
+                Self::from_rotation_z(angle)
+            }
+
+            /// Create a 3x3 rotation matrix from a normalized rotation axis and angle (in radians).
+            #[inline]
+            pub fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+                glam_assert!(axis.is_normalized());
+                let (sin, cos) = scalar_sin_cos(angle);
+                let (x, y, z) = axis.into();
+                let (xsin, ysin, zsin) = (axis * sin).into();
+                let (x2, y2, z2) = (axis * axis).into();
+                let omc = 1.0 - cos;
+                let xyomc = x * y * omc;
+                let xzomc = x * z * omc;
+                let yzomc = y * z * omc;
+                Self {
+                    x_axis: $vec3::new(x2 * omc + cos, xyomc + zsin, xzomc - ysin),
+                    y_axis: $vec3::new(xyomc - zsin, y2 * omc + cos, yzomc + xsin),
+                    z_axis: $vec3::new(xzomc + ysin, yzomc - xsin, z2 * omc + cos),
+                }
+            }
+
+            /// Create a 3x3 rotation matrix from the angle (in radians) around the x axis.
+            #[inline]
+            pub fn from_rotation_x(angle: $t) -> Self {
+                let (sina, cosa) = scalar_sin_cos(angle);
+                Self {
+                    x_axis: $vec3::unit_x(),
+                    y_axis: $vec3::new(0.0, cosa, sina),
+                    z_axis: $vec3::new(0.0, -sina, cosa),
+                }
+            }
+
+            /// Create a 3x3 rotation matrix from the angle (in radians) around the y axis.
+            #[inline]
+            pub fn from_rotation_y(angle: $t) -> Self {
+                let (sina, cosa) = scalar_sin_cos(angle);
+                Self {
+                    x_axis: $vec3::new(cosa, 0.0, -sina),
+                    y_axis: $vec3::unit_y(),
+                    z_axis: $vec3::new(sina, 0.0, cosa),
+                }
+            }
+
+            /// Create a 3x3 rotation matrix from the angle (in radians) around the z axis.
+            #[inline]
+            pub fn from_rotation_z(angle: $t) -> Self {
+                let (sina, cosa) = scalar_sin_cos(angle);
+                Self {
+                    x_axis: $vec3::new(cosa, sina, 0.0),
+                    y_axis: $vec3::new(-sina, cosa, 0.0),
+                    z_axis: $vec3::unit_z(),
+                }
+            }
+
+            #[inline]
+            pub fn from_scale(scale: $vec3) -> Self {
+                glam_assert!(scale.cmpne($vec3::zero()).all());
+                let (x, y, z) = scale.into();
+                Self {
+                    x_axis: $vec3::new(x, 0.0, 0.0),
+                    y_axis: $vec3::new(0.0, y, 0.0),
+                    z_axis: $vec3::new(0.0, 0.0, z),
+                }
+            }
+
+            /// Creates a 3x3 matrix with `diagonal` on its diagonal and all other
+            /// elements set to `0.0`.
+            #[inline]
+            pub fn from_diagonal(diagonal: $vec3) -> Self {
+                let (x, y, z) = diagonal.into();
+                Self {
+                    x_axis: $vec3::new(x, 0.0, 0.0),
+                    y_axis: $vec3::new(0.0, y, 0.0),
+                    z_axis: $vec3::new(0.0, 0.0, z),
+                }
+            }
+
+            /// Creates the skew-symmetric matrix of `v`, so that multiplying a vector
+            /// by it is the same as taking the cross product with `v`:
+            /// `Mat3::from_cross(v) * other == v.cross(other)`.
+            #[inline]
+            pub fn from_cross(v: $vec3) -> Self {
+                let (x, y, z) = v.into();
+                Self {
+                    x_axis: $vec3::new(0.0, z, -y),
+                    y_axis: $vec3::new(-z, 0.0, x),
+                    z_axis: $vec3::new(y, -x, 0.0),
+                }
+            }
+
+            /// Returns `self` with orthonormal axes, computed with the Gram-Schmidt
+            /// process.
+            ///
+            /// The `x_axis` keeps its direction, the `y_axis` is made perpendicular to
+            /// it and the `z_axis` to both. This corrects the drift of a rotation matrix
+            /// that has accumulated floating point error over many multiplications.
+            /// The axes of `self` must be linearly independent.
+            #[inline]
+            pub fn orthonormalize(&self) -> Self {
+                let x_axis = self.x_axis.normalize();
+                let y_axis = (self.y_axis - x_axis * x_axis.dot(self.y_axis)).normalize();
+                let z_axis = self.z_axis
+                    - x_axis * x_axis.dot(self.z_axis)
+                    - y_axis * y_axis.dot(self.z_axis);
+                Self {
+                    x_axis,
+                    y_axis,
+                    z_axis: z_axis.normalize(),
+                }
+            }
+
+            /// Returns the diagonal of `self`.
+            #[inline]
+            pub fn diagonal(&self) -> $vec3 {
+                $vec3::new(self.x_axis.x(), self.y_axis.y(), self.z_axis.z())
+            }
+
+            /// Returns the trace of `self`, the sum of its diagonal elements.
+            #[inline]
+            pub fn trace(&self) -> $t {
+                self.x_axis.x() + self.y_axis.y() + self.z_axis.z()
+            }
+
+            #[inline]
+            pub fn set_x_axis(&mut self, x: $vec3) {
+                self.x_axis = x;
+            }
+
+            #[inline]
+            pub fn set_y_axis(&mut self, y: $vec3) {
+                self.y_axis = y;
+            }
+
+            #[inline]
+            pub fn set_z_axis(&mut self, z: $vec3) {
+                self.z_axis = z;
+            }
+
+            #[inline]
+            pub fn x_axis(&self) -> $vec3 {
+                self.x_axis
+            }
+
+            #[inline]
+            pub fn y_axis(&self) -> $vec3 {
+                self.y_axis
+            }
+
+            #[inline]
+            pub fn z_axis(&self) -> $vec3 {
+                self.z_axis
+            }
+
+            /// Returns the column at `index`, where `0` is the `x_axis`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 2.
+            #[inline]
+            pub fn col(&self, index: usize) -> $vec3 {
+                match index {
+                    0 => self.x_axis,
+                    1 => self.y_axis,
+                    2 => self.z_axis,
+                    _ => panic!("column index {} out of bounds", index),
+                }
+            }
+
+            /// Sets the column at `index`, where `0` is the `x_axis`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 2.
+            #[inline]
+            pub fn set_col(&mut self, index: usize, col: $vec3) {
+                match index {
+                    0 => self.x_axis = col,
+                    1 => self.y_axis = col,
+                    2 => self.z_axis = col,
+                    _ => panic!("column index {} out of bounds", index),
+                }
+            }
+
+            /// Returns the row at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 2.
+            #[inline]
+            pub fn row(&self, index: usize) -> $vec3 {
+                assert!(index < 3, "row index {} out of bounds", index);
+                $vec3::new(
+                    self.x_axis.as_ref()[index],
+                    self.y_axis.as_ref()[index],
+                    self.z_axis.as_ref()[index],
+                )
+            }
+
+            /// Sets the row at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 2.
+            #[inline]
+            pub fn set_row(&mut self, index: usize, row: $vec3) {
+                assert!(index < 3, "row index {} out of bounds", index);
+                let (x, y, z) = row.into();
+                self.x_axis.as_mut()[index] = x;
+                self.y_axis.as_mut()[index] = y;
+                self.z_axis.as_mut()[index] = z;
+            }
+
+            #[inline]
+            pub fn transpose(&self) -> Self {
+                let (m00, m01, m02) = self.x_axis.into();
+                let (m10, m11, m12) = self.y_axis.into();
+                let (m20, m21, m22) = self.z_axis.into();
+
+                Self {
+                    x_axis: $vec3::new(m00, m10, m20),
+                    y_axis: $vec3::new(m01, m11, m21),
+                    z_axis: $vec3::new(m02, m12, m22),
+                }
+            }
+
+            #[inline]
+            pub fn determinant(&self) -> $t {
+                self.z_axis.dot(self.x_axis.cross(self.y_axis))
+            }
+
+            pub fn inverse(&self) -> Self {
+                let tmp0 = self.y_axis.cross(self.z_axis);
+                let tmp1 = self.z_axis.cross(self.x_axis);
+                let tmp2 = self.x_axis.cross(self.y_axis);
+                let det = self.z_axis.dot_into_vec(tmp2);
+                glam_assert!(det.cmpne($vec3::zero()).all());
+                let inv_det = det.reciprocal();
+                // TODO: Work out if it's possible to get rid of the transpose
+                $mat3::from_cols(tmp0 * inv_det, tmp1 * inv_det, tmp2 * inv_det).transpose()
+            }
+
+            /// Decomposes `self` into an orthogonal matrix and a symmetric matrix, so
+            /// that `self == rotation * stretch`, returned as `(rotation, stretch)`.
+            ///
+            /// The rotation is the closest orthogonal matrix to `self`, which makes this
+            /// useful for extracting a rotation from a deformed frame. It is computed
+            /// with Higham's iteration, averaging the matrix with its inverse transpose
+            /// until it converges.
+            ///
+            /// `self` must be invertible. When its determinant is negative the
+            /// orthogonal part contains a reflection as well as a rotation.
+            pub fn polar_decomposition(&self) -> (Self, Self) {
+                const MAX_ITERATIONS: usize = 32;
+                const TOLERANCE: $t = 16.0 * <$t>::EPSILON;
+                glam_assert!(self.determinant() != 0.0);
+                let mut rotation = *self;
+                for _ in 0..MAX_ITERATIONS {
+                    // the inverse transpose has the scaled cross products as columns
+                    let col0 = rotation.y_axis.cross(rotation.z_axis);
+                    let col1 = rotation.z_axis.cross(rotation.x_axis);
+                    let col2 = rotation.x_axis.cross(rotation.y_axis);
+                    let inv_det = 1.0 / rotation.x_axis.dot(col0);
+                    let inverse_transpose = Self {
+                        x_axis: col0 * inv_det,
+                        y_axis: col1 * inv_det,
+                        z_axis: col2 * inv_det,
+                    };
+                    let next = (rotation + inverse_transpose) * 0.5;
+                    let converged = next.abs_diff_eq(rotation, TOLERANCE);
+                    rotation = next;
+                    if converged {
+                        break;
+                    }
+                }
+                let stretch = rotation.transpose() * *self;
+                (rotation, stretch)
+            }
+
+            /// Computes the eigenvalues and eigenvectors of the symmetric matrix
+            /// `self`, returned as `(eigenvalues, eigenvectors)`.
+            ///
+            /// The eigenvalues are sorted from largest to smallest and the column of
+            /// `eigenvectors` at the same index is the matching unit length
+            /// eigenvector. The eigenvectors form a rotation matrix, so that
+            /// `self == eigenvectors * Self::from_diagonal(eigenvalues) *
+            /// eigenvectors.transpose()`. They are computed with the cyclic Jacobi
+            /// method.
+            ///
+            /// Only the lower triangle of `self` is read, the upper triangle is
+            /// assumed to be the same.
+            pub fn symmetric_eigen(&self) -> ($vec3, Self) {
+                const MAX_SWEEPS: usize = 32;
+                let (m00, m10, m20) = self.x_axis.into();
+                let (_, m11, m21) = self.y_axis.into();
+                let m22 = self.z_axis.z();
+                // a[row][col] is symmetric and v accumulates the rotations
+                let mut a = [[m00, m10, m20], [m10, m11, m21], [m20, m21, m22]];
+                let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+                let norm_sq = m00 * m00
+                    + m11 * m11
+                    + m22 * m22
+                    + 2.0 * (m10 * m10 + m20 * m20 + m21 * m21);
+                let tolerance = <$t>::EPSILON * <$t>::EPSILON * norm_sq;
+                for _ in 0..MAX_SWEEPS {
+                    let off_sq = a[1][0] * a[1][0] + a[2][0] * a[2][0] + a[2][1] * a[2][1];
+                    if off_sq <= tolerance {
+                        break;
+                    }
+                    for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+                        let apq = a[p][q];
+                        if apq == 0.0 {
+                            continue;
+                        }
+                        // the rotation in the pq plane which zeroes a[p][q]
+                        let theta = (a[q][q] - a[p][p]) / (2.0 * apq);
+                        let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                        let c = 1.0 / (t * t + 1.0).sqrt();
+                        let s = t * c;
+                        for row in a.iter_mut().chain(v.iter_mut()) {
+                            let (rp, rq) = (row[p], row[q]);
+                            row[p] = c * rp - s * rq;
+                            row[q] = s * rp + c * rq;
+                        }
+                        let (ap, aq) = (a[p], a[q]);
+                        for k in 0..3 {
+                            a[p][k] = c * ap[k] - s * aq[k];
+                            a[q][k] = s * ap[k] + c * aq[k];
+                        }
+                    }
+                }
+
+                let mut order = [0, 1, 2];
+                order.sort_by(|&i, &j| {
+                    a[j][j]
+                        .partial_cmp(&a[i][i])
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                let [i, j, k] = order;
+                let eigenvalues = $vec3::new(a[i][i], a[j][j], a[k][k]);
+                let x_axis = $vec3::new(v[0][i], v[1][i], v[2][i]);
+                let y_axis = $vec3::new(v[0][j], v[1][j], v[2][j]);
+                // the cross product keeps the eigenvectors right handed
+                let z_axis = x_axis.cross(y_axis);
+                (eigenvalues, Self::from_cols(x_axis, y_axis, z_axis))
+            }
+
+            /// Computes the singular value decomposition of `self`, returned as
+            /// `(u, singular_values, v)` so that
+            /// `self == u * Self::from_diagonal(singular_values) * v.transpose()`.
+            ///
+            /// The singular values are non negative and sorted from largest to
+            /// smallest, `u` and `v` are orthogonal. `v` is always a rotation while `u`
+            /// also contains a reflection when the determinant of `self` is negative.
+            /// `v` is found iteratively as the eigenvectors of `self^T * self`, see
+            /// `symmetric_eigen`.
+            pub fn svd(&self) -> (Self, $vec3, Self) {
+                let (_, v) = (self.transpose() * *self).symmetric_eigen();
+                let mv0 = *self * v.x_axis;
+                let mv1 = *self * v.y_axis;
+                let mv2 = *self * v.z_axis;
+
+                // the columns of u are the normalized images of the columns of v,
+                // completing the basis where self is singular
+                let s0 = mv0.length();
+                let u0 = if s0 > 0.0 { mv0 / s0 } else { v.x_axis };
+                let w = mv1 - u0 * u0.dot(mv1);
+                let s1 = w.length();
+                let u1 = if s1 > <$t>::EPSILON * s0 {
+                    w / s1
+                } else {
+                    u0.any_orthonormal_vector()
+                };
+                let u2 = u0.cross(u1);
+                let s2 = u2.dot(mv2);
+                let (u2, s2) = if s2 < 0.0 { (-u2, -s2) } else { (u2, s2) };
+                (
+                    Self::from_cols(u0, u1, u2),
+                    $vec3::new(s0, s1, s2),
+                    v,
+                )
+            }
+
+            #[inline]
+            pub fn mul_vec3(&self, other: $vec3) -> $vec3 {
+                let mut res = self.x_axis * other.dup_x();
+                res = self.y_axis.mul_add(other.dup_y(), res);
+                res = self.z_axis.mul_add(other.dup_z(), res);
+                res
+            }
+
+            #[inline]
+            /// Multiplies two 3x3 matrices.
+            pub fn mul_mat3(&self, other: &Self) -> Self {
+                Self {
+                    x_axis: self.mul_vec3(other.x_axis),
+                    y_axis: self.mul_vec3(other.y_axis),
+                    z_axis: self.mul_vec3(other.z_axis),
+                }
+            }
+
+            #[inline]
+            pub fn add_mat3(&self, other: &Self) -> Self {
+                Self {
+                    x_axis: self.x_axis + other.x_axis,
+                    y_axis: self.y_axis + other.y_axis,
+                    z_axis: self.z_axis + other.z_axis,
+                }
+            }
+
+            #[inline]
+            pub fn sub_mat3(&self, other: &Self) -> Self {
+                Self {
+                    x_axis: self.x_axis - other.x_axis,
+                    y_axis: self.y_axis - other.y_axis,
+                    z_axis: self.z_axis - other.z_axis,
+                }
+            }
+
+            #[inline]
+            pub fn mul_scalar(&self, other: $t) -> Self {
+                let s = $vec3::splat(other);
+                Self {
+                    x_axis: self.x_axis * s,
+                    y_axis: self.y_axis * s,
+                    z_axis: self.z_axis * s,
+                }
+            }
+
+            /// Transforms the given 2D point, treating `self` as a 2D affine transform
+            /// whose last row is `[0, 0, 1]`.
+            ///
+            /// This applies the translation in the `z_axis` of `self`.
+            #[inline]
+            pub fn transform_point2(&self, other: $vec2) -> $vec2 {
+                let res = self.x_axis * other.x() + self.z_axis;
+                let res = self.y_axis.mul_add($vec3::splat(other.y()), res);
+                res.truncate()
+            }
+
+            /// Transforms the given 2D vector, treating `self` as a 2D affine transform
+            /// whose last row is `[0, 0, 1]`.
+            ///
+            /// This ignores the translation in the `z_axis` of `self`.
+            #[inline]
+            pub fn transform_vector2(&self, other: $vec2) -> $vec2 {
+                let res = self.x_axis * other.x();
+                let res = self.y_axis.mul_add($vec3::splat(other.y()), res);
+                res.truncate()
+            }
+
+            /// Returns `true` if all elements of `self` are finite, that is neither
+            /// infinite nor NaN.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                self.to_cols_array().iter().all(|e| e.is_finite())
+            }
+
+            /// Returns `true` if any element of `self` is NaN.
+            #[inline]
+            pub fn is_nan(&self) -> bool {
+                self.to_cols_array().iter().any(|e| e.is_nan())
+            }
+
+            /// Returns true if the absolute difference of all elements between `self`
+            /// and `other` is less than or equal to `max_abs_diff`.
+            ///
+            /// This can be used to compare if two matrices contain similar elements. It
+            /// works best when comparing with a known value. The `max_abs_diff` that
+            /// should be used used depends on the values being compared against.
+            ///
+            /// For more on floating point comparisons see
+            /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+            #[inline]
+            pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+                self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+                    && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+                    && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or within `max_relative` times the larger
+            /// magnitude of the two elements.
+            #[inline]
+            pub fn relative_eq(&self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                self.x_axis.relative_eq(other.x_axis, max_abs_diff, max_relative)
+                    && self.y_axis.relative_eq(other.y_axis, max_abs_diff, max_relative)
+                    && self.z_axis.relative_eq(other.z_axis, max_abs_diff, max_relative)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or at most `max_ulps` representable
+            /// floating point values apart.
+            #[inline]
+            pub fn ulps_eq(&self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                self.x_axis.ulps_eq(other.x_axis, max_abs_diff, max_ulps)
+                    && self.y_axis.ulps_eq(other.y_axis, max_abs_diff, max_ulps)
+                    && self.z_axis.ulps_eq(other.z_axis, max_abs_diff, max_ulps)
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl rand::distributions::Distribution<$mat3> for rand::distributions::Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $mat3 {
+                $mat3::from_cols_array(&rng.gen())
+            }
+        }
+
+        impl std::ops::Add<$mat3> for $mat3 {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                self.add_mat3(&other)
+            }
+        }
+
+        impl std::ops::AddAssign<$mat3> for $mat3 {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = self.add_mat3(&other);
+            }
+        }
+
+        impl std::ops::Sub<$mat3> for $mat3 {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                self.sub_mat3(&other)
+            }
+        }
+
+        impl std::ops::SubAssign<$mat3> for $mat3 {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = self.sub_mat3(&other);
+            }
+        }
+
+        impl std::ops::Mul<$mat3> for $mat3 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                self.mul_mat3(&other)
+            }
+        }
+
+        impl std::ops::MulAssign<$mat3> for $mat3 {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = self.mul_mat3(&other);
+            }
+        }
+
+        impl std::ops::Mul<$vec3> for $mat3 {
+            type Output = $vec3;
+            #[inline]
+            fn mul(self, other: $vec3) -> $vec3 {
+                self.mul_vec3(other)
+            }
+        }
+
+        impl std::ops::Mul<$mat3> for $t {
+            type Output = $mat3;
+            #[inline]
+            fn mul(self, other: $mat3) -> $mat3 {
+                other.mul_scalar(self)
+            }
+        }
+
+        impl std::ops::Mul<$t> for $mat3 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: $t) -> Self {
+                self.mul_scalar(other)
+            }
+        }
+
+        impl std::ops::MulAssign<$t> for $mat3 {
+            #[inline]
+            fn mul_assign(&mut self, other: $t) {
+                *self = self.mul_scalar(other);
+            }
+        }
+
+        impl std::ops::Div<$t> for $mat3 {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: $t) -> Self {
+                Self {
+                    x_axis: self.x_axis / other,
+                    y_axis: self.y_axis / other,
+                    z_axis: self.z_axis / other,
+                }
+            }
+        }
+
+        impl std::ops::DivAssign<$t> for $mat3 {
+            #[inline]
+            fn div_assign(&mut self, other: $t) {
+                *self = *self / other;
+            }
+        }
+
+        impl std::ops::Neg for $mat3 {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self {
+                    x_axis: -self.x_axis,
+                    y_axis: -self.y_axis,
+                    z_axis: -self.z_axis,
+                }
+            }
+        }
+
+        /// Indexes the elements of the matrix by `(column, row)`, following the
+        /// column major convention of glam. For example `m[(1, 0)]` is the `x`
+        /// element of the `y_axis` column.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the column or the row is out of bounds.
+        impl std::ops::Index<(usize, usize)> for $mat3 {
+            type Output = $t;
+            #[inline]
+            fn index(&self, (col, row): (usize, usize)) -> &$t {
+                let col = match col {
+                    0 => &self.x_axis,
+                    1 => &self.y_axis,
+                    2 => &self.z_axis,
+                    _ => panic!("column index {} out of bounds", col),
+                };
+                &col.as_ref()[row]
+            }
+        }
+
+        /// Mutably indexes the elements of the matrix by `(column, row)`, see the
+        /// `Index` implementation.
+        impl std::ops::IndexMut<(usize, usize)> for $mat3 {
+            #[inline]
+            fn index_mut(&mut self, (col, row): (usize, usize)) -> &mut $t {
+                let col = match col {
+                    0 => &mut self.x_axis,
+                    1 => &mut self.y_axis,
+                    2 => &mut self.z_axis,
+                    _ => panic!("column index {} out of bounds", col),
+                };
+                &mut col.as_mut()[row]
+            }
+        }
+
+        impl_ref_binop!(Add, add, $mat3, $mat3, $mat3);
+        impl_ref_binop!(Sub, sub, $mat3, $mat3, $mat3);
+        impl_ref_binop!(Mul, mul, $mat3, $mat3, $mat3);
+        impl_ref_binop!(Mul, mul, $mat3, $vec3, $vec3);
+        impl_ref_binop!(Mul, mul, $mat3, $t, $mat3);
+
+        impl_sum!($mat3, $mat3::zero());
+        impl_product!($mat3, $mat3::identity());
+        impl_slice_methods!($mat3, $t, 9);
+    };
+}
+
+macro_rules! impl_mat3_quat {
+    ($mat3:ident, $t:ty, $quat:ident, $vec3:ident) => {
+        #[inline]
+        fn quat_to_axes(rotation: $quat) -> ($vec3, $vec3, $vec3) {
+            glam_assert!(rotation.is_normalized());
+            let (x, y, z, w) = rotation.into();
+            let x2 = x + x;
+            let y2 = y + y;
+            let z2 = z + z;
+            let xx = x * x2;
+            let xy = x * y2;
+            let xz = x * z2;
+            let yy = y * y2;
+            let yz = y * z2;
+            let zz = z * z2;
+            let wx = w * x2;
+            let wy = w * y2;
+            let wz = w * z2;
+
+            let x_axis = $vec3::new(1.0 - (yy + zz), xy + wz, xz - wy);
+            let y_axis = $vec3::new(xy - wz, 1.0 - (xx + zz), yz + wx);
+            let z_axis = $vec3::new(xz + wy, yz - wx, 1.0 - (xx + yy));
+            (x_axis, y_axis, z_axis)
+        }
+
+        impl $mat3 {
+            #[inline]
+            pub fn from_quat(rotation: $quat) -> Self {
+                let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+                Self {
+                    x_axis,
+                    y_axis,
+                    z_axis,
+                }
+            }
+
+            /// Create a 3x3 rotation matrix from the given euler angles (in radians).
+            #[inline]
+            pub fn from_rotation_ypr(yaw: $t, pitch: $t, roll: $t) -> Self {
+                let quat = $quat::from_rotation_ypr(yaw, pitch, roll);
+                Self::from_quat(quat)
+            }
+
+            /// Create a 3x3 rotation matrix from the given Euler angles (in radians),
+            /// applied in the given `order`.
+            #[inline]
+            pub fn from_euler(order: crate::EulerRot, a: $t, b: $t, c: $t) -> Self {
+                Self::from_quat($quat::from_euler(order, a, b, c))
+            }
+
+            /// Creates a 3x3 rotation matrix from a rotation vector, whose direction is
+            /// the rotation axis and whose length is the angle (in radians).
+            ///
+            /// This is the exponential map from `so(3)` to rotation matrices, where the
+            /// skew-symmetric matrix of `v` is `Self::from_cross(v)`.
+            #[inline]
+            pub fn from_scaled_axis(v: $vec3) -> Self {
+                Self::from_quat($quat::from_scaled_axis(v.into()))
+            }
+
+            /// Returns the rotation vector of the rotation matrix `self`, whose
+            /// direction is the rotation axis and whose length is the angle (in
+            /// radians) in the range `[0, PI]`.
+            ///
+            /// This is the logarithm map from rotation matrices to `so(3)`, the inverse
+            /// of `from_scaled_axis`. `Self::from_cross(self.to_scaled_axis())` gives
+            /// the logarithm as a skew-symmetric matrix.
+            #[inline]
+            pub fn to_scaled_axis(&self) -> $vec3 {
+                let rotation = $quat::from_rotation_axes(
+                    self.x_axis.into(),
+                    self.y_axis.into(),
+                    self.z_axis.into(),
+                );
+                let (x, y, z, w) = rotation.canonicalize().into();
+                let v = $vec3::new(x, y, z);
+                let sin_half_angle = v.length();
+                if sin_half_angle == 0.0 {
+                    $vec3::zero()
+                } else {
+                    // atan2 is more precise than acos for small angles
+                    v * (2.0 * sin_half_angle.atan2(w) / sin_half_angle)
+                }
+            }
+        }
+    };
+}
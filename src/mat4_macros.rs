@@ -0,0 +1,1330 @@
+// Shared implementation of 4x4 matrix types.
+//
+// `impl_mat4` implements a 4x4 column major matrix stored as four 4D column
+// vectors named `x_axis`, `y_axis`, `z_axis` and `w_axis`. The struct itself
+// must be declared by the caller so that it can choose its own attributes and
+// documentation. `transpose`, `inverse` and `mul_mat4` are not included so that
+// the caller can provide a SIMD implementation. `scalar_sin_cos` must be in scope where the
+// macro is invoked.
+//
+// `impl_mat4_scalar` implements `inverse` for 4x4 matrix types that store their
+// columns as scalars.
+//
+// `impl_mat4_quat` implements conversions from a quaternion type to a 4x4
+// matrix type.
+//
+// `impl_mat4_simd_cols` implements conversions between a 4x4 matrix type and
+// an array of the SIMD registers its column vector type can be converted to.
+
+/// The smallest number of elements processed by each rayon task in the
+/// `par_transform_*_slice` methods, so that small slices are not split into
+/// tasks that cost more to schedule than to run.
+#[cfg(feature = "rayon")]
+pub(crate) const PAR_MIN_LEN: usize = 1024;
+
+macro_rules! impl_mat4 {
+    ($mat4:ident, $t:ty, $vec3:ident, $vec4:ident, $mat3:ident) => {
+        impl Default for $mat4 {
+            #[inline]
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl std::fmt::Display for $mat4 {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(
+                    f,
+                    "[{}, {}, {}, {}]",
+                    self.x_axis, self.y_axis, self.z_axis, self.w_axis
+                )
+            }
+        }
+
+        impl $mat4 {
+            /// Creates a new matrix with all elements set to `0.0`.
+            #[inline]
+            pub fn zero() -> Self {
+                Self {
+                    x_axis: $vec4::zero(),
+                    y_axis: $vec4::zero(),
+                    z_axis: $vec4::zero(),
+                    w_axis: $vec4::zero(),
+                }
+            }
+
+            /// Creates a new matrix identity matrix.
+            #[inline]
+            pub fn identity() -> Self {
+                Self {
+                    x_axis: $vec4::unit_x(),
+                    y_axis: $vec4::unit_y(),
+                    z_axis: $vec4::unit_z(),
+                    w_axis: $vec4::unit_w(),
+                }
+            }
+
+            /// Creates a new matrix from four column vectors.
+            #[inline]
+            pub fn from_cols(x_axis: $vec4, y_axis: $vec4, z_axis: $vec4, w_axis: $vec4) -> Self {
+                Self {
+                    x_axis,
+                    y_axis,
+                    z_axis,
+                    w_axis,
+                }
+            }
+
+            /// Creates a new matrix from an array stored in column major order.
+            /// If your data is stored in row major you will need to `transpose` the resulting matrix.
+            #[inline]
+            pub fn from_cols_array(m: &[$t; 16]) -> Self {
+                $mat4 {
+                    x_axis: $vec4::new(m[0], m[1], m[2], m[3]),
+                    y_axis: $vec4::new(m[4], m[5], m[6], m[7]),
+                    z_axis: $vec4::new(m[8], m[9], m[10], m[11]),
+                    w_axis: $vec4::new(m[12], m[13], m[14], m[15]),
+                }
+            }
+
+            /// Creates a new array storing data in column major order.
+            /// If you require data in row major order `transpose` the matrix first.
+            #[inline]
+            pub fn to_cols_array(&self) -> [$t; 16] {
+                *self.as_ref()
+            }
+
+            /// Creates a new matrix from the first 16 values in `slice`, stored in
+            /// column major order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than 16 elements long.
+            #[inline]
+            pub fn from_slice_unaligned(slice: &[$t]) -> Self {
+                Self {
+                    x_axis: $vec4::from_slice_unaligned(&slice[0..4]),
+                    y_axis: $vec4::from_slice_unaligned(&slice[4..8]),
+                    z_axis: $vec4::from_slice_unaligned(&slice[8..12]),
+                    w_axis: $vec4::from_slice_unaligned(&slice[12..16]),
+                }
+            }
+
+            /// Writes the elements of `self` to the first 16 elements in `slice` in
+            /// column major order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than 16 elements long.
+            #[inline]
+            pub fn write_to_slice_unaligned(&self, slice: &mut [$t]) {
+                self.x_axis.write_to_slice_unaligned(&mut slice[0..4]);
+                self.y_axis.write_to_slice_unaligned(&mut slice[4..8]);
+                self.z_axis.write_to_slice_unaligned(&mut slice[8..12]);
+                self.w_axis.write_to_slice_unaligned(&mut slice[12..16]);
+            }
+
+            /// Creates a new matrix from the first 16 values in `slice`, stored in
+            /// column major order, which must start at a multiple of the alignment of
+            /// `Self`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an `AlignmentError` if `slice` is not aligned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than 16 elements long.
+            #[inline]
+            pub fn from_slice_aligned(slice: &[$t]) -> Result<Self, crate::AlignmentError> {
+                crate::AlignmentError::check::<Self, $t>(slice.as_ptr())?;
+                Ok(Self::from_slice_unaligned(slice))
+            }
+
+            /// Writes the elements of `self` to the first 16 elements in `slice` in
+            /// column major order. `slice` must start at a multiple of the alignment
+            /// of `Self`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an `AlignmentError` if `slice` is not aligned, in which case
+            /// `slice` is left unchanged.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than 16 elements long.
+            #[inline]
+            pub fn write_to_slice_aligned(
+                &self,
+                slice: &mut [$t],
+            ) -> Result<(), crate::AlignmentError> {
+                crate::AlignmentError::check::<Self, $t>(slice.as_ptr())?;
+                self.write_to_slice_unaligned(slice);
+                Ok(())
+            }
+
+            /// Creates a new matrix from a 2D array stored in column major order.
+            /// If your data is in row major order you will need to `transpose` the resulting matrix.
+            #[inline]
+            pub fn from_cols_array_2d(m: &[[$t; 4]; 4]) -> Self {
+                $mat4 {
+                    x_axis: m[0].into(),
+                    y_axis: m[1].into(),
+                    z_axis: m[2].into(),
+                    w_axis: m[3].into(),
+                }
+            }
+
+            /// Creates a new 2D array storing data in column major order.
+            /// If you require data in row major order `transpose` the matrix first.
+            #[inline]
+            pub fn to_cols_array_2d(&self) -> [[$t; 4]; 4] {
+                [
+                    self.x_axis.into(),
+                    self.y_axis.into(),
+                    self.z_axis.into(),
+                    self.w_axis.into(),
+                ]
+            }
+
+            /// Creates a 4x4 matrix with `m` as its upper left 3x3 part and the
+            /// remaining elements taken from the identity, giving an affine transform
+            /// without translation.
+            #[inline]
+            pub fn from_mat3(m: &$mat3) -> Self {
+                Self {
+                    x_axis: m.x_axis.extend(0.0),
+                    y_axis: m.y_axis.extend(0.0),
+                    z_axis: m.z_axis.extend(0.0),
+                    w_axis: $vec4::unit_w(),
+                }
+            }
+
+            #[inline]
+            pub fn from_translation(translation: $vec3) -> Self {
+                Self {
+                    x_axis: $vec4::unit_x(),
+                    y_axis: $vec4::unit_y(),
+                    z_axis: $vec4::unit_z(),
+                    w_axis: translation.extend(1.0),
+                }
+            }
+
+            /// Creates a new matrix containing a rotation around a normalized rotation axis of
+            /// angle (in radians).
+            #[inline]
+            pub fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+                glam_assert!(axis.is_normalized());
+                let (sin, cos) = scalar_sin_cos(angle);
+                let (x, y, z) = axis.into();
+                let (xsin, ysin, zsin) = (axis * sin).into();
+                let (x2, y2, z2) = (axis * axis).into();
+                let omc = 1.0 - cos;
+                let xyomc = x * y * omc;
+                let xzomc = x * z * omc;
+                let yzomc = y * z * omc;
+                Self {
+                    x_axis: $vec4::new(x2 * omc + cos, xyomc + zsin, xzomc - ysin, 0.0),
+                    y_axis: $vec4::new(xyomc - zsin, y2 * omc + cos, yzomc + xsin, 0.0),
+                    z_axis: $vec4::new(xzomc + ysin, yzomc - xsin, z2 * omc + cos, 0.0),
+                    w_axis: $vec4::unit_w(),
+                }
+            }
+
+            /// Creates a new matrix containing a rotation around the x axis of angle
+            /// (in radians).
+            #[inline]
+            pub fn from_rotation_x(angle: $t) -> Self {
+                let (sina, cosa) = scalar_sin_cos(angle);
+                Self {
+                    x_axis: $vec4::unit_x(),
+                    y_axis: $vec4::new(0.0, cosa, sina, 0.0),
+                    z_axis: $vec4::new(0.0, -sina, cosa, 0.0),
+                    w_axis: $vec4::unit_w(),
+                }
+            }
+
+            /// Creates a new matrix containing a rotation around the y axis of angle
+            /// (in radians).
+            #[inline]
+            pub fn from_rotation_y(angle: $t) -> Self {
+                let (sina, cosa) = scalar_sin_cos(angle);
+                Self {
+                    x_axis: $vec4::new(cosa, 0.0, -sina, 0.0),
+                    y_axis: $vec4::unit_y(),
+                    z_axis: $vec4::new(sina, 0.0, cosa, 0.0),
+                    w_axis: $vec4::unit_w(),
+                }
+            }
+
+            /// Creates a new matrix containing a rotation around the z axis of angle
+            /// (in radians).
+            #[inline]
+            pub fn from_rotation_z(angle: $t) -> Self {
+                let (sina, cosa) = scalar_sin_cos(angle);
+                Self {
+                    x_axis: $vec4::new(cosa, sina, 0.0, 0.0),
+                    y_axis: $vec4::new(-sina, cosa, 0.0, 0.0),
+                    z_axis: $vec4::unit_z(),
+                    w_axis: $vec4::unit_w(),
+                }
+            }
+
+            #[inline]
+            pub fn from_scale(scale: $vec3) -> Self {
+                glam_assert!(scale.cmpne($vec3::zero()).any()); // Do not panic as long as any component is non-zero
+                let (x, y, z) = scale.into();
+                Self {
+                    x_axis: $vec4::new(x, 0.0, 0.0, 0.0),
+                    y_axis: $vec4::new(0.0, y, 0.0, 0.0),
+                    z_axis: $vec4::new(0.0, 0.0, z, 0.0),
+                    w_axis: $vec4::unit_w(),
+                }
+            }
+
+            /// Creates a 4x4 matrix with `diagonal` on its diagonal and all other
+            /// elements set to `0.0`.
+            #[inline]
+            pub fn from_diagonal(diagonal: $vec4) -> Self {
+                let (x, y, z, w) = diagonal.into();
+                Self {
+                    x_axis: $vec4::new(x, 0.0, 0.0, 0.0),
+                    y_axis: $vec4::new(0.0, y, 0.0, 0.0),
+                    z_axis: $vec4::new(0.0, 0.0, z, 0.0),
+                    w_axis: $vec4::new(0.0, 0.0, 0.0, w),
+                }
+            }
+
+            /// Returns `self` with the axes of its upper 3x3 part made orthonormal with
+            /// the Gram-Schmidt process, as `orthonormalize` on 3x3 matrices does. The
+            /// translation and the last row are unchanged.
+            #[inline]
+            pub fn orthonormalize(&self) -> Self {
+                let m = $mat3::from_mat4(self).orthonormalize();
+                Self {
+                    x_axis: m.x_axis.extend(self.x_axis.w()),
+                    y_axis: m.y_axis.extend(self.y_axis.w()),
+                    z_axis: m.z_axis.extend(self.z_axis.w()),
+                    w_axis: self.w_axis,
+                }
+            }
+
+            /// Returns the diagonal of `self`.
+            #[inline]
+            pub fn diagonal(&self) -> $vec4 {
+                $vec4::new(
+                    self.x_axis.x(),
+                    self.y_axis.y(),
+                    self.z_axis.z(),
+                    self.w_axis.w(),
+                )
+            }
+
+            /// Returns the trace of `self`, the sum of its diagonal elements.
+            #[inline]
+            pub fn trace(&self) -> $t {
+                self.x_axis.x() + self.y_axis.y() + self.z_axis.z() + self.w_axis.w()
+            }
+
+            #[inline]
+            pub fn set_x_axis(&mut self, x: $vec4) {
+                self.x_axis = x;
+            }
+
+            #[inline]
+            pub fn set_y_axis(&mut self, y: $vec4) {
+                self.y_axis = y;
+            }
+
+            #[inline]
+            pub fn set_z_axis(&mut self, z: $vec4) {
+                self.z_axis = z;
+            }
+
+            #[inline]
+            pub fn set_w_axis(&mut self, w: $vec4) {
+                self.w_axis = w;
+            }
+
+            #[inline]
+            pub fn x_axis(&self) -> $vec4 {
+                self.x_axis
+            }
+
+            #[inline]
+            pub fn y_axis(&self) -> $vec4 {
+                self.y_axis
+            }
+
+            #[inline]
+            pub fn z_axis(&self) -> $vec4 {
+                self.z_axis
+            }
+
+            #[inline]
+            pub fn w_axis(&self) -> $vec4 {
+                self.w_axis
+            }
+
+            /// Returns the column at `index`, where `0` is the `x_axis`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 3.
+            #[inline]
+            pub fn col(&self, index: usize) -> $vec4 {
+                match index {
+                    0 => self.x_axis,
+                    1 => self.y_axis,
+                    2 => self.z_axis,
+                    3 => self.w_axis,
+                    _ => panic!("column index {} out of bounds", index),
+                }
+            }
+
+            /// Sets the column at `index`, where `0` is the `x_axis`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 3.
+            #[inline]
+            pub fn set_col(&mut self, index: usize, col: $vec4) {
+                match index {
+                    0 => self.x_axis = col,
+                    1 => self.y_axis = col,
+                    2 => self.z_axis = col,
+                    3 => self.w_axis = col,
+                    _ => panic!("column index {} out of bounds", index),
+                }
+            }
+
+            /// Returns the row at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 3.
+            #[inline]
+            pub fn row(&self, index: usize) -> $vec4 {
+                assert!(index < 4, "row index {} out of bounds", index);
+                $vec4::new(
+                    self.x_axis.as_ref()[index],
+                    self.y_axis.as_ref()[index],
+                    self.z_axis.as_ref()[index],
+                    self.w_axis.as_ref()[index],
+                )
+            }
+
+            /// Sets the row at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 3.
+            #[inline]
+            pub fn set_row(&mut self, index: usize, row: $vec4) {
+                assert!(index < 4, "row index {} out of bounds", index);
+                let (x, y, z, w) = row.into();
+                self.x_axis.as_mut()[index] = x;
+                self.y_axis.as_mut()[index] = y;
+                self.z_axis.as_mut()[index] = z;
+                self.w_axis.as_mut()[index] = w;
+            }
+
+            #[inline]
+            pub fn determinant(&self) -> $t {
+                let (m00, m01, m02, m03) = self.x_axis.into();
+                let (m10, m11, m12, m13) = self.y_axis.into();
+                let (m20, m21, m22, m23) = self.z_axis.into();
+                let (m30, m31, m32, m33) = self.w_axis.into();
+
+                let a2323 = m22 * m33 - m23 * m32;
+                let a1323 = m21 * m33 - m23 * m31;
+                let a1223 = m21 * m32 - m22 * m31;
+                let a0323 = m20 * m33 - m23 * m30;
+                let a0223 = m20 * m32 - m22 * m30;
+                let a0123 = m20 * m31 - m21 * m30;
+
+                m00 * (m11 * a2323 - m12 * a1323 + m13 * a1223)
+                    - m01 * (m10 * a2323 - m12 * a0323 + m13 * a0223)
+                    + m02 * (m10 * a1323 - m11 * a0323 + m13 * a0123)
+                    - m03 * (m10 * a1223 - m11 * a0223 + m12 * a0123)
+            }
+
+            /// Returns `true` if the last row of `self` is `(0, 0, 0, 1)`, meaning it
+            /// is an affine transform with no projection.
+            #[inline]
+            pub fn is_affine(&self) -> bool {
+                self.x_axis.w() == 0.0
+                    && self.y_axis.w() == 0.0
+                    && self.z_axis.w() == 0.0
+                    && self.w_axis.w() == 1.0
+            }
+
+            /// Returns the inverse of the affine transform `self`.
+            ///
+            /// This is cheaper than `inverse` as only the upper 3x3 part needs to be
+            /// inverted, the inverse translation is then the negated translation
+            /// transformed by it. `self` must be affine, see `is_affine`, and its
+            /// upper 3x3 part must be invertible.
+            #[inline]
+            pub fn inverse_affine(&self) -> Self {
+                glam_assert!(self.is_affine());
+                let x_axis = self.x_axis.truncate();
+                let y_axis = self.y_axis.truncate();
+                let z_axis = self.z_axis.truncate();
+                let translation = self.w_axis.truncate();
+
+                // the rows of the inverse are the cross products of the axes
+                let row0 = y_axis.cross(z_axis);
+                let row1 = z_axis.cross(x_axis);
+                let row2 = x_axis.cross(y_axis);
+                let det = x_axis.dot(row0);
+                glam_assert!(det != 0.0);
+                let inv_det = 1.0 / det;
+                let row0 = row0 * inv_det;
+                let row1 = row1 * inv_det;
+                let row2 = row2 * inv_det;
+
+                Self::from_cols(
+                    $vec4::new(row0.x(), row1.x(), row2.x(), 0.0),
+                    $vec4::new(row0.y(), row1.y(), row2.y(), 0.0),
+                    $vec4::new(row0.z(), row1.z(), row2.z(), 0.0),
+                    $vec4::new(
+                        -row0.dot(translation),
+                        -row1.dot(translation),
+                        -row2.dot(translation),
+                        1.0,
+                    ),
+                )
+            }
+
+            /// Returns the matrix for transforming normals by `self`, the inverse
+            /// transpose of its upper 3x3 part.
+            ///
+            /// Unlike the upper 3x3 part itself this keeps normals perpendicular to
+            /// surfaces under non-uniform scale. Transformed normals are not of unit
+            /// length in general and need to be normalized.
+            #[inline]
+            pub fn normal_matrix(&self) -> $mat3 {
+                let x_axis = self.x_axis.truncate();
+                let y_axis = self.y_axis.truncate();
+                let z_axis = self.z_axis.truncate();
+
+                // the columns of the inverse transpose are the cross products of the
+                // axes
+                let col0 = y_axis.cross(z_axis);
+                let col1 = z_axis.cross(x_axis);
+                let col2 = x_axis.cross(y_axis);
+                let det = x_axis.dot(col0);
+                glam_assert!(det != 0.0);
+                let inv_det = 1.0 / det;
+                $mat3::from_cols(
+                    (col0 * inv_det).into(),
+                    (col1 * inv_det).into(),
+                    (col2 * inv_det).into(),
+                )
+            }
+
+            /// Returns the inverse of the rigid transform `self`, a rotation followed
+            /// by a translation.
+            ///
+            /// This is cheaper than `inverse_affine` as the inverse of the rotation is
+            /// its transpose. `self` must be affine and its upper 3x3 part must be a
+            /// pure rotation without any scale or shear, otherwise the result is
+            /// wrong.
+            #[inline]
+            pub fn inverse_rigid(&self) -> Self {
+                glam_assert!(self.is_affine());
+                let x_axis = self.x_axis.truncate();
+                let y_axis = self.y_axis.truncate();
+                let z_axis = self.z_axis.truncate();
+                glam_assert!(x_axis.is_normalized());
+                glam_assert!(y_axis.is_normalized());
+                glam_assert!(z_axis.is_normalized());
+                let translation = self.w_axis.truncate();
+
+                Self::from_cols(
+                    $vec4::new(x_axis.x(), y_axis.x(), z_axis.x(), 0.0),
+                    $vec4::new(x_axis.y(), y_axis.y(), z_axis.y(), 0.0),
+                    $vec4::new(x_axis.z(), y_axis.z(), z_axis.z(), 0.0),
+                    $vec4::new(
+                        -x_axis.dot(translation),
+                        -y_axis.dot(translation),
+                        -z_axis.dot(translation),
+                        1.0,
+                    ),
+                )
+            }
+
+            /// Creates a left-handed view matrix using a camera position, a facing
+            /// direction and an up direction.
+            ///
+            /// For a left-handed view coordinate system with `+X=right`, `+Y=up` and
+            /// `+Z=forward`. `dir` does not need to be normalized, `up` does.
+            #[inline]
+            pub fn look_to_lh(eye: $vec3, dir: $vec3, up: $vec3) -> Self {
+                glam_assert!(up.is_normalized());
+                let f = dir.normalize();
+                let s = up.cross(f).normalize();
+                let u = f.cross(s);
+                let (fx, fy, fz) = f.into();
+                let (sx, sy, sz) = s.into();
+                let (ux, uy, uz) = u.into();
+                $mat4::from_cols(
+                    $vec4::new(sx, ux, fx, 0.0),
+                    $vec4::new(sy, uy, fy, 0.0),
+                    $vec4::new(sz, uz, fz, 0.0),
+                    $vec4::new(-s.dot(eye), -u.dot(eye), -f.dot(eye), 1.0),
+                )
+            }
+
+            /// Creates a right-handed view matrix using a camera position, a facing
+            /// direction and an up direction.
+            ///
+            /// For a right-handed view coordinate system with `+X=right`, `+Y=up` and
+            /// `+Z=back`. `dir` does not need to be normalized, `up` does.
+            #[inline]
+            pub fn look_to_rh(eye: $vec3, dir: $vec3, up: $vec3) -> Self {
+                $mat4::look_to_lh(eye, -dir, up)
+            }
+
+            /// Creates a left-handed view matrix using a camera position, a focal
+            /// point and an up direction.
+            ///
+            /// For a left-handed view coordinate system with `+X=right`, `+Y=up` and
+            /// `+Z=forward`.
+            #[inline]
+            pub fn look_at_lh(eye: $vec3, center: $vec3, up: $vec3) -> Self {
+                $mat4::look_to_lh(eye, center - eye, up)
+            }
+
+            /// Creates a right-handed view matrix using a camera position, a focal
+            /// point and an up direction.
+            ///
+            /// For a right-handed view coordinate system with `+X=right`, `+Y=up` and
+            /// `+Z=back`.
+            #[inline]
+            pub fn look_at_rh(eye: $vec3, center: $vec3, up: $vec3) -> Self {
+                $mat4::look_to_rh(eye, center - eye, up)
+            }
+
+            #[inline]
+            /// Builds a right-handed perspective projection matrix with [-1,1] depth range.
+            /// This is the equivalent of the common perspective function `gluPerspective` in OpenGL.
+            /// See https://www.khronos.org/opengl/wiki/GluPerspective_code
+            pub fn perspective_glu_rh(
+                fov_y_radians: $t,
+                aspect_ratio: $t,
+                z_near: $t,
+                z_far: $t,
+            ) -> $mat4 {
+                let inv_length = 1.0 / (z_near - z_far);
+                let f = 1.0 / (0.5 * fov_y_radians).tan();
+                let a = f / aspect_ratio;
+                let b = (z_near + z_far) * inv_length;
+                let c = (2.0 * z_near * z_far) * inv_length;
+                $mat4::from_cols(
+                    $vec4::new(a, 0.0, 0.0, 0.0),
+                    $vec4::new(0.0, f, 0.0, 0.0),
+                    $vec4::new(0.0, 0.0, b, -1.0),
+                    $vec4::new(0.0, 0.0, c, 0.0),
+                )
+            }
+
+            /// Build infinite right-handed perspective projection matrix with [0,1] depth range.
+            pub fn perspective_infinite_rh(
+                fov_y_radians: $t,
+                aspect_ratio: $t,
+                z_near: $t,
+            ) -> $mat4 {
+                let f = 1.0 / (0.5 * fov_y_radians).tan();
+                $mat4::from_cols(
+                    $vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+                    $vec4::new(0.0, f, 0.0, 0.0),
+                    $vec4::new(0.0, 0.0, -1.0, -1.0),
+                    $vec4::new(0.0, 0.0, -z_near, 0.0),
+                )
+            }
+
+            /// Build infinite reverse right-handed perspective projection matrix with [0,1] depth range.
+            pub fn perspective_infinite_reverse_rh(
+                fov_y_radians: $t,
+                aspect_ratio: $t,
+                z_near: $t,
+            ) -> $mat4 {
+                let f = 1.0 / (0.5 * fov_y_radians).tan();
+                $mat4::from_cols(
+                    $vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+                    $vec4::new(0.0, f, 0.0, 0.0),
+                    $vec4::new(0.0, 0.0, 0.0, -1.0),
+                    $vec4::new(0.0, 0.0, z_near, 0.0),
+                )
+            }
+
+            #[inline]
+            pub fn mul_vec4(&self, other: $vec4) -> $vec4 {
+                let mut res = self.x_axis * other.dup_x();
+                res = self.y_axis.mul_add(other.dup_y(), res);
+                res = self.z_axis.mul_add(other.dup_z(), res);
+                res = self.w_axis.mul_add(other.dup_w(), res);
+                res
+            }
+
+            #[inline]
+            pub fn add_mat4(&self, other: &Self) -> Self {
+                Self {
+                    x_axis: self.x_axis + other.x_axis,
+                    y_axis: self.y_axis + other.y_axis,
+                    z_axis: self.z_axis + other.z_axis,
+                    w_axis: self.w_axis + other.w_axis,
+                }
+            }
+
+            #[inline]
+            pub fn sub_mat4(&self, other: &Self) -> Self {
+                Self {
+                    x_axis: self.x_axis - other.x_axis,
+                    y_axis: self.y_axis - other.y_axis,
+                    z_axis: self.z_axis - other.z_axis,
+                    w_axis: self.w_axis - other.w_axis,
+                }
+            }
+
+            #[inline]
+            pub fn mul_scalar(&self, other: $t) -> Self {
+                let s = $vec4::splat(other);
+                Self {
+                    x_axis: self.x_axis * s,
+                    y_axis: self.y_axis * s,
+                    z_axis: self.z_axis * s,
+                    w_axis: self.w_axis * s,
+                }
+            }
+
+            /// Transforms the given 3D point, treating it as a 4D vector with a `w` of
+            /// `1.0` so the translation of `self` is applied.
+            ///
+            /// This assumes `self` is an affine transform and ignores the last row of
+            /// `self`. Use `project_point3` for perspective projections.
+            #[inline]
+            pub fn transform_point3(&self, other: $vec3) -> $vec3 {
+                let mut res = self.x_axis.truncate() * other.dup_x();
+                res = self.y_axis.truncate().mul_add(other.dup_y(), res);
+                res = self.z_axis.truncate().mul_add(other.dup_z(), res);
+                // other w = 1
+                res = self.w_axis.truncate() + res;
+                res
+            }
+
+            /// Transforms the given 3D vector, treating it as a 4D vector with a `w` of
+            /// `0.0` so the translation of `self` is ignored.
+            ///
+            /// This assumes `self` is an affine transform and ignores the last row of
+            /// `self`.
+            #[inline]
+            pub fn transform_vector3(&self, other: $vec3) -> $vec3 {
+                let mut res = self.x_axis.truncate() * other.dup_x();
+                res = self.y_axis.truncate().mul_add(other.dup_y(), res);
+                res = self.z_axis.truncate().mul_add(other.dup_z(), res);
+                // other w = 0
+                res
+            }
+
+            /// Transforms the given 3D point with a perspective divide, treating it as
+            /// a 4D vector with a `w` of `1.0` and dividing the result by its `w`.
+            ///
+            /// This is intended for projection matrices such as those built by
+            /// `perspective_glu_rh`, mapping a point in view space to normalized device
+            /// coordinates.
+            #[inline]
+            pub fn project_point3(&self, other: $vec3) -> $vec3 {
+                let res = self.mul_vec4(other.extend(1.0));
+                res.truncate() / res.w()
+            }
+
+            /// Transforms each point in `points` as `transform_point3` does, writing
+            /// the results to `out`.
+            ///
+            /// The matrix columns are multiplied as 4D vectors so the work for each
+            /// point stays in SIMD registers where available.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `points` and `out` have different lengths.
+            #[inline]
+            pub fn transform_point3_slice(&self, points: &[$vec3], out: &mut [$vec3]) {
+                assert_eq!(points.len(), out.len());
+                for (point, out) in points.iter().zip(out.iter_mut()) {
+                    *out = self.transform_point3_as_vec4(*point).truncate();
+                }
+            }
+
+            /// Transforms each point in `points` in place as `transform_point3` does.
+            #[inline]
+            pub fn transform_point3_slice_in_place(&self, points: &mut [$vec3]) {
+                for point in points.iter_mut() {
+                    *point = self.transform_point3_as_vec4(*point).truncate();
+                }
+            }
+
+            /// Transforms each vector in `vectors` as `transform_vector3` does,
+            /// writing the results to `out`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `vectors` and `out` have different lengths.
+            #[inline]
+            pub fn transform_vector3_slice(&self, vectors: &[$vec3], out: &mut [$vec3]) {
+                assert_eq!(vectors.len(), out.len());
+                for (vector, out) in vectors.iter().zip(out.iter_mut()) {
+                    *out = self.transform_vector3_as_vec4(*vector).truncate();
+                }
+            }
+
+            /// Transforms each vector in `vectors` in place as `transform_vector3`
+            /// does.
+            #[inline]
+            pub fn transform_vector3_slice_in_place(&self, vectors: &mut [$vec3]) {
+                for vector in vectors.iter_mut() {
+                    *vector = self.transform_vector3_as_vec4(*vector).truncate();
+                }
+            }
+
+            /// Transforms each point in `points` as `transform_point3_slice` does,
+            /// splitting the work across the rayon thread pool.
+            ///
+            /// This is only worth it for large slices, smaller batches of points are
+            /// left on a single thread.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `points` and `out` have different lengths.
+            #[cfg(feature = "rayon")]
+            pub fn par_transform_point3_slice(&self, points: &[$vec3], out: &mut [$vec3]) {
+                use rayon::prelude::*;
+                assert_eq!(points.len(), out.len());
+                points
+                    .par_iter()
+                    .zip(out.par_iter_mut())
+                    .with_min_len(crate::mat4_macros::PAR_MIN_LEN)
+                    .for_each(|(point, out)| {
+                        *out = self.transform_point3_as_vec4(*point).truncate();
+                    });
+            }
+
+            /// Transforms each point in `points` in place as
+            /// `transform_point3_slice_in_place` does, splitting the work across the
+            /// rayon thread pool.
+            #[cfg(feature = "rayon")]
+            pub fn par_transform_point3_slice_in_place(&self, points: &mut [$vec3]) {
+                use rayon::prelude::*;
+                points
+                    .par_iter_mut()
+                    .with_min_len(crate::mat4_macros::PAR_MIN_LEN)
+                    .for_each(|point| {
+                        *point = self.transform_point3_as_vec4(*point).truncate();
+                    });
+            }
+
+            /// Transforms each vector in `vectors` as `transform_vector3_slice` does,
+            /// splitting the work across the rayon thread pool.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `vectors` and `out` have different lengths.
+            #[cfg(feature = "rayon")]
+            pub fn par_transform_vector3_slice(&self, vectors: &[$vec3], out: &mut [$vec3]) {
+                use rayon::prelude::*;
+                assert_eq!(vectors.len(), out.len());
+                vectors
+                    .par_iter()
+                    .zip(out.par_iter_mut())
+                    .with_min_len(crate::mat4_macros::PAR_MIN_LEN)
+                    .for_each(|(vector, out)| {
+                        *out = self.transform_vector3_as_vec4(*vector).truncate();
+                    });
+            }
+
+            /// Transforms each vector in `vectors` in place as
+            /// `transform_vector3_slice_in_place` does, splitting the work across the
+            /// rayon thread pool.
+            #[cfg(feature = "rayon")]
+            pub fn par_transform_vector3_slice_in_place(&self, vectors: &mut [$vec3]) {
+                use rayon::prelude::*;
+                vectors
+                    .par_iter_mut()
+                    .with_min_len(crate::mat4_macros::PAR_MIN_LEN)
+                    .for_each(|vector| {
+                        *vector = self.transform_vector3_as_vec4(*vector).truncate();
+                    });
+            }
+
+            #[inline]
+            fn transform_point3_as_vec4(&self, other: $vec3) -> $vec4 {
+                let mut res = self.x_axis * $vec4::splat(other.x());
+                res = self.y_axis.mul_add($vec4::splat(other.y()), res);
+                res = self.z_axis.mul_add($vec4::splat(other.z()), res);
+                // other w = 1
+                self.w_axis + res
+            }
+
+            #[inline]
+            fn transform_vector3_as_vec4(&self, other: $vec3) -> $vec4 {
+                let mut res = self.x_axis * $vec4::splat(other.x());
+                res = self.y_axis.mul_add($vec4::splat(other.y()), res);
+                // other w = 0
+                self.z_axis.mul_add($vec4::splat(other.z()), res)
+            }
+
+            /// Returns `true` if all elements of `self` are finite, that is neither
+            /// infinite nor NaN.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                self.as_ref().iter().all(|e| e.is_finite())
+            }
+
+            /// Returns `true` if any element of `self` is NaN.
+            #[inline]
+            pub fn is_nan(&self) -> bool {
+                self.as_ref().iter().any(|e| e.is_nan())
+            }
+
+            /// Returns true if the absolute difference of all elements between `self`
+            /// and `other` is less than or equal to `max_abs_diff`.
+            ///
+            /// This can be used to compare if two matrices contain similar elements. It
+            /// works best when comparing with a known value. The `max_abs_diff` that
+            /// should be used used depends on the values being compared against.
+            ///
+            /// For more on floating point comparisons see
+            /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+            #[inline]
+            pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+                self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+                    && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+                    && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+                    && self.w_axis.abs_diff_eq(other.w_axis, max_abs_diff)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or within `max_relative` times the larger
+            /// magnitude of the two elements.
+            #[inline]
+            pub fn relative_eq(&self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                self.x_axis.relative_eq(other.x_axis, max_abs_diff, max_relative)
+                    && self.y_axis.relative_eq(other.y_axis, max_abs_diff, max_relative)
+                    && self.z_axis.relative_eq(other.z_axis, max_abs_diff, max_relative)
+                    && self.w_axis.relative_eq(other.w_axis, max_abs_diff, max_relative)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or at most `max_ulps` representable
+            /// floating point values apart.
+            #[inline]
+            pub fn ulps_eq(&self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                self.x_axis.ulps_eq(other.x_axis, max_abs_diff, max_ulps)
+                    && self.y_axis.ulps_eq(other.y_axis, max_abs_diff, max_ulps)
+                    && self.z_axis.ulps_eq(other.z_axis, max_abs_diff, max_ulps)
+                    && self.w_axis.ulps_eq(other.w_axis, max_abs_diff, max_ulps)
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl rand::distributions::Distribution<$mat4> for rand::distributions::Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $mat4 {
+                $mat4::from_cols_array(&rng.gen())
+            }
+        }
+
+        impl AsRef<[$t; 16]> for $mat4 {
+            #[inline]
+            fn as_ref(&self) -> &[$t; 16] {
+                unsafe { &*(self as *const Self as *const [$t; 16]) }
+            }
+        }
+
+        impl AsMut<[$t; 16]> for $mat4 {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [$t; 16] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; 16]) }
+            }
+        }
+
+        impl std::ops::Add<$mat4> for $mat4 {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                self.add_mat4(&other)
+            }
+        }
+
+        impl std::ops::AddAssign<$mat4> for $mat4 {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = self.add_mat4(&other);
+            }
+        }
+
+        impl std::ops::Sub<$mat4> for $mat4 {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                self.sub_mat4(&other)
+            }
+        }
+
+        impl std::ops::SubAssign<$mat4> for $mat4 {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = self.sub_mat4(&other);
+            }
+        }
+
+        impl std::ops::Mul<$mat4> for $mat4 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                self.mul_mat4(&other)
+            }
+        }
+
+        impl std::ops::MulAssign<$mat4> for $mat4 {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = self.mul_mat4(&other);
+            }
+        }
+
+        impl std::ops::Mul<$vec4> for $mat4 {
+            type Output = $vec4;
+            #[inline]
+            fn mul(self, other: $vec4) -> $vec4 {
+                self.mul_vec4(other)
+            }
+        }
+
+        impl std::ops::Mul<$mat4> for $t {
+            type Output = $mat4;
+            #[inline]
+            fn mul(self, other: $mat4) -> $mat4 {
+                other.mul_scalar(self)
+            }
+        }
+
+        impl std::ops::Mul<$t> for $mat4 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: $t) -> Self {
+                self.mul_scalar(other)
+            }
+        }
+
+        impl std::ops::MulAssign<$t> for $mat4 {
+            #[inline]
+            fn mul_assign(&mut self, other: $t) {
+                *self = self.mul_scalar(other);
+            }
+        }
+
+        impl std::ops::Div<$t> for $mat4 {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: $t) -> Self {
+                Self {
+                    x_axis: self.x_axis / other,
+                    y_axis: self.y_axis / other,
+                    z_axis: self.z_axis / other,
+                    w_axis: self.w_axis / other,
+                }
+            }
+        }
+
+        impl std::ops::DivAssign<$t> for $mat4 {
+            #[inline]
+            fn div_assign(&mut self, other: $t) {
+                *self = *self / other;
+            }
+        }
+
+        impl std::ops::Neg for $mat4 {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self {
+                    x_axis: -self.x_axis,
+                    y_axis: -self.y_axis,
+                    z_axis: -self.z_axis,
+                    w_axis: -self.w_axis,
+                }
+            }
+        }
+
+        /// Indexes the elements of the matrix by `(column, row)`, following the
+        /// column major convention of glam. For example `m[(1, 0)]` is the `x`
+        /// element of the `y_axis` column.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the column or the row is out of bounds.
+        impl std::ops::Index<(usize, usize)> for $mat4 {
+            type Output = $t;
+            #[inline]
+            fn index(&self, (col, row): (usize, usize)) -> &$t {
+                let col = match col {
+                    0 => &self.x_axis,
+                    1 => &self.y_axis,
+                    2 => &self.z_axis,
+                    3 => &self.w_axis,
+                    _ => panic!("column index {} out of bounds", col),
+                };
+                &col.as_ref()[row]
+            }
+        }
+
+        /// Mutably indexes the elements of the matrix by `(column, row)`, see the
+        /// `Index` implementation.
+        impl std::ops::IndexMut<(usize, usize)> for $mat4 {
+            #[inline]
+            fn index_mut(&mut self, (col, row): (usize, usize)) -> &mut $t {
+                let col = match col {
+                    0 => &mut self.x_axis,
+                    1 => &mut self.y_axis,
+                    2 => &mut self.z_axis,
+                    3 => &mut self.w_axis,
+                    _ => panic!("column index {} out of bounds", col),
+                };
+                &mut col.as_mut()[row]
+            }
+        }
+
+        impl_ref_binop!(Add, add, $mat4, $mat4, $mat4);
+        impl_ref_binop!(Sub, sub, $mat4, $mat4, $mat4);
+        impl_ref_binop!(Mul, mul, $mat4, $mat4, $mat4);
+        impl_ref_binop!(Mul, mul, $mat4, $vec4, $vec4);
+        impl_ref_binop!(Mul, mul, $mat4, $t, $mat4);
+
+        impl_sum!($mat4, $mat4::zero());
+        impl_product!($mat4, $mat4::identity());
+        impl_slice_methods!($mat4, $t, 16);
+    };
+}
+
+macro_rules! impl_mat4_quat {
+    ($mat4:ident, $t:ty, $quat:ident, $vec3:ident, $vec4:ident) => {
+        #[inline]
+        fn quat_to_axes(rotation: $quat) -> ($vec4, $vec4, $vec4) {
+            glam_assert!(rotation.is_normalized());
+            let (x, y, z, w) = rotation.into();
+            let x2 = x + x;
+            let y2 = y + y;
+            let z2 = z + z;
+            let xx = x * x2;
+            let xy = x * y2;
+            let xz = x * z2;
+            let yy = y * y2;
+            let yz = y * z2;
+            let zz = z * z2;
+            let wx = w * x2;
+            let wy = w * y2;
+            let wz = w * z2;
+
+            let x_axis = $vec4::new(1.0 - (yy + zz), xy + wz, xz - wy, 0.0);
+            let y_axis = $vec4::new(xy - wz, 1.0 - (xx + zz), yz + wx, 0.0);
+            let z_axis = $vec4::new(xz + wy, yz - wx, 1.0 - (xx + yy), 0.0);
+            (x_axis, y_axis, z_axis)
+        }
+
+        impl $mat4 {
+            #[inline]
+            pub fn from_scale_rotation_translation(
+                scale: $vec3,
+                rotation: $quat,
+                translation: $vec3,
+            ) -> Self {
+                glam_assert!(rotation.is_normalized());
+                let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+                let (scale_x, scale_y, scale_z) = scale.into();
+                Self {
+                    x_axis: x_axis * scale_x,
+                    y_axis: y_axis * scale_y,
+                    z_axis: z_axis * scale_z,
+                    w_axis: translation.extend(1.0),
+                }
+            }
+
+            /// Decomposes `self` into its scale, rotation and translation, such that
+            /// `from_scale_rotation_translation` of the result gives back `self`.
+            ///
+            /// `self` must be an affine transform without shear. A negative
+            /// determinant means `self` mirrors, which a rotation cannot express, so
+            /// the mirroring is returned as a negative `x` scale.
+            #[inline]
+            pub fn to_scale_rotation_translation(&self) -> ($vec3, $quat, $vec3) {
+                let det = self.determinant();
+                glam_assert!(det != 0.0);
+                let scale = $vec3::new(
+                    self.x_axis.truncate().length() * det.signum(),
+                    self.y_axis.truncate().length(),
+                    self.z_axis.truncate().length(),
+                );
+                let inv_scale = scale.reciprocal();
+                let rotation = $quat::from_mat4(&Self {
+                    x_axis: self.x_axis * inv_scale.x(),
+                    y_axis: self.y_axis * inv_scale.y(),
+                    z_axis: self.z_axis * inv_scale.z(),
+                    w_axis: $vec4::unit_w(),
+                });
+                (scale, rotation, self.w_axis.truncate())
+            }
+
+            #[inline]
+            pub fn from_rotation_translation(rotation: $quat, translation: $vec3) -> Self {
+                glam_assert!(rotation.is_normalized());
+                let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+                Self {
+                    x_axis,
+                    y_axis,
+                    z_axis,
+                    w_axis: translation.extend(1.0),
+                }
+            }
+
+            #[inline]
+            pub fn from_quat(rotation: $quat) -> Self {
+                glam_assert!(rotation.is_normalized());
+                let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+                Self {
+                    x_axis,
+                    y_axis,
+                    z_axis,
+                    w_axis: $vec4::unit_w(),
+                }
+            }
+
+            /// Creates a new matrix containing a rotation around the given euler
+            /// angles (in radians).
+            #[inline]
+            pub fn from_rotation_ypr(yaw: $t, pitch: $t, roll: $t) -> Self {
+                let quat = $quat::from_rotation_ypr(yaw, pitch, roll);
+                Self::from_quat(quat)
+            }
+
+            /// Creates a new matrix containing a rotation around the given Euler
+            /// angles (in radians), applied in the given `order`.
+            #[inline]
+            pub fn from_euler(order: crate::EulerRot, a: $t, b: $t, c: $t) -> Self {
+                Self::from_quat($quat::from_euler(order, a, b, c))
+            }
+        }
+    };
+}
+
+macro_rules! impl_mat4_scalar {
+    ($mat4:ident, $vec4:ident) => {
+        impl $mat4 {
+            #[inline]
+            pub fn inverse(&self) -> Self {
+                let (m00, m01, m02, m03) = self.x_axis.into();
+                let (m10, m11, m12, m13) = self.y_axis.into();
+                let (m20, m21, m22, m23) = self.z_axis.into();
+                let (m30, m31, m32, m33) = self.w_axis.into();
+
+                let coef00 = m22 * m33 - m32 * m23;
+                let coef02 = m12 * m33 - m32 * m13;
+                let coef03 = m12 * m23 - m22 * m13;
+
+                let coef04 = m21 * m33 - m31 * m23;
+                let coef06 = m11 * m33 - m31 * m13;
+                let coef07 = m11 * m23 - m21 * m13;
+
+                let coef08 = m21 * m32 - m31 * m22;
+                let coef10 = m11 * m32 - m31 * m12;
+                let coef11 = m11 * m22 - m21 * m12;
+
+                let coef12 = m20 * m33 - m30 * m23;
+                let coef14 = m10 * m33 - m30 * m13;
+                let coef15 = m10 * m23 - m20 * m13;
+
+                let coef16 = m20 * m32 - m30 * m22;
+                let coef18 = m10 * m32 - m30 * m12;
+                let coef19 = m10 * m22 - m20 * m12;
+
+                let coef20 = m20 * m31 - m30 * m21;
+                let coef22 = m10 * m31 - m30 * m11;
+                let coef23 = m10 * m21 - m20 * m11;
+
+                let fac0 = $vec4::new(coef00, coef00, coef02, coef03);
+                let fac1 = $vec4::new(coef04, coef04, coef06, coef07);
+                let fac2 = $vec4::new(coef08, coef08, coef10, coef11);
+                let fac3 = $vec4::new(coef12, coef12, coef14, coef15);
+                let fac4 = $vec4::new(coef16, coef16, coef18, coef19);
+                let fac5 = $vec4::new(coef20, coef20, coef22, coef23);
+
+                let vec0 = $vec4::new(m10, m00, m00, m00);
+                let vec1 = $vec4::new(m11, m01, m01, m01);
+                let vec2 = $vec4::new(m12, m02, m02, m02);
+                let vec3 = $vec4::new(m13, m03, m03, m03);
+
+                let inv0 = vec1 * fac0 - vec2 * fac1 + vec3 * fac2;
+                let inv1 = vec0 * fac0 - vec2 * fac3 + vec3 * fac4;
+                let inv2 = vec0 * fac1 - vec1 * fac3 + vec3 * fac5;
+                let inv3 = vec0 * fac2 - vec1 * fac4 + vec2 * fac5;
+
+                let sign_a = $vec4::new(1.0, -1.0, 1.0, -1.0);
+                let sign_b = $vec4::new(-1.0, 1.0, -1.0, 1.0);
+
+                let inverse = Self {
+                    x_axis: inv0 * sign_a,
+                    y_axis: inv1 * sign_b,
+                    z_axis: inv2 * sign_a,
+                    w_axis: inv3 * sign_b,
+                };
+
+                let col0 = $vec4::new(
+                    inverse.x_axis.x(),
+                    inverse.y_axis.x(),
+                    inverse.z_axis.x(),
+                    inverse.w_axis.x(),
+                );
+
+                let dot0 = self.x_axis * col0;
+                let dot1 = dot0.x() + dot0.y() + dot0.z() + dot0.w();
+
+                glam_assert!(dot1 != 0.0);
+
+                let rcp_det = 1.0 / dot1;
+                inverse * rcp_det
+            }
+        }
+    };
+}
+
+// Unused when `scalar-math` is enabled or there are no SIMD registers.
+#[allow(unused_macros)]
+macro_rules! impl_mat4_simd_cols {
+    ($mat4:ident, $simd:ty) => {
+        impl From<$mat4> for [$simd; 4] {
+            #[inline]
+            fn from(m: $mat4) -> Self {
+                [
+                    m.x_axis.into(),
+                    m.y_axis.into(),
+                    m.z_axis.into(),
+                    m.w_axis.into(),
+                ]
+            }
+        }
+
+        impl From<[$simd; 4]> for $mat4 {
+            #[inline]
+            fn from(cols: [$simd; 4]) -> Self {
+                Self::from_cols(
+                    cols[0].into(),
+                    cols[1].into(),
+                    cols[2].into(),
+                    cols[3].into(),
+                )
+            }
+        }
+    };
+}
@@ -0,0 +1,929 @@
+// Shared implementation of quaternion types.
+//
+// `impl_quat` implements a quaternion stored as a single 4D vector. The struct
+// itself must be declared by the caller so that it can choose its own
+// attributes and documentation. `scalar_sin_cos` and `scalar_acos` must be in
+// scope where the macro is invoked.
+//
+// `impl_quat_scalar` implements quaternion multiplication and `Debug` for
+// quaternion types that store their 4D vector as scalars.
+
+macro_rules! impl_quat {
+    ($quat:ident, $t:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mat3:ident, $mat4:ident) => {
+        impl $quat {
+            /// Creates a new rotation quaternion.
+            ///
+            /// This should generally not be called manually unless you know what you are doing. Use one of
+            /// the other constructors instead such as `identity` or `from_axis_angle`.
+            ///
+            /// `new` is mostly used by unit tests and `serde` deserialization.
+            #[inline]
+            pub fn new(x: $t, y: $t, z: $t, w: $t) -> Self {
+                Self($vec4::new(x, y, z, w))
+            }
+
+            #[inline]
+            pub fn identity() -> Self {
+                Self($vec4::new(0.0, 0.0, 0.0, 1.0))
+            }
+
+            /// Creates a new rotation quaternion from an unaligned slice.
+            ///
+            /// # Preconditions
+            ///
+            /// The resulting quaternion is expected to be of unit length.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` length is less than 4.
+            #[inline]
+            pub fn from_slice_unaligned(slice: &[$t]) -> Self {
+                let q = Self($vec4::from_slice_unaligned(slice));
+                glam_assert!(q.is_normalized());
+                q
+            }
+
+            /// Writes the quaternion to an unaligned mutable slice.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` length is less than 4.
+            #[inline]
+            pub fn write_to_slice_unaligned(self, slice: &mut [$t]) {
+                self.0.write_to_slice_unaligned(slice)
+            }
+
+            /// Create a new quaterion for a normalized rotation axis and angle
+            /// (in radians).
+            #[inline]
+            pub fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+                glam_assert!(axis.is_normalized());
+                let (s, c) = scalar_sin_cos(angle * 0.5);
+                Self((axis * s).extend(c))
+            }
+
+            /// Creates a new quaternion from a rotation vector, whose direction is the
+            /// rotation axis and whose length is the angle (in radians).
+            ///
+            /// This is the exponential map, useful for integrating angular velocities.
+            #[inline]
+            pub fn from_scaled_axis(v: $vec3) -> Self {
+                let length = v.length();
+                if length == 0.0 {
+                    Self::identity()
+                } else {
+                    Self::from_axis_angle(v / length, length)
+                }
+            }
+
+            /// Creates a new quaternion from the angle (in radians) around the x axis.
+            #[inline]
+            pub fn from_rotation_x(angle: $t) -> Self {
+                let (s, c) = scalar_sin_cos(angle * 0.5);
+                Self::new(s, 0.0, 0.0, c)
+            }
+
+            /// Creates a new quaternion from the angle (in radians) around the y axis.
+            #[inline]
+            pub fn from_rotation_y(angle: $t) -> Self {
+                let (s, c) = scalar_sin_cos(angle * 0.5);
+                Self::new(0.0, s, 0.0, c)
+            }
+
+            /// Creates a new quaternion from the angle (in radians) around the z axis.
+            #[inline]
+            pub fn from_rotation_z(angle: $t) -> Self {
+                let (s, c) = scalar_sin_cos(angle * 0.5);
+                Self::new(0.0, 0.0, s, c)
+            }
+
+            #[inline]
+            /// Create a quaternion from the given yaw (around y), pitch (around x) and roll (around z)
+            /// in radians.
+            pub fn from_rotation_ypr(yaw: $t, pitch: $t, roll: $t) -> Self {
+                // TODO: Optimize
+                Self::from_rotation_y(yaw)
+                    * Self::from_rotation_x(pitch)
+                    * Self::from_rotation_z(roll)
+            }
+
+            /// Creates a quaternion from the given Euler angles (in radians), applied
+            /// in the given `order`.
+            ///
+            /// `a`, `b` and `c` are the angles around the first, second and third axis
+            /// named by `order`.
+            #[inline]
+            pub fn from_euler(order: crate::EulerRot, a: $t, b: $t, c: $t) -> Self {
+                let rotation = |axis, angle| match axis {
+                    0 => Self::from_rotation_x(angle),
+                    1 => Self::from_rotation_y(angle),
+                    _ => Self::from_rotation_z(angle),
+                };
+                let (i, j, k) = order.axes();
+                if order.is_extrinsic() {
+                    rotation(k, c) * rotation(j, b) * rotation(i, a)
+                } else {
+                    rotation(i, a) * rotation(j, b) * rotation(k, c)
+                }
+            }
+
+            #[inline]
+            pub(crate) fn from_rotation_axes(x_axis: $vec3, y_axis: $vec3, z_axis: $vec3) -> Self {
+                // Shepperd's method: solve for the largest of x, y, z and w first,
+                // which keeps the division well conditioned for any rotation.
+                let (m00, m01, m02) = x_axis.into();
+                let (m10, m11, m12) = y_axis.into();
+                let (m20, m21, m22) = z_axis.into();
+                let trace = m00 + m11 + m22;
+                if trace >= m00 && trace >= m11 && trace >= m22 {
+                    // w^2 is the largest
+                    let four_wsq = 1.0 + trace;
+                    let inv4w = 0.5 / four_wsq.sqrt();
+                    Self::new(
+                        (m12 - m21) * inv4w,
+                        (m20 - m02) * inv4w,
+                        (m01 - m10) * inv4w,
+                        four_wsq * inv4w,
+                    )
+                } else if m00 >= m11 && m00 >= m22 {
+                    // x^2 is the largest
+                    let four_xsq = 1.0 + m00 - m11 - m22;
+                    let inv4x = 0.5 / four_xsq.sqrt();
+                    Self::new(
+                        four_xsq * inv4x,
+                        (m01 + m10) * inv4x,
+                        (m02 + m20) * inv4x,
+                        (m12 - m21) * inv4x,
+                    )
+                } else if m11 >= m22 {
+                    // y^2 is the largest
+                    let four_ysq = 1.0 - m00 + m11 - m22;
+                    let inv4y = 0.5 / four_ysq.sqrt();
+                    Self::new(
+                        (m01 + m10) * inv4y,
+                        four_ysq * inv4y,
+                        (m12 + m21) * inv4y,
+                        (m20 - m02) * inv4y,
+                    )
+                } else {
+                    // z^2 is the largest
+                    let four_zsq = 1.0 - m00 - m11 + m22;
+                    let inv4z = 0.5 / four_zsq.sqrt();
+                    Self::new(
+                        (m02 + m20) * inv4z,
+                        (m12 + m21) * inv4z,
+                        four_zsq * inv4z,
+                        (m01 - m10) * inv4z,
+                    )
+                }
+            }
+
+            /// Creates a quaternion from a 3x3 rotation matrix.
+            ///
+            /// The matrix must be a pure rotation, without scale or shear.
+            #[inline]
+            pub fn from_mat3(mat: &$mat3) -> Self {
+                Self::from_rotation_axes(mat.x_axis(), mat.y_axis(), mat.z_axis())
+            }
+
+            /// Creates a quaternion from the upper 3x3 rotation part of a 4x4 matrix.
+            ///
+            /// The upper 3x3 part must be a pure rotation, without scale or shear.
+            #[inline]
+            pub fn from_mat4(mat: &$mat4) -> Self {
+                Self::from_rotation_axes(
+                    mat.x_axis().truncate(),
+                    mat.y_axis().truncate(),
+                    mat.z_axis().truncate(),
+                )
+            }
+
+            #[deprecated(since = "0.9.0", note = "please use `from_mat3` instead")]
+            #[inline]
+            pub fn from_rotation_mat3(mat: &$mat3) -> Self {
+                Self::from_mat3(mat)
+            }
+
+            #[deprecated(since = "0.9.0", note = "please use `from_mat4` instead")]
+            #[inline]
+            pub fn from_rotation_mat4(mat: &$mat4) -> Self {
+                Self::from_mat4(mat)
+            }
+
+            /// Creates a left-handed view rotation looking in the direction `dir`, with
+            /// `up` as the up direction.
+            ///
+            /// This is the rotation part of `Mat4::look_at_lh`, so it rotates `dir` onto
+            /// the positive z axis.
+            #[inline]
+            pub fn look_to_lh(dir: $vec3, up: $vec3) -> Self {
+                glam_assert!(up.is_normalized());
+                let f = dir.normalize();
+                let s = up.cross(f).normalize();
+                let u = f.cross(s);
+                // the rows of the rotation matrix are s, u and f
+                let (fx, fy, fz) = f.into();
+                let (sx, sy, sz) = s.into();
+                let (ux, uy, uz) = u.into();
+                Self::from_rotation_axes(
+                    $vec3::new(sx, ux, fx),
+                    $vec3::new(sy, uy, fy),
+                    $vec3::new(sz, uz, fz),
+                )
+            }
+
+            /// Creates a right-handed view rotation looking in the direction `dir`,
+            /// with `up` as the up direction.
+            ///
+            /// This is the rotation part of `Mat4::look_at_rh`, so it rotates `dir` onto
+            /// the negative z axis.
+            #[inline]
+            pub fn look_to_rh(dir: $vec3, up: $vec3) -> Self {
+                Self::look_to_lh(-dir, up)
+            }
+
+            /// Creates a left-handed view rotation for a viewer at `eye` looking at
+            /// `center`, with `up` as the up direction.
+            ///
+            /// This is the rotation part of `Mat4::look_at_lh`.
+            #[inline]
+            pub fn look_at_lh(eye: $vec3, center: $vec3, up: $vec3) -> Self {
+                Self::look_to_lh(center - eye, up)
+            }
+
+            /// Creates a right-handed view rotation for a viewer at `eye` looking at
+            /// `center`, with `up` as the up direction.
+            ///
+            /// This is the rotation part of `Mat4::look_at_rh`.
+            #[inline]
+            pub fn look_at_rh(eye: $vec3, center: $vec3, up: $vec3) -> Self {
+                Self::look_to_lh(eye - center, up)
+            }
+
+            /// Creates the shortest rotation which rotates the unit vector `from` onto
+            /// the unit vector `to`.
+            ///
+            /// When `from` and `to` point in opposite directions any axis orthogonal to
+            /// `from` gives a shortest rotation and one is picked arbitrarily.
+            #[inline]
+            pub fn from_rotation_arc(from: $vec3, to: $vec3) -> Self {
+                glam_assert!(from.is_normalized());
+                glam_assert!(to.is_normalized());
+                const ONE_MINUS_EPSILON: $t = 1.0 - 2.0 * $t::EPSILON;
+                let dot = from.dot(to);
+                if dot > ONE_MINUS_EPSILON {
+                    Self::identity()
+                } else if dot < -ONE_MINUS_EPSILON {
+                    // a half turn around an axis orthogonal to `from`
+                    Self(from.any_orthonormal_vector().extend(0.0))
+                } else {
+                    Self(from.cross(to).extend(1.0 + dot)).normalize()
+                }
+            }
+
+            /// Creates the shortest rotation around the z axis which rotates the 2D
+            /// unit vector `from` onto the 2D unit vector `to`.
+            ///
+            /// When `from` and `to` point in opposite directions the result is a half
+            /// turn around the z axis.
+            #[inline]
+            pub fn from_rotation_arc_2d(from: $vec2, to: $vec2) -> Self {
+                glam_assert!(from.is_normalized());
+                glam_assert!(to.is_normalized());
+                const ONE_MINUS_EPSILON: $t = 1.0 - 2.0 * $t::EPSILON;
+                let dot = from.dot(to);
+                if dot > ONE_MINUS_EPSILON {
+                    Self::identity()
+                } else if dot < -ONE_MINUS_EPSILON {
+                    Self::new(0.0, 0.0, 1.0, 0.0)
+                } else {
+                    let z = from.x() * to.y() - to.x() * from.y();
+                    let w = 1.0 + dot;
+                    let len_rcp = 1.0 / (z * z + w * w).sqrt();
+                    Self::new(0.0, 0.0, z * len_rcp, w * len_rcp)
+                }
+            }
+
+            #[inline]
+            pub fn to_axis_angle(self) -> ($vec3, $t) {
+                const EPSILON: $t = 1.0e-8;
+                const EPSILON_SQUARED: $t = EPSILON * EPSILON;
+                let (x, y, z, w) = self.0.into();
+                let angle = scalar_acos(w) * 2.0;
+                let scale_sq = (1.0 - w * w).max(0.0);
+                if scale_sq >= EPSILON_SQUARED {
+                    ($vec3::new(x, y, z) / scale_sq.sqrt(), angle)
+                } else {
+                    ($vec3::unit_x(), angle)
+                }
+            }
+
+            /// Returns the Euler angles (in radians) of `self` for the given `order`,
+            /// such that `from_euler(order, a, b, c)` is the same rotation as `self`.
+            ///
+            /// The second angle is in the range `[-PI/2, PI/2]` and the others in
+            /// `[-PI, PI]`. In gimbal lock, when the second angle is `PI/2` or
+            /// `-PI/2`, the first and third rotations are about the same axis. The
+            /// angle of the outermost rotation is then returned as zero, which is the
+            /// first angle for intrinsic orders and the third for extrinsic orders.
+            #[inline]
+            pub fn to_euler(self, order: crate::EulerRot) -> ($t, $t, $t) {
+                glam_assert!(self.is_normalized());
+                const GIMBAL_LOCK_EPSILON: $t = 1.0e-6;
+                // extrinsic angles are the intrinsic angles in the reverse order
+                let extrinsic = order.is_extrinsic();
+                let (i, j, k) = order.axes();
+                let (i, k) = if extrinsic { (k, i) } else { (i, k) };
+                // the sign of the permutation (i, j, k)
+                let s = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+                let cols = $mat3::from_quat(self).to_cols_array_2d();
+                let m = |row: usize, col: usize| cols[col][row];
+                let cos_b = (m(i, i) * m(i, i) + m(i, j) * m(i, j)).sqrt();
+                let b = (s * m(i, k)).atan2(cos_b);
+                let (a, c) = if cos_b < GIMBAL_LOCK_EPSILON {
+                    (0.0, (s * m(j, i)).atan2(m(j, j)))
+                } else {
+                    let a = (-s * m(j, k)).atan2(m(k, k));
+                    // solve for c from the rotation left after undoing a
+                    let (sin_a, cos_a) = scalar_sin_cos(a);
+                    let c = (s * cos_a * m(j, i) + sin_a * m(k, i))
+                        .atan2(cos_a * m(j, j) + s * sin_a * m(k, j));
+                    (a, c)
+                };
+                if extrinsic {
+                    (c, b, a)
+                } else {
+                    (a, b, c)
+                }
+            }
+
+            /// Returns the rotation vector of `self`, whose direction is the rotation
+            /// axis and whose length is the angle (in radians).
+            ///
+            /// This is the inverse of `from_scaled_axis`.
+            #[inline]
+            pub fn to_scaled_axis(self) -> $vec3 {
+                let (axis, angle) = self.to_axis_angle();
+                axis * angle
+            }
+
+            #[inline]
+            pub fn conjugate(self) -> Self {
+                Self((-self.0.truncate()).extend(self.0.w()))
+            }
+
+            /// Splits `self` into a swing and a twist around the unit vector `axis`,
+            /// returned as `(swing, twist)` such that `self == swing * twist`.
+            ///
+            /// The twist is the part of the rotation around `axis` and the swing is the
+            /// remaining rotation around an axis perpendicular to `axis`. If the swing
+            /// is a half turn the twist is undefined and the identity is returned.
+            #[inline]
+            pub fn to_swing_twist(self, axis: $vec3) -> (Self, Self) {
+                glam_assert!(self.is_normalized());
+                glam_assert!(axis.is_normalized());
+                const EPSILON: $t = 1.0e-8;
+                const EPSILON_SQUARED: $t = EPSILON * EPSILON;
+                let projected = axis * self.0.truncate().dot(axis);
+                let twist = Self(projected.extend(self.0.w()));
+                let length_squared = twist.length_squared();
+                let twist = if length_squared >= EPSILON_SQUARED {
+                    Self(twist.0 / length_squared.sqrt())
+                } else {
+                    Self::identity()
+                };
+                (self * twist.conjugate(), twist)
+            }
+
+            /// Returns the natural logarithm of `self`.
+            ///
+            /// For a unit quaternion this is the pure quaternion holding half the
+            /// rotation vector, see `to_scaled_axis`. If the vector part of `self` is
+            /// zero the vector part of the result is also zero.
+            #[inline]
+            pub fn ln(self) -> Self {
+                let v = self.0.truncate();
+                let w = self.0.w();
+                let v_length = v.length();
+                let angle = v_length.atan2(w);
+                let scale = if v_length > 0.0 {
+                    angle / v_length
+                } else {
+                    0.0
+                };
+                Self((v * scale).extend(self.length().ln()))
+            }
+
+            /// Returns the exponential of `self`.
+            ///
+            /// For a pure quaternion holding half a rotation vector this is the unit
+            /// quaternion of that rotation, see `from_scaled_axis`.
+            #[inline]
+            pub fn exp(self) -> Self {
+                let v = self.0.truncate();
+                let v_length = v.length();
+                let (sin, cos) = scalar_sin_cos(v_length);
+                let scale = if v_length > 0.0 { sin / v_length } else { 1.0 };
+                let exp_w = self.0.w().exp();
+                Self((v * (scale * exp_w)).extend(cos * exp_w))
+            }
+
+            /// Returns `self` raised to the power `n`.
+            ///
+            /// For a unit quaternion this scales the rotation angle by `n`, so
+            /// `q.powf(0.5)` is half of the rotation `q`.
+            #[inline]
+            pub fn powf(self, n: $t) -> Self {
+                Self(self.ln().0 * n).exp()
+            }
+
+            /// Integrates `self` over the time step `dt` for the given world space
+            /// `angular_velocity` (in radians per unit of time).
+            ///
+            /// This is the first order update `q + 0.5 * dt * (angular_velocity, 0) * q`
+            /// followed by a normalization, so it is only accurate for small steps. Use
+            /// `from_scaled_axis(angular_velocity * dt) * self` for an exact update.
+            #[inline]
+            pub fn integrate(self, angular_velocity: $vec3, dt: $t) -> Self {
+                glam_assert!(self.is_normalized());
+                let half_dt = 0.5 * dt;
+                let v = self.0.truncate();
+                let w = self.0.w();
+                // the product of the pure quaternion (angular_velocity, 0) and self
+                let dv = angular_velocity * w + angular_velocity.cross(v);
+                let dw = -angular_velocity.dot(v);
+                Self((v + dv * half_dt).extend(w + dw * half_dt)).normalize()
+            }
+
+            /// Returns the angle (in radians) of the rotation from `self` to `other`.
+            ///
+            /// `q` and `-q` are the same rotation, so the result is always in the range
+            /// `[0, PI]`.
+            #[inline]
+            pub fn angle_between(self, other: Self) -> $t {
+                glam_assert!(self.is_normalized());
+                glam_assert!(other.is_normalized());
+                scalar_acos(self.dot(other).abs()) * 2.0
+            }
+
+            /// Computes the dot product of `self` and `other`.
+            #[inline]
+            pub fn dot(self, other: Self) -> $t {
+                self.0.dot(other.0)
+            }
+
+            /// Computes the length of `self`.
+            #[inline]
+            pub fn length(self) -> $t {
+                self.0.length()
+            }
+
+            /// Computes the squared length of `self`.
+            ///
+            /// This is generally faster than `length()` as it avoids a square
+            /// root operation.
+            #[inline]
+            pub fn length_squared(self) -> $t {
+                self.0.length_squared()
+            }
+
+            /// Computes `1.0 / length()`.
+            ///
+            /// For valid results, `self` must _not_ be of length zero.
+            #[inline]
+            pub fn length_reciprocal(self) -> $t {
+                1.0 / self.0.length()
+            }
+
+            /// Returns `self` normalized to length 1.0.
+            ///
+            /// For valid results, `self` must _not_ be of length zero.
+            #[inline]
+            pub fn normalize(self) -> Self {
+                let inv_len = self.0.length_reciprocal();
+                Self(self.0 * inv_len)
+            }
+
+            /// Returns `self` rescaled to approximately length `1.0`, using a first
+            /// order approximation of the reciprocal square root instead of a square
+            /// root and a division.
+            ///
+            /// This is only accurate when `self` is already close to normalized, which
+            /// makes it a cheap way to correct the drift from accumulating rotations
+            /// every frame. Use `normalize` for quaternions far from unit length.
+            #[inline]
+            pub fn renormalize(self) -> Self {
+                // 1 / sqrt(x) ~= (3 - x) / 2 for x near 1
+                let scale = (3.0 - self.0.length_squared()) * 0.5;
+                Self(self.0 * scale)
+            }
+
+            /// Returns whether `self` of length `1.0` or not.
+            ///
+            /// Uses a precision threshold of `1e-4`.
+            #[inline]
+            pub fn is_normalized(self) -> bool {
+                is_normalized!(self)
+            }
+
+            #[inline]
+            pub fn is_near_identity(self) -> bool {
+                // Implementation taken from RTM
+                const THRESHOLD_ANGLE: $t = 0.002_847_144_6;
+                // Because of floating point precision, we cannot represent very small rotations.
+                // The closest f32 to 1.0 that is not 1.0 itself yields:
+                // 0.99999994.acos() * 2.0  = 0.000690533954 rad
+                //
+                // An error threshold of 1.e-6 is used by default.
+                // (1.0 - 1.e-6).acos() * 2.0 = 0.00284714461 rad
+                // (1.0 - 1.e-7).acos() * 2.0 = 0.00097656250 rad
+                //
+                // We don't really care about the angle value itself, only if it's close to 0.
+                // This will happen whenever quat.w is close to 1.0.
+                // If the quat.w is close to -1.0, the angle will be near 2*PI which is close to
+                // a negative 0 rotation. By forcing quat.w to be positive, we'll end up with
+                // the shortest path.
+                let positive_w_angle = scalar_acos(self.0.w().abs()) * 2.0;
+                positive_w_angle < THRESHOLD_ANGLE
+            }
+
+            /// Returns `true` if all elements of `self` are finite, that is neither
+            /// infinite nor NaN.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                self.0.as_ref().iter().all(|e| e.is_finite())
+            }
+
+            /// Returns `true` if any element of `self` is NaN.
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                self.0.as_ref().iter().any(|e| e.is_nan())
+            }
+
+            /// Returns true if the absolute difference of all elements between `self`
+            /// and `other` is less than or equal to `max_abs_diff`.
+            ///
+            /// This can be used to compare if two quaternions contain similar elements. It
+            /// works best when comparing with a known value. The `max_abs_diff` that
+            /// should be used used depends on the values being compared against.
+            ///
+            /// For more on floating point comparisons see
+            /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+            #[inline]
+            pub fn abs_diff_eq(self, other: Self, max_abs_diff: $t) -> bool {
+                self.0.abs_diff_eq(other.0, max_abs_diff)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or within `max_relative` times the larger
+            /// magnitude of the two elements.
+            #[inline]
+            pub fn relative_eq(self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                self.0.relative_eq(other.0, max_abs_diff, max_relative)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or at most `max_ulps` representable
+            /// floating point values apart.
+            #[inline]
+            pub fn ulps_eq(self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                self.0.ulps_eq(other.0, max_abs_diff, max_ulps)
+            }
+
+            /// Returns true if `self` and `other` represent the same rotation, that is
+            /// if `self` is within `max_abs_diff` of either `other` or `-other`.
+            ///
+            /// This avoids spurious failures when comparing rotations whose signs were
+            /// flipped, such as after `nlerp` or a conversion from a matrix.
+            #[inline]
+            pub fn abs_diff_eq_rotation(self, other: Self, max_abs_diff: $t) -> bool {
+                self.abs_diff_eq(other, max_abs_diff) || self.abs_diff_eq(-other, max_abs_diff)
+            }
+
+            /// Returns `self` or `-self`, whichever has a non negative `w`.
+            ///
+            /// `q` and `-q` represent the same rotation, so this gives a unique
+            /// representation of the rotation except when `w` is zero.
+            #[inline]
+            pub fn canonicalize(self) -> Self {
+                if self.0.w() < 0.0 {
+                    -self
+                } else {
+                    self
+                }
+            }
+
+            /// Performs a normalized linear interpolation between `self` and `end`
+            /// based on the value `t`.
+            ///
+            /// This is the same as `nlerp`.
+            #[inline]
+            pub fn lerp(self, end: Self, t: $t) -> Self {
+                self.nlerp(end, t)
+            }
+
+            /// Performs a normalized linear interpolation between `self` and `end`
+            /// based on the value `t`, taking the shortest path.
+            ///
+            /// When `t` is `0.0`, the result will be equal to `self`. When `t` is
+            /// `1.0`, the result will be equal to `end`, or `-end` if `self` and `end`
+            /// are more than 90 degrees apart. `-end` is the same rotation as `end`.
+            ///
+            /// This is much cheaper than a spherical linear interpolation. The angular
+            /// velocity is not constant, but it is close enough for blending between
+            /// nearby rotations such as animation poses.
+            #[inline]
+            pub fn nlerp(self, end: Self, t: $t) -> Self {
+                glam_assert!(self.is_normalized());
+                glam_assert!(end.is_normalized());
+                let start = self.0;
+                let end = end.0;
+                let dot = start.dot(end);
+                let bias = if dot >= 0.0 { 1.0 } else { -1.0 };
+                let interpolated = start + (t * ((end * bias) - start));
+                Self(interpolated.normalize())
+            }
+
+            /// Performs a spherical linear interpolation between `self` and `end`
+            /// based on the value `t`, taking the shortest path.
+            ///
+            /// When `t` is `0.0`, the result will be equal to `self`. When `t` is
+            /// `1.0`, the result will be equal to `end`, or `-end` if `self` and `end`
+            /// are more than 90 degrees apart. Nearly parallel quaternions fall back to
+            /// `nlerp`.
+            #[inline]
+            pub fn slerp(self, end: Self, t: $t) -> Self {
+                glam_assert!(self.is_normalized());
+                glam_assert!(end.is_normalized());
+                const DOT_THRESHOLD: $t = 0.9995;
+                let dot = self.dot(end);
+                let (end, dot) = if dot >= 0.0 { (end, dot) } else { (-end, -dot) };
+                if dot > DOT_THRESHOLD {
+                    return self.nlerp(end, t);
+                }
+                let theta = scalar_acos(dot);
+                let (sin_theta, _) = scalar_sin_cos(theta);
+                let (sin_a, _) = scalar_sin_cos(theta * (1.0 - t));
+                let (sin_b, _) = scalar_sin_cos(theta * t);
+                let inv_sin_theta = 1.0 / sin_theta;
+                Self(
+                    (self.0 * (sin_a * inv_sin_theta) + end.0 * (sin_b * inv_sin_theta))
+                        .normalize(),
+                )
+            }
+
+            /// Returns the rotation halfway between `self` and `end`, taking the
+            /// shortest path. This is the same as `self.slerp(end, 0.5)`.
+            #[inline]
+            pub fn midpoint(self, end: Self) -> Self {
+                self.slerp(end, 0.5)
+            }
+
+            /// Performs a spherical cubic interpolation between `self` and `end` based
+            /// on the value `t`, using the control quaternions `control0` and
+            /// `control1` of `self` and `end`.
+            ///
+            /// The control quaternions of a sequence of keyframes are computed with
+            /// `squad_control_point` or `squad_control_points`. Interpolating each pair
+            /// of consecutive keyframes then gives a path with a continuous angular
+            /// velocity.
+            #[inline]
+            pub fn squad(self, control0: Self, control1: Self, end: Self, t: $t) -> Self {
+                let q = self.slerp(end, t);
+                let c = control0.slerp(control1, t);
+                q.slerp(c, 2.0 * t * (1.0 - t))
+            }
+
+            /// Returns the `squad` control quaternion of the keyframe `current`, given
+            /// the keyframes before and after it.
+            #[inline]
+            pub fn squad_control_point(prev: Self, current: Self, next: Self) -> Self {
+                glam_assert!(prev.is_normalized());
+                glam_assert!(current.is_normalized());
+                glam_assert!(next.is_normalized());
+                // q * exp(-(ln(q^-1 * prev) + ln(q^-1 * next)) / 4), where the rotation
+                // vector of a unit quaternion is twice its logarithm
+                let inv = current.conjugate();
+                let to_prev = inv * if current.dot(prev) < 0.0 { -prev } else { prev };
+                let to_next = inv * if current.dot(next) < 0.0 { -next } else { next };
+                let v = (to_prev.to_scaled_axis() + to_next.to_scaled_axis()) * -0.25;
+                current * Self::from_scaled_axis(v)
+            }
+
+            /// Returns the `squad` control quaternions of a sequence of keyframes.
+            ///
+            /// The first and last keyframes are treated as if they were repeated.
+            pub fn squad_control_points(keys: &[Self]) -> Vec<Self> {
+                let last = keys.len().saturating_sub(1);
+                (0..keys.len())
+                    .map(|i| {
+                        let prev = keys[i.saturating_sub(1)];
+                        let next = keys[(i + 1).min(last)];
+                        Self::squad_control_point(prev, keys[i], next)
+                    })
+                    .collect()
+            }
+
+            /// Rotates `self` towards `target` by at most `max_angle` (in radians),
+            /// taking the shortest path.
+            ///
+            /// Returns `target` once it is within `max_angle` of `self`. A negative
+            /// `max_angle` rotates away from `target`, but never further than the
+            /// opposite orientation.
+            #[inline]
+            pub fn rotate_towards(self, target: Self, max_angle: $t) -> Self {
+                glam_assert!(self.is_normalized());
+                glam_assert!(target.is_normalized());
+                let angle = self.angle_between(target);
+                if angle <= max_angle {
+                    return target;
+                }
+                let t = (max_angle / angle).max(-1.0);
+                self.slerp(target, t)
+            }
+        }
+
+        impl std::fmt::Display for $quat {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+                let (x, y, z, w) = self.0.into();
+                write!(fmt, "[{}, {}, {}, {}]", x, y, z, w)
+            }
+        }
+
+        impl std::ops::Mul<$quat> for $quat {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                self.mul_quat(other)
+            }
+        }
+
+        impl std::ops::MulAssign<$quat> for $quat {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = self.mul_quat(other);
+            }
+        }
+
+        impl std::ops::Mul<$vec3> for $quat {
+            type Output = $vec3;
+            #[inline]
+            fn mul(self, other: $vec3) -> $vec3 {
+                self.mul_vec3(other)
+            }
+        }
+
+        impl std::ops::Neg for $quat {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-1.0 * self.0)
+            }
+        }
+
+        impl Default for $quat {
+            #[inline]
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl PartialEq for $quat {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0.cmpeq(other.0).all()
+            }
+        }
+
+        impl PartialOrd for $quat {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                self.as_ref().partial_cmp(other.as_ref())
+            }
+        }
+
+        impl AsRef<[$t; 4]> for $quat {
+            #[inline]
+            fn as_ref(&self) -> &[$t; 4] {
+                self.0.as_ref()
+            }
+        }
+
+        impl AsMut<[$t; 4]> for $quat {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [$t; 4] {
+                self.0.as_mut()
+            }
+        }
+
+        impl From<$vec4> for $quat {
+            #[inline]
+            fn from(v: $vec4) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<$quat> for $vec4 {
+            #[inline]
+            fn from(q: $quat) -> Self {
+                q.0
+            }
+        }
+
+        impl From<($t, $t, $t, $t)> for $quat {
+            #[inline]
+            fn from(t: ($t, $t, $t, $t)) -> Self {
+                $quat::new(t.0, t.1, t.2, t.3)
+            }
+        }
+
+        impl From<$quat> for ($t, $t, $t, $t) {
+            #[inline]
+            fn from(q: $quat) -> Self {
+                q.0.into()
+            }
+        }
+
+        impl From<[$t; 4]> for $quat {
+            #[inline]
+            fn from(a: [$t; 4]) -> Self {
+                Self(a.into())
+            }
+        }
+
+        impl From<$quat> for [$t; 4] {
+            #[inline]
+            fn from(q: $quat) -> Self {
+                q.0.into()
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl rand::distributions::Distribution<$quat> for crate::distributions::UnitQuaternion {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $quat {
+                use std::$t::consts::PI;
+                let u1 = rng.gen::<$t>();
+                let (s2, c2) = scalar_sin_cos(rng.gen::<$t>() * 2.0 * PI);
+                let (s3, c3) = scalar_sin_cos(rng.gen::<$t>() * 2.0 * PI);
+                let r1 = (1.0 - u1).sqrt();
+                let r2 = u1.sqrt();
+                $quat::new(r1 * s2, r1 * c2, r2 * s3, r2 * c3)
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl rand::distributions::Distribution<$quat> for rand::distributions::Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $quat {
+                rng.sample(crate::distributions::UnitQuaternion)
+            }
+        }
+
+        impl_product!($quat, $quat::identity());
+        impl_slice_methods!($quat, $t, 4);
+    };
+}
+
+macro_rules! impl_quat_scalar {
+    ($quat:ident, $vec3:ident) => {
+        impl $quat {
+            /// Multiplies a quaternion and a 3D vector, rotating it.
+            #[inline]
+            pub fn mul_vec3(self, other: $vec3) -> $vec3 {
+                glam_assert!(self.is_normalized());
+                let w = self.0.w();
+                let b = self.0.truncate();
+                let b2 = b.dot(b);
+                other * (w * w - b2) + b * (other.dot(b) * 2.0) + b.cross(other) * (w * 2.0)
+            }
+
+            /// Multiplies two quaternions.
+            /// Note that due to floating point rounding the result may not be perfectly normalized.
+            #[inline]
+            pub fn mul_quat(self, other: Self) -> Self {
+                glam_assert!(self.is_normalized());
+                glam_assert!(other.is_normalized());
+                let (x0, y0, z0, w0) = self.0.into();
+                let (x1, y1, z1, w1) = other.0.into();
+                Self::new(
+                    w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+                    w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+                    w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+                    w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+                )
+            }
+        }
+
+        impl std::fmt::Debug for $quat {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+                fmt.debug_tuple(stringify!($quat))
+                    .field(&self.0.x())
+                    .field(&self.0.y())
+                    .field(&self.0.z())
+                    .field(&self.0.w())
+                    .finish()
+            }
+        }
+    };
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// The error returned by checked slice loads and stores such as
+/// `Vec4::try_from_slice` when the slice has fewer elements than the type being
+/// loaded or stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceLengthError {
+    required: usize,
+}
+
+impl SliceLengthError {
+    /// Returns an error if `len` is less than `required`.
+    #[inline]
+    pub(crate) fn check(len: usize, required: usize) -> Result<(), Self> {
+        if len >= required {
+            Ok(())
+        } else {
+            Err(Self { required })
+        }
+    }
+
+    /// Returns the number of elements that the slice was required to have.
+    #[inline]
+    pub fn required_len(&self) -> usize {
+        self.required
+    }
+}
+
+impl fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "slice is shorter than {} elements", self.required)
+    }
+}
+
+impl std::error::Error for SliceLengthError {}
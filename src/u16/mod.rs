@@ -0,0 +1,7 @@
+mod u16vec2;
+mod u16vec3;
+mod u16vec4;
+
+pub use u16vec2::*;
+pub use u16vec3::*;
+pub use u16vec4::*;
@@ -0,0 +1,65 @@
+use crate::{
+    bool::BVec2, f32::Vec2, f64::DVec2, i16::I16Vec2, i32::IVec2, i64::I64Vec2, u16::U16Vec3,
+    u32::UVec2, u64::U64Vec2,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 2-dimensional vector of `u16` elements.
+///
+/// This type is intended for compact storage such as vertex buffers. Convert to
+/// `UVec2` for general purpose integer math.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct U16Vec2(u16, u16);
+
+#[inline]
+pub fn u16vec2(x: u16, y: u16) -> U16Vec2 {
+    U16Vec2(x, y)
+}
+
+impl_vec2_int!(U16Vec2, u16, u64, BVec2, U16Vec3);
+impl_vec2_as!(
+    U16Vec2,
+    as_vec2 => Vec2, f32;
+    as_dvec2 => DVec2, f64;
+    as_i16vec2 => I16Vec2, i16;
+    as_ivec2 => IVec2, i32;
+    as_uvec2 => UVec2, u32;
+    as_i64vec2 => I64Vec2, i64;
+    as_u64vec2 => U64Vec2, u64;
+);
+
+impl From<U16Vec2> for UVec2 {
+    #[inline]
+    fn from(v: U16Vec2) -> Self {
+        Self::new(u32::from(v.0), u32::from(v.1))
+    }
+}
+
+impl From<U16Vec2> for IVec2 {
+    #[inline]
+    fn from(v: U16Vec2) -> Self {
+        Self::new(i32::from(v.0), i32::from(v.1))
+    }
+}
+
+impl TryFrom<IVec2> for U16Vec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U16Vec2`, failing if any element is out of range for `u16`.
+    #[inline]
+    fn try_from(v: IVec2) -> Result<Self, Self::Error> {
+        Ok(Self(u16::try_from(v.x())?, u16::try_from(v.y())?))
+    }
+}
+
+impl TryFrom<UVec2> for U16Vec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U16Vec2`, failing if any element is out of range for `u16`.
+    #[inline]
+    fn try_from(v: UVec2) -> Result<Self, Self::Error> {
+        Ok(Self(u16::try_from(v.x())?, u16::try_from(v.y())?))
+    }
+}
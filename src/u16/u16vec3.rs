@@ -0,0 +1,81 @@
+use crate::{
+    bool::BVec3,
+    f32::{Vec3, Vec3A},
+    f64::DVec3,
+    i16::I16Vec3,
+    i32::IVec3,
+    i64::I64Vec3,
+    u16::{U16Vec2, U16Vec4},
+    u32::UVec3,
+    u64::U64Vec3,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 3-dimensional vector of `u16` elements.
+///
+/// This type is intended for compact storage such as vertex buffers. Convert to
+/// `UVec3` for general purpose integer math.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct U16Vec3(u16, u16, u16);
+
+#[inline]
+pub fn u16vec3(x: u16, y: u16, z: u16) -> U16Vec3 {
+    U16Vec3(x, y, z)
+}
+
+impl_vec3_int!(U16Vec3, u16, u64, BVec3, U16Vec2, U16Vec4);
+impl_vec3_as!(
+    U16Vec3,
+    as_vec3 => Vec3, f32;
+    as_vec3a => Vec3A, f32;
+    as_dvec3 => DVec3, f64;
+    as_i16vec3 => I16Vec3, i16;
+    as_ivec3 => IVec3, i32;
+    as_uvec3 => UVec3, u32;
+    as_i64vec3 => I64Vec3, i64;
+    as_u64vec3 => U64Vec3, u64;
+);
+
+impl From<U16Vec3> for UVec3 {
+    #[inline]
+    fn from(v: U16Vec3) -> Self {
+        Self::new(u32::from(v.0), u32::from(v.1), u32::from(v.2))
+    }
+}
+
+impl From<U16Vec3> for IVec3 {
+    #[inline]
+    fn from(v: U16Vec3) -> Self {
+        Self::new(i32::from(v.0), i32::from(v.1), i32::from(v.2))
+    }
+}
+
+impl TryFrom<IVec3> for U16Vec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U16Vec3`, failing if any element is out of range for `u16`.
+    #[inline]
+    fn try_from(v: IVec3) -> Result<Self, Self::Error> {
+        Ok(Self(
+            u16::try_from(v.x())?,
+            u16::try_from(v.y())?,
+            u16::try_from(v.z())?,
+        ))
+    }
+}
+
+impl TryFrom<UVec3> for U16Vec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U16Vec3`, failing if any element is out of range for `u16`.
+    #[inline]
+    fn try_from(v: UVec3) -> Result<Self, Self::Error> {
+        Ok(Self(
+            u16::try_from(v.x())?,
+            u16::try_from(v.y())?,
+            u16::try_from(v.z())?,
+        ))
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    bool::BVec4, f32::Vec4, f64::DVec4, i16::I16Vec4, i32::IVec4, i64::I64Vec4, u16::U16Vec3,
+    u32::UVec4, u64::U64Vec4,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 4-dimensional vector of `u16` elements.
+///
+/// This type is intended for compact storage such as vertex buffers. Convert to
+/// `UVec4` for general purpose integer math.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct U16Vec4(u16, u16, u16, u16);
+
+#[inline]
+pub fn u16vec4(x: u16, y: u16, z: u16, w: u16) -> U16Vec4 {
+    U16Vec4(x, y, z, w)
+}
+
+impl_vec4_int!(U16Vec4, u16, u64, BVec4, U16Vec3);
+impl_vec4_as!(
+    U16Vec4,
+    as_vec4 => Vec4, f32;
+    as_dvec4 => DVec4, f64;
+    as_i16vec4 => I16Vec4, i16;
+    as_ivec4 => IVec4, i32;
+    as_uvec4 => UVec4, u32;
+    as_i64vec4 => I64Vec4, i64;
+    as_u64vec4 => U64Vec4, u64;
+);
+
+impl From<U16Vec4> for UVec4 {
+    #[inline]
+    fn from(v: U16Vec4) -> Self {
+        Self::new(
+            u32::from(v.0),
+            u32::from(v.1),
+            u32::from(v.2),
+            u32::from(v.3),
+        )
+    }
+}
+
+impl From<U16Vec4> for IVec4 {
+    #[inline]
+    fn from(v: U16Vec4) -> Self {
+        Self::new(
+            i32::from(v.0),
+            i32::from(v.1),
+            i32::from(v.2),
+            i32::from(v.3),
+        )
+    }
+}
+
+impl TryFrom<IVec4> for U16Vec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U16Vec4`, failing if any element is out of range for `u16`.
+    #[inline]
+    fn try_from(v: IVec4) -> Result<Self, Self::Error> {
+        Ok(Self(
+            u16::try_from(v.x())?,
+            u16::try_from(v.y())?,
+            u16::try_from(v.z())?,
+            u16::try_from(v.w())?,
+        ))
+    }
+}
+
+impl TryFrom<UVec4> for U16Vec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U16Vec4`, failing if any element is out of range for `u16`.
+    #[inline]
+    fn try_from(v: UVec4) -> Result<Self, Self::Error> {
+        Ok(Self(
+            u16::try_from(v.x())?,
+            u16::try_from(v.y())?,
+            u16::try_from(v.z())?,
+            u16::try_from(v.w())?,
+        ))
+    }
+}
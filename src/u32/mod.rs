@@ -0,0 +1,7 @@
+mod uvec2;
+mod uvec3;
+mod uvec4;
+
+pub use uvec2::*;
+pub use uvec3::*;
+pub use uvec4::*;
@@ -0,0 +1,55 @@
+use crate::{
+    bool::BVec2, f32::Vec2, f64::DVec2, i16::I16Vec2, i32::IVec2, i64::I64Vec2, u16::U16Vec2,
+    u32::UVec3, u64::U64Vec2,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 2-dimensional vector of `u32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct UVec2(u32, u32);
+
+#[inline]
+pub fn uvec2(x: u32, y: u32) -> UVec2 {
+    UVec2(x, y)
+}
+
+impl_vec2_int!(UVec2, u32, u64, BVec2, UVec3);
+impl_vec2_as!(
+    UVec2,
+    as_vec2 => Vec2, f32;
+    as_dvec2 => DVec2, f64;
+    as_i16vec2 => I16Vec2, i16;
+    as_u16vec2 => U16Vec2, u16;
+    as_ivec2 => IVec2, i32;
+    as_i64vec2 => I64Vec2, i64;
+    as_u64vec2 => U64Vec2, u64;
+);
+
+impl From<UVec2> for DVec2 {
+    #[inline]
+    fn from(v: UVec2) -> Self {
+        v.as_dvec2()
+    }
+}
+
+impl TryFrom<IVec2> for UVec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `UVec2`, failing if any element is negative.
+    #[inline]
+    fn try_from(v: IVec2) -> Result<Self, Self::Error> {
+        Ok(Self(u32::try_from(v.x())?, u32::try_from(v.y())?))
+    }
+}
+
+impl TryFrom<UVec2> for IVec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `IVec2`, failing if any element is above `i32::MAX`.
+    #[inline]
+    fn try_from(v: UVec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(i32::try_from(v.0)?, i32::try_from(v.1)?))
+    }
+}
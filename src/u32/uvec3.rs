@@ -0,0 +1,71 @@
+use crate::{
+    bool::BVec3,
+    f32::{Vec3, Vec3A},
+    f64::DVec3,
+    i16::I16Vec3,
+    i32::IVec3,
+    i64::I64Vec3,
+    u16::U16Vec3,
+    u32::{UVec2, UVec4},
+    u64::U64Vec3,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 3-dimensional vector of `u32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct UVec3(u32, u32, u32);
+
+#[inline]
+pub fn uvec3(x: u32, y: u32, z: u32) -> UVec3 {
+    UVec3(x, y, z)
+}
+
+impl_vec3_int!(UVec3, u32, u64, BVec3, UVec2, UVec4);
+impl_vec3_as!(
+    UVec3,
+    as_vec3 => Vec3, f32;
+    as_vec3a => Vec3A, f32;
+    as_dvec3 => DVec3, f64;
+    as_i16vec3 => I16Vec3, i16;
+    as_u16vec3 => U16Vec3, u16;
+    as_ivec3 => IVec3, i32;
+    as_i64vec3 => I64Vec3, i64;
+    as_u64vec3 => U64Vec3, u64;
+);
+
+impl From<UVec3> for DVec3 {
+    #[inline]
+    fn from(v: UVec3) -> Self {
+        v.as_dvec3()
+    }
+}
+
+impl TryFrom<IVec3> for UVec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `UVec3`, failing if any element is negative.
+    #[inline]
+    fn try_from(v: IVec3) -> Result<Self, Self::Error> {
+        Ok(Self(
+            u32::try_from(v.x())?,
+            u32::try_from(v.y())?,
+            u32::try_from(v.z())?,
+        ))
+    }
+}
+
+impl TryFrom<UVec3> for IVec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `IVec3`, failing if any element is above `i32::MAX`.
+    #[inline]
+    fn try_from(v: UVec3) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.0)?,
+            i32::try_from(v.1)?,
+            i32::try_from(v.2)?,
+        ))
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    bool::BVec4, f32::Vec4, f64::DVec4, i16::I16Vec4, i32::IVec4, i64::I64Vec4, u16::U16Vec4,
+    u32::UVec3, u64::U64Vec4,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 4-dimensional vector of `u32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct UVec4(u32, u32, u32, u32);
+
+#[inline]
+pub fn uvec4(x: u32, y: u32, z: u32, w: u32) -> UVec4 {
+    UVec4(x, y, z, w)
+}
+
+impl_vec4_int!(UVec4, u32, u64, BVec4, UVec3);
+impl_vec4_as!(
+    UVec4,
+    as_vec4 => Vec4, f32;
+    as_dvec4 => DVec4, f64;
+    as_i16vec4 => I16Vec4, i16;
+    as_u16vec4 => U16Vec4, u16;
+    as_ivec4 => IVec4, i32;
+    as_i64vec4 => I64Vec4, i64;
+    as_u64vec4 => U64Vec4, u64;
+);
+
+impl From<UVec4> for DVec4 {
+    #[inline]
+    fn from(v: UVec4) -> Self {
+        v.as_dvec4()
+    }
+}
+
+impl TryFrom<IVec4> for UVec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `UVec4`, failing if any element is negative.
+    #[inline]
+    fn try_from(v: IVec4) -> Result<Self, Self::Error> {
+        Ok(Self(
+            u32::try_from(v.x())?,
+            u32::try_from(v.y())?,
+            u32::try_from(v.z())?,
+            u32::try_from(v.w())?,
+        ))
+    }
+}
+
+impl TryFrom<UVec4> for IVec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to an `IVec4`, failing if any element is above `i32::MAX`.
+    #[inline]
+    fn try_from(v: UVec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.0)?,
+            i32::try_from(v.1)?,
+            i32::try_from(v.2)?,
+            i32::try_from(v.3)?,
+        ))
+    }
+}
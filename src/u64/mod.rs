@@ -0,0 +1,7 @@
+mod u64vec2;
+mod u64vec3;
+mod u64vec4;
+
+pub use u64vec2::*;
+pub use u64vec3::*;
+pub use u64vec4::*;
@@ -0,0 +1,55 @@
+use crate::{
+    bool::BVec2, f32::Vec2, f64::DVec2, i16::I16Vec2, i32::IVec2, i64::I64Vec2, u16::U16Vec2,
+    u32::UVec2, u64::U64Vec3,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 2-dimensional vector of `u64` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct U64Vec2(u64, u64);
+
+#[inline]
+pub fn u64vec2(x: u64, y: u64) -> U64Vec2 {
+    U64Vec2(x, y)
+}
+
+impl_vec2_int!(U64Vec2, u64, u128, BVec2, U64Vec3);
+impl_vec2_as!(
+    U64Vec2,
+    as_vec2 => Vec2, f32;
+    as_dvec2 => DVec2, f64;
+    as_i16vec2 => I16Vec2, i16;
+    as_u16vec2 => U16Vec2, u16;
+    as_ivec2 => IVec2, i32;
+    as_uvec2 => UVec2, u32;
+    as_i64vec2 => I64Vec2, i64;
+);
+
+impl From<UVec2> for U64Vec2 {
+    #[inline]
+    fn from(v: UVec2) -> Self {
+        Self(u64::from(v.x()), u64::from(v.y()))
+    }
+}
+
+impl TryFrom<U64Vec2> for UVec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `UVec2`, failing if any element is out of range for `u32`.
+    #[inline]
+    fn try_from(v: U64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(u32::try_from(v.0)?, u32::try_from(v.1)?))
+    }
+}
+
+impl TryFrom<I64Vec2> for U64Vec2 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U64Vec2`, failing if any element is out of range for `u64`.
+    #[inline]
+    fn try_from(v: I64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self(u64::try_from(v.x())?, u64::try_from(v.y())?))
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    bool::BVec3,
+    f32::{Vec3, Vec3A},
+    f64::DVec3,
+    i16::I16Vec3,
+    i32::IVec3,
+    i64::I64Vec3,
+    u16::U16Vec3,
+    u32::UVec3,
+    u64::{U64Vec2, U64Vec4},
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 3-dimensional vector of `u64` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct U64Vec3(u64, u64, u64);
+
+#[inline]
+pub fn u64vec3(x: u64, y: u64, z: u64) -> U64Vec3 {
+    U64Vec3(x, y, z)
+}
+
+impl_vec3_int!(U64Vec3, u64, u128, BVec3, U64Vec2, U64Vec4);
+impl_vec3_as!(
+    U64Vec3,
+    as_vec3 => Vec3, f32;
+    as_vec3a => Vec3A, f32;
+    as_dvec3 => DVec3, f64;
+    as_i16vec3 => I16Vec3, i16;
+    as_u16vec3 => U16Vec3, u16;
+    as_ivec3 => IVec3, i32;
+    as_uvec3 => UVec3, u32;
+    as_i64vec3 => I64Vec3, i64;
+);
+
+impl From<UVec3> for U64Vec3 {
+    #[inline]
+    fn from(v: UVec3) -> Self {
+        Self(u64::from(v.x()), u64::from(v.y()), u64::from(v.z()))
+    }
+}
+
+impl TryFrom<U64Vec3> for UVec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `UVec3`, failing if any element is out of range for `u32`.
+    #[inline]
+    fn try_from(v: U64Vec3) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u32::try_from(v.0)?,
+            u32::try_from(v.1)?,
+            u32::try_from(v.2)?,
+        ))
+    }
+}
+
+impl TryFrom<I64Vec3> for U64Vec3 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U64Vec3`, failing if any element is out of range for `u64`.
+    #[inline]
+    fn try_from(v: I64Vec3) -> Result<Self, Self::Error> {
+        Ok(Self(
+            u64::try_from(v.x())?,
+            u64::try_from(v.y())?,
+            u64::try_from(v.z())?,
+        ))
+    }
+}
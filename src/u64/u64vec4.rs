@@ -0,0 +1,70 @@
+use crate::{
+    bool::BVec4, f32::Vec4, f64::DVec4, i16::I16Vec4, i32::IVec4, i64::I64Vec4, u16::U16Vec4,
+    u32::UVec4, u64::U64Vec3,
+};
+use std::convert::TryFrom;
+use std::num::TryFromIntError;
+
+/// A 4-dimensional vector of `u64` elements.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(C)]
+pub struct U64Vec4(u64, u64, u64, u64);
+
+#[inline]
+pub fn u64vec4(x: u64, y: u64, z: u64, w: u64) -> U64Vec4 {
+    U64Vec4(x, y, z, w)
+}
+
+impl_vec4_int!(U64Vec4, u64, u128, BVec4, U64Vec3);
+impl_vec4_as!(
+    U64Vec4,
+    as_vec4 => Vec4, f32;
+    as_dvec4 => DVec4, f64;
+    as_i16vec4 => I16Vec4, i16;
+    as_u16vec4 => U16Vec4, u16;
+    as_ivec4 => IVec4, i32;
+    as_uvec4 => UVec4, u32;
+    as_i64vec4 => I64Vec4, i64;
+);
+
+impl From<UVec4> for U64Vec4 {
+    #[inline]
+    fn from(v: UVec4) -> Self {
+        Self(
+            u64::from(v.x()),
+            u64::from(v.y()),
+            u64::from(v.z()),
+            u64::from(v.w()),
+        )
+    }
+}
+
+impl TryFrom<U64Vec4> for UVec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `UVec4`, failing if any element is out of range for `u32`.
+    #[inline]
+    fn try_from(v: U64Vec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u32::try_from(v.0)?,
+            u32::try_from(v.1)?,
+            u32::try_from(v.2)?,
+            u32::try_from(v.3)?,
+        ))
+    }
+}
+
+impl TryFrom<I64Vec4> for U64Vec4 {
+    type Error = TryFromIntError;
+
+    /// Converts `v` to a `U64Vec4`, failing if any element is out of range for `u64`.
+    #[inline]
+    fn try_from(v: I64Vec4) -> Result<Self, Self::Error> {
+        Ok(Self(
+            u64::try_from(v.x())?,
+            u64::try_from(v.y())?,
+            u64::try_from(v.z())?,
+            u64::try_from(v.w())?,
+        ))
+    }
+}
@@ -0,0 +1,1893 @@
+// Shared implementation of 2D vector types.
+//
+// `impl_vec2_float_methods` contains methods that are written in terms of
+// other vector methods and are shared by every 2D floating point vector type.
+// `scalar_sin_cos` must be in scope where the macro is invoked.
+//
+// `impl_vec2_scalar` implements a 2D vector stored as a tuple struct of two
+// scalars. The struct itself must be declared by the caller so that it can
+// choose its own attributes and documentation.
+//
+// `impl_vec2_int` implements a 2D integer vector stored as a tuple struct of
+// two scalars and `impl_vec2_signed` adds the methods and operators that only
+// make sense for signed integer elements.
+//
+// `impl_vec2_as` implements `as_*` methods which cast each element of a 2D
+// vector to the element type of another 2D vector type.
+
+macro_rules! impl_vec2_float_methods {
+    ($vec2:ident, $t:ty, $mat2:ident, $mask:ident) => {
+        impl $vec2 {
+            /// Returns a vector containing the reciprocal `1.0/n` of each element of
+            /// `self`.
+            ///
+            /// This is the same as `reciprocal`, named after the scalar `recip`.
+            #[inline]
+            pub fn recip(self) -> Self {
+                Self::one() / self
+            }
+
+            /// Computes the reciprocal `1.0/n` of each element, returning the
+            /// results in a new vector.
+            #[inline]
+            pub fn reciprocal(self) -> Self {
+                Self::one() / self
+            }
+
+            /// Computes the outer product of `self` and `other`, the matrix
+            /// `self * other^T` whose columns are `self` scaled by each element of
+            /// `other`.
+            #[inline]
+            pub fn outer(self, other: Self) -> $mat2 {
+                $mat2::from_cols(
+                    self * other.x(),
+                    self * other.y(),
+                )
+            }
+
+            /// Creates a unit vector pointing in the direction of `angle`, measured in
+            /// radians counter-clockwise from the positive x axis.
+            #[inline]
+            pub fn from_angle(angle: $t) -> Self {
+                let (sin, cos) = scalar_sin_cos(angle);
+                Self::new(cos, sin)
+            }
+
+            /// Returns the angle of `self` in radians, measured counter-clockwise from
+            /// the positive x axis, in the range `[-PI, PI]`.
+            ///
+            /// `self` does not need to be normalized.
+            #[inline]
+            pub fn to_angle(self) -> $t {
+                self.y().atan2(self.x())
+            }
+
+            /// Returns `other` rotated by the angle of `self`, multiplying the two
+            /// vectors as complex numbers.
+            ///
+            /// If `self` is normalized this is a pure rotation, otherwise `other` is
+            /// also scaled by the length of `self`. Combine with `from_angle` to rotate
+            /// by an angle.
+            #[inline]
+            pub fn rotate(self, other: Self) -> Self {
+                Self::new(
+                    self.x() * other.x() - self.y() * other.y(),
+                    self.y() * other.x() + self.x() * other.y(),
+                )
+            }
+
+            /// Returns the signed angle in radians from `self` to `other`, in the range
+            /// `[-PI, PI]`. The angle is positive when `other` is counter-clockwise from
+            /// `self`.
+            ///
+            /// The inputs do not need to be normalized. Using `atan2` of the cross and
+            /// dot products keeps the result precise for nearly parallel vectors.
+            #[inline]
+            pub fn angle_between(self, other: Self) -> $t {
+                let cross = self.x() * other.y() - self.y() * other.x();
+                cross.atan2(self.dot(other))
+            }
+
+            /// Returns the reflection of the incident vector `self` off a surface with
+            /// the given `normal`, like the GLSL `reflect` function.
+            ///
+            /// `normal` must be normalized.
+            #[inline]
+            pub fn reflect(self, normal: Self) -> Self {
+                glam_assert!(normal.is_normalized());
+                self - normal * (2.0 * self.dot(normal))
+            }
+
+            /// Returns the refraction of the incident vector `self` through a surface
+            /// with the given `normal` and ratio of indices of refraction `eta`, like
+            /// the GLSL `refract` function.
+            ///
+            /// Returns zero on total internal reflection. `self` and `normal` must be
+            /// normalized.
+            #[inline]
+            pub fn refract(self, normal: Self, eta: $t) -> Self {
+                glam_assert!(self.is_normalized());
+                glam_assert!(normal.is_normalized());
+                let n_dot_i = normal.dot(self);
+                let k = 1.0 - eta * eta * (1.0 - n_dot_i * n_dot_i);
+                if k >= 0.0 {
+                    self * eta - normal * (eta * n_dot_i + k.sqrt())
+                } else {
+                    Self::zero()
+                }
+            }
+
+            /// Component-wise clamping of values, similar to the scalar `clamp`.
+            ///
+            /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+                self.max(min).min(max)
+            }
+
+            /// Returns a vector containing the minimum of each element of `self` and
+            /// `min`.
+            #[inline]
+            pub fn min_scalar(self, min: $t) -> Self {
+                self.min(Self::splat(min))
+            }
+
+            /// Returns a vector containing the maximum of each element of `self` and
+            /// `max`.
+            #[inline]
+            pub fn max_scalar(self, max: $t) -> Self {
+                self.max(Self::splat(max))
+            }
+
+            /// Clamps every element of `self` to the range `[min, max]`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+            #[inline]
+            pub fn clamp_scalar(self, min: $t, max: $t) -> Self {
+                glam_assert!(min <= max, "clamp_scalar: expected min <= max");
+                self.max(Self::splat(min)).min(Self::splat(max))
+            }
+
+            /// Returns `self` with its length clamped to the range `[min, max]`,
+            /// keeping its direction.
+            ///
+            /// A zero vector has no direction and is returned unchanged, even if `min`
+            /// is greater than zero.
+            #[inline]
+            pub fn clamp_length(self, min: $t, max: $t) -> Self {
+                glam_assert!(0.0 <= min && min <= max);
+                let length_sq = self.length_squared();
+                if length_sq > max * max {
+                    self * (max / length_sq.sqrt())
+                } else if length_sq < min * min && length_sq > 0.0 {
+                    self * (min / length_sq.sqrt())
+                } else {
+                    self
+                }
+            }
+
+            /// Returns `self` with its length clamped to be no more than `max`,
+            /// keeping its direction.
+            #[inline]
+            pub fn clamp_length_max(self, max: $t) -> Self {
+                glam_assert!(0.0 <= max);
+                let length_sq = self.length_squared();
+                if length_sq > max * max {
+                    self * (max / length_sq.sqrt())
+                } else {
+                    self
+                }
+            }
+
+            /// Returns `self` with its length clamped to be no less than `min`,
+            /// keeping its direction.
+            ///
+            /// A zero vector has no direction and is returned unchanged.
+            #[inline]
+            pub fn clamp_length_min(self, min: $t) -> Self {
+                glam_assert!(0.0 <= min);
+                let length_sq = self.length_squared();
+                if length_sq < min * min && length_sq > 0.0 {
+                    self * (min / length_sq.sqrt())
+                } else {
+                    self
+                }
+            }
+
+            /// Computes the Euclidean distance between two points in space.
+            #[inline]
+            pub fn distance(self, other: Self) -> $t {
+                (self - other).length()
+            }
+
+            /// Computes the squared Euclidean distance between two points in space.
+            ///
+            /// This is faster than `distance` as it avoids a square root, so prefer it
+            /// when only comparing distances.
+            #[inline]
+            pub fn distance_squared(self, other: Self) -> $t {
+                (self - other).length_squared()
+            }
+
+            /// Performs a linear interpolation between `self` and `other` based on
+            /// the value `s`.
+            ///
+            /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+            /// is `1.0`, the result will be equal to `other`.
+            ///
+            /// `s` is not clamped, values outside of `[0, 1]` extrapolate along the
+            /// line through `self` and `other`. For example `-1.0` gives
+            /// `self - (other - self)`.
+            #[inline]
+            pub fn lerp(self, other: Self, s: $t) -> Self {
+                self + ((other - self) * s)
+            }
+
+            /// Returns the point halfway between `self` and `other`.
+            #[inline]
+            pub fn midpoint(self, other: Self) -> Self {
+                (self + other) * 0.5
+            }
+
+            /// Moves `self` towards `target` by at most `max_distance`, without
+            /// overshooting.
+            ///
+            /// Returns `target` if it is within `max_distance` of `self`. A negative
+            /// `max_distance` moves `self` away from `target`.
+            #[inline]
+            pub fn move_towards(self, target: Self, max_distance: $t) -> Self {
+                let delta = target - self;
+                let distance = delta.length();
+                if distance == 0.0 || (max_distance >= 0.0 && distance <= max_distance) {
+                    target
+                } else {
+                    self + delta * (max_distance / distance)
+                }
+            }
+
+            /// Returns whether `self` is length `1.0` or not.
+            ///
+            /// Uses a precision threshold of `1e-4`.
+            #[inline]
+            pub fn is_normalized(self) -> bool {
+                is_normalized!(self)
+            }
+
+            /// Returns `true` if any element of `self` is NaN.
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                self.is_nan_mask().any()
+            }
+
+            /// Returns `true` if all elements of `self` are finite, that is neither
+            /// infinite nor NaN.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                self.is_finite_mask().all()
+            }
+
+            /// Performs `is_nan` on each element of `self`, returning a vector mask of
+            /// the results.
+            #[inline]
+            pub fn is_nan_mask(self) -> $mask {
+                // NaN is the only value that is not equal to itself
+                self.cmpne(self)
+            }
+
+            /// Performs `is_finite` on each element of `self`, returning a vector mask
+            /// of the results.
+            #[inline]
+            pub fn is_finite_mask(self) -> $mask {
+                // comparisons with NaN are false
+                self.abs().cmplt(Self::splat(<$t>::INFINITY))
+            }
+
+            /// Returns true if the absolute difference of all elements between `self`
+            /// and `other` is less than or equal to `max_abs_diff`.
+            ///
+            /// This can be used to compare if two vectors contain similar elements. It
+            /// works best when comparing with a known value. The `max_abs_diff` that
+            /// should be used used depends on the values being compared against.
+            ///
+            /// For more on floating point comparisons see
+            /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+            #[inline]
+            pub fn abs_diff_eq(self, other: Self, max_abs_diff: $t) -> bool {
+                abs_diff_eq!(self, other, max_abs_diff)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or within `max_relative` times the larger
+            /// magnitude of the two elements.
+            ///
+            /// Unlike `abs_diff_eq` the tolerance scales with the magnitude of the
+            /// elements, which works better when comparing values of very different
+            /// magnitudes. The `max_abs_diff` is still needed for values near zero.
+            #[inline]
+            pub fn relative_eq(self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                relative_eq!(self, other, max_abs_diff, max_relative)
+            }
+
+            /// Returns true if all elements of `self` and `other` are within
+            /// `max_abs_diff` of each other, or at most `max_ulps` representable
+            /// floating point values apart.
+            ///
+            /// Elements with different signs are only equal within `max_abs_diff`.
+            #[inline]
+            pub fn ulps_eq(self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                ulps_eq!(self, other, max_abs_diff, max_ulps)
+            }
+
+            /// Returns a vector containing the sign of each element of `self`.
+            ///
+            /// - `1.0` if the element is positive, `+0.0` or `INFINITY`
+            /// - `-1.0` if the element is negative, `-0.0` or `NEG_INFINITY`
+            /// - `NAN` if the element is `NAN`
+            ///
+            /// Unlike `sign` this follows the scalar `signum`, including for `-0.0`.
+            #[inline]
+            pub fn signum(self) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.signum(), y.signum())
+            }
+
+            /// Returns a vector with the magnitude of each element of `self` and the
+            /// sign of the corresponding element of `sign`.
+            #[inline]
+            pub fn copysign(self, sign: Self) -> Self {
+                let (x1, y1) = self.into();
+                let (x2, y2) = sign.into();
+                Self::new(x1.copysign(x2), y1.copysign(y2))
+            }
+
+            /// Returns a vector containing the largest integer less than or equal to each
+            /// element of `self`.
+            #[inline]
+            pub fn floor(self) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.floor(), y.floor())
+            }
+
+            /// Returns a vector containing the smallest integer greater than or equal to
+            /// each element of `self`.
+            #[inline]
+            pub fn ceil(self) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.ceil(), y.ceil())
+            }
+
+            /// Returns a vector containing the nearest integer to each element of `self`.
+            /// Round half-way cases away from `0.0`.
+            #[inline]
+            pub fn round(self) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.round(), y.round())
+            }
+
+            /// Returns a vector containing the integer part of each element of `self`,
+            /// rounding toward `0.0`.
+            #[inline]
+            pub fn trunc(self) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.trunc(), y.trunc())
+            }
+
+            /// Returns a vector containing the fractional part of each element of
+            /// `self`, computed as `self - self.floor()`.
+            ///
+            /// Note that this differs from the scalar `fract` for negative elements: the
+            /// result is always in the range `[0.0, 1.0)`, as with GLSL `fract`.
+            #[inline]
+            pub fn fract(self) -> Self {
+                self - self.floor()
+            }
+
+            /// Returns a vector containing `e^self` (the exponential function) for each
+            /// element of `self`.
+            #[inline]
+            pub fn exp(self) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.exp(), y.exp())
+            }
+
+            /// Returns a vector containing `2^self` for each element of `self`.
+            #[inline]
+            pub fn exp2(self) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.exp2(), y.exp2())
+            }
+
+            /// Returns a vector containing the base 2 logarithm of each element of
+            /// `self`.
+            #[inline]
+            pub fn log2(self) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.log2(), y.log2())
+            }
+
+            /// Returns a vector containing each element of `self` raised to the power of
+            /// `n`.
+            #[inline]
+            pub fn powf(self, n: $t) -> Self {
+                let (x, y) = self.into();
+                Self::new(x.powf(n), y.powf(n))
+            }
+
+            /// Converts each element of `self` from degrees to radians.
+            #[inline]
+            pub fn to_radians(self) -> Self {
+                self * <$t>::to_radians(1.0)
+            }
+
+            /// Converts each element of `self` from radians to degrees.
+            #[inline]
+            pub fn to_degrees(self) -> Self {
+                self * <$t>::to_degrees(1.0)
+            }
+
+            /// Returns the element-wise quotient of Euclidean division of `self` by
+            /// `other`.
+            ///
+            /// In other words this computes `[x1.div_euclid(x2), y1.div_euclid(y2)]`.
+            #[inline]
+            pub fn div_euclid(self, other: Self) -> Self {
+                let (x1, y1) = self.into();
+                let (x2, y2) = other.into();
+                Self::new(x1.div_euclid(x2), y1.div_euclid(y2))
+            }
+
+            /// Returns the element-wise least nonnegative remainder of `self` divided
+            /// by `other`.
+            ///
+            /// In other words this computes `[x1.rem_euclid(x2), y1.rem_euclid(y2)]`.
+            #[inline]
+            pub fn rem_euclid(self, other: Self) -> Self {
+                let (x1, y1) = self.into();
+                let (x2, y2) = other.into();
+                Self::new(x1.rem_euclid(x2), y1.rem_euclid(y2))
+            }
+        }
+
+        impl AsRef<[$t; 2]> for $vec2 {
+            #[inline]
+            fn as_ref(&self) -> &[$t; 2] {
+                unsafe { &*(self as *const $vec2 as *const [$t; 2]) }
+            }
+        }
+
+        impl AsMut<[$t; 2]> for $vec2 {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [$t; 2] {
+                unsafe { &mut *(self as *mut $vec2 as *mut [$t; 2]) }
+            }
+        }
+
+        impl_sum!($vec2, $vec2::zero());
+        impl_slice_methods!($vec2, $t, 2);
+        impl_vec_index!($vec2, $t, 2);
+    };
+}
+
+macro_rules! impl_vec2_scalar {
+    ($vec2:ident, $t:ty, $mask:ident, $vec3:ident) => {
+        impl $vec2 {
+            /// Creates a new vector.
+            #[inline]
+            pub fn new(x: $t, y: $t) -> Self {
+                Self(x, y)
+            }
+
+            /// Creates a new vector with all elements set to `0.0`.
+            #[inline]
+            pub fn zero() -> Self {
+                Self(0.0, 0.0)
+            }
+
+            /// Creates a new vector with all elements set to `1.0`.
+            #[inline]
+            pub fn one() -> Self {
+                Self(1.0, 1.0)
+            }
+
+            /// Creates a new vector with values `[x: 1.0, y: 0.0]`.
+            #[inline]
+            pub fn unit_x() -> Self {
+                Self(1.0, 0.0)
+            }
+
+            /// Creates a new vector with values `[x: 0.0, y: 1.0]`.
+            #[inline]
+            pub fn unit_y() -> Self {
+                Self(0.0, 1.0)
+            }
+
+            /// Creates a new vector with all elements set to `v`.
+            #[inline]
+            pub fn splat(v: $t) -> Self {
+                Self(v, v)
+            }
+
+            /// Creates a new 3D vector from `self` and the given `z` value.
+            #[inline]
+            pub fn extend(self, z: $t) -> $vec3 {
+                $vec3::new(self.0, self.1, z)
+            }
+
+            /// Returns element `x`.
+            #[inline]
+            pub fn x(self) -> $t {
+                self.0
+            }
+
+            /// Returns element `y`.
+            #[inline]
+            pub fn y(self) -> $t {
+                self.1
+            }
+
+            /// Sets element `x`.
+            #[inline]
+            pub fn set_x(&mut self, x: $t) {
+                self.0 = x;
+            }
+
+            /// Sets element `y`.
+            #[inline]
+            pub fn set_y(&mut self, y: $t) {
+                self.1 = y;
+            }
+
+            /// Returns a vector with all elements set to the value of element `x`.
+            #[inline]
+            pub(crate) fn dup_x(self) -> Self {
+                Self(self.0, self.0)
+            }
+
+            /// Returns a vector with all elements set to the value of element `y`.
+            #[inline]
+            pub(crate) fn dup_y(self) -> Self {
+                Self(self.1, self.1)
+            }
+
+            /// Computes the dot product of `self` and `other`.
+            #[inline]
+            pub fn dot(self, other: Self) -> $t {
+                (self.0 * other.0) + (self.1 * other.1)
+            }
+
+            /// Computes the length of `self`.
+            #[inline]
+            pub fn length(self) -> $t {
+                self.dot(self).sqrt()
+            }
+
+            /// Computes the squared length of `self`.
+            ///
+            /// This is generally faster than `length()` as it avoids a square
+            /// root operation.
+            #[inline]
+            pub fn length_squared(self) -> $t {
+                self.dot(self)
+            }
+
+            /// Computes `1.0 / length()`.
+            ///
+            /// For valid results, `self` must _not_ be of length zero.
+            #[inline]
+            pub fn length_reciprocal(self) -> $t {
+                1.0 / self.length()
+            }
+
+            /// Computes an approximation of `1.0 / length()`.
+            ///
+            /// SIMD vector types use a faster reciprocal square root estimate here, this
+            /// type has none so the result is the same as `length_reciprocal`.
+            ///
+            /// For valid results, `self` must _not_ be of length zero.
+            #[inline]
+            pub fn length_recip(self) -> $t {
+                self.length_reciprocal()
+            }
+
+            /// Returns `self` normalized to length 1.0.
+            ///
+            /// For valid results, `self` must _not_ be of length zero.
+            #[inline]
+            pub fn normalize(self) -> Self {
+                self * self.length_reciprocal()
+            }
+
+            /// Returns the vertical minimum of `self` and `other`.
+            ///
+            /// In other words, this computes
+            /// `[x: min(x1, x2), y: min(y1, y2)]`,
+            /// taking the minimum of each element individually.
+            #[inline]
+            pub fn min(self, other: Self) -> Self {
+                Self(self.0.min(other.0), self.1.min(other.1))
+            }
+
+            /// Returns the vertical maximum of `self` and `other`.
+            ///
+            /// In other words, this computes
+            /// `[x: max(x1, x2), y: max(y1, y2)]`,
+            /// taking the maximum of each element individually.
+            #[inline]
+            pub fn max(self, other: Self) -> Self {
+                Self(self.0.max(other.0), self.1.max(other.1))
+            }
+
+            /// Returns the horizontal minimum of `self`'s elements.
+            ///
+            /// In other words, this computes `min(x, y)`.
+            #[inline]
+            pub fn min_element(self) -> $t {
+                self.0.min(self.1)
+            }
+
+            /// Returns the horizontal maximum of `self`'s elements.
+            ///
+            /// In other words, this computes `max(x, y)`.
+            #[inline]
+            pub fn max_element(self) -> $t {
+                self.0.max(self.1)
+            }
+
+            /// Performs a vertical `==` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 == x2, y1 == y2]`.
+            #[inline]
+            pub fn cmpeq(self, other: Self) -> $mask {
+                $mask::new(self.0.eq(&other.0), self.1.eq(&other.1))
+            }
+
+            /// Performs a vertical `!=` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 != x2, y1 != y2]`.
+            #[inline]
+            pub fn cmpne(self, other: Self) -> $mask {
+                $mask::new(self.0.ne(&other.0), self.1.ne(&other.1))
+            }
+
+            /// Performs a vertical `>=` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 >= x2, y1 >= y2]`.
+            #[inline]
+            pub fn cmpge(self, other: Self) -> $mask {
+                $mask::new(self.0.ge(&other.0), self.1.ge(&other.1))
+            }
+
+            /// Performs a vertical `>` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 > x2, y1 > y2]`.
+            #[inline]
+            pub fn cmpgt(self, other: Self) -> $mask {
+                $mask::new(self.0.gt(&other.0), self.1.gt(&other.1))
+            }
+
+            /// Performs a vertical `<=` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 <= x2, y1 <= y2]`.
+            #[inline]
+            pub fn cmple(self, other: Self) -> $mask {
+                $mask::new(self.0.le(&other.0), self.1.le(&other.1))
+            }
+
+            /// Performs a vertical `<` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 < x2, y1 < y2]`.
+            #[inline]
+            pub fn cmplt(self, other: Self) -> $mask {
+                $mask::new(self.0.lt(&other.0), self.1.lt(&other.1))
+            }
+
+            /// Creates a vector from the elements in `if_true` and `if_false`,
+            /// selecting which to use for each element based on `mask`.
+            ///
+            /// A true element in the mask uses the corresponding element from
+            /// `if_true`, and false uses the element from `if_false`.
+            #[inline]
+            pub fn select(mask: $mask, if_true: Self, if_false: Self) -> Self {
+                let bitmask = mask.bitmask();
+                Self(
+                    if bitmask & 0x1 != 0 {
+                        if_true.0
+                    } else {
+                        if_false.0
+                    },
+                    if bitmask & 0x2 != 0 {
+                        if_true.1
+                    } else {
+                        if_false.1
+                    },
+                )
+            }
+
+            /// Creates a new vector from the first two values in `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than two elements long.
+            #[inline]
+            pub fn from_slice_unaligned(slice: &[$t]) -> Self {
+                Self(slice[0], slice[1])
+            }
+
+            /// Writes the elements of `self` to the first two elements in `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than two elements long.
+            #[inline]
+            pub fn write_to_slice_unaligned(self, slice: &mut [$t]) {
+                slice[0] = self.0;
+                slice[1] = self.1;
+            }
+
+            /// Computes `(self * a) + b` element-wise.
+            ///
+            /// This uses a fused multiply-add instruction, which rounds only once,
+            /// when the target supports it, for example when the `fma` target
+            /// feature is enabled on x86. Otherwise the multiplication and addition
+            /// are rounded separately.
+            #[inline]
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                #[cfg(any(target_feature = "fma", target_arch = "aarch64"))]
+                {
+                    Self(self.0.mul_add(a.0, b.0), self.1.mul_add(a.1, b.1))
+                }
+                #[cfg(not(any(target_feature = "fma", target_arch = "aarch64")))]
+                {
+                    Self((self.0 * a.0) + b.0, (self.1 * a.1) + b.1)
+                }
+            }
+
+            /// Per element negative multiplication/subtraction of the three inputs `-((self * a) - b)`
+            /// This is mathematically equivalent to `b - (self * a)`
+            #[inline]
+            pub(crate) fn neg_mul_sub(self, a: Self, b: Self) -> Self {
+                Self(b.0 - (self.0 * a.0), b.1 - (self.1 * a.1))
+            }
+
+            /// Returns a new vector with elements representing the sign of
+            /// `self`.
+            ///
+            /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+            /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+            #[inline]
+            pub fn sign(self) -> Self {
+                Self(
+                    if self.0 >= 0.0 { 1.0 } else { -1.0 },
+                    if self.1 >= 0.0 { 1.0 } else { -1.0 },
+                )
+            }
+
+            /// Returns a new vector containing the absolute value of each element
+            /// of the original vector.
+            #[inline]
+            pub fn abs(self) -> Self {
+                Self(self.0.abs(), self.1.abs())
+            }
+
+            /// Returns a new vector containing the square root of each element of
+            /// the original vector.
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                Self(self.0.sqrt(), self.1.sqrt())
+            }
+        }
+
+        impl std::fmt::Display for $vec2 {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "[{}, {}]", self.0, self.1)
+            }
+        }
+
+        impl std::ops::Div<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: Self) -> Self {
+                Self(self.0 / other.0, self.1 / other.1)
+            }
+        }
+
+        impl std::ops::DivAssign<$vec2> for $vec2 {
+            #[inline]
+            fn div_assign(&mut self, other: Self) {
+                *self = Self(self.0 / other.0, self.1 / other.1)
+            }
+        }
+
+        impl std::ops::Div<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: $t) -> Self {
+                Self(self.0 / other, self.1 / other)
+            }
+        }
+
+        impl std::ops::DivAssign<$t> for $vec2 {
+            #[inline]
+            fn div_assign(&mut self, other: $t) {
+                *self = Self(self.0 / other, self.1 / other)
+            }
+        }
+
+        impl std::ops::Mul<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                Self(self.0 * other.0, self.1 * other.1)
+            }
+        }
+
+        impl std::ops::MulAssign<$vec2> for $vec2 {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = Self(self.0 * other.0, self.1 * other.1)
+            }
+        }
+
+        impl std::ops::Mul<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: $t) -> Self {
+                Self(self.0 * other, self.1 * other)
+            }
+        }
+
+        impl std::ops::MulAssign<$t> for $vec2 {
+            #[inline]
+            fn mul_assign(&mut self, other: $t) {
+                *self = Self(self.0 * other, self.1 * other)
+            }
+        }
+
+        impl std::ops::Mul<$vec2> for $t {
+            type Output = $vec2;
+            #[inline]
+            fn mul(self, other: $vec2) -> $vec2 {
+                $vec2(self * other.0, self * other.1)
+            }
+        }
+
+        impl std::ops::Add<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0, self.1 + other.1)
+            }
+        }
+
+        impl std::ops::AddAssign<$vec2> for $vec2 {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = Self(self.0 + other.0, self.1 + other.1)
+            }
+        }
+
+        impl std::ops::Sub<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0, self.1 - other.1)
+            }
+        }
+
+        impl std::ops::SubAssign<$vec2> for $vec2 {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = Self(self.0 - other.0, self.1 - other.1)
+            }
+        }
+
+        impl std::ops::Neg for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0, -self.1)
+            }
+        }
+
+        impl From<($t, $t)> for $vec2 {
+            #[inline]
+            fn from(t: ($t, $t)) -> Self {
+                Self(t.0, t.1)
+            }
+        }
+
+        impl From<$vec2> for ($t, $t) {
+            #[inline]
+            fn from(v: $vec2) -> Self {
+                (v.0, v.1)
+            }
+        }
+
+        impl From<[$t; 2]> for $vec2 {
+            #[inline]
+            fn from(a: [$t; 2]) -> Self {
+                Self(a[0], a[1])
+            }
+        }
+
+        impl From<$vec2> for [$t; 2] {
+            #[inline]
+            fn from(v: $vec2) -> Self {
+                [v.0, v.1]
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl rand::distributions::Distribution<$vec2> for rand::distributions::Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $vec2 {
+                rng.gen::<($t, $t)>().into()
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec2_int {
+    ($vec2:ident, $t:ty, $wide:ty, $mask:ident, $vec3:ident) => {
+        impl $vec2 {
+            /// Creates a new vector.
+            #[inline]
+            pub fn new(x: $t, y: $t) -> Self {
+                Self(x, y)
+            }
+
+            /// Creates a new vector with all elements set to `0`.
+            #[inline]
+            pub fn zero() -> Self {
+                Self(0, 0)
+            }
+
+            /// Creates a new vector with all elements set to `1`.
+            #[inline]
+            pub fn one() -> Self {
+                Self(1, 1)
+            }
+
+            /// Creates a new vector with values `[x: 1, y: 0]`.
+            #[inline]
+            pub fn unit_x() -> Self {
+                Self(1, 0)
+            }
+
+            /// Creates a new vector with values `[x: 0, y: 1]`.
+            #[inline]
+            pub fn unit_y() -> Self {
+                Self(0, 1)
+            }
+
+            /// Creates a new vector with all elements set to `v`.
+            #[inline]
+            pub fn splat(v: $t) -> Self {
+                Self(v, v)
+            }
+
+            /// Creates a new 3D vector from `self` and the given `z` value.
+            #[inline]
+            pub fn extend(self, z: $t) -> $vec3 {
+                $vec3::new(self.0, self.1, z)
+            }
+
+            /// Returns element `x`.
+            #[inline]
+            pub fn x(self) -> $t {
+                self.0
+            }
+
+            /// Returns element `y`.
+            #[inline]
+            pub fn y(self) -> $t {
+                self.1
+            }
+
+            /// Sets element `x`.
+            #[inline]
+            pub fn set_x(&mut self, x: $t) {
+                self.0 = x;
+            }
+
+            /// Sets element `y`.
+            #[inline]
+            pub fn set_y(&mut self, y: $t) {
+                self.1 = y;
+            }
+
+            /// Computes the dot product of `self` and `other`.
+            #[inline]
+            pub fn dot(self, other: Self) -> $t {
+                (self.0 * other.0) + (self.1 * other.1)
+            }
+
+            /// Computes the dot product of `self` and `other` in a wider integer type.
+            ///
+            /// Unlike [`Self::dot`] this cannot overflow as long as every element of
+            /// `self` and `other` lies between `MIN / 2` and `MAX / 2` of the element
+            /// type, which makes it suitable for comparing distances on large grids.
+            /// Closer to the limits the sum of the products may still overflow, for
+            /// example when squaring a vector with all elements equal to `i32::MIN`.
+            #[inline]
+            pub fn dot_wide(self, other: Self) -> $wide {
+                (<$wide>::from(self.0) * <$wide>::from(other.0))
+                    + (<$wide>::from(self.1) * <$wide>::from(other.1))
+            }
+
+            /// Returns the point halfway between `self` and `other`, rounded towards
+            /// zero.
+            ///
+            /// The sum is computed in a wider type, so unlike `(self + other) / 2` this
+            /// never overflows.
+            #[inline]
+            pub fn midpoint(self, other: Self) -> Self {
+                Self(
+                    ((<$wide>::from(self.0) + <$wide>::from(other.0)) / 2) as $t,
+                    ((<$wide>::from(self.1) + <$wide>::from(other.1)) / 2) as $t,
+                )
+            }
+
+            /// Computes the dot product of `self` and `other`, wrapping around at the
+            /// boundary of the element type.
+            #[inline]
+            pub fn wrapping_dot(self, other: Self) -> $t {
+                self.0
+                    .wrapping_mul(other.0)
+                    .wrapping_add(self.1.wrapping_mul(other.1))
+            }
+
+            /// Computes the squared length of `self` in a wider integer type.
+            ///
+            /// See [`Self::dot_wide`] for overflow behaviour.
+            #[inline]
+            pub fn length_squared(self) -> $wide {
+                self.dot_wide(self)
+            }
+
+            /// Computes the squared length of `self`, wrapping around at the boundary
+            /// of the element type.
+            #[inline]
+            pub fn wrapping_length_squared(self) -> $t {
+                self.wrapping_dot(self)
+            }
+
+            /// Computes the Manhattan (L1) distance between `self` and `other`, which is
+            /// the sum of the absolute differences of each element.
+            ///
+            /// The result is computed in a wider integer type, so this never overflows.
+            #[inline]
+            pub fn manhattan_distance(self, other: Self) -> $wide {
+                let (hi, lo) = (self.max(other), self.min(other));
+                (<$wide>::from(hi.0) - <$wide>::from(lo.0))
+                    + (<$wide>::from(hi.1) - <$wide>::from(lo.1))
+            }
+
+            /// Computes the Chebyshev (L∞) distance between `self` and `other`, which is
+            /// the largest absolute difference of any element.
+            ///
+            /// The result is computed in a wider integer type, so this never overflows.
+            #[inline]
+            pub fn chebyshev_distance(self, other: Self) -> $wide {
+                let (hi, lo) = (self.max(other), self.min(other));
+                (<$wide>::from(hi.0) - <$wide>::from(lo.0))
+                    .max(<$wide>::from(hi.1) - <$wide>::from(lo.1))
+            }
+
+            /// Computes the Manhattan (L1) length of `self`.
+            #[inline]
+            pub fn manhattan_length(self) -> $wide {
+                self.manhattan_distance(Self::zero())
+            }
+
+            /// Computes the Chebyshev (L∞) length of `self`.
+            #[inline]
+            pub fn chebyshev_length(self) -> $wide {
+                self.chebyshev_distance(Self::zero())
+            }
+
+            /// Returns the vertical minimum of `self` and `other`.
+            ///
+            /// In other words, this computes
+            /// `[x: min(x1, x2), y: min(y1, y2)]`,
+            /// taking the minimum of each element individually.
+            #[inline]
+            pub fn min(self, other: Self) -> Self {
+                Self(self.0.min(other.0), self.1.min(other.1))
+            }
+
+            /// Returns the vertical maximum of `self` and `other`.
+            ///
+            /// In other words, this computes
+            /// `[x: max(x1, x2), y: max(y1, y2)]`,
+            /// taking the maximum of each element individually.
+            #[inline]
+            pub fn max(self, other: Self) -> Self {
+                Self(self.0.max(other.0), self.1.max(other.1))
+            }
+
+            /// Component-wise clamping of values, similar to the scalar `clamp`.
+            ///
+            /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+                self.max(min).min(max)
+            }
+
+            /// Returns a vector containing the minimum of each element of `self` and
+            /// `min`.
+            #[inline]
+            pub fn min_scalar(self, min: $t) -> Self {
+                self.min(Self::splat(min))
+            }
+
+            /// Returns a vector containing the maximum of each element of `self` and
+            /// `max`.
+            #[inline]
+            pub fn max_scalar(self, max: $t) -> Self {
+                self.max(Self::splat(max))
+            }
+
+            /// Clamps every element of `self` to the range `[min, max]`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+            #[inline]
+            pub fn clamp_scalar(self, min: $t, max: $t) -> Self {
+                glam_assert!(min <= max, "clamp_scalar: expected min <= max");
+                self.max(Self::splat(min)).min(Self::splat(max))
+            }
+
+            /// Returns the horizontal minimum of `self`'s elements.
+            ///
+            /// In other words, this computes `min(x, y)`.
+            #[inline]
+            pub fn min_element(self) -> $t {
+                self.0.min(self.1)
+            }
+
+            /// Returns the horizontal maximum of `self`'s elements.
+            ///
+            /// In other words, this computes `max(x, y)`.
+            #[inline]
+            pub fn max_element(self) -> $t {
+                self.0.max(self.1)
+            }
+
+            /// Performs a vertical `==` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 == x2, y1 == y2]`.
+            #[inline]
+            pub fn cmpeq(self, other: Self) -> $mask {
+                $mask::new(self.0.eq(&other.0), self.1.eq(&other.1))
+            }
+
+            /// Performs a vertical `!=` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 != x2, y1 != y2]`.
+            #[inline]
+            pub fn cmpne(self, other: Self) -> $mask {
+                $mask::new(self.0.ne(&other.0), self.1.ne(&other.1))
+            }
+
+            /// Performs a vertical `>=` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 >= x2, y1 >= y2]`.
+            #[inline]
+            pub fn cmpge(self, other: Self) -> $mask {
+                $mask::new(self.0.ge(&other.0), self.1.ge(&other.1))
+            }
+
+            /// Performs a vertical `>` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 > x2, y1 > y2]`.
+            #[inline]
+            pub fn cmpgt(self, other: Self) -> $mask {
+                $mask::new(self.0.gt(&other.0), self.1.gt(&other.1))
+            }
+
+            /// Performs a vertical `<=` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 <= x2, y1 <= y2]`.
+            #[inline]
+            pub fn cmple(self, other: Self) -> $mask {
+                $mask::new(self.0.le(&other.0), self.1.le(&other.1))
+            }
+
+            /// Performs a vertical `<` comparison between `self` and `other`,
+            /// returning a boolean vector mask of the results.
+            ///
+            /// In other words, this computes `[x1 < x2, y1 < y2]`.
+            #[inline]
+            pub fn cmplt(self, other: Self) -> $mask {
+                $mask::new(self.0.lt(&other.0), self.1.lt(&other.1))
+            }
+
+            /// Creates a vector from the elements in `if_true` and `if_false`,
+            /// selecting which to use for each element based on `mask`.
+            ///
+            /// A true element in the mask uses the corresponding element from
+            /// `if_true`, and false uses the element from `if_false`.
+            #[inline]
+            pub fn select(mask: $mask, if_true: Self, if_false: Self) -> Self {
+                let bitmask = mask.bitmask();
+                Self(
+                    if bitmask & 0x1 != 0 {
+                        if_true.0
+                    } else {
+                        if_false.0
+                    },
+                    if bitmask & 0x2 != 0 {
+                        if_true.1
+                    } else {
+                        if_false.1
+                    },
+                )
+            }
+
+            /// Returns a vector containing the wrapping addition of `self` and
+            /// `other`, wrapping around at the boundary of the element type.
+            ///
+            /// In other words this computes `[x1.wrapping_add(x2), y1.wrapping_add(y2)]`.
+            #[inline]
+            pub fn wrapping_add(self, other: Self) -> Self {
+                Self(self.0.wrapping_add(other.0), self.1.wrapping_add(other.1))
+            }
+
+            /// Returns a vector containing the wrapping subtraction of `self` and
+            /// `other`, wrapping around at the boundary of the element type.
+            ///
+            /// In other words this computes `[x1.wrapping_sub(x2), y1.wrapping_sub(y2)]`.
+            #[inline]
+            pub fn wrapping_sub(self, other: Self) -> Self {
+                Self(self.0.wrapping_sub(other.0), self.1.wrapping_sub(other.1))
+            }
+
+            /// Returns a vector containing the wrapping multiplication of `self` and
+            /// `other`, wrapping around at the boundary of the element type.
+            ///
+            /// In other words this computes `[x1.wrapping_mul(x2), y1.wrapping_mul(y2)]`.
+            #[inline]
+            pub fn wrapping_mul(self, other: Self) -> Self {
+                Self(self.0.wrapping_mul(other.0), self.1.wrapping_mul(other.1))
+            }
+
+            /// Returns a vector containing the saturating addition of `self` and
+            /// `other`, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// In other words this computes `[x1.saturating_add(x2), y1.saturating_add(y2)]`.
+            #[inline]
+            pub fn saturating_add(self, other: Self) -> Self {
+                Self(
+                    self.0.saturating_add(other.0),
+                    self.1.saturating_add(other.1),
+                )
+            }
+
+            /// Returns a vector containing the saturating subtraction of `self` and
+            /// `other`, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// In other words this computes `[x1.saturating_sub(x2), y1.saturating_sub(y2)]`.
+            #[inline]
+            pub fn saturating_sub(self, other: Self) -> Self {
+                Self(
+                    self.0.saturating_sub(other.0),
+                    self.1.saturating_sub(other.1),
+                )
+            }
+
+            /// Returns a vector containing the saturating multiplication of `self` and
+            /// `other`, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// In other words this computes `[x1.saturating_mul(x2), y1.saturating_mul(y2)]`.
+            #[inline]
+            pub fn saturating_mul(self, other: Self) -> Self {
+                Self(
+                    self.0.saturating_mul(other.0),
+                    self.1.saturating_mul(other.1),
+                )
+            }
+
+            /// Returns a vector containing the checked addition of `self` and
+            /// `other`, or `None` if overflow occurred for any element.
+            #[inline]
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                Some(Self(
+                    self.0.checked_add(other.0)?,
+                    self.1.checked_add(other.1)?,
+                ))
+            }
+
+            /// Returns a vector containing the checked subtraction of `self` and
+            /// `other`, or `None` if overflow occurred for any element.
+            #[inline]
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(Self(
+                    self.0.checked_sub(other.0)?,
+                    self.1.checked_sub(other.1)?,
+                ))
+            }
+
+            /// Returns a vector containing the checked multiplication of `self` and
+            /// `other`, or `None` if overflow occurred for any element.
+            #[inline]
+            pub fn checked_mul(self, other: Self) -> Option<Self> {
+                Some(Self(
+                    self.0.checked_mul(other.0)?,
+                    self.1.checked_mul(other.1)?,
+                ))
+            }
+
+            /// Returns a vector containing the checked division of `self` and
+            /// `other`, or `None` if overflow occurred or `other` has a zero element for any element.
+            #[inline]
+            pub fn checked_div(self, other: Self) -> Option<Self> {
+                Some(Self(
+                    self.0.checked_div(other.0)?,
+                    self.1.checked_div(other.1)?,
+                ))
+            }
+
+            /// Returns the element-wise quotient of Euclidean division of `self` by
+            /// `other`.
+            ///
+            /// In other words this computes `[x1.div_euclid(x2), y1.div_euclid(y2)]`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any element of `other` is `0` or the division results in
+            /// overflow.
+            #[inline]
+            pub fn div_euclid(self, other: Self) -> Self {
+                Self(self.0.div_euclid(other.0), self.1.div_euclid(other.1))
+            }
+
+            /// Returns the element-wise least nonnegative remainder of `self` divided
+            /// by `other`.
+            ///
+            /// In other words this computes `[x1.rem_euclid(x2), y1.rem_euclid(y2)]`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any element of `other` is `0` or the division results in
+            /// overflow.
+            #[inline]
+            pub fn rem_euclid(self, other: Self) -> Self {
+                Self(self.0.rem_euclid(other.0), self.1.rem_euclid(other.1))
+            }
+
+            /// Creates a new vector from the first two values in `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than two elements long.
+            #[inline]
+            pub fn from_slice_unaligned(slice: &[$t]) -> Self {
+                Self(slice[0], slice[1])
+            }
+
+            /// Writes the elements of `self` to the first two elements in `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is less than two elements long.
+            #[inline]
+            pub fn write_to_slice_unaligned(self, slice: &mut [$t]) {
+                slice[0] = self.0;
+                slice[1] = self.1;
+            }
+        }
+
+        impl AsRef<[$t; 2]> for $vec2 {
+            #[inline]
+            fn as_ref(&self) -> &[$t; 2] {
+                unsafe { &*(self as *const $vec2 as *const [$t; 2]) }
+            }
+        }
+
+        impl AsMut<[$t; 2]> for $vec2 {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [$t; 2] {
+                unsafe { &mut *(self as *mut $vec2 as *mut [$t; 2]) }
+            }
+        }
+
+        impl std::fmt::Display for $vec2 {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "[{}, {}]", self.0, self.1)
+            }
+        }
+
+        impl std::ops::Div<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: Self) -> Self {
+                Self(self.0 / other.0, self.1 / other.1)
+            }
+        }
+
+        impl std::ops::DivAssign<$vec2> for $vec2 {
+            #[inline]
+            fn div_assign(&mut self, other: Self) {
+                *self = Self(self.0 / other.0, self.1 / other.1)
+            }
+        }
+
+        impl std::ops::Div<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: $t) -> Self {
+                Self(self.0 / other, self.1 / other)
+            }
+        }
+
+        impl std::ops::DivAssign<$t> for $vec2 {
+            #[inline]
+            fn div_assign(&mut self, other: $t) {
+                *self = Self(self.0 / other, self.1 / other)
+            }
+        }
+
+        impl std::ops::Div<$vec2> for $t {
+            type Output = $vec2;
+            #[inline]
+            fn div(self, other: $vec2) -> $vec2 {
+                $vec2(self / other.0, self / other.1)
+            }
+        }
+
+        impl std::ops::Mul<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                Self(self.0 * other.0, self.1 * other.1)
+            }
+        }
+
+        impl std::ops::MulAssign<$vec2> for $vec2 {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = Self(self.0 * other.0, self.1 * other.1)
+            }
+        }
+
+        impl std::ops::Mul<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: $t) -> Self {
+                Self(self.0 * other, self.1 * other)
+            }
+        }
+
+        impl std::ops::MulAssign<$t> for $vec2 {
+            #[inline]
+            fn mul_assign(&mut self, other: $t) {
+                *self = Self(self.0 * other, self.1 * other)
+            }
+        }
+
+        impl std::ops::Mul<$vec2> for $t {
+            type Output = $vec2;
+            #[inline]
+            fn mul(self, other: $vec2) -> $vec2 {
+                $vec2(self * other.0, self * other.1)
+            }
+        }
+
+        impl std::ops::Add<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0, self.1 + other.1)
+            }
+        }
+
+        impl std::ops::AddAssign<$vec2> for $vec2 {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = Self(self.0 + other.0, self.1 + other.1)
+            }
+        }
+
+        impl std::ops::Add<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: $t) -> Self {
+                Self(self.0 + other, self.1 + other)
+            }
+        }
+
+        impl std::ops::AddAssign<$t> for $vec2 {
+            #[inline]
+            fn add_assign(&mut self, other: $t) {
+                *self = Self(self.0 + other, self.1 + other)
+            }
+        }
+
+        impl std::ops::Add<$vec2> for $t {
+            type Output = $vec2;
+            #[inline]
+            fn add(self, other: $vec2) -> $vec2 {
+                $vec2(self + other.0, self + other.1)
+            }
+        }
+
+        impl std::ops::Sub<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0, self.1 - other.1)
+            }
+        }
+
+        impl std::ops::SubAssign<$vec2> for $vec2 {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = Self(self.0 - other.0, self.1 - other.1)
+            }
+        }
+
+        impl std::ops::Sub<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: $t) -> Self {
+                Self(self.0 - other, self.1 - other)
+            }
+        }
+
+        impl std::ops::SubAssign<$t> for $vec2 {
+            #[inline]
+            fn sub_assign(&mut self, other: $t) {
+                *self = Self(self.0 - other, self.1 - other)
+            }
+        }
+
+        impl std::ops::Sub<$vec2> for $t {
+            type Output = $vec2;
+            #[inline]
+            fn sub(self, other: $vec2) -> $vec2 {
+                $vec2(self - other.0, self - other.1)
+            }
+        }
+
+        impl std::ops::Rem<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn rem(self, other: Self) -> Self {
+                Self(self.0 % other.0, self.1 % other.1)
+            }
+        }
+
+        impl std::ops::RemAssign<$vec2> for $vec2 {
+            #[inline]
+            fn rem_assign(&mut self, other: Self) {
+                *self = Self(self.0 % other.0, self.1 % other.1)
+            }
+        }
+
+        impl std::ops::Rem<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn rem(self, other: $t) -> Self {
+                Self(self.0 % other, self.1 % other)
+            }
+        }
+
+        impl std::ops::RemAssign<$t> for $vec2 {
+            #[inline]
+            fn rem_assign(&mut self, other: $t) {
+                *self = Self(self.0 % other, self.1 % other)
+            }
+        }
+
+        impl std::ops::Rem<$vec2> for $t {
+            type Output = $vec2;
+            #[inline]
+            fn rem(self, other: $vec2) -> $vec2 {
+                $vec2(self % other.0, self % other.1)
+            }
+        }
+
+        impl std::ops::BitAnd<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, other: Self) -> Self {
+                Self(self.0 & other.0, self.1 & other.1)
+            }
+        }
+
+        impl std::ops::BitAndAssign<$vec2> for $vec2 {
+            #[inline]
+            fn bitand_assign(&mut self, other: Self) {
+                *self = Self(self.0 & other.0, self.1 & other.1)
+            }
+        }
+
+        impl std::ops::BitAnd<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, other: $t) -> Self {
+                Self(self.0 & other, self.1 & other)
+            }
+        }
+
+        impl std::ops::BitAndAssign<$t> for $vec2 {
+            #[inline]
+            fn bitand_assign(&mut self, other: $t) {
+                *self = Self(self.0 & other, self.1 & other)
+            }
+        }
+
+        impl std::ops::BitOr<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, other: Self) -> Self {
+                Self(self.0 | other.0, self.1 | other.1)
+            }
+        }
+
+        impl std::ops::BitOrAssign<$vec2> for $vec2 {
+            #[inline]
+            fn bitor_assign(&mut self, other: Self) {
+                *self = Self(self.0 | other.0, self.1 | other.1)
+            }
+        }
+
+        impl std::ops::BitOr<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, other: $t) -> Self {
+                Self(self.0 | other, self.1 | other)
+            }
+        }
+
+        impl std::ops::BitOrAssign<$t> for $vec2 {
+            #[inline]
+            fn bitor_assign(&mut self, other: $t) {
+                *self = Self(self.0 | other, self.1 | other)
+            }
+        }
+
+        impl std::ops::BitXor<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, other: Self) -> Self {
+                Self(self.0 ^ other.0, self.1 ^ other.1)
+            }
+        }
+
+        impl std::ops::BitXorAssign<$vec2> for $vec2 {
+            #[inline]
+            fn bitxor_assign(&mut self, other: Self) {
+                *self = Self(self.0 ^ other.0, self.1 ^ other.1)
+            }
+        }
+
+        impl std::ops::BitXor<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, other: $t) -> Self {
+                Self(self.0 ^ other, self.1 ^ other)
+            }
+        }
+
+        impl std::ops::BitXorAssign<$t> for $vec2 {
+            #[inline]
+            fn bitxor_assign(&mut self, other: $t) {
+                *self = Self(self.0 ^ other, self.1 ^ other)
+            }
+        }
+
+        impl std::ops::Shl<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn shl(self, other: Self) -> Self {
+                Self(self.0 << other.0, self.1 << other.1)
+            }
+        }
+
+        impl std::ops::ShlAssign<$vec2> for $vec2 {
+            #[inline]
+            fn shl_assign(&mut self, other: Self) {
+                *self = Self(self.0 << other.0, self.1 << other.1)
+            }
+        }
+
+        impl std::ops::Shl<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn shl(self, other: $t) -> Self {
+                Self(self.0 << other, self.1 << other)
+            }
+        }
+
+        impl std::ops::ShlAssign<$t> for $vec2 {
+            #[inline]
+            fn shl_assign(&mut self, other: $t) {
+                *self = Self(self.0 << other, self.1 << other)
+            }
+        }
+
+        impl std::ops::Shr<$vec2> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn shr(self, other: Self) -> Self {
+                Self(self.0 >> other.0, self.1 >> other.1)
+            }
+        }
+
+        impl std::ops::ShrAssign<$vec2> for $vec2 {
+            #[inline]
+            fn shr_assign(&mut self, other: Self) {
+                *self = Self(self.0 >> other.0, self.1 >> other.1)
+            }
+        }
+
+        impl std::ops::Shr<$t> for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn shr(self, other: $t) -> Self {
+                Self(self.0 >> other, self.1 >> other)
+            }
+        }
+
+        impl std::ops::ShrAssign<$t> for $vec2 {
+            #[inline]
+            fn shr_assign(&mut self, other: $t) {
+                *self = Self(self.0 >> other, self.1 >> other)
+            }
+        }
+
+        impl std::ops::Not for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self {
+                Self(!self.0, !self.1)
+            }
+        }
+
+        impl From<($t, $t)> for $vec2 {
+            #[inline]
+            fn from(t: ($t, $t)) -> Self {
+                Self(t.0, t.1)
+            }
+        }
+
+        impl From<$vec2> for ($t, $t) {
+            #[inline]
+            fn from(v: $vec2) -> Self {
+                (v.0, v.1)
+            }
+        }
+
+        impl From<[$t; 2]> for $vec2 {
+            #[inline]
+            fn from(a: [$t; 2]) -> Self {
+                Self(a[0], a[1])
+            }
+        }
+
+        impl From<$vec2> for [$t; 2] {
+            #[inline]
+            fn from(v: $vec2) -> Self {
+                [v.0, v.1]
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl rand::distributions::Distribution<$vec2> for rand::distributions::Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $vec2 {
+                rng.gen::<($t, $t)>().into()
+            }
+        }
+
+        impl_sum!($vec2, $vec2::zero());
+        impl_slice_methods!($vec2, $t, 2);
+        impl_vec_index!($vec2, $t, 2);
+    };
+}
+
+macro_rules! impl_vec2_signed {
+    ($vec2:ident) => {
+        impl $vec2 {
+            /// Returns a new vector containing the absolute value of each element
+            /// of the original vector.
+            #[inline]
+            pub fn abs(self) -> Self {
+                Self(self.0.abs(), self.1.abs())
+            }
+
+            /// Returns a new vector with elements representing the sign of
+            /// `self`.
+            ///
+            /// - `0` if the number is zero
+            /// - `1` if the number is positive
+            /// - `-1` if the number is negative
+            #[inline]
+            pub fn signum(self) -> Self {
+                Self(self.0.signum(), self.1.signum())
+            }
+        }
+
+        impl std::ops::Neg for $vec2 {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0, -self.1)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec2_as {
+    ($vec2:ident, $($as_fn:ident => $other:ident, $u:ty);+ $(;)?) => {
+        impl $vec2 {
+            $(
+                /// Casts all elements of `self` to the element type of the
+                /// returned vector using an `as` cast.
+                ///
+                /// Float to integer casts round towards zero and saturate at the
+                /// bounds of the integer type, with `NaN` converted to `0`.
+                /// Integer to integer casts truncate or sign extend.
+                #[inline]
+                pub fn $as_fn(self) -> $other {
+                    $other::new(self.x() as $u, self.y() as $u)
+                }
+            )+
+        }
+    };
+}
+
+macro_rules! impl_vec2_try_as {
+    ($vec2:ident, $t:ty, $($try_fn:ident => $other:ident, $u:ty);+ $(;)?) => {
+        impl $vec2 {
+            $(
+                /// Casts all elements of `self` to the integer element type of the
+                /// returned vector, rounding towards zero.
+                ///
+                /// Returns `None` if any element is `NaN`, infinite or outside the range
+                /// of the integer type after rounding, where an `as` cast would saturate.
+                #[inline]
+                pub fn $try_fn(self) -> Option<$other> {
+                    // both bounds are zero or powers of two, so they are exact in `$t`
+                    let min = <$u>::MIN as $t;
+                    let max = (<$u>::MAX / 2 + 1) as $t * 2.0;
+                    let in_range = |f: $t| {
+                        let f = f.trunc();
+                        f >= min && f < max
+                    };
+                    if in_range(self.x()) && in_range(self.y()) {
+                        Some($other::new(self.x() as $u, self.y() as $u))
+                    } else {
+                        None
+                    }
+                }
+            )+
+        }
+    };
+}
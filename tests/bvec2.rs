@@ -0,0 +1,84 @@
+use glam::*;
+
+#[test]
+fn test_bvec2_align() {
+    use std::mem;
+    assert_eq!(8, mem::size_of::<BVec2>());
+    assert_eq!(4, mem::align_of::<BVec2>());
+}
+
+#[test]
+fn test_bvec2_new() {
+    assert_eq!(bvec2(false, false).bitmask(), 0b0);
+    assert_eq!(bvec2(true, false).bitmask(), 0b1);
+    assert_eq!(bvec2(false, true).bitmask(), 0b10);
+    assert_eq!(bvec2(true, true).bitmask(), 0b11);
+    assert_eq!(BVec2::splat(true).bitmask(), 0x3);
+    assert_eq!(BVec2::splat(false), BVec2::default());
+}
+
+#[test]
+fn test_bvec2_any_all() {
+    assert!(!BVec2::splat(false).any());
+    assert!(!BVec2::splat(false).all());
+    assert!(bvec2(true, false).any());
+    assert!(!bvec2(true, false).all());
+    assert!(BVec2::splat(true).any());
+    assert!(BVec2::splat(true).all());
+}
+
+#[test]
+fn test_bvec2_ops() {
+    let a = bvec2(true, false);
+    let b = bvec2(true, true);
+    assert_eq!(bvec2(true, false), a & b);
+    assert_eq!(bvec2(true, true), a | b);
+    assert_eq!(bvec2(false, true), a ^ b);
+    assert_eq!(bvec2(false, true), !a);
+
+    let mut c = a;
+    c &= b;
+    assert_eq!(bvec2(true, false), c);
+    c |= a;
+    assert_eq!(bvec2(true, false), c);
+    c ^= b;
+    assert_eq!(bvec2(false, true), c);
+}
+
+#[test]
+fn test_bvec2_fmt() {
+    let a = bvec2(true, false);
+    let s = "true, false";
+    assert_eq!(format!("{:?}", a), format!("BVec2({})", s));
+    assert_eq!(format!("{}", a), format!("[{}]", s));
+}
+
+#[test]
+fn test_bvec2_array() {
+    let a = [true, false];
+    let b = BVec2::from(a);
+    assert_eq!(bvec2(true, false), b);
+    let c: [bool; 2] = b.into();
+    assert_eq!(a, c);
+}
+
+#[test]
+fn test_bvec2_select() {
+    let mask = bvec2(true, false);
+    assert_eq!(
+        vec2(1.0, -2.0),
+        Vec2::select(mask, vec2(1.0, 2.0), vec2(-1.0, -2.0))
+    );
+    assert_eq!(
+        vec2(1.0, -2.0),
+        mask.select(vec2(1.0, 2.0), vec2(-1.0, -2.0))
+    );
+    assert_eq!(
+        dvec2(1.0, -2.0),
+        DVec2::select(mask, dvec2(1.0, 2.0), dvec2(-1.0, -2.0))
+    );
+    assert_eq!(
+        ivec2(1, -2),
+        IVec2::select(mask, ivec2(1, 2), ivec2(-1, -2))
+    );
+}
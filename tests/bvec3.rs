@@ -0,0 +1,93 @@
+use glam::*;
+
+#[test]
+fn test_bvec3_align() {
+    use std::mem;
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(12, mem::size_of::<BVec3>());
+        assert_eq!(4, mem::align_of::<BVec3>());
+    } else {
+        assert_eq!(16, mem::size_of::<BVec3>());
+        assert_eq!(16, mem::align_of::<BVec3>());
+    }
+}
+
+#[test]
+fn test_bvec3_new() {
+    assert_eq!(bvec3(false, false, false).bitmask(), 0b0);
+    assert_eq!(bvec3(true, false, false).bitmask(), 0b1);
+    assert_eq!(bvec3(false, true, false).bitmask(), 0b10);
+    assert_eq!(bvec3(true, true, false).bitmask(), 0b11);
+    assert_eq!(bvec3(false, false, true).bitmask(), 0b100);
+    assert_eq!(bvec3(true, false, true).bitmask(), 0b101);
+    assert_eq!(bvec3(false, true, true).bitmask(), 0b110);
+    assert_eq!(bvec3(true, true, true).bitmask(), 0b111);
+    assert_eq!(BVec3::splat(true).bitmask(), 0x7);
+    assert_eq!(BVec3::splat(false), BVec3::default());
+}
+
+#[test]
+fn test_bvec3_any_all() {
+    assert!(!BVec3::splat(false).any());
+    assert!(!BVec3::splat(false).all());
+    assert!(bvec3(true, false, false).any());
+    assert!(!bvec3(true, false, false).all());
+    assert!(BVec3::splat(true).any());
+    assert!(BVec3::splat(true).all());
+}
+
+#[test]
+fn test_bvec3_ops() {
+    let a = bvec3(true, false, true);
+    let b = bvec3(true, true, false);
+    assert_eq!(bvec3(true, false, false), a & b);
+    assert_eq!(bvec3(true, true, true), a | b);
+    assert_eq!(bvec3(false, true, true), a ^ b);
+    assert_eq!(bvec3(false, true, false), !a);
+
+    let mut c = a;
+    c &= b;
+    assert_eq!(bvec3(true, false, false), c);
+    c |= a;
+    assert_eq!(bvec3(true, false, true), c);
+    c ^= b;
+    assert_eq!(bvec3(false, true, true), c);
+}
+
+#[test]
+fn test_bvec3_fmt() {
+    let a = bvec3(true, false, true);
+    let s = "true, false, true";
+    assert_eq!(format!("{:?}", a), format!("BVec3({})", s));
+    assert_eq!(format!("{}", a), format!("[{}]", s));
+}
+
+#[test]
+fn test_bvec3_array() {
+    let a = [true, false, true];
+    let b = BVec3::from(a);
+    assert_eq!(bvec3(true, false, true), b);
+    let c: [bool; 3] = b.into();
+    assert_eq!(a, c);
+}
+
+#[test]
+fn test_bvec3_select() {
+    let mask = bvec3(true, false, true);
+    assert_eq!(
+        vec3(1.0, -2.0, 3.0),
+        Vec3::select(mask, vec3(1.0, 2.0, 3.0), vec3(-1.0, -2.0, -3.0))
+    );
+    assert_eq!(
+        vec3(1.0, -2.0, 3.0),
+        mask.select(vec3(1.0, 2.0, 3.0), vec3(-1.0, -2.0, -3.0))
+    );
+    assert_eq!(
+        dvec3(1.0, -2.0, 3.0),
+        DVec3::select(mask, dvec3(1.0, 2.0, 3.0), dvec3(-1.0, -2.0, -3.0))
+    );
+    assert_eq!(
+        ivec3(1, -2, 3),
+        IVec3::select(mask, ivec3(1, 2, 3), ivec3(-1, -2, -3))
+    );
+}
@@ -0,0 +1,96 @@
+use glam::*;
+
+#[test]
+fn test_bvec4_align() {
+    use std::mem;
+    assert_eq!(16, mem::size_of::<BVec4>());
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(4, mem::align_of::<BVec4>());
+    } else {
+        assert_eq!(16, mem::align_of::<BVec4>());
+    }
+}
+
+#[test]
+fn test_bvec4_new() {
+    assert_eq!(bvec4(false, false, false, false).bitmask(), 0b0);
+    assert_eq!(bvec4(true, false, false, false).bitmask(), 0b1);
+    assert_eq!(bvec4(false, true, false, false).bitmask(), 0b10);
+    assert_eq!(bvec4(false, false, true, false).bitmask(), 0b100);
+    assert_eq!(bvec4(true, false, true, false).bitmask(), 0b101);
+    assert_eq!(bvec4(false, false, false, true).bitmask(), 0b1000);
+    assert_eq!(bvec4(false, true, false, true).bitmask(), 0b1010);
+    assert_eq!(bvec4(true, true, true, true).bitmask(), 0b1111);
+    assert_eq!(BVec4::splat(true).bitmask(), 0xf);
+    assert_eq!(BVec4::splat(false), BVec4::default());
+}
+
+#[test]
+fn test_bvec4_any_all() {
+    assert!(!BVec4::splat(false).any());
+    assert!(!BVec4::splat(false).all());
+    assert!(bvec4(true, false, false, false).any());
+    assert!(!bvec4(true, false, false, false).all());
+    assert!(BVec4::splat(true).any());
+    assert!(BVec4::splat(true).all());
+}
+
+#[test]
+fn test_bvec4_ops() {
+    let a = bvec4(true, false, true, false);
+    let b = bvec4(true, true, false, false);
+    assert_eq!(bvec4(true, false, false, false), a & b);
+    assert_eq!(bvec4(true, true, true, false), a | b);
+    assert_eq!(bvec4(false, true, true, false), a ^ b);
+    assert_eq!(bvec4(false, true, false, true), !a);
+
+    let mut c = a;
+    c &= b;
+    assert_eq!(bvec4(true, false, false, false), c);
+    c |= a;
+    assert_eq!(bvec4(true, false, true, false), c);
+    c ^= b;
+    assert_eq!(bvec4(false, true, true, false), c);
+}
+
+#[test]
+fn test_bvec4_fmt() {
+    let a = bvec4(true, false, true, false);
+    let s = "true, false, true, false";
+    assert_eq!(format!("{:?}", a), format!("BVec4({})", s));
+    assert_eq!(format!("{}", a), format!("[{}]", s));
+}
+
+#[test]
+fn test_bvec4_array() {
+    let a = [true, false, true, false];
+    let b = BVec4::from(a);
+    assert_eq!(bvec4(true, false, true, false), b);
+    let c: [bool; 4] = b.into();
+    assert_eq!(a, c);
+}
+
+#[test]
+fn test_bvec4_select() {
+    let mask = bvec4(true, false, true, false);
+    assert_eq!(
+        vec4(1.0, -2.0, 3.0, -4.0),
+        Vec4::select(mask, vec4(1.0, 2.0, 3.0, 4.0), vec4(-1.0, -2.0, -3.0, -4.0))
+    );
+    assert_eq!(
+        vec4(1.0, -2.0, 3.0, -4.0),
+        mask.select(vec4(1.0, 2.0, 3.0, 4.0), vec4(-1.0, -2.0, -3.0, -4.0))
+    );
+    assert_eq!(
+        dvec4(1.0, -2.0, 3.0, -4.0),
+        DVec4::select(
+            mask,
+            dvec4(1.0, 2.0, 3.0, 4.0),
+            dvec4(-1.0, -2.0, -3.0, -4.0)
+        )
+    );
+    assert_eq!(
+        ivec4(1, -2, 3, -4),
+        IVec4::select(mask, ivec4(1, 2, 3, 4), ivec4(-1, -2, -3, -4))
+    );
+}
@@ -0,0 +1,162 @@
+mod support;
+
+use glam::f64::*;
+
+const IDENTITY: [[f64; 2]; 2] = [[1.0, 0.0], [0.0, 1.0]];
+
+const MATRIX: [[f64; 2]; 2] = [[1.0, 2.0], [3.0, 4.0]];
+
+const ZERO: [[f64; 2]; 2] = [[0.0; 2]; 2];
+
+#[test]
+fn test_dmat2_align() {
+    use std::mem;
+    assert_eq!(32, mem::size_of::<DMat2>());
+    if cfg!(all(target_feature = "avx", not(feature = "scalar-math"))) {
+        assert_eq!(32, mem::align_of::<DMat2>());
+    } else {
+        assert_eq!(8, mem::align_of::<DMat2>());
+    }
+}
+
+#[test]
+fn test_dmat2_identity() {
+    let identity = DMat2::identity();
+    assert_eq!(IDENTITY, identity.to_cols_array_2d());
+    assert_eq!(DMat2::from_cols_array_2d(&IDENTITY), identity);
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, DMat2::default());
+}
+
+#[test]
+fn test_dmat2_zero() {
+    assert_eq!(DMat2::from_cols_array_2d(&ZERO), DMat2::zero());
+}
+
+#[test]
+fn test_dmat2_accessors() {
+    let mut m = DMat2::zero();
+    m.set_x_axis(DVec2::new(1.0, 2.0));
+    m.set_y_axis(DVec2::new(3.0, 4.0));
+    assert_eq!(DMat2::from_cols_array_2d(&MATRIX), m);
+    assert_eq!(DVec2::new(1.0, 2.0), m.x_axis());
+    assert_eq!(DVec2::new(3.0, 4.0), m.y_axis());
+}
+
+#[test]
+fn test_dmat2_from_axes() {
+    let a = DMat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = DMat2::from_cols(dvec2(1.0, 2.0), dvec2(3.0, 4.0));
+    assert_eq!(a, b);
+    let c = dmat2(dvec2(1.0, 2.0), dvec2(3.0, 4.0));
+    assert_eq!(a, c);
+    let d = b.to_cols_array();
+    let f = DMat2::from_cols_array(&d);
+    assert_eq!(b, f);
+}
+
+#[test]
+fn test_dmat2_mul() {
+    let mat_a = DMat2::from_angle(90.0_f64.to_radians());
+    let res_a = mat_a * DVec2::unit_y();
+    assert_approx_eq!(dvec2(-1.0, 0.0), res_a);
+    let res_b = mat_a * DVec2::unit_x();
+    assert_approx_eq!(dvec2(0.0, 1.0), res_b);
+}
+
+#[test]
+fn test_dmat2_from_scale() {
+    let m = DMat2::from_scale(DVec2::new(2.0, 4.0));
+    assert_approx_eq!(m * DVec2::new(1.0, 1.0), DVec2::new(2.0, 4.0));
+    assert_approx_eq!(DVec2::unit_x() * 2.0, m.x_axis());
+    assert_approx_eq!(DVec2::unit_y() * 4.0, m.y_axis());
+
+    let rot = DMat2::from_scale_angle(DVec2::new(4.0, 2.0), 180.0_f64.to_radians());
+    assert_approx_eq!(DVec2::unit_x() * -4.0, rot * DVec2::unit_x(), 1.0e-6);
+    assert_approx_eq!(DVec2::unit_y() * -2.0, rot * DVec2::unit_y(), 1.0e-6);
+}
+
+#[test]
+fn test_dmat2_transpose() {
+    let m = dmat2(dvec2(1.0, 2.0), dvec2(3.0, 4.0));
+    let mt = m.transpose();
+    assert_eq!(mt.x_axis(), dvec2(1.0, 3.0));
+    assert_eq!(mt.y_axis(), dvec2(2.0, 4.0));
+}
+
+#[test]
+fn test_dmat2_det() {
+    assert_eq!(0.0, DMat2::zero().determinant());
+    assert_eq!(1.0, DMat2::identity().determinant());
+    assert_eq!(1.0, DMat2::from_angle(90.0_f64.to_radians()).determinant());
+    assert_eq!(1.0, DMat2::from_angle(180.0_f64.to_radians()).determinant());
+    assert_eq!(1.0, DMat2::from_angle(270.0_f64.to_radians()).determinant());
+    assert_eq!(2.0 * 2.0, DMat2::from_scale(dvec2(2.0, 2.0)).determinant());
+}
+
+#[test]
+fn test_dmat2_inverse() {
+    let inv = DMat2::identity().inverse();
+    assert_approx_eq!(DMat2::identity(), inv);
+
+    let rot = DMat2::from_angle(90.0_f64.to_radians());
+    let rot_inv = rot.inverse();
+    assert_approx_eq!(DMat2::identity(), rot * rot_inv);
+    assert_approx_eq!(DMat2::identity(), rot_inv * rot);
+
+    let scale = DMat2::from_scale(dvec2(4.0, 5.0));
+    let scale_inv = scale.inverse();
+    assert_approx_eq!(DMat2::identity(), scale * scale_inv);
+    assert_approx_eq!(DMat2::identity(), scale_inv * scale);
+
+    let m = scale * rot;
+    let m_inv = m.inverse();
+    assert_approx_eq!(DMat2::identity(), m * m_inv);
+    assert_approx_eq!(DMat2::identity(), m_inv * m);
+    assert_approx_eq!(m_inv, rot_inv * scale_inv);
+}
+
+#[test]
+fn test_dmat2_ops() {
+    let m0 = DMat2::from_cols_array_2d(&MATRIX);
+    assert_eq!(
+        DMat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]),
+        m0 * 2.0
+    );
+    assert_eq!(
+        DMat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]),
+        2.0 * m0
+    );
+    assert_eq!(
+        DMat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]),
+        m0 + m0
+    );
+    assert_eq!(DMat2::zero(), m0 - m0);
+    assert_eq!(DMat2::zero(), m0 + -m0);
+    assert_eq!(m0 * -1.0, -m0);
+    assert_eq!(m0 * 0.5, m0 / 2.0);
+    let v = dvec2(1.0, 2.0);
+    let (r0, rv) = (&m0, &v);
+    assert_eq!(m0 * m0, r0 * r0);
+    assert_eq!(m0 * m0, m0 * r0);
+    assert_eq!(m0 * m0, r0 * m0);
+    assert_eq!(m0 + m0, r0 + r0);
+    assert_eq!(m0 - m0, r0 - r0);
+    assert_eq!(m0 * v, r0 * rv);
+    assert_eq!(m0 * 2.0, r0 * 2.0);
+    assert_approx_eq!(
+        DMat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]),
+        m0 * DMat2::identity()
+    );
+    assert_approx_eq!(
+        DMat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]),
+        DMat2::identity() * m0
+    );
+}
+
+#[test]
+fn test_dmat2_fmt() {
+    let a = DMat2::from_cols_array_2d(&MATRIX);
+    assert_eq!(format!("{}", a), "[[1, 2], [3, 4]]");
+}
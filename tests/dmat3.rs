@@ -0,0 +1,385 @@
+mod support;
+
+use glam::f64::*;
+
+const IDENTITY: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+const MATRIX: [[f64; 3]; 3] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+const ZERO: [[f64; 3]; 3] = [[0.0; 3]; 3];
+
+#[test]
+fn test_dmat3_align() {
+    use std::mem;
+    assert_eq!(72, mem::size_of::<DMat3>());
+    assert_eq!(8, mem::align_of::<DMat3>());
+}
+
+#[test]
+fn test_dmat3_identity() {
+    let identity = DMat3::identity();
+    assert_eq!(IDENTITY, identity.to_cols_array_2d());
+    assert_eq!(DMat3::from_cols_array_2d(&IDENTITY), identity);
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, DMat3::default());
+}
+
+#[test]
+fn test_dmat3_zero() {
+    assert_eq!(DMat3::from_cols_array_2d(&ZERO), DMat3::zero());
+}
+
+#[test]
+fn test_dmat3_accessors() {
+    let mut m = DMat3::zero();
+    m.set_x_axis(DVec3::new(1.0, 2.0, 3.0));
+    m.set_y_axis(DVec3::new(4.0, 5.0, 6.0));
+    m.set_z_axis(DVec3::new(7.0, 8.0, 9.0));
+    assert_eq!(DMat3::from_cols_array_2d(&MATRIX), m);
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), m.x_axis());
+    assert_eq!(DVec3::new(4.0, 5.0, 6.0), m.y_axis());
+    assert_eq!(DVec3::new(7.0, 8.0, 9.0), m.z_axis());
+}
+
+#[test]
+fn test_dmat3_submatrix() {
+    let m4 = DMat4::from_cols_array(&[
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    ]);
+    let m3 = DMat3::from_mat4(&m4);
+    assert_eq!(
+        DMat3::from_cols_array(&[1.0, 2.0, 3.0, 5.0, 6.0, 7.0, 9.0, 10.0, 11.0]),
+        m3
+    );
+    assert_eq!(
+        DMat4::from_cols_array(&[
+            1.0, 2.0, 3.0, 0.0, 5.0, 6.0, 7.0, 0.0, 9.0, 10.0, 11.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ]),
+        DMat4::from_mat3(&m3)
+    );
+    assert_eq!(
+        DMat2::from_cols_array(&[1.0, 2.0, 5.0, 6.0]),
+        DMat2::from_mat3(&m3)
+    );
+
+    let m2 = DMat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        DMat3::from_cols_array(&[1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 0.0, 0.0, 1.0]),
+        DMat3::from_mat2(&m2)
+    );
+    assert_eq!(m2, DMat2::from_mat3(&DMat3::from_mat2(&m2)));
+    assert_eq!(DMat4::identity(), DMat4::from_mat3(&DMat3::identity()));
+}
+
+#[test]
+fn test_dmat3_from_cross() {
+    let a = DVec3::new(1.0, -2.0, 3.0);
+    let m = DMat3::from_cross(a);
+    for v in [
+        DVec3::unit_x(),
+        DVec3::unit_y(),
+        DVec3::unit_z(),
+        DVec3::new(4.0, 5.0, -6.0),
+    ]
+    .iter()
+    {
+        assert_eq!(a.cross(*v), m * *v);
+    }
+    assert_eq!(-m, m.transpose());
+}
+
+#[test]
+fn test_dmat3_orthonormalize() {
+    let rotation = DMat3::from_quat(DQuat::from_rotation_ypr(0.1, 0.2, 0.3));
+    let drifted = rotation
+        + DMat3::from_cols_array(&[
+            1.0e-3, -2.0e-3, 0.5e-3, 2.0e-3, 1.0e-3, -1.0e-3, -0.5e-3, 1.5e-3, 2.0e-3,
+        ]);
+    let m = drifted.orthonormalize();
+    assert_approx_eq!(DMat3::identity(), m * m.transpose(), 1.0e-12);
+    assert_approx_eq!(1.0, m.determinant(), 1.0e-12);
+    assert_approx_eq!(drifted.x_axis().normalize(), m.x_axis(), 1.0e-12);
+    assert_approx_eq!(rotation, m, 1.0e-2);
+    assert_approx_eq!(rotation, rotation.orthonormalize(), 1.0e-12);
+}
+
+#[test]
+fn test_dmat3_polar_decomposition() {
+    let rotation = DMat3::from_quat(DQuat::from_rotation_ypr(0.1, 0.2, 0.3));
+    let stretch = DMat3::from_cols_array(&[2.0, 0.5, 0.1, 0.5, 1.0, -0.2, 0.1, -0.2, 3.0]);
+    let m = rotation * stretch;
+    let (r, s) = m.polar_decomposition();
+    assert_approx_eq!(rotation, r, 1.0e-12);
+    assert_approx_eq!(stretch, s, 1.0e-12);
+    assert_approx_eq!(m, r * s, 1.0e-12);
+
+    // a reflection stays in the orthogonal part
+    let mirrored = m * DMat3::from_scale(DVec3::new(-1.0, 1.0, 1.0));
+    let (r, s) = mirrored.polar_decomposition();
+    assert_approx_eq!(-1.0, r.determinant(), 1.0e-12);
+    assert_approx_eq!(DMat3::identity(), r * r.transpose(), 1.0e-12);
+    assert_approx_eq!(s, s.transpose(), 1.0e-12);
+    assert_approx_eq!(mirrored, r * s, 1.0e-12);
+}
+
+#[test]
+fn test_dmat3_symmetric_eigen() {
+    let rotation = DMat3::from_quat(DQuat::from_rotation_ypr(0.1, 0.2, 0.3));
+    let m = rotation * DMat3::from_diagonal(DVec3::new(2.0, -1.0, 5.0)) * rotation.transpose();
+    let (values, vectors) = m.symmetric_eigen();
+    assert_approx_eq!(DVec3::new(5.0, 2.0, -1.0), values, 1.0e-12);
+    assert_approx_eq!(1.0, vectors.determinant(), 1.0e-12);
+    assert_approx_eq!(
+        m,
+        vectors * DMat3::from_diagonal(values) * vectors.transpose(),
+        1.0e-12
+    );
+    for (i, value) in values.as_ref().iter().enumerate() {
+        let v = vectors.col(i);
+        assert_approx_eq!(v * *value, m * v, 1.0e-12);
+    }
+    // the eigenvector of the largest eigenvalue is the rotated z axis
+    assert_approx_eq!(1.0, vectors.col(0).dot(rotation.z_axis()).abs(), 1.0e-12);
+
+    // repeated eigenvalues
+    let (values, vectors) = DMat3::from_diagonal(DVec3::new(1.0, 3.0, 1.0)).symmetric_eigen();
+    assert_eq!(DVec3::new(3.0, 1.0, 1.0), values);
+    assert_eq!(DVec3::unit_y(), vectors.col(0).abs());
+    assert_approx_eq!(1.0, vectors.determinant(), 1.0e-12);
+}
+
+#[test]
+fn test_dmat3_svd() {
+    let rotation = DMat3::from_quat(DQuat::from_rotation_ypr(0.1, 0.2, 0.3));
+    for m in [
+        DMat3::from_cols_array(&[1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, 8.0, -9.0]),
+        rotation * DMat3::from_scale(DVec3::new(0.5, -3.0, 2.0)),
+        DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+    ]
+    .iter()
+    {
+        let (u, s, v) = m.svd();
+        assert_approx_eq!(*m, u * DMat3::from_diagonal(s) * v.transpose(), 1.0e-12);
+        assert!(s.x() >= s.y() && s.y() >= s.z() && s.z() >= 0.0);
+        assert_approx_eq!(DMat3::identity(), u * u.transpose(), 1.0e-12);
+        assert_approx_eq!(DMat3::identity(), v * v.transpose(), 1.0e-12);
+    }
+}
+
+#[test]
+fn test_dmat3_scaled_axis() {
+    let v = DVec3::new(0.3, -0.2, 0.5);
+    let m = DMat3::from_scaled_axis(v);
+    assert_approx_eq!(
+        DMat3::from_axis_angle(v.normalize(), v.length()),
+        m,
+        1.0e-12
+    );
+    assert_approx_eq!(v, m.to_scaled_axis(), 1.0e-12);
+    assert_eq!(DVec3::zero(), DMat3::identity().to_scaled_axis());
+
+    // small angles are close to the identity plus the skew-symmetric matrix
+    let v = DVec3::new(1.0e-4, 2.0e-4, -3.0e-4);
+    let m = DMat3::from_scaled_axis(v);
+    assert_approx_eq!(DMat3::identity() + DMat3::from_cross(v), m, 1.0e-7);
+    assert_approx_eq!(v, m.to_scaled_axis(), 1.0e-15);
+
+    // angles close to a half turn
+    let axis = DVec3::new(1.0, 2.0, -2.0) / 3.0;
+    let v = axis * (std::f64::consts::PI - 1.0e-3);
+    assert_approx_eq!(v, DMat3::from_scaled_axis(v).to_scaled_axis(), 1.0e-12);
+}
+
+#[test]
+fn test_dmat3_from_axes() {
+    let a = DMat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = DMat3::from_cols(
+        dvec3(1.0, 2.0, 3.0),
+        dvec3(4.0, 5.0, 6.0),
+        dvec3(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a, b);
+    let c = dmat3(
+        dvec3(1.0, 2.0, 3.0),
+        dvec3(4.0, 5.0, 6.0),
+        dvec3(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a, c);
+    let d = b.to_cols_array();
+    let f = DMat3::from_cols_array(&d);
+    assert_eq!(b, f);
+}
+
+#[test]
+fn test_dmat3_from_rotation() {
+    let rot_x1 = DMat3::from_rotation_x(180.0_f64.to_radians());
+    let rot_x2 = DMat3::from_axis_angle(DVec3::unit_x(), 180.0_f64.to_radians());
+    assert_approx_eq!(rot_x1, rot_x2);
+    let rot_y1 = DMat3::from_rotation_y(180.0_f64.to_radians());
+    let rot_y2 = DMat3::from_axis_angle(DVec3::unit_y(), 180.0_f64.to_radians());
+    assert_approx_eq!(rot_y1, rot_y2);
+    let rot_z1 = DMat3::from_rotation_z(180.0_f64.to_radians());
+    let rot_z2 = DMat3::from_axis_angle(DVec3::unit_z(), 180.0_f64.to_radians());
+    assert_approx_eq!(rot_z1, rot_z2);
+}
+
+#[test]
+fn test_dmat3_mul() {
+    let mat_a = DMat3::from_axis_angle(DVec3::unit_z(), 90.0_f64.to_radians());
+    let result3 = mat_a * DVec3::unit_y();
+    assert_approx_eq!(dvec3(-1.0, 0.0, 0.0), result3);
+}
+
+#[test]
+fn test_dmat3_2d_transform() {
+    let m = DMat3::from_translation(DVec2::new(1.0, 2.0));
+    assert_eq!(
+        DVec2::new(2.0, 4.0),
+        m.transform_point2(DVec2::new(1.0, 2.0))
+    );
+    assert_eq!(
+        DVec2::new(1.0, 2.0),
+        m.transform_vector2(DVec2::new(1.0, 2.0))
+    );
+    let m = DMat3::from_scale_angle_translation(DVec2::new(2.0, 3.0), 0.5, DVec2::new(-4.0, 5.0));
+    let p = DVec2::new(1.0, -1.0);
+    assert_approx_eq!(
+        DMat3::from_translation(DVec2::new(-4.0, 5.0))
+            .transform_point2(DMat3::from_angle(0.5).transform_vector2(p * DVec2::new(2.0, 3.0))),
+        m.transform_point2(p)
+    );
+}
+
+#[test]
+fn test_dmat3_from_scale() {
+    let m = DMat3::from_scale(DVec3::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(m * DVec3::new(1.0, 1.0, 1.0), DVec3::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(DVec3::unit_x() * 2.0, m.x_axis());
+    assert_approx_eq!(DVec3::unit_y() * 4.0, m.y_axis());
+    assert_approx_eq!(DVec3::unit_z() * 8.0, m.z_axis());
+}
+
+#[test]
+fn test_dmat3_transpose() {
+    let m = dmat3(
+        dvec3(1.0, 2.0, 3.0),
+        dvec3(4.0, 5.0, 6.0),
+        dvec3(7.0, 8.0, 9.0),
+    );
+    let mt = m.transpose();
+    assert_eq!(mt.x_axis(), dvec3(1.0, 4.0, 7.0));
+    assert_eq!(mt.y_axis(), dvec3(2.0, 5.0, 8.0));
+    assert_eq!(mt.z_axis(), dvec3(3.0, 6.0, 9.0));
+}
+
+#[test]
+fn test_dmat3_det() {
+    assert_eq!(0.0, DMat3::zero().determinant());
+    assert_eq!(1.0, DMat3::identity().determinant());
+    assert_eq!(
+        1.0,
+        DMat3::from_rotation_x(90.0_f64.to_radians()).determinant()
+    );
+    assert_eq!(
+        1.0,
+        DMat3::from_rotation_y(180.0_f64.to_radians()).determinant()
+    );
+    assert_eq!(
+        1.0,
+        DMat3::from_rotation_z(270.0_f64.to_radians()).determinant()
+    );
+    assert_eq!(
+        2.0 * 2.0 * 2.0,
+        DMat3::from_scale(dvec3(2.0, 2.0, 2.0)).determinant()
+    );
+}
+
+#[test]
+fn test_dmat3_inverse() {
+    // assert_eq!(None, DMat3::zero().inverse());
+    let inv = DMat3::identity().inverse();
+    // assert_ne!(None, inv);
+    assert_approx_eq!(DMat3::identity(), inv);
+
+    let rotz = DMat3::from_rotation_z(90.0_f64.to_radians());
+    let rotz_inv = rotz.inverse();
+    // assert_ne!(None, rotz_inv);
+    // let rotz_inv = rotz_inv.unwrap();
+    assert_approx_eq!(DMat3::identity(), rotz * rotz_inv);
+    assert_approx_eq!(DMat3::identity(), rotz_inv * rotz);
+
+    let scale = DMat3::from_scale(dvec3(4.0, 5.0, 6.0));
+    let scale_inv = scale.inverse();
+    // assert_ne!(None, scale_inv);
+    // let scale_inv = scale_inv.unwrap();
+    assert_approx_eq!(DMat3::identity(), scale * scale_inv);
+    assert_approx_eq!(DMat3::identity(), scale_inv * scale);
+
+    let m = scale * rotz;
+    let m_inv = m.inverse();
+    // assert_ne!(None, m_inv);
+    // let m_inv = m_inv.unwrap();
+    assert_approx_eq!(DMat3::identity(), m * m_inv);
+    assert_approx_eq!(DMat3::identity(), m_inv * m);
+    assert_approx_eq!(m_inv, rotz_inv * scale_inv);
+}
+
+#[test]
+fn test_dmat3_ops() {
+    let m0 = DMat3::from_cols_array_2d(&MATRIX);
+    let m0x2 = DMat3::from_cols_array_2d(&[[2.0, 4.0, 6.0], [8.0, 10.0, 12.0], [14.0, 16.0, 18.0]]);
+    assert_eq!(m0x2, m0 * 2.0);
+    assert_eq!(m0x2, 2.0 * m0);
+    assert_eq!(m0x2, m0 + m0);
+    assert_eq!(DMat3::zero(), m0 - m0);
+    assert_eq!(DMat3::zero(), m0 + -m0);
+    assert_eq!(m0 * -1.0, -m0);
+    assert_eq!(m0 * 0.5, m0 / 2.0);
+    let v = dvec3(1.0, 2.0, 3.0);
+    let (r0, rv) = (&m0, &v);
+    assert_eq!(m0 * m0, r0 * r0);
+    assert_eq!(m0 * m0, m0 * r0);
+    assert_eq!(m0 * m0, r0 * m0);
+    assert_eq!(m0 + m0, r0 + r0);
+    assert_eq!(m0 - m0, r0 - r0);
+    assert_eq!(m0 * v, r0 * rv);
+    assert_eq!(m0 * 2.0, r0 * 2.0);
+    assert_approx_eq!(m0, m0 * DMat3::identity());
+    assert_approx_eq!(m0, DMat3::identity() * m0);
+}
+
+#[test]
+fn test_dmat3_assign_ops() {
+    let m0 = DMat3::from_cols_array_2d(&MATRIX);
+    let mut m = m0;
+    m += m0;
+    assert_eq!(m0 * 2.0, m);
+    m -= m0;
+    assert_eq!(m0, m);
+    m *= 2.0;
+    assert_eq!(m0 * 2.0, m);
+    m /= 2.0;
+    assert_eq!(m0, m);
+    m = m0;
+    m *= m0;
+    assert_eq!(m0 * m0, m);
+}
+
+#[test]
+fn test_dmat3_sum_product() {
+    let m0 = DMat3::from_cols_array_2d(&MATRIX);
+    let ms = [m0, m0, m0];
+    assert_eq!(m0 * 3.0, ms.iter().sum());
+    assert_eq!(m0 * 3.0, ms.iter().copied().sum());
+    assert_eq!(m0 * m0 * m0, ms.iter().product());
+    assert_eq!(m0 * m0 * m0, ms.iter().copied().product());
+    assert_eq!(DMat3::zero(), std::iter::empty::<DMat3>().sum());
+    assert_eq!(DMat3::identity(), std::iter::empty::<DMat3>().product());
+}
+
+#[test]
+fn test_dmat3_fmt() {
+    let a = DMat3::from_cols_array_2d(&MATRIX);
+    assert_eq!(format!("{}", a), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+}
@@ -0,0 +1,430 @@
+mod support;
+
+use glam::f64::*;
+
+const IDENTITY: [[f64; 4]; 4] = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+const MATRIX: [[f64; 4]; 4] = [
+    [1.0, 2.0, 3.0, 4.0],
+    [5.0, 6.0, 7.0, 8.0],
+    [9.0, 10.0, 11.0, 12.0],
+    [13.0, 14.0, 15.0, 16.0],
+];
+
+const ZERO: [[f64; 4]; 4] = [[0.0; 4]; 4];
+
+#[test]
+fn test_dmat4_align() {
+    use std::mem;
+    assert_eq!(128, mem::size_of::<DMat4>());
+    if cfg!(all(target_feature = "avx", not(feature = "scalar-math"))) {
+        assert_eq!(32, mem::align_of::<DMat4>());
+    } else {
+        assert_eq!(8, mem::align_of::<DMat4>());
+    }
+}
+
+#[test]
+fn test_dmat4_identity() {
+    let identity = DMat4::identity();
+    assert_eq!(IDENTITY, identity.to_cols_array_2d());
+    assert_eq!(DMat4::from_cols_array_2d(&IDENTITY), identity);
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, DMat4::default());
+}
+
+#[test]
+fn test_dmat4_zero() {
+    assert_eq!(DMat4::from_cols_array_2d(&ZERO), DMat4::zero());
+}
+
+#[test]
+fn test_dmat4_accessors() {
+    let mut m = DMat4::zero();
+    m.set_x_axis(DVec4::new(1.0, 2.0, 3.0, 4.0));
+    m.set_y_axis(DVec4::new(5.0, 6.0, 7.0, 8.0));
+    m.set_z_axis(DVec4::new(9.0, 10.0, 11.0, 12.0));
+    m.set_w_axis(DVec4::new(13.0, 14.0, 15.0, 16.0));
+    assert_eq!(DMat4::from_cols_array_2d(&MATRIX), m);
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), m.x_axis());
+    assert_eq!(DVec4::new(5.0, 6.0, 7.0, 8.0), m.y_axis());
+    assert_eq!(DVec4::new(9.0, 10.0, 11.0, 12.0), m.z_axis());
+    assert_eq!(DVec4::new(13.0, 14.0, 15.0, 16.0), m.w_axis());
+}
+
+#[test]
+fn test_dmat4_from_axes() {
+    let a = DMat4::from_cols_array_2d(&[
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = DMat4::from_cols(
+        dvec4(1.0, 2.0, 3.0, 4.0),
+        dvec4(5.0, 6.0, 7.0, 8.0),
+        dvec4(9.0, 10.0, 11.0, 12.0),
+        dvec4(13.0, 14.0, 15.0, 16.0),
+    );
+    assert_eq!(a, b);
+    let c = dmat4(
+        dvec4(1.0, 2.0, 3.0, 4.0),
+        dvec4(5.0, 6.0, 7.0, 8.0),
+        dvec4(9.0, 10.0, 11.0, 12.0),
+        dvec4(13.0, 14.0, 15.0, 16.0),
+    );
+    assert_eq!(a, c);
+    let d = b.to_cols_array();
+    let f = DMat4::from_cols_array(&d);
+    assert_eq!(b, f);
+}
+
+#[test]
+fn test_dmat4_translation() {
+    let translate = DMat4::from_translation(dvec3(1.0, 2.0, 3.0));
+    assert_eq!(
+        DMat4::from_cols(
+            dvec4(1.0, 0.0, 0.0, 0.0),
+            dvec4(0.0, 1.0, 0.0, 0.0),
+            dvec4(0.0, 0.0, 1.0, 0.0),
+            dvec4(1.0, 2.0, 3.0, 1.0)
+        ),
+        translate
+    );
+}
+
+#[test]
+fn test_dmat4_from_rotation() {
+    let rot_x1 = DMat4::from_rotation_x(180.0_f64.to_radians());
+    let rot_x2 = DMat4::from_axis_angle(DVec3::unit_x(), 180.0_f64.to_radians());
+    assert_approx_eq!(rot_x1, rot_x2);
+    let rot_y1 = DMat4::from_rotation_y(180.0_f64.to_radians());
+    let rot_y2 = DMat4::from_axis_angle(DVec3::unit_y(), 180.0_f64.to_radians());
+    assert_approx_eq!(rot_y1, rot_y2);
+    let rot_z1 = DMat4::from_rotation_z(180.0_f64.to_radians());
+    let rot_z2 = DMat4::from_axis_angle(DVec3::unit_z(), 180.0_f64.to_radians());
+    assert_approx_eq!(rot_z1, rot_z2);
+}
+
+#[test]
+fn test_dmat4_transform_slice() {
+    let m = DMat4::from_scale_rotation_translation(
+        DVec3::new(0.5, 1.5, 2.0),
+        DQuat::from_rotation_ypr(0.5, -1.0, 2.0),
+        DVec3::new(1.0, -2.0, 3.0),
+    );
+    let points = [
+        DVec3::zero(),
+        DVec3::unit_x(),
+        DVec3::new(1.0, 2.0, 3.0),
+        DVec3::new(-4.0, 0.5, 10.0),
+    ];
+
+    let mut out = [DVec3::zero(); 4];
+    m.transform_point3_slice(&points, &mut out);
+    let mut in_place = points;
+    m.transform_point3_slice_in_place(&mut in_place);
+    for i in 0..points.len() {
+        assert_approx_eq!(m.transform_point3(points[i]), out[i], 1e-5);
+        assert_eq!(out[i], in_place[i]);
+    }
+
+    m.transform_vector3_slice(&points, &mut out);
+    let mut in_place = points;
+    m.transform_vector3_slice_in_place(&mut in_place);
+    for i in 0..points.len() {
+        assert_approx_eq!(m.transform_vector3(points[i]), out[i], 1e-5);
+        assert_eq!(out[i], in_place[i]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_dmat4_transform_slice_len_mismatch() {
+    let mut out = [DVec3::zero(); 1];
+    DMat4::identity().transform_point3_slice(&[DVec3::zero(); 2], &mut out);
+}
+
+#[test]
+fn test_dmat4_from_scale() {
+    let m = DMat4::from_scale(DVec3::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(
+        m.transform_point3(DVec3::new(1.0, 1.0, 1.0)),
+        DVec3::new(2.0, 4.0, 8.0)
+    );
+    assert_approx_eq!(DVec4::unit_x() * 2.0, m.x_axis());
+    assert_approx_eq!(DVec4::unit_y() * 4.0, m.y_axis());
+    assert_approx_eq!(DVec4::unit_z() * 8.0, m.z_axis());
+    assert_approx_eq!(DVec4::unit_w(), m.w_axis());
+}
+
+#[test]
+fn test_dmat4_transpose() {
+    let m = dmat4(
+        dvec4(1.0, 2.0, 3.0, 4.0),
+        dvec4(5.0, 6.0, 7.0, 8.0),
+        dvec4(9.0, 10.0, 11.0, 12.0),
+        dvec4(13.0, 14.0, 15.0, 16.0),
+    );
+    let mt = m.transpose();
+    assert_eq!(mt.x_axis(), dvec4(1.0, 5.0, 9.0, 13.0));
+    assert_eq!(mt.y_axis(), dvec4(2.0, 6.0, 10.0, 14.0));
+    assert_eq!(mt.z_axis(), dvec4(3.0, 7.0, 11.0, 15.0));
+    assert_eq!(mt.w_axis(), dvec4(4.0, 8.0, 12.0, 16.0));
+}
+
+#[test]
+fn test_dmat4_det() {
+    assert_eq!(0.0, DMat4::zero().determinant());
+    assert_eq!(1.0, DMat4::identity().determinant());
+    assert_eq!(
+        1.0,
+        DMat4::from_rotation_x(90.0_f64.to_radians()).determinant()
+    );
+    assert_eq!(
+        1.0,
+        DMat4::from_rotation_y(180.0_f64.to_radians()).determinant()
+    );
+    assert_eq!(
+        1.0,
+        DMat4::from_rotation_z(270.0_f64.to_radians()).determinant()
+    );
+    assert_eq!(
+        2.0 * 2.0 * 2.0,
+        DMat4::from_scale(dvec3(2.0, 2.0, 2.0)).determinant()
+    );
+}
+
+#[test]
+fn test_dmat4_inverse() {
+    // assert_eq!(None, DMat4::zero().inverse());
+    let inv = DMat4::identity().inverse();
+    // assert_ne!(None, inv);
+    assert_approx_eq!(DMat4::identity(), inv);
+
+    let rotz = DMat4::from_rotation_z(90.0_f64.to_radians());
+    let rotz_inv = rotz.inverse();
+    // assert_ne!(None, rotz_inv);
+    // let rotz_inv = rotz_inv.unwrap();
+    assert_approx_eq!(DMat4::identity(), rotz * rotz_inv);
+    assert_approx_eq!(DMat4::identity(), rotz_inv * rotz);
+
+    let trans = DMat4::from_translation(dvec3(1.0, 2.0, 3.0));
+    let trans_inv = trans.inverse();
+    // assert_ne!(None, trans_inv);
+    // let trans_inv = trans_inv.unwrap();
+    assert_approx_eq!(DMat4::identity(), trans * trans_inv);
+    assert_approx_eq!(DMat4::identity(), trans_inv * trans);
+
+    let scale = DMat4::from_scale(dvec3(4.0, 5.0, 6.0));
+    let scale_inv = scale.inverse();
+    // assert_ne!(None, scale_inv);
+    // let scale_inv = scale_inv.unwrap();
+    assert_approx_eq!(DMat4::identity(), scale * scale_inv);
+    assert_approx_eq!(DMat4::identity(), scale_inv * scale);
+
+    let m = scale * rotz * trans;
+    let m_inv = m.inverse();
+    // assert_ne!(None, m_inv);
+    // let m_inv = m_inv.unwrap();
+    assert_approx_eq!(DMat4::identity(), m * m_inv, 1.0e-5);
+    assert_approx_eq!(DMat4::identity(), m_inv * m, 1.0e-5);
+    assert_approx_eq!(m_inv, trans_inv * rotz_inv * scale_inv, 1.0e-6);
+}
+
+#[test]
+fn test_dmat4_inverse_affine() {
+    let rotation = DQuat::from_rotation_ypr(0.1, 0.2, 0.3);
+    let translation = DVec3::new(1.0, -2.0, 3.0);
+    let rigid = DMat4::from_rotation_translation(rotation, translation);
+    assert!(rigid.is_affine());
+    assert_approx_eq!(rigid.inverse(), rigid.inverse_rigid(), 1.0e-12);
+    assert_approx_eq!(rigid.inverse(), rigid.inverse_affine(), 1.0e-12);
+
+    let m =
+        DMat4::from_scale_rotation_translation(DVec3::new(2.0, -3.0, 4.0), rotation, translation);
+    assert!(m.is_affine());
+    assert_approx_eq!(m.inverse(), m.inverse_affine(), 1.0e-12);
+    assert_approx_eq!(DMat4::identity(), m * m.inverse_affine(), 1.0e-12);
+    assert!(!DMat4::perspective_glu_rh(1.0, 1.5, 0.1, 100.0).is_affine());
+}
+
+#[test]
+fn test_dmat4_normal_matrix() {
+    let rotation = DQuat::from_rotation_z(0.5);
+    let m = DMat4::from_scale_rotation_translation(
+        DVec3::new(2.0, 1.0, 0.5),
+        rotation,
+        DVec3::new(1.0, 2.0, 3.0),
+    );
+    let normal_matrix = m.normal_matrix();
+    assert_approx_eq!(
+        DMat3::from_quat(rotation) * DMat3::from_scale(DVec3::new(0.5, 1.0, 2.0)),
+        normal_matrix,
+        1.0e-12
+    );
+
+    // a transformed normal stays perpendicular to the transformed surface
+    let tangent = DVec3::new(1.0, -1.0, 0.0);
+    let normal = DVec3::new(1.0, 1.0, 1.0);
+    assert_eq!(0.0, tangent.dot(normal));
+    let tangent = m.transform_vector3(tangent);
+    let normal = normal_matrix * normal;
+    assert_approx_eq!(0.0, tangent.dot(normal), 1.0e-12);
+
+    // rotations are unchanged
+    let r = DMat4::from_quat(rotation);
+    assert_approx_eq!(DMat3::from_quat(rotation), r.normal_matrix(), 1.0e-12);
+}
+
+#[test]
+fn test_dmat4_look_at() {
+    let eye = DVec3::new(0.0, 0.0, -5.0);
+    let center = DVec3::new(0.0, 0.0, 0.0);
+    let up = DVec3::new(1.0, 0.0, 0.0);
+    let lh = DMat4::look_at_lh(eye, center, up);
+    let rh = DMat4::look_at_rh(eye, center, up);
+    let point = DVec3::new(1.0, 0.0, 0.0);
+    assert_approx_eq!(lh.transform_point3(point), DVec3::new(0.0, 1.0, 5.0));
+    assert_approx_eq!(rh.transform_point3(point), DVec3::new(0.0, 1.0, -5.0));
+}
+
+#[test]
+fn test_dmat4_look_to() {
+    let eye = DVec3::new(1.0, 2.0, -5.0);
+    let dir = DVec3::new(0.5, -1.0, 2.0);
+    let up = DVec3::unit_y();
+    let lh = DMat4::look_to_lh(eye, dir, up);
+    let rh = DMat4::look_to_rh(eye, dir, up);
+    assert_approx_eq!(DMat4::look_at_lh(eye, eye + dir, up), lh);
+    assert_approx_eq!(DMat4::look_at_rh(eye, eye + dir, up), rh);
+    // the eye is at the origin and the direction is along +z or -z in view space
+    assert_approx_eq!(DVec3::zero(), lh.transform_point3(eye), 1.0e-6);
+    assert_approx_eq!(DVec3::zero(), rh.transform_point3(eye), 1.0e-6);
+    let forward = dir.length();
+    assert_approx_eq!(
+        DVec3::new(0.0, 0.0, forward),
+        lh.transform_vector3(dir),
+        1.0e-6
+    );
+    assert_approx_eq!(
+        DVec3::new(0.0, 0.0, -forward),
+        rh.transform_vector3(dir),
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_dmat4_to_scale_rotation_translation() {
+    let scale = DVec3::new(2.0, -3.0, 4.0);
+    let rotation = DQuat::from_rotation_ypr(0.5, -1.0, 2.0);
+    let translation = DVec3::new(1.0, -2.0, 3.0);
+    let m = DMat4::from_scale_rotation_translation(scale, rotation, translation);
+    let (s, r, t) = m.to_scale_rotation_translation();
+    assert_approx_eq!(DVec3::new(-2.0, 3.0, 4.0), s);
+    assert_approx_eq!(m, DMat4::from_scale_rotation_translation(s, r, t));
+    assert_eq!(translation, t);
+}
+
+#[test]
+fn test_dmat4_project_point3() {
+    let m = DMat4::perspective_glu_rh(90.0_f64.to_radians(), 2.0, 1.0, 10.0);
+    assert_approx_eq!(
+        DVec3::new(0.5, 1.0, 1.0),
+        m.project_point3(DVec3::new(10.0, 10.0, -10.0))
+    );
+    let v = DVec3::new(1.0, -1.0, 0.5);
+    let m = DMat4::from_rotation_translation(DQuat::from_rotation_x(0.5), DVec3::one());
+    assert_approx_eq!(m.transform_point3(v), m.project_point3(v));
+    assert_approx_eq!((m * v.extend(0.0)).truncate(), m.transform_vector3(v));
+}
+
+#[test]
+fn test_dmat4_ops() {
+    let m0 = DMat4::from_cols_array_2d(&MATRIX);
+    let m0x2 = DMat4::from_cols_array_2d(&[
+        [2.0, 4.0, 6.0, 8.0],
+        [10.0, 12.0, 14.0, 16.0],
+        [18.0, 20.0, 22.0, 24.0],
+        [26.0, 28.0, 30.0, 32.0],
+    ]);
+    assert_eq!(m0x2, m0 * 2.0);
+    assert_eq!(m0x2, 2.0 * m0);
+    assert_eq!(m0x2, m0 + m0);
+    assert_eq!(DMat4::zero(), m0 - m0);
+    assert_eq!(DMat4::zero(), m0 + -m0);
+    assert_eq!(m0 * -1.0, -m0);
+    assert_eq!(m0 * 0.5, m0 / 2.0);
+    let v = dvec4(1.0, 2.0, 3.0, 4.0);
+    let (r0, rv) = (&m0, &v);
+    assert_eq!(m0 * m0, r0 * r0);
+    assert_eq!(m0 * m0, m0 * r0);
+    assert_eq!(m0 * m0, r0 * m0);
+    assert_eq!(m0 + m0, r0 + r0);
+    assert_eq!(m0 - m0, r0 - r0);
+    assert_eq!(m0 * v, r0 * rv);
+    assert_eq!(m0 * 2.0, r0 * 2.0);
+    assert_approx_eq!(m0, m0 * DMat4::identity());
+    assert_approx_eq!(m0, DMat4::identity() * m0);
+}
+
+#[test]
+fn test_dmat4_fmt() {
+    let a = DMat4::from_cols_array_2d(&MATRIX);
+    assert_eq!(
+        format!("{}", a),
+        "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]]"
+    );
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx",
+    not(feature = "scalar-math")
+))]
+#[test]
+fn test_dmat4_m256d_cols() {
+    use std::arch::x86_64::*;
+    let m = DMat4::from_cols_array_2d(&MATRIX);
+    let cols: [__m256d; 4] = m.into();
+    assert_eq!(DVec4::from(cols[1]), m.y_axis());
+    assert_eq!(DMat4::from(cols), m);
+
+    let q = DQuat::from_rotation_y(std::f64::consts::FRAC_PI_2);
+    let r: __m256d = q.into();
+    assert_eq!(DQuat::from(r), q);
+}
+
+#[test]
+fn test_dmat4_slice() {
+    #[repr(align(32))]
+    struct Aligned([f64; 20]);
+    let m = DMat4::from_cols_array_2d(&MATRIX);
+    let mut a = Aligned([0.0; 20]);
+    m.write_to_slice_unaligned(&mut a.0[1..]);
+    assert_eq!(&a.0[1..17], &m.to_cols_array()[..]);
+    assert_eq!(DMat4::from_slice_unaligned(&a.0[1..]), m);
+
+    m.write_to_slice_aligned(&mut a.0).unwrap();
+    assert_eq!(&a.0[..16], &m.to_cols_array()[..]);
+    assert_eq!(DMat4::from_slice_aligned(&a.0), Ok(m));
+
+    let align = core::mem::align_of::<DMat4>();
+    if align > core::mem::align_of::<f64>() {
+        let err = DMat4::from_slice_aligned(&a.0[1..]).unwrap_err();
+        assert_eq!(err.required_alignment(), align);
+        assert_eq!(m.write_to_slice_aligned(&mut a.0[1..]), Err(err));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_dmat4_slice_too_short() {
+    DMat4::from_slice_unaligned(&[0.0; 15]);
+}
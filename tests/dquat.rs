@@ -0,0 +1,383 @@
+mod support;
+
+use glam::f64::{dquat, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+use glam::EulerRot;
+
+#[test]
+fn test_dquat_align() {
+    use std::mem;
+    assert_eq!(32, mem::size_of::<DQuat>());
+    if cfg!(all(target_feature = "avx", not(feature = "scalar-math"))) {
+        assert_eq!(32, mem::align_of::<DQuat>());
+    } else {
+        assert_eq!(8, mem::align_of::<DQuat>());
+    }
+}
+
+#[test]
+fn test_dquat_rotation() {
+    let zero = 0.0_f64;
+    let yaw = 30.0_f64.to_radians();
+    let pitch = 60.0_f64.to_radians();
+    let roll = 90.0_f64.to_radians();
+    let y0 = DQuat::from_rotation_y(yaw);
+    let (axis, angle) = y0.to_axis_angle();
+    assert_approx_eq!(axis, DVec3::unit_y(), 1.0e-6);
+    assert_approx_eq!(angle as f32, yaw as f32);
+    let y1 = DQuat::from_rotation_ypr(yaw, zero, zero);
+    assert_approx_eq!(y0, y1);
+    let y2 = DQuat::from_axis_angle(DVec3::unit_y(), yaw);
+    assert_approx_eq!(y0, y2);
+    let y3 = DQuat::from_mat3(&DMat3::from_rotation_y(yaw));
+    assert_approx_eq!(y0, y3);
+    let y4 = DQuat::from_mat3(&DMat3::from_quat(y0));
+    assert_approx_eq!(y0, y4);
+
+    let x0 = DQuat::from_rotation_x(pitch);
+    let (axis, angle) = x0.to_axis_angle();
+    assert_approx_eq!(axis, DVec3::unit_x());
+    assert_approx_eq!(angle as f32, pitch as f32);
+    let x1 = DQuat::from_rotation_ypr(zero, pitch, zero);
+    assert_approx_eq!(x0, x1);
+    let x2 = DQuat::from_axis_angle(DVec3::unit_x(), pitch);
+    assert_approx_eq!(x0, x2);
+    let x3 = DQuat::from_mat4(&DMat4::from_rotation_x(180.0_f64.to_radians()));
+    assert_approx_eq!(DQuat::from_rotation_x(180.0_f64.to_radians()), x3);
+
+    let z0 = DQuat::from_rotation_z(roll);
+    let (axis, angle) = z0.to_axis_angle();
+    assert_approx_eq!(axis, DVec3::unit_z());
+    assert_approx_eq!(angle as f32, roll as f32);
+    let z1 = DQuat::from_rotation_ypr(zero, zero, roll);
+    assert_approx_eq!(z0, z1);
+    let z2 = DQuat::from_axis_angle(DVec3::unit_z(), roll);
+    assert_approx_eq!(z0, z2);
+    let z3 = DQuat::from_mat4(&DMat4::from_rotation_z(roll));
+    assert_approx_eq!(z0, z3);
+
+    let yx0 = y0 * x0;
+    let yx1 = DQuat::from_rotation_ypr(yaw, pitch, zero);
+    assert_approx_eq!(yx0, yx1);
+
+    let yxz0 = y0 * x0 * z0;
+    let yxz1 = DQuat::from_rotation_ypr(yaw, pitch, roll);
+    assert_approx_eq!(yxz0, yxz1);
+
+    // use the conjugate of z0 to remove the rotation from yxz0
+    let yx2 = yxz0 * z0.conjugate();
+    assert_approx_eq!(yx0, yx2);
+
+    let yxz2 = DQuat::from_mat4(&DMat4::from_quat(yxz0));
+    assert_approx_eq!(yxz0, yxz2);
+
+    // if near identity, just returns x axis and 0 rotation
+    let (axis, angle) = DQuat::identity().to_axis_angle();
+    assert_eq!(axis, DVec3::unit_x());
+    assert_eq!(angle, 0.0);
+}
+
+#[test]
+fn test_dquat_from_mat() {
+    let axis = DVec3::new(-0.3, 0.8, 0.2).normalize();
+    for angle in [0.0, 90.0, 179.99, 180.0_f64].iter() {
+        let q = DQuat::from_axis_angle(axis, angle.to_radians());
+        let q3 = DQuat::from_mat3(&DMat3::from_quat(q));
+        assert_approx_eq!(q, if q3.dot(q) < 0.0 { -q3 } else { q3 });
+        let q4 = DQuat::from_mat4(&DMat4::from_quat(q));
+        assert_approx_eq!(q, if q4.dot(q) < 0.0 { -q4 } else { q4 });
+    }
+}
+
+#[test]
+fn test_dquat_new() {
+    let ytheta = 45.0_f64.to_radians();
+    let q0 = DQuat::from_rotation_y(ytheta);
+
+    let t1 = (0.0, (ytheta * 0.5).sin(), 0.0, (ytheta * 0.5).cos());
+    assert_eq!(q0, t1.into());
+    let q1 = DQuat::from(t1);
+    assert_eq!(t1, q1.into());
+
+    assert_eq!(q0, dquat(t1.0, t1.1, t1.2, t1.3));
+
+    let a1 = [0.0, (ytheta * 0.5).sin(), 0.0, (ytheta * 0.5).cos()];
+    assert_eq!(q0, a1.into());
+    let q1 = DQuat::from(a1);
+    let a2: [f64; 4] = q1.into();
+    assert_eq!(a1, a2);
+}
+
+#[test]
+fn test_dquat_mul_vec() {
+    let qrz = DQuat::from_rotation_z(90.0_f64.to_radians());
+    assert_approx_eq!(DVec3::unit_y(), qrz * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_y(), -qrz * DVec3::unit_x());
+    assert_approx_eq!(-DVec3::unit_x(), qrz * DVec3::unit_y());
+    assert_approx_eq!(-DVec3::unit_x(), -qrz * DVec3::unit_y());
+
+    // check vec3 * mat3 is the same
+    let mrz = DMat3::from_quat(qrz);
+    assert_approx_eq!(DVec3::unit_y(), mrz * DVec3::unit_x());
+    assert_approx_eq!(-DVec3::unit_x(), mrz * DVec3::unit_y());
+
+    let qrx = DQuat::from_rotation_x(90.0_f64.to_radians());
+    assert_approx_eq!(DVec3::unit_x(), qrx * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_z(), qrx * DVec3::unit_y());
+
+    let qrxz = qrz * qrx;
+    assert_approx_eq!(DVec3::unit_y(), qrxz * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_z(), qrxz * DVec3::unit_y());
+
+    let mrxz = mrz * DMat3::from_quat(qrx);
+    assert_approx_eq!(DVec3::unit_y(), mrxz * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_z(), mrxz * DVec3::unit_y());
+}
+
+#[test]
+fn test_dquat_funcs() {
+    let q0 = DQuat::from_rotation_ypr(
+        45.0_f64.to_radians(),
+        180.0_f64.to_radians(),
+        90.0_f64.to_radians(),
+    );
+    assert!(q0.is_normalized());
+    assert_approx_eq!(q0.length_squared() as f32, 1.0);
+    assert_approx_eq!(q0.length() as f32, 1.0);
+    assert_approx_eq!(q0.length_reciprocal() as f32, 1.0);
+    assert_approx_eq!(q0, q0.normalize());
+
+    assert_approx_eq!(q0.dot(q0) as f32, 1.0);
+
+    let q1 = DQuat::from(DVec4::from(q0) * 2.0);
+    assert!(!q1.is_normalized());
+    assert_approx_eq!(q1.length_squared() as f32, 4.0, 1.0e-6);
+    assert_approx_eq!(q1.length() as f32, 2.0);
+    assert_approx_eq!(q1.length_reciprocal() as f32, 0.5);
+    assert_approx_eq!(q0, q1.normalize());
+    assert_approx_eq!(q0.dot(q1) as f32, 2.0, 1.0e-6);
+}
+
+#[test]
+fn test_dquat_lerp() {
+    let q0 = DQuat::from_rotation_y(0.0);
+    let q1 = DQuat::from_rotation_y(90.0_f64.to_radians());
+    assert_approx_eq!(q0, q0.lerp(q1, 0.0));
+    assert_approx_eq!(q1, q0.lerp(q1, 1.0));
+    assert_approx_eq!(
+        DQuat::from_rotation_y(45.0_f64.to_radians()),
+        q0.lerp(q1, 0.5)
+    );
+}
+
+#[test]
+fn test_dquat_nlerp() {
+    let q0 = DQuat::from_rotation_y(0.0);
+    let q1 = DQuat::from_rotation_y(90.0_f64.to_radians());
+    assert_approx_eq!(q0, q0.nlerp(q1, 0.0));
+    assert_approx_eq!(q1, q0.nlerp(q1, 1.0));
+    assert_approx_eq!(
+        DQuat::from_rotation_y(45.0_f64.to_radians()),
+        q0.nlerp(-q1, 0.5)
+    );
+    assert!(q0.nlerp(q1, 0.3).is_normalized());
+}
+
+#[test]
+fn test_dquat_slerp_rotate_towards() {
+    let q0 = DQuat::from_rotation_y(0.0);
+    let q1 = DQuat::from_rotation_y(90.0_f64.to_radians());
+    let q30 = DQuat::from_rotation_y(30.0_f64.to_radians());
+    assert_approx_eq!(q30, q0.slerp(q1, 1.0 / 3.0));
+    assert_approx_eq!(q30, q0.slerp(-q1, 1.0 / 3.0));
+    assert_approx_eq!(q30, q0.rotate_towards(q1, 30.0_f64.to_radians()));
+    assert_eq!(q1, q0.rotate_towards(q1, 120.0_f64.to_radians()));
+}
+
+#[test]
+fn test_dquat_from_euler() {
+    let (a, b, c) = (0.5, -1.0, 2.0);
+    let x = DQuat::from_rotation_x(a);
+    let y = DQuat::from_rotation_y(b);
+    let z = DQuat::from_rotation_z(c);
+    assert_approx_eq!(x * y * z, DQuat::from_euler(EulerRot::XYZ, a, b, c));
+    assert_approx_eq!(z * y * x, DQuat::from_euler(EulerRot::XYZEx, a, b, c));
+    assert_approx_eq!(
+        DMat3::from_quat(y * x * z),
+        DMat3::from_euler(EulerRot::YXZ, b, a, c)
+    );
+    assert_approx_eq!(
+        DMat4::from_quat(y * x * z),
+        DMat4::from_euler(EulerRot::YXZ, b, a, c)
+    );
+}
+
+#[test]
+fn test_dquat_to_euler() {
+    let (a, b, c) = (0.5, -1.0, 2.0);
+    for order in [EulerRot::XZY, EulerRot::ZYXEx].iter().copied() {
+        let (a1, b1, c1) = DQuat::from_euler(order, a, b, c).to_euler(order);
+        assert_approx_eq!(a, a1);
+        assert_approx_eq!(b, b1);
+        assert_approx_eq!(c, c1);
+    }
+}
+
+#[test]
+fn test_dquat_swing_twist() {
+    let swing = DQuat::from_rotation_x(0.5);
+    let twist = DQuat::from_rotation_z(-1.5);
+    let (swing1, twist1) = (swing * twist).to_swing_twist(DVec3::unit_z());
+    assert_approx_eq!(swing, swing1);
+    assert_approx_eq!(twist, twist1);
+}
+
+#[test]
+fn test_dquat_ln_exp_powf() {
+    let q = DQuat::from_rotation_ypr(0.5, -1.0, 2.0);
+    assert_approx_eq!(q, q.ln().exp());
+    let q = DQuat::new(1.0, -2.0, 0.5, 3.0);
+    assert_approx_eq!(q, q.ln().exp());
+    let q = DQuat::from_rotation_z(1.5);
+    assert_approx_eq!(DQuat::from_rotation_z(0.5), q.powf(1.0 / 3.0));
+}
+
+#[test]
+fn test_dquat_canonicalize() {
+    let q = DQuat::from_rotation_x(4.0);
+    assert_eq!(-q, q.canonicalize());
+    assert_eq!(-q, (-q).canonicalize());
+    assert!(q.abs_diff_eq_rotation(-q, 0.0));
+    assert!(!q.abs_diff_eq_rotation(q.conjugate(), 1.0e-6));
+}
+
+#[test]
+fn test_dquat_integrate() {
+    let q0 = DQuat::from_rotation_x(0.5);
+    let angular_velocity = DVec3::new(0.0, 0.0, 2.0);
+    let dt = 1.0 / 10000.0;
+    let mut q = q0;
+    for _ in 0..10000 {
+        q = q.integrate(angular_velocity, dt);
+    }
+    assert_approx_eq!(DQuat::from_rotation_z(2.0) * q0, q, 1.0e-6);
+}
+
+#[test]
+fn test_dquat_squad() {
+    let keys: Vec<DQuat> = [0.0, 0.5, 1.0, 1.5_f64]
+        .iter()
+        .map(|angle| DQuat::from_rotation_x(*angle))
+        .collect();
+    let c = DQuat::squad_control_points(&keys);
+    assert_approx_eq!(keys[1], keys[1].squad(c[1], c[2], keys[2], 0.0));
+    assert_approx_eq!(keys[2], keys[1].squad(c[1], c[2], keys[2], 1.0));
+    assert_approx_eq!(
+        DQuat::from_rotation_x(0.75),
+        keys[1].squad(c[1], c[2], keys[2], 0.5)
+    );
+}
+
+#[test]
+fn test_dquat_scaled_axis() {
+    let v = DVec3::new(0.5, -1.0, 0.25);
+    let q = DQuat::from_scaled_axis(v);
+    assert_approx_eq!(DQuat::from_axis_angle(v.normalize(), v.length()), q);
+    assert_approx_eq!(v, q.to_scaled_axis());
+    assert_eq!(DQuat::identity(), DQuat::from_scaled_axis(DVec3::zero()));
+}
+
+#[test]
+fn test_dquat_angle_between() {
+    let q0 = DQuat::from_rotation_y(10.0_f64.to_radians());
+    let q1 = DQuat::from_rotation_y(70.0_f64.to_radians());
+    assert_approx_eq!(60.0_f64.to_radians(), q0.angle_between(q1));
+    assert_approx_eq!(60.0_f64.to_radians(), q0.angle_between(-q1));
+    assert_approx_eq!(0.0, q0.angle_between(q0), 1.0e-7);
+}
+
+#[test]
+fn test_dquat_look_at() {
+    let eye = DVec3::new(1.0, 2.0, 3.0);
+    let center = DVec3::new(-2.0, 0.5, 1.0);
+    let up = DVec3::unit_y();
+    let q = DQuat::look_at_rh(eye, center, up);
+    assert_approx_eq!(DQuat::from_mat4(&DMat4::look_at_rh(eye, center, up)), q);
+    assert_approx_eq!(-DVec3::unit_z(), q * (center - eye).normalize());
+    let q = DQuat::look_to_lh(center - eye, up);
+    assert_approx_eq!(DQuat::from_mat4(&DMat4::look_at_lh(eye, center, up)), q);
+}
+
+#[test]
+fn test_dquat_from_rotation_arc() {
+    let from = DVec3::new(1.0, 2.0, 3.0).normalize();
+    let to = DVec3::new(-3.0, 0.5, 1.0).normalize();
+    let q = DQuat::from_rotation_arc(from, to);
+    assert!(q.is_normalized());
+    assert_approx_eq!(to, q * from);
+    assert_eq!(DQuat::identity(), DQuat::from_rotation_arc(to, to));
+
+    let q = DQuat::from_rotation_arc(from, -from);
+    assert!(q.is_normalized());
+    assert_approx_eq!(-from, q * from);
+
+    let from = DVec2::new(3.0, -1.0).normalize();
+    let to = DVec2::new(-2.0, 5.0).normalize();
+    let q = DQuat::from_rotation_arc_2d(from, to);
+    assert_approx_eq!(to.extend(0.0), q * from.extend(0.0));
+    assert_approx_eq!(
+        DQuat::from_rotation_z(std::f64::consts::PI),
+        DQuat::from_rotation_arc_2d(from, -from)
+    );
+}
+
+#[test]
+fn test_dquat_accumulated_rotation() {
+    // many small incremental rotations should not drift noticeably
+    const STEPS: u32 = 100_000;
+    let step = DQuat::from_rotation_y(std::f64::consts::PI * 2.0 / STEPS as f64);
+    let mut q = DQuat::identity();
+    for _ in 0..STEPS {
+        q = (q * step).normalize();
+    }
+    assert!(q.abs_diff_eq(DQuat::identity(), 1e-9) || q.abs_diff_eq(-DQuat::identity(), 1e-9));
+}
+
+#[test]
+fn test_dquat_fmt() {
+    let a = DQuat::identity();
+    assert_eq!(format!("{:?}", a), "DQuat(0.0, 0.0, 0.0, 1.0)");
+    assert_eq!(format!("{}", a), "[0, 0, 0, 1]");
+}
+
+#[test]
+fn test_dquat_identity() {
+    let identity = DQuat::identity();
+    assert!(identity.is_near_identity());
+    assert!(identity.is_normalized());
+    assert_eq!(identity, DQuat::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(identity, identity * identity);
+    let q = DQuat::from_rotation_ypr(
+        10.0_f64.to_radians(),
+        -10.0_f64.to_radians(),
+        45.0_f64.to_radians(),
+    );
+    assert_eq!(q, q * identity);
+    assert_eq!(q, identity * q);
+    assert_eq!(identity, DQuat::default());
+}
+
+#[test]
+fn test_dquat_slice() {
+    let a: [f64; 4] = DQuat::from_rotation_ypr(
+        30.0_f64.to_radians(),
+        60.0_f64.to_radians(),
+        90.0_f64.to_radians(),
+    )
+    .into();
+    let b = DQuat::from_slice_unaligned(&a);
+    let c: [f64; 4] = b.into();
+    assert_eq!(a, c);
+    let mut d = [0.0, 0.0, 0.0, 0.0];
+    b.write_to_slice_unaligned(&mut d[..]);
+    assert_eq!(a, d);
+}
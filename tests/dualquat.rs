@@ -0,0 +1,131 @@
+#[macro_use]
+mod support;
+
+use glam::*;
+use support::deg;
+
+#[test]
+fn test_dualquat_rotation_translation() {
+    let rotation = Quat::from_rotation_ypr(0.5, -1.0, 2.0);
+    let translation = Vec3::new(1.0, -2.0, 3.0);
+    let dq = DualQuat::from_rotation_translation(rotation, translation);
+    assert!(dq.is_normalized());
+    let (r, t) = dq.to_rotation_translation();
+    assert_eq!(rotation, r);
+    assert_approx_eq!(translation, t, 1.0e-6);
+    assert_eq!(dq, DualQuat::from((rotation, translation)));
+    let (r, t): (Quat, Vec3) = dq.into();
+    assert_eq!(rotation, r);
+    assert_approx_eq!(translation, t, 1.0e-6);
+
+    assert_eq!(DualQuat::identity(), DualQuat::default());
+    assert_approx_eq!(
+        translation,
+        DualQuat::from_translation(translation).translation()
+    );
+    assert_approx_eq!(
+        Vec3::zero(),
+        DualQuat::from_rotation(rotation).translation()
+    );
+}
+
+#[test]
+fn test_dualquat_transform() {
+    let rotation = Quat::from_rotation_y(deg(90.0));
+    let translation = Vec3::new(1.0, 2.0, 3.0);
+    let dq = DualQuat::from_rotation_translation(rotation, translation);
+    let m = Mat4::from_rotation_translation(rotation, translation);
+    let p = Vec3::new(4.0, -5.0, 6.0);
+    assert_approx_eq!(m.transform_point3(p), dq.transform_point3(p), 1.0e-5);
+    assert_approx_eq!(m.transform_vector3(p), dq.transform_vector3(p), 1.0e-5);
+
+    // multiplication applies the right hand side first
+    let dq2 = DualQuat::from_rotation_translation(Quat::from_rotation_x(0.5), -translation);
+    let m2 = Mat4::from_rotation_translation(Quat::from_rotation_x(0.5), -translation);
+    let dq3 = dq * dq2;
+    assert!(dq3.is_normalized());
+    assert_approx_eq!(
+        (m * m2).transform_point3(p),
+        dq3.transform_point3(p),
+        1.0e-5
+    );
+
+    // the conjugate of a unit dual quaternion is its inverse
+    assert_approx_eq!(
+        p,
+        dq.conjugate().transform_point3(dq.transform_point3(p)),
+        1.0e-5
+    );
+}
+
+#[test]
+fn test_dualquat_normalize() {
+    let a = DualQuat::from_rotation_translation(Quat::from_rotation_z(0.5), Vec3::unit_x());
+    let b = DualQuat::from_rotation_translation(Quat::from_rotation_z(1.5), Vec3::unit_y());
+    let blend = a * 0.25 + b * 0.75;
+    assert!(!blend.is_normalized());
+    let blend = blend.normalize();
+    assert!(blend.is_normalized());
+    assert_approx_eq!(
+        Quat::from_rotation_z(0.5).nlerp(Quat::from_rotation_z(1.5), 0.75),
+        blend.real,
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_dualquat_sclerp() {
+    let a = DualQuat::from_rotation_translation(Quat::identity(), Vec3::zero());
+    let b = DualQuat::from_rotation_translation(
+        Quat::from_rotation_z(deg(90.0)),
+        Vec3::new(0.0, 0.0, 2.0),
+    );
+    assert_approx_eq!(a.real, a.sclerp(b, 0.0).real, 1.0e-6);
+    assert_approx_eq!(b.real, a.sclerp(b, 1.0).real, 1.0e-6);
+    assert_approx_eq!(b.translation(), a.sclerp(b, 1.0).translation(), 1.0e-6);
+
+    // a screw motion along the z axis rotates and translates at the same rate
+    let half = a.sclerp(b, 0.5);
+    assert!(half.is_normalized());
+    assert_approx_eq!(Quat::from_rotation_z(deg(45.0)), half.real, 1.0e-6);
+    assert_approx_eq!(Vec3::new(0.0, 0.0, 1.0), half.translation(), 1.0e-6);
+
+    // a rotation about an axis away from the origin moves along an arc
+    let pivot = Vec3::new(1.0, 0.0, 0.0);
+    let rotation = Quat::from_rotation_z(deg(120.0));
+    let c = DualQuat::from_rotation_translation(rotation, pivot - rotation * pivot);
+    let half = a.sclerp(c, 0.5);
+    assert_approx_eq!(
+        Vec3::new(1.0 - deg(60.0).cos(), -deg(60.0).sin(), 0.0),
+        half.transform_point3(Vec3::zero()),
+        1.0e-5
+    );
+
+    // pure translations are interpolated linearly, taking the shortest path
+    let d = DualQuat::from_translation(Vec3::new(2.0, 4.0, 6.0));
+    let d_neg = -d;
+    assert_eq!(DualQuat::new(-d.real, -d.dual), d_neg);
+    assert_approx_eq!(
+        Vec3::new(1.0, 2.0, 3.0),
+        a.sclerp(d, 0.5).translation(),
+        1.0e-6
+    );
+    assert_approx_eq!(
+        Vec3::new(1.0, 2.0, 3.0),
+        a.sclerp(d_neg, 0.5).translation(),
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_dualquat_assign_ops() {
+    let a = DualQuat::from_rotation_translation(Quat::from_rotation_z(0.5), Vec3::unit_x());
+    let b = DualQuat::from_rotation_translation(Quat::from_rotation_x(1.5), Vec3::unit_y());
+    let mut c = a;
+    c *= b;
+    assert_eq!(a * b, c);
+    let mut c = a;
+    c *= 0.25;
+    c += b * 0.75;
+    assert_eq!(a * 0.25 + b * 0.75, c);
+}
@@ -0,0 +1,322 @@
+use glam::*;
+#[cfg(feature = "rand")]
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "rand")]
+use rand_xoshiro::Xoshiro256Plus;
+
+#[test]
+fn test_dvec2_new() {
+    let v = dvec2(1.0, 2.0);
+
+    assert_eq!(v.x(), 1.0);
+    assert_eq!(v.y(), 2.0);
+
+    let t = (1.0, 2.0);
+    let v = DVec2::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1.0, 2.0];
+    let v = DVec2::from(a);
+    let a1: [f64; 2] = v.into();
+    assert_eq!(a, a1);
+
+    let v = DVec2::new(t.0, t.1);
+    assert_eq!(t, v.into());
+
+    assert_eq!(DVec2::new(1.0, 0.0), DVec2::unit_x());
+    assert_eq!(DVec2::new(0.0, 1.0), DVec2::unit_y());
+}
+
+#[test]
+fn test_dvec2_fmt() {
+    let a = DVec2::new(1.0, 2.0);
+    assert_eq!(format!("{:?}", a), "DVec2(1.0, 2.0)");
+    // assert_eq!(format!("{:#?}", a), "DVec2(\n    1.0,\n    2.0\n)");
+    assert_eq!(format!("{}", a), "[1, 2]");
+}
+
+#[test]
+fn test_dvec2_zero() {
+    let v = DVec2::zero();
+    assert_eq!(dvec2(0.0, 0.0), v);
+    assert_eq!(v, DVec2::default());
+}
+
+#[test]
+fn test_dvec2_splat() {
+    let v = DVec2::splat(1.0);
+    assert_eq!(dvec2(1.0, 1.0), v);
+}
+
+#[test]
+fn test_dvec2_accessors() {
+    let mut a = dvec2(0.0, 0.0);
+    a.set_x(1.0);
+    a.set_y(2.0);
+    assert_eq!(1.0, a.x());
+    assert_eq!(2.0, a.y());
+}
+
+#[test]
+fn test_dvec2_funcs() {
+    let x = dvec2(1.0, 0.0);
+    let y = dvec2(0.0, 1.0);
+    assert_eq!(1.0, x.dot(x));
+    assert_eq!(0.0, x.dot(y));
+    assert_eq!(-1.0, x.dot(-x));
+    assert_eq!(4.0, (2.0 * x).length_squared());
+    assert_eq!(9.0, (-3.0 * y).length_squared());
+    assert_eq!(2.0, (-2.0 * x).length());
+    assert_eq!(3.0, (3.0 * y).length());
+    assert_eq!(x, (2.0 * x).normalize());
+    assert_eq!(1.0 * 3.0 + 2.0 * 4.0, dvec2(1.0, 2.0).dot(dvec2(3.0, 4.0)));
+    assert_eq!(2.0 * 2.0 + 3.0 * 3.0, dvec2(2.0, 3.0).length_squared());
+    assert_eq!((2.0_f64 * 2.0 + 3.0 * 3.0).sqrt(), dvec2(2.0, 3.0).length());
+    assert_eq!(
+        1.0 / (2.0_f64 * 2.0 + 3.0 * 3.0).sqrt(),
+        dvec2(2.0, 3.0).length_reciprocal()
+    );
+    assert!(dvec2(2.0, 3.0).normalize().is_normalized());
+    assert_eq!(
+        dvec2(2.0, 3.0) / (2.0_f64 * 2.0 + 3.0 * 3.0).sqrt(),
+        dvec2(2.0, 3.0).normalize()
+    );
+}
+
+#[test]
+fn test_dvec2_mul_add() {
+    assert_eq!(
+        dvec2(1.0, 2.0).mul_add(dvec2(5.0, 6.0), dvec2(-1.0, 0.5)),
+        dvec2(4.0, 12.5)
+    );
+}
+
+#[test]
+fn test_dvec2_ops() {
+    let a = dvec2(1.0, 2.0);
+    assert_eq!(dvec2(2.0, 4.0), (a + a));
+    assert_eq!(dvec2(0.0, 0.0), (a - a));
+    assert_eq!(dvec2(1.0, 4.0), (a * a));
+    assert_eq!(dvec2(2.0, 4.0), (a * 2.0));
+    assert_eq!(dvec2(1.0, 1.0), (a / a));
+    assert_eq!(dvec2(0.5, 1.0), (a / 2.0));
+    assert_eq!(dvec2(-1.0, -2.0), (-a));
+}
+
+#[test]
+fn test_dvec2_assign_ops() {
+    let a = dvec2(1.0, 2.0);
+    let mut b = a;
+    b += a;
+    assert_eq!(dvec2(2.0, 4.0), b);
+    b -= a;
+    assert_eq!(dvec2(1.0, 2.0), b);
+    b *= a;
+    assert_eq!(dvec2(1.0, 4.0), b);
+    b /= a;
+    assert_eq!(dvec2(1.0, 2.0), b);
+    b *= 2.0;
+    assert_eq!(dvec2(2.0, 4.0), b);
+    b /= 2.0;
+    assert_eq!(dvec2(1.0, 2.0), b);
+}
+
+#[test]
+fn test_dvec2_angle() {
+    use std::f64::consts::{FRAC_PI_2, PI};
+    assert!(DVec2::from_angle(0.0).abs_diff_eq(DVec2::unit_x(), 1e-6));
+    assert!(DVec2::from_angle(FRAC_PI_2).abs_diff_eq(DVec2::unit_y(), 1e-6));
+    assert!(DVec2::from_angle(PI).abs_diff_eq(-DVec2::unit_x(), 1e-6));
+    assert!((DVec2::from_angle(-2.5).to_angle() - -2.5).abs() < 1e-6);
+    assert!((DVec2::new(-3.0, -3.0).to_angle() - -0.75 * PI).abs() < 1e-6);
+    assert_eq!(0.0, DVec2::unit_x().to_angle());
+
+    let v = DVec2::new(2.0, 1.0);
+    let r = DVec2::from_angle(FRAC_PI_2);
+    assert!(r.rotate(v).abs_diff_eq(DVec2::new(-1.0, 2.0), 1e-6));
+    assert!(DVec2::from_angle(0.5)
+        .rotate(DVec2::from_angle(1.0))
+        .abs_diff_eq(DVec2::from_angle(1.5), 1e-6));
+    assert!((DVec2::from_angle(0.5).rotate(v).to_angle() - (v.to_angle() + 0.5)).abs() < 1e-6);
+    assert_eq!(v * 2.0, DVec2::new(2.0, 0.0).rotate(v));
+}
+
+#[test]
+fn test_dvec2_angle_between() {
+    use std::f64::consts::{FRAC_PI_2, PI};
+    let x = DVec2::unit_x();
+    let y = DVec2::unit_y();
+    assert!((x.angle_between(y) - FRAC_PI_2).abs() < 1e-6);
+    assert!((y.angle_between(x) + FRAC_PI_2).abs() < 1e-6);
+    assert!((x.angle_between(-x).abs() - PI).abs() < 1e-6);
+    assert_eq!(0.0, x.angle_between(x * 3.0));
+    assert!(
+        (DVec2::from_angle(0.25).angle_between(DVec2::from_angle(2.0) * 5.0) - 1.75).abs() < 1e-6
+    );
+    // nearly parallel vectors keep their precision
+    assert!((DVec2::from_angle(1.0e-4).angle_between(x) + 1.0e-4).abs() < 1e-10);
+}
+
+#[test]
+fn test_dvec2_relative_ulps_eq() {
+    let a = dvec2(1.0e12, 1.0);
+    let b = dvec2(1.0e12 + 1.0, 1.0 + 1.0e-12);
+    assert!(!a.abs_diff_eq(b, 1.0e-6));
+    assert!(a.relative_eq(b, 1.0e-6, 1.0e-11));
+    assert!(!a.relative_eq(b, 1.0e-6, 1.0e-13));
+    let next = |x: f64| f64::from_bits(x.to_bits() + 1);
+    assert!(a.ulps_eq(dvec2(next(1.0e12), next(1.0)), 0.0, 1));
+    assert!(!a.ulps_eq(b, 0.0, 4));
+}
+
+#[test]
+fn test_dvec2_min_max() {
+    let a = dvec2(-1.0, 2.0);
+    let b = dvec2(1.0, -2.0);
+    assert_eq!(dvec2(-1.0, -2.0), a.min(b));
+    assert_eq!(dvec2(-1.0, -2.0), b.min(a));
+    assert_eq!(dvec2(1.0, 2.0), a.max(b));
+    assert_eq!(dvec2(1.0, 2.0), b.max(a));
+}
+
+#[test]
+fn test_dvec2_hmin_hmax() {
+    let a = dvec2(-1.0, 2.0);
+    assert_eq!(-1.0, a.min_element());
+    assert_eq!(2.0, a.max_element());
+}
+
+#[test]
+fn test_dvec2_eq() {
+    let a = dvec2(1.0, 1.0);
+    let b = dvec2(1.0, 2.0);
+    assert!(a.cmpeq(a).all());
+    assert!(b.cmpeq(b).all());
+    assert!(a.cmpne(b).any());
+    assert!(b.cmpne(a).any());
+    assert!(b.cmpeq(a).any());
+}
+
+#[test]
+fn test_dvec2_cmp() {
+    assert!(!BVec2::default().any());
+    assert!(!BVec2::default().all());
+    assert_eq!(BVec2::default().bitmask(), 0x0);
+    let a = dvec2(-1.0, -1.0);
+    let b = dvec2(1.0, 1.0);
+    let c = dvec2(-1.0, -1.0);
+    let d = dvec2(1.0, -1.0);
+    assert_eq!(a.cmplt(a).bitmask(), 0x0);
+    assert_eq!(a.cmplt(b).bitmask(), 0x3);
+    assert_eq!(a.cmplt(d).bitmask(), 0x1);
+    assert_eq!(c.cmple(a).bitmask(), 0x3);
+    assert!(a.cmplt(b).all());
+    assert!(a.cmplt(d).any());
+    assert!(a.cmple(b).all());
+    assert!(a.cmple(a).all());
+    assert!(b.cmpgt(a).all());
+    assert!(b.cmpge(a).all());
+    assert!(b.cmpge(b).all());
+    assert!(!(a.cmpge(d).all()));
+    assert!(c.cmple(c).all());
+    assert!(c.cmpge(c).all());
+}
+
+#[test]
+fn test_dvec2_extend_truncate() {
+    let a = dvec2(1.0, 2.0);
+    let b = a.extend(3.0);
+    assert_eq!(dvec3(1.0, 2.0, 3.0), b);
+}
+
+#[test]
+fn test_dvec2_sign() {
+    assert_eq!(DVec2::zero().sign(), DVec2::one());
+    assert_eq!(-DVec2::zero().sign(), -DVec2::one());
+    assert_eq!(DVec2::one().sign(), DVec2::one());
+    assert_eq!((-DVec2::one()).sign(), -DVec2::one());
+    assert_eq!(DVec2::splat(f64::NEG_INFINITY).sign(), -DVec2::one());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_dvec2_rand() {
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (f64, f64) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: DVec2 = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[test]
+fn test_dvec2_abs() {
+    assert_eq!(DVec2::zero().abs(), DVec2::zero());
+    assert_eq!(DVec2::one().abs(), DVec2::one());
+    assert_eq!((-DVec2::one()).abs(), DVec2::one());
+}
+
+#[test]
+fn test_dvec2_align() {
+    use std::mem;
+    assert_eq!(16, mem::size_of::<DVec2>());
+    assert_eq!(8, mem::align_of::<DVec2>());
+}
+
+#[test]
+fn test_dvec2_as() {
+    let a = DVec2::new(1.0, 2.0);
+    assert_eq!(Vec2::new(1.0, 2.0), a.as_vec2());
+    assert_eq!(I16Vec2::new(1, 2), a.as_i16vec2());
+    assert_eq!(U16Vec2::new(1, 2), a.as_u16vec2());
+    assert_eq!(IVec2::new(1, 2), a.as_ivec2());
+    assert_eq!(UVec2::new(1, 2), a.as_uvec2());
+    assert_eq!(I64Vec2::new(1, 2), a.as_i64vec2());
+    assert_eq!(U64Vec2::new(1, 2), a.as_u64vec2());
+
+    let b = DVec2::new(-1.5, 2.5);
+    assert_eq!(IVec2::new(-1, 2), b.as_ivec2());
+    assert_eq!(UVec2::new(0, 2), b.as_uvec2());
+    assert_eq!(i32::MAX, DVec2::new(1.0e10, 1.0).as_ivec2().x());
+}
+
+#[test]
+fn test_dvec2_try_as() {
+    let a = DVec2::new(1.9, 2.0);
+    assert_eq!(Some(IVec2::new(1, 2)), a.try_as_ivec2());
+    assert_eq!(Some(UVec2::new(1, 2)), a.try_as_uvec2());
+    assert_eq!(Some(U64Vec2::new(1, 2)), a.try_as_u64vec2());
+    // negative elements that truncate to zero are in range
+    assert_eq!(Some(UVec2::new(0, 2)), DVec2::new(-0.5, 2.0).try_as_uvec2());
+    assert_eq!(None, DVec2::new(-1.0, 2.0).try_as_uvec2());
+    assert_eq!(None, DVec2::new(1.9, f64::NAN).try_as_ivec2());
+    assert_eq!(None, DVec2::splat(f64::INFINITY).try_as_i64vec2());
+    assert_eq!(None, DVec2::splat(f64::NEG_INFINITY).try_as_i64vec2());
+    assert_eq!(
+        Some(U16Vec2::splat(u16::MAX)),
+        DVec2::splat(65535.5).try_as_u16vec2()
+    );
+    assert_eq!(None, DVec2::splat(65536.0).try_as_u16vec2());
+    assert_eq!(
+        Some(I16Vec2::splat(i16::MIN)),
+        DVec2::splat(-32768.5).try_as_i16vec2()
+    );
+    assert_eq!(None, DVec2::splat(-32769.0).try_as_i16vec2());
+    assert_eq!(
+        Some(IVec2::splat(i32::MIN)),
+        DVec2::splat(-2147483648.0).try_as_ivec2()
+    );
+    assert_eq!(None, DVec2::splat(2147483648.0).try_as_ivec2());
+    assert_eq!(None, DVec2::splat(4294967296.0).try_as_uvec2());
+    assert_eq!(
+        Some(UVec2::splat(u32::MAX)),
+        DVec2::splat(4294967295.5).try_as_uvec2()
+    );
+}
+
+#[test]
+fn test_dvec2_euclid() {
+    let a = DVec2::new(-7.0, 7.0);
+    let b = DVec2::new(4.0, -4.0);
+    assert_eq!(DVec2::new(-2.0, -1.0), a.div_euclid(b));
+    assert_eq!(DVec2::new(1.0, 3.0), a.rem_euclid(b));
+}
@@ -0,0 +1,544 @@
+mod support;
+
+use glam::*;
+#[cfg(feature = "rand")]
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "rand")]
+use rand_xoshiro::Xoshiro256Plus;
+
+#[test]
+fn test_dvec3_new() {
+    let v = dvec3(1.0, 2.0, 3.0);
+
+    assert_eq!(v.x(), 1.0);
+    assert_eq!(v.y(), 2.0);
+    assert_eq!(v.z(), 3.0);
+
+    let t = (1.0, 2.0, 3.0);
+    let v = DVec3::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1.0, 2.0, 3.0];
+    let v = DVec3::from(a);
+    let a1: [f64; 3] = v.into();
+    assert_eq!(a, a1);
+
+    let v = DVec3::new(t.0, t.1, t.2);
+    assert_eq!(t, v.into());
+
+    assert_eq!(DVec3::new(1.0, 0.0, 0.0), DVec3::unit_x());
+    assert_eq!(DVec3::new(0.0, 1.0, 0.0), DVec3::unit_y());
+    assert_eq!(DVec3::new(0.0, 0.0, 1.0), DVec3::unit_z());
+}
+
+#[test]
+fn test_dvec3_fmt() {
+    let a = DVec3::new(1.0, 2.0, 3.0);
+    assert_eq!(format!("{:?}", a), "DVec3(1.0, 2.0, 3.0)");
+    // assert_eq!(format!("{:#?}", a), "DVec3(\n    1.0,\n    2.0,\n    3.0\n)");
+    assert_eq!(format!("{}", a), "[1, 2, 3]");
+}
+
+#[test]
+fn test_dvec3_zero() {
+    let v = DVec3::zero();
+    assert_eq!((0.0, 0.0, 0.0), v.into());
+    assert_eq!(v, DVec3::default());
+}
+
+#[test]
+fn test_dvec3_splat() {
+    let v = DVec3::splat(1.0);
+    assert_eq!((1.0, 1.0, 1.0), v.into());
+}
+
+#[test]
+fn test_dvec3_accessors() {
+    let mut a = dvec3(0.0, 0.0, 0.0);
+    a.set_x(1.0);
+    a.set_y(2.0);
+    a.set_z(3.0);
+    assert_eq!(1.0, a.x());
+    assert_eq!(2.0, a.y());
+    assert_eq!(3.0, a.z());
+}
+
+#[test]
+fn test_dvec3_funcs() {
+    let x = dvec3(1.0, 0.0, 0.0);
+    let y = dvec3(0.0, 1.0, 0.0);
+    let z = dvec3(0.0, 0.0, 1.0);
+    assert_eq!(1.0, x.dot(x));
+    assert_eq!(0.0, x.dot(y));
+    assert_eq!(-1.0, z.dot(-z));
+    assert_eq!(y, z.cross(x));
+    assert_eq!(z, x.cross(y));
+    assert_eq!(4.0, (2.0 * x).length_squared());
+    assert_eq!(9.0, (-3.0 * y).length_squared());
+    assert_eq!(16.0, (4.0 * z).length_squared());
+    assert_eq!(2.0, (-2.0 * x).length());
+    assert_eq!(3.0, (3.0 * y).length());
+    assert_eq!(4.0, (-4.0 * z).length());
+    assert_eq!(x, (2.0 * x).normalize());
+    assert_eq!(
+        1.0 * 4.0 + 2.0 * 5.0 + 3.0 * 6.0,
+        dvec3(1.0, 2.0, 3.0).dot(dvec3(4.0, 5.0, 6.0))
+    );
+    assert_eq!(
+        DVec3::splat(1.0 * 4.0 + 2.0 * 5.0 + 3.0 * 6.0),
+        dvec3(1.0, 2.0, 3.0).dot_into_vec(dvec3(4.0, 5.0, 6.0))
+    );
+    assert_eq!(
+        2.0 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0,
+        dvec3(2.0, 3.0, 4.0).length_squared()
+    );
+    assert_eq!(
+        (2.0_f64 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0).sqrt(),
+        dvec3(2.0, 3.0, 4.0).length()
+    );
+    assert_eq!(
+        1.0 / (2.0_f64 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0).sqrt(),
+        dvec3(2.0, 3.0, 4.0).length_reciprocal()
+    );
+    assert!(dvec3(2.0, 3.0, 4.0).normalize().is_normalized());
+    assert_approx_eq!(
+        dvec3(2.0, 3.0, 4.0) / (2.0_f64 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0).sqrt(),
+        dvec3(2.0, 3.0, 4.0).normalize()
+    );
+}
+
+#[test]
+fn test_dvec3_outer() {
+    let a = DVec3::new(1.0, 2.0, 3.0);
+    let b = DVec3::new(4.0, 5.0, 6.0);
+    let m = a.outer(b);
+    assert_eq!(DMat3::from_cols(a * 4.0, a * 5.0, a * 6.0), m);
+    // (a * b^T) * c = a * (b . c)
+    let c = DVec3::new(-1.0, 2.0, 0.5);
+    assert_eq!(a * b.dot(c), m * c);
+}
+
+#[test]
+fn test_dvec3_mul_add() {
+    assert_eq!(
+        dvec3(1.0, 2.0, 3.0).mul_add(dvec3(5.0, 6.0, 7.0), dvec3(-1.0, 0.5, 2.0)),
+        dvec3(4.0, 12.5, 23.0)
+    );
+}
+
+#[test]
+fn test_dvec3_ops() {
+    let a = dvec3(1.0, 2.0, 3.0);
+    assert_eq!((2.0, 4.0, 6.0), (a + a).into());
+    assert_eq!((0.0, 0.0, 0.0), (a - a).into());
+    assert_eq!((1.0, 4.0, 9.0), (a * a).into());
+    assert_eq!((2.0, 4.0, 6.0), (a * 2.0).into());
+    assert_eq!((1.0, 1.0, 1.0), (a / a).into());
+    assert_eq!((0.5, 1.0, 1.5), (a / 2.0).into());
+    assert_eq!((-1.0, -2.0, -3.0), (-a).into());
+}
+
+#[test]
+fn test_dvec3_assign_ops() {
+    let a = dvec3(1.0, 2.0, 3.0);
+    let mut b = a;
+    b += a;
+    assert_eq!((2.0, 4.0, 6.0), b.into());
+    b -= a;
+    assert_eq!((1.0, 2.0, 3.0), b.into());
+    b *= a;
+    assert_eq!((1.0, 4.0, 9.0), b.into());
+    b /= a;
+    assert_eq!((1.0, 2.0, 3.0), b.into());
+    b *= 2.0;
+    assert_eq!((2.0, 4.0, 6.0), b.into());
+    b /= 2.0;
+    assert_eq!((1.0, 2.0, 3.0), b.into());
+}
+
+#[test]
+fn test_dvec3_sum() {
+    let a = dvec3(1.0, 2.0, 3.0);
+    let vs = [a, a, a];
+    assert_eq!(dvec3(3.0, 6.0, 9.0), vs.iter().sum());
+    assert_eq!(dvec3(3.0, 6.0, 9.0), vs.iter().copied().sum());
+    assert_eq!(DVec3::zero(), std::iter::empty::<DVec3>().sum());
+}
+
+#[test]
+fn test_dvec3_clamp_length() {
+    let v = dvec3(0.0, 3.0, 4.0);
+    assert_eq!(v, v.clamp_length(1.0, 10.0));
+    assert_eq!(v * 0.4, v.clamp_length(0.0, 2.0));
+    assert_eq!(v * 2.0, v.clamp_length(10.0, 20.0));
+    assert_eq!(v * 0.4, v.clamp_length_max(2.0));
+    assert_eq!(v, v.clamp_length_max(10.0));
+    assert_eq!(v * 2.0, v.clamp_length_min(10.0));
+    assert_eq!(v, v.clamp_length_min(2.0));
+    assert_eq!(DVec3::zero(), v.clamp_length_max(0.0));
+    // a zero vector has no direction to scale along
+    assert_eq!(DVec3::zero(), DVec3::zero().clamp_length(1.0, 2.0));
+    assert_eq!(DVec3::zero(), DVec3::zero().clamp_length_min(1.0));
+}
+
+#[test]
+fn test_dvec3_is_normalized() {
+    let v = dvec3(2.0, 3.0, 4.0).normalize();
+    assert!(v.is_normalized());
+    assert!((v * (1.0 + 1.0e-5)).is_normalized());
+    assert!(!(v * (1.0 + 1.0e-3)).is_normalized());
+    assert!(!(v * (1.0 - 1.0e-3)).is_normalized());
+    assert!(!DVec3::zero().is_normalized());
+}
+
+#[test]
+fn test_dvec3_nan_finite() {
+    let a = dvec3(1.0, f64::NAN, f64::INFINITY);
+    assert!(a.is_nan());
+    assert!(!a.is_finite());
+    assert_eq!(BVec3::new(false, true, false), a.is_nan_mask());
+    assert_eq!(BVec3::new(true, false, false), a.is_finite_mask());
+    assert!(!DVec3::one().is_nan());
+    assert!(DVec3::one().is_finite());
+    assert!(!DVec3::splat(f64::NEG_INFINITY).is_finite_mask().any());
+}
+
+#[test]
+fn test_dvec3_lerp() {
+    let a = dvec3(-1.0, 0.0, 2.0);
+    let b = dvec3(3.0, 4.0, -2.0);
+    assert_eq!(a, a.lerp(b, 0.0));
+    assert_eq!(b, a.lerp(b, 1.0));
+    assert_eq!(dvec3(1.0, 2.0, 0.0), a.lerp(b, 0.5));
+    // the interpolation value is not clamped
+    assert_eq!(dvec3(7.0, 8.0, -6.0), a.lerp(b, 2.0));
+    assert_eq!(dvec3(-5.0, -4.0, 6.0), a.lerp(b, -1.0));
+}
+
+#[test]
+fn test_dvec3_distance() {
+    let a = dvec3(1.0, 2.0, 3.0);
+    let b = dvec3(3.0, 5.0, 9.0);
+    assert_eq!(7.0, a.distance(b));
+    assert_eq!(7.0, b.distance(a));
+    assert_eq!(49.0, a.distance_squared(b));
+    assert_eq!((a - b).length(), a.distance(b));
+    assert_eq!(0.0, a.distance(a));
+}
+
+#[test]
+fn test_dvec3_midpoint() {
+    let a = dvec3(-1.0, 0.0, 2.0);
+    let b = dvec3(3.0, 5.0, 2.0);
+    assert_eq!(dvec3(1.0, 2.5, 2.0), a.midpoint(b));
+    assert_eq!(a.lerp(b, 0.5), a.midpoint(b));
+}
+
+#[test]
+fn test_dvec3_rounding() {
+    let a = dvec3(-1.5, 2.5, -0.25);
+    assert_eq!(dvec3(-2.0, 2.0, -1.0), a.floor());
+    assert_eq!(dvec3(-1.0, 3.0, -0.0), a.ceil());
+    assert_eq!(dvec3(-2.0, 3.0, -0.0), a.round());
+    assert_eq!(dvec3(-1.0, 2.0, -0.0), a.trunc());
+    assert_eq!(dvec3(0.5, 0.5, 0.75), a.fract());
+    assert_eq!(a, a.floor() + a.fract());
+}
+
+#[test]
+fn test_dvec3_exp_pow() {
+    let a = dvec3(4.0, 0.25, 16.0);
+    assert_eq!(dvec3(2.0, 0.5, 4.0), a.sqrt());
+    assert_eq!(dvec3(2.0, -2.0, 4.0), a.log2());
+    assert!(a.log2().exp2().abs_diff_eq(a, 1e-6));
+    assert_eq!(dvec3(0.25, 4.0, 0.0625), a.recip());
+    assert_eq!(a.reciprocal(), a.recip());
+    assert!(a.powf(0.5).abs_diff_eq(a.sqrt(), 1e-6));
+    assert!(a.powf(-1.0).abs_diff_eq(a.recip(), 1e-6));
+    assert!(DVec3::zero().exp().abs_diff_eq(DVec3::one(), 1e-6));
+    assert!(DVec3::one()
+        .exp()
+        .abs_diff_eq(DVec3::splat(std::f64::consts::E), 1e-6));
+}
+
+#[test]
+fn test_dvec3_to_radians_degrees() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    let a = dvec3(90.0, -180.0, 45.0);
+    let b = dvec3(FRAC_PI_2, -PI, FRAC_PI_4);
+    assert!(a.to_radians().abs_diff_eq(b, 1e-6));
+    assert!(b.to_degrees().abs_diff_eq(a, 1e-4));
+    assert_eq!(a.x().to_radians(), a.to_radians().x());
+    assert_eq!(b.y().to_degrees(), b.to_degrees().y());
+}
+
+#[test]
+fn test_dvec3_move_towards() {
+    let a = dvec3(1.0, 1.0, 1.0);
+    let b = dvec3(4.0, 5.0, 1.0);
+    assert!(a
+        .move_towards(b, 1.0)
+        .abs_diff_eq(dvec3(1.6, 1.8, 1.0), 1e-6));
+    assert!(a.move_towards(b, 1.0).abs_diff_eq(a.lerp(b, 0.2), 1e-6));
+    // does not overshoot
+    assert_eq!(b, a.move_towards(b, 5.0));
+    assert_eq!(b, a.move_towards(b, 100.0));
+    assert_eq!(a, a.move_towards(a, 1.0));
+    // a negative distance moves away
+    assert!(a.move_towards(b, -5.0).abs_diff_eq(a.lerp(b, -1.0), 1e-6));
+    // even when very close to the target
+    let c = a + dvec3(1e-5, 0.0, 0.0);
+    assert!(a
+        .move_towards(c, -1.0)
+        .abs_diff_eq(a - dvec3(1.0, 0.0, 0.0), 1e-6));
+    // a zero distance does not move or snap to the target
+    assert_eq!(a, a.move_towards(b, 0.0));
+    assert_eq!(a, a.move_towards(c, 0.0));
+}
+
+#[test]
+fn test_dvec3_angle_between() {
+    use std::f64::consts::{FRAC_PI_2, PI};
+    let x = DVec3::unit_x();
+    let y = DVec3::unit_y();
+    assert!((x.angle_between(y) - FRAC_PI_2).abs() < 1e-6);
+    assert!((y.angle_between(x) - FRAC_PI_2).abs() < 1e-6);
+    assert!((x.angle_between(-x) - PI).abs() < 1e-6);
+    assert_eq!(0.0, x.angle_between(x * 3.0));
+    assert!((dvec3(1.0, 1.0, 0.0).angle_between(dvec3(0.0, 0.0, 2.0)) - FRAC_PI_2).abs() < 1e-6);
+    // nearly parallel vectors keep their precision
+    let a = dvec3(1.0, 1.0e-4, 0.0);
+    assert!((a.angle_between(x) - 1.0e-4).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_dvec3_any_orthonormal() {
+    for v in [
+        DVec3::unit_x(),
+        DVec3::unit_y(),
+        DVec3::unit_z(),
+        -DVec3::unit_z(),
+        dvec3(1.0, -2.0, 3.0).normalize(),
+        dvec3(-1.0, 2.0, -0.5).normalize(),
+        dvec3(0.0, 1.0e-4, -1.0).normalize(),
+    ]
+    .iter()
+    {
+        let v = *v;
+        let (a, b) = v.any_orthonormal_pair();
+        assert!(a.is_normalized() && b.is_normalized());
+        assert!(v.dot(a).abs() < 1e-6);
+        assert!(v.dot(b).abs() < 1e-6);
+        assert!(a.dot(b).abs() < 1e-6);
+        assert!(a.cross(b).abs_diff_eq(v, 1e-6));
+        assert_eq!(b, v.any_orthonormal_vector());
+    }
+}
+
+#[test]
+fn test_dvec3_reflect_refract() {
+    let n = DVec3::unit_y();
+    assert_eq!(dvec3(1.0, 2.0, -3.0), dvec3(1.0, -2.0, -3.0).reflect(n));
+    assert_eq!(dvec3(1.0, 2.0, -3.0), dvec3(1.0, -2.0, -3.0).reflect(-n));
+
+    let i = dvec3(1.0, -1.0, 0.0).normalize();
+    // an index ratio of one does not bend the ray
+    assert!(i.refract(n, 1.0).abs_diff_eq(i, 1e-6));
+    // entering a denser medium bends the ray towards the normal
+    let r = i.refract(n, 1.0 / 1.5);
+    assert!(r.is_normalized());
+    assert!(r.y() < 0.0 && r.x() < i.x());
+    // Snell's law: eta * sin(incident) = sin(refracted)
+    assert!((i.x() / 1.5 - r.x()).abs() < 1e-6);
+    // total internal reflection
+    assert_eq!(DVec3::zero(), i.refract(n, 1.5));
+}
+
+#[test]
+fn test_dvec3_min_max() {
+    let a = dvec3(-1.0, 2.0, -3.0);
+    let b = dvec3(1.0, -2.0, 3.0);
+    assert_eq!((-1.0, -2.0, -3.0), a.min(b).into());
+    assert_eq!((-1.0, -2.0, -3.0), b.min(a).into());
+    assert_eq!((1.0, 2.0, 3.0), a.max(b).into());
+    assert_eq!((1.0, 2.0, 3.0), b.max(a).into());
+}
+
+#[test]
+fn test_dvec3_clamp() {
+    let min = dvec3(1.0, 1.0, -1.0);
+    let max = dvec3(3.0, 3.0, 1.0);
+    let a = dvec3(0.0, 2.0, 4.0);
+    assert_eq!(dvec3(1.0, 2.0, 1.0), a.clamp(min, max));
+    assert_eq!(dvec3(1.0, 2.0, 3.0), a.clamp_scalar(1.0, 3.0));
+    assert_eq!(dvec3(0.0, 2.0, 2.0), a.min_scalar(2.0));
+    assert_eq!(dvec3(0.5, 2.0, 4.0), a.max_scalar(0.5));
+    assert_eq!(a.min(DVec3::splat(2.0)), a.min_scalar(2.0));
+}
+
+#[test]
+fn test_dvec3_hmin_hmax() {
+    let a = dvec3(-1.0, 2.0, -3.0);
+    assert_eq!(-3.0, a.min_element());
+    assert_eq!(2.0, a.max_element());
+}
+
+#[test]
+fn test_dvec3_eq() {
+    let a = dvec3(1.0, 1.0, 1.0);
+    let b = dvec3(1.0, 2.0, 3.0);
+    assert!(a.cmpeq(a).all());
+    assert!(b.cmpeq(b).all());
+    assert!(a.cmpne(b).any());
+    assert!(b.cmpne(a).any());
+    assert!(b.cmpeq(a).any());
+}
+
+#[test]
+fn test_dvec3_cmp() {
+    assert!(!BVec3::default().any());
+    assert!(!BVec3::default().all());
+    assert_eq!(BVec3::default().bitmask(), 0x0);
+    let a = dvec3(-1.0, -1.0, -1.0);
+    let b = dvec3(1.0, 1.0, 1.0);
+    let c = dvec3(-1.0, -1.0, 1.0);
+    let d = dvec3(1.0, -1.0, -1.0);
+    assert_eq!(a.cmplt(a).bitmask(), 0x0);
+    assert_eq!(a.cmplt(b).bitmask(), 0x7);
+    assert_eq!(a.cmplt(c).bitmask(), 0x4);
+    assert_eq!(c.cmple(a).bitmask(), 0x3);
+    assert_eq!(a.cmplt(d).bitmask(), 0x1);
+    assert!(a.cmplt(b).all());
+    assert!(a.cmplt(c).any());
+    assert!(a.cmple(b).all());
+    assert!(a.cmple(a).all());
+    assert!(b.cmpgt(a).all());
+    assert!(b.cmpge(a).all());
+    assert!(b.cmpge(b).all());
+    assert!(!(a.cmpge(c).all()));
+    assert!(c.cmple(c).all());
+    assert!(c.cmpge(c).all());
+}
+
+#[test]
+fn test_dvec3_extend_truncate() {
+    let a = dvec3(1.0, 2.0, 3.0);
+    let b = a.extend(4.0);
+    assert_eq!((1.0, 2.0, 3.0, 4.0), b.into());
+    let c = b.truncate();
+    assert_eq!(a, c);
+    assert_eq!(a, b.xyz());
+    assert_eq!(dvec2(1.0, 2.0), a.xy());
+}
+
+#[test]
+fn test_dvec3_sign() {
+    assert_eq!(DVec3::zero().sign(), DVec3::one());
+    assert_eq!(-DVec3::zero().sign(), -DVec3::one());
+    assert_eq!(DVec3::one().sign(), DVec3::one());
+    assert_eq!((-DVec3::one()).sign(), -DVec3::one());
+    assert_eq!(DVec3::splat(f64::NEG_INFINITY).sign(), -DVec3::one());
+}
+
+#[test]
+fn test_dvec3_signum_copysign() {
+    assert_eq!(DVec3::zero().signum(), DVec3::one());
+    assert_eq!((-DVec3::zero()).signum(), -DVec3::one());
+    assert_eq!((-DVec3::zero()).sign(), DVec3::one());
+    assert_eq!(DVec3::splat(f64::NEG_INFINITY).signum(), -DVec3::one());
+    assert!(DVec3::splat(f64::NAN).signum().x().is_nan());
+    let a = dvec3(1.0, -2.0, 3.0);
+    assert_eq!(dvec3(1.0, -1.0, 1.0), a.signum());
+    assert_eq!(a.abs(), a.copysign(DVec3::one()));
+    assert_eq!(-a.abs(), a.copysign(-DVec3::one()));
+    assert_eq!(a, a.abs().copysign(a));
+    assert_eq!(-a, a.copysign(-a));
+    assert_eq!(-DVec3::one(), DVec3::one().copysign(-DVec3::zero()));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_dvec3_rand() {
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (f64, f64, f64) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: DVec3 = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[test]
+fn test_dvec3_abs() {
+    assert_eq!(DVec3::zero().abs(), DVec3::zero());
+    assert_eq!(DVec3::one().abs(), DVec3::one());
+    assert_eq!((-DVec3::one()).abs(), DVec3::one());
+}
+
+#[test]
+fn test_dvec3_align() {
+    use std::mem;
+    assert_eq!(24, mem::size_of::<DVec3>());
+    assert_eq!(8, mem::align_of::<DVec3>());
+}
+
+#[test]
+fn test_dvec3_as() {
+    let a = DVec3::new(1.0, 2.0, 3.0);
+    assert_eq!(Vec3::new(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), a.as_vec3a());
+    assert_eq!(I16Vec3::new(1, 2, 3), a.as_i16vec3());
+    assert_eq!(U16Vec3::new(1, 2, 3), a.as_u16vec3());
+    assert_eq!(IVec3::new(1, 2, 3), a.as_ivec3());
+    assert_eq!(UVec3::new(1, 2, 3), a.as_uvec3());
+    assert_eq!(I64Vec3::new(1, 2, 3), a.as_i64vec3());
+    assert_eq!(U64Vec3::new(1, 2, 3), a.as_u64vec3());
+
+    let b = DVec3::new(-1.5, 2.5, f64::NAN);
+    assert_eq!(IVec3::new(-1, 2, 0), b.as_ivec3());
+    assert_eq!(UVec3::new(0, 2, 0), b.as_uvec3());
+    assert_eq!(i32::MAX, DVec3::new(1.0e10, 1.0, 1.0).as_ivec3().x());
+}
+
+#[test]
+fn test_dvec3_try_as() {
+    let a = DVec3::new(1.9, 2.0, 3.0);
+    assert_eq!(Some(IVec3::new(1, 2, 3)), a.try_as_ivec3());
+    assert_eq!(Some(UVec3::new(1, 2, 3)), a.try_as_uvec3());
+    assert_eq!(Some(U64Vec3::new(1, 2, 3)), a.try_as_u64vec3());
+    // negative elements that truncate to zero are in range
+    assert_eq!(
+        Some(UVec3::new(0, 2, 3)),
+        DVec3::new(-0.5, 2.0, 3.0).try_as_uvec3()
+    );
+    assert_eq!(None, DVec3::new(-1.0, 2.0, 3.0).try_as_uvec3());
+    assert_eq!(None, DVec3::new(1.9, 2.0, f64::NAN).try_as_ivec3());
+    assert_eq!(None, DVec3::splat(f64::INFINITY).try_as_i64vec3());
+    assert_eq!(None, DVec3::splat(f64::NEG_INFINITY).try_as_i64vec3());
+    assert_eq!(
+        Some(U16Vec3::splat(u16::MAX)),
+        DVec3::splat(65535.5).try_as_u16vec3()
+    );
+    assert_eq!(None, DVec3::splat(65536.0).try_as_u16vec3());
+    assert_eq!(
+        Some(I16Vec3::splat(i16::MIN)),
+        DVec3::splat(-32768.5).try_as_i16vec3()
+    );
+    assert_eq!(None, DVec3::splat(-32769.0).try_as_i16vec3());
+    assert_eq!(
+        Some(IVec3::splat(i32::MIN)),
+        DVec3::splat(-2147483648.0).try_as_ivec3()
+    );
+    assert_eq!(None, DVec3::splat(2147483648.0).try_as_ivec3());
+    assert_eq!(None, DVec3::splat(4294967296.0).try_as_uvec3());
+    assert_eq!(
+        Some(UVec3::splat(u32::MAX)),
+        DVec3::splat(4294967295.5).try_as_uvec3()
+    );
+}
+
+#[test]
+fn test_dvec3_euclid() {
+    let a = DVec3::new(-7.0, 7.0, -7.5);
+    let b = DVec3::new(4.0, -4.0, 2.0);
+    assert_eq!(DVec3::new(-2.0, -1.0, -4.0), a.div_euclid(b));
+    assert_eq!(DVec3::new(1.0, 3.0, 0.5), a.rem_euclid(b));
+}
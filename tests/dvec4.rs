@@ -0,0 +1,361 @@
+mod support;
+
+use glam::*;
+#[cfg(feature = "rand")]
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "rand")]
+use rand_xoshiro::Xoshiro256Plus;
+
+#[test]
+fn test_dvec4_new() {
+    let v = dvec4(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(v.x(), 1.0);
+    assert_eq!(v.y(), 2.0);
+    assert_eq!(v.z(), 3.0);
+    assert_eq!(v.w(), 4.0);
+
+    let t = (1.0, 2.0, 3.0, 4.0);
+    let v = DVec4::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let v = DVec4::from(a);
+    let a1: [f64; 4] = v.into();
+    assert_eq!(a, a1);
+
+    let v = DVec4::new(t.0, t.1, t.2, t.3);
+    assert_eq!(t, v.into());
+
+    assert_eq!(DVec4::new(1.0, 0.0, 0.0, 0.0), DVec4::unit_x());
+    assert_eq!(DVec4::new(0.0, 1.0, 0.0, 0.0), DVec4::unit_y());
+    assert_eq!(DVec4::new(0.0, 0.0, 1.0, 0.0), DVec4::unit_z());
+    assert_eq!(DVec4::new(0.0, 0.0, 0.0, 1.0), DVec4::unit_w());
+}
+
+#[test]
+fn test_dvec4_fmt() {
+    let a = DVec4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(format!("{:?}", a), "DVec4(1.0, 2.0, 3.0, 4.0)");
+    // assert_eq!(
+    //     format!("{:#?}", a),
+    //     "DVec4(\n    1.0,\n    2.0,\n    3.0,\n    4.0\n)"
+    // );
+    assert_eq!(format!("{}", a), "[1, 2, 3, 4]");
+}
+
+#[test]
+fn test_dvec4_zero() {
+    let v = DVec4::zero();
+    assert_eq!((0.0, 0.0, 0.0, 0.0), v.into());
+    assert_eq!(v, DVec4::default());
+}
+
+#[test]
+fn test_dvec4_splat() {
+    let v = DVec4::splat(1.0);
+    assert_eq!((1.0, 1.0, 1.0, 1.0), v.into());
+}
+
+#[test]
+fn test_dvec4_accessors() {
+    let mut a = dvec4(0.0, 0.0, 0.0, 0.0);
+    a.set_x(1.0);
+    a.set_y(2.0);
+    a.set_z(3.0);
+    a.set_w(4.0);
+    assert_eq!(1.0, a.x());
+    assert_eq!(2.0, a.y());
+    assert_eq!(3.0, a.z());
+    assert_eq!(4.0, a.w());
+    assert_eq!((1.0, 2.0, 3.0, 4.0), a.into());
+}
+
+#[test]
+fn test_dvec4_funcs() {
+    let x = dvec4(1.0, 0.0, 0.0, 0.0);
+    let y = dvec4(0.0, 1.0, 0.0, 0.0);
+    let z = dvec4(0.0, 0.0, 1.0, 0.0);
+    let w = dvec4(0.0, 0.0, 0.0, 1.0);
+    assert_eq!(1.0, x.dot(x));
+    assert_eq!(0.0, x.dot(y));
+    assert_eq!(-1.0, z.dot(-z));
+    assert_eq!(4.0, (2.0 * x).length_squared());
+    assert_eq!(9.0, (-3.0 * y).length_squared());
+    assert_eq!(16.0, (4.0 * z).length_squared());
+    assert_eq!(64.0, (8.0 * w).length_squared());
+    assert_eq!(2.0, (-2.0 * x).length());
+    assert_eq!(3.0, (3.0 * y).length());
+    assert_eq!(4.0, (-4.0 * z).length());
+    assert_eq!(5.0, (-5.0 * w).length());
+    assert_eq!(x, (2.0 * x).normalize());
+    assert_eq!(
+        1.0 * 5.0 + 2.0 * 6.0 + 3.0 * 7.0 + 4.0 * 8.0,
+        dvec4(1.0, 2.0, 3.0, 4.0).dot(dvec4(5.0, 6.0, 7.0, 8.0))
+    );
+    assert_eq!(
+        DVec4::splat(1.0 * 5.0 + 2.0 * 6.0 + 3.0 * 7.0 + 4.0 * 8.0),
+        dvec4(1.0, 2.0, 3.0, 4.0).dot_into_vec(dvec4(5.0, 6.0, 7.0, 8.0))
+    );
+    assert_eq!(
+        2.0 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0 + 5.0 * 5.0,
+        dvec4(2.0, 3.0, 4.0, 5.0).length_squared()
+    );
+    assert_eq!(
+        (2.0_f64 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0 + 5.0 * 5.0).sqrt(),
+        dvec4(2.0, 3.0, 4.0, 5.0).length()
+    );
+    assert_eq!(
+        1.0 / (2.0_f64 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0 + 5.0 * 5.0).sqrt(),
+        dvec4(2.0, 3.0, 4.0, 5.0).length_reciprocal()
+    );
+    assert!(dvec4(2.0, 3.0, 4.0, 5.0).normalize().is_normalized());
+    assert_approx_eq!(
+        dvec4(2.0, 3.0, 4.0, 5.0) / (2.0_f64 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0 + 5.0 * 5.0).sqrt(),
+        dvec4(2.0, 3.0, 4.0, 5.0).normalize()
+    );
+}
+
+#[test]
+fn test_dvec4_mul_add() {
+    assert_eq!(
+        dvec4(1.0, 2.0, 3.0, 4.0).mul_add(dvec4(5.0, 6.0, 7.0, 8.0), dvec4(-1.0, 0.5, 2.0, -3.0)),
+        dvec4(4.0, 12.5, 23.0, 29.0)
+    );
+}
+
+#[test]
+fn test_dvec4_ops() {
+    let a = dvec4(1.0, 2.0, 3.0, 4.0);
+    assert_eq!((2.0, 4.0, 6.0, 8.0), (a + a).into());
+    assert_eq!((0.0, 0.0, 0.0, 0.0), (a - a).into());
+    assert_eq!((1.0, 4.0, 9.0, 16.0), (a * a).into());
+    assert_eq!((2.0, 4.0, 6.0, 8.0), (a * 2.0).into());
+    assert_eq!((2.0, 4.0, 6.0, 8.0), (2.0 * a).into());
+    assert_eq!((1.0, 1.0, 1.0, 1.0), (a / a).into());
+    assert_eq!((0.5, 1.0, 1.5, 2.0), (a / 2.0).into());
+    // is this a sensible operator?
+    // assert_eq!((1.0, 0.5, 1.0/3.0, 0.25), (1.0 / a).into());
+    assert_eq!((-1.0, -2.0, -3.0, -4.0), (-a).into());
+}
+
+#[test]
+fn test_dvec4_assign_ops() {
+    let a = dvec4(1.0, 2.0, 3.0, 4.0);
+    let mut b = a;
+    b += a;
+    assert_eq!((2.0, 4.0, 6.0, 8.0), b.into());
+    b -= a;
+    assert_eq!((1.0, 2.0, 3.0, 4.0), b.into());
+    b *= a;
+    assert_eq!((1.0, 4.0, 9.0, 16.0), b.into());
+    b /= a;
+    assert_eq!((1.0, 2.0, 3.0, 4.0), b.into());
+    b *= 2.0;
+    assert_eq!((2.0, 4.0, 6.0, 8.0), b.into());
+    b /= 2.0;
+    assert_eq!((1.0, 2.0, 3.0, 4.0), b.into());
+}
+
+#[test]
+fn test_dvec4_min_max() {
+    let a = dvec4(-1.0, 2.0, -3.0, 4.0);
+    let b = dvec4(1.0, -2.0, 3.0, -4.0);
+    assert_eq!((-1.0, -2.0, -3.0, -4.0), a.min(b).into());
+    assert_eq!((-1.0, -2.0, -3.0, -4.0), b.min(a).into());
+    assert_eq!((1.0, 2.0, 3.0, 4.0), a.max(b).into());
+    assert_eq!((1.0, 2.0, 3.0, 4.0), b.max(a).into());
+}
+
+#[test]
+fn test_dvec4_hmin_hmax() {
+    let a = dvec4(-1.0, 4.0, -3.0, 2.0);
+    assert_eq!(-3.0, a.min_element());
+    assert_eq!(4.0, a.max_element());
+    assert_eq!(3.0, dvec4(1.0, 2.0, 3.0, 4.0).truncate().max_element());
+    assert_eq!(-3.0, dvec4(-1.0, -2.0, -3.0, -4.0).truncate().min_element());
+}
+
+#[test]
+fn test_dvec4_eq() {
+    let a = dvec4(1.0, 1.0, 1.0, 1.0);
+    let b = dvec4(1.0, 2.0, 3.0, 4.0);
+    assert!(a.cmpeq(a).all());
+    assert!(b.cmpeq(b).all());
+    assert!(a.cmpne(b).any());
+    assert!(b.cmpne(a).any());
+    assert!(b.cmpeq(a).any());
+}
+
+#[test]
+fn test_dvec4_cmp() {
+    assert!(!BVec4::default().any());
+    assert!(!BVec4::default().all());
+    assert_eq!(BVec4::default().bitmask(), 0x0);
+    let a = dvec4(-1.0, -1.0, -1.0, -1.0);
+    let b = dvec4(1.0, 1.0, 1.0, 1.0);
+    let c = dvec4(-1.0, -1.0, 1.0, 1.0);
+    let d = dvec4(1.0, -1.0, -1.0, 1.0);
+    assert_eq!(a.cmplt(a).bitmask(), 0x0);
+    assert_eq!(a.cmplt(b).bitmask(), 0xf);
+    assert_eq!(a.cmplt(c).bitmask(), 0xc);
+    assert_eq!(c.cmple(a).bitmask(), 0x3);
+    assert_eq!(a.cmplt(d).bitmask(), 0x9);
+    assert!(a.cmplt(b).all());
+    assert!(a.cmplt(c).any());
+    assert!(a.cmple(b).all());
+    assert!(a.cmple(a).all());
+    assert!(b.cmpgt(a).all());
+    assert!(b.cmpge(a).all());
+    assert!(b.cmpge(b).all());
+    assert!(!(a.cmpge(c).all()));
+    assert!(c.cmple(c).all());
+    assert!(c.cmpge(c).all());
+    assert!(a.cmpeq(a).all());
+    assert!(!a.cmpeq(b).all());
+    assert!(a.cmpeq(c).any());
+    assert!(!a.cmpne(a).all());
+    assert!(a.cmpne(b).all());
+    assert!(a.cmpne(c).any());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_dvec4_rand() {
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (f64, f64, f64, f64) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: DVec4 = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[test]
+fn test_dvec4_slice() {
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let b = DVec4::from_slice_unaligned(&a);
+    let c: [f64; 4] = b.into();
+    assert_eq!(a, c);
+    let mut d = [0.0, 0.0, 0.0, 0.0];
+    b.write_to_slice_unaligned(&mut d[..]);
+    assert_eq!(a, d);
+}
+
+#[test]
+fn test_dvec4_slice_aligned() {
+    #[repr(align(32))]
+    struct Aligned([f64; 8]);
+    let mut a = Aligned([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let b = DVec4::from_slice_aligned(&a.0).unwrap();
+    assert_eq!(b, dvec4(1.0, 2.0, 3.0, 4.0));
+    b.write_to_slice_aligned(&mut a.0[4..]).unwrap();
+    assert_eq!(a.0, [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]);
+
+    let align = core::mem::align_of::<DVec4>();
+    if align > core::mem::align_of::<f64>() {
+        let err = DVec4::from_slice_aligned(&a.0[1..]).unwrap_err();
+        assert_eq!(err.required_alignment(), align);
+        assert_eq!(
+            DVec4::zero().write_to_slice_aligned(&mut a.0[1..]),
+            Err(err)
+        );
+        assert_eq!(a.0, [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]);
+    }
+}
+
+#[test]
+fn test_dvec4_sign() {
+    assert_eq!(DVec4::zero().sign(), DVec4::one());
+    assert_eq!(-DVec4::zero().sign(), -DVec4::one());
+    assert_eq!(DVec4::one().sign(), DVec4::one());
+    assert_eq!((-DVec4::one()).sign(), -DVec4::one());
+    assert_eq!(DVec4::splat(f64::NEG_INFINITY).sign(), -DVec4::one());
+}
+
+#[test]
+fn test_dvec4_abs() {
+    assert_eq!(DVec4::zero().abs(), DVec4::zero());
+    assert_eq!(DVec4::one().abs(), DVec4::one());
+    assert_eq!((-DVec4::one()).abs(), DVec4::one());
+}
+
+// #[test]
+// fn dup_element() {
+//     let a = dvec4(1.0, 2.0, 3.0, 4.0);
+//     assert_eq!(dvec4(1.0, 1.0, 1.0, 1.0), a.dup_x());
+//     assert_eq!(dvec4(2.0, 2.0, 2.0, 2.0), a.dup_y());
+//     assert_eq!(dvec4(3.0, 3.0, 3.0, 3.0), a.dup_z());
+//     assert_eq!(dvec4(4.0, 4.0, 4.0, 4.0), a.dup_w());
+// }
+
+#[test]
+fn test_dvec4_align() {
+    use std::mem;
+    assert_eq!(32, mem::size_of::<DVec4>());
+    if cfg!(all(target_feature = "avx", not(feature = "scalar-math"))) {
+        assert_eq!(32, mem::align_of::<DVec4>());
+    } else {
+        assert_eq!(8, mem::align_of::<DVec4>());
+    }
+}
+
+#[test]
+fn test_dvec4_as() {
+    let a = DVec4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(I16Vec4::new(1, 2, 3, 4), a.as_i16vec4());
+    assert_eq!(U16Vec4::new(1, 2, 3, 4), a.as_u16vec4());
+    assert_eq!(IVec4::new(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(UVec4::new(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(I64Vec4::new(1, 2, 3, 4), a.as_i64vec4());
+    assert_eq!(U64Vec4::new(1, 2, 3, 4), a.as_u64vec4());
+
+    let b = DVec4::new(-1.5, 2.5, f64::NAN, 4.9);
+    assert_eq!(IVec4::new(-1, 2, 0, 4), b.as_ivec4());
+    assert_eq!(UVec4::new(0, 2, 0, 4), b.as_uvec4());
+    assert_eq!(i32::MAX, DVec4::new(1.0e10, 1.0, 1.0, 1.0).as_ivec4().x());
+}
+
+#[test]
+fn test_dvec4_try_as() {
+    let a = DVec4::new(1.9, 2.0, 3.0, 4.0);
+    assert_eq!(Some(IVec4::new(1, 2, 3, 4)), a.try_as_ivec4());
+    assert_eq!(Some(UVec4::new(1, 2, 3, 4)), a.try_as_uvec4());
+    assert_eq!(Some(U64Vec4::new(1, 2, 3, 4)), a.try_as_u64vec4());
+    // negative elements that truncate to zero are in range
+    assert_eq!(
+        Some(UVec4::new(0, 2, 3, 4)),
+        DVec4::new(-0.5, 2.0, 3.0, 4.0).try_as_uvec4()
+    );
+    assert_eq!(None, DVec4::new(-1.0, 2.0, 3.0, 4.0).try_as_uvec4());
+    assert_eq!(None, DVec4::new(1.9, 2.0, 3.0, f64::NAN).try_as_ivec4());
+    assert_eq!(None, DVec4::splat(f64::INFINITY).try_as_i64vec4());
+    assert_eq!(None, DVec4::splat(f64::NEG_INFINITY).try_as_i64vec4());
+    assert_eq!(
+        Some(U16Vec4::splat(u16::MAX)),
+        DVec4::splat(65535.5).try_as_u16vec4()
+    );
+    assert_eq!(None, DVec4::splat(65536.0).try_as_u16vec4());
+    assert_eq!(
+        Some(I16Vec4::splat(i16::MIN)),
+        DVec4::splat(-32768.5).try_as_i16vec4()
+    );
+    assert_eq!(None, DVec4::splat(-32769.0).try_as_i16vec4());
+    assert_eq!(
+        Some(IVec4::splat(i32::MIN)),
+        DVec4::splat(-2147483648.0).try_as_ivec4()
+    );
+    assert_eq!(None, DVec4::splat(2147483648.0).try_as_ivec4());
+    assert_eq!(None, DVec4::splat(4294967296.0).try_as_uvec4());
+    assert_eq!(
+        Some(UVec4::splat(u32::MAX)),
+        DVec4::splat(4294967295.5).try_as_uvec4()
+    );
+}
+
+#[test]
+fn test_dvec4_euclid() {
+    let a = DVec4::new(-7.0, 7.0, -7.5, 7.5);
+    let b = DVec4::new(4.0, -4.0, 2.0, 2.0);
+    assert_eq!(DVec4::new(-2.0, -1.0, -4.0, 3.0), a.div_euclid(b));
+    assert_eq!(DVec4::new(1.0, 3.0, 0.5, 1.5), a.rem_euclid(b));
+}
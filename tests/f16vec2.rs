@@ -0,0 +1,49 @@
+#![cfg(feature = "half")]
+use glam::*;
+use std::mem;
+
+#[test]
+fn test_f16vec2_align() {
+    assert_eq!(4, mem::size_of::<F16Vec2>());
+    assert_eq!(2, mem::align_of::<F16Vec2>());
+}
+
+#[test]
+fn test_f16vec2_new() {
+    let v = f16vec2(1.0, -2.0);
+    assert_eq!(v.x(), 1.0);
+    assert_eq!(v.y(), -2.0);
+    assert_eq!(v.to_bits(), [0x3c00, 0xc000]);
+    assert_eq!(v, F16Vec2::from_bits([0x3c00, 0xc000]));
+    assert_eq!(v, F16Vec2::new(1.0, -2.0));
+
+    let a: [u16; 2] = v.into();
+    assert_eq!(v, F16Vec2::from(a));
+    let u = U16Vec2::from(v);
+    assert_eq!(u, u16vec2(0x3c00, 0xc000));
+    assert_eq!(v, F16Vec2::from(u));
+}
+
+#[test]
+fn test_f16vec2_round_trip() {
+    let v = Vec2::new(1.0, -2.0);
+    assert_eq!(v, F16Vec2::from(v).into());
+    assert_eq!(v, F16Vec2::from_vec2(v).to_vec2());
+
+    // values are rounded to the nearest half precision value
+    let v = F16Vec2::from(Vec2::splat(0.1));
+    assert_eq!(v.to_bits(), [0x2e66; 2]);
+    assert!((Vec2::from(v) - Vec2::splat(0.1))
+        .abs()
+        .cmplt(Vec2::splat(1.0e-4))
+        .all());
+}
+
+#[test]
+fn test_f16vec2_range() {
+    let v = f16vec2(1.0e10, -1.0e10);
+    assert_eq!(v.to_bits(), [0x7c00, 0xfc00]);
+    let nan = f16vec2(f32::NAN, f32::NAN);
+    assert!(nan.to_bits().iter().all(|&b| b & 0x7fff > 0x7c00));
+    assert!(nan.x().is_nan());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "half")]
+use glam::*;
+use std::mem;
+
+#[test]
+fn test_f16vec3_align() {
+    assert_eq!(6, mem::size_of::<F16Vec3>());
+    assert_eq!(2, mem::align_of::<F16Vec3>());
+}
+
+#[test]
+fn test_f16vec3_new() {
+    let v = f16vec3(1.0, -2.0, 0.5);
+    assert_eq!(v.x(), 1.0);
+    assert_eq!(v.y(), -2.0);
+    assert_eq!(v.z(), 0.5);
+    assert_eq!(v.to_bits(), [0x3c00, 0xc000, 0x3800]);
+    assert_eq!(v, F16Vec3::from_bits([0x3c00, 0xc000, 0x3800]));
+    assert_eq!(v, F16Vec3::new(1.0, -2.0, 0.5));
+
+    let a: [u16; 3] = v.into();
+    assert_eq!(v, F16Vec3::from(a));
+    let u = U16Vec3::from(v);
+    assert_eq!(u, u16vec3(0x3c00, 0xc000, 0x3800));
+    assert_eq!(v, F16Vec3::from(u));
+}
+
+#[test]
+fn test_f16vec3_round_trip() {
+    let v = Vec3::new(1.0, -2.0, 0.5);
+    assert_eq!(v, F16Vec3::from(v).into());
+    assert_eq!(v, F16Vec3::from_vec3(v).to_vec3());
+
+    // values are rounded to the nearest half precision value
+    let v = F16Vec3::from(Vec3::splat(0.1));
+    assert_eq!(v.to_bits(), [0x2e66; 3]);
+    assert!((Vec3::from(v) - Vec3::splat(0.1))
+        .abs()
+        .cmplt(Vec3::splat(1.0e-4))
+        .all());
+}
+
+#[test]
+fn test_f16vec3_range() {
+    let v = f16vec3(1.0e10, -1.0e10, 5.960_464_5e-8);
+    assert_eq!(v.to_bits(), [0x7c00, 0xfc00, 0x0001]);
+    let nan = f16vec3(f32::NAN, f32::NAN, f32::NAN);
+    assert!(nan.to_bits().iter().all(|&b| b & 0x7fff > 0x7c00));
+    assert!(nan.x().is_nan());
+}
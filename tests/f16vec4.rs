@@ -0,0 +1,51 @@
+#![cfg(feature = "half")]
+use glam::*;
+use std::mem;
+
+#[test]
+fn test_f16vec4_align() {
+    assert_eq!(8, mem::size_of::<F16Vec4>());
+    assert_eq!(2, mem::align_of::<F16Vec4>());
+}
+
+#[test]
+fn test_f16vec4_new() {
+    let v = f16vec4(1.0, -2.0, 0.5, 65504.0);
+    assert_eq!(v.x(), 1.0);
+    assert_eq!(v.y(), -2.0);
+    assert_eq!(v.z(), 0.5);
+    assert_eq!(v.w(), 65504.0);
+    assert_eq!(v.to_bits(), [0x3c00, 0xc000, 0x3800, 0x7bff]);
+    assert_eq!(v, F16Vec4::from_bits([0x3c00, 0xc000, 0x3800, 0x7bff]));
+    assert_eq!(v, F16Vec4::new(1.0, -2.0, 0.5, 65504.0));
+
+    let a: [u16; 4] = v.into();
+    assert_eq!(v, F16Vec4::from(a));
+    let u = U16Vec4::from(v);
+    assert_eq!(u, u16vec4(0x3c00, 0xc000, 0x3800, 0x7bff));
+    assert_eq!(v, F16Vec4::from(u));
+}
+
+#[test]
+fn test_f16vec4_round_trip() {
+    let v = Vec4::new(1.0, -2.0, 0.5, 65504.0);
+    assert_eq!(v, F16Vec4::from(v).into());
+    assert_eq!(v, F16Vec4::from_vec4(v).to_vec4());
+
+    // values are rounded to the nearest half precision value
+    let v = F16Vec4::from(Vec4::splat(0.1));
+    assert_eq!(v.to_bits(), [0x2e66; 4]);
+    assert!((Vec4::from(v) - Vec4::splat(0.1))
+        .abs()
+        .cmplt(Vec4::splat(1.0e-4))
+        .all());
+}
+
+#[test]
+fn test_f16vec4_range() {
+    let v = f16vec4(1.0e10, -1.0e10, 5.960_464_5e-8, 1.0e-30);
+    assert_eq!(v.to_bits(), [0x7c00, 0xfc00, 0x0001, 0x0000]);
+    let nan = f16vec4(f32::NAN, f32::NAN, f32::NAN, f32::NAN);
+    assert!(nan.to_bits().iter().all(|&b| b & 0x7fff > 0x7c00));
+    assert!(nan.x().is_nan());
+}
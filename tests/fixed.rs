@@ -0,0 +1,83 @@
+#![cfg(feature = "fixed")]
+use glam::*;
+
+#[test]
+fn test_fixed_conversions() {
+    assert_eq!(Fixed::one().to_bits(), 0x1_0000);
+    assert_eq!(Fixed::from_int(-3).to_bits(), -0x3_0000);
+    assert_eq!(Fixed::from_f32(0.5).to_bits(), 0x8000);
+    assert_eq!(Fixed::from_f64(-0.25).to_bits(), -0x4000);
+    assert_eq!(Fixed::from_bits(0x1_8000).to_f32(), 1.5);
+    assert_eq!(Fixed::from_bits(-0x1_8000).to_f64(), -1.5);
+    assert_eq!(Fixed::from_bits(-0x1_8000).to_int(), -2);
+    assert_eq!(Fixed::from_f32(1.0e10), Fixed::max_value());
+    assert_eq!(Fixed::from_f32(-1.0e10), Fixed::min_value());
+    assert_eq!(Fixed::from_f32(f32::NAN), Fixed::zero());
+    assert_eq!(Fixed::from(-2_i16), Fixed::from_int(-2));
+    assert_eq!(f32::from(Fixed::from_int(7)), 7.0);
+    assert_eq!(format!("{}", Fixed::from_f32(-2.5)), "-2.5");
+}
+
+#[test]
+fn test_fixed_ops() {
+    let a = Fixed::from_f32(1.5);
+    let b = Fixed::from_f32(-0.25);
+    assert_eq!(a + b, Fixed::from_f32(1.25));
+    assert_eq!(a - b, Fixed::from_f32(1.75));
+    assert_eq!(a * b, Fixed::from_f32(-0.375));
+    assert_eq!(a / b, Fixed::from_int(-6));
+    assert_eq!(-a, Fixed::from_f32(-1.5));
+    assert_eq!(b.abs(), Fixed::from_f32(0.25));
+    assert_eq!(a.min(b), b);
+    assert_eq!(a.max(b), a);
+    assert!(b < a);
+
+    // multiplication rounds towards negative infinity
+    let e = Fixed::from_bits(1);
+    assert_eq!(e * Fixed::from_f32(0.5), Fixed::zero());
+    assert_eq!(-e * Fixed::from_f32(0.5), -e);
+    // division rounds towards zero
+    assert_eq!(Fixed::one() / Fixed::from_int(3), Fixed::from_bits(0x5555));
+    assert_eq!(
+        -Fixed::one() / Fixed::from_int(3),
+        Fixed::from_bits(-0x5555)
+    );
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= Fixed::from_int(2);
+    c /= Fixed::from_int(3);
+    assert_eq!(c, Fixed::one());
+}
+
+#[test]
+fn test_fixed_range() {
+    assert_eq!(Fixed::from_int(32767).to_int(), 32767);
+    assert_eq!(Fixed::from_int(-32768), Fixed::min_value());
+    let big = Fixed::from_int(181);
+    assert_eq!(big * big, Fixed::from_int(32761));
+    assert_eq!(Fixed::from_int(-32768) / Fixed::one(), Fixed::min_value());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_fixed_from_int_overflow() {
+    let _ = Fixed::from_int(32768);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_fixed_mul_overflow() {
+    let big = Fixed::from_int(182);
+    let _ = big * big;
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_fixed_div_overflow() {
+    let _ = Fixed::from_int(256) / Fixed::from_f32(1.0 / 256.0);
+}
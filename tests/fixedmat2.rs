@@ -0,0 +1,84 @@
+#![cfg(feature = "fixed")]
+use glam::*;
+use std::mem;
+
+const A: [f32; 4] = [1.5, -2.0, 0.25, 3.0];
+const B: [f32; 4] = [0.5, 1.0, -3.0, 2.0];
+
+#[test]
+fn test_fixedmat2_align() {
+    assert_eq!(16, mem::size_of::<FixedMat2>());
+    assert_eq!(4, mem::align_of::<FixedMat2>());
+}
+
+#[test]
+fn test_fixedmat2_new() {
+    let a = Mat2::from_cols_array(&A);
+    let m = FixedMat2::from(a);
+    assert_eq!(m, fixedmat2(m.x_axis(), m.y_axis()));
+    assert_eq!(
+        m,
+        FixedMat2::from_cols(FixedVec2::from(a.x_axis()), FixedVec2::from(a.y_axis()))
+    );
+    assert_eq!(FixedMat2::identity(), FixedMat2::from(Mat2::identity()));
+    assert_eq!(FixedMat2::identity(), FixedMat2::default());
+    assert_eq!(format!("{}", FixedMat2::identity()), "[[1, 0], [0, 1]]");
+    assert_eq!(FixedMat2::zero(), FixedMat2::from(Mat2::zero()));
+    assert_eq!(
+        FixedMat2::from_diagonal(FixedVec2::from(Vec2::new(2.0, -1.0))),
+        FixedMat2::from(Mat2::from_diagonal(Vec2::new(2.0, -1.0)))
+    );
+
+    let mut m = FixedMat2::zero();
+    m.set_x_axis(FixedVec2::splat(Fixed::from_int(1)));
+    m.set_y_axis(FixedVec2::splat(Fixed::from_int(2)));
+    assert_eq!(m.x_axis(), FixedVec2::splat(Fixed::from_int(1)));
+    assert_eq!(m.y_axis(), FixedVec2::splat(Fixed::from_int(2)));
+}
+
+#[test]
+fn test_fixedmat2_ops() {
+    let (a, b) = (Mat2::from_cols_array(&A), Mat2::from_cols_array(&B));
+    let (fa, fb) = (FixedMat2::from(a), FixedMat2::from(b));
+    let two = Fixed::from_int(2);
+    assert_eq!((fa + fb).to_mat2(), a + b);
+    assert_eq!((fa - fb).to_mat2(), a - b);
+    assert_eq!((fa * fb).to_mat2(), a * b);
+    assert_eq!(fa.mul_mat2(&fb), fa * fb);
+    assert_eq!((fa * two).to_mat2(), a * 2.0);
+    assert_eq!((two * fa).to_mat2(), a * 2.0);
+    assert_eq!((-fa).to_mat2(), -a);
+    assert_eq!(fa * FixedMat2::identity(), fa);
+    assert_eq!(FixedMat2::identity() * fa, fa);
+
+    let v = Vec2::new(1.5, -2.0);
+    assert_eq!((fa * FixedVec2::from(v)).to_vec2(), a * v);
+    assert_eq!(fa.mul_vec2(FixedVec2::from(v)), fa * FixedVec2::from(v));
+
+    let mut c = fa;
+    c += fb;
+    c -= fb;
+    c *= two;
+    assert_eq!(c, fa * two);
+    c *= FixedMat2::identity();
+    assert_eq!(c, fa * two);
+}
+
+#[test]
+fn test_fixedmat2_transpose_determinant() {
+    let a = Mat2::from_cols_array(&A);
+    let fa = FixedMat2::from(a);
+    assert_eq!(fa.transpose().to_mat2(), a.transpose());
+    assert_eq!(fa.transpose().transpose(), fa);
+    assert_eq!(fa.determinant().to_f32(), a.determinant());
+    assert_eq!(fa.transpose().determinant(), fa.determinant());
+    assert_eq!(FixedMat2::identity().determinant(), Fixed::one());
+    assert_eq!(FixedMat2::zero().determinant(), Fixed::zero());
+}
+
+#[test]
+fn test_fixedmat2_conversions() {
+    let a = Mat2::from_cols_array(&A);
+    assert_eq!(a, FixedMat2::from(a).into());
+    assert_eq!(a, FixedMat2::from_mat2(&a).to_mat2());
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "fixed")]
+use glam::*;
+use std::mem;
+
+const A: [f32; 9] = [1.5, -2.0, 0.25, 3.0, 0.5, -1.0, 2.0, 1.0, -0.5];
+const B: [f32; 9] = [0.5, 1.0, -3.0, 2.0, -0.25, 1.5, 1.0, 0.0, -2.0];
+
+#[test]
+fn test_fixedmat3_align() {
+    assert_eq!(36, mem::size_of::<FixedMat3>());
+    assert_eq!(4, mem::align_of::<FixedMat3>());
+}
+
+#[test]
+fn test_fixedmat3_new() {
+    let a = Mat3::from_cols_array(&A);
+    let m = FixedMat3::from(a);
+    assert_eq!(m, fixedmat3(m.x_axis(), m.y_axis(), m.z_axis()));
+    assert_eq!(
+        m,
+        FixedMat3::from_cols(
+            FixedVec3::from(a.x_axis()),
+            FixedVec3::from(a.y_axis()),
+            FixedVec3::from(a.z_axis())
+        )
+    );
+    assert_eq!(FixedMat3::identity(), FixedMat3::from(Mat3::identity()));
+    assert_eq!(FixedMat3::identity(), FixedMat3::default());
+    assert_eq!(
+        format!("{}", FixedMat3::identity()),
+        "[[1, 0, 0], [0, 1, 0], [0, 0, 1]]"
+    );
+    assert_eq!(FixedMat3::zero(), FixedMat3::from(Mat3::zero()));
+    assert_eq!(
+        FixedMat3::from_diagonal(FixedVec3::from(Vec3::new(2.0, -1.0, 0.5))),
+        FixedMat3::from(Mat3::from_diagonal(Vec3::new(2.0, -1.0, 0.5)))
+    );
+
+    let mut m = FixedMat3::zero();
+    m.set_x_axis(FixedVec3::splat(Fixed::from_int(1)));
+    m.set_y_axis(FixedVec3::splat(Fixed::from_int(2)));
+    m.set_z_axis(FixedVec3::splat(Fixed::from_int(3)));
+    assert_eq!(m.x_axis(), FixedVec3::splat(Fixed::from_int(1)));
+    assert_eq!(m.y_axis(), FixedVec3::splat(Fixed::from_int(2)));
+    assert_eq!(m.z_axis(), FixedVec3::splat(Fixed::from_int(3)));
+}
+
+#[test]
+fn test_fixedmat3_ops() {
+    let (a, b) = (Mat3::from_cols_array(&A), Mat3::from_cols_array(&B));
+    let (fa, fb) = (FixedMat3::from(a), FixedMat3::from(b));
+    let two = Fixed::from_int(2);
+    assert_eq!((fa + fb).to_mat3(), a + b);
+    assert_eq!((fa - fb).to_mat3(), a - b);
+    assert_eq!((fa * fb).to_mat3(), a * b);
+    assert_eq!(fa.mul_mat3(&fb), fa * fb);
+    assert_eq!((fa * two).to_mat3(), a * 2.0);
+    assert_eq!((two * fa).to_mat3(), a * 2.0);
+    assert_eq!((-fa).to_mat3(), -a);
+    assert_eq!(fa * FixedMat3::identity(), fa);
+    assert_eq!(FixedMat3::identity() * fa, fa);
+
+    let v = Vec3::new(1.5, -2.0, 0.5);
+    assert_eq!((fa * FixedVec3::from(v)).to_vec3(), a * v);
+    assert_eq!(fa.mul_vec3(FixedVec3::from(v)), fa * FixedVec3::from(v));
+
+    let mut c = fa;
+    c += fb;
+    c -= fb;
+    c *= two;
+    assert_eq!(c, fa * two);
+    c *= FixedMat3::identity();
+    assert_eq!(c, fa * two);
+}
+
+#[test]
+fn test_fixedmat3_transpose_determinant() {
+    let a = Mat3::from_cols_array(&A);
+    let fa = FixedMat3::from(a);
+    assert_eq!(fa.transpose().to_mat3(), a.transpose());
+    assert_eq!(fa.transpose().transpose(), fa);
+    assert_eq!(fa.determinant().to_f32(), a.determinant());
+    assert_eq!(fa.transpose().determinant(), fa.determinant());
+    assert_eq!(FixedMat3::identity().determinant(), Fixed::one());
+    assert_eq!(FixedMat3::zero().determinant(), Fixed::zero());
+}
+
+#[test]
+fn test_fixedmat3_conversions() {
+    let a = Mat3::from_cols_array(&A);
+    assert_eq!(a, FixedMat3::from(a).into());
+    assert_eq!(a, FixedMat3::from_mat3(&a).to_mat3());
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "fixed")]
+use glam::*;
+use std::mem;
+
+const A: [f32; 16] = [
+    1.5, -2.0, 0.25, 3.0, 0.5, -1.0, 2.0, 1.0, -0.5, 4.0, 0.75, -3.0, 1.0, 0.0, 2.5, -1.25,
+];
+const B: [f32; 16] = [
+    0.5, 1.0, -3.0, 2.0, -0.25, 1.5, 1.0, 0.0, -2.0, 0.5, 1.0, 3.0, -1.0, 2.0, 0.25, -0.5,
+];
+
+#[test]
+fn test_fixedmat4_align() {
+    assert_eq!(64, mem::size_of::<FixedMat4>());
+    assert_eq!(4, mem::align_of::<FixedMat4>());
+}
+
+#[test]
+fn test_fixedmat4_new() {
+    let a = Mat4::from_cols_array(&A);
+    let m = FixedMat4::from(a);
+    assert_eq!(m, fixedmat4(m.x_axis(), m.y_axis(), m.z_axis(), m.w_axis()));
+    assert_eq!(
+        m,
+        FixedMat4::from_cols(
+            FixedVec4::from(a.x_axis()),
+            FixedVec4::from(a.y_axis()),
+            FixedVec4::from(a.z_axis()),
+            FixedVec4::from(a.w_axis())
+        )
+    );
+    assert_eq!(FixedMat4::identity(), FixedMat4::from(Mat4::identity()));
+    assert_eq!(FixedMat4::identity(), FixedMat4::default());
+    assert_eq!(
+        format!("{}", FixedMat4::identity()),
+        "[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]"
+    );
+    assert_eq!(FixedMat4::zero(), FixedMat4::from(Mat4::zero()));
+    assert_eq!(
+        FixedMat4::from_diagonal(FixedVec4::from(Vec4::new(2.0, -1.0, 0.5, 3.0))),
+        FixedMat4::from(Mat4::from_diagonal(Vec4::new(2.0, -1.0, 0.5, 3.0)))
+    );
+
+    let mut m = FixedMat4::zero();
+    m.set_x_axis(FixedVec4::splat(Fixed::from_int(1)));
+    m.set_y_axis(FixedVec4::splat(Fixed::from_int(2)));
+    m.set_z_axis(FixedVec4::splat(Fixed::from_int(3)));
+    m.set_w_axis(FixedVec4::splat(Fixed::from_int(4)));
+    assert_eq!(m.x_axis(), FixedVec4::splat(Fixed::from_int(1)));
+    assert_eq!(m.y_axis(), FixedVec4::splat(Fixed::from_int(2)));
+    assert_eq!(m.z_axis(), FixedVec4::splat(Fixed::from_int(3)));
+    assert_eq!(m.w_axis(), FixedVec4::splat(Fixed::from_int(4)));
+}
+
+#[test]
+fn test_fixedmat4_ops() {
+    let (a, b) = (Mat4::from_cols_array(&A), Mat4::from_cols_array(&B));
+    let (fa, fb) = (FixedMat4::from(a), FixedMat4::from(b));
+    let two = Fixed::from_int(2);
+    assert_eq!((fa + fb).to_mat4(), a + b);
+    assert_eq!((fa - fb).to_mat4(), a - b);
+    assert_eq!((fa * fb).to_mat4(), a * b);
+    assert_eq!(fa.mul_mat4(&fb), fa * fb);
+    assert_eq!((fa * two).to_mat4(), a * 2.0);
+    assert_eq!((two * fa).to_mat4(), a * 2.0);
+    assert_eq!((-fa).to_mat4(), -a);
+    assert_eq!(fa * FixedMat4::identity(), fa);
+    assert_eq!(FixedMat4::identity() * fa, fa);
+
+    let v = Vec4::new(1.5, -2.0, 0.5, 4.0);
+    assert_eq!((fa * FixedVec4::from(v)).to_vec4(), a * v);
+    assert_eq!(fa.mul_vec4(FixedVec4::from(v)), fa * FixedVec4::from(v));
+
+    let mut c = fa;
+    c += fb;
+    c -= fb;
+    c *= two;
+    assert_eq!(c, fa * two);
+    c *= FixedMat4::identity();
+    assert_eq!(c, fa * two);
+}
+
+#[test]
+fn test_fixedmat4_transpose_determinant() {
+    let a = Mat4::from_cols_array(&A);
+    let fa = FixedMat4::from(a);
+    assert_eq!(fa.transpose().to_mat4(), a.transpose());
+    assert_eq!(fa.transpose().transpose(), fa);
+    assert_eq!(fa.determinant().to_f32(), a.determinant());
+    assert_eq!(fa.transpose().determinant(), fa.determinant());
+    assert_eq!(FixedMat4::identity().determinant(), Fixed::one());
+    assert_eq!(FixedMat4::zero().determinant(), Fixed::zero());
+}
+
+#[test]
+fn test_fixedmat4_conversions() {
+    let a = Mat4::from_cols_array(&A);
+    assert_eq!(a, FixedMat4::from(a).into());
+    assert_eq!(a, FixedMat4::from_mat4(&a).to_mat4());
+}
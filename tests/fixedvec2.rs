@@ -0,0 +1,101 @@
+#![cfg(feature = "fixed")]
+use glam::*;
+use std::convert::TryFrom;
+use std::mem;
+
+#[test]
+fn test_fixedvec2_align() {
+    assert_eq!(8, mem::size_of::<FixedVec2>());
+    assert_eq!(4, mem::align_of::<FixedVec2>());
+}
+
+#[test]
+fn test_fixedvec2_new() {
+    let v = fixedvec2(Fixed::from_f32(1.5), Fixed::from_f32(-2.0));
+    assert_eq!(v.x(), Fixed::from_f32(1.5));
+    assert_eq!(v.y(), Fixed::from_f32(-2.0));
+    assert_eq!(
+        v,
+        FixedVec2::new(Fixed::from_f32(1.5), Fixed::from_f32(-2.0))
+    );
+    assert_eq!(v, FixedVec2::from(Vec2::new(1.5, -2.0)));
+    let a: [Fixed; 2] = v.into();
+    assert_eq!(v, FixedVec2::from(a));
+    assert_eq!(FixedVec2::zero(), FixedVec2::splat(Fixed::zero()));
+    assert_eq!(FixedVec2::one(), FixedVec2::splat(Fixed::one()));
+    assert_eq!(FixedVec2::unit_x().x(), Fixed::one());
+    assert_eq!(FixedVec2::unit_y().y(), Fixed::one());
+
+    let e = FixedVec2::one().extend(Fixed::zero());
+    assert_eq!(e.z(), Fixed::zero());
+
+    let mut s = FixedVec2::zero();
+    s.set_y(Fixed::one());
+    assert_eq!(s, FixedVec2::unit_y());
+    assert_eq!(format!("{}", v), "[1.5, -2]");
+}
+
+#[test]
+fn test_fixedvec2_ops() {
+    let a = fixedvec2(Fixed::from_f32(1.5), Fixed::from_f32(-2.0));
+    let b = fixedvec2(Fixed::from_f32(2.0), Fixed::from_f32(0.5));
+    assert_eq!(a + b, FixedVec2::from(Vec2::new(3.5, -1.5)));
+    assert_eq!(a - b, FixedVec2::from(Vec2::new(-0.5, -2.5)));
+    assert_eq!(a * b, FixedVec2::from(Vec2::new(3.0, -1.0)));
+    assert_eq!(a / b, FixedVec2::from(Vec2::new(0.75, -4.0)));
+    let two = Fixed::from_int(2);
+    assert_eq!(a * two, FixedVec2::from(Vec2::new(3.0, -4.0)));
+    assert_eq!(two * a, FixedVec2::from(Vec2::new(3.0, -4.0)));
+    assert_eq!(a / two, FixedVec2::from(Vec2::new(0.75, -1.0)));
+    assert_eq!(a + two, FixedVec2::from(Vec2::new(3.5, 0.0)));
+    assert_eq!(a - two, FixedVec2::from(Vec2::new(-0.5, -4.0)));
+    assert_eq!(-a, FixedVec2::from(Vec2::new(-1.5, 2.0)));
+    assert_eq!(a.abs(), FixedVec2::from(Vec2::new(1.5, 2.0)));
+    assert_eq!(a.min(b), FixedVec2::from(Vec2::new(1.5, -2.0)));
+    assert_eq!(a.max(b), FixedVec2::from(Vec2::new(2.0, 0.5)));
+    assert_eq!(a.clamp(b, b), b);
+    assert_eq!(a.clamp_scalar(two, two), FixedVec2::splat(two));
+    assert_eq!(a.min_scalar(two), a.min(FixedVec2::splat(two)));
+    assert_eq!(a.max_scalar(two), a.max(FixedVec2::splat(two)));
+    assert!(a.cmpeq(a).all());
+    assert_eq!(a.cmplt(b), BVec2::new(true, true));
+    assert!(a.cmple(a).all());
+    assert_eq!(b.cmple(a), BVec2::new(false, false));
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= two;
+    c /= two;
+    c += two;
+    c -= two;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn test_fixedvec2_dot() {
+    let a = fixedvec2(Fixed::from_f32(1.5), Fixed::from_f32(-2.0));
+    let b = fixedvec2(Fixed::from_f32(2.0), Fixed::from_f32(0.5));
+    assert_eq!(a.dot(b), Fixed::from_f32(2.0));
+    assert_eq!(a.length_squared(), Fixed::from_f32(6.25));
+
+    // products are summed before rounding
+    let e = FixedVec2::splat(Fixed::from_bits(0xc0));
+    assert_eq!(e.x() * e.x(), Fixed::zero());
+    assert_eq!(e.length_squared(), Fixed::from_bits(1));
+}
+
+#[test]
+fn test_fixedvec2_conversions() {
+    let v = Vec2::new(1.5, -2.0);
+    assert_eq!(v, FixedVec2::from(v).into());
+    assert_eq!(v, FixedVec2::from_vec2(v).to_vec2());
+    let i = IVec2::new(3, -4);
+    assert_eq!(Ok(i), FixedVec2::try_from(i).map(FixedVec2::to_ivec2));
+    assert!(FixedVec2::try_from(IVec2::new(40000, 0)).is_err());
+    assert_eq!(FixedVec2::from_ivec2(i).to_vec2(), i.as_vec2());
+    assert_eq!(
+        fixedvec2(Fixed::from_f32(1.5), Fixed::from_f32(-2.0)).to_ivec2(),
+        IVec2::new(1, -2)
+    );
+}
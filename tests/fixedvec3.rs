@@ -0,0 +1,132 @@
+#![cfg(feature = "fixed")]
+use glam::*;
+use std::convert::TryFrom;
+use std::mem;
+
+#[test]
+fn test_fixedvec3_align() {
+    assert_eq!(12, mem::size_of::<FixedVec3>());
+    assert_eq!(4, mem::align_of::<FixedVec3>());
+}
+
+#[test]
+fn test_fixedvec3_new() {
+    let v = fixedvec3(
+        Fixed::from_f32(1.5),
+        Fixed::from_f32(-2.0),
+        Fixed::from_f32(0.25),
+    );
+    assert_eq!(v.x(), Fixed::from_f32(1.5));
+    assert_eq!(v.y(), Fixed::from_f32(-2.0));
+    assert_eq!(v.z(), Fixed::from_f32(0.25));
+    assert_eq!(
+        v,
+        FixedVec3::new(
+            Fixed::from_f32(1.5),
+            Fixed::from_f32(-2.0),
+            Fixed::from_f32(0.25)
+        )
+    );
+    assert_eq!(v, FixedVec3::from(Vec3::new(1.5, -2.0, 0.25)));
+    let a: [Fixed; 3] = v.into();
+    assert_eq!(v, FixedVec3::from(a));
+    assert_eq!(FixedVec3::zero(), FixedVec3::splat(Fixed::zero()));
+    assert_eq!(FixedVec3::one(), FixedVec3::splat(Fixed::one()));
+    assert_eq!(FixedVec3::unit_x().x(), Fixed::one());
+    assert_eq!(FixedVec3::unit_y().y(), Fixed::one());
+    assert_eq!(FixedVec3::unit_z().z(), Fixed::one());
+
+    let e = FixedVec3::one().extend(Fixed::zero());
+    assert_eq!(e.w(), Fixed::zero());
+
+    let mut s = FixedVec3::zero();
+    s.set_z(Fixed::one());
+    assert_eq!(s, FixedVec3::unit_z());
+    assert_eq!(format!("{}", v), "[1.5, -2, 0.25]");
+}
+
+#[test]
+fn test_fixedvec3_ops() {
+    let a = fixedvec3(
+        Fixed::from_f32(1.5),
+        Fixed::from_f32(-2.0),
+        Fixed::from_f32(0.25),
+    );
+    let b = fixedvec3(
+        Fixed::from_f32(2.0),
+        Fixed::from_f32(0.5),
+        Fixed::from_f32(-4.0),
+    );
+    assert_eq!(a + b, FixedVec3::from(Vec3::new(3.5, -1.5, -3.75)));
+    assert_eq!(a - b, FixedVec3::from(Vec3::new(-0.5, -2.5, 4.25)));
+    assert_eq!(a * b, FixedVec3::from(Vec3::new(3.0, -1.0, -1.0)));
+    assert_eq!(a / b, FixedVec3::from(Vec3::new(0.75, -4.0, -0.0625)));
+    let two = Fixed::from_int(2);
+    assert_eq!(a * two, FixedVec3::from(Vec3::new(3.0, -4.0, 0.5)));
+    assert_eq!(two * a, FixedVec3::from(Vec3::new(3.0, -4.0, 0.5)));
+    assert_eq!(a / two, FixedVec3::from(Vec3::new(0.75, -1.0, 0.125)));
+    assert_eq!(a + two, FixedVec3::from(Vec3::new(3.5, 0.0, 2.25)));
+    assert_eq!(a - two, FixedVec3::from(Vec3::new(-0.5, -4.0, -1.75)));
+    assert_eq!(-a, FixedVec3::from(Vec3::new(-1.5, 2.0, -0.25)));
+    assert_eq!(a.abs(), FixedVec3::from(Vec3::new(1.5, 2.0, 0.25)));
+    assert_eq!(a.min(b), FixedVec3::from(Vec3::new(1.5, -2.0, -4.0)));
+    assert_eq!(a.max(b), FixedVec3::from(Vec3::new(2.0, 0.5, 0.25)));
+    assert_eq!(a.clamp(b, b), b);
+    assert_eq!(a.clamp_scalar(two, two), FixedVec3::splat(two));
+    assert_eq!(a.min_scalar(two), a.min(FixedVec3::splat(two)));
+    assert_eq!(a.max_scalar(two), a.max(FixedVec3::splat(two)));
+    assert!(a.cmpeq(a).all());
+    assert_eq!(a.cmplt(b), BVec3::new(true, true, false));
+    assert!(a.cmple(a).all());
+    assert_eq!(b.cmple(a), BVec3::new(false, false, true));
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= two;
+    c /= two;
+    c += two;
+    c -= two;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn test_fixedvec3_dot() {
+    let a = fixedvec3(
+        Fixed::from_f32(1.5),
+        Fixed::from_f32(-2.0),
+        Fixed::from_f32(0.25),
+    );
+    let b = fixedvec3(
+        Fixed::from_f32(2.0),
+        Fixed::from_f32(0.5),
+        Fixed::from_f32(-4.0),
+    );
+    assert_eq!(a.dot(b), Fixed::from_f32(1.0));
+    assert_eq!(a.length_squared(), Fixed::from_f32(6.3125));
+
+    // products are summed before rounding
+    let e = FixedVec3::splat(Fixed::from_bits(0xc0));
+    assert_eq!(e.x() * e.x(), Fixed::zero());
+    assert_eq!(e.length_squared(), Fixed::from_bits(1));
+}
+
+#[test]
+fn test_fixedvec3_conversions() {
+    let v = Vec3::new(1.5, -2.0, 0.25);
+    assert_eq!(v, FixedVec3::from(v).into());
+    assert_eq!(v, FixedVec3::from_vec3(v).to_vec3());
+    let i = IVec3::new(3, -4, 5);
+    assert_eq!(Ok(i), FixedVec3::try_from(i).map(FixedVec3::to_ivec3));
+    assert!(FixedVec3::try_from(IVec3::new(0, -32769, 0)).is_err());
+    assert_eq!(FixedVec3::from_ivec3(i).to_vec3(), i.as_vec3());
+    assert_eq!(
+        fixedvec3(
+            Fixed::from_f32(1.5),
+            Fixed::from_f32(-2.0),
+            Fixed::from_f32(0.25)
+        )
+        .to_ivec3(),
+        IVec3::new(1, -2, 0)
+    );
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "fixed")]
+use glam::*;
+use std::convert::TryFrom;
+use std::mem;
+
+#[test]
+fn test_fixedvec4_align() {
+    assert_eq!(16, mem::size_of::<FixedVec4>());
+    assert_eq!(4, mem::align_of::<FixedVec4>());
+}
+
+#[test]
+fn test_fixedvec4_new() {
+    let v = fixedvec4(
+        Fixed::from_f32(1.5),
+        Fixed::from_f32(-2.0),
+        Fixed::from_f32(0.25),
+        Fixed::from_f32(3.0),
+    );
+    assert_eq!(v.x(), Fixed::from_f32(1.5));
+    assert_eq!(v.y(), Fixed::from_f32(-2.0));
+    assert_eq!(v.z(), Fixed::from_f32(0.25));
+    assert_eq!(v.w(), Fixed::from_f32(3.0));
+    assert_eq!(
+        v,
+        FixedVec4::new(
+            Fixed::from_f32(1.5),
+            Fixed::from_f32(-2.0),
+            Fixed::from_f32(0.25),
+            Fixed::from_f32(3.0)
+        )
+    );
+    assert_eq!(v, FixedVec4::from(Vec4::new(1.5, -2.0, 0.25, 3.0)));
+    let a: [Fixed; 4] = v.into();
+    assert_eq!(v, FixedVec4::from(a));
+    assert_eq!(FixedVec4::zero(), FixedVec4::splat(Fixed::zero()));
+    assert_eq!(FixedVec4::one(), FixedVec4::splat(Fixed::one()));
+    assert_eq!(FixedVec4::unit_x().x(), Fixed::one());
+    assert_eq!(FixedVec4::unit_y().y(), Fixed::one());
+    assert_eq!(FixedVec4::unit_z().z(), Fixed::one());
+    assert_eq!(FixedVec4::unit_w().w(), Fixed::one());
+
+    assert_eq!(FixedVec4::one().truncate(), FixedVec3::one());
+
+    let mut s = FixedVec4::zero();
+    s.set_w(Fixed::one());
+    assert_eq!(s, FixedVec4::unit_w());
+    assert_eq!(format!("{}", v), "[1.5, -2, 0.25, 3]");
+}
+
+#[test]
+fn test_fixedvec4_ops() {
+    let a = fixedvec4(
+        Fixed::from_f32(1.5),
+        Fixed::from_f32(-2.0),
+        Fixed::from_f32(0.25),
+        Fixed::from_f32(3.0),
+    );
+    let b = fixedvec4(
+        Fixed::from_f32(2.0),
+        Fixed::from_f32(0.5),
+        Fixed::from_f32(-4.0),
+        Fixed::from_f32(1.0),
+    );
+    assert_eq!(a + b, FixedVec4::from(Vec4::new(3.5, -1.5, -3.75, 4.0)));
+    assert_eq!(a - b, FixedVec4::from(Vec4::new(-0.5, -2.5, 4.25, 2.0)));
+    assert_eq!(a * b, FixedVec4::from(Vec4::new(3.0, -1.0, -1.0, 3.0)));
+    assert_eq!(a / b, FixedVec4::from(Vec4::new(0.75, -4.0, -0.0625, 3.0)));
+    let two = Fixed::from_int(2);
+    assert_eq!(a * two, FixedVec4::from(Vec4::new(3.0, -4.0, 0.5, 6.0)));
+    assert_eq!(two * a, FixedVec4::from(Vec4::new(3.0, -4.0, 0.5, 6.0)));
+    assert_eq!(a / two, FixedVec4::from(Vec4::new(0.75, -1.0, 0.125, 1.5)));
+    assert_eq!(a + two, FixedVec4::from(Vec4::new(3.5, 0.0, 2.25, 5.0)));
+    assert_eq!(a - two, FixedVec4::from(Vec4::new(-0.5, -4.0, -1.75, 1.0)));
+    assert_eq!(-a, FixedVec4::from(Vec4::new(-1.5, 2.0, -0.25, -3.0)));
+    assert_eq!(a.abs(), FixedVec4::from(Vec4::new(1.5, 2.0, 0.25, 3.0)));
+    assert_eq!(a.min(b), FixedVec4::from(Vec4::new(1.5, -2.0, -4.0, 1.0)));
+    assert_eq!(a.max(b), FixedVec4::from(Vec4::new(2.0, 0.5, 0.25, 3.0)));
+    assert_eq!(a.clamp(b, b), b);
+    assert_eq!(a.clamp_scalar(two, two), FixedVec4::splat(two));
+    assert_eq!(a.min_scalar(two), a.min(FixedVec4::splat(two)));
+    assert_eq!(a.max_scalar(two), a.max(FixedVec4::splat(two)));
+    assert!(a.cmpeq(a).all());
+    assert_eq!(a.cmplt(b), BVec4::new(true, true, false, false));
+    assert!(a.cmple(a).all());
+    assert_eq!(b.cmple(a), BVec4::new(false, false, true, true));
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= two;
+    c /= two;
+    c += two;
+    c -= two;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn test_fixedvec4_dot() {
+    let a = fixedvec4(
+        Fixed::from_f32(1.5),
+        Fixed::from_f32(-2.0),
+        Fixed::from_f32(0.25),
+        Fixed::from_f32(3.0),
+    );
+    let b = fixedvec4(
+        Fixed::from_f32(2.0),
+        Fixed::from_f32(0.5),
+        Fixed::from_f32(-4.0),
+        Fixed::from_f32(1.0),
+    );
+    assert_eq!(a.dot(b), Fixed::from_f32(4.0));
+    assert_eq!(a.length_squared(), Fixed::from_f32(15.3125));
+
+    // products are summed before rounding
+    let e = FixedVec4::splat(Fixed::from_bits(0xc0));
+    assert_eq!(e.x() * e.x(), Fixed::zero());
+    assert_eq!(e.length_squared(), Fixed::from_bits(2));
+}
+
+#[test]
+fn test_fixedvec4_conversions() {
+    let v = Vec4::new(1.5, -2.0, 0.25, 3.0);
+    assert_eq!(v, FixedVec4::from(v).into());
+    assert_eq!(v, FixedVec4::from_vec4(v).to_vec4());
+    let i = IVec4::new(3, -4, 5, -6);
+    assert_eq!(Ok(i), FixedVec4::try_from(i).map(FixedVec4::to_ivec4));
+    assert!(FixedVec4::try_from(IVec4::new(0, 0, 0, 32768)).is_err());
+    assert_eq!(FixedVec4::from_ivec4(i).to_vec4(), i.as_vec4());
+    assert_eq!(
+        fixedvec4(
+            Fixed::from_f32(1.5),
+            Fixed::from_f32(-2.0),
+            Fixed::from_f32(0.25),
+            Fixed::from_f32(3.0)
+        )
+        .to_ivec4(),
+        IVec4::new(1, -2, 0, 3)
+    );
+}
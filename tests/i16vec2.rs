@@ -0,0 +1,71 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec2_int_tests!(i16, i16vec2, I16Vec2, i16vec3);
+impl_vec2_signed_tests!(i16, i16vec2, I16Vec2);
+
+#[test]
+fn test_i16vec2_conversions() {
+    let a = i16vec2(1, 2);
+    assert_eq!(vec2(1.0, 2.0), a.as_vec2());
+    assert_eq!(ivec2(1, 2), IVec2::from(a));
+
+    assert_eq!(Ok(a), I16Vec2::try_from(ivec2(1, 2)));
+    assert!(I16Vec2::try_from(ivec2(70000, 2)).is_err());
+}
+
+#[test]
+fn test_i16vec2_as() {
+    let a = I16Vec2::new(1, 2);
+    assert_eq!(Vec2::new(1.0, 2.0), a.as_vec2());
+    assert_eq!(DVec2::new(1.0, 2.0), a.as_dvec2());
+    assert_eq!(U16Vec2::new(1, 2), a.as_u16vec2());
+    assert_eq!(IVec2::new(1, 2), a.as_ivec2());
+    assert_eq!(UVec2::new(1, 2), a.as_uvec2());
+    assert_eq!(I64Vec2::new(1, 2), a.as_i64vec2());
+    assert_eq!(U64Vec2::new(1, 2), a.as_u64vec2());
+
+    let b = I16Vec2::new(-1, 2);
+    assert_eq!(u16::MAX, b.as_u16vec2().x());
+    assert_eq!(-1, b.as_i64vec2().x());
+}
+
+#[test]
+fn test_i16vec2_dot_wide() {
+    let a = I16Vec2::splat(i16::MIN / 4);
+    assert_eq!(
+        2 * (i64::from(i16::MIN / 4) * i64::from(i16::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        2 * (i64::from(i16::MIN / 4) * i64::from(i16::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i16::MIN / 4).wrapping_mul(i16::MIN / 4).wrapping_mul(2),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i16::MIN / 4).wrapping_mul(i16::MIN / 4).wrapping_mul(2),
+        a.wrapping_dot(a)
+    );
+    let b = I16Vec2::new(1, 2);
+    assert_eq!(5, b.length_squared());
+    assert_eq!(5, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_i16vec2_manhattan_chebyshev_extremes() {
+    let a = I16Vec2::splat(i16::MIN);
+    let b = I16Vec2::splat(i16::MAX);
+    let d = i64::from(i16::MAX) - i64::from(i16::MIN);
+    assert_eq!(2 * d, a.manhattan_distance(b));
+    assert_eq!(2 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(2 * -i64::from(i16::MIN), a.manhattan_length());
+    assert_eq!(-i64::from(i16::MIN), a.chebyshev_length());
+}
@@ -0,0 +1,72 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec3_int_tests!(i16, i16vec3, I16Vec3, i16vec2, i16vec4);
+impl_vec3_signed_tests!(i16, i16vec3, I16Vec3);
+
+#[test]
+fn test_i16vec3_conversions() {
+    let a = i16vec3(1, 2, 3);
+    assert_eq!(vec3(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(ivec3(1, 2, 3), IVec3::from(a));
+
+    assert_eq!(Ok(a), I16Vec3::try_from(ivec3(1, 2, 3)));
+    assert!(I16Vec3::try_from(ivec3(70000, 2, 3)).is_err());
+}
+
+#[test]
+fn test_i16vec3_as() {
+    let a = I16Vec3::new(1, 2, 3);
+    assert_eq!(Vec3::new(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), a.as_vec3a());
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(U16Vec3::new(1, 2, 3), a.as_u16vec3());
+    assert_eq!(IVec3::new(1, 2, 3), a.as_ivec3());
+    assert_eq!(UVec3::new(1, 2, 3), a.as_uvec3());
+    assert_eq!(I64Vec3::new(1, 2, 3), a.as_i64vec3());
+    assert_eq!(U64Vec3::new(1, 2, 3), a.as_u64vec3());
+
+    let b = I16Vec3::new(-1, 2, 3);
+    assert_eq!(u16::MAX, b.as_u16vec3().x());
+    assert_eq!(-1, b.as_i64vec3().x());
+}
+
+#[test]
+fn test_i16vec3_dot_wide() {
+    let a = I16Vec3::splat(i16::MIN / 4);
+    assert_eq!(
+        3 * (i64::from(i16::MIN / 4) * i64::from(i16::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        3 * (i64::from(i16::MIN / 4) * i64::from(i16::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i16::MIN / 4).wrapping_mul(i16::MIN / 4).wrapping_mul(3),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i16::MIN / 4).wrapping_mul(i16::MIN / 4).wrapping_mul(3),
+        a.wrapping_dot(a)
+    );
+    let b = I16Vec3::new(1, 2, 3);
+    assert_eq!(14, b.length_squared());
+    assert_eq!(14, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_i16vec3_manhattan_chebyshev_extremes() {
+    let a = I16Vec3::splat(i16::MIN);
+    let b = I16Vec3::splat(i16::MAX);
+    let d = i64::from(i16::MAX) - i64::from(i16::MIN);
+    assert_eq!(3 * d, a.manhattan_distance(b));
+    assert_eq!(3 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(3 * -i64::from(i16::MIN), a.manhattan_length());
+    assert_eq!(-i64::from(i16::MIN), a.chebyshev_length());
+}
@@ -0,0 +1,71 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec4_int_tests!(i16, i16vec4, I16Vec4, i16vec3);
+impl_vec4_signed_tests!(i16, i16vec4, I16Vec4);
+
+#[test]
+fn test_i16vec4_conversions() {
+    let a = i16vec4(1, 2, 3, 4);
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(ivec4(1, 2, 3, 4), IVec4::from(a));
+
+    assert_eq!(Ok(a), I16Vec4::try_from(ivec4(1, 2, 3, 4)));
+    assert!(I16Vec4::try_from(ivec4(70000, 2, 3, 4)).is_err());
+}
+
+#[test]
+fn test_i16vec4_as() {
+    let a = I16Vec4::new(1, 2, 3, 4);
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(U16Vec4::new(1, 2, 3, 4), a.as_u16vec4());
+    assert_eq!(IVec4::new(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(UVec4::new(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(I64Vec4::new(1, 2, 3, 4), a.as_i64vec4());
+    assert_eq!(U64Vec4::new(1, 2, 3, 4), a.as_u64vec4());
+
+    let b = I16Vec4::new(-1, 2, 3, 4);
+    assert_eq!(u16::MAX, b.as_u16vec4().x());
+    assert_eq!(-1, b.as_i64vec4().x());
+}
+
+#[test]
+fn test_i16vec4_dot_wide() {
+    let a = I16Vec4::splat(i16::MIN / 4);
+    assert_eq!(
+        4 * (i64::from(i16::MIN / 4) * i64::from(i16::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        4 * (i64::from(i16::MIN / 4) * i64::from(i16::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i16::MIN / 4).wrapping_mul(i16::MIN / 4).wrapping_mul(4),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i16::MIN / 4).wrapping_mul(i16::MIN / 4).wrapping_mul(4),
+        a.wrapping_dot(a)
+    );
+    let b = I16Vec4::new(1, 2, 3, 4);
+    assert_eq!(30, b.length_squared());
+    assert_eq!(30, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_i16vec4_manhattan_chebyshev_extremes() {
+    let a = I16Vec4::splat(i16::MIN);
+    let b = I16Vec4::splat(i16::MAX);
+    let d = i64::from(i16::MAX) - i64::from(i16::MIN);
+    assert_eq!(4 * d, a.manhattan_distance(b));
+    assert_eq!(4 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(4 * -i64::from(i16::MIN), a.manhattan_length());
+    assert_eq!(-i64::from(i16::MIN), a.chebyshev_length());
+}
@@ -0,0 +1,87 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec2_int_tests!(i64, i64vec2, I64Vec2, i64vec3);
+impl_vec2_signed_tests!(i64, i64vec2, I64Vec2);
+
+#[test]
+fn test_i64vec2_conversions() {
+    let a = i64vec2(1, 2);
+    assert_eq!(vec2(1.0, 2.0), a.as_vec2());
+    assert_eq!(dvec2(1.0, 2.0), a.as_dvec2());
+    assert_eq!(ivec2(1, 2), a.as_ivec2());
+    assert_eq!(a, I64Vec2::from(ivec2(1, 2)));
+    assert_eq!(a, I64Vec2::from(uvec2(1, 2)));
+
+    assert_eq!(Ok(ivec2(1, 2)), IVec2::try_from(a));
+    assert!(IVec2::try_from(i64vec2(i64::MAX, 2)).is_err());
+    assert_eq!(Ok(a), I64Vec2::try_from(u64vec2(1, 2)));
+    assert!(I64Vec2::try_from(u64vec2(u64::MAX, 2)).is_err());
+}
+
+#[test]
+fn test_i64vec2_as() {
+    let a = I64Vec2::new(1, 2);
+    assert_eq!(Vec2::new(1.0, 2.0), a.as_vec2());
+    assert_eq!(DVec2::new(1.0, 2.0), a.as_dvec2());
+    assert_eq!(I16Vec2::new(1, 2), a.as_i16vec2());
+    assert_eq!(U16Vec2::new(1, 2), a.as_u16vec2());
+    assert_eq!(IVec2::new(1, 2), a.as_ivec2());
+    assert_eq!(UVec2::new(1, 2), a.as_uvec2());
+    assert_eq!(U64Vec2::new(1, 2), a.as_u64vec2());
+
+    let b = I64Vec2::new(-1, 2);
+    assert_eq!(u64::MAX, b.as_u64vec2().x());
+    assert_eq!(-1, b.as_i16vec2().x());
+}
+
+#[test]
+fn test_i64vec2_dot_wide() {
+    let a = I64Vec2::splat(i64::MIN / 4);
+    assert_eq!(
+        2 * (i128::from(i64::MIN / 4) * i128::from(i64::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        2 * (i128::from(i64::MIN / 4) * i128::from(i64::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i64::MIN / 4).wrapping_mul(i64::MIN / 4).wrapping_mul(2),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i64::MIN / 4).wrapping_mul(i64::MIN / 4).wrapping_mul(2),
+        a.wrapping_dot(a)
+    );
+    let b = I64Vec2::new(1, 2);
+    assert_eq!(5, b.length_squared());
+    assert_eq!(5, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_i64vec2_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let (lo, hi) = (i128::from(i64::MIN / 2), i128::from(i64::MAX / 2));
+    let a = I64Vec2::splat(i64::MIN / 2);
+    let b = I64Vec2::splat(i64::MAX / 2);
+    assert_eq!(2 * lo * lo, a.dot_wide(a));
+    assert_eq!(2 * lo * hi, a.dot_wide(b));
+    assert_eq!(2 * hi * hi, b.length_squared());
+}
+
+#[test]
+fn test_i64vec2_manhattan_chebyshev_extremes() {
+    let a = I64Vec2::splat(i64::MIN);
+    let b = I64Vec2::splat(i64::MAX);
+    let d = i128::from(i64::MAX) - i128::from(i64::MIN);
+    assert_eq!(2 * d, a.manhattan_distance(b));
+    assert_eq!(2 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(2 * -i128::from(i64::MIN), a.manhattan_length());
+    assert_eq!(-i128::from(i64::MIN), a.chebyshev_length());
+}
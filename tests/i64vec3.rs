@@ -0,0 +1,88 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec3_int_tests!(i64, i64vec3, I64Vec3, i64vec2, i64vec4);
+impl_vec3_signed_tests!(i64, i64vec3, I64Vec3);
+
+#[test]
+fn test_i64vec3_conversions() {
+    let a = i64vec3(1, 2, 3);
+    assert_eq!(vec3(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(dvec3(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(ivec3(1, 2, 3), a.as_ivec3());
+    assert_eq!(a, I64Vec3::from(ivec3(1, 2, 3)));
+    assert_eq!(a, I64Vec3::from(uvec3(1, 2, 3)));
+
+    assert_eq!(Ok(ivec3(1, 2, 3)), IVec3::try_from(a));
+    assert!(IVec3::try_from(i64vec3(i64::MAX, 2, 3)).is_err());
+    assert_eq!(Ok(a), I64Vec3::try_from(u64vec3(1, 2, 3)));
+    assert!(I64Vec3::try_from(u64vec3(u64::MAX, 2, 3)).is_err());
+}
+
+#[test]
+fn test_i64vec3_as() {
+    let a = I64Vec3::new(1, 2, 3);
+    assert_eq!(Vec3::new(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), a.as_vec3a());
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(I16Vec3::new(1, 2, 3), a.as_i16vec3());
+    assert_eq!(U16Vec3::new(1, 2, 3), a.as_u16vec3());
+    assert_eq!(IVec3::new(1, 2, 3), a.as_ivec3());
+    assert_eq!(UVec3::new(1, 2, 3), a.as_uvec3());
+    assert_eq!(U64Vec3::new(1, 2, 3), a.as_u64vec3());
+
+    let b = I64Vec3::new(-1, 2, 3);
+    assert_eq!(u64::MAX, b.as_u64vec3().x());
+    assert_eq!(-1, b.as_i16vec3().x());
+}
+
+#[test]
+fn test_i64vec3_dot_wide() {
+    let a = I64Vec3::splat(i64::MIN / 4);
+    assert_eq!(
+        3 * (i128::from(i64::MIN / 4) * i128::from(i64::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        3 * (i128::from(i64::MIN / 4) * i128::from(i64::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i64::MIN / 4).wrapping_mul(i64::MIN / 4).wrapping_mul(3),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i64::MIN / 4).wrapping_mul(i64::MIN / 4).wrapping_mul(3),
+        a.wrapping_dot(a)
+    );
+    let b = I64Vec3::new(1, 2, 3);
+    assert_eq!(14, b.length_squared());
+    assert_eq!(14, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_i64vec3_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let (lo, hi) = (i128::from(i64::MIN / 2), i128::from(i64::MAX / 2));
+    let a = I64Vec3::splat(i64::MIN / 2);
+    let b = I64Vec3::splat(i64::MAX / 2);
+    assert_eq!(3 * lo * lo, a.dot_wide(a));
+    assert_eq!(3 * lo * hi, a.dot_wide(b));
+    assert_eq!(3 * hi * hi, b.length_squared());
+}
+
+#[test]
+fn test_i64vec3_manhattan_chebyshev_extremes() {
+    let a = I64Vec3::splat(i64::MIN);
+    let b = I64Vec3::splat(i64::MAX);
+    let d = i128::from(i64::MAX) - i128::from(i64::MIN);
+    assert_eq!(3 * d, a.manhattan_distance(b));
+    assert_eq!(3 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(3 * -i128::from(i64::MIN), a.manhattan_length());
+    assert_eq!(-i128::from(i64::MIN), a.chebyshev_length());
+}
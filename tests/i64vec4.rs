@@ -0,0 +1,87 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec4_int_tests!(i64, i64vec4, I64Vec4, i64vec3);
+impl_vec4_signed_tests!(i64, i64vec4, I64Vec4);
+
+#[test]
+fn test_i64vec4_conversions() {
+    let a = i64vec4(1, 2, 3, 4);
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(dvec4(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(ivec4(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(a, I64Vec4::from(ivec4(1, 2, 3, 4)));
+    assert_eq!(a, I64Vec4::from(uvec4(1, 2, 3, 4)));
+
+    assert_eq!(Ok(ivec4(1, 2, 3, 4)), IVec4::try_from(a));
+    assert!(IVec4::try_from(i64vec4(i64::MAX, 2, 3, 4)).is_err());
+    assert_eq!(Ok(a), I64Vec4::try_from(u64vec4(1, 2, 3, 4)));
+    assert!(I64Vec4::try_from(u64vec4(u64::MAX, 2, 3, 4)).is_err());
+}
+
+#[test]
+fn test_i64vec4_as() {
+    let a = I64Vec4::new(1, 2, 3, 4);
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(I16Vec4::new(1, 2, 3, 4), a.as_i16vec4());
+    assert_eq!(U16Vec4::new(1, 2, 3, 4), a.as_u16vec4());
+    assert_eq!(IVec4::new(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(UVec4::new(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(U64Vec4::new(1, 2, 3, 4), a.as_u64vec4());
+
+    let b = I64Vec4::new(-1, 2, 3, 4);
+    assert_eq!(u64::MAX, b.as_u64vec4().x());
+    assert_eq!(-1, b.as_i16vec4().x());
+}
+
+#[test]
+fn test_i64vec4_dot_wide() {
+    let a = I64Vec4::splat(i64::MIN / 4);
+    assert_eq!(
+        4 * (i128::from(i64::MIN / 4) * i128::from(i64::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        4 * (i128::from(i64::MIN / 4) * i128::from(i64::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i64::MIN / 4).wrapping_mul(i64::MIN / 4).wrapping_mul(4),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i64::MIN / 4).wrapping_mul(i64::MIN / 4).wrapping_mul(4),
+        a.wrapping_dot(a)
+    );
+    let b = I64Vec4::new(1, 2, 3, 4);
+    assert_eq!(30, b.length_squared());
+    assert_eq!(30, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_i64vec4_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let (lo, hi) = (i128::from(i64::MIN / 2), i128::from(i64::MAX / 2));
+    let a = I64Vec4::splat(i64::MIN / 2);
+    let b = I64Vec4::splat(i64::MAX / 2);
+    assert_eq!(4 * lo * lo, a.dot_wide(a));
+    assert_eq!(4 * lo * hi, a.dot_wide(b));
+    assert_eq!(4 * hi * hi, b.length_squared());
+}
+
+#[test]
+fn test_i64vec4_manhattan_chebyshev_extremes() {
+    let a = I64Vec4::splat(i64::MIN);
+    let b = I64Vec4::splat(i64::MAX);
+    let d = i128::from(i64::MAX) - i128::from(i64::MIN);
+    assert_eq!(4 * d, a.manhattan_distance(b));
+    assert_eq!(4 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(4 * -i128::from(i64::MIN), a.manhattan_length());
+    assert_eq!(-i128::from(i64::MIN), a.chebyshev_length());
+}
@@ -0,0 +1,81 @@
+mod support;
+
+use glam::*;
+
+impl_vec2_int_tests!(i32, ivec2, IVec2, ivec3);
+impl_vec2_signed_tests!(i32, ivec2, IVec2);
+
+#[test]
+fn test_ivec2_float_conversions() {
+    let a = ivec2(1, 2);
+    assert_eq!(vec2(1.0, 2.0), a.as_vec2());
+    assert_eq!(dvec2(1.0, 2.0), a.as_dvec2());
+    assert_eq!(dvec2(1.0, 2.0), DVec2::from(a));
+    assert_eq!(ivec2(-1, 2), vec2(-1.9, 2.9).as_ivec2());
+    assert_eq!(ivec2(-1, 2), dvec2(-1.9, 2.9).as_ivec2());
+}
+
+#[test]
+fn test_ivec2_as() {
+    let a = IVec2::new(1, 2);
+    assert_eq!(Vec2::new(1.0, 2.0), a.as_vec2());
+    assert_eq!(DVec2::new(1.0, 2.0), a.as_dvec2());
+    assert_eq!(I16Vec2::new(1, 2), a.as_i16vec2());
+    assert_eq!(U16Vec2::new(1, 2), a.as_u16vec2());
+    assert_eq!(UVec2::new(1, 2), a.as_uvec2());
+    assert_eq!(I64Vec2::new(1, 2), a.as_i64vec2());
+    assert_eq!(U64Vec2::new(1, 2), a.as_u64vec2());
+
+    let b = IVec2::new(-1, 2);
+    assert_eq!(u32::MAX, b.as_uvec2().x());
+    assert_eq!(-1, b.as_i16vec2().x());
+}
+
+#[test]
+fn test_ivec2_dot_wide() {
+    let a = IVec2::splat(i32::MIN / 4);
+    assert_eq!(
+        2 * (i64::from(i32::MIN / 4) * i64::from(i32::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        2 * (i64::from(i32::MIN / 4) * i64::from(i32::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i32::MIN / 4).wrapping_mul(i32::MIN / 4).wrapping_mul(2),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i32::MIN / 4).wrapping_mul(i32::MIN / 4).wrapping_mul(2),
+        a.wrapping_dot(a)
+    );
+    let b = IVec2::new(1, 2);
+    assert_eq!(5, b.length_squared());
+    assert_eq!(5, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_ivec2_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let (lo, hi) = (i64::from(i32::MIN / 2), i64::from(i32::MAX / 2));
+    let a = IVec2::splat(i32::MIN / 2);
+    let b = IVec2::splat(i32::MAX / 2);
+    assert_eq!(2 * lo * lo, a.dot_wide(a));
+    assert_eq!(2 * lo * hi, a.dot_wide(b));
+    assert_eq!(2 * hi * hi, b.length_squared());
+}
+
+#[test]
+fn test_ivec2_manhattan_chebyshev_extremes() {
+    let a = IVec2::splat(i32::MIN);
+    let b = IVec2::splat(i32::MAX);
+    let d = i64::from(i32::MAX) - i64::from(i32::MIN);
+    assert_eq!(2 * d, a.manhattan_distance(b));
+    assert_eq!(2 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(2 * -i64::from(i32::MIN), a.manhattan_length());
+    assert_eq!(-i64::from(i32::MIN), a.chebyshev_length());
+}
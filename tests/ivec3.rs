@@ -0,0 +1,90 @@
+mod support;
+
+use glam::*;
+
+impl_vec3_int_tests!(i32, ivec3, IVec3, ivec2, ivec4);
+impl_vec3_signed_tests!(i32, ivec3, IVec3);
+
+#[test]
+fn test_ivec3_float_conversions() {
+    let a = ivec3(1, 2, 3);
+    assert_eq!(vec3(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(dvec3(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(dvec3(1.0, 2.0, 3.0), DVec3::from(a));
+    assert_eq!(ivec3(-1, 2, -3), vec3(-1.9, 2.9, -3.9).as_ivec3());
+    assert_eq!(ivec3(-1, 2, -3), dvec3(-1.9, 2.9, -3.9).as_ivec3());
+}
+
+#[test]
+fn test_ivec3_as() {
+    let a = IVec3::new(1, 2, 3);
+    assert_eq!(Vec3::new(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), a.as_vec3a());
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(I16Vec3::new(1, 2, 3), a.as_i16vec3());
+    assert_eq!(U16Vec3::new(1, 2, 3), a.as_u16vec3());
+    assert_eq!(UVec3::new(1, 2, 3), a.as_uvec3());
+    assert_eq!(I64Vec3::new(1, 2, 3), a.as_i64vec3());
+    assert_eq!(U64Vec3::new(1, 2, 3), a.as_u64vec3());
+
+    let b = IVec3::new(-1, 2, 3);
+    assert_eq!(u32::MAX, b.as_uvec3().x());
+    assert_eq!(-1, b.as_i16vec3().x());
+}
+
+#[test]
+fn test_ivec3_dot_wide() {
+    let a = IVec3::splat(i32::MIN / 4);
+    assert_eq!(
+        3 * (i64::from(i32::MIN / 4) * i64::from(i32::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        3 * (i64::from(i32::MIN / 4) * i64::from(i32::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i32::MIN / 4).wrapping_mul(i32::MIN / 4).wrapping_mul(3),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i32::MIN / 4).wrapping_mul(i32::MIN / 4).wrapping_mul(3),
+        a.wrapping_dot(a)
+    );
+    let b = IVec3::new(1, 2, 3);
+    assert_eq!(14, b.length_squared());
+    assert_eq!(14, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_ivec3_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let (lo, hi) = (i64::from(i32::MIN / 2), i64::from(i32::MAX / 2));
+    let a = IVec3::splat(i32::MIN / 2);
+    let b = IVec3::splat(i32::MAX / 2);
+    assert_eq!(3 * lo * lo, a.dot_wide(a));
+    assert_eq!(3 * lo * hi, a.dot_wide(b));
+    assert_eq!(3 * hi * hi, b.length_squared());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_ivec3_dot_wide_overflow() {
+    let a = IVec3::splat(i32::MIN);
+    let _ = a.dot_wide(a);
+}
+
+#[test]
+fn test_ivec3_manhattan_chebyshev_extremes() {
+    let a = IVec3::splat(i32::MIN);
+    let b = IVec3::splat(i32::MAX);
+    let d = i64::from(i32::MAX) - i64::from(i32::MIN);
+    assert_eq!(3 * d, a.manhattan_distance(b));
+    assert_eq!(3 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(3 * -i64::from(i32::MIN), a.manhattan_length());
+    assert_eq!(-i64::from(i32::MIN), a.chebyshev_length());
+}
@@ -0,0 +1,81 @@
+mod support;
+
+use glam::*;
+
+impl_vec4_int_tests!(i32, ivec4, IVec4, ivec3);
+impl_vec4_signed_tests!(i32, ivec4, IVec4);
+
+#[test]
+fn test_ivec4_float_conversions() {
+    let a = ivec4(1, 2, 3, 4);
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(dvec4(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(dvec4(1.0, 2.0, 3.0, 4.0), DVec4::from(a));
+    assert_eq!(ivec4(-1, 2, -3, 4), vec4(-1.9, 2.9, -3.9, 4.9).as_ivec4());
+    assert_eq!(ivec4(-1, 2, -3, 4), dvec4(-1.9, 2.9, -3.9, 4.9).as_ivec4());
+}
+
+#[test]
+fn test_ivec4_as() {
+    let a = IVec4::new(1, 2, 3, 4);
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(I16Vec4::new(1, 2, 3, 4), a.as_i16vec4());
+    assert_eq!(U16Vec4::new(1, 2, 3, 4), a.as_u16vec4());
+    assert_eq!(UVec4::new(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(I64Vec4::new(1, 2, 3, 4), a.as_i64vec4());
+    assert_eq!(U64Vec4::new(1, 2, 3, 4), a.as_u64vec4());
+
+    let b = IVec4::new(-1, 2, 3, 4);
+    assert_eq!(u32::MAX, b.as_uvec4().x());
+    assert_eq!(-1, b.as_i16vec4().x());
+}
+
+#[test]
+fn test_ivec4_dot_wide() {
+    let a = IVec4::splat(i32::MIN / 4);
+    assert_eq!(
+        4 * (i64::from(i32::MIN / 4) * i64::from(i32::MIN / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        4 * (i64::from(i32::MIN / 4) * i64::from(i32::MIN / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (i32::MIN / 4).wrapping_mul(i32::MIN / 4).wrapping_mul(4),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (i32::MIN / 4).wrapping_mul(i32::MIN / 4).wrapping_mul(4),
+        a.wrapping_dot(a)
+    );
+    let b = IVec4::new(1, 2, 3, 4);
+    assert_eq!(30, b.length_squared());
+    assert_eq!(30, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_ivec4_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let (lo, hi) = (i64::from(i32::MIN / 2), i64::from(i32::MAX / 2));
+    let a = IVec4::splat(i32::MIN / 2);
+    let b = IVec4::splat(i32::MAX / 2);
+    assert_eq!(4 * lo * lo, a.dot_wide(a));
+    assert_eq!(4 * lo * hi, a.dot_wide(b));
+    assert_eq!(4 * hi * hi, b.length_squared());
+}
+
+#[test]
+fn test_ivec4_manhattan_chebyshev_extremes() {
+    let a = IVec4::splat(i32::MIN);
+    let b = IVec4::splat(i32::MAX);
+    let d = i64::from(i32::MAX) - i64::from(i32::MIN);
+    assert_eq!(4 * d, a.manhattan_distance(b));
+    assert_eq!(4 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(4 * -i64::from(i32::MIN), a.manhattan_length());
+    assert_eq!(-i64::from(i32::MIN), a.chebyshev_length());
+}
@@ -44,6 +44,103 @@ fn test_mat2_accessors() {
     assert_eq!(Vec2::new(3.0, 4.0), m.y_axis());
 }
 
+#[test]
+fn test_mat2_rows_cols() {
+    let m = Mat2::from_cols_array_2d(&MATRIX);
+    let transpose = m.transpose();
+    let mut rows = Mat2::zero();
+    let mut cols = Mat2::zero();
+    for (i, col) in MATRIX.iter().enumerate() {
+        assert_eq!(Vec2::from(*col), m.col(i));
+        assert_eq!(transpose.col(i), m.row(i));
+        rows.set_row(i, m.row(i));
+        cols.set_col(i, m.col(i));
+    }
+    assert_eq!(m, rows);
+    assert_eq!(m, cols);
+}
+
+#[test]
+#[should_panic]
+fn test_mat2_row_out_of_bounds() {
+    Mat2::identity().row(2);
+}
+
+#[test]
+#[should_panic]
+fn test_mat2_set_col_out_of_bounds() {
+    Mat2::identity().set_col(2, Vec2::zero());
+}
+
+#[test]
+fn test_mat2_index() {
+    let mut m = Mat2::from_cols_array_2d(&MATRIX);
+    for (col, values) in MATRIX.iter().enumerate() {
+        for (row, value) in values.iter().enumerate() {
+            assert_eq!(*value, m[(col, row)]);
+        }
+    }
+    m[(1, 0)] = -1.0;
+    assert_eq!(-1.0, m.y_axis().x());
+    assert_eq!(-1.0, m.row(0).y());
+}
+
+#[test]
+#[should_panic]
+fn test_mat2_index_out_of_bounds() {
+    let _ = Mat2::identity()[(0, 2)];
+}
+
+#[test]
+fn test_mat2_diagonal() {
+    let m = Mat2::from_cols_array_2d(&MATRIX);
+    assert_eq!(Vec2::new(1.0, 4.0), m.diagonal());
+    assert_eq!(5.0, m.trace());
+    assert_eq!(
+        Mat2::from_scale(Vec2::new(2.0, 3.0)),
+        Mat2::from_diagonal(Vec2::new(2.0, 3.0))
+    );
+    assert_eq!(Mat2::identity(), Mat2::from_diagonal(Vec2::one()));
+    assert_eq!(
+        Vec2::new(2.0, 3.0),
+        Mat2::from_diagonal(Vec2::new(2.0, 3.0)).diagonal()
+    );
+}
+
+#[test]
+fn test_mat2_svd() {
+    for m in [
+        Mat2::from_cols_array(&[1.0, 3.0, 2.0, -4.0]),
+        Mat2::from_cols_array(&[2.0, -1.0, 0.5, 3.0]),
+        Mat2::from_scale_angle(Vec2::new(3.0, 0.5), deg(30.0)),
+        Mat2::from_cols_array(&[1.0, 2.0, 2.0, 4.0]),
+    ]
+    .iter()
+    {
+        let (u, s, v) = m.svd();
+        assert_approx_eq!(*m, u * Mat2::from_diagonal(s) * v.transpose(), 1.0e-5);
+        assert!(s.x() >= s.y() && s.y() >= 0.0);
+        assert_approx_eq!(Mat2::identity(), u * u.transpose(), 1.0e-5);
+        assert_approx_eq!(1.0, v.determinant(), 1.0e-5);
+        assert_approx_eq!(m.determinant().signum(), u.determinant(), 1.0e-5);
+    }
+    let (_, s, _) = Mat2::from_scale_angle(Vec2::new(3.0, 0.5), deg(30.0)).svd();
+    assert_approx_eq!(Vec2::new(3.0, 0.5), s, 1.0e-5);
+}
+
+#[test]
+fn test_mat2_is_finite() {
+    assert!(Mat2::identity().is_finite());
+    assert!(!Mat2::identity().is_nan());
+    let mut m = Mat2::identity();
+    m[(1, 0)] = f32::INFINITY;
+    assert!(!m.is_finite());
+    assert!(!m.is_nan());
+    m[(1, 0)] = f32::NAN;
+    assert!(!m.is_finite());
+    assert!(m.is_nan());
+}
+
 #[test]
 fn test_mat2_from_axes() {
     let a = Mat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]);
@@ -131,6 +228,18 @@ fn test_mat2_ops() {
     );
     assert_eq!(Mat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]), m0 + m0);
     assert_eq!(Mat2::zero(), m0 - m0);
+    assert_eq!(Mat2::zero(), m0 + -m0);
+    assert_eq!(m0 * -1.0, -m0);
+    assert_eq!(m0 * 0.5, m0 / 2.0);
+    let v = vec2(1.0, 2.0);
+    let (r0, rv) = (&m0, &v);
+    assert_eq!(m0 * m0, r0 * r0);
+    assert_eq!(m0 * m0, m0 * r0);
+    assert_eq!(m0 * m0, r0 * m0);
+    assert_eq!(m0 + m0, r0 + r0);
+    assert_eq!(m0 - m0, r0 - r0);
+    assert_eq!(m0 * v, r0 * rv);
+    assert_eq!(m0 * 2.0, r0 * 2.0);
     assert_approx_eq!(
         Mat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]),
         m0 * Mat2::identity()
@@ -141,6 +250,67 @@ fn test_mat2_ops() {
     );
 }
 
+#[test]
+fn test_mat2_assign_ops() {
+    let m0 = Mat2::from_cols_array_2d(&MATRIX);
+    let mut m = m0;
+    m += m0;
+    assert_eq!(m0 * 2.0, m);
+    m -= m0;
+    assert_eq!(m0, m);
+    m *= 2.0;
+    assert_eq!(m0 * 2.0, m);
+    m /= 2.0;
+    assert_eq!(m0, m);
+    m = m0;
+    m *= m0;
+    assert_eq!(m0 * m0, m);
+}
+
+#[test]
+fn test_mat2_sum_product() {
+    let m0 = Mat2::from_cols_array_2d(&MATRIX);
+    let ms = [m0, m0, m0];
+    assert_eq!(m0 * 3.0, ms.iter().sum());
+    assert_eq!(m0 * 3.0, ms.iter().copied().sum());
+    assert_eq!(m0 * m0 * m0, ms.iter().product());
+    assert_eq!(m0 * m0 * m0, ms.iter().copied().product());
+    assert_eq!(Mat2::zero(), std::iter::empty::<Mat2>().sum());
+    assert_eq!(Mat2::identity(), std::iter::empty::<Mat2>().product());
+}
+
+#[test]
+fn test_mat2_relative_ulps_eq() {
+    let m0 = Mat2::from_cols_array_2d(&MATRIX) * 1.0e6;
+    let m1 = m0 * (1.0 + 1.0e-6);
+    assert!(!m0.abs_diff_eq(m1, 1.0e-3));
+    assert!(m0.relative_eq(m1, 1.0e-3, 1.0e-5));
+    assert!(!m0.relative_eq(m1, 1.0e-3, 1.0e-7));
+    assert!(!m0.ulps_eq(m1, 1.0e-3, 4));
+    assert!(m0.ulps_eq(m1, 1.0e-3, 32));
+}
+
+#[test]
+fn test_mat2_slice() {
+    let m = Mat2::from_cols_array_2d(&MATRIX);
+    let mut a = [0.0; 5];
+    m.write_to_slice(&mut a[1..]);
+    assert_eq!(&a[1..], &m.to_cols_array()[..]);
+    assert_eq!(m, Mat2::from_slice(&a[1..]));
+    assert_eq!(Ok(m), Mat2::try_from_slice(&a[1..]));
+    let err = Mat2::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(4, err.required_len());
+    let mut b = [0.0; 3];
+    assert_eq!(Err(err), m.try_write_to_slice(&mut b));
+    assert_eq!([0.0; 3], b);
+}
+
+#[test]
+#[should_panic]
+fn test_mat2_slice_too_short() {
+    Mat2::from_slice(&[0.0; 3]);
+}
+
 #[test]
 fn test_mat2_fmt() {
     let a = Mat2::from_cols_array_2d(&MATRIX);
@@ -168,3 +338,13 @@ fn test_mat2_serde() {
     let deserialized = serde_json::from_str::<Mat2>("[[1.0,2.0],[3.0,4.0]]");
     assert!(deserialized.is_err());
 }
+
+#[test]
+fn test_mat2_non_symmetric() {
+    let m = Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(-2.0, m.determinant());
+    assert_eq!(Mat2::from_cols_array(&[-2.0, 1.0, 1.5, -0.5]), m.inverse());
+    assert_eq!(vec2(23.0, 34.0), m * vec2(5.0, 6.0));
+    let n = Mat2::from_cols_array(&[5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(Mat2::from_cols_array(&[23.0, 34.0, 31.0, 46.0]), m * n);
+}
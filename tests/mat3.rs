@@ -1,6 +1,7 @@
 mod support;
 
 use glam::f32::*;
+use glam::EulerRot;
 use support::deg;
 
 const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
@@ -12,13 +13,8 @@ const ZERO: [[f32; 3]; 3] = [[0.0; 3]; 3];
 #[test]
 fn test_mat3_align() {
     use std::mem;
-    if cfg!(feature = "scalar-math") {
-        assert_eq!(36, mem::size_of::<Mat3>());
-        assert_eq!(4, mem::align_of::<Mat3>());
-    } else {
-        assert_eq!(48, mem::size_of::<Mat3>());
-        assert_eq!(16, mem::align_of::<Mat3>());
-    }
+    assert_eq!(36, mem::size_of::<Mat3>());
+    assert_eq!(4, mem::align_of::<Mat3>());
 }
 
 #[test]
@@ -47,6 +43,238 @@ fn test_mat3_accessors() {
     assert_eq!(Vec3::new(7.0, 8.0, 9.0), m.z_axis());
 }
 
+#[test]
+fn test_mat3_submatrix() {
+    let m4 = Mat4::from_cols_array(&[
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    ]);
+    let m3 = Mat3::from_mat4(&m4);
+    assert_eq!(
+        Mat3::from_cols_array(&[1.0, 2.0, 3.0, 5.0, 6.0, 7.0, 9.0, 10.0, 11.0]),
+        m3
+    );
+    assert_eq!(
+        Mat4::from_cols_array(&[
+            1.0, 2.0, 3.0, 0.0, 5.0, 6.0, 7.0, 0.0, 9.0, 10.0, 11.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ]),
+        Mat4::from_mat3(&m3)
+    );
+    assert_eq!(
+        Mat2::from_cols_array(&[1.0, 2.0, 5.0, 6.0]),
+        Mat2::from_mat3(&m3)
+    );
+
+    let m2 = Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        Mat3::from_cols_array(&[1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 0.0, 0.0, 1.0]),
+        Mat3::from_mat2(&m2)
+    );
+    assert_eq!(m2, Mat2::from_mat3(&Mat3::from_mat2(&m2)));
+    assert_eq!(Mat4::identity(), Mat4::from_mat3(&Mat3::identity()));
+}
+
+#[test]
+fn test_mat3_rows_cols() {
+    let m = Mat3::from_cols_array_2d(&MATRIX);
+    let transpose = m.transpose();
+    let mut rows = Mat3::zero();
+    let mut cols = Mat3::zero();
+    for (i, col) in MATRIX.iter().enumerate() {
+        assert_eq!(Vec3::from(*col), m.col(i));
+        assert_eq!(transpose.col(i), m.row(i));
+        rows.set_row(i, m.row(i));
+        cols.set_col(i, m.col(i));
+    }
+    assert_eq!(m, rows);
+    assert_eq!(m, cols);
+}
+
+#[test]
+#[should_panic]
+fn test_mat3_row_out_of_bounds() {
+    Mat3::identity().row(3);
+}
+
+#[test]
+#[should_panic]
+fn test_mat3_set_col_out_of_bounds() {
+    Mat3::identity().set_col(3, Vec3::zero());
+}
+
+#[test]
+fn test_mat3_index() {
+    let mut m = Mat3::from_cols_array_2d(&MATRIX);
+    for (col, values) in MATRIX.iter().enumerate() {
+        for (row, value) in values.iter().enumerate() {
+            assert_eq!(*value, m[(col, row)]);
+        }
+    }
+    m[(1, 0)] = -1.0;
+    assert_eq!(-1.0, m.y_axis().x());
+    assert_eq!(-1.0, m.row(0).y());
+}
+
+#[test]
+#[should_panic]
+fn test_mat3_index_out_of_bounds() {
+    let _ = Mat3::identity()[(0, 3)];
+}
+
+#[test]
+fn test_mat3_diagonal() {
+    let m = Mat3::from_cols_array_2d(&MATRIX);
+    assert_eq!(Vec3::new(1.0, 5.0, 9.0), m.diagonal());
+    assert_eq!(15.0, m.trace());
+    assert_eq!(
+        Mat3::from_scale(Vec3::new(2.0, 3.0, 4.0)),
+        Mat3::from_diagonal(Vec3::new(2.0, 3.0, 4.0))
+    );
+    assert_eq!(Mat3::identity(), Mat3::from_diagonal(Vec3::one()));
+    assert_eq!(
+        Vec3::new(2.0, 3.0, 4.0),
+        Mat3::from_diagonal(Vec3::new(2.0, 3.0, 4.0)).diagonal()
+    );
+}
+
+#[test]
+fn test_mat3_from_cross() {
+    let a = Vec3::new(1.0, -2.0, 3.0);
+    let m = Mat3::from_cross(a);
+    for v in [
+        Vec3::unit_x(),
+        Vec3::unit_y(),
+        Vec3::unit_z(),
+        Vec3::new(4.0, 5.0, -6.0),
+    ]
+    .iter()
+    {
+        assert_eq!(a.cross(*v), m * *v);
+    }
+    assert_eq!(-m, m.transpose());
+}
+
+#[test]
+fn test_mat3_orthonormalize() {
+    let rotation = Mat3::from_quat(Quat::from_rotation_ypr(0.1, 0.2, 0.3));
+    let drifted = rotation
+        + Mat3::from_cols_array(&[
+            1.0e-3, -2.0e-3, 0.5e-3, 2.0e-3, 1.0e-3, -1.0e-3, -0.5e-3, 1.5e-3, 2.0e-3,
+        ]);
+    let m = drifted.orthonormalize();
+    assert_approx_eq!(Mat3::identity(), m * m.transpose(), 1.0e-5);
+    assert_approx_eq!(1.0, m.determinant(), 1.0e-5);
+    assert_approx_eq!(drifted.x_axis().normalize(), m.x_axis(), 1.0e-5);
+    assert_approx_eq!(rotation, m, 1.0e-2);
+    assert_approx_eq!(rotation, rotation.orthonormalize(), 1.0e-5);
+}
+
+#[test]
+fn test_mat3_polar_decomposition() {
+    let rotation = Mat3::from_quat(Quat::from_rotation_ypr(0.1, 0.2, 0.3));
+    let stretch = Mat3::from_cols_array(&[2.0, 0.5, 0.1, 0.5, 1.0, -0.2, 0.1, -0.2, 3.0]);
+    let m = rotation * stretch;
+    let (r, s) = m.polar_decomposition();
+    assert_approx_eq!(rotation, r, 1.0e-5);
+    assert_approx_eq!(stretch, s, 1.0e-5);
+    assert_approx_eq!(m, r * s, 1.0e-5);
+
+    // a reflection stays in the orthogonal part
+    let mirrored = m * Mat3::from_scale(Vec3::new(-1.0, 1.0, 1.0));
+    let (r, s) = mirrored.polar_decomposition();
+    assert_approx_eq!(-1.0, r.determinant(), 1.0e-5);
+    assert_approx_eq!(Mat3::identity(), r * r.transpose(), 1.0e-5);
+    assert_approx_eq!(s, s.transpose(), 1.0e-5);
+    assert_approx_eq!(mirrored, r * s, 1.0e-5);
+}
+
+#[test]
+fn test_mat3_symmetric_eigen() {
+    let rotation = Mat3::from_quat(Quat::from_rotation_ypr(0.1, 0.2, 0.3));
+    let m = rotation * Mat3::from_diagonal(Vec3::new(2.0, -1.0, 5.0)) * rotation.transpose();
+    let (values, vectors) = m.symmetric_eigen();
+    assert_approx_eq!(Vec3::new(5.0, 2.0, -1.0), values, 1.0e-5);
+    assert_approx_eq!(1.0, vectors.determinant(), 1.0e-5);
+    assert_approx_eq!(
+        m,
+        vectors * Mat3::from_diagonal(values) * vectors.transpose(),
+        1.0e-5
+    );
+    for (i, value) in values.as_ref().iter().enumerate() {
+        let v = vectors.col(i);
+        assert_approx_eq!(v * *value, m * v, 1.0e-5);
+    }
+    // the eigenvector of the largest eigenvalue is the rotated z axis
+    assert_approx_eq!(1.0, vectors.col(0).dot(rotation.z_axis()).abs(), 1.0e-5);
+
+    // repeated eigenvalues
+    let (values, vectors) = Mat3::from_diagonal(Vec3::new(1.0, 3.0, 1.0)).symmetric_eigen();
+    assert_eq!(Vec3::new(3.0, 1.0, 1.0), values);
+    assert_eq!(Vec3::unit_y(), vectors.col(0).abs());
+    assert_approx_eq!(1.0, vectors.determinant(), 1.0e-5);
+}
+
+#[test]
+fn test_mat3_svd() {
+    let rotation = Mat3::from_quat(Quat::from_rotation_ypr(0.1, 0.2, 0.3));
+    for m in [
+        Mat3::from_cols_array(&[1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, 8.0, -9.0]),
+        rotation * Mat3::from_scale(Vec3::new(0.5, -3.0, 2.0)),
+        // singular matrices
+        Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+        Mat3::from_cols(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::zero(),
+            Vec3::new(2.0, 4.0, 6.0),
+        ),
+        Mat3::zero(),
+    ]
+    .iter()
+    {
+        let (u, s, v) = m.svd();
+        assert_approx_eq!(*m, u * Mat3::from_diagonal(s) * v.transpose(), 1.0e-4);
+        assert!(s.x() >= s.y() && s.y() >= s.z() && s.z() >= 0.0);
+        assert_approx_eq!(Mat3::identity(), u * u.transpose(), 1.0e-5);
+        assert_approx_eq!(Mat3::identity(), v * v.transpose(), 1.0e-5);
+        assert_approx_eq!(1.0, v.determinant(), 1.0e-5);
+    }
+    let (u, s, _) = (rotation * Mat3::from_scale(Vec3::new(0.5, -3.0, 2.0))).svd();
+    assert_approx_eq!(Vec3::new(3.0, 2.0, 0.5), s, 1.0e-5);
+    assert_approx_eq!(-1.0, u.determinant(), 1.0e-5);
+}
+
+#[test]
+fn test_mat3_scaled_axis() {
+    let v = Vec3::new(0.3, -0.2, 0.5);
+    let m = Mat3::from_scaled_axis(v);
+    assert_approx_eq!(Mat3::from_axis_angle(v.normalize(), v.length()), m, 1.0e-5);
+    assert_approx_eq!(v, m.to_scaled_axis(), 1.0e-5);
+    assert_eq!(Vec3::zero(), Mat3::identity().to_scaled_axis());
+
+    // small angles are close to the identity plus the skew-symmetric matrix
+    let v = Vec3::new(1.0e-4, 2.0e-4, -3.0e-4);
+    let m = Mat3::from_scaled_axis(v);
+    assert_approx_eq!(Mat3::identity() + Mat3::from_cross(v), m, 1.0e-7);
+    assert_approx_eq!(v, m.to_scaled_axis(), 1.0e-9);
+
+    // angles close to a half turn
+    let axis = Vec3::new(1.0, 2.0, -2.0) / 3.0;
+    let v = axis * (std::f32::consts::PI - 1.0e-3);
+    assert_approx_eq!(v, Mat3::from_scaled_axis(v).to_scaled_axis(), 1.0e-5);
+}
+
+#[test]
+fn test_mat3_is_finite() {
+    assert!(Mat3::identity().is_finite());
+    assert!(!Mat3::identity().is_nan());
+    let mut m = Mat3::identity();
+    m[(1, 0)] = f32::INFINITY;
+    assert!(!m.is_finite());
+    assert!(!m.is_nan());
+    m[(1, 0)] = f32::NAN;
+    assert!(!m.is_finite());
+    assert!(m.is_nan());
+}
+
 #[test]
 fn test_mat3_from_axes() {
     let a = Mat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
@@ -115,6 +343,57 @@ fn test_from_ypr() {
     assert_approx_eq!(yxz0, yxz1, 1e-6);
 }
 
+#[test]
+fn test_from_euler() {
+    let x = Mat3::from_rotation_x(deg(30.0));
+    let y = Mat3::from_rotation_y(deg(60.0));
+    let z = Mat3::from_rotation_z(deg(90.0));
+    let (a, b, c) = (deg(30.0), deg(60.0), deg(90.0));
+    assert_approx_eq!(x * y * z, Mat3::from_euler(EulerRot::XYZ, a, b, c), 1e-6);
+    assert_approx_eq!(z * y * x, Mat3::from_euler(EulerRot::XYZEx, a, b, c), 1e-6);
+    assert_approx_eq!(x * z * y, Mat3::from_euler(EulerRot::XZY, a, c, b), 1e-6);
+    assert_approx_eq!(
+        Mat3::from_rotation_ypr(deg(60.0), deg(30.0), deg(90.0)),
+        Mat3::from_euler(EulerRot::YXZ, b, a, c),
+        1e-6
+    );
+}
+
+#[test]
+fn test_mat3_2d_transform() {
+    let m = Mat3::from_translation(Vec2::new(1.0, 2.0));
+    assert_eq!(Vec3::new(1.0, 2.0, 1.0), m.z_axis());
+    assert_eq!(Vec2::new(2.0, 4.0), m.transform_point2(Vec2::new(1.0, 2.0)));
+    assert_eq!(
+        Vec2::new(1.0, 2.0),
+        m.transform_vector2(Vec2::new(1.0, 2.0))
+    );
+
+    let m = Mat3::from_angle(deg(90.0));
+    assert_eq!(Mat3::from_rotation_z(deg(90.0)), m);
+    assert_approx_eq!(Vec2::unit_y(), m.transform_point2(Vec2::unit_x()), 1e-6);
+
+    let scale = Vec2::new(2.0, 3.0);
+    let angle = deg(30.0);
+    let translation = Vec2::new(-4.0, 5.0);
+    let m = Mat3::from_scale_angle_translation(scale, angle, translation);
+    let expected = Mat3::from_translation(translation)
+        * Mat3::from_angle(angle)
+        * Mat3::from_scale(scale.extend(1.0));
+    assert_approx_eq!(expected, m, 1e-6);
+    let p = Vec2::new(1.0, -1.0);
+    assert_approx_eq!(
+        expected.mul_vec3(p.extend(1.0)).truncate(),
+        m.transform_point2(p),
+        1e-6
+    );
+    assert_approx_eq!(
+        expected.mul_vec3(p.extend(0.0)).truncate(),
+        m.transform_vector2(p),
+        1e-6
+    );
+}
+
 #[test]
 fn test_from_scale() {
     let m = Mat3::from_scale(Vec3::new(2.0, 4.0, 8.0));
@@ -188,16 +467,89 @@ fn test_mat3_ops() {
     assert_eq!(m0x2, 2.0 * m0);
     assert_eq!(m0x2, m0 + m0);
     assert_eq!(Mat3::zero(), m0 - m0);
+    assert_eq!(Mat3::zero(), m0 + -m0);
+    assert_eq!(m0 * -1.0, -m0);
+    assert_eq!(m0 * 0.5, m0 / 2.0);
+    let v = vec3(1.0, 2.0, 3.0);
+    let (r0, rv) = (&m0, &v);
+    assert_eq!(m0 * m0, r0 * r0);
+    assert_eq!(m0 * m0, m0 * r0);
+    assert_eq!(m0 * m0, r0 * m0);
+    assert_eq!(m0 + m0, r0 + r0);
+    assert_eq!(m0 - m0, r0 - r0);
+    assert_eq!(m0 * v, r0 * rv);
+    assert_eq!(m0 * 2.0, r0 * 2.0);
     assert_approx_eq!(m0, m0 * Mat3::identity());
     assert_approx_eq!(m0, Mat3::identity() * m0);
 }
 
+#[test]
+fn test_mat3_assign_ops() {
+    let m0 = Mat3::from_cols_array_2d(&MATRIX);
+    let mut m = m0;
+    m += m0;
+    assert_eq!(m0 * 2.0, m);
+    m -= m0;
+    assert_eq!(m0, m);
+    m *= 2.0;
+    assert_eq!(m0 * 2.0, m);
+    m /= 2.0;
+    assert_eq!(m0, m);
+    m = m0;
+    m *= m0;
+    assert_eq!(m0 * m0, m);
+}
+
+#[test]
+fn test_mat3_sum_product() {
+    let m0 = Mat3::from_cols_array_2d(&MATRIX);
+    let ms = [m0, m0, m0];
+    assert_eq!(m0 * 3.0, ms.iter().sum());
+    assert_eq!(m0 * 3.0, ms.iter().copied().sum());
+    assert_eq!(m0 * m0 * m0, ms.iter().product());
+    assert_eq!(m0 * m0 * m0, ms.iter().copied().product());
+    assert_eq!(Mat3::zero(), std::iter::empty::<Mat3>().sum());
+    assert_eq!(Mat3::identity(), std::iter::empty::<Mat3>().product());
+}
+
+#[test]
+fn test_mat3_relative_ulps_eq() {
+    let m0 = Mat3::from_cols_array_2d(&MATRIX) * 1.0e6;
+    let m1 = m0 * (1.0 + 1.0e-6);
+    assert!(!m0.abs_diff_eq(m1, 1.0e-3));
+    assert!(m0.relative_eq(m1, 1.0e-3, 1.0e-5));
+    assert!(!m0.relative_eq(m1, 1.0e-3, 1.0e-7));
+    assert!(!m0.ulps_eq(m1, 1.0e-3, 4));
+    assert!(m0.ulps_eq(m1, 1.0e-3, 32));
+}
+
 #[test]
 fn test_mat3_fmt() {
     let a = Mat3::from_cols_array_2d(&MATRIX);
     assert_eq!(format!("{}", a), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
 }
 
+#[test]
+fn test_mat3_slice() {
+    let m = Mat3::from_cols_array_2d(&MATRIX);
+    let mut a = [0.0; 10];
+    m.write_to_slice(&mut a[1..]);
+    assert_eq!(&a[1..], &m.to_cols_array()[..]);
+    assert_eq!(m, Mat3::from_slice(&a[1..]));
+    assert_eq!(Ok(m), Mat3::try_from_slice(&a[1..]));
+    let err = Mat3::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(9, err.required_len());
+    let mut b = [0.0; 8];
+    assert_eq!(Err(err), m.try_write_to_slice(&mut b));
+    assert_eq!([0.0; 8], b);
+}
+
+#[test]
+#[should_panic]
+fn test_mat3_slice_too_short() {
+    Mat3::from_slice(&[0.0; 8]);
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_mat3_serde() {
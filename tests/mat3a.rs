@@ -0,0 +1,422 @@
+mod support;
+
+use glam::f32::*;
+use support::deg;
+
+const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+const MATRIX: [[f32; 3]; 3] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+const ZERO: [[f32; 3]; 3] = [[0.0; 3]; 3];
+
+#[test]
+fn test_mat3a_align() {
+    use std::mem;
+    assert_eq!(48, mem::size_of::<Mat3A>());
+    assert_eq!(16, mem::align_of::<Mat3A>());
+}
+
+#[test]
+fn test_mat3a_identity() {
+    let identity = Mat3A::identity();
+    assert_eq!(IDENTITY, identity.to_cols_array_2d());
+    assert_eq!(Mat3A::from_cols_array_2d(&IDENTITY), identity);
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, Mat3A::default());
+}
+
+#[test]
+fn test_mat3a_zero() {
+    assert_eq!(Mat3A::from_cols_array_2d(&ZERO), Mat3A::zero());
+}
+
+#[test]
+fn test_mat3a_accessors() {
+    let mut m = Mat3A::zero();
+    m.set_x_axis(Vec3A::new(1.0, 2.0, 3.0));
+    m.set_y_axis(Vec3A::new(4.0, 5.0, 6.0));
+    m.set_z_axis(Vec3A::new(7.0, 8.0, 9.0));
+    assert_eq!(Mat3A::from_cols_array_2d(&MATRIX), m);
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), m.x_axis());
+    assert_eq!(Vec3A::new(4.0, 5.0, 6.0), m.y_axis());
+    assert_eq!(Vec3A::new(7.0, 8.0, 9.0), m.z_axis());
+}
+
+#[test]
+fn test_mat3a_submatrix() {
+    let m4 = Mat4::from_cols_array(&[
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    ]);
+    let m3 = Mat3A::from_mat4(&m4);
+    assert_eq!(
+        Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 5.0, 6.0, 7.0, 9.0, 10.0, 11.0]),
+        m3
+    );
+    assert_eq!(
+        Mat4::from_cols_array(&[
+            1.0, 2.0, 3.0, 0.0, 5.0, 6.0, 7.0, 0.0, 9.0, 10.0, 11.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ]),
+        Mat4::from_mat3a(&m3)
+    );
+    assert_eq!(
+        Mat2::from_cols_array(&[1.0, 2.0, 5.0, 6.0]),
+        Mat2::from_mat3a(&m3)
+    );
+
+    let m2 = Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        Mat3A::from_cols_array(&[1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 0.0, 0.0, 1.0]),
+        Mat3A::from_mat2(&m2)
+    );
+    assert_eq!(m2, Mat2::from_mat3a(&Mat3A::from_mat2(&m2)));
+    assert_eq!(Mat4::identity(), Mat4::from_mat3a(&Mat3A::identity()));
+}
+
+#[test]
+fn test_mat3a_rows_cols() {
+    let m = Mat3A::from_cols_array_2d(&MATRIX);
+    let transpose = m.transpose();
+    let mut rows = Mat3A::zero();
+    let mut cols = Mat3A::zero();
+    for (i, col) in MATRIX.iter().enumerate() {
+        assert_eq!(Vec3A::from(*col), m.col(i));
+        assert_eq!(transpose.col(i), m.row(i));
+        rows.set_row(i, m.row(i));
+        cols.set_col(i, m.col(i));
+    }
+    assert_eq!(m, rows);
+    assert_eq!(m, cols);
+}
+
+#[test]
+#[should_panic]
+fn test_mat3a_row_out_of_bounds() {
+    Mat3A::identity().row(3);
+}
+
+#[test]
+#[should_panic]
+fn test_mat3a_set_col_out_of_bounds() {
+    Mat3A::identity().set_col(3, Vec3A::zero());
+}
+
+#[test]
+fn test_mat3a_index() {
+    let mut m = Mat3A::from_cols_array_2d(&MATRIX);
+    for (col, values) in MATRIX.iter().enumerate() {
+        for (row, value) in values.iter().enumerate() {
+            assert_eq!(*value, m[(col, row)]);
+        }
+    }
+    m[(1, 0)] = -1.0;
+    assert_eq!(-1.0, m.y_axis().x());
+    assert_eq!(-1.0, m.row(0).y());
+}
+
+#[test]
+#[should_panic]
+fn test_mat3a_index_out_of_bounds() {
+    let _ = Mat3A::identity()[(0, 3)];
+}
+
+#[test]
+fn test_mat3a_diagonal() {
+    let m = Mat3A::from_cols_array_2d(&MATRIX);
+    assert_eq!(Vec3A::new(1.0, 5.0, 9.0), m.diagonal());
+    assert_eq!(15.0, m.trace());
+    assert_eq!(
+        Mat3A::from_scale(Vec3A::new(2.0, 3.0, 4.0)),
+        Mat3A::from_diagonal(Vec3A::new(2.0, 3.0, 4.0))
+    );
+    assert_eq!(Mat3A::identity(), Mat3A::from_diagonal(Vec3A::one()));
+    assert_eq!(
+        Vec3A::new(2.0, 3.0, 4.0),
+        Mat3A::from_diagonal(Vec3A::new(2.0, 3.0, 4.0)).diagonal()
+    );
+}
+
+#[test]
+fn test_mat3a_from_cross() {
+    let a = Vec3A::new(1.0, -2.0, 3.0);
+    let m = Mat3A::from_cross(a);
+    for v in [
+        Vec3A::unit_x(),
+        Vec3A::unit_y(),
+        Vec3A::unit_z(),
+        Vec3A::new(4.0, 5.0, -6.0),
+    ]
+    .iter()
+    {
+        assert_eq!(a.cross(*v), m * *v);
+    }
+    assert_eq!(-m, m.transpose());
+}
+
+#[test]
+fn test_mat3a_from_axes() {
+    let a = Mat3A::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = Mat3A::from_cols(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a, b);
+    let c = mat3a(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a, c);
+    let d = b.to_cols_array();
+    let f = Mat3A::from_cols_array(&d);
+    assert_eq!(b, f);
+}
+
+#[test]
+fn test_mat3a_from_rotation() {
+    let rot_x1 = Mat3A::from_rotation_x(deg(180.0));
+    let rot_x2 = Mat3A::from_axis_angle(Vec3A::unit_x(), deg(180.0));
+    assert_approx_eq!(rot_x1, rot_x2);
+    let rot_y1 = Mat3A::from_rotation_y(deg(180.0));
+    let rot_y2 = Mat3A::from_axis_angle(Vec3A::unit_y(), deg(180.0));
+    assert_approx_eq!(rot_y1, rot_y2);
+    let rot_z1 = Mat3A::from_rotation_z(deg(180.0));
+    let rot_z2 = Mat3A::from_axis_angle(Vec3A::unit_z(), deg(180.0));
+    assert_approx_eq!(rot_z1, rot_z2);
+}
+
+#[test]
+fn test_mat3a_mul() {
+    let mat_a = Mat3A::from_axis_angle(Vec3A::unit_z(), deg(90.0));
+    let result3 = mat_a * Vec3A::unit_y();
+    assert_approx_eq!(vec3a(-1.0, 0.0, 0.0), result3);
+}
+
+#[test]
+fn test_mat3a_from_ypr() {
+    let zero = deg(0.0);
+    let yaw = deg(30.0);
+    let pitch = deg(60.0);
+    let roll = deg(90.0);
+    let y0 = Mat3A::from_rotation_y(yaw);
+    let y1 = Mat3A::from_rotation_ypr(yaw, zero, zero);
+    assert_approx_eq!(y0, y1);
+
+    let x0 = Mat3A::from_rotation_x(pitch);
+    let x1 = Mat3A::from_rotation_ypr(zero, pitch, zero);
+    assert_approx_eq!(x0, x1);
+
+    let z0 = Mat3A::from_rotation_z(roll);
+    let z1 = Mat3A::from_rotation_ypr(zero, zero, roll);
+    assert_approx_eq!(z0, z1);
+
+    let yx0 = y0 * x0;
+    let yx1 = Mat3A::from_rotation_ypr(yaw, pitch, zero);
+    assert_approx_eq!(yx0, yx1);
+
+    let yxz0 = y0 * x0 * z0;
+    let yxz1 = Mat3A::from_rotation_ypr(yaw, pitch, roll);
+    assert_approx_eq!(yxz0, yxz1, 1e-6);
+}
+
+#[test]
+fn test_mat3a_2d_transform() {
+    let m =
+        Mat3A::from_scale_angle_translation(Vec2::new(2.0, 3.0), deg(30.0), Vec2::new(-4.0, 5.0));
+    let p = Vec2::new(1.0, -1.0);
+    assert_approx_eq!(
+        (m * Vec3A::from(p.extend(1.0))).truncate(),
+        m.transform_point2(p),
+        1e-6
+    );
+    assert_approx_eq!(
+        (m * Vec3A::from(p.extend(0.0))).truncate(),
+        m.transform_vector2(p),
+        1e-6
+    );
+    assert_eq!(
+        Vec2::new(2.0, 4.0),
+        Mat3A::from_translation(Vec2::new(1.0, 2.0)).transform_point2(Vec2::new(1.0, 2.0))
+    );
+}
+
+#[test]
+fn test_mat3a_from_scale() {
+    let m = Mat3A::from_scale(Vec3A::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(m * Vec3A::new(1.0, 1.0, 1.0), Vec3A::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(Vec3A::unit_x() * 2.0, m.x_axis());
+    assert_approx_eq!(Vec3A::unit_y() * 4.0, m.y_axis());
+    assert_approx_eq!(Vec3A::unit_z() * 8.0, m.z_axis());
+}
+
+#[test]
+fn test_mat3a_transpose() {
+    let m = mat3a(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+    );
+    let mt = m.transpose();
+    assert_eq!(mt.x_axis(), vec3a(1.0, 4.0, 7.0));
+    assert_eq!(mt.y_axis(), vec3a(2.0, 5.0, 8.0));
+    assert_eq!(mt.z_axis(), vec3a(3.0, 6.0, 9.0));
+}
+
+#[test]
+fn test_mat3a_det() {
+    assert_eq!(0.0, Mat3A::zero().determinant());
+    assert_eq!(1.0, Mat3A::identity().determinant());
+    assert_eq!(1.0, Mat3A::from_rotation_x(deg(90.0)).determinant());
+    assert_eq!(1.0, Mat3A::from_rotation_y(deg(180.0)).determinant());
+    assert_eq!(1.0, Mat3A::from_rotation_z(deg(270.0)).determinant());
+    assert_eq!(
+        2.0 * 2.0 * 2.0,
+        Mat3A::from_scale(vec3a(2.0, 2.0, 2.0)).determinant()
+    );
+}
+
+#[test]
+fn test_mat3a_inverse() {
+    // assert_eq!(None, Mat3A::zero().inverse());
+    let inv = Mat3A::identity().inverse();
+    // assert_ne!(None, inv);
+    assert_approx_eq!(Mat3A::identity(), inv);
+
+    let rotz = Mat3A::from_rotation_z(deg(90.0));
+    let rotz_inv = rotz.inverse();
+    // assert_ne!(None, rotz_inv);
+    // let rotz_inv = rotz_inv.unwrap();
+    assert_approx_eq!(Mat3A::identity(), rotz * rotz_inv);
+    assert_approx_eq!(Mat3A::identity(), rotz_inv * rotz);
+
+    let scale = Mat3A::from_scale(vec3a(4.0, 5.0, 6.0));
+    let scale_inv = scale.inverse();
+    // assert_ne!(None, scale_inv);
+    // let scale_inv = scale_inv.unwrap();
+    assert_approx_eq!(Mat3A::identity(), scale * scale_inv);
+    assert_approx_eq!(Mat3A::identity(), scale_inv * scale);
+
+    let m = scale * rotz;
+    let m_inv = m.inverse();
+    // assert_ne!(None, m_inv);
+    // let m_inv = m_inv.unwrap();
+    assert_approx_eq!(Mat3A::identity(), m * m_inv);
+    assert_approx_eq!(Mat3A::identity(), m_inv * m);
+    assert_approx_eq!(m_inv, rotz_inv * scale_inv);
+}
+
+#[test]
+fn test_mat3a_ops() {
+    let m0 = Mat3A::from_cols_array_2d(&MATRIX);
+    let m0x2 = Mat3A::from_cols_array_2d(&[[2.0, 4.0, 6.0], [8.0, 10.0, 12.0], [14.0, 16.0, 18.0]]);
+    assert_eq!(m0x2, m0 * 2.0);
+    assert_eq!(m0x2, 2.0 * m0);
+    assert_eq!(m0x2, m0 + m0);
+    assert_eq!(Mat3A::zero(), m0 - m0);
+    assert_eq!(Mat3A::zero(), m0 + -m0);
+    assert_eq!(m0 * -1.0, -m0);
+    assert_eq!(m0 * 0.5, m0 / 2.0);
+    let v = vec3a(1.0, 2.0, 3.0);
+    let (r0, rv) = (&m0, &v);
+    assert_eq!(m0 * m0, r0 * r0);
+    assert_eq!(m0 * m0, m0 * r0);
+    assert_eq!(m0 * m0, r0 * m0);
+    assert_eq!(m0 + m0, r0 + r0);
+    assert_eq!(m0 - m0, r0 - r0);
+    assert_eq!(m0 * v, r0 * rv);
+    assert_eq!(m0 * 2.0, r0 * 2.0);
+    assert_approx_eq!(m0, m0 * Mat3A::identity());
+    assert_approx_eq!(m0, Mat3A::identity() * m0);
+}
+
+#[test]
+fn test_mat3a_assign_ops() {
+    let m0 = Mat3A::from_cols_array_2d(&MATRIX);
+    let mut m = m0;
+    m += m0;
+    assert_eq!(m0 * 2.0, m);
+    m -= m0;
+    assert_eq!(m0, m);
+    m *= 2.0;
+    assert_eq!(m0 * 2.0, m);
+    m /= 2.0;
+    assert_eq!(m0, m);
+    m = m0;
+    m *= m0;
+    assert_eq!(m0 * m0, m);
+}
+
+#[test]
+fn test_mat3a_sum_product() {
+    let m0 = Mat3A::from_cols_array_2d(&MATRIX);
+    let ms = [m0, m0, m0];
+    assert_eq!(m0 * 3.0, ms.iter().sum());
+    assert_eq!(m0 * 3.0, ms.iter().copied().sum());
+    assert_eq!(m0 * m0 * m0, ms.iter().product());
+    assert_eq!(m0 * m0 * m0, ms.iter().copied().product());
+    assert_eq!(Mat3A::zero(), std::iter::empty::<Mat3A>().sum());
+    assert_eq!(Mat3A::identity(), std::iter::empty::<Mat3A>().product());
+}
+
+#[test]
+fn test_mat3a_from_mat3() {
+    let a = Mat3A::from_cols_array_2d(&MATRIX);
+    let b = Mat3::from_cols_array_2d(&MATRIX);
+    assert_eq!(a, Mat3A::from(b));
+    assert_eq!(b, Mat3::from(a));
+    assert_eq!(MATRIX, Mat3::from(a).to_cols_array_2d());
+}
+
+#[test]
+fn test_mat3a_fmt() {
+    let a = Mat3A::from_cols_array_2d(&MATRIX);
+    assert_eq!(format!("{}", a), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+}
+
+#[test]
+fn test_mat3a_slice() {
+    let m = Mat3A::from_cols_array_2d(&MATRIX);
+    let mut a = [0.0; 10];
+    m.write_to_slice(&mut a[1..]);
+    assert_eq!(&a[1..], &m.to_cols_array()[..]);
+    assert_eq!(m, Mat3A::from_slice(&a[1..]));
+    assert_eq!(Ok(m), Mat3A::try_from_slice(&a[1..]));
+    let err = Mat3A::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(9, err.required_len());
+    let mut b = [0.0; 8];
+    assert_eq!(Err(err), m.try_write_to_slice(&mut b));
+    assert_eq!([0.0; 8], b);
+}
+
+#[test]
+#[should_panic]
+fn test_mat3a_slice_too_short() {
+    Mat3A::from_slice(&[0.0; 8]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_mat3a_serde() {
+    let a = Mat3A::from_cols(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+    );
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Mat3A>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[1.0,2.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[1.0,2.0,3.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[1.0,2.0,3.0,4.0,5.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+    assert!(deserialized.is_err());
+}
@@ -0,0 +1,146 @@
+#[macro_use]
+mod support;
+
+use glam::*;
+use support::deg;
+
+const MATRIX: [[f32; 2]; 3] = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+
+#[test]
+fn test_mat3x2_identity() {
+    let identity = Mat3x2::identity();
+    assert_eq!([1.0, 0.0, 0.0, 1.0, 0.0, 0.0], identity.to_cols_array());
+    assert_eq!(Mat3x2::default(), identity);
+    let p = Vec2::new(1.0, -2.0);
+    assert_eq!(p, identity.transform_point2(p));
+    assert_eq!(Mat3x2::zero().to_cols_array(), [0.0; 6]);
+}
+
+#[test]
+fn test_mat3x2_from_axes() {
+    let a = Mat3x2::from_cols_array_2d(&MATRIX);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    assert_eq!(a, Mat3x2::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    assert_eq!(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], a.as_ref());
+    let b = mat3x2(vec2(1.0, 2.0), vec2(3.0, 4.0), vec2(5.0, 6.0));
+    assert_eq!(a, b);
+    assert_eq!(vec2(1.0, 2.0), b.x_axis());
+    assert_eq!(vec2(3.0, 4.0), b.y_axis());
+    assert_eq!(vec2(5.0, 6.0), b.z_axis());
+    assert_eq!(b.z_axis(), b.translation());
+    assert_eq!(Mat2::from_cols(vec2(1.0, 2.0), vec2(3.0, 4.0)), b.matrix2());
+
+    let mut c = a;
+    c.as_mut()[4] = 7.0;
+    assert_eq!(vec2(7.0, 6.0), c.translation());
+}
+
+#[test]
+fn test_mat3x2_transform() {
+    let m = Mat3x2::from_scale_angle_translation(vec2(2.0, 3.0), deg(90.0), vec2(1.0, 2.0));
+    let m3 = Mat3::from_scale_angle_translation(vec2(2.0, 3.0), deg(90.0), vec2(1.0, 2.0));
+    let p = vec2(4.0, -5.0);
+    assert_approx_eq!(m3.transform_point2(p), m.transform_point2(p));
+    assert_approx_eq!(m3.transform_vector2(p), m.transform_vector2(p));
+    assert_approx_eq!(m3.determinant(), m.determinant());
+
+    assert_eq!(
+        vec2(2.0, 4.0),
+        Mat3x2::from_translation(vec2(1.0, 2.0)).transform_point2(vec2(1.0, 2.0))
+    );
+    assert_eq!(
+        vec2(1.0, 2.0),
+        Mat3x2::from_translation(vec2(1.0, 2.0)).transform_vector2(vec2(1.0, 2.0))
+    );
+    assert_approx_eq!(
+        vec2(-2.0, 1.0),
+        Mat3x2::from_angle(deg(90.0)).transform_point2(vec2(1.0, 2.0))
+    );
+    assert_eq!(
+        vec2(2.0, 6.0),
+        Mat3x2::from_scale(vec2(2.0, 3.0)).transform_point2(vec2(1.0, 2.0))
+    );
+}
+
+#[test]
+fn test_mat3x2_mul_inverse() {
+    let a = Mat3x2::from_scale_angle_translation(vec2(2.0, 0.5), deg(30.0), vec2(1.0, -2.0));
+    let b = Mat3x2::from_scale_angle_translation(vec2(1.0, 3.0), deg(-75.0), vec2(4.0, 3.0));
+    let p = vec2(4.0, -5.0);
+
+    // multiplication applies the right hand side first
+    assert_approx_eq!(
+        a.transform_point2(b.transform_point2(p)),
+        (a * b).transform_point2(p),
+        1.0e-5
+    );
+    assert_approx_eq!(Mat3::from(a * b), Mat3::from(a) * Mat3::from(b), 1.0e-5);
+
+    let mut c = a;
+    c *= b;
+    assert_eq!(a * b, c);
+    let (ra, rb) = (&a, &b);
+    assert_eq!(a * b, ra * rb);
+
+    let inv = a.inverse();
+    assert!((a * inv).abs_diff_eq(Mat3x2::identity(), 1.0e-6));
+    assert!((inv * a).abs_diff_eq(Mat3x2::identity(), 1.0e-6));
+    assert!((inv * a).relative_eq(Mat3x2::identity(), 1.0e-6, 1.0e-6));
+    assert!((inv * a).ulps_eq(Mat3x2::identity(), 1.0e-6, 4));
+    assert_approx_eq!(Mat3::from(a).inverse(), Mat3::from(inv), 1.0e-6);
+}
+
+#[test]
+fn test_mat3x2_conversions() {
+    let a = Mat3x2::from_cols_array_2d(&MATRIX);
+    let m3 = Mat3::from(a);
+    assert_eq!(
+        [1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0, 1.0],
+        m3.to_cols_array()
+    );
+    assert_eq!(a, Mat3x2::from_mat3(&m3));
+
+    let m4 = Mat4::from(a);
+    let p = vec2(4.0, -5.0);
+    assert_eq!(
+        a.transform_point2(p).extend(0.0),
+        m4.transform_point3(p.extend(0.0))
+    );
+    assert_eq!(
+        a.transform_vector2(p).extend(0.0),
+        m4.transform_vector3(p.extend(0.0))
+    );
+    assert_eq!(vec3(0.0, 0.0, 1.0), m4.transform_vector3(Vec3::unit_z()));
+
+    assert_eq!(
+        a,
+        Mat3x2::from_mat2_translation(a.matrix2(), a.translation())
+    );
+}
+
+#[test]
+fn test_mat3x2_fmt() {
+    let a = Mat3x2::from_cols_array_2d(&MATRIX);
+    assert_eq!(format!("{}", a), "[[1, 2], [3, 4], [5, 6]]");
+}
+
+#[test]
+fn test_mat3x2_slice() {
+    let m = Mat3x2::from_cols_array_2d(&MATRIX);
+    let mut a = [0.0; 7];
+    m.write_to_slice(&mut a[1..]);
+    assert_eq!(&a[1..], &m.to_cols_array()[..]);
+    assert_eq!(m, Mat3x2::from_slice(&a[1..]));
+    assert_eq!(Ok(m), Mat3x2::try_from_slice(&a[1..]));
+    let err = Mat3x2::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(6, err.required_len());
+    let mut b = [0.0; 5];
+    assert_eq!(Err(err), m.try_write_to_slice(&mut b));
+    assert_eq!([0.0; 5], b);
+}
+
+#[test]
+#[should_panic]
+fn test_mat3x2_slice_too_short() {
+    Mat3x2::from_slice(&[0.0; 5]);
+}
@@ -1,6 +1,7 @@
 mod support;
 
 use glam::f32::*;
+use glam::EulerRot;
 use support::deg;
 
 const IDENTITY: [[f32; 4]; 4] = [
@@ -58,6 +59,100 @@ fn test_mat4_accessors() {
     assert_eq!(Vec4::new(13.0, 14.0, 15.0, 16.0), m.w_axis());
 }
 
+#[test]
+fn test_mat4_rows_cols() {
+    let m = Mat4::from_cols_array_2d(&MATRIX);
+    let transpose = m.transpose();
+    let mut rows = Mat4::zero();
+    let mut cols = Mat4::zero();
+    for (i, col) in MATRIX.iter().enumerate() {
+        assert_eq!(Vec4::from(*col), m.col(i));
+        assert_eq!(transpose.col(i), m.row(i));
+        rows.set_row(i, m.row(i));
+        cols.set_col(i, m.col(i));
+    }
+    assert_eq!(m, rows);
+    assert_eq!(m, cols);
+}
+
+#[test]
+#[should_panic]
+fn test_mat4_row_out_of_bounds() {
+    Mat4::identity().row(4);
+}
+
+#[test]
+#[should_panic]
+fn test_mat4_set_col_out_of_bounds() {
+    Mat4::identity().set_col(4, Vec4::zero());
+}
+
+#[test]
+fn test_mat4_index() {
+    let mut m = Mat4::from_cols_array_2d(&MATRIX);
+    for (col, values) in MATRIX.iter().enumerate() {
+        for (row, value) in values.iter().enumerate() {
+            assert_eq!(*value, m[(col, row)]);
+        }
+    }
+    m[(1, 0)] = -1.0;
+    assert_eq!(-1.0, m.y_axis().x());
+    assert_eq!(-1.0, m.row(0).y());
+}
+
+#[test]
+#[should_panic]
+fn test_mat4_index_out_of_bounds() {
+    let _ = Mat4::identity()[(0, 4)];
+}
+
+#[test]
+fn test_mat4_diagonal() {
+    let m = Mat4::from_cols_array_2d(&MATRIX);
+    assert_eq!(Vec4::new(1.0, 6.0, 11.0, 16.0), m.diagonal());
+    assert_eq!(34.0, m.trace());
+    assert_eq!(
+        Mat4::from_scale(Vec3::new(2.0, 3.0, 4.0)),
+        Mat4::from_diagonal(Vec4::new(2.0, 3.0, 4.0, 1.0))
+    );
+    assert_eq!(Mat4::identity(), Mat4::from_diagonal(Vec4::one()));
+    assert_eq!(
+        Vec4::new(2.0, 3.0, 4.0, 1.0),
+        Mat4::from_diagonal(Vec4::new(2.0, 3.0, 4.0, 1.0)).diagonal()
+    );
+}
+
+#[test]
+fn test_mat4_orthonormalize() {
+    let rotation = Mat4::from_quat(Quat::from_rotation_ypr(0.1, 0.2, 0.3));
+    let drifted = rotation
+        + Mat4::from_cols_array(&[
+            1.0e-3, -2.0e-3, 0.5e-3, 0.0, 2.0e-3, 1.0e-3, -1.0e-3, 0.0, -0.5e-3, 1.5e-3, 2.0e-3,
+            0.0, 0.0, 0.0, 0.0, 0.0,
+        ])
+        + Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0))
+        - Mat4::identity();
+    let m = drifted.orthonormalize();
+    assert_eq!(drifted.w_axis(), m.w_axis());
+    assert!(m.is_affine());
+    let m3 = Mat3::from_mat4(&m);
+    assert_approx_eq!(Mat3::identity(), m3 * m3.transpose(), 1.0e-5);
+    assert_approx_eq!(Mat3::from_mat4(&drifted).orthonormalize(), m3);
+}
+
+#[test]
+fn test_mat4_is_finite() {
+    assert!(Mat4::identity().is_finite());
+    assert!(!Mat4::identity().is_nan());
+    let mut m = Mat4::identity();
+    m[(1, 0)] = f32::INFINITY;
+    assert!(!m.is_finite());
+    assert!(!m.is_nan());
+    m[(1, 0)] = f32::NAN;
+    assert!(!m.is_finite());
+    assert!(m.is_nan());
+}
+
 #[test]
 fn test_mat4_from_axes() {
     let a = Mat4::from_cols_array_2d(&[
@@ -135,6 +230,12 @@ fn test_mat4_mul() {
     let result3 = mat_b.transform_point3(Vec3::unit_y());
     assert_approx_eq!(vec3(1.0, 2.0, 4.5), result3, 1.0e-6);
     assert_approx_eq!(result3, (mat_b * Vec3::unit_y().extend(1.0)).truncate());
+
+    let result3a = mat_b.transform_vector3a(Vec3A::unit_y());
+    assert_approx_eq!(vec3a(0.0, 0.0, 1.5), result3a, 1.0e-6);
+
+    let result3a = mat_b.transform_point3a(Vec3A::unit_y());
+    assert_approx_eq!(vec3a(1.0, 2.0, 4.5), result3a, 1.0e-6);
 }
 
 #[test]
@@ -164,6 +265,161 @@ fn test_from_ypr() {
     assert_approx_eq!(yxz0, yxz1, 1e-6);
 }
 
+#[test]
+fn test_from_euler() {
+    let (a, b, c) = (deg(30.0), deg(60.0), deg(90.0));
+    let x = Mat4::from_rotation_x(a);
+    let y = Mat4::from_rotation_y(b);
+    let z = Mat4::from_rotation_z(c);
+    assert_approx_eq!(z * y * x, Mat4::from_euler(EulerRot::ZYX, c, b, a), 1e-6);
+    assert_approx_eq!(x * y * z, Mat4::from_euler(EulerRot::ZYXEx, c, b, a), 1e-6);
+}
+
+#[test]
+fn test_mat4_transform_slice() {
+    let m = Mat4::from_scale_rotation_translation(
+        Vec3::new(0.5, 1.5, 2.0),
+        Quat::from_rotation_ypr(0.5, -1.0, 2.0),
+        Vec3::new(1.0, -2.0, 3.0),
+    );
+    let points = [
+        Vec3::zero(),
+        Vec3::unit_x(),
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(-4.0, 0.5, 10.0),
+    ];
+
+    let mut out = [Vec3::zero(); 4];
+    m.transform_point3_slice(&points, &mut out);
+    let mut in_place = points;
+    m.transform_point3_slice_in_place(&mut in_place);
+    for i in 0..points.len() {
+        assert_approx_eq!(m.transform_point3(points[i]), out[i], 1e-5);
+        assert_eq!(out[i], in_place[i]);
+    }
+
+    m.transform_vector3_slice(&points, &mut out);
+    let mut in_place = points;
+    m.transform_vector3_slice_in_place(&mut in_place);
+    for i in 0..points.len() {
+        assert_approx_eq!(m.transform_vector3(points[i]), out[i], 1e-5);
+        assert_eq!(out[i], in_place[i]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_mat4_transform_slice_len_mismatch() {
+    let mut out = [Vec3::zero(); 1];
+    Mat4::identity().transform_point3_slice(&[Vec3::zero(); 2], &mut out);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_mat4_par_transform_slice() {
+    let m = Mat4::from_scale_rotation_translation(
+        Vec3::new(1.0, 2.0, 3.0),
+        Quat::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0)),
+        Vec3::new(-4.0, 5.0, 6.0),
+    );
+    let points: Vec<Vec3> = (0..5000)
+        .map(|i| Vec3::new(i as f32, (i % 7) as f32, -(i as f32) * 0.5))
+        .collect();
+
+    let mut expected = vec![Vec3::zero(); points.len()];
+    let mut out = vec![Vec3::zero(); points.len()];
+    m.transform_point3_slice(&points, &mut expected);
+    m.par_transform_point3_slice(&points, &mut out);
+    assert_eq!(expected, out);
+    let mut in_place = points.clone();
+    m.par_transform_point3_slice_in_place(&mut in_place);
+    assert_eq!(expected, in_place);
+
+    m.transform_vector3_slice(&points, &mut expected);
+    m.par_transform_vector3_slice(&points, &mut out);
+    assert_eq!(expected, out);
+    let mut in_place = points;
+    m.par_transform_vector3_slice_in_place(&mut in_place);
+    assert_eq!(expected, in_place);
+}
+
+#[test]
+fn test_mat4_blend4() {
+    let a = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    let b = Mat4::from_rotation_z(deg(90.0));
+    let c = Mat4::from_scale(Vec3::splat(2.0));
+    let d = Mat4::identity();
+    let matrices = [a, b, c, d];
+    let weights = vec4(0.1, 0.2, 0.3, 0.4);
+    let mut expected = [0.0; 16];
+    for (m, w) in matrices.iter().zip(<[f32; 4]>::from(weights).iter()) {
+        for (e, v) in expected.iter_mut().zip(m.to_cols_array().iter()) {
+            *e += v * w;
+        }
+    }
+    assert_approx_eq!(
+        Mat4::blend4(&matrices, weights),
+        Mat4::from_cols_array(&expected)
+    );
+    assert_eq!(Mat4::blend4(&matrices, Vec4::unit_y()), b);
+}
+
+#[test]
+fn test_mat4_skin_slice() {
+    let palette = [
+        Mat4::identity(),
+        Mat4::from_translation(Vec3::new(0.0, 2.0, 0.0)),
+        Mat4::from_rotation_x(deg(90.0)),
+    ];
+    let joints = [
+        glam::u16vec4(0, 0, 0, 0),
+        glam::u16vec4(1, 0, 0, 0),
+        glam::u16vec4(0, 1, 0, 0),
+        glam::u16vec4(2, 1, 0, 0),
+    ];
+    let weights = [
+        Vec4::unit_x(),
+        Vec4::unit_x(),
+        vec4(0.5, 0.5, 0.0, 0.0),
+        vec4(0.25, 0.75, 0.0, 0.0),
+    ];
+    let original = [(Vec3::new(1.0, 2.0, 3.0), Vec3::unit_y()); 4];
+    let mut vertices = original;
+    Mat4::skin_slice(&palette, &joints, &weights, &mut vertices);
+    for i in 0..vertices.len() {
+        let j: [u16; 4] = joints[i].into();
+        let w: [f32; 4] = weights[i].into();
+        let mut m = [0.0; 16];
+        for (j, w) in j.iter().zip(w.iter()) {
+            for (e, v) in m
+                .iter_mut()
+                .zip(palette[*j as usize].to_cols_array().iter())
+            {
+                *e += v * w;
+            }
+        }
+        let m = Mat4::from_cols_array(&m);
+        let (position, normal) = original[i];
+        assert_approx_eq!(vertices[i].0, m.transform_point3(position), 1e-5);
+        assert_approx_eq!(vertices[i].1, m.transform_vector3(normal).normalize(), 1e-5);
+        assert!(vertices[i].1.is_normalized());
+    }
+    assert_eq!(vertices[0], original[0]);
+    assert_approx_eq!(vertices[1].0, Vec3::new(1.0, 4.0, 3.0));
+}
+
+#[test]
+#[should_panic]
+fn test_mat4_skin_slice_len_mismatch() {
+    let mut vertices = [(Vec3::zero(), Vec3::unit_y()); 2];
+    Mat4::skin_slice(
+        &[Mat4::identity()],
+        &[glam::u16vec4(0, 0, 0, 0)],
+        &[Vec4::unit_x(); 2],
+        &mut vertices,
+    );
+}
+
 #[test]
 fn test_from_scale() {
     let m = Mat4::from_scale(Vec3::new(2.0, 4.0, 8.0));
@@ -190,6 +446,7 @@ fn test_mat4_transpose() {
     assert_eq!(mt.y_axis(), vec4(2.0, 6.0, 10.0, 14.0));
     assert_eq!(mt.z_axis(), vec4(3.0, 7.0, 11.0, 15.0));
     assert_eq!(mt.w_axis(), vec4(4.0, 8.0, 12.0, 16.0));
+    assert_eq!(m, mt.transpose());
 }
 
 #[test]
@@ -242,6 +499,68 @@ fn test_mat4_inverse() {
     assert_approx_eq!(m_inv, trans_inv * rotz_inv * scale_inv, 1.0e-6);
 }
 
+#[test]
+fn test_mat4_inverse_general() {
+    // a matrix without any zero or symmetric elements exercises every cofactor
+    let m = Mat4::from_cols_array(&[
+        2.0, 1.0, 0.5, 3.0, //
+        -1.0, 4.0, 2.0, 1.0, //
+        0.5, -2.0, 3.0, -1.0, //
+        1.0, 0.5, -1.5, 2.0,
+    ]);
+    let m_inv = m.inverse();
+    assert_approx_eq!(Mat4::identity(), m * m_inv, 1.0e-5);
+    assert_approx_eq!(Mat4::identity(), m_inv * m, 1.0e-5);
+    assert_approx_eq!(m, m_inv.inverse(), 1.0e-5);
+    assert_approx_eq!(1.0 / m.determinant(), m_inv.determinant(), 1.0e-6);
+}
+
+#[test]
+fn test_mat4_inverse_affine() {
+    let rotation = Quat::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let translation = Vec3::new(1.0, -2.0, 3.0);
+    let rigid = Mat4::from_rotation_translation(rotation, translation);
+    assert!(rigid.is_affine());
+    assert_approx_eq!(rigid.inverse(), rigid.inverse_rigid(), 1.0e-6);
+    assert_approx_eq!(rigid.inverse(), rigid.inverse_affine(), 1.0e-6);
+
+    let m = Mat4::from_scale_rotation_translation(Vec3::new(2.0, -3.0, 4.0), rotation, translation);
+    assert!(m.is_affine());
+    assert_approx_eq!(m.inverse(), m.inverse_affine(), 1.0e-6);
+    assert_approx_eq!(Mat4::identity(), m * m.inverse_affine(), 1.0e-6);
+
+    let projection = Mat4::perspective_glu_rh(deg(60.0), 1.5, 0.1, 100.0);
+    assert!(!projection.is_affine());
+}
+
+#[test]
+fn test_mat4_normal_matrix() {
+    let rotation = Quat::from_rotation_z(deg(30.0));
+    let m = Mat4::from_scale_rotation_translation(
+        Vec3::new(2.0, 1.0, 0.5),
+        rotation,
+        Vec3::new(1.0, 2.0, 3.0),
+    );
+    let normal_matrix = m.normal_matrix();
+    assert_approx_eq!(
+        Mat3::from_quat(rotation) * Mat3::from_scale(Vec3::new(0.5, 1.0, 2.0)),
+        normal_matrix,
+        1.0e-6
+    );
+
+    // a transformed normal stays perpendicular to the transformed surface
+    let tangent = Vec3::new(1.0, -1.0, 0.0);
+    let normal = Vec3::new(1.0, 1.0, 1.0);
+    assert_eq!(0.0, tangent.dot(normal));
+    let tangent = m.transform_vector3(tangent);
+    let normal = normal_matrix * normal;
+    assert_approx_eq!(0.0, tangent.dot(normal), 1.0e-6);
+
+    // rotations are unchanged
+    let r = Mat4::from_quat(rotation);
+    assert_approx_eq!(Mat3::from_quat(rotation), r.normal_matrix(), 1.0e-6);
+}
+
 #[test]
 fn test_mat4_look_at() {
     let eye = Vec3::new(0.0, 0.0, -5.0);
@@ -254,6 +573,111 @@ fn test_mat4_look_at() {
     assert_approx_eq!(rh.transform_point3(point), Vec3::new(0.0, 1.0, -5.0));
 }
 
+#[test]
+fn test_mat4_look_to() {
+    let eye = Vec3::new(1.0, 2.0, -5.0);
+    let dir = Vec3::new(0.5, -1.0, 2.0);
+    let up = Vec3::unit_y();
+    let lh = Mat4::look_to_lh(eye, dir, up);
+    let rh = Mat4::look_to_rh(eye, dir, up);
+    assert_approx_eq!(Mat4::look_at_lh(eye, eye + dir, up), lh);
+    assert_approx_eq!(Mat4::look_at_rh(eye, eye + dir, up), rh);
+    // the eye is at the origin and the direction is along +z or -z in view space
+    assert_approx_eq!(Vec3::zero(), lh.transform_point3(eye), 1.0e-6);
+    assert_approx_eq!(Vec3::zero(), rh.transform_point3(eye), 1.0e-6);
+    let forward = dir.length();
+    assert_approx_eq!(
+        Vec3::new(0.0, 0.0, forward),
+        lh.transform_vector3(dir),
+        1.0e-6
+    );
+    assert_approx_eq!(
+        Vec3::new(0.0, 0.0, -forward),
+        rh.transform_vector3(dir),
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_mat4_to_scale_rotation_translation() {
+    let scale = Vec3::new(2.0, 3.0, 4.0);
+    let rotation = Quat::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let translation = Vec3::new(1.0, -2.0, 3.0);
+    let m = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+    let (s, r, t) = m.to_scale_rotation_translation();
+    assert_approx_eq!(scale, s, 1.0e-6);
+    assert_approx_eq!(rotation, r, 1.0e-6);
+    assert_eq!(translation, t);
+
+    // mirroring is returned as a negative x scale
+    for scale in [
+        Vec3::new(-2.0, 3.0, 4.0),
+        Vec3::new(2.0, -3.0, 4.0),
+        Vec3::new(-2.0, -3.0, -4.0),
+    ]
+    .iter()
+    {
+        let m = Mat4::from_scale_rotation_translation(*scale, rotation, translation);
+        let (s, r, t) = m.to_scale_rotation_translation();
+        assert!(s.x() < 0.0);
+        assert!(r.is_normalized());
+        assert_approx_eq!(scale.abs(), s.abs(), 1.0e-5);
+        assert_approx_eq!(m, Mat4::from_scale_rotation_translation(s, r, t), 1.0e-5);
+    }
+
+    // a negative scale on two axes is the same as a rotation
+    let m =
+        Mat4::from_scale_rotation_translation(Vec3::new(-1.0, -1.0, 1.0), rotation, translation);
+    let (s, r, _) = m.to_scale_rotation_translation();
+    assert_approx_eq!(Vec3::one(), s, 1.0e-6);
+    assert!(r.abs_diff_eq_rotation(rotation * Quat::from_rotation_z(deg(180.0)), 1.0e-6));
+}
+
+#[test]
+fn test_mat4_transform_point_vector() {
+    let m = Mat4::from_scale_rotation_translation(
+        Vec3::new(2.0, 3.0, 4.0),
+        Quat::from_rotation_y(deg(90.0)),
+        Vec3::new(1.0, 2.0, 3.0),
+    );
+    let v = Vec3::new(1.0, -1.0, 0.5);
+    assert_approx_eq!((m * v.extend(1.0)).truncate(), m.transform_point3(v));
+    assert_approx_eq!((m * v.extend(0.0)).truncate(), m.transform_vector3(v));
+    assert_approx_eq!(
+        Vec3A::from(m.transform_point3(v)),
+        m.project_point3a(v.into())
+    );
+}
+
+#[test]
+fn test_mat4_project_point3() {
+    let m = Mat4::perspective_glu_rh(deg(90.0), 2.0, 1.0, 10.0);
+    assert_approx_eq!(
+        Vec3::new(0.0, 0.0, -1.0),
+        m.project_point3(Vec3::new(0.0, 0.0, -1.0))
+    );
+    assert_approx_eq!(
+        Vec3::new(0.0, 0.0, 1.0),
+        m.project_point3(Vec3::new(0.0, 0.0, -10.0)),
+        1.0e-6
+    );
+    assert_approx_eq!(
+        Vec3::new(0.5, 1.0, 1.0),
+        m.project_point3(Vec3::new(10.0, 10.0, -10.0)),
+        1.0e-6
+    );
+    let p = Vec3::new(2.0, -3.0, -4.0);
+    assert_approx_eq!(
+        Vec3A::from(m.project_point3(p)),
+        m.project_point3a(p.into()),
+        1.0e-6
+    );
+    // projection matrices are not affine, so transform_point3 skips the divide
+    assert!(!m
+        .transform_point3(p)
+        .abs_diff_eq(m.project_point3(p), 1.0e-3));
+}
+
 #[test]
 fn test_mat4_ops() {
     let m0 = Mat4::from_cols_array_2d(&MATRIX);
@@ -267,10 +691,63 @@ fn test_mat4_ops() {
     assert_eq!(m0x2, 2.0 * m0);
     assert_eq!(m0x2, m0 + m0);
     assert_eq!(Mat4::zero(), m0 - m0);
+    assert_eq!(Mat4::zero(), m0 + -m0);
+    assert_eq!(m0 * -1.0, -m0);
+    assert_eq!(m0 * 0.5, m0 / 2.0);
+    let v = vec4(1.0, 2.0, 3.0, 4.0);
+    let (r0, rv) = (&m0, &v);
+    assert_eq!(m0 * m0, r0 * r0);
+    assert_eq!(m0 * m0, m0 * r0);
+    assert_eq!(m0 * m0, r0 * m0);
+    assert_eq!(m0 + m0, r0 + r0);
+    assert_eq!(m0 - m0, r0 - r0);
+    assert_eq!(m0 * v, r0 * rv);
+    assert_eq!(m0 * 2.0, r0 * 2.0);
     assert_approx_eq!(m0, m0 * Mat4::identity());
     assert_approx_eq!(m0, Mat4::identity() * m0);
 }
 
+#[test]
+fn test_mat4_assign_ops() {
+    let m0 = Mat4::from_cols_array_2d(&MATRIX);
+    let mut m = m0;
+    m += m0;
+    assert_eq!(m0 * 2.0, m);
+    m -= m0;
+    assert_eq!(m0, m);
+    m *= 2.0;
+    assert_eq!(m0 * 2.0, m);
+    m /= 2.0;
+    assert_eq!(m0, m);
+    m = m0;
+    m *= m0;
+    assert_eq!(m0 * m0, m);
+}
+
+#[test]
+fn test_mat4_sum_product() {
+    let m0 = Mat4::from_cols_array_2d(&MATRIX);
+    let ms = [m0, m0, m0];
+    assert_eq!(m0 * 3.0, ms.iter().sum());
+    assert_eq!(m0 * 3.0, ms.iter().copied().sum());
+    assert_eq!(m0 * m0 * m0, ms.iter().product());
+    assert_eq!(m0 * m0 * m0, ms.iter().copied().product());
+    assert_eq!(Mat4::zero(), std::iter::empty::<Mat4>().sum());
+    assert_eq!(Mat4::identity(), std::iter::empty::<Mat4>().product());
+}
+
+#[test]
+fn test_mat4_relative_ulps_eq() {
+    let m0 = Mat4::from_cols_array_2d(&MATRIX) * 1.0e6;
+    let m1 = m0 * (1.0 + 1.0e-6);
+    assert!(!m0.abs_diff_eq(m1, 1.0e-3));
+    assert!(m0.relative_eq(m1, 1.0e-3, 1.0e-5));
+    assert!(!m0.relative_eq(m1, 1.0e-3, 1.0e-7));
+    assert!(m0.ulps_eq(m0 * 1.0, 0.0, 0));
+    assert!(!m0.ulps_eq(m1, 1.0e-3, 4));
+    assert!(m0.ulps_eq(m1, 1.0e-3, 32));
+}
+
 #[test]
 fn test_mat4_fmt() {
     let a = Mat4::from_cols_array_2d(&MATRIX);
@@ -313,3 +790,69 @@ fn test_mat4_serde() {
     );
     assert!(deserialized.is_err());
 }
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+#[test]
+fn test_mat4_m128_cols() {
+    use std::arch::x86_64::*;
+    let m = Mat4::from_cols_array_2d(&MATRIX);
+    let cols: [__m128; 4] = m.into();
+    assert_eq!(Vec4::from(cols[0]), m.x_axis());
+    assert_eq!(Vec4::from(cols[1]), m.y_axis());
+    assert_eq!(Vec4::from(cols[2]), m.z_axis());
+    assert_eq!(Vec4::from(cols[3]), m.w_axis());
+    assert_eq!(Mat4::from(cols), m);
+}
+
+#[test]
+fn test_mat4_slice() {
+    #[repr(align(32))]
+    struct Aligned([f32; 20]);
+    let m = Mat4::from_cols_array_2d(&MATRIX);
+    let mut a = Aligned([0.0; 20]);
+    m.write_to_slice_unaligned(&mut a.0[1..]);
+    assert_eq!(&a.0[1..17], &m.to_cols_array()[..]);
+    assert_eq!(Mat4::from_slice_unaligned(&a.0[1..]), m);
+
+    m.write_to_slice_aligned(&mut a.0).unwrap();
+    assert_eq!(&a.0[..16], &m.to_cols_array()[..]);
+    assert_eq!(Mat4::from_slice_aligned(&a.0), Ok(m));
+
+    let align = core::mem::align_of::<Mat4>();
+    if align > core::mem::align_of::<f32>() {
+        let err = Mat4::from_slice_aligned(&a.0[1..]).unwrap_err();
+        assert_eq!(err.required_alignment(), align);
+        assert_eq!(m.write_to_slice_aligned(&mut a.0[1..]), Err(err));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_mat4_slice_too_short() {
+    Mat4::from_slice_unaligned(&[0.0; 15]);
+}
+
+#[test]
+fn test_mat4_try_slice() {
+    let m = Mat4::from_cols_array_2d(&MATRIX);
+    let mut a = [0.0; 17];
+    m.write_to_slice(&mut a[1..]);
+    assert_eq!(&a[1..], &m.to_cols_array()[..]);
+    assert_eq!(m, Mat4::from_slice(&a[1..]));
+    assert_eq!(Ok(m), Mat4::try_from_slice(&a[1..]));
+    let err = Mat4::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(16, err.required_len());
+    let mut b = [0.0; 15];
+    assert_eq!(Err(err), m.try_write_to_slice(&mut b));
+    assert_eq!([0.0; 15], b);
+}
+
+#[test]
+#[should_panic]
+fn test_mat4_from_slice_too_short() {
+    Mat4::from_slice(&[0.0; 15]);
+}
@@ -0,0 +1,166 @@
+#[macro_use]
+mod support;
+
+use glam::*;
+use support::deg;
+
+const MATRIX: [[f32; 3]; 4] = [
+    [1.0, 2.0, 3.0],
+    [4.0, 5.0, 6.0],
+    [7.0, 8.0, 9.0],
+    [10.0, 11.0, 12.0],
+];
+
+#[test]
+fn test_mat4x3_identity() {
+    let identity = Mat4x3::identity();
+    assert_eq!(
+        [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+        identity.to_cols_array()
+    );
+    assert_eq!(Mat4x3::default(), identity);
+    let p = vec3(1.0, -2.0, 3.0);
+    assert_eq!(p, identity.transform_point3(p));
+    assert_eq!(Mat4x3::zero().to_cols_array(), [0.0; 12]);
+}
+
+#[test]
+fn test_mat4x3_from_axes() {
+    let a = Mat4x3::from_cols_array_2d(&MATRIX);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let cols = [
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+    ];
+    assert_eq!(a, Mat4x3::from_cols_array(&cols));
+    assert_eq!(&cols, a.as_ref());
+    let b = mat4x3(
+        vec3(1.0, 2.0, 3.0),
+        vec3(4.0, 5.0, 6.0),
+        vec3(7.0, 8.0, 9.0),
+        vec3(10.0, 11.0, 12.0),
+    );
+    assert_eq!(a, b);
+    assert_eq!(vec3(1.0, 2.0, 3.0), b.x_axis());
+    assert_eq!(vec3(4.0, 5.0, 6.0), b.y_axis());
+    assert_eq!(vec3(7.0, 8.0, 9.0), b.z_axis());
+    assert_eq!(vec3(10.0, 11.0, 12.0), b.w_axis());
+    assert_eq!(b.w_axis(), b.translation());
+
+    let rows = [
+        1.0, 4.0, 7.0, 10.0, 2.0, 5.0, 8.0, 11.0, 3.0, 6.0, 9.0, 12.0,
+    ];
+    assert_eq!(rows, a.to_rows_array());
+    assert_eq!(a, Mat4x3::from_rows_array(&rows));
+
+    let mut c = a;
+    c.as_mut()[9] = 13.0;
+    assert_eq!(vec3(13.0, 11.0, 12.0), c.translation());
+}
+
+#[test]
+fn test_mat4x3_transform() {
+    let scale = vec3(2.0, 3.0, 0.5);
+    let rotation = Quat::from_rotation_ypr(deg(30.0), deg(-45.0), deg(60.0));
+    let translation = vec3(1.0, 2.0, 3.0);
+    let m = Mat4x3::from_scale_rotation_translation(scale, rotation, translation);
+    let m4 = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+    let p = vec3(4.0, -5.0, 6.0);
+    assert_approx_eq!(m4.transform_point3(p), m.transform_point3(p), 1.0e-5);
+    assert_approx_eq!(m4.transform_vector3(p), m.transform_vector3(p), 1.0e-5);
+    assert_approx_eq!(m4.determinant(), m.determinant(), 1.0e-5);
+    assert_approx_eq!(Mat4::from(m), m4, 1.0e-6);
+    assert_approx_eq!(
+        Mat4::from_rotation_translation(rotation, translation),
+        Mat4::from(Mat4x3::from_rotation_translation(rotation, translation)),
+        1.0e-6
+    );
+    assert_eq!(
+        vec3(2.0, 4.0, 6.0),
+        Mat4x3::from_translation(translation).transform_point3(translation)
+    );
+    assert_eq!(
+        translation,
+        Mat4x3::from_translation(translation).transform_vector3(translation)
+    );
+}
+
+#[test]
+fn test_mat4x3_mul_inverse() {
+    let a = Mat4x3::from_scale_rotation_translation(
+        vec3(2.0, 0.5, 1.5),
+        Quat::from_rotation_x(deg(30.0)),
+        vec3(1.0, -2.0, 3.0),
+    );
+    let b = Mat4x3::from_scale_rotation_translation(
+        vec3(1.0, 3.0, 0.5),
+        Quat::from_rotation_z(deg(-75.0)),
+        vec3(4.0, 3.0, -1.0),
+    );
+    let p = vec3(4.0, -5.0, 6.0);
+
+    // multiplication applies the right hand side first
+    assert_approx_eq!(
+        a.transform_point3(b.transform_point3(p)),
+        (a * b).transform_point3(p),
+        1.0e-5
+    );
+    assert_approx_eq!(Mat4::from(a * b), Mat4::from(a) * Mat4::from(b), 1.0e-5);
+
+    let mut c = a;
+    c *= b;
+    assert_eq!(a * b, c);
+    let (ra, rb) = (&a, &b);
+    assert_eq!(a * b, ra * rb);
+
+    let inv = a.inverse();
+    assert!((a * inv).abs_diff_eq(Mat4x3::identity(), 1.0e-6));
+    assert!((inv * a).abs_diff_eq(Mat4x3::identity(), 1.0e-6));
+    assert!((inv * a).relative_eq(Mat4x3::identity(), 1.0e-6, 1.0e-6));
+    assert!((inv * a).ulps_eq(Mat4x3::identity(), 1.0e-6, 4));
+    assert_approx_eq!(Mat4::from(a).inverse(), Mat4::from(inv), 1.0e-6);
+}
+
+#[test]
+fn test_mat4x3_conversions() {
+    let a = Mat4x3::from_cols_array_2d(&MATRIX);
+    let m4 = Mat4::from(a);
+    assert_eq!(
+        [1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0, 7.0, 8.0, 9.0, 0.0, 10.0, 11.0, 12.0, 1.0],
+        m4.to_cols_array()
+    );
+    assert_eq!(a, Mat4x3::from_mat4(&m4));
+    assert_eq!(
+        a,
+        Mat4x3::from_mat3_translation(a.matrix3(), a.translation())
+    );
+}
+
+#[test]
+fn test_mat4x3_fmt() {
+    let a = Mat4x3::from_cols_array_2d(&MATRIX);
+    assert_eq!(
+        format!("{}", a),
+        "[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]"
+    );
+}
+
+#[test]
+fn test_mat4x3_slice() {
+    let m = Mat4x3::from_cols_array_2d(&MATRIX);
+    let mut a = [0.0; 13];
+    m.write_to_slice(&mut a[1..]);
+    assert_eq!(&a[1..], &m.to_cols_array()[..]);
+    assert_eq!(m, Mat4x3::from_slice(&a[1..]));
+    assert_eq!(Ok(m), Mat4x3::try_from_slice(&a[1..]));
+    let err = Mat4x3::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(12, err.required_len());
+    let mut b = [0.0; 11];
+    assert_eq!(Err(err), m.try_write_to_slice(&mut b));
+    assert_eq!([0.0; 11], b);
+}
+
+#[test]
+#[should_panic]
+fn test_mat4x3_slice_too_short() {
+    Mat4x3::from_slice(&[0.0; 11]);
+}
@@ -1,6 +1,7 @@
 mod support;
 
-use glam::f32::{quat, Mat3, Mat4, Quat, Vec3, Vec4};
+use glam::f32::{quat, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use glam::EulerRot;
 use support::{deg, rad};
 
 #[test]
@@ -28,9 +29,9 @@ fn test_quat_rotation() {
     assert_approx_eq!(y0, y1);
     let y2 = Quat::from_axis_angle(Vec3::unit_y(), yaw);
     assert_approx_eq!(y0, y2);
-    let y3 = Quat::from_rotation_mat3(&Mat3::from_rotation_y(yaw));
+    let y3 = Quat::from_mat3(&Mat3::from_rotation_y(yaw));
     assert_approx_eq!(y0, y3);
-    let y4 = Quat::from_rotation_mat3(&Mat3::from_quat(y0));
+    let y4 = Quat::from_mat3(&Mat3::from_quat(y0));
     assert_approx_eq!(y0, y4);
 
     let x0 = Quat::from_rotation_x(pitch);
@@ -41,7 +42,7 @@ fn test_quat_rotation() {
     assert_approx_eq!(x0, x1);
     let x2 = Quat::from_axis_angle(Vec3::unit_x(), pitch);
     assert_approx_eq!(x0, x2);
-    let x3 = Quat::from_rotation_mat4(&Mat4::from_rotation_x(deg(180.0)));
+    let x3 = Quat::from_mat4(&Mat4::from_rotation_x(deg(180.0)));
     assert_approx_eq!(Quat::from_rotation_x(deg(180.0)), x3);
 
     let z0 = Quat::from_rotation_z(roll);
@@ -52,7 +53,7 @@ fn test_quat_rotation() {
     assert_approx_eq!(z0, z1);
     let z2 = Quat::from_axis_angle(Vec3::unit_z(), roll);
     assert_approx_eq!(z0, z2);
-    let z3 = Quat::from_rotation_mat4(&Mat4::from_rotation_z(roll));
+    let z3 = Quat::from_mat4(&Mat4::from_rotation_z(roll));
     assert_approx_eq!(z0, z3);
 
     let yx0 = y0 * x0;
@@ -67,7 +68,7 @@ fn test_quat_rotation() {
     let yx2 = yxz0 * z0.conjugate();
     assert_approx_eq!(yx0, yx2);
 
-    let yxz2 = Quat::from_rotation_mat4(&Mat4::from_quat(yxz0));
+    let yxz2 = Quat::from_mat4(&Mat4::from_quat(yxz0));
     assert_approx_eq!(yxz0, yxz2);
 
     // if near identity, just returns x axis and 0 rotation
@@ -76,6 +77,28 @@ fn test_quat_rotation() {
     assert_eq!(angle, rad(0.0));
 }
 
+#[test]
+fn test_quat_from_mat() {
+    let axes = [
+        Vec3::unit_x(),
+        Vec3::unit_y(),
+        -Vec3::unit_z(),
+        Vec3::new(1.0, 1.0, 1.0).normalize(),
+        Vec3::new(-0.3, 0.8, 0.2).normalize(),
+    ];
+    for axis in axes.iter().copied() {
+        for angle in [0.0, 30.0, 90.0, 150.0, 179.9, 180.0, 270.0].iter() {
+            let q = Quat::from_axis_angle(axis, deg(*angle));
+            // q and -q are the same rotation
+            let q3 = Quat::from_mat3(&Mat3::from_quat(q));
+            assert_approx_eq!(q, if q3.dot(q) < 0.0 { -q3 } else { q3 }, 1.0e-6);
+            assert!(q3.is_normalized());
+            let q4 = Quat::from_mat4(&Mat4::from_quat(q));
+            assert_approx_eq!(q, if q4.dot(q) < 0.0 { -q4 } else { q4 }, 1.0e-6);
+        }
+    }
+}
+
 #[test]
 fn test_quat_new() {
     let ytheta = deg(45.0);
@@ -135,6 +158,50 @@ fn test_quat_mul_vec() {
     let mrzx = qrx * qrz;
     assert_approx_eq!(Vec3::unit_z(), mrzx * Vec3::unit_x());
     assert_approx_eq!(-Vec3::unit_x(), mrzx * Vec3::unit_y());
+
+    assert_approx_eq!(Vec3A::unit_z(), qrzx * Vec3A::unit_x());
+    assert_approx_eq!(-Vec3A::unit_x(), qrzx.mul_vec3a(Vec3A::unit_y()));
+}
+
+#[test]
+fn test_quat_mul_vec_matches_mat3() {
+    let axes = [
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(-0.5, 0.25, 1.0),
+        Vec3::new(0.0, -1.0, 0.0),
+    ];
+    let points = [
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(-2.0, 3.5, 0.25),
+        Vec3::new(10.0, -20.0, 30.0),
+    ];
+    for axis in axes.iter() {
+        for angle in [-2.5, -0.1, 0.0, 1.0, 3.0].iter() {
+            let q = Quat::from_axis_angle(axis.normalize(), *angle);
+            let m = Mat3::from_quat(q);
+            for p in points.iter() {
+                let expected = m * *p;
+                assert_approx_eq!(expected, q * *p, 1e-5 * p.length());
+                assert_approx_eq!(expected, q.mul_vec3(*p), 1e-5 * p.length());
+                assert_approx_eq!(
+                    Vec3A::from(expected),
+                    q.mul_vec3a(Vec3A::from(*p)),
+                    1e-5 * p.length()
+                );
+                assert_approx_eq!(expected, -q * *p, 1e-5 * p.length());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_quat_is_finite() {
+    assert!(Quat::identity().is_finite());
+    assert!(!Quat::identity().is_nan());
+    assert!(!Quat::new(0.0, 0.0, f32::INFINITY, 1.0).is_finite());
+    let nan = Quat::new(f32::NAN, 0.0, 0.0, 1.0);
+    assert!(!nan.is_finite());
+    assert!(nan.is_nan());
 }
 
 #[test]
@@ -151,6 +218,8 @@ fn test_quat_funcs() {
 
     let q1 = Quat::from(Vec4::from(q0) * 2.0);
     assert!(!q1.is_normalized());
+    assert!(Quat::from(Vec4::from(q0) * (1.0 + 1.0e-5)).is_normalized());
+    assert!(!Quat::from(Vec4::from(q0) * (1.0 + 1.0e-3)).is_normalized());
     assert_approx_eq!(q1.length_squared(), 4.0, 1.0e-6);
     assert_approx_eq!(q1.length(), 2.0);
     assert_approx_eq!(q1.length_reciprocal(), 0.5);
@@ -167,12 +236,421 @@ fn test_quat_lerp() {
     assert_approx_eq!(Quat::from_rotation_y(deg(45.0)), q0.lerp(q1, 0.5));
 }
 
+#[test]
+fn test_quat_nlerp() {
+    let q0 = Quat::from_rotation_y(deg(0.0));
+    let q1 = Quat::from_rotation_y(deg(90.0));
+    assert_approx_eq!(q0, q0.nlerp(q1, 0.0));
+    assert_approx_eq!(q1, q0.nlerp(q1, 1.0));
+    assert_approx_eq!(Quat::from_rotation_y(deg(45.0)), q0.nlerp(q1, 0.5));
+    assert_eq!(q0.lerp(q1, 0.25), q0.nlerp(q1, 0.25));
+
+    // -q1 is the same rotation as q1, the result should take the shortest path
+    assert_approx_eq!(Quat::from_rotation_y(deg(45.0)), q0.nlerp(-q1, 0.5));
+    assert_approx_eq!(q1, q0.nlerp(-q1, 1.0));
+    let q2 = Quat::from_rotation_y(deg(270.0));
+    assert_approx_eq!(Quat::from_rotation_y(deg(-45.0)), q0.nlerp(q2, 0.5), 1.0e-6);
+    assert!(q0.nlerp(q1, 0.3).is_normalized());
+}
+
+#[test]
+fn test_quat_from_euler() {
+    use EulerRot::*;
+    let (a, b, c) = (deg(10.0), deg(-40.0), deg(75.0));
+    let x = Quat::from_rotation_x(a);
+    let y = Quat::from_rotation_y(b);
+    let z = Quat::from_rotation_z(c);
+    assert_approx_eq!(
+        Quat::from_rotation_ypr(b, a, c),
+        Quat::from_euler(YXZ, b, a, c)
+    );
+    let orders = [
+        (XYZ, XYZEx, (x, y, z), (a, b, c)),
+        (XZY, XZYEx, (x, z, y), (a, c, b)),
+        (YXZ, YXZEx, (y, x, z), (b, a, c)),
+        (YZX, YZXEx, (y, z, x), (b, c, a)),
+        (ZXY, ZXYEx, (z, x, y), (c, a, b)),
+        (ZYX, ZYXEx, (z, y, x), (c, b, a)),
+    ];
+    for (intrinsic, extrinsic, (q0, q1, q2), (a, b, c)) in orders.iter().copied() {
+        assert_approx_eq!(q0 * q1 * q2, Quat::from_euler(intrinsic, a, b, c), 1.0e-6);
+        assert_approx_eq!(q2 * q1 * q0, Quat::from_euler(extrinsic, a, b, c), 1.0e-6);
+    }
+
+    // extrinsic rotations apply the first rotation first
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_approx_eq!(
+        z * (y * (x * v)),
+        Quat::from_euler(XYZEx, a, b, c) * v,
+        1.0e-5
+    );
+}
+
+#[test]
+fn test_quat_to_euler() {
+    use EulerRot::*;
+    let orders = [
+        XYZ, XZY, YXZ, YZX, ZXY, ZYX, XYZEx, XZYEx, YXZEx, YZXEx, ZXYEx, ZYXEx,
+    ];
+    let angles = [
+        (deg(10.0), deg(-40.0), deg(75.0)),
+        (deg(-170.0), deg(80.0), deg(120.0)),
+        (deg(0.0), deg(0.0), deg(0.0)),
+    ];
+    for order in orders.iter().copied() {
+        for (a, b, c) in angles.iter().copied() {
+            let (a1, b1, c1) = Quat::from_euler(order, a, b, c).to_euler(order);
+            assert_approx_eq!(a, a1, 1.0e-4);
+            assert_approx_eq!(b, b1, 1.0e-4);
+            assert_approx_eq!(c, c1, 1.0e-4);
+        }
+
+        // in gimbal lock the first angle is zero and the rotation is preserved
+        let q = Quat::from_euler(order, deg(30.0), deg(90.0), deg(20.0));
+        let (a, b, c) = q.to_euler(order);
+        assert_eq!(0.0, if order.is_extrinsic() { c } else { a });
+        assert_approx_eq!(deg(90.0), b, 1.0e-3);
+        assert_approx_eq!(q, Quat::from_euler(order, a, b, c), 1.0e-3);
+        let q = Quat::from_euler(order, deg(30.0), deg(-90.0), deg(20.0));
+        let (a, b, c) = q.to_euler(order);
+        assert_approx_eq!(q, Quat::from_euler(order, a, b, c), 1.0e-3);
+    }
+}
+
+#[test]
+fn test_quat_swing_twist() {
+    let swing = Quat::from_rotation_x(deg(40.0));
+    let twist = Quat::from_rotation_y(deg(-70.0));
+    let (swing1, twist1) = (swing * twist).to_swing_twist(Vec3::unit_y());
+    assert_approx_eq!(swing, swing1, 1.0e-6);
+    assert_approx_eq!(twist, twist1, 1.0e-6);
+
+    let q = Quat::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let axis = Vec3::new(1.0, -2.0, 0.5).normalize();
+    let (swing, twist) = q.to_swing_twist(axis);
+    assert_approx_eq!(q, swing * twist, 1.0e-6);
+    assert!(swing.is_normalized());
+    assert!(twist.is_normalized());
+    // the twist axis is `axis` and the swing axis is perpendicular to it
+    let twist_axis = Vec4::from(twist).truncate();
+    let swing_axis = Vec4::from(swing).truncate();
+    assert_approx_eq!(0.0, twist_axis.cross(axis).length(), 1.0e-6);
+    assert_approx_eq!(0.0, swing_axis.dot(axis), 1.0e-6);
+
+    // a half turn swing leaves the twist undefined
+    let q = Quat::new(1.0, 0.0, 0.0, 0.0);
+    let (swing, twist) = q.to_swing_twist(Vec3::unit_y());
+    assert_eq!(Quat::identity(), twist);
+    assert_approx_eq!(q, swing);
+}
+
+#[test]
+fn test_quat_ln_exp_powf() {
+    let q = Quat::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let ln = q.ln();
+    assert_approx_eq!(q.to_scaled_axis() * 0.5, Vec4::from(ln).truncate(), 1.0e-6);
+    assert_approx_eq!(0.0, Vec4::from(ln).w(), 1.0e-6);
+    assert_approx_eq!(q, ln.exp(), 1.0e-6);
+    assert_eq!(Quat::new(0.0, 0.0, 0.0, 0.0), Quat::identity().ln());
+    assert_eq!(Quat::identity(), Quat::new(0.0, 0.0, 0.0, 0.0).exp());
+
+    // non unit quaternions
+    let q = Quat::new(1.0, -2.0, 0.5, 3.0);
+    assert_approx_eq!(q, q.ln().exp(), 1.0e-5);
+    assert_approx_eq!(q.length().ln(), Vec4::from(q.ln()).w(), 1.0e-6);
+
+    let q = Quat::from_rotation_y(deg(60.0));
+    assert_approx_eq!(Quat::from_rotation_y(deg(30.0)), q.powf(0.5), 1.0e-6);
+    assert_approx_eq!(Quat::from_rotation_y(deg(150.0)), q.powf(2.5), 1.0e-6);
+    assert_approx_eq!(Quat::from_rotation_y(deg(-60.0)), q.powf(-1.0), 1.0e-6);
+    assert_approx_eq!(Quat::identity(), q.powf(0.0));
+}
+
+#[test]
+fn test_quat_integrate() {
+    let q0 = Quat::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let angular_velocity = Vec3::new(0.5, -1.0, 2.0);
+    assert_eq!(q0.normalize(), q0.integrate(Vec3::zero(), 0.1));
+
+    let dt = 1.0 / 1000.0;
+    let mut q = q0;
+    for _ in 0..1000 {
+        q = q.integrate(angular_velocity, dt);
+    }
+    assert!(q.is_normalized());
+    assert_approx_eq!(Quat::from_scaled_axis(angular_velocity) * q0, q, 1.0e-3);
+}
+
+#[test]
+fn test_quat_scaled_axis() {
+    let v = Vec3::new(0.5, -1.0, 0.25);
+    let q = Quat::from_scaled_axis(v);
+    assert_approx_eq!(Quat::from_axis_angle(v.normalize(), v.length()), q);
+    assert_approx_eq!(v, q.to_scaled_axis(), 1.0e-6);
+    assert_eq!(Quat::identity(), Quat::from_scaled_axis(Vec3::zero()));
+    assert_approx_eq!(Vec3::zero(), Quat::identity().to_scaled_axis());
+    assert_approx_eq!(
+        Vec3::unit_y() * deg(90.0),
+        Quat::from_rotation_y(deg(90.0)).to_scaled_axis()
+    );
+}
+
+#[test]
+fn test_quat_angle_between() {
+    let q0 = Quat::from_rotation_y(deg(10.0));
+    let q1 = Quat::from_rotation_y(deg(70.0));
+    assert_approx_eq!(deg(60.0), q0.angle_between(q1), 1.0e-5);
+    assert_approx_eq!(deg(60.0), q1.angle_between(q0), 1.0e-5);
+    assert_approx_eq!(deg(60.0), q0.angle_between(-q1), 1.0e-5);
+    assert_approx_eq!(0.0, q0.angle_between(q0), 1.0e-3);
+    let q2 = Quat::from_rotation_y(deg(300.0));
+    assert_approx_eq!(deg(70.0), q0.angle_between(q2), 1.0e-5);
+    let q3 = Quat::from_rotation_x(deg(180.0));
+    assert_approx_eq!(deg(180.0), Quat::identity().angle_between(q3), 1.0e-5);
+}
+
+#[test]
+fn test_quat_look_at() {
+    let eye = Vec3::new(1.0, 2.0, 3.0);
+    let center = Vec3::new(-2.0, 0.5, 1.0);
+    let up = Vec3::unit_y();
+    let dir = (center - eye).normalize();
+
+    let q = Quat::look_at_lh(eye, center, up);
+    assert_approx_eq!(Quat::from_mat4(&Mat4::look_at_lh(eye, center, up)), q);
+    assert_approx_eq!(Vec3::unit_z(), q * dir, 1.0e-6);
+    assert_approx_eq!(q, Quat::look_to_lh(dir, up));
+
+    let q = Quat::look_at_rh(eye, center, up);
+    assert_approx_eq!(Quat::from_mat4(&Mat4::look_at_rh(eye, center, up)), q);
+    assert_approx_eq!(-Vec3::unit_z(), q * dir, 1.0e-6);
+    assert_approx_eq!(q, Quat::look_to_rh(dir, up));
+    // up stays in the upper half of the view
+    assert!((q * up).y() > 0.0);
+    assert_approx_eq!(0.0, (q * up).x(), 1.0e-6);
+}
+
+#[test]
+fn test_quat_from_rotation_arc() {
+    let x = Vec3::unit_x();
+    let y = Vec3::unit_y();
+    let q = Quat::from_rotation_arc(x, y);
+    assert_approx_eq!(Quat::from_rotation_z(deg(90.0)), q);
+    assert_approx_eq!(y, q * x);
+    assert_eq!(Quat::identity(), Quat::from_rotation_arc(y, y));
+
+    let from = Vec3::new(1.0, 2.0, 3.0).normalize();
+    let to = Vec3::new(-3.0, 0.5, 1.0).normalize();
+    let q = Quat::from_rotation_arc(from, to);
+    assert!(q.is_normalized());
+    assert_approx_eq!(to, q * from, 1.0e-6);
+
+    // antiparallel vectors rotate by 180 degrees around some orthogonal axis
+    for from in &[x, y, Vec3::unit_z(), -Vec3::unit_z(), from] {
+        let q = Quat::from_rotation_arc(*from, -*from);
+        assert!(q.is_normalized());
+        assert_approx_eq!(-*from, q * *from, 1.0e-6);
+    }
+}
+
+#[test]
+fn test_quat_from_rotation_arc_2d() {
+    let x = Vec2::unit_x();
+    let y = Vec2::unit_y();
+    assert_approx_eq!(
+        Quat::from_rotation_z(deg(90.0)),
+        Quat::from_rotation_arc_2d(x, y)
+    );
+    assert_approx_eq!(
+        Quat::from_rotation_z(deg(-90.0)),
+        Quat::from_rotation_arc_2d(y, x)
+    );
+    assert_eq!(Quat::identity(), Quat::from_rotation_arc_2d(x, x));
+    assert_approx_eq!(
+        Quat::from_rotation_z(deg(180.0)),
+        Quat::from_rotation_arc_2d(x, -x)
+    );
+
+    let from = Vec2::new(3.0, -1.0).normalize();
+    let to = Vec2::new(-2.0, 5.0).normalize();
+    let q = Quat::from_rotation_arc_2d(from, to);
+    assert!(q.is_normalized());
+    assert_approx_eq!(to.extend(0.0), q * from.extend(0.0), 1.0e-6);
+}
+
+#[test]
+fn test_quat_slerp() {
+    let q0 = Quat::from_rotation_y(deg(0.0));
+    let q1 = Quat::from_rotation_y(deg(90.0));
+    assert_approx_eq!(q0, q0.slerp(q1, 0.0), 1.0e-6);
+    assert_approx_eq!(q1, q0.slerp(q1, 1.0), 1.0e-6);
+    assert_approx_eq!(
+        Quat::from_rotation_y(deg(30.0)),
+        q0.slerp(q1, 1.0 / 3.0),
+        1.0e-6
+    );
+    assert_approx_eq!(Quat::from_rotation_y(deg(45.0)), q0.slerp(-q1, 0.5), 1.0e-6);
+    let q2 = Quat::from_rotation_y(deg(0.01));
+    assert_approx_eq!(q0.nlerp(q2, 0.5), q0.slerp(q2, 0.5));
+}
+
+#[test]
+fn test_quat_midpoint() {
+    let q0 = Quat::from_rotation_y(deg(10.0));
+    let q1 = Quat::from_rotation_y(deg(70.0));
+    assert_approx_eq!(Quat::from_rotation_y(deg(40.0)), q0.midpoint(q1), 1.0e-6);
+    assert_approx_eq!(q0.slerp(q1, 0.5), q0.midpoint(q1));
+    // takes the shortest path
+    assert_approx_eq!(Quat::from_rotation_y(deg(40.0)), q0.midpoint(-q1), 1.0e-6);
+}
+
+#[test]
+fn test_quat_squad() {
+    let keys: Vec<Quat> = [0.0, 30.0, 60.0, 90.0]
+        .iter()
+        .map(|angle| Quat::from_rotation_y(deg(*angle)))
+        .collect();
+    let controls = Quat::squad_control_points(&keys);
+    assert_eq!(keys.len(), controls.len());
+    let (q0, q1) = (keys[1], keys[2]);
+    let (c0, c1) = (controls[1], controls[2]);
+    assert_approx_eq!(q0, q0.squad(c0, c1, q1, 0.0), 1.0e-6);
+    assert_approx_eq!(q1, q0.squad(c0, c1, q1, 1.0), 1.0e-6);
+    // evenly spaced keys around one axis interpolate like slerp
+    assert_approx_eq!(c0, q0, 1.0e-6);
+    assert_approx_eq!(
+        Quat::from_rotation_y(deg(40.0)),
+        q0.squad(c0, c1, q1, 1.0 / 3.0),
+        1.0e-6
+    );
+
+    // negating the keys only negates the control point
+    let prev = Quat::from_rotation_x(deg(-40.0));
+    let next = Quat::from_rotation_z(deg(50.0));
+    let c = Quat::squad_control_point(prev, Quat::identity(), next);
+    assert!(c.is_normalized());
+    assert_approx_eq!(
+        c,
+        -Quat::squad_control_point(-prev, -Quat::identity(), next),
+        1.0e-6
+    );
+    assert!(Quat::squad_control_points(&[]).is_empty());
+    assert_eq!(vec![next], Quat::squad_control_points(&[next]));
+}
+
+#[test]
+fn test_quat_rotate_towards() {
+    let q0 = Quat::from_rotation_y(deg(0.0));
+    let q1 = Quat::from_rotation_y(deg(90.0));
+    assert_approx_eq!(
+        Quat::from_rotation_y(deg(30.0)),
+        q0.rotate_towards(q1, deg(30.0)),
+        1.0e-5
+    );
+    assert_approx_eq!(
+        Quat::from_rotation_y(deg(30.0)),
+        q0.rotate_towards(-q1, deg(30.0)),
+        1.0e-5
+    );
+    assert_eq!(q1, q0.rotate_towards(q1, deg(90.0)));
+    assert_eq!(q1, q0.rotate_towards(q1, deg(120.0)));
+    assert_approx_eq!(
+        Quat::from_rotation_y(deg(-30.0)),
+        q0.rotate_towards(q1, deg(-30.0)),
+        1.0e-5
+    );
+}
+
+#[test]
+fn test_quat_canonicalize() {
+    let q = Quat::from_rotation_y(deg(270.0));
+    assert!(Vec4::from(q).w() < 0.0);
+    assert_eq!(-q, q.canonicalize());
+    assert_eq!(-q, (-q).canonicalize());
+    assert!(q.abs_diff_eq_rotation(-q, 0.0));
+    assert!(!q.abs_diff_eq(-q, 0.0));
+    assert!(q.abs_diff_eq_rotation(Quat::from_rotation_y(deg(-90.0)), 1.0e-6));
+    assert!(!q.abs_diff_eq_rotation(Quat::from_rotation_y(deg(90.0)), 1.0e-6));
+}
+
+#[test]
+fn test_quat_relative_ulps_eq() {
+    let q0 = Quat::from_rotation_ypr(0.5, -1.0, 2.0);
+    let q1 = Quat::from(Vec4::from(q0) * (1.0 + 1.0e-6));
+    assert!(q0.relative_eq(q1, 0.0, 1.0e-5));
+    assert!(!q0.relative_eq(q1, 0.0, 1.0e-7));
+    assert!(!q0.ulps_eq(q1, 0.0, 2));
+    assert!(q0.ulps_eq(q1, 0.0, 32));
+}
+
+#[test]
+fn test_quat_product() {
+    let qs = [
+        Quat::from_rotation_x(0.5),
+        Quat::from_rotation_y(-1.0),
+        Quat::from_rotation_z(2.0),
+    ];
+    assert_approx_eq!(qs[0] * qs[1] * qs[2], qs.iter().product());
+    assert_approx_eq!(qs[0] * qs[1] * qs[2], qs.iter().copied().product());
+    assert_eq!(Quat::identity(), std::iter::empty::<Quat>().product());
+}
+
+#[test]
+fn test_quat_renormalize() {
+    let q = Quat::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let drifted = Quat::from(Vec4::from(q) * 1.0001);
+    assert!(!drifted.is_normalized());
+    let renormalized = drifted.renormalize();
+    assert!(renormalized.is_normalized());
+    assert_approx_eq!(q, renormalized, 1.0e-5);
+    assert_approx_eq!(q, q.renormalize(), 1.0e-6);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_quat_rand() {
+    use glam::distributions::UnitQuaternion;
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: Quat = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b = rng2.sample(UnitQuaternion);
+    assert_eq!(a, b);
+
+    // uniformly distributed rotations are uniformly distributed on the unit
+    // 4D sphere, where the mean square of each element is 1/4
+    const COUNT: usize = 20000;
+    let mut sum_squares = Vec4::zero();
+    let mut sum_abs_w = 0.0;
+    for _ in 0..COUNT {
+        let q: Quat = rng1.gen();
+        assert!(q.is_normalized());
+        let v = Vec4::from(q);
+        sum_squares += v * v;
+        sum_abs_w += v.w().abs();
+    }
+    assert_approx_eq!(Vec4::splat(0.25), sum_squares / COUNT as f32, 0.01);
+    assert_approx_eq!(
+        4.0 / (3.0 * std::f32::consts::PI),
+        sum_abs_w / COUNT as f32,
+        0.01
+    );
+}
+
 #[test]
 fn test_quat_fmt() {
     let a = Quat::identity();
-    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
     assert_eq!(format!("{:?}", a), "Quat(__m128(0.0, 0.0, 0.0, 1.0))");
-    #[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+    #[cfg(any(
+        not(target_feature = "sse2"),
+        feature = "scalar-math",
+        feature = "core-simd"
+    ))]
     assert_eq!(format!("{:?}", a), "Quat(0.0, 0.0, 0.0, 1.0)");
     // assert_eq!(
     //     format!("{:#?}", a),
@@ -203,6 +681,9 @@ fn test_quat_slice() {
     let mut d = [0.0, 0.0, 0.0, 0.0];
     b.write_to_slice_unaligned(&mut d[..]);
     assert_eq!(a, d);
+    assert_eq!(b, Quat::from_slice(&a));
+    assert_eq!(Ok(b), Quat::try_from_slice(&a));
+    assert!(Quat::try_from_slice(&a[1..]).is_err());
 }
 
 #[cfg(feature = "serde")]
@@ -224,3 +705,17 @@ fn test_quat_serde() {
     let deserialized = serde_json::from_str::<Quat>("[1.0,2.0,3.0,4.0,5.0]");
     assert!(deserialized.is_err());
 }
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+#[test]
+fn test_quat_m128() {
+    use std::arch::x86_64::*;
+    let q = Quat::from_rotation_y(deg(90.0));
+    let m: __m128 = q.into();
+    assert_eq!(Vec4::from(m), Vec4::from(q));
+    assert_eq!(Quat::from(m), q);
+}
@@ -0,0 +1,121 @@
+#[macro_use]
+mod support;
+
+use glam::*;
+
+fn test_quats() -> [Quat; 4] {
+    [
+        Quat::identity(),
+        Quat::from_rotation_x(1.0),
+        Quat::from_rotation_ypr(0.5, -1.0, 2.0),
+        Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0).normalize(), -2.5),
+    ]
+}
+
+#[test]
+fn test_quatx4_pack_unpack() {
+    let a = test_quats();
+    let q = Quatx4::from(a);
+    for (i, expected) in a.iter().enumerate() {
+        assert_eq!(*expected, q.lane(i));
+        let (x, y, z, w) = (*expected).into();
+        assert_eq!(x, q.x()[i]);
+        assert_eq!(y, q.y()[i]);
+        assert_eq!(z, q.z()[i]);
+        assert_eq!(w, q.w()[i]);
+    }
+    let b: [Quat; 4] = q.into();
+    assert_eq!(a, b);
+
+    let mut q = Quatx4::identity();
+    assert_eq!(Quatx4::default(), q);
+    q.set_lane(2, a[2]);
+    assert_eq!(Quat::identity(), q.lane(1));
+    assert_eq!(a[2], q.lane(2));
+}
+
+#[test]
+fn test_quatx4_funcs() {
+    let a = test_quats();
+    let q = Quatx4::from(a);
+    let dot = q.dot(q);
+    let length = q.length();
+    let scaled = Quatx4::new(
+        [2.0, 0.0, 0.0, 1.0],
+        [0.0, 3.0, 0.0, 1.0],
+        [0.0, 0.0, 4.0, 1.0],
+        [0.0, 0.0, 0.0, 1.0],
+    );
+    let normalized = scaled.normalize();
+    let conjugate = q.conjugate();
+    for i in 0..4 {
+        assert_approx_eq!(a[i].dot(a[i]), dot[i]);
+        assert_approx_eq!(a[i].length(), length[i]);
+        assert_approx_eq!(scaled.lane(i).normalize(), normalized.lane(i));
+        assert_eq!(a[i].conjugate(), conjugate.lane(i));
+    }
+}
+
+#[test]
+fn test_quatx4_mul() {
+    let a = test_quats();
+    let mut b = a;
+    b.reverse();
+    let qa = Quatx4::from(a);
+    let qb = Quatx4::from(b);
+    let points = [
+        Vec3::unit_x(),
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(-4.0, 0.5, 2.0),
+        Vec3::new(0.0, -1.0, 10.0),
+    ];
+    let rotated = qa * Vec3x4::from(points);
+    let product = qa * qb;
+    for i in 0..4 {
+        assert_approx_eq!(a[i] * b[i], product.lane(i));
+        assert_approx_eq!(a[i] * points[i], rotated.lane(i), 1e-5);
+    }
+}
+
+#[test]
+fn test_quatx4_interpolate() {
+    let axis = Vec3::new(1.0, -1.0, 0.5).normalize();
+    let start = [
+        Quat::identity(),
+        Quat::from_axis_angle(axis, 0.5),
+        Quat::from_axis_angle(axis, -1.0),
+        Quat::from_axis_angle(axis, 1.0),
+    ];
+    let end = [
+        Quat::from_axis_angle(axis, 2.0),
+        Quat::from_axis_angle(axis, 0.5005),
+        Quat::from_axis_angle(axis, 1.5),
+        // more than 180 degrees apart so the shortest path is taken
+        Quat::from_axis_angle(axis, 1.0 + 3.5),
+    ];
+    let qs = Quatx4::from(start);
+    let qe = Quatx4::from(end);
+    assert_approx_eq!(start[2], qs.slerp(qe, 0.0).lane(2));
+    assert_approx_eq!(end[2], qs.slerp(qe, 1.0).lane(2));
+
+    let s = 0.25;
+    let nlerp = qs.nlerp(qe, s);
+    let slerp = qs.slerp(qe, s);
+    let expected_angles = [
+        0.5,
+        0.500_125,
+        -1.0 + 2.5 * s,
+        1.0 - (6.283_185_5 - 3.5) * s,
+    ];
+    for i in 0..4 {
+        assert_approx_eq!(start[i].lerp(end[i], s), nlerp.lane(i), 1e-6);
+        let expected = Quat::from_axis_angle(axis, expected_angles[i]);
+        assert!(
+            slerp.lane(i).abs_diff_eq(expected, 1e-5) || slerp.lane(i).abs_diff_eq(-expected, 1e-5),
+            "lane {}: {:?} != {:?}",
+            i,
+            slerp.lane(i),
+            expected
+        );
+    }
+}
@@ -0,0 +1,201 @@
+// These tests compare the SIMD types against plain `f32` reference code and
+// their scalar counterparts. Run with and without the `scalar-math` feature to
+// check that both code paths agree.
+#[macro_use]
+mod support;
+
+use glam::*;
+
+const VALUES: [f32; 12] = [
+    0.0, -0.0, 1.0, -1.0, 0.5, -2.25, 3.75, 100.0, -1e-3, 1e-7, 12345.678, -0.33,
+];
+
+fn vec4_inputs() -> Vec<[f32; 4]> {
+    let mut inputs = Vec::new();
+    for i in 0..VALUES.len() {
+        inputs.push([
+            VALUES[i],
+            VALUES[(i + 3) % VALUES.len()],
+            VALUES[(i + 5) % VALUES.len()],
+            VALUES[(i + 7) % VALUES.len()],
+        ]);
+    }
+    inputs
+}
+
+fn assert_bits_eq4(a: Vec4, b: [f32; 4]) {
+    let a: [f32; 4] = a.into();
+    for i in 0..4 {
+        assert_eq!(a[i].to_bits(), b[i].to_bits(), "{:?} != {:?}", a, b);
+    }
+}
+
+fn assert_bits_eq3(a: Vec3A, b: Vec3) {
+    let a: [f32; 3] = a.into();
+    let b: [f32; 3] = b.into();
+    for i in 0..3 {
+        assert_eq!(a[i].to_bits(), b[i].to_bits(), "{:?} != {:?}", a, b);
+    }
+}
+
+fn mat4_to_dmat4(m: Mat4) -> DMat4 {
+    let mut a = [0.0; 16];
+    for (d, s) in a.iter_mut().zip(m.to_cols_array().iter()) {
+        *d = f64::from(*s);
+    }
+    DMat4::from_cols_array(&a)
+}
+
+fn dmat4_to_mat4(m: DMat4) -> Mat4 {
+    let mut a = [0.0; 16];
+    for (d, s) in a.iter_mut().zip(m.to_cols_array().iter()) {
+        *d = *s as f32;
+    }
+    Mat4::from_cols_array(&a)
+}
+
+fn quat_to_dquat(q: Quat) -> DQuat {
+    let a: [f32; 4] = q.into();
+    DQuat::new(a[0].into(), a[1].into(), a[2].into(), a[3].into())
+}
+
+fn dquat_to_quat(q: DQuat) -> Quat {
+    let a: [f64; 4] = q.into();
+    Quat::new(a[0] as f32, a[1] as f32, a[2] as f32, a[3] as f32)
+}
+
+fn map4(a: [f32; 4], b: [f32; 4], f: impl Fn(f32, f32) -> f32) -> [f32; 4] {
+    [f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])]
+}
+
+#[test]
+fn test_vec4_elementwise_matches_scalar() {
+    let inputs = vec4_inputs();
+    for &a in &inputs {
+        let va = Vec4::from(a);
+        assert_bits_eq4(-va, map4(a, a, |x, _| -x));
+        assert_bits_eq4(va.abs(), map4(a, a, |x, _| x.abs()));
+        assert_bits_eq4(va * 3.0, map4(a, a, |x, _| x * 3.0));
+        assert_bits_eq4(va / 3.0, map4(a, a, |x, _| x / 3.0));
+        for &b in &inputs {
+            let vb = Vec4::from(b);
+            assert_bits_eq4(va + vb, map4(a, b, |x, y| x + y));
+            assert_bits_eq4(va - vb, map4(a, b, |x, y| x - y));
+            assert_bits_eq4(va * vb, map4(a, b, |x, y| x * y));
+            // the sign of a zero returned by `min` and `max` is unspecified
+            assert_eq!(<[f32; 4]>::from(va.min(vb)), map4(a, b, f32::min));
+            assert_eq!(<[f32; 4]>::from(va.max(vb)), map4(a, b, f32::max));
+            let lt: [bool; 4] = va.cmplt(vb).into();
+            assert_eq!(lt, [a[0] < b[0], a[1] < b[1], a[2] < b[2], a[3] < b[3]]);
+            let eq: [bool; 4] = va.cmpeq(vb).into();
+            assert_eq!(eq, [a[0] == b[0], a[1] == b[1], a[2] == b[2], a[3] == b[3]]);
+        }
+    }
+}
+
+#[test]
+fn test_vec4_reductions_match_scalar() {
+    let inputs = vec4_inputs();
+    for &a in &inputs {
+        let va = Vec4::from(a);
+        assert_eq!(va.min_element(), a[0].min(a[1]).min(a[2]).min(a[3]));
+        assert_eq!(va.max_element(), a[0].max(a[1]).max(a[2]).max(a[3]));
+        for &b in &inputs {
+            let vb = Vec4::from(b);
+            let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+            let tolerance = 1e-6 * (va.abs().dot(vb.abs()) + 1.0);
+            assert_approx_eq!(va.dot(vb), dot, tolerance);
+        }
+    }
+}
+
+#[test]
+fn test_vec3a_matches_vec3() {
+    let inputs = vec4_inputs();
+    for &a in &inputs {
+        let v3a = Vec3A::new(a[0], a[1], a[2]);
+        let v3 = Vec3::new(a[0], a[1], a[2]);
+        assert_bits_eq3(-v3a, -v3);
+        assert_bits_eq3(v3a.abs(), v3.abs());
+        for &b in &inputs {
+            let w3a = Vec3A::new(b[0], b[1], b[2]);
+            let w3 = Vec3::new(b[0], b[1], b[2]);
+            assert_bits_eq3(v3a + w3a, v3 + w3);
+            assert_bits_eq3(v3a - w3a, v3 - w3);
+            assert_bits_eq3(v3a * w3a, v3 * w3);
+            assert_eq!(Vec3::from(v3a.min(w3a)), v3.min(w3));
+            assert_eq!(Vec3::from(v3a.max(w3a)), v3.max(w3));
+            assert_bits_eq3(v3a.cross(w3a), v3.cross(w3));
+            let tolerance = 1e-6 * (v3.abs().dot(w3.abs()) + 1.0);
+            assert_approx_eq!(v3a.dot(w3a), v3.dot(w3), tolerance);
+        }
+    }
+}
+
+#[test]
+fn test_mat4_matches_dmat4() {
+    let m = Mat4::from_scale_rotation_translation(
+        Vec3::new(1.5, 0.5, 2.0),
+        Quat::from_rotation_ypr(0.3, -0.7, 1.1),
+        Vec3::new(-3.0, 4.0, 0.25),
+    );
+    let n = Mat4::from_cols_array(&[
+        2.0, -1.0, 0.5, 0.0, 0.25, 3.0, -0.75, 0.0, 1.0, 0.5, 4.0, 0.0, -2.0, 1.0, 0.5, 1.0,
+    ]);
+    let dm = mat4_to_dmat4(m);
+    let dn = mat4_to_dmat4(n);
+    assert_approx_eq!(m.transpose(), dmat4_to_mat4(dm.transpose()), 1e-6);
+    assert_approx_eq!(m * n, dmat4_to_mat4(dm * dn), 1e-5);
+    assert_approx_eq!(m.inverse(), dmat4_to_mat4(dm.inverse()), 1e-5);
+    assert_approx_eq!(m.determinant(), dm.determinant() as f32, 1e-5);
+    for a in vec4_inputs() {
+        let v = Vec4::from(a);
+        let dv = v.as_dvec4();
+        let expected = (dm * dv).as_vec4();
+        let tolerance = 1e-5 * (v.abs().max_element() + 1.0);
+        assert_approx_eq!(m * v, expected, tolerance);
+    }
+}
+
+#[test]
+fn test_quat_matches_dquat() {
+    let a = Quat::from_rotation_ypr(0.3, -0.7, 1.1);
+    let b = Quat::from_axis_angle(Vec3::new(1.0, 2.0, -0.5).normalize(), 2.1);
+    let da = quat_to_dquat(a);
+    let db = quat_to_dquat(b);
+    assert_approx_eq!(a * b, dquat_to_quat(da * db), 1e-6);
+    for v in vec4_inputs() {
+        let v = Vec3::new(v[0], v[1], v[2]);
+        let dv = v.as_dvec3();
+        let tolerance = 1e-5 * (v.abs().max_element() + 1.0);
+        assert_approx_eq!(a * v, (da * dv).as_vec3(), tolerance);
+        assert_approx_eq!(a * Vec3A::from(v), Vec3A::from(a * v), tolerance);
+    }
+}
+
+#[test]
+fn test_vec4_trig_matches_std() {
+    let mut x = -100.0_f32;
+    while x < 100.0 {
+        let v = Vec4::new(x, x * 0.5, -x * 0.25, x * 2.0);
+        let (sin, cos) = v.sin_cos();
+        let (sin, cos): ([f32; 4], [f32; 4]) = (sin.into(), cos.into());
+        let a: [f32; 4] = v.into();
+        for i in 0..4 {
+            assert_approx_eq!(sin[i], a[i].sin(), 1e-6);
+            assert_approx_eq!(cos[i], a[i].cos(), 1e-6);
+        }
+        x += 0.137;
+    }
+
+    for &y in &VALUES {
+        for &x in &VALUES {
+            let atan2 = Vec4::splat(y).atan2(Vec4::splat(x)).x();
+            assert_approx_eq!(atan2, y.atan2(x), 1e-6);
+            if y == 0.0 {
+                // the sign of a zero angle follows `y` like `f32::atan2`
+                assert_eq!(atan2.to_bits(), y.atan2(x).to_bits());
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@
 macro_rules! assert_approx_eq {
     ($a:expr, $b:expr) => {{
         use support::FloatCompare;
-        let eps = core::f32::EPSILON;
+        let eps = f32::EPSILON;
         let (a, b) = (&$a, &$b);
         assert!(
             a.approx_eq(b, eps),
@@ -29,3 +29,1252 @@ macro_rules! assert_approx_eq {
         );
     }};
 }
+
+/// Tests shared by all 2D integer vector types. Cases that depend on the sign
+/// of the element type are in `impl_vec2_signed_tests`.
+#[macro_export]
+macro_rules! impl_vec2_int_tests {
+    ($t:ident, $new:ident, $vec2:ident, $new3:ident) => {
+        #[test]
+        fn test_new() {
+            let v = $new(1, 2);
+            assert_eq!(v.x(), 1);
+            assert_eq!(v.y(), 2);
+
+            let t = (1, 2);
+            let v = $vec2::from(t);
+            assert_eq!(t, v.into());
+
+            let a = [1, 2];
+            let v = $vec2::from(a);
+            let a1: [$t; 2] = v.into();
+            assert_eq!(a, a1);
+
+            let v = $vec2::new(t.0, t.1);
+            assert_eq!(t, v.into());
+
+            assert_eq!($vec2::new(1, 0), $vec2::unit_x());
+            assert_eq!($vec2::new(0, 1), $vec2::unit_y());
+        }
+
+        #[test]
+        fn test_fmt() {
+            let a = $new(1, 2);
+            assert_eq!(format!("{:?}", a), concat!(stringify!($vec2), "(1, 2)"));
+            assert_eq!(format!("{}", a), "[1, 2]");
+        }
+
+        #[test]
+        fn test_zero() {
+            let v = $vec2::zero();
+            assert_eq!($new(0, 0), v);
+            assert_eq!(v, $vec2::default());
+        }
+
+        #[test]
+        fn test_splat() {
+            let v = $vec2::splat(1);
+            assert_eq!($new(1, 1), v);
+        }
+
+        #[test]
+        fn test_accessors() {
+            let mut a = $vec2::zero();
+            a.set_x(1);
+            a.set_y(2);
+            assert_eq!(1, a.x());
+            assert_eq!(2, a.y());
+        }
+
+        #[test]
+        fn test_funcs() {
+            let x = $new(1, 0);
+            let y = $new(0, 1);
+            assert_eq!(1, x.dot(x));
+            assert_eq!(0, x.dot(y));
+            assert_eq!(8, $new(1, 2).dot($new(2, 3)));
+        }
+
+        #[test]
+        fn test_ops() {
+            let a = $new(2, 4);
+            assert_eq!($new(4, 8), (a + a));
+            assert_eq!($new(0, 0), (a - a));
+            assert_eq!($new(4, 16), (a * a));
+            assert_eq!($new(4, 8), (a * 2));
+            assert_eq!($new(4, 8), (2 * a));
+            assert_eq!($new(1, 1), (a / a));
+            assert_eq!($new(1, 2), (a / 2));
+            assert_eq!($new(2, 1), (a % 3));
+        }
+
+        #[test]
+        fn test_assign_ops() {
+            let a = $new(1, 2);
+            let mut b = a;
+            b += a;
+            assert_eq!($new(2, 4), b);
+            b -= a;
+            assert_eq!($new(1, 2), b);
+            b *= a;
+            assert_eq!($new(1, 4), b);
+            b /= a;
+            assert_eq!($new(1, 2), b);
+            b *= 2;
+            assert_eq!($new(2, 4), b);
+            b /= 2;
+            assert_eq!($new(1, 2), b);
+            b %= 2;
+            assert_eq!($new(1, 0), b);
+        }
+
+        #[test]
+        fn test_midpoint() {
+            assert_eq!($new(2, 2), $new(1, 4).midpoint($new(4, 1)));
+            // the sum does not overflow
+            assert_eq!(
+                $vec2::splat($t::MAX - 1),
+                $vec2::splat($t::MAX).midpoint($vec2::splat($t::MAX - 2))
+            );
+        }
+
+        #[test]
+        fn test_sum() {
+            let a = $new(1, 2);
+            let vs = [a, a, a];
+            assert_eq!($new(3, 6), vs.iter().sum());
+            assert_eq!($new(3, 6), vs.iter().copied().sum());
+            assert_eq!($vec2::zero(), std::iter::empty::<$vec2>().sum());
+        }
+
+        #[test]
+        fn test_min_max() {
+            let a = $new(1, 6);
+            let b = $new(5, 2);
+            assert_eq!($new(1, 2), a.min(b));
+            assert_eq!($new(1, 2), b.min(a));
+            assert_eq!($new(5, 6), a.max(b));
+            assert_eq!($new(5, 6), b.max(a));
+        }
+
+        #[test]
+        fn test_clamp() {
+            let min = $new(1, 1);
+            let max = $new(3, 3);
+            assert_eq!($new(1, 2), $new(0, 2).clamp(min, max));
+            let a = $new(0, 2);
+            assert_eq!($new(1, 2), a.clamp_scalar(1, 3));
+            assert_eq!($new(0, 2), a.min_scalar(2));
+            assert_eq!($new(2, 2), a.max_scalar(2));
+        }
+
+        #[test]
+        fn test_hmin_hmax() {
+            let a = $new(1, 6);
+            assert_eq!(1, a.min_element());
+            assert_eq!(6, a.max_element());
+        }
+
+        #[test]
+        fn test_cmp() {
+            let a = $vec2::splat(1);
+            let b = $vec2::splat(2);
+            assert!(a.cmpeq(a).all());
+            assert!(a.cmpne(b).all());
+            assert_eq!(a.cmplt(b).bitmask(), 0x3);
+            assert_eq!(a.cmple(a).bitmask(), 0x3);
+            assert_eq!(a.cmpgt(b).bitmask(), 0x0);
+            assert!(b.cmpgt(a).all());
+            assert!(b.cmpge(b).all());
+            assert!(!a.cmpge(b).any());
+        }
+
+        #[test]
+        fn test_extend_truncate() {
+            let a = $new(1, 2);
+            let b = a.extend(3);
+            assert_eq!($new3(1, 2, 3), b);
+        }
+
+        #[test]
+        fn test_slice() {
+            let a = [1, 2];
+            let b = $vec2::from_slice_unaligned(&a);
+            let c: [$t; 2] = b.into();
+            assert_eq!(a, c);
+            let mut d = [0; 2];
+            b.write_to_slice_unaligned(&mut d[..]);
+            assert_eq!(a, d);
+            assert_eq!(&a, b.as_ref());
+        }
+
+        #[test]
+        fn test_try_slice() {
+            let v = $new(1, 2);
+            let mut a = [0; 3];
+            v.write_to_slice(&mut a[1..]);
+            assert_eq!(v, $vec2::from_slice(&a[1..]));
+            assert_eq!(Ok(v), $vec2::try_from_slice(&a[1..]));
+            let err = $vec2::try_from_slice(&a[2..]).unwrap_err();
+            assert_eq!(2, err.required_len());
+            let mut b = [0; 1];
+            assert_eq!(Err(err), v.try_write_to_slice(&mut b));
+            assert!(v.try_write_to_slice(&mut a).is_ok());
+            assert_eq!(v, $vec2::from_slice(&a));
+        }
+
+        #[test]
+        fn test_index() {
+            let mut v = $new(1, 2);
+            assert_eq!(1, v[0]);
+            assert_eq!(2, v[1]);
+            v[1] = 5;
+            assert_eq!(5, v.y());
+            v[0] += 2;
+            assert_eq!($new(3, 5), v);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_index_out_of_bounds() {
+            let _ = $vec2::zero()[2];
+        }
+
+        #[cfg(feature = "rand")]
+        #[test]
+        fn test_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let a: ($t, $t) = rng1.gen();
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let b: $vec2 = rng2.gen();
+            assert_eq!(a, b.into());
+        }
+
+        #[test]
+        fn test_wrapping() {
+            let a = $vec2::new($t::MAX, 1);
+            let b = $vec2::new(1, 2);
+            let c = $vec2::new($t::MIN, 5);
+            assert_eq!($vec2::new($t::MIN, 3), a.wrapping_add(b));
+            assert_eq!($vec2::new($t::MAX, 3), c.wrapping_sub(b));
+            assert_eq!(
+                $vec2::new($t::MAX.wrapping_mul(2), 2),
+                a.wrapping_mul($vec2::splat(2))
+            );
+        }
+
+        #[test]
+        fn test_saturating() {
+            let a = $vec2::new($t::MAX, 1);
+            let b = $vec2::new(1, 2);
+            let c = $vec2::new($t::MIN, 5);
+            assert_eq!($vec2::new($t::MAX, 3), a.saturating_add(b));
+            assert_eq!($vec2::new($t::MIN, 3), c.saturating_sub(b));
+            assert_eq!($vec2::new($t::MAX, 2), a.saturating_mul($vec2::splat(2)));
+        }
+
+        #[test]
+        fn test_checked() {
+            let a = $vec2::new($t::MAX, 1);
+            let b = $vec2::new(1, 2);
+            let c = $vec2::new($t::MIN, 5);
+            assert_eq!(None, a.checked_add(b));
+            assert_eq!(Some($vec2::new(2, 4)), b.checked_add(b));
+            assert_eq!(None, c.checked_sub(b));
+            assert_eq!(Some($vec2::zero()), b.checked_sub(b));
+            assert_eq!(None, a.checked_mul($vec2::splat(2)));
+            assert_eq!(Some($vec2::new(1, 4)), b.checked_mul(b));
+            assert_eq!(None, b.checked_div($vec2::zero()));
+            assert_eq!(None, b.checked_div($vec2::new(1, 0)));
+            assert_eq!(Some($vec2::splat(2)), $vec2::new(2, 4).checked_div(b));
+        }
+
+        #[test]
+        fn test_bitops() {
+            let a = $vec2::new(0b1100, 0b1010);
+            let b = $vec2::new(0b1010, 0b110);
+            assert_eq!($vec2::new(0b1000, 0b10), a & b);
+            assert_eq!($vec2::new(0b1110, 0b1110), a | b);
+            assert_eq!($vec2::new(0b110, 0b1100), a ^ b);
+            assert_eq!($vec2::new(0b100, 0b10), a & 0b0110);
+            assert_eq!($vec2::new(0b1110, 0b1110), a | 0b0110);
+            assert_eq!($vec2::new(0b1010, 0b1100), a ^ 0b0110);
+            assert_eq!($vec2::zero(), !$vec2::splat(!0));
+            assert_eq!(a, !!a);
+
+            let mut c = a;
+            c &= b;
+            assert_eq!(a & b, c);
+            c |= a;
+            assert_eq!((a & b) | a, c);
+            c ^= b;
+            assert_eq!(((a & b) | a) ^ b, c);
+            c &= 0b1;
+            c |= 0b10;
+            c ^= 0b11;
+            assert_eq!(((((a & b) | a) ^ b) & 0b1 | 0b10) ^ 0b11, c);
+        }
+
+        #[test]
+        fn test_shifts() {
+            let a = $vec2::new(1, 2);
+            assert_eq!($vec2::new(4, 8), a << 2);
+            assert_eq!($vec2::new(0, 1), a >> 1);
+            assert_eq!($vec2::new(1, 4), a << $vec2::new(0, 1));
+            assert_eq!($vec2::new(1, 1), a >> $vec2::new(0, 1));
+
+            let mut b = a;
+            b <<= 2;
+            assert_eq!($vec2::new(4, 8), b);
+            b >>= 1;
+            assert_eq!($vec2::new(2, 4), b);
+            b <<= $vec2::new(1, 0);
+            assert_eq!($vec2::new(4, 4), b);
+            b >>= $vec2::new(2, 1);
+            assert_eq!($vec2::new(1, 2), b);
+        }
+
+        #[test]
+        fn test_euclid() {
+            let a = $vec2::new(7, 8);
+            let b = $vec2::new(4, 4);
+            assert_eq!($vec2::new(1, 2), a.div_euclid(b));
+            assert_eq!($vec2::new(3, 0), a.rem_euclid(b));
+        }
+
+        #[test]
+        fn test_manhattan_chebyshev() {
+            let a = $vec2::new(1, 7);
+            let b = $vec2::new(4, 2);
+            assert_eq!(8, a.manhattan_distance(b));
+            assert_eq!(8, b.manhattan_distance(a));
+            assert_eq!(5, a.chebyshev_distance(b));
+            assert_eq!(5, b.chebyshev_distance(a));
+            assert_eq!(8, a.manhattan_length());
+            assert_eq!(7, a.chebyshev_length());
+            assert_eq!(0, a.manhattan_distance(a));
+            assert_eq!(0, a.chebyshev_distance(a));
+        }
+    };
+}
+
+/// Tests shared by the signed 2D integer vector types, in addition to
+/// `impl_vec2_int_tests`.
+#[macro_export]
+macro_rules! impl_vec2_signed_tests {
+    ($t:ident, $new:ident, $vec2:ident) => {
+        #[test]
+        fn test_neg() {
+            let a = $new(2, 4);
+            assert_eq!($new(-2, -4), -a);
+        }
+
+        #[test]
+        fn test_signed_midpoint() {
+            assert_eq!($new(2, -2), $new(1, -4).midpoint($new(4, -1)));
+            // the sum does not overflow
+            assert_eq!(
+                $vec2::splat($t::MAX - 1),
+                $vec2::splat($t::MAX).midpoint($vec2::splat($t::MAX - 2))
+            );
+            assert_eq!(
+                $vec2::splat($t::MIN + 1),
+                $vec2::splat($t::MIN).midpoint($vec2::splat($t::MIN + 2))
+            );
+        }
+
+        #[test]
+        fn test_signed_min_max() {
+            let a = $new(1, -2);
+            let b = $new(-1, 2);
+            assert_eq!($new(-1, -2), a.min(b));
+            assert_eq!($new(-1, -2), b.min(a));
+            assert_eq!($new(1, 2), a.max(b));
+            assert_eq!($new(1, 2), b.max(a));
+        }
+
+        #[test]
+        fn test_signed_hmin_hmax() {
+            let a = $new(1, -2);
+            assert_eq!(-2, a.min_element());
+            assert_eq!(1, a.max_element());
+        }
+
+        #[test]
+        fn test_abs_signum() {
+            assert_eq!($vec2::zero().abs(), $vec2::zero());
+            assert_eq!($vec2::one().abs(), $vec2::one());
+            assert_eq!((-$vec2::one()).abs(), $vec2::one());
+            assert_eq!($vec2::zero().signum(), $vec2::zero());
+            assert_eq!($vec2::splat(5).signum(), $vec2::one());
+            assert_eq!($vec2::splat(-5).signum(), -$vec2::one());
+        }
+
+        #[test]
+        fn test_signed_euclid() {
+            let a = $vec2::new(-7, 7);
+            let b = $vec2::new(4, -4);
+            assert_eq!($vec2::new(-2, -1), a.div_euclid(b));
+            assert_eq!($vec2::new(1, 3), a.rem_euclid(b));
+        }
+
+        #[test]
+        fn test_signed_manhattan_chebyshev() {
+            let a = $vec2::new(1, -2);
+            let b = $vec2::new(-3, 5);
+            assert_eq!(11, a.manhattan_distance(b));
+            assert_eq!(11, b.manhattan_distance(a));
+            assert_eq!(7, a.chebyshev_distance(b));
+            assert_eq!(7, b.chebyshev_distance(a));
+            assert_eq!(3, a.manhattan_length());
+            assert_eq!(2, a.chebyshev_length());
+            assert_eq!(0, a.manhattan_distance(a));
+            assert_eq!(0, a.chebyshev_distance(a));
+        }
+    };
+}
+
+/// Tests shared by all 3D integer vector types. Cases that depend on the sign
+/// of the element type are in `impl_vec3_signed_tests`.
+#[macro_export]
+macro_rules! impl_vec3_int_tests {
+    ($t:ident, $new:ident, $vec3:ident, $new2:ident, $new4:ident) => {
+        #[test]
+        fn test_new() {
+            let v = $new(1, 2, 3);
+            assert_eq!(v.x(), 1);
+            assert_eq!(v.y(), 2);
+            assert_eq!(v.z(), 3);
+
+            let t = (1, 2, 3);
+            let v = $vec3::from(t);
+            assert_eq!(t, v.into());
+
+            let a = [1, 2, 3];
+            let v = $vec3::from(a);
+            let a1: [$t; 3] = v.into();
+            assert_eq!(a, a1);
+
+            let v = $vec3::new(t.0, t.1, t.2);
+            assert_eq!(t, v.into());
+
+            assert_eq!($vec3::new(1, 0, 0), $vec3::unit_x());
+            assert_eq!($vec3::new(0, 1, 0), $vec3::unit_y());
+            assert_eq!($vec3::new(0, 0, 1), $vec3::unit_z());
+        }
+
+        #[test]
+        fn test_fmt() {
+            let a = $new(1, 2, 3);
+            assert_eq!(format!("{:?}", a), concat!(stringify!($vec3), "(1, 2, 3)"));
+            assert_eq!(format!("{}", a), "[1, 2, 3]");
+        }
+
+        #[test]
+        fn test_zero() {
+            let v = $vec3::zero();
+            assert_eq!($new(0, 0, 0), v);
+            assert_eq!(v, $vec3::default());
+        }
+
+        #[test]
+        fn test_splat() {
+            let v = $vec3::splat(1);
+            assert_eq!($new(1, 1, 1), v);
+        }
+
+        #[test]
+        fn test_accessors() {
+            let mut a = $vec3::zero();
+            a.set_x(1);
+            a.set_y(2);
+            a.set_z(3);
+            assert_eq!(1, a.x());
+            assert_eq!(2, a.y());
+            assert_eq!(3, a.z());
+        }
+
+        #[test]
+        fn test_funcs() {
+            let x = $new(1, 0, 0);
+            let y = $new(0, 1, 0);
+            assert_eq!(1, x.dot(x));
+            assert_eq!(0, x.dot(y));
+            assert_eq!(20, $new(1, 2, 3).dot($new(2, 3, 4)));
+        }
+
+        #[test]
+        fn test_ops() {
+            let a = $new(2, 4, 6);
+            assert_eq!($new(4, 8, 12), (a + a));
+            assert_eq!($new(0, 0, 0), (a - a));
+            assert_eq!($new(4, 16, 36), (a * a));
+            assert_eq!($new(4, 8, 12), (a * 2));
+            assert_eq!($new(4, 8, 12), (2 * a));
+            assert_eq!($new(1, 1, 1), (a / a));
+            assert_eq!($new(1, 2, 3), (a / 2));
+            assert_eq!($new(2, 1, 0), (a % 3));
+        }
+
+        #[test]
+        fn test_assign_ops() {
+            let a = $new(1, 2, 3);
+            let mut b = a;
+            b += a;
+            assert_eq!($new(2, 4, 6), b);
+            b -= a;
+            assert_eq!($new(1, 2, 3), b);
+            b *= a;
+            assert_eq!($new(1, 4, 9), b);
+            b /= a;
+            assert_eq!($new(1, 2, 3), b);
+            b *= 2;
+            assert_eq!($new(2, 4, 6), b);
+            b /= 2;
+            assert_eq!($new(1, 2, 3), b);
+            b %= 2;
+            assert_eq!($new(1, 0, 1), b);
+        }
+
+        #[test]
+        fn test_midpoint() {
+            assert_eq!($new(2, 2, 4), $new(1, 4, 7).midpoint($new(4, 1, 2)));
+            // the sum does not overflow
+            assert_eq!(
+                $vec3::splat($t::MAX - 1),
+                $vec3::splat($t::MAX).midpoint($vec3::splat($t::MAX - 2))
+            );
+        }
+
+        #[test]
+        fn test_sum() {
+            let a = $new(1, 2, 3);
+            let vs = [a, a, a];
+            assert_eq!($new(3, 6, 9), vs.iter().sum());
+            assert_eq!($new(3, 6, 9), vs.iter().copied().sum());
+            assert_eq!($vec3::zero(), std::iter::empty::<$vec3>().sum());
+        }
+
+        #[test]
+        fn test_min_max() {
+            let a = $new(1, 6, 3);
+            let b = $new(5, 2, 7);
+            assert_eq!($new(1, 2, 3), a.min(b));
+            assert_eq!($new(1, 2, 3), b.min(a));
+            assert_eq!($new(5, 6, 7), a.max(b));
+            assert_eq!($new(5, 6, 7), b.max(a));
+        }
+
+        #[test]
+        fn test_clamp() {
+            let min = $new(1, 1, 1);
+            let max = $new(3, 3, 3);
+            assert_eq!($new(1, 2, 3), $new(0, 2, 5).clamp(min, max));
+            let a = $new(0, 2, 5);
+            assert_eq!($new(1, 2, 3), a.clamp_scalar(1, 3));
+            assert_eq!($new(0, 2, 2), a.min_scalar(2));
+            assert_eq!($new(2, 2, 5), a.max_scalar(2));
+        }
+
+        #[test]
+        fn test_hmin_hmax() {
+            let a = $new(1, 6, 3);
+            assert_eq!(1, a.min_element());
+            assert_eq!(6, a.max_element());
+        }
+
+        #[test]
+        fn test_cmp() {
+            let a = $vec3::splat(1);
+            let b = $vec3::splat(2);
+            assert!(a.cmpeq(a).all());
+            assert!(a.cmpne(b).all());
+            assert_eq!(a.cmplt(b).bitmask(), 0x7);
+            assert_eq!(a.cmple(a).bitmask(), 0x7);
+            assert_eq!(a.cmpgt(b).bitmask(), 0x0);
+            assert!(b.cmpgt(a).all());
+            assert!(b.cmpge(b).all());
+            assert!(!a.cmpge(b).any());
+        }
+
+        #[test]
+        fn test_extend_truncate() {
+            let a = $new(1, 2, 3);
+            let b = a.extend(4);
+            assert_eq!($new4(1, 2, 3, 4), b);
+            let c = b.truncate();
+            assert_eq!(a, c);
+            assert_eq!($new2(1, 2), a.truncate());
+            assert_eq!($new2(1, 2), a.xy());
+        }
+
+        #[test]
+        fn test_slice() {
+            let a = [1, 2, 3];
+            let b = $vec3::from_slice_unaligned(&a);
+            let c: [$t; 3] = b.into();
+            assert_eq!(a, c);
+            let mut d = [0; 3];
+            b.write_to_slice_unaligned(&mut d[..]);
+            assert_eq!(a, d);
+            assert_eq!(&a, b.as_ref());
+        }
+
+        #[test]
+        fn test_try_slice() {
+            let v = $new(1, 2, 3);
+            let mut a = [0; 4];
+            v.write_to_slice(&mut a[1..]);
+            assert_eq!(v, $vec3::from_slice(&a[1..]));
+            assert_eq!(Ok(v), $vec3::try_from_slice(&a[1..]));
+            let err = $vec3::try_from_slice(&a[2..]).unwrap_err();
+            assert_eq!(3, err.required_len());
+            let mut b = [0; 2];
+            assert_eq!(Err(err), v.try_write_to_slice(&mut b));
+            assert!(v.try_write_to_slice(&mut a).is_ok());
+            assert_eq!(v, $vec3::from_slice(&a));
+        }
+
+        #[test]
+        fn test_index() {
+            let mut v = $new(1, 2, 3);
+            assert_eq!(1, v[0]);
+            assert_eq!(2, v[1]);
+            assert_eq!(3, v[2]);
+            v[2] = 5;
+            assert_eq!(5, v.z());
+            v[0] += 2;
+            assert_eq!($new(3, 2, 5), v);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_index_out_of_bounds() {
+            let _ = $vec3::zero()[3];
+        }
+
+        #[cfg(feature = "rand")]
+        #[test]
+        fn test_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let a: ($t, $t, $t) = rng1.gen();
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let b: $vec3 = rng2.gen();
+            assert_eq!(a, b.into());
+        }
+
+        #[test]
+        fn test_wrapping() {
+            let a = $vec3::new($t::MAX, 1, 2);
+            let b = $vec3::new(1, 2, 3);
+            let c = $vec3::new($t::MIN, 5, 6);
+            assert_eq!($vec3::new($t::MIN, 3, 5), a.wrapping_add(b));
+            assert_eq!($vec3::new($t::MAX, 3, 3), c.wrapping_sub(b));
+            assert_eq!(
+                $vec3::new($t::MAX.wrapping_mul(2), 2, 4),
+                a.wrapping_mul($vec3::splat(2))
+            );
+        }
+
+        #[test]
+        fn test_saturating() {
+            let a = $vec3::new($t::MAX, 1, 2);
+            let b = $vec3::new(1, 2, 3);
+            let c = $vec3::new($t::MIN, 5, 6);
+            assert_eq!($vec3::new($t::MAX, 3, 5), a.saturating_add(b));
+            assert_eq!($vec3::new($t::MIN, 3, 3), c.saturating_sub(b));
+            assert_eq!($vec3::new($t::MAX, 2, 4), a.saturating_mul($vec3::splat(2)));
+        }
+
+        #[test]
+        fn test_checked() {
+            let a = $vec3::new($t::MAX, 1, 2);
+            let b = $vec3::new(1, 2, 3);
+            let c = $vec3::new($t::MIN, 5, 6);
+            assert_eq!(None, a.checked_add(b));
+            assert_eq!(Some($vec3::new(2, 4, 6)), b.checked_add(b));
+            assert_eq!(None, c.checked_sub(b));
+            assert_eq!(Some($vec3::zero()), b.checked_sub(b));
+            assert_eq!(None, a.checked_mul($vec3::splat(2)));
+            assert_eq!(Some($vec3::new(1, 4, 9)), b.checked_mul(b));
+            assert_eq!(None, b.checked_div($vec3::zero()));
+            assert_eq!(None, b.checked_div($vec3::new(1, 1, 0)));
+            assert_eq!(Some($vec3::splat(2)), $vec3::new(2, 4, 6).checked_div(b));
+        }
+
+        #[test]
+        fn test_bitops() {
+            let a = $vec3::new(0b1100, 0b1010, 0b110);
+            let b = $vec3::new(0b1010, 0b110, 0b11);
+            assert_eq!($vec3::new(0b1000, 0b10, 0b10), a & b);
+            assert_eq!($vec3::new(0b1110, 0b1110, 0b111), a | b);
+            assert_eq!($vec3::new(0b110, 0b1100, 0b101), a ^ b);
+            assert_eq!($vec3::new(0b100, 0b10, 0b110), a & 0b0110);
+            assert_eq!($vec3::new(0b1110, 0b1110, 0b110), a | 0b0110);
+            assert_eq!($vec3::new(0b1010, 0b1100, 0b0), a ^ 0b0110);
+            assert_eq!($vec3::zero(), !$vec3::splat(!0));
+            assert_eq!(a, !!a);
+
+            let mut c = a;
+            c &= b;
+            assert_eq!(a & b, c);
+            c |= a;
+            assert_eq!((a & b) | a, c);
+            c ^= b;
+            assert_eq!(((a & b) | a) ^ b, c);
+            c &= 0b1;
+            c |= 0b10;
+            c ^= 0b11;
+            assert_eq!(((((a & b) | a) ^ b) & 0b1 | 0b10) ^ 0b11, c);
+        }
+
+        #[test]
+        fn test_shifts() {
+            let a = $vec3::new(1, 2, 3);
+            assert_eq!($vec3::new(4, 8, 12), a << 2);
+            assert_eq!($vec3::new(0, 1, 1), a >> 1);
+            assert_eq!($vec3::new(1, 4, 12), a << $vec3::new(0, 1, 2));
+            assert_eq!($vec3::new(1, 1, 0), a >> $vec3::new(0, 1, 2));
+
+            let mut b = a;
+            b <<= 2;
+            assert_eq!($vec3::new(4, 8, 12), b);
+            b >>= 1;
+            assert_eq!($vec3::new(2, 4, 6), b);
+            b <<= $vec3::new(1, 0, 1);
+            assert_eq!($vec3::new(4, 4, 12), b);
+            b >>= $vec3::new(2, 1, 2);
+            assert_eq!($vec3::new(1, 2, 3), b);
+        }
+
+        #[test]
+        fn test_euclid() {
+            let a = $vec3::new(7, 8, 9);
+            let b = $vec3::new(4, 4, 3);
+            assert_eq!($vec3::new(1, 2, 3), a.div_euclid(b));
+            assert_eq!($vec3::new(3, 0, 0), a.rem_euclid(b));
+        }
+
+        #[test]
+        fn test_manhattan_chebyshev() {
+            let a = $vec3::new(1, 7, 3);
+            let b = $vec3::new(4, 2, 3);
+            assert_eq!(8, a.manhattan_distance(b));
+            assert_eq!(8, b.manhattan_distance(a));
+            assert_eq!(5, a.chebyshev_distance(b));
+            assert_eq!(5, b.chebyshev_distance(a));
+            assert_eq!(11, a.manhattan_length());
+            assert_eq!(7, a.chebyshev_length());
+            assert_eq!(0, a.manhattan_distance(a));
+            assert_eq!(0, a.chebyshev_distance(a));
+        }
+    };
+}
+
+/// Tests shared by the signed 3D integer vector types, in addition to
+/// `impl_vec3_int_tests`.
+#[macro_export]
+macro_rules! impl_vec3_signed_tests {
+    ($t:ident, $new:ident, $vec3:ident) => {
+        #[test]
+        fn test_neg() {
+            let a = $new(2, 4, 6);
+            assert_eq!($new(-2, -4, -6), -a);
+        }
+
+        #[test]
+        fn test_signed_midpoint() {
+            assert_eq!($new(2, -2, 2), $new(1, -4, 7).midpoint($new(4, -1, -2)));
+            // the sum does not overflow
+            assert_eq!(
+                $vec3::splat($t::MAX - 1),
+                $vec3::splat($t::MAX).midpoint($vec3::splat($t::MAX - 2))
+            );
+            assert_eq!(
+                $vec3::splat($t::MIN + 1),
+                $vec3::splat($t::MIN).midpoint($vec3::splat($t::MIN + 2))
+            );
+        }
+
+        #[test]
+        fn test_signed_min_max() {
+            let a = $new(1, -2, 3);
+            let b = $new(-1, 2, -3);
+            assert_eq!($new(-1, -2, -3), a.min(b));
+            assert_eq!($new(-1, -2, -3), b.min(a));
+            assert_eq!($new(1, 2, 3), a.max(b));
+            assert_eq!($new(1, 2, 3), b.max(a));
+        }
+
+        #[test]
+        fn test_signed_hmin_hmax() {
+            let a = $new(1, -2, 3);
+            assert_eq!(-2, a.min_element());
+            assert_eq!(3, a.max_element());
+        }
+
+        #[test]
+        fn test_abs_signum() {
+            assert_eq!($vec3::zero().abs(), $vec3::zero());
+            assert_eq!($vec3::one().abs(), $vec3::one());
+            assert_eq!((-$vec3::one()).abs(), $vec3::one());
+            assert_eq!($vec3::zero().signum(), $vec3::zero());
+            assert_eq!($vec3::splat(5).signum(), $vec3::one());
+            assert_eq!($vec3::splat(-5).signum(), -$vec3::one());
+        }
+
+        #[test]
+        fn test_signed_euclid() {
+            let a = $vec3::new(-7, 7, -7);
+            let b = $vec3::new(4, -4, -4);
+            assert_eq!($vec3::new(-2, -1, 2), a.div_euclid(b));
+            assert_eq!($vec3::new(1, 3, 1), a.rem_euclid(b));
+        }
+
+        #[test]
+        fn test_signed_manhattan_chebyshev() {
+            let a = $vec3::new(1, -2, 3);
+            let b = $vec3::new(-3, 5, 3);
+            assert_eq!(11, a.manhattan_distance(b));
+            assert_eq!(11, b.manhattan_distance(a));
+            assert_eq!(7, a.chebyshev_distance(b));
+            assert_eq!(7, b.chebyshev_distance(a));
+            assert_eq!(6, a.manhattan_length());
+            assert_eq!(3, a.chebyshev_length());
+            assert_eq!(0, a.manhattan_distance(a));
+            assert_eq!(0, a.chebyshev_distance(a));
+        }
+    };
+}
+
+/// Tests shared by all 4D integer vector types. Cases that depend on the sign
+/// of the element type are in `impl_vec4_signed_tests`.
+#[macro_export]
+macro_rules! impl_vec4_int_tests {
+    ($t:ident, $new:ident, $vec4:ident, $new3:ident) => {
+        #[test]
+        fn test_new() {
+            let v = $new(1, 2, 3, 4);
+            assert_eq!(v.x(), 1);
+            assert_eq!(v.y(), 2);
+            assert_eq!(v.z(), 3);
+            assert_eq!(v.w(), 4);
+
+            let t = (1, 2, 3, 4);
+            let v = $vec4::from(t);
+            assert_eq!(t, v.into());
+
+            let a = [1, 2, 3, 4];
+            let v = $vec4::from(a);
+            let a1: [$t; 4] = v.into();
+            assert_eq!(a, a1);
+
+            let v = $vec4::new(t.0, t.1, t.2, t.3);
+            assert_eq!(t, v.into());
+
+            assert_eq!($vec4::new(1, 0, 0, 0), $vec4::unit_x());
+            assert_eq!($vec4::new(0, 1, 0, 0), $vec4::unit_y());
+            assert_eq!($vec4::new(0, 0, 1, 0), $vec4::unit_z());
+            assert_eq!($vec4::new(0, 0, 0, 1), $vec4::unit_w());
+        }
+
+        #[test]
+        fn test_fmt() {
+            let a = $new(1, 2, 3, 4);
+            assert_eq!(
+                format!("{:?}", a),
+                concat!(stringify!($vec4), "(1, 2, 3, 4)")
+            );
+            assert_eq!(format!("{}", a), "[1, 2, 3, 4]");
+        }
+
+        #[test]
+        fn test_zero() {
+            let v = $vec4::zero();
+            assert_eq!($new(0, 0, 0, 0), v);
+            assert_eq!(v, $vec4::default());
+        }
+
+        #[test]
+        fn test_splat() {
+            let v = $vec4::splat(1);
+            assert_eq!($new(1, 1, 1, 1), v);
+        }
+
+        #[test]
+        fn test_accessors() {
+            let mut a = $vec4::zero();
+            a.set_x(1);
+            a.set_y(2);
+            a.set_z(3);
+            a.set_w(4);
+            assert_eq!(1, a.x());
+            assert_eq!(2, a.y());
+            assert_eq!(3, a.z());
+            assert_eq!(4, a.w());
+        }
+
+        #[test]
+        fn test_funcs() {
+            let x = $new(1, 0, 0, 0);
+            let y = $new(0, 1, 0, 0);
+            assert_eq!(1, x.dot(x));
+            assert_eq!(0, x.dot(y));
+            assert_eq!(40, $new(1, 2, 3, 4).dot($new(2, 3, 4, 5)));
+        }
+
+        #[test]
+        fn test_ops() {
+            let a = $new(2, 4, 6, 8);
+            assert_eq!($new(4, 8, 12, 16), (a + a));
+            assert_eq!($new(0, 0, 0, 0), (a - a));
+            assert_eq!($new(4, 16, 36, 64), (a * a));
+            assert_eq!($new(4, 8, 12, 16), (a * 2));
+            assert_eq!($new(4, 8, 12, 16), (2 * a));
+            assert_eq!($new(1, 1, 1, 1), (a / a));
+            assert_eq!($new(1, 2, 3, 4), (a / 2));
+            assert_eq!($new(2, 1, 0, 2), (a % 3));
+        }
+
+        #[test]
+        fn test_assign_ops() {
+            let a = $new(1, 2, 3, 4);
+            let mut b = a;
+            b += a;
+            assert_eq!($new(2, 4, 6, 8), b);
+            b -= a;
+            assert_eq!($new(1, 2, 3, 4), b);
+            b *= a;
+            assert_eq!($new(1, 4, 9, 16), b);
+            b /= a;
+            assert_eq!($new(1, 2, 3, 4), b);
+            b *= 2;
+            assert_eq!($new(2, 4, 6, 8), b);
+            b /= 2;
+            assert_eq!($new(1, 2, 3, 4), b);
+            b %= 2;
+            assert_eq!($new(1, 0, 1, 0), b);
+        }
+
+        #[test]
+        fn test_midpoint() {
+            assert_eq!(
+                $new(2, 2, 4, 2),
+                $new(1, 4, 7, 0).midpoint($new(4, 1, 2, 5))
+            );
+            // the sum does not overflow
+            assert_eq!(
+                $vec4::splat($t::MAX - 1),
+                $vec4::splat($t::MAX).midpoint($vec4::splat($t::MAX - 2))
+            );
+        }
+
+        #[test]
+        fn test_sum() {
+            let a = $new(1, 2, 3, 4);
+            let vs = [a, a, a];
+            assert_eq!($new(3, 6, 9, 12), vs.iter().sum());
+            assert_eq!($new(3, 6, 9, 12), vs.iter().copied().sum());
+            assert_eq!($vec4::zero(), std::iter::empty::<$vec4>().sum());
+        }
+
+        #[test]
+        fn test_min_max() {
+            let a = $new(1, 6, 3, 8);
+            let b = $new(5, 2, 7, 4);
+            assert_eq!($new(1, 2, 3, 4), a.min(b));
+            assert_eq!($new(1, 2, 3, 4), b.min(a));
+            assert_eq!($new(5, 6, 7, 8), a.max(b));
+            assert_eq!($new(5, 6, 7, 8), b.max(a));
+        }
+
+        #[test]
+        fn test_clamp() {
+            let min = $new(1, 1, 1, 1);
+            let max = $new(3, 3, 3, 3);
+            assert_eq!($new(1, 2, 3, 3), $new(0, 2, 5, 4).clamp(min, max));
+            let a = $new(0, 2, 5, 3);
+            assert_eq!($new(1, 2, 3, 3), a.clamp_scalar(1, 3));
+            assert_eq!($new(0, 2, 2, 2), a.min_scalar(2));
+            assert_eq!($new(2, 2, 5, 3), a.max_scalar(2));
+        }
+
+        #[test]
+        fn test_hmin_hmax() {
+            let a = $new(1, 6, 3, 8);
+            assert_eq!(1, a.min_element());
+            assert_eq!(8, a.max_element());
+        }
+
+        #[test]
+        fn test_cmp() {
+            let a = $vec4::splat(1);
+            let b = $vec4::splat(2);
+            assert!(a.cmpeq(a).all());
+            assert!(a.cmpne(b).all());
+            assert_eq!(a.cmplt(b).bitmask(), 0xf);
+            assert_eq!(a.cmple(a).bitmask(), 0xf);
+            assert_eq!(a.cmpgt(b).bitmask(), 0x0);
+            assert!(b.cmpgt(a).all());
+            assert!(b.cmpge(b).all());
+            assert!(!a.cmpge(b).any());
+        }
+
+        #[test]
+        fn test_extend_truncate() {
+            let a = $new(1, 2, 3, 4);
+            assert_eq!($new3(1, 2, 3), a.truncate());
+            assert_eq!($new3(1, 2, 3), a.xyz());
+        }
+
+        #[test]
+        fn test_slice() {
+            let a = [1, 2, 3, 4];
+            let b = $vec4::from_slice_unaligned(&a);
+            let c: [$t; 4] = b.into();
+            assert_eq!(a, c);
+            let mut d = [0; 4];
+            b.write_to_slice_unaligned(&mut d[..]);
+            assert_eq!(a, d);
+            assert_eq!(&a, b.as_ref());
+        }
+
+        #[test]
+        fn test_try_slice() {
+            let v = $new(1, 2, 3, 4);
+            let mut a = [0; 5];
+            v.write_to_slice(&mut a[1..]);
+            assert_eq!(v, $vec4::from_slice(&a[1..]));
+            assert_eq!(Ok(v), $vec4::try_from_slice(&a[1..]));
+            let err = $vec4::try_from_slice(&a[2..]).unwrap_err();
+            assert_eq!(4, err.required_len());
+            let mut b = [0; 3];
+            assert_eq!(Err(err), v.try_write_to_slice(&mut b));
+            assert!(v.try_write_to_slice(&mut a).is_ok());
+            assert_eq!(v, $vec4::from_slice(&a));
+        }
+
+        #[test]
+        fn test_index() {
+            let mut v = $new(1, 2, 3, 4);
+            assert_eq!(1, v[0]);
+            assert_eq!(2, v[1]);
+            assert_eq!(3, v[2]);
+            assert_eq!(4, v[3]);
+            v[3] = 5;
+            assert_eq!(5, v.w());
+            v[0] += 2;
+            assert_eq!($new(3, 2, 3, 5), v);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_index_out_of_bounds() {
+            let _ = $vec4::zero()[4];
+        }
+
+        #[cfg(feature = "rand")]
+        #[test]
+        fn test_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let a: ($t, $t, $t, $t) = rng1.gen();
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let b: $vec4 = rng2.gen();
+            assert_eq!(a, b.into());
+        }
+
+        #[test]
+        fn test_wrapping() {
+            let a = $vec4::new($t::MAX, 1, 2, 3);
+            let b = $vec4::new(1, 2, 3, 4);
+            let c = $vec4::new($t::MIN, 5, 6, 7);
+            assert_eq!($vec4::new($t::MIN, 3, 5, 7), a.wrapping_add(b));
+            assert_eq!($vec4::new($t::MAX, 3, 3, 3), c.wrapping_sub(b));
+            assert_eq!(
+                $vec4::new($t::MAX.wrapping_mul(2), 2, 4, 6),
+                a.wrapping_mul($vec4::splat(2))
+            );
+        }
+
+        #[test]
+        fn test_saturating() {
+            let a = $vec4::new($t::MAX, 1, 2, 3);
+            let b = $vec4::new(1, 2, 3, 4);
+            let c = $vec4::new($t::MIN, 5, 6, 7);
+            assert_eq!($vec4::new($t::MAX, 3, 5, 7), a.saturating_add(b));
+            assert_eq!($vec4::new($t::MIN, 3, 3, 3), c.saturating_sub(b));
+            assert_eq!(
+                $vec4::new($t::MAX, 2, 4, 6),
+                a.saturating_mul($vec4::splat(2))
+            );
+        }
+
+        #[test]
+        fn test_checked() {
+            let a = $vec4::new($t::MAX, 1, 2, 3);
+            let b = $vec4::new(1, 2, 3, 4);
+            let c = $vec4::new($t::MIN, 5, 6, 7);
+            assert_eq!(None, a.checked_add(b));
+            assert_eq!(Some($vec4::new(2, 4, 6, 8)), b.checked_add(b));
+            assert_eq!(None, c.checked_sub(b));
+            assert_eq!(Some($vec4::zero()), b.checked_sub(b));
+            assert_eq!(None, a.checked_mul($vec4::splat(2)));
+            assert_eq!(Some($vec4::new(1, 4, 9, 16)), b.checked_mul(b));
+            assert_eq!(None, b.checked_div($vec4::zero()));
+            assert_eq!(None, b.checked_div($vec4::new(1, 1, 0, 1)));
+            assert_eq!(Some($vec4::splat(2)), $vec4::new(2, 4, 6, 8).checked_div(b));
+        }
+
+        #[test]
+        fn test_bitops() {
+            let a = $vec4::new(0b1100, 0b1010, 0b110, 0b1);
+            let b = $vec4::new(0b1010, 0b110, 0b11, 0b1);
+            assert_eq!($vec4::new(0b1000, 0b10, 0b10, 0b1), a & b);
+            assert_eq!($vec4::new(0b1110, 0b1110, 0b111, 0b1), a | b);
+            assert_eq!($vec4::new(0b110, 0b1100, 0b101, 0b0), a ^ b);
+            assert_eq!($vec4::new(0b100, 0b10, 0b110, 0b0), a & 0b0110);
+            assert_eq!($vec4::new(0b1110, 0b1110, 0b110, 0b111), a | 0b0110);
+            assert_eq!($vec4::new(0b1010, 0b1100, 0b0, 0b111), a ^ 0b0110);
+            assert_eq!($vec4::zero(), !$vec4::splat(!0));
+            assert_eq!(a, !!a);
+
+            let mut c = a;
+            c &= b;
+            assert_eq!(a & b, c);
+            c |= a;
+            assert_eq!((a & b) | a, c);
+            c ^= b;
+            assert_eq!(((a & b) | a) ^ b, c);
+            c &= 0b1;
+            c |= 0b10;
+            c ^= 0b11;
+            assert_eq!(((((a & b) | a) ^ b) & 0b1 | 0b10) ^ 0b11, c);
+        }
+
+        #[test]
+        fn test_shifts() {
+            let a = $vec4::new(1, 2, 3, 4);
+            assert_eq!($vec4::new(4, 8, 12, 16), a << 2);
+            assert_eq!($vec4::new(0, 1, 1, 2), a >> 1);
+            assert_eq!($vec4::new(1, 4, 12, 32), a << $vec4::new(0, 1, 2, 3));
+            assert_eq!($vec4::new(1, 1, 0, 0), a >> $vec4::new(0, 1, 2, 3));
+
+            let mut b = a;
+            b <<= 2;
+            assert_eq!($vec4::new(4, 8, 12, 16), b);
+            b >>= 1;
+            assert_eq!($vec4::new(2, 4, 6, 8), b);
+            b <<= $vec4::new(1, 0, 1, 0);
+            assert_eq!($vec4::new(4, 4, 12, 8), b);
+            b >>= $vec4::new(2, 1, 2, 1);
+            assert_eq!($vec4::new(1, 2, 3, 4), b);
+        }
+
+        #[test]
+        fn test_euclid() {
+            let a = $vec4::new(7, 8, 9, 10);
+            let b = $vec4::new(4, 4, 3, 3);
+            assert_eq!($vec4::new(1, 2, 3, 3), a.div_euclid(b));
+            assert_eq!($vec4::new(3, 0, 0, 1), a.rem_euclid(b));
+        }
+
+        #[test]
+        fn test_manhattan_chebyshev() {
+            let a = $vec4::new(1, 7, 3, 9);
+            let b = $vec4::new(4, 2, 3, 1);
+            assert_eq!(16, a.manhattan_distance(b));
+            assert_eq!(16, b.manhattan_distance(a));
+            assert_eq!(8, a.chebyshev_distance(b));
+            assert_eq!(8, b.chebyshev_distance(a));
+            assert_eq!(20, a.manhattan_length());
+            assert_eq!(9, a.chebyshev_length());
+            assert_eq!(0, a.manhattan_distance(a));
+            assert_eq!(0, a.chebyshev_distance(a));
+        }
+    };
+}
+
+/// Tests shared by the signed 4D integer vector types, in addition to
+/// `impl_vec4_int_tests`.
+#[macro_export]
+macro_rules! impl_vec4_signed_tests {
+    ($t:ident, $new:ident, $vec4:ident) => {
+        #[test]
+        fn test_neg() {
+            let a = $new(2, 4, 6, 8);
+            assert_eq!($new(-2, -4, -6, -8), -a);
+        }
+
+        #[test]
+        fn test_signed_midpoint() {
+            assert_eq!(
+                $new(2, -2, 2, 2),
+                $new(1, -4, 7, 0).midpoint($new(4, -1, -2, 5))
+            );
+            // the sum does not overflow
+            assert_eq!(
+                $vec4::splat($t::MAX - 1),
+                $vec4::splat($t::MAX).midpoint($vec4::splat($t::MAX - 2))
+            );
+            assert_eq!(
+                $vec4::splat($t::MIN + 1),
+                $vec4::splat($t::MIN).midpoint($vec4::splat($t::MIN + 2))
+            );
+        }
+
+        #[test]
+        fn test_signed_min_max() {
+            let a = $new(1, -2, 3, -4);
+            let b = $new(-1, 2, -3, 4);
+            assert_eq!($new(-1, -2, -3, -4), a.min(b));
+            assert_eq!($new(-1, -2, -3, -4), b.min(a));
+            assert_eq!($new(1, 2, 3, 4), a.max(b));
+            assert_eq!($new(1, 2, 3, 4), b.max(a));
+        }
+
+        #[test]
+        fn test_signed_hmin_hmax() {
+            let a = $new(1, -2, 3, -4);
+            assert_eq!(-4, a.min_element());
+            assert_eq!(3, a.max_element());
+        }
+
+        #[test]
+        fn test_abs_signum() {
+            assert_eq!($vec4::zero().abs(), $vec4::zero());
+            assert_eq!($vec4::one().abs(), $vec4::one());
+            assert_eq!((-$vec4::one()).abs(), $vec4::one());
+            assert_eq!($vec4::zero().signum(), $vec4::zero());
+            assert_eq!($vec4::splat(5).signum(), $vec4::one());
+            assert_eq!($vec4::splat(-5).signum(), -$vec4::one());
+        }
+
+        #[test]
+        fn test_signed_euclid() {
+            let a = $vec4::new(-7, 7, -7, 7);
+            let b = $vec4::new(4, -4, -4, 4);
+            assert_eq!($vec4::new(-2, -1, 2, 1), a.div_euclid(b));
+            assert_eq!($vec4::new(1, 3, 1, 3), a.rem_euclid(b));
+        }
+
+        #[test]
+        fn test_signed_manhattan_chebyshev() {
+            let a = $vec4::new(1, -2, 3, -4);
+            let b = $vec4::new(-3, 5, 3, 0);
+            assert_eq!(15, a.manhattan_distance(b));
+            assert_eq!(15, b.manhattan_distance(a));
+            assert_eq!(7, a.chebyshev_distance(b));
+            assert_eq!(7, b.chebyshev_distance(a));
+            assert_eq!(10, a.manhattan_length());
+            assert_eq!(4, a.chebyshev_length());
+            assert_eq!(0, a.manhattan_distance(a));
+            assert_eq!(0, a.chebyshev_distance(a));
+        }
+    };
+}
@@ -1,7 +1,10 @@
 #[macro_use]
 mod macros;
 
-use glam::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use glam::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3,
+    Vec3A, Vec4,
+};
 
 /// Helper function for migrating away from `glam::angle::deg`.
 #[allow(dead_code)]
@@ -18,6 +21,7 @@ pub fn rad(angle: f32) -> f32 {
 }
 
 /// Trait used by the `assert_approx_eq` macro for floating point comparisons.
+#[allow(dead_code)]
 pub trait FloatCompare<Rhs: ?Sized = Self> {
     /// Return true if the absolute difference between `self` and `other` is
     /// less then or equal to `max_abs_diff`.
@@ -38,6 +42,17 @@ impl FloatCompare for f32 {
     }
 }
 
+impl FloatCompare for f64 {
+    #[inline]
+    fn approx_eq(&self, other: &f64, max_abs_diff: f32) -> bool {
+        (self - other).abs() <= max_abs_diff as f64
+    }
+    #[inline]
+    fn abs_diff(&self, other: &f64) -> f64 {
+        (self - other).abs()
+    }
+}
+
 impl FloatCompare for Mat2 {
     #[inline]
     fn approx_eq(&self, other: &Mat2, max_abs_diff: f32) -> bool {
@@ -67,6 +82,21 @@ impl FloatCompare for Mat3 {
     }
 }
 
+impl FloatCompare for Mat3A {
+    #[inline]
+    fn approx_eq(&self, other: &Mat3A, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Mat3A) -> Mat3A {
+        Mat3A::from_cols(
+            (self.x_axis() - other.x_axis()).abs(),
+            (self.y_axis() - other.y_axis()).abs(),
+            (self.z_axis() - other.z_axis()).abs(),
+        )
+    }
+}
+
 impl FloatCompare for Mat4 {
     #[inline]
     fn approx_eq(&self, other: &Mat4, max_abs_diff: f32) -> bool {
@@ -118,6 +148,17 @@ impl FloatCompare for Vec3 {
     }
 }
 
+impl FloatCompare for Vec3A {
+    #[inline]
+    fn approx_eq(&self, other: &Vec3A, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Vec3A) -> Vec3A {
+        (*self - *other).abs()
+    }
+}
+
 impl FloatCompare for Vec4 {
     #[inline]
     fn approx_eq(&self, other: &Vec4, max_abs_diff: f32) -> bool {
@@ -128,3 +169,94 @@ impl FloatCompare for Vec4 {
         (*self - *other).abs()
     }
 }
+
+impl FloatCompare for DVec2 {
+    #[inline]
+    fn approx_eq(&self, other: &DVec2, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &DVec2) -> DVec2 {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for DVec3 {
+    #[inline]
+    fn approx_eq(&self, other: &DVec3, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &DVec3) -> DVec3 {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for DVec4 {
+    #[inline]
+    fn approx_eq(&self, other: &DVec4, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &DVec4) -> DVec4 {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for DMat2 {
+    #[inline]
+    fn approx_eq(&self, other: &DMat2, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &DMat2) -> DMat2 {
+        DMat2::from_cols(
+            (self.x_axis() - other.x_axis()).abs(),
+            (self.y_axis() - other.y_axis()).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DMat3 {
+    #[inline]
+    fn approx_eq(&self, other: &DMat3, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &DMat3) -> DMat3 {
+        DMat3::from_cols(
+            (self.x_axis() - other.x_axis()).abs(),
+            (self.y_axis() - other.y_axis()).abs(),
+            (self.z_axis() - other.z_axis()).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DMat4 {
+    #[inline]
+    fn approx_eq(&self, other: &DMat4, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &DMat4) -> DMat4 {
+        DMat4::from_cols(
+            (self.x_axis() - other.x_axis()).abs(),
+            (self.y_axis() - other.y_axis()).abs(),
+            (self.z_axis() - other.z_axis()).abs(),
+            (self.w_axis() - other.w_axis()).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DQuat {
+    #[inline]
+    fn approx_eq(&self, other: &DQuat, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &DQuat) -> DQuat {
+        let a: DVec4 = (*self).into();
+        let b: DVec4 = (*other).into();
+        (a - b).abs().into()
+    }
+}
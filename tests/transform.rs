@@ -1,5 +1,9 @@
+#[cfg(feature = "transform-types")]
+mod support;
+
 #[cfg(feature = "transform-types")]
 mod transform {
+    use crate::{assert_approx_eq, support};
     use glam::f32::*;
 
     #[test]
@@ -54,7 +58,9 @@ mod transform {
         assert_approx_eq!(v0, v2);
 
         assert_eq!(tr * TransformRT::identity(), tr);
-        assert_eq!(tr * inv_tr, TransformRT::identity());
+        let tr_inv_tr = tr * inv_tr;
+        assert_approx_eq!(tr_inv_tr.rotation, Quat::identity());
+        assert_approx_eq!(tr_inv_tr.translation, Vec3::zero());
 
         assert_eq!(tr * TransformSRT::identity(), TransformSRT::from(tr));
         assert_eq!(TransformSRT::identity() * tr, TransformSRT::from(tr));
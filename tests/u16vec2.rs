@@ -0,0 +1,74 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec2_int_tests!(u16, u16vec2, U16Vec2, u16vec3);
+
+#[test]
+fn test_u16vec2_conversions() {
+    let a = u16vec2(1, 2);
+    assert_eq!(vec2(1.0, 2.0), a.as_vec2());
+    assert_eq!(ivec2(1, 2), IVec2::from(a));
+    assert_eq!(uvec2(1, 2), UVec2::from(a));
+
+    assert_eq!(Ok(a), U16Vec2::try_from(ivec2(1, 2)));
+    assert!(U16Vec2::try_from(ivec2(70000, 2)).is_err());
+    assert_eq!(Ok(a), U16Vec2::try_from(uvec2(1, 2)));
+    assert!(U16Vec2::try_from(uvec2(70000, 2)).is_err());
+    assert!(U16Vec2::try_from(ivec2(-1, 2)).is_err());
+}
+
+#[test]
+fn test_u16vec2_as() {
+    let a = U16Vec2::new(1, 2);
+    assert_eq!(Vec2::new(1.0, 2.0), a.as_vec2());
+    assert_eq!(DVec2::new(1.0, 2.0), a.as_dvec2());
+    assert_eq!(I16Vec2::new(1, 2), a.as_i16vec2());
+    assert_eq!(IVec2::new(1, 2), a.as_ivec2());
+    assert_eq!(UVec2::new(1, 2), a.as_uvec2());
+    assert_eq!(I64Vec2::new(1, 2), a.as_i64vec2());
+    assert_eq!(U64Vec2::new(1, 2), a.as_u64vec2());
+
+    let b = U16Vec2::splat(u16::MAX);
+    assert_eq!(-1, b.as_i16vec2().x());
+    assert_eq!(u64::from(u16::MAX), b.as_u64vec2().x());
+}
+
+#[test]
+fn test_u16vec2_dot_wide() {
+    let a = U16Vec2::splat(u16::MAX / 4);
+    assert_eq!(
+        2 * (u64::from(u16::MAX / 4) * u64::from(u16::MAX / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        2 * (u64::from(u16::MAX / 4) * u64::from(u16::MAX / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (u16::MAX / 4).wrapping_mul(u16::MAX / 4).wrapping_mul(2),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (u16::MAX / 4).wrapping_mul(u16::MAX / 4).wrapping_mul(2),
+        a.wrapping_dot(a)
+    );
+    let b = U16Vec2::new(1, 2);
+    assert_eq!(5, b.length_squared());
+    assert_eq!(5, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_u16vec2_manhattan_chebyshev_extremes() {
+    let a = U16Vec2::zero();
+    let b = U16Vec2::splat(u16::MAX);
+    let d = u64::from(u16::MAX);
+    assert_eq!(2 * d, a.manhattan_distance(b));
+    assert_eq!(2 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(2 * d, b.manhattan_length());
+    assert_eq!(d, b.chebyshev_length());
+}
@@ -0,0 +1,74 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec4_int_tests!(u16, u16vec4, U16Vec4, u16vec3);
+
+#[test]
+fn test_u16vec4_conversions() {
+    let a = u16vec4(1, 2, 3, 4);
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(ivec4(1, 2, 3, 4), IVec4::from(a));
+    assert_eq!(uvec4(1, 2, 3, 4), UVec4::from(a));
+
+    assert_eq!(Ok(a), U16Vec4::try_from(ivec4(1, 2, 3, 4)));
+    assert!(U16Vec4::try_from(ivec4(70000, 2, 3, 4)).is_err());
+    assert_eq!(Ok(a), U16Vec4::try_from(uvec4(1, 2, 3, 4)));
+    assert!(U16Vec4::try_from(uvec4(70000, 2, 3, 4)).is_err());
+    assert!(U16Vec4::try_from(ivec4(-1, 2, 3, 4)).is_err());
+}
+
+#[test]
+fn test_u16vec4_as() {
+    let a = U16Vec4::new(1, 2, 3, 4);
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(I16Vec4::new(1, 2, 3, 4), a.as_i16vec4());
+    assert_eq!(IVec4::new(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(UVec4::new(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(I64Vec4::new(1, 2, 3, 4), a.as_i64vec4());
+    assert_eq!(U64Vec4::new(1, 2, 3, 4), a.as_u64vec4());
+
+    let b = U16Vec4::splat(u16::MAX);
+    assert_eq!(-1, b.as_i16vec4().x());
+    assert_eq!(u64::from(u16::MAX), b.as_u64vec4().x());
+}
+
+#[test]
+fn test_u16vec4_dot_wide() {
+    let a = U16Vec4::splat(u16::MAX / 4);
+    assert_eq!(
+        4 * (u64::from(u16::MAX / 4) * u64::from(u16::MAX / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        4 * (u64::from(u16::MAX / 4) * u64::from(u16::MAX / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (u16::MAX / 4).wrapping_mul(u16::MAX / 4).wrapping_mul(4),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (u16::MAX / 4).wrapping_mul(u16::MAX / 4).wrapping_mul(4),
+        a.wrapping_dot(a)
+    );
+    let b = U16Vec4::new(1, 2, 3, 4);
+    assert_eq!(30, b.length_squared());
+    assert_eq!(30, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_u16vec4_manhattan_chebyshev_extremes() {
+    let a = U16Vec4::zero();
+    let b = U16Vec4::splat(u16::MAX);
+    let d = u64::from(u16::MAX);
+    assert_eq!(4 * d, a.manhattan_distance(b));
+    assert_eq!(4 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(4 * d, b.manhattan_length());
+    assert_eq!(d, b.chebyshev_length());
+}
@@ -0,0 +1,83 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec2_int_tests!(u64, u64vec2, U64Vec2, u64vec3);
+
+#[test]
+fn test_u64vec2_conversions() {
+    let a = u64vec2(1, 2);
+    assert_eq!(vec2(1.0, 2.0), a.as_vec2());
+    assert_eq!(dvec2(1.0, 2.0), a.as_dvec2());
+    assert_eq!(uvec2(1, 2), a.as_uvec2());
+    assert_eq!(a, U64Vec2::from(uvec2(1, 2)));
+
+    assert_eq!(Ok(uvec2(1, 2)), UVec2::try_from(a));
+    assert!(UVec2::try_from(u64vec2(u64::MAX, 2)).is_err());
+    assert_eq!(Ok(a), U64Vec2::try_from(i64vec2(1, 2)));
+    assert!(U64Vec2::try_from(i64vec2(-1, 2)).is_err());
+}
+
+#[test]
+fn test_u64vec2_as() {
+    let a = U64Vec2::new(1, 2);
+    assert_eq!(Vec2::new(1.0, 2.0), a.as_vec2());
+    assert_eq!(DVec2::new(1.0, 2.0), a.as_dvec2());
+    assert_eq!(I16Vec2::new(1, 2), a.as_i16vec2());
+    assert_eq!(U16Vec2::new(1, 2), a.as_u16vec2());
+    assert_eq!(IVec2::new(1, 2), a.as_ivec2());
+    assert_eq!(UVec2::new(1, 2), a.as_uvec2());
+    assert_eq!(I64Vec2::new(1, 2), a.as_i64vec2());
+
+    let b = U64Vec2::splat(u64::MAX);
+    assert_eq!(-1, b.as_i16vec2().x());
+    assert_eq!(u16::MAX, b.as_u16vec2().x());
+}
+
+#[test]
+fn test_u64vec2_dot_wide() {
+    let a = U64Vec2::splat(u64::MAX / 4);
+    assert_eq!(
+        2 * (u128::from(u64::MAX / 4) * u128::from(u64::MAX / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        2 * (u128::from(u64::MAX / 4) * u128::from(u64::MAX / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (u64::MAX / 4).wrapping_mul(u64::MAX / 4).wrapping_mul(2),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (u64::MAX / 4).wrapping_mul(u64::MAX / 4).wrapping_mul(2),
+        a.wrapping_dot(a)
+    );
+    let b = U64Vec2::new(1, 2);
+    assert_eq!(5, b.length_squared());
+    assert_eq!(5, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_u64vec2_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let hi = u128::from(u64::MAX / 2);
+    let a = U64Vec2::splat(u64::MAX / 2);
+    assert_eq!(2 * hi * hi, a.dot_wide(a));
+    assert_eq!(2 * hi * hi, a.length_squared());
+}
+
+#[test]
+fn test_u64vec2_manhattan_chebyshev_extremes() {
+    let a = U64Vec2::zero();
+    let b = U64Vec2::splat(u64::MAX);
+    let d = u128::from(u64::MAX);
+    assert_eq!(2 * d, a.manhattan_distance(b));
+    assert_eq!(2 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(2 * d, b.manhattan_length());
+    assert_eq!(d, b.chebyshev_length());
+}
@@ -0,0 +1,84 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec3_int_tests!(u64, u64vec3, U64Vec3, u64vec2, u64vec4);
+
+#[test]
+fn test_u64vec3_conversions() {
+    let a = u64vec3(1, 2, 3);
+    assert_eq!(vec3(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(dvec3(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(uvec3(1, 2, 3), a.as_uvec3());
+    assert_eq!(a, U64Vec3::from(uvec3(1, 2, 3)));
+
+    assert_eq!(Ok(uvec3(1, 2, 3)), UVec3::try_from(a));
+    assert!(UVec3::try_from(u64vec3(u64::MAX, 2, 3)).is_err());
+    assert_eq!(Ok(a), U64Vec3::try_from(i64vec3(1, 2, 3)));
+    assert!(U64Vec3::try_from(i64vec3(-1, 2, 3)).is_err());
+}
+
+#[test]
+fn test_u64vec3_as() {
+    let a = U64Vec3::new(1, 2, 3);
+    assert_eq!(Vec3::new(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), a.as_vec3a());
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(I16Vec3::new(1, 2, 3), a.as_i16vec3());
+    assert_eq!(U16Vec3::new(1, 2, 3), a.as_u16vec3());
+    assert_eq!(IVec3::new(1, 2, 3), a.as_ivec3());
+    assert_eq!(UVec3::new(1, 2, 3), a.as_uvec3());
+    assert_eq!(I64Vec3::new(1, 2, 3), a.as_i64vec3());
+
+    let b = U64Vec3::splat(u64::MAX);
+    assert_eq!(-1, b.as_i16vec3().x());
+    assert_eq!(u16::MAX, b.as_u16vec3().x());
+}
+
+#[test]
+fn test_u64vec3_dot_wide() {
+    let a = U64Vec3::splat(u64::MAX / 4);
+    assert_eq!(
+        3 * (u128::from(u64::MAX / 4) * u128::from(u64::MAX / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        3 * (u128::from(u64::MAX / 4) * u128::from(u64::MAX / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (u64::MAX / 4).wrapping_mul(u64::MAX / 4).wrapping_mul(3),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (u64::MAX / 4).wrapping_mul(u64::MAX / 4).wrapping_mul(3),
+        a.wrapping_dot(a)
+    );
+    let b = U64Vec3::new(1, 2, 3);
+    assert_eq!(14, b.length_squared());
+    assert_eq!(14, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_u64vec3_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let hi = u128::from(u64::MAX / 2);
+    let a = U64Vec3::splat(u64::MAX / 2);
+    assert_eq!(3 * hi * hi, a.dot_wide(a));
+    assert_eq!(3 * hi * hi, a.length_squared());
+}
+
+#[test]
+fn test_u64vec3_manhattan_chebyshev_extremes() {
+    let a = U64Vec3::zero();
+    let b = U64Vec3::splat(u64::MAX);
+    let d = u128::from(u64::MAX);
+    assert_eq!(3 * d, a.manhattan_distance(b));
+    assert_eq!(3 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(3 * d, b.manhattan_length());
+    assert_eq!(d, b.chebyshev_length());
+}
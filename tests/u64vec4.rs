@@ -0,0 +1,83 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec4_int_tests!(u64, u64vec4, U64Vec4, u64vec3);
+
+#[test]
+fn test_u64vec4_conversions() {
+    let a = u64vec4(1, 2, 3, 4);
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(dvec4(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(uvec4(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(a, U64Vec4::from(uvec4(1, 2, 3, 4)));
+
+    assert_eq!(Ok(uvec4(1, 2, 3, 4)), UVec4::try_from(a));
+    assert!(UVec4::try_from(u64vec4(u64::MAX, 2, 3, 4)).is_err());
+    assert_eq!(Ok(a), U64Vec4::try_from(i64vec4(1, 2, 3, 4)));
+    assert!(U64Vec4::try_from(i64vec4(-1, 2, 3, 4)).is_err());
+}
+
+#[test]
+fn test_u64vec4_as() {
+    let a = U64Vec4::new(1, 2, 3, 4);
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(I16Vec4::new(1, 2, 3, 4), a.as_i16vec4());
+    assert_eq!(U16Vec4::new(1, 2, 3, 4), a.as_u16vec4());
+    assert_eq!(IVec4::new(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(UVec4::new(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(I64Vec4::new(1, 2, 3, 4), a.as_i64vec4());
+
+    let b = U64Vec4::splat(u64::MAX);
+    assert_eq!(-1, b.as_i16vec4().x());
+    assert_eq!(u16::MAX, b.as_u16vec4().x());
+}
+
+#[test]
+fn test_u64vec4_dot_wide() {
+    let a = U64Vec4::splat(u64::MAX / 4);
+    assert_eq!(
+        4 * (u128::from(u64::MAX / 4) * u128::from(u64::MAX / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        4 * (u128::from(u64::MAX / 4) * u128::from(u64::MAX / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (u64::MAX / 4).wrapping_mul(u64::MAX / 4).wrapping_mul(4),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (u64::MAX / 4).wrapping_mul(u64::MAX / 4).wrapping_mul(4),
+        a.wrapping_dot(a)
+    );
+    let b = U64Vec4::new(1, 2, 3, 4);
+    assert_eq!(30, b.length_squared());
+    assert_eq!(30, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_u64vec4_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let hi = u128::from(u64::MAX / 2);
+    let a = U64Vec4::splat(u64::MAX / 2);
+    assert_eq!(4 * hi * hi, a.dot_wide(a));
+    assert_eq!(4 * hi * hi, a.length_squared());
+}
+
+#[test]
+fn test_u64vec4_manhattan_chebyshev_extremes() {
+    let a = U64Vec4::zero();
+    let b = U64Vec4::splat(u64::MAX);
+    let d = u128::from(u64::MAX);
+    assert_eq!(4 * d, a.manhattan_distance(b));
+    assert_eq!(4 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(4 * d, b.manhattan_length());
+    assert_eq!(d, b.chebyshev_length());
+}
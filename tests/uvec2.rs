@@ -0,0 +1,86 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec2_int_tests!(u32, uvec2, UVec2, uvec3);
+
+#[test]
+fn test_uvec2_conversions() {
+    let a = uvec2(1, 2);
+    assert_eq!(vec2(1.0, 2.0), a.as_vec2());
+    assert_eq!(dvec2(1.0, 2.0), a.as_dvec2());
+    assert_eq!(dvec2(1.0, 2.0), DVec2::from(a));
+    assert_eq!(ivec2(1, 2), a.as_ivec2());
+    assert_eq!(a, ivec2(1, 2).as_uvec2());
+    assert_eq!(uvec2(0, 2), vec2(-1.5, 2.5).as_uvec2());
+    assert_eq!(uvec2(0, 2), dvec2(-1.5, 2.5).as_uvec2());
+
+    assert_eq!(Ok(a), UVec2::try_from(ivec2(1, 2)));
+    assert!(UVec2::try_from(ivec2(-1, 2)).is_err());
+    assert_eq!(Ok(ivec2(1, 2)), IVec2::try_from(a));
+    assert!(IVec2::try_from(uvec2(u32::MAX, 2)).is_err());
+}
+
+#[test]
+fn test_uvec2_as() {
+    let a = UVec2::new(1, 2);
+    assert_eq!(Vec2::new(1.0, 2.0), a.as_vec2());
+    assert_eq!(DVec2::new(1.0, 2.0), a.as_dvec2());
+    assert_eq!(I16Vec2::new(1, 2), a.as_i16vec2());
+    assert_eq!(U16Vec2::new(1, 2), a.as_u16vec2());
+    assert_eq!(IVec2::new(1, 2), a.as_ivec2());
+    assert_eq!(I64Vec2::new(1, 2), a.as_i64vec2());
+    assert_eq!(U64Vec2::new(1, 2), a.as_u64vec2());
+
+    let b = UVec2::splat(u32::MAX);
+    assert_eq!(-1, b.as_i16vec2().x());
+    assert_eq!(u16::MAX, b.as_u16vec2().x());
+}
+
+#[test]
+fn test_uvec2_dot_wide() {
+    let a = UVec2::splat(u32::MAX / 4);
+    assert_eq!(
+        2 * (u64::from(u32::MAX / 4) * u64::from(u32::MAX / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        2 * (u64::from(u32::MAX / 4) * u64::from(u32::MAX / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (u32::MAX / 4).wrapping_mul(u32::MAX / 4).wrapping_mul(2),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (u32::MAX / 4).wrapping_mul(u32::MAX / 4).wrapping_mul(2),
+        a.wrapping_dot(a)
+    );
+    let b = UVec2::new(1, 2);
+    assert_eq!(5, b.length_squared());
+    assert_eq!(5, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_uvec2_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let hi = u64::from(u32::MAX / 2);
+    let a = UVec2::splat(u32::MAX / 2);
+    assert_eq!(2 * hi * hi, a.dot_wide(a));
+    assert_eq!(2 * hi * hi, a.length_squared());
+}
+
+#[test]
+fn test_uvec2_manhattan_chebyshev_extremes() {
+    let a = UVec2::zero();
+    let b = UVec2::splat(u32::MAX);
+    let d = u64::from(u32::MAX);
+    assert_eq!(2 * d, a.manhattan_distance(b));
+    assert_eq!(2 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(2 * d, b.manhattan_length());
+    assert_eq!(d, b.chebyshev_length());
+}
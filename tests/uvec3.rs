@@ -0,0 +1,87 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec3_int_tests!(u32, uvec3, UVec3, uvec2, uvec4);
+
+#[test]
+fn test_uvec3_conversions() {
+    let a = uvec3(1, 2, 3);
+    assert_eq!(vec3(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(dvec3(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(dvec3(1.0, 2.0, 3.0), DVec3::from(a));
+    assert_eq!(ivec3(1, 2, 3), a.as_ivec3());
+    assert_eq!(a, ivec3(1, 2, 3).as_uvec3());
+    assert_eq!(uvec3(0, 2, 3), vec3(-1.5, 2.5, 3.5).as_uvec3());
+    assert_eq!(uvec3(0, 2, 3), dvec3(-1.5, 2.5, 3.5).as_uvec3());
+
+    assert_eq!(Ok(a), UVec3::try_from(ivec3(1, 2, 3)));
+    assert!(UVec3::try_from(ivec3(-1, 2, 3)).is_err());
+    assert_eq!(Ok(ivec3(1, 2, 3)), IVec3::try_from(a));
+    assert!(IVec3::try_from(uvec3(u32::MAX, 2, 3)).is_err());
+}
+
+#[test]
+fn test_uvec3_as() {
+    let a = UVec3::new(1, 2, 3);
+    assert_eq!(Vec3::new(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), a.as_vec3a());
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(I16Vec3::new(1, 2, 3), a.as_i16vec3());
+    assert_eq!(U16Vec3::new(1, 2, 3), a.as_u16vec3());
+    assert_eq!(IVec3::new(1, 2, 3), a.as_ivec3());
+    assert_eq!(I64Vec3::new(1, 2, 3), a.as_i64vec3());
+    assert_eq!(U64Vec3::new(1, 2, 3), a.as_u64vec3());
+
+    let b = UVec3::splat(u32::MAX);
+    assert_eq!(-1, b.as_i16vec3().x());
+    assert_eq!(u16::MAX, b.as_u16vec3().x());
+}
+
+#[test]
+fn test_uvec3_dot_wide() {
+    let a = UVec3::splat(u32::MAX / 4);
+    assert_eq!(
+        3 * (u64::from(u32::MAX / 4) * u64::from(u32::MAX / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        3 * (u64::from(u32::MAX / 4) * u64::from(u32::MAX / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (u32::MAX / 4).wrapping_mul(u32::MAX / 4).wrapping_mul(3),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (u32::MAX / 4).wrapping_mul(u32::MAX / 4).wrapping_mul(3),
+        a.wrapping_dot(a)
+    );
+    let b = UVec3::new(1, 2, 3);
+    assert_eq!(14, b.length_squared());
+    assert_eq!(14, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_uvec3_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let hi = u64::from(u32::MAX / 2);
+    let a = UVec3::splat(u32::MAX / 2);
+    assert_eq!(3 * hi * hi, a.dot_wide(a));
+    assert_eq!(3 * hi * hi, a.length_squared());
+}
+
+#[test]
+fn test_uvec3_manhattan_chebyshev_extremes() {
+    let a = UVec3::zero();
+    let b = UVec3::splat(u32::MAX);
+    let d = u64::from(u32::MAX);
+    assert_eq!(3 * d, a.manhattan_distance(b));
+    assert_eq!(3 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(3 * d, b.manhattan_length());
+    assert_eq!(d, b.chebyshev_length());
+}
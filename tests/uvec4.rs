@@ -0,0 +1,86 @@
+mod support;
+
+use glam::*;
+use std::convert::TryFrom;
+
+impl_vec4_int_tests!(u32, uvec4, UVec4, uvec3);
+
+#[test]
+fn test_uvec4_conversions() {
+    let a = uvec4(1, 2, 3, 4);
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(dvec4(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(dvec4(1.0, 2.0, 3.0, 4.0), DVec4::from(a));
+    assert_eq!(ivec4(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(a, ivec4(1, 2, 3, 4).as_uvec4());
+    assert_eq!(uvec4(0, 2, 3, 4), vec4(-1.5, 2.5, 3.5, 4.5).as_uvec4());
+    assert_eq!(uvec4(0, 2, 3, 4), dvec4(-1.5, 2.5, 3.5, 4.5).as_uvec4());
+
+    assert_eq!(Ok(a), UVec4::try_from(ivec4(1, 2, 3, 4)));
+    assert!(UVec4::try_from(ivec4(-1, 2, 3, 4)).is_err());
+    assert_eq!(Ok(ivec4(1, 2, 3, 4)), IVec4::try_from(a));
+    assert!(IVec4::try_from(uvec4(u32::MAX, 2, 3, 4)).is_err());
+}
+
+#[test]
+fn test_uvec4_as() {
+    let a = UVec4::new(1, 2, 3, 4);
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(I16Vec4::new(1, 2, 3, 4), a.as_i16vec4());
+    assert_eq!(U16Vec4::new(1, 2, 3, 4), a.as_u16vec4());
+    assert_eq!(IVec4::new(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(I64Vec4::new(1, 2, 3, 4), a.as_i64vec4());
+    assert_eq!(U64Vec4::new(1, 2, 3, 4), a.as_u64vec4());
+
+    let b = UVec4::splat(u32::MAX);
+    assert_eq!(-1, b.as_i16vec4().x());
+    assert_eq!(u16::MAX, b.as_u16vec4().x());
+}
+
+#[test]
+fn test_uvec4_dot_wide() {
+    let a = UVec4::splat(u32::MAX / 4);
+    assert_eq!(
+        4 * (u64::from(u32::MAX / 4) * u64::from(u32::MAX / 4)),
+        a.length_squared()
+    );
+    assert_eq!(
+        4 * (u64::from(u32::MAX / 4) * u64::from(u32::MAX / 4)),
+        a.dot_wide(a)
+    );
+    assert_eq!(
+        (u32::MAX / 4).wrapping_mul(u32::MAX / 4).wrapping_mul(4),
+        a.wrapping_length_squared()
+    );
+    assert_eq!(
+        (u32::MAX / 4).wrapping_mul(u32::MAX / 4).wrapping_mul(4),
+        a.wrapping_dot(a)
+    );
+    let b = UVec4::new(1, 2, 3, 4);
+    assert_eq!(30, b.length_squared());
+    assert_eq!(30, b.wrapping_length_squared());
+    assert_eq!(b.dot(b), b.wrapping_dot(b));
+}
+
+#[test]
+fn test_uvec4_dot_wide_bound() {
+    // elements within half the range of the element type never overflow
+    let hi = u64::from(u32::MAX / 2);
+    let a = UVec4::splat(u32::MAX / 2);
+    assert_eq!(4 * hi * hi, a.dot_wide(a));
+    assert_eq!(4 * hi * hi, a.length_squared());
+}
+
+#[test]
+fn test_uvec4_manhattan_chebyshev_extremes() {
+    let a = UVec4::zero();
+    let b = UVec4::splat(u32::MAX);
+    let d = u64::from(u32::MAX);
+    assert_eq!(4 * d, a.manhattan_distance(b));
+    assert_eq!(4 * d, b.manhattan_distance(a));
+    assert_eq!(d, a.chebyshev_distance(b));
+    assert_eq!(d, b.chebyshev_distance(a));
+    assert_eq!(4 * d, b.manhattan_length());
+    assert_eq!(d, b.chebyshev_length());
+}
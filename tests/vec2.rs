@@ -9,8 +9,8 @@ fn test_vec2_align() {
     use core::mem;
     assert_eq!(8, mem::size_of::<Vec2>());
     assert_eq!(4, mem::align_of::<Vec2>());
-    assert_eq!(8, mem::size_of::<Vec2Mask>());
-    assert_eq!(4, mem::align_of::<Vec2Mask>());
+    assert_eq!(8, mem::size_of::<BVec2>());
+    assert_eq!(4, mem::align_of::<BVec2>());
 }
 
 #[test]
@@ -44,6 +44,38 @@ fn test_vec2_fmt() {
     assert_eq!(format!("{}", a), "[1, 2]");
 }
 
+#[test]
+fn test_vec2_try_slice() {
+    let v = vec2(1.0, 2.0);
+    let mut a = [0.0; 3];
+    v.write_to_slice(&mut a[1..]);
+    assert_eq!(v, Vec2::from_slice(&a[1..]));
+    assert_eq!(Ok(v), Vec2::try_from_slice(&a[1..]));
+    let err = Vec2::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(2, err.required_len());
+    let mut b = [0.0; 1];
+    assert_eq!(Err(err), v.try_write_to_slice(&mut b));
+    assert!(v.try_write_to_slice(&mut a).is_ok());
+    assert_eq!(v, Vec2::from_slice(&a));
+}
+
+#[test]
+fn test_vec2_index() {
+    let mut v = vec2(1.0, 2.0);
+    assert_eq!(1.0, v[0]);
+    assert_eq!(2.0, v[1]);
+    v[1] = 5.0;
+    assert_eq!(5.0, v.y());
+    v[0] += 2.0;
+    assert_eq!(vec2(3.0, 5.0), v);
+}
+
+#[test]
+#[should_panic]
+fn test_vec2_index_out_of_bounds() {
+    let _ = Vec2::zero()[2];
+}
+
 #[test]
 fn test_vec2_zero() {
     let v = Vec2::zero();
@@ -92,6 +124,25 @@ fn test_vec2_funcs() {
     );
 }
 
+#[test]
+fn test_vec2_outer() {
+    let a = Vec2::new(1.0, 2.0);
+    let b = Vec2::new(4.0, 5.0);
+    let m = a.outer(b);
+    assert_eq!(Mat2::from_cols(a * 4.0, a * 5.0), m);
+    // (a * b^T) * c = a * (b . c)
+    let c = Vec2::new(-1.0, 2.0);
+    assert_eq!(a * b.dot(c), m * c);
+}
+
+#[test]
+fn test_vec2_mul_add() {
+    assert_eq!(
+        vec2(1.0, 2.0).mul_add(vec2(5.0, 6.0), vec2(-1.0, 0.5)),
+        vec2(4.0, 12.5)
+    );
+}
+
 #[test]
 fn test_vec2_ops() {
     let a = vec2(1.0, 2.0);
@@ -122,6 +173,193 @@ fn test_vec2_assign_ops() {
     assert_eq!(vec2(1.0, 2.0), b);
 }
 
+#[test]
+fn test_vec2_angle() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    assert!(Vec2::from_angle(0.0).abs_diff_eq(Vec2::unit_x(), 1e-6));
+    assert!(Vec2::from_angle(FRAC_PI_2).abs_diff_eq(Vec2::unit_y(), 1e-6));
+    assert!(Vec2::from_angle(PI).abs_diff_eq(-Vec2::unit_x(), 1e-6));
+    assert!((Vec2::from_angle(-2.5).to_angle() - -2.5).abs() < 1e-6);
+    assert!((Vec2::new(-3.0, -3.0).to_angle() - -0.75 * PI).abs() < 1e-6);
+    assert_eq!(0.0, Vec2::unit_x().to_angle());
+
+    let v = Vec2::new(2.0, 1.0);
+    let r = Vec2::from_angle(FRAC_PI_2);
+    assert!(r.rotate(v).abs_diff_eq(Vec2::new(-1.0, 2.0), 1e-6));
+    assert!(Vec2::from_angle(0.5)
+        .rotate(Vec2::from_angle(1.0))
+        .abs_diff_eq(Vec2::from_angle(1.5), 1e-6));
+    assert!((Vec2::from_angle(0.5).rotate(v).to_angle() - (v.to_angle() + 0.5)).abs() < 1e-6);
+    assert_eq!(v * 2.0, Vec2::new(2.0, 0.0).rotate(v));
+}
+
+#[test]
+fn test_vec2_angle_between() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    let x = Vec2::unit_x();
+    let y = Vec2::unit_y();
+    assert!((x.angle_between(y) - FRAC_PI_2).abs() < 1e-6);
+    assert!((y.angle_between(x) + FRAC_PI_2).abs() < 1e-6);
+    assert!((x.angle_between(-x).abs() - PI).abs() < 1e-6);
+    assert_eq!(0.0, x.angle_between(x * 3.0));
+    assert!(
+        (Vec2::from_angle(0.25).angle_between(Vec2::from_angle(2.0) * 5.0) - 1.75).abs() < 1e-6
+    );
+    // nearly parallel vectors keep their precision
+    assert!((Vec2::from_angle(1.0e-4).angle_between(x) + 1.0e-4).abs() < 1e-10);
+}
+
+#[test]
+fn test_vec2_reflect_refract() {
+    let n = Vec2::unit_y();
+    assert_eq!(vec2(1.0, 2.0), vec2(1.0, -2.0).reflect(n));
+    assert_eq!(vec2(1.0, 2.0), vec2(1.0, -2.0).reflect(-n));
+    let i = vec2(1.0, -1.0).normalize();
+    assert!(i.refract(n, 1.0).abs_diff_eq(i, 1e-6));
+    let r = i.refract(n, 1.0 / 1.5);
+    assert!(r.is_normalized());
+    assert!((i.x() / 1.5 - r.x()).abs() < 1e-6);
+    assert_eq!(Vec2::zero(), i.refract(n, 1.5));
+}
+
+#[test]
+fn test_vec2_sum() {
+    let a = vec2(1.0, 2.0);
+    let vs = [a, a, a];
+    assert_eq!(vec2(3.0, 6.0), vs.iter().sum());
+    assert_eq!(vec2(3.0, 6.0), vs.iter().copied().sum());
+    assert_eq!(Vec2::zero(), std::iter::empty::<Vec2>().sum());
+}
+
+#[test]
+fn test_vec2_clamp_length() {
+    let v = vec2(3.0, 4.0);
+    assert_eq!(v, v.clamp_length(1.0, 10.0));
+    assert_eq!(v * 0.4, v.clamp_length(0.0, 2.0));
+    assert_eq!(v * 2.0, v.clamp_length(10.0, 20.0));
+    assert_eq!(v * 0.4, v.clamp_length_max(2.0));
+    assert_eq!(v, v.clamp_length_max(10.0));
+    assert_eq!(v * 2.0, v.clamp_length_min(10.0));
+    assert_eq!(v, v.clamp_length_min(2.0));
+    assert_eq!(Vec2::zero(), v.clamp_length_max(0.0));
+    // a zero vector has no direction to scale along
+    assert_eq!(Vec2::zero(), Vec2::zero().clamp_length(1.0, 2.0));
+    assert_eq!(Vec2::zero(), Vec2::zero().clamp_length_min(1.0));
+}
+
+#[test]
+fn test_vec2_is_normalized() {
+    let v = vec2(3.0, 4.0).normalize();
+    assert!(v.is_normalized());
+    assert!((v * (1.0 + 1.0e-5)).is_normalized());
+    assert!(!(v * (1.0 + 1.0e-3)).is_normalized());
+    assert!(!(v * (1.0 - 1.0e-3)).is_normalized());
+    assert!(!Vec2::zero().is_normalized());
+}
+
+#[test]
+fn test_vec2_nan_finite() {
+    let a = vec2(1.0, f32::NAN);
+    assert!(a.is_nan());
+    assert!(!a.is_finite());
+    assert_eq!(BVec2::new(false, true), a.is_nan_mask());
+    assert_eq!(BVec2::new(true, false), a.is_finite_mask());
+    assert!(!Vec2::one().is_nan());
+    assert!(Vec2::one().is_finite());
+    assert!(!Vec2::splat(f32::NEG_INFINITY).is_finite_mask().any());
+}
+
+#[test]
+fn test_vec2_lerp() {
+    let a = vec2(-1.0, 0.0);
+    let b = vec2(3.0, 4.0);
+    assert_eq!(a, a.lerp(b, 0.0));
+    assert_eq!(b, a.lerp(b, 1.0));
+    assert_eq!(vec2(1.0, 2.0), a.lerp(b, 0.5));
+    // the interpolation value is not clamped
+    assert_eq!(vec2(7.0, 8.0), a.lerp(b, 2.0));
+    assert_eq!(vec2(-5.0, -4.0), a.lerp(b, -1.0));
+}
+
+#[test]
+fn test_vec2_distance() {
+    let a = vec2(1.0, 2.0);
+    let b = vec2(4.0, 6.0);
+    assert_eq!(5.0, a.distance(b));
+    assert_eq!(5.0, b.distance(a));
+    assert_eq!(25.0, a.distance_squared(b));
+    assert_eq!((a - b).length(), a.distance(b));
+    assert_eq!(0.0, a.distance(a));
+}
+
+#[test]
+fn test_vec2_midpoint() {
+    let a = vec2(-1.0, 0.0);
+    let b = vec2(3.0, 5.0);
+    assert_eq!(vec2(1.0, 2.5), a.midpoint(b));
+    assert_eq!(a.lerp(b, 0.5), a.midpoint(b));
+}
+
+#[test]
+fn test_vec2_rounding() {
+    let a = vec2(-1.5, 2.5);
+    assert_eq!(vec2(-2.0, 2.0), a.floor());
+    assert_eq!(vec2(-1.0, 3.0), a.ceil());
+    assert_eq!(vec2(-2.0, 3.0), a.round());
+    assert_eq!(vec2(-1.0, 2.0), a.trunc());
+    assert_eq!(vec2(0.5, 0.5), a.fract());
+    assert_eq!(a, a.floor() + a.fract());
+}
+
+#[test]
+fn test_vec2_exp_pow() {
+    let a = vec2(4.0, 0.25);
+    assert_eq!(vec2(2.0, 0.5), a.sqrt());
+    assert_eq!(vec2(2.0, -2.0), a.log2());
+    assert!(a.log2().exp2().abs_diff_eq(a, 1e-6));
+    assert_eq!(vec2(0.25, 4.0), a.recip());
+    assert_eq!(a.reciprocal(), a.recip());
+    assert!(a.powf(0.5).abs_diff_eq(a.sqrt(), 1e-6));
+    assert!(a.powf(-1.0).abs_diff_eq(a.recip(), 1e-6));
+    assert!(Vec2::zero().exp().abs_diff_eq(Vec2::one(), 1e-6));
+    assert!(Vec2::one()
+        .exp()
+        .abs_diff_eq(Vec2::splat(std::f32::consts::E), 1e-6));
+}
+
+#[test]
+fn test_vec2_to_radians_degrees() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    let a = vec2(90.0, -180.0);
+    let b = vec2(FRAC_PI_2, -PI);
+    assert!(a.to_radians().abs_diff_eq(b, 1e-6));
+    assert!(b.to_degrees().abs_diff_eq(a, 1e-4));
+    assert_eq!(a.x().to_radians(), a.to_radians().x());
+    assert_eq!(b.y().to_degrees(), b.to_degrees().y());
+}
+
+#[test]
+fn test_vec2_move_towards() {
+    let a = vec2(1.0, 1.0);
+    let b = vec2(4.0, 5.0);
+    assert!(a.move_towards(b, 1.0).abs_diff_eq(vec2(1.6, 1.8), 1e-6));
+    assert!(a.move_towards(b, 1.0).abs_diff_eq(a.lerp(b, 0.2), 1e-6));
+    // does not overshoot
+    assert_eq!(b, a.move_towards(b, 5.0));
+    assert_eq!(b, a.move_towards(b, 100.0));
+    assert_eq!(a, a.move_towards(a, 1.0));
+    // a negative distance moves away
+    assert!(a.move_towards(b, -5.0).abs_diff_eq(a.lerp(b, -1.0), 1e-6));
+    // even when very close to the target
+    let c = a + vec2(1e-5, 0.0);
+    assert!(a
+        .move_towards(c, -1.0)
+        .abs_diff_eq(a - vec2(1.0, 0.0), 1e-6));
+    // a zero distance does not move or snap to the target
+    assert_eq!(a, a.move_towards(b, 0.0));
+    assert_eq!(a, a.move_towards(c, 0.0));
+}
+
 #[test]
 fn test_vec2_min_max() {
     let a = vec2(-1.0, 2.0);
@@ -132,6 +370,18 @@ fn test_vec2_min_max() {
     assert_eq!(vec2(1.0, 2.0), b.max(a));
 }
 
+#[test]
+fn test_vec2_clamp() {
+    let min = vec2(1.0, 1.0);
+    let max = vec2(3.0, 3.0);
+    let a = vec2(0.0, 2.0);
+    assert_eq!(vec2(1.0, 2.0), a.clamp(min, max));
+    assert_eq!(vec2(1.0, 2.0), a.clamp_scalar(1.0, 3.0));
+    assert_eq!(vec2(0.0, 2.0), a.min_scalar(2.0));
+    assert_eq!(vec2(0.5, 2.0), a.max_scalar(0.5));
+    assert_eq!(a.min(Vec2::splat(2.0)), a.min_scalar(2.0));
+}
+
 #[test]
 fn test_vec2_hmin_hmax() {
     let a = vec2(-1.0, 2.0);
@@ -152,9 +402,9 @@ fn test_vec2_eq() {
 
 #[test]
 fn test_vec2_cmp() {
-    assert!(!Vec2Mask::default().any());
-    assert!(!Vec2Mask::default().all());
-    assert_eq!(Vec2Mask::default().bitmask(), 0x0);
+    assert!(!BVec2::default().any());
+    assert!(!BVec2::default().all());
+    assert_eq!(BVec2::default().bitmask(), 0x0);
     let a = vec2(-1.0, -1.0);
     let b = vec2(1.0, 1.0);
     let c = vec2(-1.0, -1.0);
@@ -195,95 +445,92 @@ fn test_vec2b() {
 
 #[test]
 fn test_vec2mask_bitmask() {
-    assert_eq!(Vec2Mask::new(false, false).bitmask(), 0b00);
-    assert_eq!(Vec2Mask::new(true, false).bitmask(), 0b01);
-    assert_eq!(Vec2Mask::new(false, true).bitmask(), 0b10);
-    assert_eq!(Vec2Mask::new(true, true).bitmask(), 0b11);
+    assert_eq!(BVec2::new(false, false).bitmask(), 0b00);
+    assert_eq!(BVec2::new(true, false).bitmask(), 0b01);
+    assert_eq!(BVec2::new(false, true).bitmask(), 0b10);
+    assert_eq!(BVec2::new(true, true).bitmask(), 0b11);
 }
 
 #[test]
 fn test_vec2mask_any() {
-    assert_eq!(Vec2Mask::new(false, false).any(), false);
-    assert_eq!(Vec2Mask::new(true, false).any(), true);
-    assert_eq!(Vec2Mask::new(false, true).any(), true);
-    assert_eq!(Vec2Mask::new(true, true).any(), true);
+    assert!(!BVec2::new(false, false).any());
+    assert!(BVec2::new(true, false).any());
+    assert!(BVec2::new(false, true).any());
+    assert!(BVec2::new(true, true).any());
 }
 
 #[test]
 fn test_vec2mask_all() {
-    assert_eq!(Vec2Mask::new(false, false).all(), false);
-    assert_eq!(Vec2Mask::new(true, false).all(), false);
-    assert_eq!(Vec2Mask::new(false, true).all(), false);
-    assert_eq!(Vec2Mask::new(true, true).all(), true);
+    assert!(!BVec2::new(false, false).all());
+    assert!(!BVec2::new(true, false).all());
+    assert!(!BVec2::new(false, true).all());
+    assert!(BVec2::new(true, true).all());
 }
 
 #[test]
 fn test_vec2mask_select() {
     let a = Vec2::new(1.0, 2.0);
     let b = Vec2::new(3.0, 4.0);
-    assert_eq!(Vec2Mask::new(true, true).select(a, b), Vec2::new(1.0, 2.0),);
-    assert_eq!(Vec2Mask::new(true, false).select(a, b), Vec2::new(1.0, 4.0),);
-    assert_eq!(Vec2Mask::new(false, true).select(a, b), Vec2::new(3.0, 2.0),);
-    assert_eq!(
-        Vec2Mask::new(false, false).select(a, b),
-        Vec2::new(3.0, 4.0),
-    );
+    assert_eq!(BVec2::new(true, true).select(a, b), Vec2::new(1.0, 2.0),);
+    assert_eq!(BVec2::new(true, false).select(a, b), Vec2::new(1.0, 4.0),);
+    assert_eq!(BVec2::new(false, true).select(a, b), Vec2::new(3.0, 2.0),);
+    assert_eq!(BVec2::new(false, false).select(a, b), Vec2::new(3.0, 4.0),);
 }
 
 #[test]
 fn test_vec2mask_and() {
     assert_eq!(
-        (Vec2Mask::new(false, false) & Vec2Mask::new(false, false)).bitmask(),
+        (BVec2::new(false, false) & BVec2::new(false, false)).bitmask(),
         0b00,
     );
     assert_eq!(
-        (Vec2Mask::new(true, true) & Vec2Mask::new(true, false)).bitmask(),
+        (BVec2::new(true, true) & BVec2::new(true, false)).bitmask(),
         0b01,
     );
     assert_eq!(
-        (Vec2Mask::new(true, false) & Vec2Mask::new(false, true)).bitmask(),
+        (BVec2::new(true, false) & BVec2::new(false, true)).bitmask(),
         0b00,
     );
     assert_eq!(
-        (Vec2Mask::new(true, true) & Vec2Mask::new(true, true)).bitmask(),
+        (BVec2::new(true, true) & BVec2::new(true, true)).bitmask(),
         0b11,
     );
 
-    let mut mask = Vec2Mask::new(true, true);
-    mask &= Vec2Mask::new(true, false);
+    let mut mask = BVec2::new(true, true);
+    mask &= BVec2::new(true, false);
     assert_eq!(mask.bitmask(), 0b01);
 }
 
 #[test]
 fn test_vec2mask_or() {
     assert_eq!(
-        (Vec2Mask::new(false, false) | Vec2Mask::new(false, false)).bitmask(),
+        (BVec2::new(false, false) | BVec2::new(false, false)).bitmask(),
         0b00,
     );
     assert_eq!(
-        (Vec2Mask::new(false, false) | Vec2Mask::new(false, true)).bitmask(),
+        (BVec2::new(false, false) | BVec2::new(false, true)).bitmask(),
         0b10,
     );
     assert_eq!(
-        (Vec2Mask::new(true, false) | Vec2Mask::new(false, true)).bitmask(),
+        (BVec2::new(true, false) | BVec2::new(false, true)).bitmask(),
         0b11,
     );
     assert_eq!(
-        (Vec2Mask::new(true, true) | Vec2Mask::new(true, true)).bitmask(),
+        (BVec2::new(true, true) | BVec2::new(true, true)).bitmask(),
         0b11,
     );
 
-    let mut mask = Vec2Mask::new(true, true);
-    mask |= Vec2Mask::new(true, false);
+    let mut mask = BVec2::new(true, true);
+    mask |= BVec2::new(true, false);
     assert_eq!(mask.bitmask(), 0b11);
 }
 
 #[test]
 fn test_vec2mask_not() {
-    assert_eq!((!Vec2Mask::new(false, false)).bitmask(), 0b11);
-    assert_eq!((!Vec2Mask::new(true, false)).bitmask(), 0b10);
-    assert_eq!((!Vec2Mask::new(false, true)).bitmask(), 0b01);
-    assert_eq!((!Vec2Mask::new(true, true)).bitmask(), 0b00);
+    assert_eq!((!BVec2::new(false, false)).bitmask(), 0b11);
+    assert_eq!((!BVec2::new(true, false)).bitmask(), 0b10);
+    assert_eq!((!BVec2::new(false, true)).bitmask(), 0b01);
+    assert_eq!((!BVec2::new(true, true)).bitmask(), 0b00);
 }
 
 #[test]
@@ -292,7 +539,23 @@ fn test_vec2_sign() {
     assert_eq!(-Vec2::zero().sign(), -Vec2::one());
     assert_eq!(Vec2::one().sign(), Vec2::one());
     assert_eq!((-Vec2::one()).sign(), -Vec2::one());
-    assert_eq!(Vec2::splat(core::f32::NEG_INFINITY).sign(), -Vec2::one());
+    assert_eq!(Vec2::splat(f32::NEG_INFINITY).sign(), -Vec2::one());
+}
+
+#[test]
+fn test_vec2_signum_copysign() {
+    assert_eq!(Vec2::zero().signum(), Vec2::one());
+    assert_eq!((-Vec2::zero()).signum(), -Vec2::one());
+    assert_eq!((-Vec2::zero()).sign(), Vec2::one());
+    assert_eq!(Vec2::splat(f32::NEG_INFINITY).signum(), -Vec2::one());
+    assert!(Vec2::splat(f32::NAN).signum().x().is_nan());
+    let a = vec2(1.0, -2.0);
+    assert_eq!(vec2(1.0, -1.0), a.signum());
+    assert_eq!(a.abs(), a.copysign(Vec2::one()));
+    assert_eq!(-a.abs(), a.copysign(-Vec2::one()));
+    assert_eq!(a, a.abs().copysign(a));
+    assert_eq!(-a, a.copysign(-a));
+    assert_eq!(-Vec2::one(), Vec2::one().copysign(-Vec2::zero()));
 }
 
 #[cfg(feature = "rand")]
@@ -327,3 +590,74 @@ fn test_vec2_serde() {
     let deserialized = serde_json::from_str::<Vec2>("[1.0,2.0,3.0]");
     assert!(deserialized.is_err());
 }
+
+#[test]
+fn test_vec2_as() {
+    let a = Vec2::new(1.0, 2.0);
+    assert_eq!(DVec2::new(1.0, 2.0), a.as_dvec2());
+    assert_eq!(I16Vec2::new(1, 2), a.as_i16vec2());
+    assert_eq!(U16Vec2::new(1, 2), a.as_u16vec2());
+    assert_eq!(IVec2::new(1, 2), a.as_ivec2());
+    assert_eq!(UVec2::new(1, 2), a.as_uvec2());
+    assert_eq!(I64Vec2::new(1, 2), a.as_i64vec2());
+    assert_eq!(U64Vec2::new(1, 2), a.as_u64vec2());
+
+    let b = Vec2::new(-1.5, 2.5);
+    assert_eq!(IVec2::new(-1, 2), b.as_ivec2());
+    assert_eq!(UVec2::new(0, 2), b.as_uvec2());
+    assert_eq!(i32::MAX, Vec2::new(1.0e10, 1.0).as_ivec2().x());
+}
+
+#[test]
+fn test_vec2_try_as() {
+    let a = Vec2::new(1.9, 2.0);
+    assert_eq!(Some(IVec2::new(1, 2)), a.try_as_ivec2());
+    assert_eq!(Some(UVec2::new(1, 2)), a.try_as_uvec2());
+    assert_eq!(Some(U64Vec2::new(1, 2)), a.try_as_u64vec2());
+    // negative elements that truncate to zero are in range
+    assert_eq!(Some(UVec2::new(0, 2)), Vec2::new(-0.5, 2.0).try_as_uvec2());
+    assert_eq!(None, Vec2::new(-1.0, 2.0).try_as_uvec2());
+    assert_eq!(None, Vec2::new(1.9, f32::NAN).try_as_ivec2());
+    assert_eq!(None, Vec2::splat(f32::INFINITY).try_as_i64vec2());
+    assert_eq!(None, Vec2::splat(f32::NEG_INFINITY).try_as_i64vec2());
+    assert_eq!(
+        Some(U16Vec2::splat(u16::MAX)),
+        Vec2::splat(65535.5).try_as_u16vec2()
+    );
+    assert_eq!(None, Vec2::splat(65536.0).try_as_u16vec2());
+    assert_eq!(
+        Some(I16Vec2::splat(i16::MIN)),
+        Vec2::splat(-32768.5).try_as_i16vec2()
+    );
+    assert_eq!(None, Vec2::splat(-32769.0).try_as_i16vec2());
+    assert_eq!(
+        Some(IVec2::splat(i32::MIN)),
+        Vec2::splat(-2147483648.0).try_as_ivec2()
+    );
+    assert_eq!(None, Vec2::splat(2147483648.0).try_as_ivec2());
+    assert_eq!(None, Vec2::splat(4294967296.0).try_as_uvec2());
+}
+
+#[test]
+fn test_vec2_euclid() {
+    let a = Vec2::new(-7.0, 7.0);
+    let b = Vec2::new(4.0, -4.0);
+    assert_eq!(Vec2::new(-2.0, -1.0), a.div_euclid(b));
+    assert_eq!(Vec2::new(1.0, 3.0), a.rem_euclid(b));
+}
+
+#[test]
+fn test_vec2_trig() {
+    let a = vec2(0.5, -1.25);
+    let b = vec2(-2.0, 0.75);
+    let (sin, cos) = a.sin_cos();
+    let (sin, cos, tan): ([f32; 2], [f32; 2], [f32; 2]) = (sin.into(), cos.into(), a.tan().into());
+    let atan2: [f32; 2] = a.atan2(b).into();
+    let (a, b): ([f32; 2], [f32; 2]) = (a.into(), b.into());
+    for i in 0..2 {
+        assert!((sin[i] - a[i].sin()).abs() < 1e-6);
+        assert!((cos[i] - a[i].cos()).abs() < 1e-6);
+        assert!((tan[i] - a[i].tan()).abs() < 1e-5);
+        assert!((atan2[i] - a[i].atan2(b[i])).abs() < 1e-6);
+    }
+}
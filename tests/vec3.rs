@@ -9,16 +9,14 @@ use rand_xoshiro::Xoshiro256Plus;
 #[test]
 fn test_vec3_align() {
     use std::mem;
+    assert_eq!(12, mem::size_of::<Vec3>());
+    assert_eq!(4, mem::align_of::<Vec3>());
     if cfg!(feature = "scalar-math") {
-        assert_eq!(12, mem::size_of::<Vec3>());
-        assert_eq!(4, mem::align_of::<Vec3>());
-        assert_eq!(12, mem::size_of::<Vec3Mask>());
-        assert_eq!(4, mem::align_of::<Vec3Mask>());
+        assert_eq!(12, mem::size_of::<BVec3>());
+        assert_eq!(4, mem::align_of::<BVec3>());
     } else {
-        assert_eq!(16, mem::size_of::<Vec3>());
-        assert_eq!(16, mem::align_of::<Vec3>());
-        assert_eq!(16, mem::size_of::<Vec3Mask>());
-        assert_eq!(16, mem::align_of::<Vec3Mask>());
+        assert_eq!(16, mem::size_of::<BVec3>());
+        assert_eq!(16, mem::align_of::<BVec3>());
     }
 }
 
@@ -50,14 +48,44 @@ fn test_vec3_new() {
 #[test]
 fn test_vec3_fmt() {
     let a = Vec3::new(1.0, 2.0, 3.0);
-    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
-    assert_eq!(format!("{:?}", a), "Vec3(__m128(1.0, 2.0, 3.0, 3.0))");
-    #[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
     assert_eq!(format!("{:?}", a), "Vec3(1.0, 2.0, 3.0)");
     // assert_eq!(format!("{:#?}", a), "Vec3(\n    1.0,\n    2.0,\n    3.0\n)");
     assert_eq!(format!("{}", a), "[1, 2, 3]");
 }
 
+#[test]
+fn test_vec3_try_slice() {
+    let v = vec3(1.0, 2.0, 3.0);
+    let mut a = [0.0; 4];
+    v.write_to_slice(&mut a[1..]);
+    assert_eq!(v, Vec3::from_slice(&a[1..]));
+    assert_eq!(Ok(v), Vec3::try_from_slice(&a[1..]));
+    let err = Vec3::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(3, err.required_len());
+    let mut b = [0.0; 2];
+    assert_eq!(Err(err), v.try_write_to_slice(&mut b));
+    assert!(v.try_write_to_slice(&mut a).is_ok());
+    assert_eq!(v, Vec3::from_slice(&a));
+}
+
+#[test]
+fn test_vec3_index() {
+    let mut v = vec3(1.0, 2.0, 3.0);
+    assert_eq!(1.0, v[0]);
+    assert_eq!(2.0, v[1]);
+    assert_eq!(3.0, v[2]);
+    v[2] = 5.0;
+    assert_eq!(5.0, v.z());
+    v[0] += 2.0;
+    assert_eq!(vec3(3.0, 2.0, 5.0), v);
+}
+
+#[test]
+#[should_panic]
+fn test_vec3_index_out_of_bounds() {
+    let _ = Vec3::zero()[3];
+}
+
 #[test]
 fn test_vec3_zero() {
     let v = Vec3::zero();
@@ -103,6 +131,10 @@ fn test_vec3_funcs() {
         1.0 * 4.0 + 2.0 * 5.0 + 3.0 * 6.0,
         vec3(1.0, 2.0, 3.0).dot(vec3(4.0, 5.0, 6.0))
     );
+    assert_eq!(
+        Vec3::splat(1.0 * 4.0 + 2.0 * 5.0 + 3.0 * 6.0),
+        vec3(1.0, 2.0, 3.0).dot_into_vec(vec3(4.0, 5.0, 6.0))
+    );
     assert_eq!(
         2.0 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0,
         vec3(2.0, 3.0, 4.0).length_squared()
@@ -122,6 +154,50 @@ fn test_vec3_funcs() {
     );
 }
 
+#[test]
+fn test_vec3_length_recip() {
+    let a = vec3(2.0, -3.0, 6.0);
+    assert_approx_eq!(a.length_reciprocal(), a.length_recip(), 1e-6);
+    assert_approx_eq!(1.0, (a * a.length_recip()).length(), 1e-6);
+    assert_approx_eq!(a / a.length(), a.normalize(), 1e-6);
+    assert!(a.normalize().is_normalized());
+    let b = Vec3::splat(1e-3);
+    assert_approx_eq!(
+        b.length_reciprocal(),
+        b.length_recip(),
+        1e-6 * b.length_reciprocal()
+    );
+}
+
+#[test]
+fn test_vec3_normalize_extremes() {
+    // the squared length of a tiny vector is denormal
+    assert_approx_eq!(Vec3::unit_x(), (Vec3::unit_x() * 1e-19).normalize(), 1e-6);
+    // and the squared length of a huge vector overflows
+    let huge = (Vec3::unit_x() * 1e20).normalize();
+    assert!(!huge.is_nan());
+    assert_eq!(Vec3::zero(), huge);
+}
+
+#[test]
+fn test_vec3_outer() {
+    let a = Vec3::new(1.0, 2.0, 3.0);
+    let b = Vec3::new(4.0, 5.0, 6.0);
+    let m = a.outer(b);
+    assert_eq!(Mat3::from_cols(a * 4.0, a * 5.0, a * 6.0), m);
+    // (a * b^T) * c = a * (b . c)
+    let c = Vec3::new(-1.0, 2.0, 0.5);
+    assert_eq!(a * b.dot(c), m * c);
+}
+
+#[test]
+fn test_vec3_mul_add() {
+    assert_eq!(
+        vec3(1.0, 2.0, 3.0).mul_add(vec3(5.0, 6.0, 7.0), vec3(-1.0, 0.5, 2.0)),
+        vec3(4.0, 12.5, 23.0)
+    );
+}
+
 #[test]
 fn test_vec3_ops() {
     let a = vec3(1.0, 2.0, 3.0);
@@ -152,6 +228,234 @@ fn test_vec3_assign_ops() {
     assert_eq!((1.0, 2.0, 3.0), b.into());
 }
 
+#[test]
+fn test_vec3_sum() {
+    let a = vec3(1.0, 2.0, 3.0);
+    let vs = [a, a, a];
+    assert_eq!(vec3(3.0, 6.0, 9.0), vs.iter().sum());
+    assert_eq!(vec3(3.0, 6.0, 9.0), vs.iter().copied().sum());
+    assert_eq!(Vec3::zero(), std::iter::empty::<Vec3>().sum());
+}
+
+#[test]
+fn test_vec3_clamp_length() {
+    let v = vec3(0.0, 3.0, 4.0);
+    assert_eq!(v, v.clamp_length(1.0, 10.0));
+    assert_eq!(v * 0.4, v.clamp_length(0.0, 2.0));
+    assert_eq!(v * 2.0, v.clamp_length(10.0, 20.0));
+    assert_eq!(v * 0.4, v.clamp_length_max(2.0));
+    assert_eq!(v, v.clamp_length_max(10.0));
+    assert_eq!(v * 2.0, v.clamp_length_min(10.0));
+    assert_eq!(v, v.clamp_length_min(2.0));
+    assert_eq!(Vec3::zero(), v.clamp_length_max(0.0));
+    // a zero vector has no direction to scale along
+    assert_eq!(Vec3::zero(), Vec3::zero().clamp_length(1.0, 2.0));
+    assert_eq!(Vec3::zero(), Vec3::zero().clamp_length_min(1.0));
+}
+
+#[test]
+fn test_vec3_is_normalized() {
+    let v = vec3(2.0, 3.0, 4.0).normalize();
+    assert!(v.is_normalized());
+    assert!((v * (1.0 + 1.0e-5)).is_normalized());
+    assert!(!(v * (1.0 + 1.0e-3)).is_normalized());
+    assert!(!(v * (1.0 - 1.0e-3)).is_normalized());
+    assert!(!Vec3::zero().is_normalized());
+}
+
+#[test]
+fn test_vec3_nan_finite() {
+    let a = vec3(1.0, f32::NAN, f32::INFINITY);
+    assert!(a.is_nan());
+    assert!(!a.is_finite());
+    assert_eq!(BVec3::new(false, true, false), a.is_nan_mask());
+    assert_eq!(BVec3::new(true, false, false), a.is_finite_mask());
+    assert!(!Vec3::one().is_nan());
+    assert!(Vec3::one().is_finite());
+    assert!(!Vec3::splat(f32::NEG_INFINITY).is_finite_mask().any());
+}
+
+#[test]
+fn test_vec3_lerp() {
+    let a = vec3(-1.0, 0.0, 2.0);
+    let b = vec3(3.0, 4.0, -2.0);
+    assert_eq!(a, a.lerp(b, 0.0));
+    assert_eq!(b, a.lerp(b, 1.0));
+    assert_eq!(vec3(1.0, 2.0, 0.0), a.lerp(b, 0.5));
+    // the interpolation value is not clamped
+    assert_eq!(vec3(7.0, 8.0, -6.0), a.lerp(b, 2.0));
+    assert_eq!(vec3(-5.0, -4.0, 6.0), a.lerp(b, -1.0));
+}
+
+#[test]
+fn test_vec3_distance() {
+    let a = vec3(1.0, 2.0, 3.0);
+    let b = vec3(3.0, 5.0, 9.0);
+    assert_eq!(7.0, a.distance(b));
+    assert_eq!(7.0, b.distance(a));
+    assert_eq!(49.0, a.distance_squared(b));
+    assert_eq!((a - b).length(), a.distance(b));
+    assert_eq!(0.0, a.distance(a));
+}
+
+#[test]
+fn test_vec3_midpoint() {
+    let a = vec3(-1.0, 0.0, 2.0);
+    let b = vec3(3.0, 5.0, 2.0);
+    assert_eq!(vec3(1.0, 2.5, 2.0), a.midpoint(b));
+    assert_eq!(a.lerp(b, 0.5), a.midpoint(b));
+}
+
+#[test]
+fn test_vec3_rounding() {
+    let a = vec3(-1.5, 2.5, -0.25);
+    assert_eq!(vec3(-2.0, 2.0, -1.0), a.floor());
+    assert_eq!(vec3(-1.0, 3.0, -0.0), a.ceil());
+    assert_eq!(vec3(-2.0, 3.0, -0.0), a.round());
+    assert_eq!(vec3(-1.0, 2.0, -0.0), a.trunc());
+    assert_eq!(vec3(0.5, 0.5, 0.75), a.fract());
+    assert_eq!(a, a.floor() + a.fract());
+}
+
+#[test]
+fn test_vec3_exp_pow() {
+    let a = vec3(4.0, 0.25, 16.0);
+    assert_eq!(vec3(2.0, 0.5, 4.0), a.sqrt());
+    assert_eq!(vec3(2.0, -2.0, 4.0), a.log2());
+    assert!(a.log2().exp2().abs_diff_eq(a, 1e-6));
+    assert_eq!(vec3(0.25, 4.0, 0.0625), a.recip());
+    assert_eq!(a.reciprocal(), a.recip());
+    assert!(a.powf(0.5).abs_diff_eq(a.sqrt(), 1e-6));
+    assert!(a.powf(-1.0).abs_diff_eq(a.recip(), 1e-6));
+    assert!(Vec3::zero().exp().abs_diff_eq(Vec3::one(), 1e-6));
+    assert!(Vec3::one()
+        .exp()
+        .abs_diff_eq(Vec3::splat(std::f32::consts::E), 1e-6));
+}
+
+#[test]
+fn test_vec3_to_radians_degrees() {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    let a = vec3(90.0, -180.0, 45.0);
+    let b = vec3(FRAC_PI_2, -PI, FRAC_PI_4);
+    assert!(a.to_radians().abs_diff_eq(b, 1e-6));
+    assert!(b.to_degrees().abs_diff_eq(a, 1e-4));
+    assert_eq!(a.x().to_radians(), a.to_radians().x());
+    assert_eq!(b.y().to_degrees(), b.to_degrees().y());
+}
+
+#[test]
+fn test_vec3_move_towards() {
+    let a = vec3(1.0, 1.0, 1.0);
+    let b = vec3(4.0, 5.0, 1.0);
+    assert!(a
+        .move_towards(b, 1.0)
+        .abs_diff_eq(vec3(1.6, 1.8, 1.0), 1e-6));
+    assert!(a.move_towards(b, 1.0).abs_diff_eq(a.lerp(b, 0.2), 1e-6));
+    // does not overshoot
+    assert_eq!(b, a.move_towards(b, 5.0));
+    assert_eq!(b, a.move_towards(b, 100.0));
+    assert_eq!(a, a.move_towards(a, 1.0));
+    // a negative distance moves away
+    assert!(a.move_towards(b, -5.0).abs_diff_eq(a.lerp(b, -1.0), 1e-6));
+    // even when very close to the target
+    let c = a + vec3(1e-5, 0.0, 0.0);
+    assert!(a
+        .move_towards(c, -1.0)
+        .abs_diff_eq(a - vec3(1.0, 0.0, 0.0), 1e-6));
+    // a zero distance does not move or snap to the target
+    assert_eq!(a, a.move_towards(b, 0.0));
+    assert_eq!(a, a.move_towards(c, 0.0));
+}
+
+#[test]
+fn test_vec3_angle_between() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    let x = Vec3::unit_x();
+    let y = Vec3::unit_y();
+    assert!((x.angle_between(y) - FRAC_PI_2).abs() < 1e-6);
+    assert!((y.angle_between(x) - FRAC_PI_2).abs() < 1e-6);
+    assert!((x.angle_between(-x) - PI).abs() < 1e-6);
+    assert_eq!(0.0, x.angle_between(x * 3.0));
+    assert!((vec3(1.0, 1.0, 0.0).angle_between(vec3(0.0, 0.0, 2.0)) - FRAC_PI_2).abs() < 1e-6);
+    // nearly parallel vectors keep their precision
+    let a = vec3(1.0, 1.0e-4, 0.0);
+    assert!((a.angle_between(x) - 1.0e-4).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_vec3_any_orthonormal() {
+    for v in [
+        Vec3::unit_x(),
+        Vec3::unit_y(),
+        Vec3::unit_z(),
+        -Vec3::unit_z(),
+        vec3(1.0, -2.0, 3.0).normalize(),
+        vec3(-1.0, 2.0, -0.5).normalize(),
+        vec3(0.0, 1.0e-4, -1.0).normalize(),
+    ]
+    .iter()
+    {
+        let v = *v;
+        let (a, b) = v.any_orthonormal_pair();
+        assert!(a.is_normalized() && b.is_normalized());
+        assert!(v.dot(a).abs() < 1e-6);
+        assert!(v.dot(b).abs() < 1e-6);
+        assert!(a.dot(b).abs() < 1e-6);
+        assert!(a.cross(b).abs_diff_eq(v, 1e-6));
+        assert_eq!(b, v.any_orthonormal_vector());
+    }
+}
+
+#[test]
+fn test_vec3_reflect_refract() {
+    let n = Vec3::unit_y();
+    assert_eq!(vec3(1.0, 2.0, -3.0), vec3(1.0, -2.0, -3.0).reflect(n));
+    assert_eq!(vec3(1.0, 2.0, -3.0), vec3(1.0, -2.0, -3.0).reflect(-n));
+
+    let i = vec3(1.0, -1.0, 0.0).normalize();
+    // an index ratio of one does not bend the ray
+    assert!(i.refract(n, 1.0).abs_diff_eq(i, 1e-6));
+    // entering a denser medium bends the ray towards the normal
+    let r = i.refract(n, 1.0 / 1.5);
+    assert!(r.is_normalized());
+    assert!(r.y() < 0.0 && r.x() < i.x());
+    // Snell's law: eta * sin(incident) = sin(refracted)
+    assert!((i.x() / 1.5 - r.x()).abs() < 1e-6);
+    // total internal reflection
+    assert_eq!(Vec3::zero(), i.refract(n, 1.5));
+}
+
+#[test]
+fn test_vec3_relative_eq() {
+    let a = vec3(1.0e6, 1.0, 1.0e-6);
+    let b = vec3(1.0e6 + 1.0, 1.0 + 1.0e-6, 2.0e-6);
+    assert!(!a.abs_diff_eq(b, 1.0e-5));
+    assert!(a.relative_eq(b, 1.0e-5, 1.0e-5));
+    assert!(!a.relative_eq(b, 1.0e-7, 1.0e-5));
+    assert!(!a.relative_eq(b, 1.0e-5, 1.0e-7));
+    let inf = vec3(f32::INFINITY, 0.0, 0.0);
+    assert!(inf.relative_eq(inf, 0.0, 0.0));
+    let nan = vec3(f32::NAN, 0.0, 0.0);
+    assert!(!nan.relative_eq(nan, 1.0, 1.0));
+}
+
+#[test]
+fn test_vec3_ulps_eq() {
+    let a = vec3(1.0, -2.0, 1.0e10);
+    let next = |x: f32| f32::from_bits(x.to_bits() + 1);
+    let b = vec3(next(1.0), next(-2.0), next(next(1.0e10)));
+    assert!(a.ulps_eq(b, 0.0, 2));
+    assert!(!a.ulps_eq(b, 0.0, 1));
+    assert!(a.ulps_eq(a, 0.0, 0));
+    // different signs are only equal within the absolute difference
+    let c = vec3(1.0e-40, 0.0, 0.0);
+    assert!(!c.ulps_eq(-c, 0.0, 4));
+    assert!(c.ulps_eq(-c, 1.0e-30, 4));
+    let nan = vec3(f32::NAN, 0.0, 0.0);
+    assert!(!nan.ulps_eq(nan, 1.0, 4));
+}
+
 #[test]
 fn test_vec3_min_max() {
     let a = vec3(-1.0, 2.0, -3.0);
@@ -162,6 +466,18 @@ fn test_vec3_min_max() {
     assert_eq!((1.0, 2.0, 3.0), b.max(a).into());
 }
 
+#[test]
+fn test_vec3_clamp() {
+    let min = vec3(1.0, 1.0, -1.0);
+    let max = vec3(3.0, 3.0, 1.0);
+    let a = vec3(0.0, 2.0, 4.0);
+    assert_eq!(vec3(1.0, 2.0, 1.0), a.clamp(min, max));
+    assert_eq!(vec3(1.0, 2.0, 3.0), a.clamp_scalar(1.0, 3.0));
+    assert_eq!(vec3(0.0, 2.0, 2.0), a.min_scalar(2.0));
+    assert_eq!(vec3(0.5, 2.0, 4.0), a.max_scalar(0.5));
+    assert_eq!(a.min(Vec3::splat(2.0)), a.min_scalar(2.0));
+}
+
 #[test]
 fn test_vec3_hmin_hmax() {
     let a = vec3(-1.0, 2.0, -3.0);
@@ -182,9 +498,9 @@ fn test_vec3_eq() {
 
 #[test]
 fn test_vec3_cmp() {
-    assert!(!Vec3Mask::default().any());
-    assert!(!Vec3Mask::default().all());
-    assert_eq!(Vec3Mask::default().bitmask(), 0x0);
+    assert!(!BVec3::default().any());
+    assert!(!BVec3::default().all());
+    assert_eq!(BVec3::default().bitmask(), 0x0);
     let a = vec3(-1.0, -1.0, -1.0);
     let b = vec3(1.0, 1.0, 1.0);
     let c = vec3(-1.0, -1.0, 1.0);
@@ -213,6 +529,8 @@ fn test_extend_truncate() {
     assert_eq!((1.0, 2.0, 3.0, 4.0), b.into());
     let c = b.truncate();
     assert_eq!(a, c);
+    assert_eq!(a, b.xyz());
+    assert_eq!(vec2(1.0, 2.0), a.xy());
 }
 
 #[test]
@@ -229,28 +547,28 @@ fn test_vec3b() {
 
 #[test]
 fn test_vec3mask_bitmask() {
-    assert_eq!(Vec3Mask::new(false, false, false).bitmask(), 0b000);
-    assert_eq!(Vec3Mask::new(true, false, false).bitmask(), 0b001);
-    assert_eq!(Vec3Mask::new(false, true, true).bitmask(), 0b110);
-    assert_eq!(Vec3Mask::new(false, true, false).bitmask(), 0b010);
-    assert_eq!(Vec3Mask::new(true, false, true).bitmask(), 0b101);
-    assert_eq!(Vec3Mask::new(true, true, true).bitmask(), 0b111);
+    assert_eq!(BVec3::new(false, false, false).bitmask(), 0b000);
+    assert_eq!(BVec3::new(true, false, false).bitmask(), 0b001);
+    assert_eq!(BVec3::new(false, true, true).bitmask(), 0b110);
+    assert_eq!(BVec3::new(false, true, false).bitmask(), 0b010);
+    assert_eq!(BVec3::new(true, false, true).bitmask(), 0b101);
+    assert_eq!(BVec3::new(true, true, true).bitmask(), 0b111);
 }
 
 #[test]
 fn test_vec3mask_any() {
-    assert_eq!(Vec3Mask::new(false, false, false).any(), false);
-    assert_eq!(Vec3Mask::new(true, false, false).any(), true);
-    assert_eq!(Vec3Mask::new(false, true, false).any(), true);
-    assert_eq!(Vec3Mask::new(false, false, true).any(), true);
+    assert!(!BVec3::new(false, false, false).any());
+    assert!(BVec3::new(true, false, false).any());
+    assert!(BVec3::new(false, true, false).any());
+    assert!(BVec3::new(false, false, true).any());
 }
 
 #[test]
 fn test_vec3mask_all() {
-    assert_eq!(Vec3Mask::new(true, true, true).all(), true);
-    assert_eq!(Vec3Mask::new(false, true, true).all(), false);
-    assert_eq!(Vec3Mask::new(true, false, true).all(), false);
-    assert_eq!(Vec3Mask::new(true, true, false).all(), false);
+    assert!(BVec3::new(true, true, true).all());
+    assert!(!BVec3::new(false, true, true).all());
+    assert!(!BVec3::new(true, false, true).all());
+    assert!(!BVec3::new(true, true, false).all());
 }
 
 #[test]
@@ -258,19 +576,19 @@ fn test_vec3mask_select() {
     let a = Vec3::new(1.0, 2.0, 3.0);
     let b = Vec3::new(4.0, 5.0, 6.0);
     assert_eq!(
-        Vec3Mask::new(true, true, true).select(a, b),
+        BVec3::new(true, true, true).select(a, b),
         Vec3::new(1.0, 2.0, 3.0),
     );
     assert_eq!(
-        Vec3Mask::new(true, false, true).select(a, b),
+        BVec3::new(true, false, true).select(a, b),
         Vec3::new(1.0, 5.0, 3.0),
     );
     assert_eq!(
-        Vec3Mask::new(false, true, false).select(a, b),
+        BVec3::new(false, true, false).select(a, b),
         Vec3::new(4.0, 2.0, 6.0),
     );
     assert_eq!(
-        Vec3Mask::new(false, false, false).select(a, b),
+        BVec3::new(false, false, false).select(a, b),
         Vec3::new(4.0, 5.0, 6.0),
     );
 }
@@ -278,57 +596,57 @@ fn test_vec3mask_select() {
 #[test]
 fn test_vec3mask_and() {
     assert_eq!(
-        (Vec3Mask::new(false, false, false) & Vec3Mask::new(false, false, false)).bitmask(),
+        (BVec3::new(false, false, false) & BVec3::new(false, false, false)).bitmask(),
         0b000,
     );
     assert_eq!(
-        (Vec3Mask::new(true, true, true) & Vec3Mask::new(true, true, true)).bitmask(),
+        (BVec3::new(true, true, true) & BVec3::new(true, true, true)).bitmask(),
         0b111,
     );
     assert_eq!(
-        (Vec3Mask::new(true, false, true) & Vec3Mask::new(false, true, false)).bitmask(),
+        (BVec3::new(true, false, true) & BVec3::new(false, true, false)).bitmask(),
         0b000,
     );
     assert_eq!(
-        (Vec3Mask::new(true, false, true) & Vec3Mask::new(true, true, true)).bitmask(),
+        (BVec3::new(true, false, true) & BVec3::new(true, true, true)).bitmask(),
         0b101,
     );
 
-    let mut mask = Vec3Mask::new(true, true, false);
-    mask &= Vec3Mask::new(true, false, false);
+    let mut mask = BVec3::new(true, true, false);
+    mask &= BVec3::new(true, false, false);
     assert_eq!(mask.bitmask(), 0b001);
 }
 
 #[test]
 fn test_vec3mask_or() {
     assert_eq!(
-        (Vec3Mask::new(false, false, false) | Vec3Mask::new(false, false, false)).bitmask(),
+        (BVec3::new(false, false, false) | BVec3::new(false, false, false)).bitmask(),
         0b000,
     );
     assert_eq!(
-        (Vec3Mask::new(true, true, true) | Vec3Mask::new(true, true, true)).bitmask(),
+        (BVec3::new(true, true, true) | BVec3::new(true, true, true)).bitmask(),
         0b111,
     );
     assert_eq!(
-        (Vec3Mask::new(true, false, true) | Vec3Mask::new(false, true, false)).bitmask(),
+        (BVec3::new(true, false, true) | BVec3::new(false, true, false)).bitmask(),
         0b111,
     );
     assert_eq!(
-        (Vec3Mask::new(true, false, true) | Vec3Mask::new(true, false, true)).bitmask(),
+        (BVec3::new(true, false, true) | BVec3::new(true, false, true)).bitmask(),
         0b101,
     );
 
-    let mut mask = Vec3Mask::new(true, true, false);
-    mask |= Vec3Mask::new(true, false, false);
+    let mut mask = BVec3::new(true, true, false);
+    mask |= BVec3::new(true, false, false);
     assert_eq!(mask.bitmask(), 0b011);
 }
 
 #[test]
 fn test_vec3mask_not() {
-    assert_eq!((!Vec3Mask::new(false, false, false)).bitmask(), 0b111);
-    assert_eq!((!Vec3Mask::new(true, true, true)).bitmask(), 0b000);
-    assert_eq!((!Vec3Mask::new(true, false, true)).bitmask(), 0b010);
-    assert_eq!((!Vec3Mask::new(false, true, false)).bitmask(), 0b101);
+    assert_eq!((!BVec3::new(false, false, false)).bitmask(), 0b111);
+    assert_eq!((!BVec3::new(true, true, true)).bitmask(), 0b000);
+    assert_eq!((!BVec3::new(true, false, true)).bitmask(), 0b010);
+    assert_eq!((!BVec3::new(false, true, false)).bitmask(), 0b101);
 }
 
 #[test]
@@ -337,7 +655,23 @@ fn test_vec3_sign() {
     assert_eq!(-Vec3::zero().sign(), -Vec3::one());
     assert_eq!(Vec3::one().sign(), Vec3::one());
     assert_eq!((-Vec3::one()).sign(), -Vec3::one());
-    assert_eq!(Vec3::splat(core::f32::NEG_INFINITY).sign(), -Vec3::one());
+    assert_eq!(Vec3::splat(f32::NEG_INFINITY).sign(), -Vec3::one());
+}
+
+#[test]
+fn test_vec3_signum_copysign() {
+    assert_eq!(Vec3::zero().signum(), Vec3::one());
+    assert_eq!((-Vec3::zero()).signum(), -Vec3::one());
+    assert_eq!((-Vec3::zero()).sign(), Vec3::one());
+    assert_eq!(Vec3::splat(f32::NEG_INFINITY).signum(), -Vec3::one());
+    assert!(Vec3::splat(f32::NAN).signum().x().is_nan());
+    let a = vec3(1.0, -2.0, 3.0);
+    assert_eq!(vec3(1.0, -1.0, 1.0), a.signum());
+    assert_eq!(a.abs(), a.copysign(Vec3::one()));
+    assert_eq!(-a.abs(), a.copysign(-Vec3::one()));
+    assert_eq!(a, a.abs().copysign(a));
+    assert_eq!(-a, a.copysign(-a));
+    assert_eq!(-Vec3::one(), Vec3::one().copysign(-Vec3::zero()));
 }
 
 #[cfg(feature = "rand")]
@@ -374,3 +708,77 @@ fn test_vec3_serde() {
     let deserialized = serde_json::from_str::<Vec3>("[1.0,2.0,3.0,4.0]");
     assert!(deserialized.is_err());
 }
+
+#[test]
+fn test_vec3_as() {
+    let a = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(I16Vec3::new(1, 2, 3), a.as_i16vec3());
+    assert_eq!(U16Vec3::new(1, 2, 3), a.as_u16vec3());
+    assert_eq!(IVec3::new(1, 2, 3), a.as_ivec3());
+    assert_eq!(UVec3::new(1, 2, 3), a.as_uvec3());
+    assert_eq!(I64Vec3::new(1, 2, 3), a.as_i64vec3());
+    assert_eq!(U64Vec3::new(1, 2, 3), a.as_u64vec3());
+
+    let b = Vec3::new(-1.5, 2.5, f32::NAN);
+    assert_eq!(IVec3::new(-1, 2, 0), b.as_ivec3());
+    assert_eq!(UVec3::new(0, 2, 0), b.as_uvec3());
+    assert_eq!(i32::MAX, Vec3::new(1.0e10, 1.0, 1.0).as_ivec3().x());
+}
+
+#[test]
+fn test_vec3_try_as() {
+    let a = Vec3::new(1.9, 2.0, 3.0);
+    assert_eq!(Some(IVec3::new(1, 2, 3)), a.try_as_ivec3());
+    assert_eq!(Some(UVec3::new(1, 2, 3)), a.try_as_uvec3());
+    assert_eq!(Some(U64Vec3::new(1, 2, 3)), a.try_as_u64vec3());
+    // negative elements that truncate to zero are in range
+    assert_eq!(
+        Some(UVec3::new(0, 2, 3)),
+        Vec3::new(-0.5, 2.0, 3.0).try_as_uvec3()
+    );
+    assert_eq!(None, Vec3::new(-1.0, 2.0, 3.0).try_as_uvec3());
+    assert_eq!(None, Vec3::new(1.9, 2.0, f32::NAN).try_as_ivec3());
+    assert_eq!(None, Vec3::splat(f32::INFINITY).try_as_i64vec3());
+    assert_eq!(None, Vec3::splat(f32::NEG_INFINITY).try_as_i64vec3());
+    assert_eq!(
+        Some(U16Vec3::splat(u16::MAX)),
+        Vec3::splat(65535.5).try_as_u16vec3()
+    );
+    assert_eq!(None, Vec3::splat(65536.0).try_as_u16vec3());
+    assert_eq!(
+        Some(I16Vec3::splat(i16::MIN)),
+        Vec3::splat(-32768.5).try_as_i16vec3()
+    );
+    assert_eq!(None, Vec3::splat(-32769.0).try_as_i16vec3());
+    assert_eq!(
+        Some(IVec3::splat(i32::MIN)),
+        Vec3::splat(-2147483648.0).try_as_ivec3()
+    );
+    assert_eq!(None, Vec3::splat(2147483648.0).try_as_ivec3());
+    assert_eq!(None, Vec3::splat(4294967296.0).try_as_uvec3());
+}
+
+#[test]
+fn test_vec3_euclid() {
+    let a = Vec3::new(-7.0, 7.0, -7.5);
+    let b = Vec3::new(4.0, -4.0, 2.0);
+    assert_eq!(Vec3::new(-2.0, -1.0, -4.0), a.div_euclid(b));
+    assert_eq!(Vec3::new(1.0, 3.0, 0.5), a.rem_euclid(b));
+}
+
+#[test]
+fn test_vec3_trig() {
+    let a = vec3(0.5, -1.25, 3.0);
+    let b = vec3(-2.0, 0.75, -0.0);
+    let (sin, cos) = a.sin_cos();
+    let (sin, cos, tan): ([f32; 3], [f32; 3], [f32; 3]) = (sin.into(), cos.into(), a.tan().into());
+    let atan2: [f32; 3] = a.atan2(b).into();
+    let (a, b): ([f32; 3], [f32; 3]) = (a.into(), b.into());
+    for i in 0..3 {
+        assert!((sin[i] - a[i].sin()).abs() < 1e-6);
+        assert!((cos[i] - a[i].cos()).abs() < 1e-6);
+        assert!((tan[i] - a[i].tan()).abs() < 1e-5);
+        assert!((atan2[i] - a[i].atan2(b[i])).abs() < 1e-6);
+    }
+}
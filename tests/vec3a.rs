@@ -0,0 +1,675 @@
+mod support;
+
+use glam::*;
+#[cfg(feature = "rand")]
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "rand")]
+use rand_xoshiro::Xoshiro256Plus;
+
+#[test]
+fn test_vec3a_align() {
+    use std::mem;
+    assert_eq!(16, mem::size_of::<Vec3A>());
+    assert_eq!(16, mem::align_of::<Vec3A>());
+}
+
+#[test]
+fn test_vec3a_new() {
+    let v = vec3a(1.0, 2.0, 3.0);
+
+    assert_eq!(v.x(), 1.0);
+    assert_eq!(v.y(), 2.0);
+    assert_eq!(v.z(), 3.0);
+
+    let t = (1.0, 2.0, 3.0);
+    let v = Vec3A::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1.0, 2.0, 3.0];
+    let v = Vec3A::from(a);
+    let a1: [f32; 3] = v.into();
+    assert_eq!(a, a1);
+
+    let v = Vec3A::new(t.0, t.1, t.2);
+    assert_eq!(t, v.into());
+
+    assert_eq!(Vec3A::new(1.0, 0.0, 0.0), Vec3A::unit_x());
+    assert_eq!(Vec3A::new(0.0, 1.0, 0.0), Vec3A::unit_y());
+    assert_eq!(Vec3A::new(0.0, 0.0, 1.0), Vec3A::unit_z());
+}
+
+#[test]
+fn test_vec3a_fmt() {
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
+    assert_eq!(format!("{:?}", a), "Vec3A(__m128(1.0, 2.0, 3.0, 3.0))");
+    #[cfg(any(
+        not(target_feature = "sse2"),
+        feature = "scalar-math",
+        feature = "core-simd"
+    ))]
+    assert_eq!(format!("{:?}", a), "Vec3A(1.0, 2.0, 3.0)");
+    // assert_eq!(format!("{:#?}", a), "Vec3A(\n    1.0,\n    2.0,\n    3.0\n)");
+    assert_eq!(format!("{}", a), "[1, 2, 3]");
+}
+
+#[test]
+fn test_vec3a_try_slice() {
+    let v = vec3a(1.0, 2.0, 3.0);
+    let mut a = [0.0; 4];
+    v.write_to_slice(&mut a[1..]);
+    assert_eq!(v, Vec3A::from_slice(&a[1..]));
+    assert_eq!(Ok(v), Vec3A::try_from_slice(&a[1..]));
+    let err = Vec3A::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(3, err.required_len());
+    let mut b = [0.0; 2];
+    assert_eq!(Err(err), v.try_write_to_slice(&mut b));
+    assert!(v.try_write_to_slice(&mut a).is_ok());
+    assert_eq!(v, Vec3A::from_slice(&a));
+}
+
+#[test]
+fn test_vec3a_index() {
+    let mut v = vec3a(1.0, 2.0, 3.0);
+    assert_eq!(1.0, v[0]);
+    assert_eq!(2.0, v[1]);
+    assert_eq!(3.0, v[2]);
+    v[2] = 5.0;
+    assert_eq!(5.0, v.z());
+    v[0] += 2.0;
+    assert_eq!(vec3a(3.0, 2.0, 5.0), v);
+}
+
+#[test]
+#[should_panic]
+fn test_vec3a_index_out_of_bounds() {
+    let _ = Vec3A::zero()[3];
+}
+
+#[test]
+fn test_vec3a_zero() {
+    let v = Vec3A::zero();
+    assert_eq!((0.0, 0.0, 0.0), v.into());
+    assert_eq!(v, Vec3A::default());
+}
+
+#[test]
+fn test_vec3a_splat() {
+    let v = Vec3A::splat(1.0);
+    assert_eq!((1.0, 1.0, 1.0), v.into());
+}
+
+#[test]
+fn test_vec3a_accessors() {
+    let mut a = vec3a(0.0, 0.0, 0.0);
+    a.set_x(1.0);
+    a.set_y(2.0);
+    a.set_z(3.0);
+    assert_eq!(1.0, a.x());
+    assert_eq!(2.0, a.y());
+    assert_eq!(3.0, a.z());
+}
+
+#[test]
+fn test_vec3a_funcs() {
+    let x = vec3a(1.0, 0.0, 0.0);
+    let y = vec3a(0.0, 1.0, 0.0);
+    let z = vec3a(0.0, 0.0, 1.0);
+    assert_eq!(1.0, x.dot(x));
+    assert_eq!(0.0, x.dot(y));
+    assert_eq!(-1.0, z.dot(-z));
+    assert_eq!(y, z.cross(x));
+    assert_eq!(z, x.cross(y));
+    assert_eq!(4.0, (2.0 * x).length_squared());
+    assert_eq!(9.0, (-3.0 * y).length_squared());
+    assert_eq!(16.0, (4.0 * z).length_squared());
+    assert_eq!(2.0, (-2.0 * x).length());
+    assert_eq!(3.0, (3.0 * y).length());
+    assert_eq!(4.0, (-4.0 * z).length());
+    assert_approx_eq!(x, (2.0 * x).normalize());
+    assert_eq!(
+        1.0 * 4.0 + 2.0 * 5.0 + 3.0 * 6.0,
+        vec3a(1.0, 2.0, 3.0).dot(vec3a(4.0, 5.0, 6.0))
+    );
+    assert_eq!(
+        Vec3A::splat(1.0 * 4.0 + 2.0 * 5.0 + 3.0 * 6.0),
+        vec3a(1.0, 2.0, 3.0).dot_into_vec(vec3a(4.0, 5.0, 6.0))
+    );
+    assert_eq!(
+        2.0 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0,
+        vec3a(2.0, 3.0, 4.0).length_squared()
+    );
+    assert_eq!(
+        (2.0_f32 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0).sqrt(),
+        vec3a(2.0, 3.0, 4.0).length()
+    );
+    assert_eq!(
+        1.0 / (2.0_f32 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0).sqrt(),
+        vec3a(2.0, 3.0, 4.0).length_reciprocal()
+    );
+    assert!(vec3a(2.0, 3.0, 4.0).normalize().is_normalized());
+    assert_approx_eq!(
+        vec3a(2.0, 3.0, 4.0) / (2.0_f32 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0).sqrt(),
+        vec3a(2.0, 3.0, 4.0).normalize()
+    );
+}
+
+#[test]
+fn test_vec3a_length_recip() {
+    let a = vec3a(2.0, -3.0, 6.0);
+    assert_approx_eq!(a.length_reciprocal(), a.length_recip(), 1e-6);
+    assert_approx_eq!(1.0, (a * a.length_recip()).length(), 1e-6);
+    assert_approx_eq!(a / a.length(), a.normalize(), 1e-6);
+    assert!(a.normalize().is_normalized());
+    let b = Vec3A::splat(1e-3);
+    assert_approx_eq!(
+        b.length_reciprocal(),
+        b.length_recip(),
+        1e-6 * b.length_reciprocal()
+    );
+}
+
+#[test]
+fn test_vec3a_normalize_extremes() {
+    // the squared length of a tiny vector is denormal
+    assert_approx_eq!(Vec3A::unit_x(), (Vec3A::unit_x() * 1e-19).normalize(), 1e-6);
+    // and the squared length of a huge vector overflows
+    let huge = (Vec3A::unit_x() * 1e20).normalize();
+    assert!(!huge.is_nan());
+    assert_eq!(Vec3A::zero(), huge);
+}
+
+#[test]
+fn test_vec3a_outer() {
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    let b = Vec3A::new(4.0, 5.0, 6.0);
+    let m = a.outer(b);
+    assert_eq!(Mat3A::from_cols(a * 4.0, a * 5.0, a * 6.0), m);
+    // (a * b^T) * c = a * (b . c)
+    let c = Vec3A::new(-1.0, 2.0, 0.5);
+    assert_eq!(a * b.dot(c), m * c);
+}
+
+#[test]
+fn test_vec3a_mul_add() {
+    assert_eq!(
+        vec3a(1.0, 2.0, 3.0).mul_add(vec3a(5.0, 6.0, 7.0), vec3a(-1.0, 0.5, 2.0)),
+        vec3a(4.0, 12.5, 23.0)
+    );
+}
+
+#[test]
+fn test_vec3a_ops() {
+    let a = vec3a(1.0, 2.0, 3.0);
+    assert_eq!((2.0, 4.0, 6.0), (a + a).into());
+    assert_eq!((0.0, 0.0, 0.0), (a - a).into());
+    assert_eq!((1.0, 4.0, 9.0), (a * a).into());
+    assert_eq!((2.0, 4.0, 6.0), (a * 2.0).into());
+    assert_eq!((1.0, 1.0, 1.0), (a / a).into());
+    assert_eq!((0.5, 1.0, 1.5), (a / 2.0).into());
+    assert_eq!((-1.0, -2.0, -3.0), (-a).into());
+}
+
+#[test]
+fn test_vec3a_assign_ops() {
+    let a = vec3a(1.0, 2.0, 3.0);
+    let mut b = a;
+    b += a;
+    assert_eq!((2.0, 4.0, 6.0), b.into());
+    b -= a;
+    assert_eq!((1.0, 2.0, 3.0), b.into());
+    b *= a;
+    assert_eq!((1.0, 4.0, 9.0), b.into());
+    b /= a;
+    assert_eq!((1.0, 2.0, 3.0), b.into());
+    b *= 2.0;
+    assert_eq!((2.0, 4.0, 6.0), b.into());
+    b /= 2.0;
+    assert_eq!((1.0, 2.0, 3.0), b.into());
+}
+
+#[test]
+fn test_vec3a_sum() {
+    let a = vec3a(1.0, 2.0, 3.0);
+    let vs = [a, a, a];
+    assert_eq!(vec3a(3.0, 6.0, 9.0), vs.iter().sum());
+    assert_eq!(vec3a(3.0, 6.0, 9.0), vs.iter().copied().sum());
+    assert_eq!(Vec3A::zero(), std::iter::empty::<Vec3A>().sum());
+}
+
+#[test]
+fn test_vec3a_clamp_length() {
+    let v = vec3a(0.0, 3.0, 4.0);
+    assert_eq!(v, v.clamp_length(1.0, 10.0));
+    assert_eq!(v * 0.4, v.clamp_length(0.0, 2.0));
+    assert_eq!(v * 2.0, v.clamp_length(10.0, 20.0));
+    assert_eq!(v * 0.4, v.clamp_length_max(2.0));
+    assert_eq!(v, v.clamp_length_max(10.0));
+    assert_eq!(v * 2.0, v.clamp_length_min(10.0));
+    assert_eq!(v, v.clamp_length_min(2.0));
+    assert_eq!(Vec3A::zero(), v.clamp_length_max(0.0));
+    // a zero vector has no direction to scale along
+    assert_eq!(Vec3A::zero(), Vec3A::zero().clamp_length(1.0, 2.0));
+    assert_eq!(Vec3A::zero(), Vec3A::zero().clamp_length_min(1.0));
+}
+
+#[test]
+fn test_vec3a_lerp() {
+    let a = vec3a(-1.0, 0.0, 2.0);
+    let b = vec3a(3.0, 4.0, -2.0);
+    assert_eq!(a, a.lerp(b, 0.0));
+    assert_eq!(b, a.lerp(b, 1.0));
+    assert_eq!(vec3a(1.0, 2.0, 0.0), a.lerp(b, 0.5));
+    // the interpolation value is not clamped
+    assert_eq!(vec3a(7.0, 8.0, -6.0), a.lerp(b, 2.0));
+    assert_eq!(vec3a(-5.0, -4.0, 6.0), a.lerp(b, -1.0));
+}
+
+#[test]
+fn test_vec3a_distance() {
+    let a = vec3a(1.0, 2.0, 3.0);
+    let b = vec3a(3.0, 5.0, 9.0);
+    assert_eq!(7.0, a.distance(b));
+    assert_eq!(7.0, b.distance(a));
+    assert_eq!(49.0, a.distance_squared(b));
+    assert_eq!((a - b).length(), a.distance(b));
+    assert_eq!(0.0, a.distance(a));
+}
+
+#[test]
+fn test_vec3a_midpoint() {
+    let a = vec3a(-1.0, 0.0, 2.0);
+    let b = vec3a(3.0, 5.0, 2.0);
+    assert_eq!(vec3a(1.0, 2.5, 2.0), a.midpoint(b));
+    assert_eq!(a.lerp(b, 0.5), a.midpoint(b));
+}
+
+#[test]
+fn test_vec3a_nan_finite() {
+    let a = vec3a(1.0, f32::NAN, f32::INFINITY);
+    assert!(a.is_nan());
+    assert!(!a.is_finite());
+    assert_eq!(BVec3::new(false, true, false), a.is_nan_mask());
+    assert_eq!(BVec3::new(true, false, false), a.is_finite_mask());
+    assert!(!Vec3A::one().is_nan());
+    assert!(Vec3A::one().is_finite());
+    assert!(!Vec3A::splat(f32::NEG_INFINITY).is_finite_mask().any());
+}
+
+#[test]
+fn test_vec3a_rounding() {
+    let a = vec3a(-1.5, 2.5, -0.25);
+    assert_eq!(vec3a(-2.0, 2.0, -1.0), a.floor());
+    assert_eq!(vec3a(-1.0, 3.0, -0.0), a.ceil());
+    assert_eq!(vec3a(-2.0, 3.0, -0.0), a.round());
+    assert_eq!(vec3a(-1.0, 2.0, -0.0), a.trunc());
+    assert_eq!(vec3a(0.5, 0.5, 0.75), a.fract());
+    assert_eq!(a, a.floor() + a.fract());
+}
+
+#[test]
+fn test_vec3a_exp_pow() {
+    let a = vec3a(4.0, 0.25, 16.0);
+    assert_eq!(vec3a(2.0, 0.5, 4.0), a.sqrt());
+    assert_eq!(vec3a(2.0, -2.0, 4.0), a.log2());
+    assert!(a.log2().exp2().abs_diff_eq(a, 1e-6));
+    assert_eq!(vec3a(0.25, 4.0, 0.0625), a.recip());
+    assert_eq!(a.reciprocal(), a.recip());
+    assert!(a.powf(0.5).abs_diff_eq(a.sqrt(), 1e-6));
+    assert!(a.powf(-1.0).abs_diff_eq(a.recip(), 1e-6));
+    assert!(Vec3A::zero().exp().abs_diff_eq(Vec3A::one(), 1e-6));
+    assert!(Vec3A::one()
+        .exp()
+        .abs_diff_eq(Vec3A::splat(std::f32::consts::E), 1e-6));
+}
+
+#[test]
+fn test_vec3a_to_radians_degrees() {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    let a = vec3a(90.0, -180.0, 45.0);
+    let b = vec3a(FRAC_PI_2, -PI, FRAC_PI_4);
+    assert!(a.to_radians().abs_diff_eq(b, 1e-6));
+    assert!(b.to_degrees().abs_diff_eq(a, 1e-4));
+    assert_eq!(a.x().to_radians(), a.to_radians().x());
+    assert_eq!(b.y().to_degrees(), b.to_degrees().y());
+}
+
+#[test]
+fn test_vec3a_move_towards() {
+    let a = vec3a(1.0, 1.0, 1.0);
+    let b = vec3a(4.0, 5.0, 1.0);
+    assert!(a
+        .move_towards(b, 1.0)
+        .abs_diff_eq(vec3a(1.6, 1.8, 1.0), 1e-6));
+    assert!(a.move_towards(b, 1.0).abs_diff_eq(a.lerp(b, 0.2), 1e-6));
+    // does not overshoot
+    assert_eq!(b, a.move_towards(b, 5.0));
+    assert_eq!(b, a.move_towards(b, 100.0));
+    assert_eq!(a, a.move_towards(a, 1.0));
+    // a negative distance moves away
+    assert!(a.move_towards(b, -5.0).abs_diff_eq(a.lerp(b, -1.0), 1e-6));
+    // even when very close to the target
+    let c = a + vec3a(1e-5, 0.0, 0.0);
+    assert!(a
+        .move_towards(c, -1.0)
+        .abs_diff_eq(a - vec3a(1.0, 0.0, 0.0), 1e-6));
+    // a zero distance does not move or snap to the target
+    assert_eq!(a, a.move_towards(b, 0.0));
+    assert_eq!(a, a.move_towards(c, 0.0));
+}
+
+#[test]
+fn test_vec3a_angle_between() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    let x = Vec3A::unit_x();
+    let y = Vec3A::unit_y();
+    assert!((x.angle_between(y) - FRAC_PI_2).abs() < 1e-6);
+    assert!((y.angle_between(x) - FRAC_PI_2).abs() < 1e-6);
+    assert!((x.angle_between(-x) - PI).abs() < 1e-6);
+    assert_eq!(0.0, x.angle_between(x * 3.0));
+    assert!((vec3a(1.0, 1.0, 0.0).angle_between(vec3a(0.0, 0.0, 2.0)) - FRAC_PI_2).abs() < 1e-6);
+    // nearly parallel vectors keep their precision
+    let a = vec3a(1.0, 1.0e-4, 0.0);
+    assert!((a.angle_between(x) - 1.0e-4).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_vec3a_any_orthonormal() {
+    for v in [
+        Vec3A::unit_x(),
+        Vec3A::unit_y(),
+        Vec3A::unit_z(),
+        -Vec3A::unit_z(),
+        vec3a(1.0, -2.0, 3.0).normalize(),
+        vec3a(-1.0, 2.0, -0.5).normalize(),
+        vec3a(0.0, 1.0e-4, -1.0).normalize(),
+    ]
+    .iter()
+    {
+        let v = *v;
+        let (a, b) = v.any_orthonormal_pair();
+        assert!(a.is_normalized() && b.is_normalized());
+        assert!(v.dot(a).abs() < 1e-6);
+        assert!(v.dot(b).abs() < 1e-6);
+        assert!(a.dot(b).abs() < 1e-6);
+        assert!(a.cross(b).abs_diff_eq(v, 1e-6));
+        assert_eq!(b, v.any_orthonormal_vector());
+    }
+}
+
+#[test]
+fn test_vec3a_reflect_refract() {
+    let n = Vec3A::unit_y();
+    assert_eq!(vec3a(1.0, 2.0, -3.0), vec3a(1.0, -2.0, -3.0).reflect(n));
+    assert_eq!(vec3a(1.0, 2.0, -3.0), vec3a(1.0, -2.0, -3.0).reflect(-n));
+
+    let i = vec3a(1.0, -1.0, 0.0).normalize();
+    // an index ratio of one does not bend the ray
+    assert!(i.refract(n, 1.0).abs_diff_eq(i, 1e-6));
+    // entering a denser medium bends the ray towards the normal
+    let r = i.refract(n, 1.0 / 1.5);
+    assert!(r.is_normalized());
+    assert!(r.y() < 0.0 && r.x() < i.x());
+    // Snell's law: eta * sin(incident) = sin(refracted)
+    assert!((i.x() / 1.5 - r.x()).abs() < 1e-6);
+    // total internal reflection
+    assert_eq!(Vec3A::zero(), i.refract(n, 1.5));
+}
+
+#[test]
+fn test_vec3a_min_max() {
+    let a = vec3a(-1.0, 2.0, -3.0);
+    let b = vec3a(1.0, -2.0, 3.0);
+    assert_eq!((-1.0, -2.0, -3.0), a.min(b).into());
+    assert_eq!((-1.0, -2.0, -3.0), b.min(a).into());
+    assert_eq!((1.0, 2.0, 3.0), a.max(b).into());
+    assert_eq!((1.0, 2.0, 3.0), b.max(a).into());
+}
+
+#[test]
+fn test_vec3a_clamp() {
+    let min = vec3a(1.0, 1.0, -1.0);
+    let max = vec3a(3.0, 3.0, 1.0);
+    let a = vec3a(0.0, 2.0, 4.0);
+    assert_eq!(vec3a(1.0, 2.0, 1.0), a.clamp(min, max));
+    assert_eq!(vec3a(1.0, 2.0, 3.0), a.clamp_scalar(1.0, 3.0));
+    assert_eq!(vec3a(0.0, 2.0, 2.0), a.min_scalar(2.0));
+    assert_eq!(vec3a(0.5, 2.0, 4.0), a.max_scalar(0.5));
+    assert_eq!(a.min(Vec3A::splat(2.0)), a.min_scalar(2.0));
+}
+
+#[test]
+fn test_vec3a_hmin_hmax() {
+    let a = vec3a(-1.0, 2.0, -3.0);
+    assert_eq!(-3.0, a.min_element());
+    assert_eq!(2.0, a.max_element());
+}
+
+#[test]
+fn test_vec3a_eq() {
+    let a = vec3a(1.0, 1.0, 1.0);
+    let b = vec3a(1.0, 2.0, 3.0);
+    assert!(a.cmpeq(a).all());
+    assert!(b.cmpeq(b).all());
+    assert!(a.cmpne(b).any());
+    assert!(b.cmpne(a).any());
+    assert!(b.cmpeq(a).any());
+}
+
+#[test]
+fn test_vec3a_cmp() {
+    assert!(!BVec3::default().any());
+    assert!(!BVec3::default().all());
+    assert_eq!(BVec3::default().bitmask(), 0x0);
+    let a = vec3a(-1.0, -1.0, -1.0);
+    let b = vec3a(1.0, 1.0, 1.0);
+    let c = vec3a(-1.0, -1.0, 1.0);
+    let d = vec3a(1.0, -1.0, -1.0);
+    assert_eq!(a.cmplt(a).bitmask(), 0x0);
+    assert_eq!(a.cmplt(b).bitmask(), 0x7);
+    assert_eq!(a.cmplt(c).bitmask(), 0x4);
+    assert_eq!(c.cmple(a).bitmask(), 0x3);
+    assert_eq!(a.cmplt(d).bitmask(), 0x1);
+    assert!(a.cmplt(b).all());
+    assert!(a.cmplt(c).any());
+    assert!(a.cmple(b).all());
+    assert!(a.cmple(a).all());
+    assert!(b.cmpgt(a).all());
+    assert!(b.cmpge(a).all());
+    assert!(b.cmpge(b).all());
+    assert!(!(a.cmpge(c).all()));
+    assert!(c.cmple(c).all());
+    assert!(c.cmpge(c).all());
+}
+
+#[test]
+fn test_extend_truncate() {
+    let a = vec3a(1.0, 2.0, 3.0);
+    let b = a.extend(4.0);
+    assert_eq!((1.0, 2.0, 3.0, 4.0), b.into());
+    let c = Vec3A::from(b);
+    assert_eq!(a, c);
+    assert_eq!(vec3(1.0, 2.0, 3.0), b.xyz());
+    assert_eq!(vec2(1.0, 2.0), a.xy());
+}
+
+#[test]
+fn test_vec3b() {
+    // make sure the unused 'w' value doesn't break Vec3b behaviour
+    let a = Vec4::zero();
+    let mut b = Vec3A::from(a);
+    b.set_x(1.0);
+    b.set_y(1.0);
+    b.set_z(1.0);
+    assert!(!b.cmpeq(Vec3A::zero()).any());
+    assert!(b.cmpeq(Vec3A::splat(1.0)).all());
+}
+
+#[test]
+fn test_vec3a_select() {
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    let b = Vec3A::new(4.0, 5.0, 6.0);
+    assert_eq!(
+        Vec3A::select(BVec3::new(true, true, true), a, b),
+        Vec3A::new(1.0, 2.0, 3.0),
+    );
+    assert_eq!(
+        Vec3A::select(BVec3::new(true, false, true), a, b),
+        Vec3A::new(1.0, 5.0, 3.0),
+    );
+    assert_eq!(
+        Vec3A::select(BVec3::new(false, true, false), a, b),
+        Vec3A::new(4.0, 2.0, 6.0),
+    );
+    assert_eq!(
+        Vec3A::select(BVec3::new(false, false, false), a, b),
+        Vec3A::new(4.0, 5.0, 6.0),
+    );
+}
+
+#[test]
+fn test_vec3a_from() {
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    let b = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(a, Vec3A::from(b));
+    assert_eq!(b, Vec3::from(a));
+    assert_eq!(a, Vec3A::from(Vec4::new(1.0, 2.0, 3.0, 4.0)));
+    assert_eq!(Vec2::new(1.0, 2.0), a.truncate());
+}
+
+#[test]
+fn test_vec3a_sign() {
+    assert_eq!(Vec3A::zero().sign(), Vec3A::one());
+    assert_eq!(-Vec3A::zero().sign(), -Vec3A::one());
+    assert_eq!(Vec3A::one().sign(), Vec3A::one());
+    assert_eq!((-Vec3A::one()).sign(), -Vec3A::one());
+    assert_eq!(Vec3A::splat(f32::NEG_INFINITY).sign(), -Vec3A::one());
+}
+
+#[test]
+fn test_vec3a_signum_copysign() {
+    assert_eq!(Vec3A::zero().signum(), Vec3A::one());
+    assert_eq!((-Vec3A::zero()).signum(), -Vec3A::one());
+    assert_eq!((-Vec3A::zero()).sign(), Vec3A::one());
+    assert_eq!(Vec3A::splat(f32::NEG_INFINITY).signum(), -Vec3A::one());
+    assert!(Vec3A::splat(f32::NAN).signum().x().is_nan());
+    let a = vec3a(1.0, -2.0, 3.0);
+    assert_eq!(vec3a(1.0, -1.0, 1.0), a.signum());
+    assert_eq!(a.abs(), a.copysign(Vec3A::one()));
+    assert_eq!(-a.abs(), a.copysign(-Vec3A::one()));
+    assert_eq!(a, a.abs().copysign(a));
+    assert_eq!(-a, a.copysign(-a));
+    assert_eq!(-Vec3A::one(), Vec3A::one().copysign(-Vec3A::zero()));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_vec3a_rand() {
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (f32, f32, f32) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: Vec3A = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[test]
+fn test_vec3a_abs() {
+    assert_eq!(Vec3A::zero().abs(), Vec3A::zero());
+    assert_eq!(Vec3A::one().abs(), Vec3A::one());
+    assert_eq!((-Vec3A::one()).abs(), Vec3A::one());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_vec3a_serde() {
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0,3.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Vec3A>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec3A>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec3A>("[1.0,2.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec3A>("[1.0,2.0,3.0,4.0]");
+    assert!(deserialized.is_err());
+}
+
+#[test]
+fn test_vec3a_as() {
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(I16Vec3::new(1, 2, 3), a.as_i16vec3());
+    assert_eq!(U16Vec3::new(1, 2, 3), a.as_u16vec3());
+    assert_eq!(IVec3::new(1, 2, 3), a.as_ivec3());
+    assert_eq!(UVec3::new(1, 2, 3), a.as_uvec3());
+    assert_eq!(I64Vec3::new(1, 2, 3), a.as_i64vec3());
+    assert_eq!(U64Vec3::new(1, 2, 3), a.as_u64vec3());
+
+    let b = Vec3A::new(-1.5, 2.5, f32::NAN);
+    assert_eq!(IVec3::new(-1, 2, 0), b.as_ivec3());
+    assert_eq!(UVec3::new(0, 2, 0), b.as_uvec3());
+    assert_eq!(i32::MAX, Vec3A::new(1.0e10, 1.0, 1.0).as_ivec3().x());
+}
+
+#[test]
+fn test_vec3a_try_as() {
+    let a = Vec3A::new(1.9, 2.0, 3.0);
+    assert_eq!(Some(IVec3::new(1, 2, 3)), a.try_as_ivec3());
+    assert_eq!(Some(UVec3::new(1, 2, 3)), a.try_as_uvec3());
+    assert_eq!(Some(U64Vec3::new(1, 2, 3)), a.try_as_u64vec3());
+    // negative elements that truncate to zero are in range
+    assert_eq!(
+        Some(UVec3::new(0, 2, 3)),
+        Vec3A::new(-0.5, 2.0, 3.0).try_as_uvec3()
+    );
+    assert_eq!(None, Vec3A::new(-1.0, 2.0, 3.0).try_as_uvec3());
+    assert_eq!(None, Vec3A::new(1.9, 2.0, f32::NAN).try_as_ivec3());
+    assert_eq!(None, Vec3A::splat(f32::INFINITY).try_as_i64vec3());
+    assert_eq!(None, Vec3A::splat(f32::NEG_INFINITY).try_as_i64vec3());
+    assert_eq!(
+        Some(U16Vec3::splat(u16::MAX)),
+        Vec3A::splat(65535.5).try_as_u16vec3()
+    );
+    assert_eq!(None, Vec3A::splat(65536.0).try_as_u16vec3());
+    assert_eq!(
+        Some(I16Vec3::splat(i16::MIN)),
+        Vec3A::splat(-32768.5).try_as_i16vec3()
+    );
+    assert_eq!(None, Vec3A::splat(-32769.0).try_as_i16vec3());
+    assert_eq!(
+        Some(IVec3::splat(i32::MIN)),
+        Vec3A::splat(-2147483648.0).try_as_ivec3()
+    );
+    assert_eq!(None, Vec3A::splat(2147483648.0).try_as_ivec3());
+    assert_eq!(None, Vec3A::splat(4294967296.0).try_as_uvec3());
+}
+
+#[test]
+fn test_vec3a_euclid() {
+    let a = Vec3A::new(-7.0, 7.0, -7.5);
+    let b = Vec3A::new(4.0, -4.0, 2.0);
+    assert_eq!(Vec3A::new(-2.0, -1.0, -4.0), a.div_euclid(b));
+    assert_eq!(Vec3A::new(1.0, 3.0, 0.5), a.rem_euclid(b));
+}
+
+#[test]
+fn test_vec3a_trig() {
+    let a = vec3a(0.5, -1.25, 3.0);
+    let b = vec3a(-2.0, 0.75, -0.0);
+    let (sin, cos) = a.sin_cos();
+    let (sin, cos, tan): ([f32; 3], [f32; 3], [f32; 3]) = (sin.into(), cos.into(), a.tan().into());
+    let atan2: [f32; 3] = a.atan2(b).into();
+    let (a, b): ([f32; 3], [f32; 3]) = (a.into(), b.into());
+    for i in 0..3 {
+        assert!((sin[i] - a[i].sin()).abs() < 1e-6);
+        assert!((cos[i] - a[i].cos()).abs() < 1e-6);
+        assert!((tan[i] - a[i].tan()).abs() < 1e-5);
+        assert!((atan2[i] - a[i].atan2(b[i])).abs() < 1e-6);
+    }
+}
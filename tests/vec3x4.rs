@@ -0,0 +1,142 @@
+#[macro_use]
+mod support;
+
+use glam::*;
+
+fn test_vectors() -> [Vec3; 4] {
+    let mut v = [Vec3::zero(); 4];
+    for (i, v) in v.iter_mut().enumerate() {
+        let f = i as f32;
+        *v = Vec3::new(f + 1.0, 2.0 - f, 0.5 * f - 1.0);
+    }
+    v
+}
+
+#[test]
+fn test_vec3x4_align() {
+    use std::mem;
+    assert_eq!(4 * 12, mem::size_of::<Vec3x4>());
+    assert_eq!(4 * 4, mem::align_of::<Vec3x4>());
+}
+
+#[test]
+fn test_vec3x4_pack_unpack() {
+    let a = test_vectors();
+    let w = Vec3x4::from(a);
+    for (i, v) in a.iter().enumerate() {
+        assert_eq!(*v, w.lane(i));
+        assert_eq!(v.x(), w.x()[i]);
+        assert_eq!(v.y(), w.y()[i]);
+        assert_eq!(v.z(), w.z()[i]);
+    }
+    let b: [Vec3; 4] = w.into();
+    assert_eq!(a, b);
+
+    let mut slice = vec![Vec3::zero(); 4 + 1];
+    w.write_to_slice(&mut slice);
+    assert_eq!(w, Vec3x4::from_slice(&slice));
+    assert_eq!(Vec3::zero(), slice[4]);
+
+    let mut w = Vec3x4::splat(Vec3::one());
+    w.set_lane(1, Vec3::unit_y());
+    assert_eq!(Vec3::one(), w.lane(0));
+    assert_eq!(Vec3::unit_y(), w.lane(1));
+    assert_eq!(Vec3x4::zero(), Vec3x4::default());
+}
+
+#[test]
+#[should_panic]
+fn test_vec3x4_from_short_slice() {
+    Vec3x4::from_slice(&[Vec3::zero(); 4 - 1]);
+}
+
+#[test]
+fn test_vec3x4_funcs() {
+    let a = test_vectors();
+    let mut b = a;
+    b.reverse();
+    let wa = Vec3x4::from(a);
+    let wb = Vec3x4::from(b);
+    let dot = wa.dot(wb);
+    let length = wa.length();
+    let length_squared = wa.length_squared();
+    let cross = wa.cross(wb);
+    let normalize = wa.normalize();
+    let min = wa.min(wb);
+    let max = wa.max(wb);
+    for i in 0..4 {
+        assert_eq!(a[i].dot(b[i]), dot[i]);
+        assert_eq!(a[i].length_squared(), length_squared[i]);
+        assert_approx_eq!(a[i].length(), length[i]);
+        assert_eq!(a[i].cross(b[i]), cross.lane(i));
+        assert_approx_eq!(a[i].normalize(), normalize.lane(i));
+        assert_eq!(a[i].min(b[i]), min.lane(i));
+        assert_eq!(a[i].max(b[i]), max.lane(i));
+    }
+}
+
+#[test]
+fn test_vec3x4_cmp_select() {
+    let a = test_vectors();
+    let mut b = a;
+    b.reverse();
+    let wa = Vec3x4::from(a);
+    let wb = Vec3x4::from(b);
+    let masks = [
+        (wa.cmpeq(wb), [false; 4]),
+        (wa.cmpeq(wa), [true; 4]),
+        (wa.cmpne(wb), [true; 4]),
+        (wa.cmpne(wa), [false; 4]),
+        (wa.cmpge(wa), [true; 4]),
+        (wa.cmpgt(wa), [false; 4]),
+        (wa.cmple(wa), [true; 4]),
+        (wa.cmplt(wa), [false; 4]),
+    ];
+    for (mask, expected) in masks.iter() {
+        assert_eq!(*expected, <[bool; 4]>::from(*mask));
+    }
+    // a lane only compares true if all of its elements do
+    let min = Vec3::new(1.5, -10.0, -10.0);
+    let lt = Vec3x4::splat(min).cmplt(wa);
+    for (i, m) in <[bool; 4]>::from(lt).iter().enumerate() {
+        assert_eq!(min.cmplt(a[i]).all(), *m);
+    }
+
+    let one = Vec3x4::splat(Vec3::one());
+    let zero = Vec3x4::splat(Vec3::zero());
+    let mask = BVec4::new(true, false, false, true);
+    let s = Vec3x4::select(mask, one, zero);
+    for (i, m) in <[bool; 4]>::from(mask).iter().enumerate() {
+        let expected = if *m { Vec3::one() } else { Vec3::zero() };
+        assert_eq!(expected, s.lane(i));
+    }
+}
+
+#[test]
+fn test_vec3x4_ops() {
+    let a = test_vectors();
+    let mut b = a;
+    b.reverse();
+    let wa = Vec3x4::from(a);
+    let wb = Vec3x4::from(b);
+    let mut scale = [0.0; 4];
+    for (i, s) in scale.iter_mut().enumerate() {
+        *s = i as f32 - 1.0;
+    }
+    for i in 0..4 {
+        assert_eq!(a[i] + b[i], (wa + wb).lane(i));
+        assert_eq!(a[i] - b[i], (wa - wb).lane(i));
+        assert_eq!(a[i] * b[i], (wa * wb).lane(i));
+        assert_eq!(a[i] * 2.0, (wa * 2.0).lane(i));
+        assert_eq!(a[i] / 2.0, (wa / 2.0).lane(i));
+        assert_eq!(a[i] * scale[i], (wa * scale).lane(i));
+        assert_eq!(-a[i], (-wa).lane(i));
+    }
+    let mut w = wa;
+    w += wb;
+    assert_eq!(wa + wb, w);
+    w -= wb;
+    assert_eq!(wa, w);
+    w *= 2.0;
+    assert_eq!(wa * 2.0, w);
+}
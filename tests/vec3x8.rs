@@ -0,0 +1,150 @@
+#[macro_use]
+mod support;
+
+use glam::*;
+
+fn test_vectors() -> [Vec3; 8] {
+    let mut v = [Vec3::zero(); 8];
+    for (i, v) in v.iter_mut().enumerate() {
+        let f = i as f32;
+        *v = Vec3::new(f + 1.0, 2.0 - f, 0.5 * f - 1.0);
+    }
+    v
+}
+
+#[test]
+fn test_vec3x8_align() {
+    use std::mem;
+    assert_eq!(8 * 12, mem::size_of::<Vec3x8>());
+    assert_eq!(8 * 4, mem::align_of::<Vec3x8>());
+}
+
+#[test]
+fn test_vec3x8_pack_unpack() {
+    let a = test_vectors();
+    let w = Vec3x8::from(a);
+    for (i, v) in a.iter().enumerate() {
+        assert_eq!(*v, w.lane(i));
+        assert_eq!(v.x(), w.x()[i]);
+        assert_eq!(v.y(), w.y()[i]);
+        assert_eq!(v.z(), w.z()[i]);
+    }
+    let b: [Vec3; 8] = w.into();
+    assert_eq!(a, b);
+
+    let mut slice = vec![Vec3::zero(); 8 + 1];
+    w.write_to_slice(&mut slice);
+    assert_eq!(w, Vec3x8::from_slice(&slice));
+    assert_eq!(Vec3::zero(), slice[8]);
+
+    let mut w = Vec3x8::splat(Vec3::one());
+    w.set_lane(1, Vec3::unit_y());
+    assert_eq!(Vec3::one(), w.lane(0));
+    assert_eq!(Vec3::unit_y(), w.lane(1));
+    assert_eq!(Vec3x8::zero(), Vec3x8::default());
+}
+
+#[test]
+#[should_panic]
+fn test_vec3x8_from_short_slice() {
+    Vec3x8::from_slice(&[Vec3::zero(); 8 - 1]);
+}
+
+#[test]
+fn test_vec3x8_funcs() {
+    let a = test_vectors();
+    let mut b = a;
+    b.reverse();
+    let wa = Vec3x8::from(a);
+    let wb = Vec3x8::from(b);
+    let dot = wa.dot(wb);
+    let length = wa.length();
+    let length_squared = wa.length_squared();
+    let cross = wa.cross(wb);
+    let normalize = wa.normalize();
+    let min = wa.min(wb);
+    let max = wa.max(wb);
+    for i in 0..8 {
+        assert_eq!(a[i].dot(b[i]), dot[i]);
+        assert_eq!(a[i].length_squared(), length_squared[i]);
+        assert_approx_eq!(a[i].length(), length[i]);
+        assert_eq!(a[i].cross(b[i]), cross.lane(i));
+        assert_approx_eq!(a[i].normalize(), normalize.lane(i));
+        assert_eq!(a[i].min(b[i]), min.lane(i));
+        assert_eq!(a[i].max(b[i]), max.lane(i));
+    }
+}
+
+fn to_bools(mask: [BVec4; 2]) -> [bool; 8] {
+    let (lo, hi): ([bool; 4], [bool; 4]) = (mask[0].into(), mask[1].into());
+    [lo[0], lo[1], lo[2], lo[3], hi[0], hi[1], hi[2], hi[3]]
+}
+
+#[test]
+fn test_vec3x8_cmp_select() {
+    let a = test_vectors();
+    let mut b = a;
+    b.reverse();
+    let wa = Vec3x8::from(a);
+    let wb = Vec3x8::from(b);
+    let masks = [
+        (wa.cmpeq(wb), [false; 8]),
+        (wa.cmpeq(wa), [true; 8]),
+        (wa.cmpne(wb), [true; 8]),
+        (wa.cmpne(wa), [false; 8]),
+        (wa.cmpge(wa), [true; 8]),
+        (wa.cmpgt(wa), [false; 8]),
+        (wa.cmple(wa), [true; 8]),
+        (wa.cmplt(wa), [false; 8]),
+    ];
+    for (mask, expected) in masks.iter() {
+        assert_eq!(*expected, to_bools(*mask));
+    }
+    // a lane only compares true if all of its elements do
+    let min = Vec3::new(1.5, -10.0, -10.0);
+    let lt = Vec3x8::splat(min).cmplt(wa);
+    for (i, m) in to_bools(lt).iter().enumerate() {
+        assert_eq!(min.cmplt(a[i]).all(), *m);
+    }
+
+    let one = Vec3x8::splat(Vec3::one());
+    let zero = Vec3x8::splat(Vec3::zero());
+    let mask = [
+        BVec4::new(true, false, false, false),
+        BVec4::new(false, true, false, true),
+    ];
+    let s = Vec3x8::select(mask, one, zero);
+    for (i, m) in to_bools(mask).iter().enumerate() {
+        let expected = if *m { Vec3::one() } else { Vec3::zero() };
+        assert_eq!(expected, s.lane(i));
+    }
+}
+
+#[test]
+fn test_vec3x8_ops() {
+    let a = test_vectors();
+    let mut b = a;
+    b.reverse();
+    let wa = Vec3x8::from(a);
+    let wb = Vec3x8::from(b);
+    let mut scale = [0.0; 8];
+    for (i, s) in scale.iter_mut().enumerate() {
+        *s = i as f32 - 1.0;
+    }
+    for i in 0..8 {
+        assert_eq!(a[i] + b[i], (wa + wb).lane(i));
+        assert_eq!(a[i] - b[i], (wa - wb).lane(i));
+        assert_eq!(a[i] * b[i], (wa * wb).lane(i));
+        assert_eq!(a[i] * 2.0, (wa * 2.0).lane(i));
+        assert_eq!(a[i] / 2.0, (wa / 2.0).lane(i));
+        assert_eq!(a[i] * scale[i], (wa * scale).lane(i));
+        assert_eq!(-a[i], (-wa).lane(i));
+    }
+    let mut w = wa;
+    w += wb;
+    assert_eq!(wa + wb, w);
+    w -= wb;
+    assert_eq!(wa, w);
+    w *= 2.0;
+    assert_eq!(wa * 2.0, w);
+}
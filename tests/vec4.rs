@@ -10,13 +10,13 @@ use rand_xoshiro::Xoshiro256Plus;
 fn test_vec4_align() {
     use std::mem;
     assert_eq!(16, mem::size_of::<Vec4>());
-    assert_eq!(16, mem::size_of::<Vec4Mask>());
+    assert_eq!(16, mem::size_of::<BVec4>());
     if cfg!(feature = "scalar-math") {
         assert_eq!(4, mem::align_of::<Vec4>());
-        assert_eq!(4, mem::align_of::<Vec4Mask>());
+        assert_eq!(4, mem::align_of::<BVec4>());
     } else {
         assert_eq!(16, mem::align_of::<Vec4>());
-        assert_eq!(16, mem::align_of::<Vec4Mask>());
+        assert_eq!(16, mem::align_of::<BVec4>());
     }
 }
 
@@ -50,9 +50,16 @@ fn test_vec4_new() {
 #[test]
 fn test_vec4_fmt() {
     let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
-    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    #[cfg(all(
+        target_feature = "sse2",
+        not(any(feature = "scalar-math", feature = "core-simd"))
+    ))]
     assert_eq!(format!("{:?}", a), "Vec4(__m128(1.0, 2.0, 3.0, 4.0))");
-    #[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+    #[cfg(any(
+        not(target_feature = "sse2"),
+        feature = "scalar-math",
+        feature = "core-simd"
+    ))]
     assert_eq!(format!("{:?}", a), "Vec4(1.0, 2.0, 3.0, 4.0)");
     // assert_eq!(
     //     format!("{:#?}", a),
@@ -105,11 +112,15 @@ fn test_vec4_funcs() {
     assert_eq!(3.0, (3.0 * y).length());
     assert_eq!(4.0, (-4.0 * z).length());
     assert_eq!(5.0, (-5.0 * w).length());
-    assert_eq!(x, (2.0 * x).normalize());
+    assert_approx_eq!(x, (2.0 * x).normalize());
     assert_eq!(
         1.0 * 5.0 + 2.0 * 6.0 + 3.0 * 7.0 + 4.0 * 8.0,
         vec4(1.0, 2.0, 3.0, 4.0).dot(vec4(5.0, 6.0, 7.0, 8.0))
     );
+    assert_eq!(
+        Vec4::splat(1.0 * 5.0 + 2.0 * 6.0 + 3.0 * 7.0 + 4.0 * 8.0),
+        vec4(1.0, 2.0, 3.0, 4.0).dot_into_vec(vec4(5.0, 6.0, 7.0, 8.0))
+    );
     assert_eq!(
         2.0 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0 + 5.0 * 5.0,
         vec4(2.0, 3.0, 4.0, 5.0).length_squared()
@@ -129,6 +140,50 @@ fn test_vec4_funcs() {
     );
 }
 
+#[test]
+fn test_vec4_length_recip() {
+    let a = vec4(1.0, -3.0, 5.0, -7.0);
+    assert_approx_eq!(a.length_reciprocal(), a.length_recip(), 1e-6);
+    assert_approx_eq!(1.0, (a * a.length_recip()).length(), 1e-6);
+    assert_approx_eq!(a / a.length(), a.normalize(), 1e-6);
+    assert!(a.normalize().is_normalized());
+    let b = Vec4::splat(1e-3);
+    assert_approx_eq!(
+        b.length_reciprocal(),
+        b.length_recip(),
+        1e-6 * b.length_reciprocal()
+    );
+}
+
+#[test]
+fn test_vec4_normalize_extremes() {
+    // the squared length of a tiny vector is denormal
+    assert_approx_eq!(Vec4::unit_x(), (Vec4::unit_x() * 1e-19).normalize(), 1e-6);
+    // and the squared length of a huge vector overflows
+    let huge = (Vec4::unit_x() * 1e20).normalize();
+    assert!(!huge.is_nan());
+    assert_eq!(Vec4::zero(), huge);
+}
+
+#[test]
+fn test_vec4_outer() {
+    let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Vec4::new(4.0, 5.0, 6.0, 7.0);
+    let m = a.outer(b);
+    assert_eq!(Mat4::from_cols(a * 4.0, a * 5.0, a * 6.0, a * 7.0), m);
+    // (a * b^T) * c = a * (b . c)
+    let c = Vec4::new(-1.0, 2.0, 0.5, 1.0);
+    assert_eq!(a * b.dot(c), m * c);
+}
+
+#[test]
+fn test_vec4_mul_add() {
+    assert_eq!(
+        vec4(1.0, 2.0, 3.0, 4.0).mul_add(vec4(5.0, 6.0, 7.0, 8.0), vec4(-1.0, 0.5, 2.0, -3.0)),
+        vec4(4.0, 12.5, 23.0, 29.0)
+    );
+}
+
 #[test]
 fn test_vec4_ops() {
     let a = vec4(1.0, 2.0, 3.0, 4.0);
@@ -162,6 +217,122 @@ fn test_vec4_assign_ops() {
     assert_eq!((1.0, 2.0, 3.0, 4.0), b.into());
 }
 
+#[test]
+fn test_vec4_sum() {
+    let a = vec4(1.0, 2.0, 3.0, 4.0);
+    let vs = [a, a, a];
+    assert_eq!(vec4(3.0, 6.0, 9.0, 12.0), vs.iter().sum());
+    assert_eq!(vec4(3.0, 6.0, 9.0, 12.0), vs.iter().copied().sum());
+    assert_eq!(Vec4::zero(), std::iter::empty::<Vec4>().sum());
+}
+
+#[test]
+fn test_vec4_clamp_length() {
+    let v = vec4(0.0, 3.0, 0.0, 4.0);
+    assert_eq!(v, v.clamp_length(1.0, 10.0));
+    assert_eq!(v * 0.4, v.clamp_length(0.0, 2.0));
+    assert_eq!(v * 2.0, v.clamp_length(10.0, 20.0));
+    assert_eq!(v * 0.4, v.clamp_length_max(2.0));
+    assert_eq!(v, v.clamp_length_max(10.0));
+    assert_eq!(v * 2.0, v.clamp_length_min(10.0));
+    assert_eq!(v, v.clamp_length_min(2.0));
+    assert_eq!(Vec4::zero(), v.clamp_length_max(0.0));
+    // a zero vector has no direction to scale along
+    assert_eq!(Vec4::zero(), Vec4::zero().clamp_length(1.0, 2.0));
+    assert_eq!(Vec4::zero(), Vec4::zero().clamp_length_min(1.0));
+}
+
+#[test]
+fn test_vec4_is_normalized() {
+    let v = vec4(2.0, 3.0, 4.0, 5.0).normalize();
+    assert!(v.is_normalized());
+    assert!((v * (1.0 + 1.0e-5)).is_normalized());
+    assert!(!(v * (1.0 + 1.0e-3)).is_normalized());
+    assert!(!(v * (1.0 - 1.0e-3)).is_normalized());
+    assert!(!Vec4::zero().is_normalized());
+}
+
+#[test]
+fn test_vec4_nan_finite() {
+    let a = vec4(1.0, f32::NAN, f32::INFINITY, -f32::MAX);
+    assert!(a.is_nan());
+    assert!(!a.is_finite());
+    assert_eq!(BVec4::new(false, true, false, false), a.is_nan_mask());
+    assert_eq!(BVec4::new(true, false, false, true), a.is_finite_mask());
+    assert!(!Vec4::one().is_nan());
+    assert!(Vec4::one().is_finite());
+    assert!(!Vec4::splat(f32::NEG_INFINITY).is_finite_mask().any());
+}
+
+#[test]
+fn test_vec4_lerp() {
+    let a = vec4(-1.0, 0.0, 2.0, 1.0);
+    let b = vec4(3.0, 4.0, -2.0, 1.0);
+    assert_eq!(a, a.lerp(b, 0.0));
+    assert_eq!(b, a.lerp(b, 1.0));
+    assert_eq!(vec4(1.0, 2.0, 0.0, 1.0), a.lerp(b, 0.5));
+    // the interpolation value is not clamped
+    assert_eq!(vec4(7.0, 8.0, -6.0, 1.0), a.lerp(b, 2.0));
+    assert_eq!(vec4(-5.0, -4.0, 6.0, 1.0), a.lerp(b, -1.0));
+}
+
+#[test]
+fn test_vec4_distance() {
+    let a = vec4(1.0, 2.0, 3.0, 4.0);
+    let b = vec4(2.0, 4.0, 5.0, 8.0);
+    assert_eq!(5.0, a.distance(b));
+    assert_eq!(5.0, b.distance(a));
+    assert_eq!(25.0, a.distance_squared(b));
+    assert_eq!((a - b).length(), a.distance(b));
+    assert_eq!(0.0, a.distance(a));
+}
+
+#[test]
+fn test_vec4_midpoint() {
+    let a = vec4(-1.0, 0.0, 2.0, 4.0);
+    let b = vec4(3.0, 5.0, 2.0, 0.0);
+    assert_eq!(vec4(1.0, 2.5, 2.0, 2.0), a.midpoint(b));
+    assert_eq!(a.lerp(b, 0.5), a.midpoint(b));
+}
+
+#[test]
+fn test_vec4_rounding() {
+    let a = vec4(-1.5, 2.5, -0.25, 3.0);
+    assert_eq!(vec4(-2.0, 2.0, -1.0, 3.0), a.floor());
+    assert_eq!(vec4(-1.0, 3.0, -0.0, 3.0), a.ceil());
+    assert_eq!(vec4(-2.0, 3.0, -0.0, 3.0), a.round());
+    assert_eq!(vec4(-1.0, 2.0, -0.0, 3.0), a.trunc());
+    assert_eq!(vec4(0.5, 0.5, 0.75, 0.0), a.fract());
+    assert_eq!(a, a.floor() + a.fract());
+}
+
+#[test]
+fn test_vec4_exp_pow() {
+    let a = vec4(4.0, 0.25, 16.0, 1.0);
+    assert_eq!(vec4(2.0, 0.5, 4.0, 1.0), a.sqrt());
+    assert_eq!(vec4(2.0, -2.0, 4.0, 0.0), a.log2());
+    assert!(a.log2().exp2().abs_diff_eq(a, 1e-6));
+    assert_eq!(vec4(0.25, 4.0, 0.0625, 1.0), a.recip());
+    assert_eq!(a.reciprocal(), a.recip());
+    assert!(a.powf(0.5).abs_diff_eq(a.sqrt(), 1e-6));
+    assert!(a.powf(-1.0).abs_diff_eq(a.recip(), 1e-6));
+    assert!(Vec4::zero().exp().abs_diff_eq(Vec4::one(), 1e-6));
+    assert!(Vec4::one()
+        .exp()
+        .abs_diff_eq(Vec4::splat(std::f32::consts::E), 1e-6));
+}
+
+#[test]
+fn test_vec4_to_radians_degrees() {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    let a = vec4(90.0, -180.0, 45.0, 0.0);
+    let b = vec4(FRAC_PI_2, -PI, FRAC_PI_4, 0.0);
+    assert!(a.to_radians().abs_diff_eq(b, 1e-6));
+    assert!(b.to_degrees().abs_diff_eq(a, 1e-4));
+    assert_eq!(a.x().to_radians(), a.to_radians().x());
+    assert_eq!(b.y().to_degrees(), b.to_degrees().y());
+}
+
 #[test]
 fn test_vec4_min_max() {
     let a = vec4(-1.0, 2.0, -3.0, 4.0);
@@ -172,6 +343,18 @@ fn test_vec4_min_max() {
     assert_eq!((1.0, 2.0, 3.0, 4.0), b.max(a).into());
 }
 
+#[test]
+fn test_vec4_clamp() {
+    let min = vec4(1.0, 1.0, -1.0, -1.0);
+    let max = vec4(3.0, 3.0, 1.0, 1.0);
+    let a = vec4(0.0, 2.0, 4.0, -0.5);
+    assert_eq!(vec4(1.0, 2.0, 1.0, -0.5), a.clamp(min, max));
+    assert_eq!(vec4(1.0, 2.0, 3.0, 1.0), a.clamp_scalar(1.0, 3.0));
+    assert_eq!(vec4(0.0, 2.0, 2.0, -0.5), a.min_scalar(2.0));
+    assert_eq!(vec4(0.5, 2.0, 4.0, 0.5), a.max_scalar(0.5));
+    assert_eq!(a.min(Vec4::splat(2.0)), a.min_scalar(2.0));
+}
+
 #[test]
 fn test_vec4_hmin_hmax() {
     let a = vec4(-1.0, 4.0, -3.0, 2.0);
@@ -194,9 +377,9 @@ fn test_vec4_eq() {
 
 #[test]
 fn test_vec4_cmp() {
-    assert!(!Vec4Mask::default().any());
-    assert!(!Vec4Mask::default().all());
-    assert_eq!(Vec4Mask::default().bitmask(), 0x0);
+    assert!(!BVec4::default().any());
+    assert!(!BVec4::default().all());
+    assert_eq!(BVec4::default().bitmask(), 0x0);
     let a = vec4(-1.0, -1.0, -1.0, -1.0);
     let b = vec4(1.0, 1.0, 1.0, 1.0);
     let c = vec4(-1.0, -1.0, 1.0, 1.0);
@@ -245,13 +428,82 @@ fn test_vec4_slice() {
     assert_eq!(a, d);
 }
 
+#[test]
+fn test_vec4_slice_aligned() {
+    #[repr(align(32))]
+    struct Aligned([f32; 8]);
+    let mut a = Aligned([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let b = Vec4::from_slice_aligned(&a.0).unwrap();
+    assert_eq!(b, vec4(1.0, 2.0, 3.0, 4.0));
+    b.write_to_slice_aligned(&mut a.0[4..]).unwrap();
+    assert_eq!(a.0, [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]);
+
+    let align = core::mem::align_of::<Vec4>();
+    if align > core::mem::align_of::<f32>() {
+        let err = Vec4::from_slice_aligned(&a.0[1..]).unwrap_err();
+        assert_eq!(err.required_alignment(), align);
+        assert_eq!(Vec4::zero().write_to_slice_aligned(&mut a.0[1..]), Err(err));
+        assert_eq!(a.0, [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]);
+    }
+}
+
+#[test]
+fn test_vec4_try_slice() {
+    let v = vec4(1.0, 2.0, 3.0, 4.0);
+    let mut a = [0.0; 5];
+    v.write_to_slice(&mut a[1..]);
+    assert_eq!(v, Vec4::from_slice(&a[1..]));
+    assert_eq!(Ok(v), Vec4::try_from_slice(&a[1..]));
+    let err = Vec4::try_from_slice(&a[2..]).unwrap_err();
+    assert_eq!(4, err.required_len());
+    let mut b = [0.0; 3];
+    assert_eq!(Err(err), v.try_write_to_slice(&mut b));
+    assert!(v.try_write_to_slice(&mut a).is_ok());
+    assert_eq!(v, Vec4::from_slice(&a));
+}
+
+#[test]
+fn test_vec4_index() {
+    let mut v = vec4(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(1.0, v[0]);
+    assert_eq!(2.0, v[1]);
+    assert_eq!(3.0, v[2]);
+    assert_eq!(4.0, v[3]);
+    v[3] = 5.0;
+    assert_eq!(5.0, v.w());
+    v[0] += 2.0;
+    assert_eq!(vec4(3.0, 2.0, 3.0, 5.0), v);
+}
+
+#[test]
+#[should_panic]
+fn test_vec4_index_out_of_bounds() {
+    let _ = Vec4::zero()[4];
+}
+
 #[test]
 fn test_vec4_sign() {
     assert_eq!(Vec4::zero().sign(), Vec4::one());
     assert_eq!(-Vec4::zero().sign(), -Vec4::one());
     assert_eq!(Vec4::one().sign(), Vec4::one());
     assert_eq!((-Vec4::one()).sign(), -Vec4::one());
-    assert_eq!(Vec4::splat(core::f32::NEG_INFINITY).sign(), -Vec4::one());
+    assert_eq!(Vec4::splat(f32::NEG_INFINITY).sign(), -Vec4::one());
+}
+
+#[test]
+fn test_vec4_signum_copysign() {
+    assert_eq!(Vec4::zero().signum(), Vec4::one());
+    assert_eq!((-Vec4::zero()).signum(), -Vec4::one());
+    assert_eq!((-Vec4::zero()).sign(), Vec4::one());
+    assert_eq!(Vec4::splat(f32::NEG_INFINITY).signum(), -Vec4::one());
+    assert!(Vec4::splat(f32::NAN).signum().x().is_nan());
+    let a = vec4(1.0, -2.0, 3.0, -4.0);
+    assert_eq!(vec4(1.0, -1.0, 1.0, -1.0), a.signum());
+    assert_eq!(a.abs(), a.copysign(Vec4::one()));
+    assert_eq!(-a.abs(), a.copysign(-Vec4::one()));
+    assert_eq!(a, a.abs().copysign(a));
+    assert_eq!(-a, a.copysign(-a));
+    assert_eq!(-Vec4::one(), Vec4::one().copysign(-Vec4::zero()));
 }
 
 #[test]
@@ -272,30 +524,30 @@ fn test_vec4_abs() {
 
 #[test]
 fn test_vec4mask_bitmask() {
-    assert_eq!(Vec4Mask::new(false, false, false, false).bitmask(), 0b0000);
-    assert_eq!(Vec4Mask::new(false, false, true, true).bitmask(), 0b1100);
-    assert_eq!(Vec4Mask::new(true, true, false, false).bitmask(), 0b0011);
-    assert_eq!(Vec4Mask::new(false, true, false, true).bitmask(), 0b1010);
-    assert_eq!(Vec4Mask::new(true, false, true, false).bitmask(), 0b0101);
-    assert_eq!(Vec4Mask::new(true, true, true, true).bitmask(), 0b1111);
+    assert_eq!(BVec4::new(false, false, false, false).bitmask(), 0b0000);
+    assert_eq!(BVec4::new(false, false, true, true).bitmask(), 0b1100);
+    assert_eq!(BVec4::new(true, true, false, false).bitmask(), 0b0011);
+    assert_eq!(BVec4::new(false, true, false, true).bitmask(), 0b1010);
+    assert_eq!(BVec4::new(true, false, true, false).bitmask(), 0b0101);
+    assert_eq!(BVec4::new(true, true, true, true).bitmask(), 0b1111);
 }
 
 #[test]
 fn test_vec4mask_any() {
-    assert_eq!(Vec4Mask::new(false, false, false, false).any(), false);
-    assert_eq!(Vec4Mask::new(true, false, false, false).any(), true);
-    assert_eq!(Vec4Mask::new(false, true, false, false).any(), true);
-    assert_eq!(Vec4Mask::new(false, false, true, false).any(), true);
-    assert_eq!(Vec4Mask::new(false, false, false, true).any(), true);
+    assert!(!BVec4::new(false, false, false, false).any());
+    assert!(BVec4::new(true, false, false, false).any());
+    assert!(BVec4::new(false, true, false, false).any());
+    assert!(BVec4::new(false, false, true, false).any());
+    assert!(BVec4::new(false, false, false, true).any());
 }
 
 #[test]
 fn test_vec4mask_all() {
-    assert_eq!(Vec4Mask::new(true, true, true, true).all(), true);
-    assert_eq!(Vec4Mask::new(false, true, true, true).all(), false);
-    assert_eq!(Vec4Mask::new(true, false, true, true).all(), false);
-    assert_eq!(Vec4Mask::new(true, true, false, true).all(), false);
-    assert_eq!(Vec4Mask::new(true, true, true, false).all(), false);
+    assert!(BVec4::new(true, true, true, true).all());
+    assert!(!BVec4::new(false, true, true, true).all());
+    assert!(!BVec4::new(true, false, true, true).all());
+    assert!(!BVec4::new(true, true, false, true).all());
+    assert!(!BVec4::new(true, true, true, false).all());
 }
 
 #[test]
@@ -303,19 +555,19 @@ fn test_vec4mask_select() {
     let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
     let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
     assert_eq!(
-        Vec4Mask::new(true, true, true, true).select(a, b),
+        BVec4::new(true, true, true, true).select(a, b),
         Vec4::new(1.0, 2.0, 3.0, 4.0),
     );
     assert_eq!(
-        Vec4Mask::new(true, false, true, false).select(a, b),
+        BVec4::new(true, false, true, false).select(a, b),
         Vec4::new(1.0, 6.0, 3.0, 8.0),
     );
     assert_eq!(
-        Vec4Mask::new(false, true, false, true).select(a, b),
+        BVec4::new(false, true, false, true).select(a, b),
         Vec4::new(5.0, 2.0, 7.0, 4.0),
     );
     assert_eq!(
-        Vec4Mask::new(false, false, false, false).select(a, b),
+        BVec4::new(false, false, false, false).select(a, b),
         Vec4::new(5.0, 6.0, 7.0, 8.0),
     );
 }
@@ -323,65 +575,57 @@ fn test_vec4mask_select() {
 #[test]
 fn test_vec4mask_and() {
     assert_eq!(
-        (Vec4Mask::new(false, false, false, false) & Vec4Mask::new(false, false, false, false))
-            .bitmask(),
+        (BVec4::new(false, false, false, false) & BVec4::new(false, false, false, false)).bitmask(),
         0b0000,
     );
     assert_eq!(
-        (Vec4Mask::new(true, true, true, true) & Vec4Mask::new(true, true, true, true)).bitmask(),
+        (BVec4::new(true, true, true, true) & BVec4::new(true, true, true, true)).bitmask(),
         0b1111,
     );
     assert_eq!(
-        (Vec4Mask::new(true, false, true, false) & Vec4Mask::new(false, true, false, true))
-            .bitmask(),
+        (BVec4::new(true, false, true, false) & BVec4::new(false, true, false, true)).bitmask(),
         0b0000,
     );
     assert_eq!(
-        (Vec4Mask::new(true, false, true, true) & Vec4Mask::new(true, true, true, false)).bitmask(),
+        (BVec4::new(true, false, true, true) & BVec4::new(true, true, true, false)).bitmask(),
         0b0101,
     );
 
-    let mut mask = Vec4Mask::new(true, true, false, false);
-    mask &= Vec4Mask::new(true, false, true, false);
+    let mut mask = BVec4::new(true, true, false, false);
+    mask &= BVec4::new(true, false, true, false);
     assert_eq!(mask.bitmask(), 0b0001);
 }
 
 #[test]
 fn test_vec4mask_or() {
     assert_eq!(
-        (Vec4Mask::new(false, false, false, false) | Vec4Mask::new(false, false, false, false))
-            .bitmask(),
+        (BVec4::new(false, false, false, false) | BVec4::new(false, false, false, false)).bitmask(),
         0b0000,
     );
     assert_eq!(
-        (Vec4Mask::new(true, true, true, true) | Vec4Mask::new(true, true, true, true)).bitmask(),
+        (BVec4::new(true, true, true, true) | BVec4::new(true, true, true, true)).bitmask(),
         0b1111,
     );
     assert_eq!(
-        (Vec4Mask::new(true, false, true, false) | Vec4Mask::new(false, true, false, true))
-            .bitmask(),
+        (BVec4::new(true, false, true, false) | BVec4::new(false, true, false, true)).bitmask(),
         0b1111,
     );
     assert_eq!(
-        (Vec4Mask::new(true, false, true, false) | Vec4Mask::new(true, false, true, false))
-            .bitmask(),
+        (BVec4::new(true, false, true, false) | BVec4::new(true, false, true, false)).bitmask(),
         0b0101,
     );
 
-    let mut mask = Vec4Mask::new(true, true, false, false);
-    mask |= Vec4Mask::new(true, false, true, false);
+    let mut mask = BVec4::new(true, true, false, false);
+    mask |= BVec4::new(true, false, true, false);
     assert_eq!(mask.bitmask(), 0b0111);
 }
 
 #[test]
 fn test_vec4mask_not() {
-    assert_eq!(
-        (!Vec4Mask::new(false, false, false, false)).bitmask(),
-        0b1111
-    );
-    assert_eq!((!Vec4Mask::new(true, true, true, true)).bitmask(), 0b0000);
-    assert_eq!((!Vec4Mask::new(true, false, true, false)).bitmask(), 0b1010);
-    assert_eq!((!Vec4Mask::new(false, true, false, true)).bitmask(), 0b0101);
+    assert_eq!((!BVec4::new(false, false, false, false)).bitmask(), 0b1111);
+    assert_eq!((!BVec4::new(true, true, true, true)).bitmask(), 0b0000);
+    assert_eq!((!BVec4::new(true, false, true, false)).bitmask(), 0b1010);
+    assert_eq!((!BVec4::new(false, true, false, true)).bitmask(), 0b0101);
 }
 
 #[cfg(feature = "serde")]
@@ -403,3 +647,97 @@ fn test_vec4_serde() {
     let deserialized = serde_json::from_str::<Vec4>("[1.0,2.0,3.0,4.0,5.0]");
     assert!(deserialized.is_err());
 }
+
+#[test]
+fn test_vec4_as() {
+    let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(I16Vec4::new(1, 2, 3, 4), a.as_i16vec4());
+    assert_eq!(U16Vec4::new(1, 2, 3, 4), a.as_u16vec4());
+    assert_eq!(IVec4::new(1, 2, 3, 4), a.as_ivec4());
+    assert_eq!(UVec4::new(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(I64Vec4::new(1, 2, 3, 4), a.as_i64vec4());
+    assert_eq!(U64Vec4::new(1, 2, 3, 4), a.as_u64vec4());
+
+    let b = Vec4::new(-1.5, 2.5, f32::NAN, 4.9);
+    assert_eq!(IVec4::new(-1, 2, 0, 4), b.as_ivec4());
+    assert_eq!(UVec4::new(0, 2, 0, 4), b.as_uvec4());
+    assert_eq!(i32::MAX, Vec4::new(1.0e10, 1.0, 1.0, 1.0).as_ivec4().x());
+}
+
+#[test]
+fn test_vec4_try_as() {
+    let a = Vec4::new(1.9, 2.0, 3.0, 4.0);
+    assert_eq!(Some(IVec4::new(1, 2, 3, 4)), a.try_as_ivec4());
+    assert_eq!(Some(UVec4::new(1, 2, 3, 4)), a.try_as_uvec4());
+    assert_eq!(Some(U64Vec4::new(1, 2, 3, 4)), a.try_as_u64vec4());
+    // negative elements that truncate to zero are in range
+    assert_eq!(
+        Some(UVec4::new(0, 2, 3, 4)),
+        Vec4::new(-0.5, 2.0, 3.0, 4.0).try_as_uvec4()
+    );
+    assert_eq!(None, Vec4::new(-1.0, 2.0, 3.0, 4.0).try_as_uvec4());
+    assert_eq!(None, Vec4::new(1.9, 2.0, 3.0, f32::NAN).try_as_ivec4());
+    assert_eq!(None, Vec4::splat(f32::INFINITY).try_as_i64vec4());
+    assert_eq!(None, Vec4::splat(f32::NEG_INFINITY).try_as_i64vec4());
+    assert_eq!(
+        Some(U16Vec4::splat(u16::MAX)),
+        Vec4::splat(65535.5).try_as_u16vec4()
+    );
+    assert_eq!(None, Vec4::splat(65536.0).try_as_u16vec4());
+    assert_eq!(
+        Some(I16Vec4::splat(i16::MIN)),
+        Vec4::splat(-32768.5).try_as_i16vec4()
+    );
+    assert_eq!(None, Vec4::splat(-32769.0).try_as_i16vec4());
+    assert_eq!(
+        Some(IVec4::splat(i32::MIN)),
+        Vec4::splat(-2147483648.0).try_as_ivec4()
+    );
+    assert_eq!(None, Vec4::splat(2147483648.0).try_as_ivec4());
+    assert_eq!(None, Vec4::splat(4294967296.0).try_as_uvec4());
+}
+
+#[test]
+fn test_vec4_euclid() {
+    let a = Vec4::new(-7.0, 7.0, -7.5, 7.5);
+    let b = Vec4::new(4.0, -4.0, 2.0, 2.0);
+    assert_eq!(Vec4::new(-2.0, -1.0, -4.0, 3.0), a.div_euclid(b));
+    assert_eq!(Vec4::new(1.0, 3.0, 0.5, 1.5), a.rem_euclid(b));
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "sse2",
+    not(any(feature = "scalar-math", feature = "core-simd"))
+))]
+#[test]
+fn test_vec4_m128() {
+    use std::arch::x86_64::*;
+    let a = vec4(1.0, 2.0, 3.0, 4.0);
+    let m: __m128 = a.into();
+    let mut b = [0.0; 4];
+    unsafe { _mm_storeu_ps(b.as_mut_ptr(), m) };
+    assert_eq!(b, [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(Vec4::from(m), a);
+
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    let m: __m128 = a.into();
+    assert_eq!(Vec3A::from(m), a);
+}
+
+#[test]
+fn test_vec4_trig() {
+    let a = vec4(0.5, -1.25, 3.0, -7.5);
+    let b = vec4(-2.0, 0.75, -0.0, 4.0);
+    let (sin, cos) = a.sin_cos();
+    let (sin, cos, tan): ([f32; 4], [f32; 4], [f32; 4]) = (sin.into(), cos.into(), a.tan().into());
+    let atan2: [f32; 4] = a.atan2(b).into();
+    let (a, b): ([f32; 4], [f32; 4]) = (a.into(), b.into());
+    for i in 0..4 {
+        assert!((sin[i] - a[i].sin()).abs() < 1e-6);
+        assert!((cos[i] - a[i].cos()).abs() < 1e-6);
+        assert!((tan[i] - a[i].tan()).abs() < 1e-5);
+        assert!((atan2[i] - a[i].atan2(b[i])).abs() < 1e-6);
+    }
+}